@@ -118,6 +118,7 @@ async fn x_process(receiver: Receiver<[u8; 3]>) -> breadx::Result<()> {
 
                 // send an exposure event to force the window to redraw itself
                 conn.send_event_async(
+                    false,
                     win,
                     EventMask::EXPOSURE,
                     Event::Expose(ExposeEvent {
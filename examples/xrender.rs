@@ -66,37 +66,43 @@ fn main() -> Result {
     )?;
 
     // create a linear gradient
-    let stops = vec![
-        double_to_fixed(0.0),
-        double_to_fixed(1.0 / 3.0),
-        double_to_fixed(2.0 / 3.0),
-        double_to_fixed(1.0),
-    ];
-    let colors = vec![
-        Color {
-            red: 0xFFFF,
-            green: 0,
-            blue: 0,
-            alpha: 0xFFFF,
-        },
-        Color {
-            red: 0,
-            green: 0xFFFF,
-            blue: 0,
-            alpha: 0xFFFF,
-        },
-        Color {
-            red: 0,
-            green: 0,
-            blue: 0xFFFF,
-            alpha: 0xFFFF,
-        },
-        Color {
-            red: 0xFFFF,
-            green: 0,
-            blue: 0,
-            alpha: 0xFFFF,
-        },
+    let stops = [
+        (
+            double_to_fixed(0.0),
+            Color {
+                red: 0xFFFF,
+                green: 0,
+                blue: 0,
+                alpha: 0xFFFF,
+            },
+        ),
+        (
+            double_to_fixed(1.0 / 3.0),
+            Color {
+                red: 0,
+                green: 0xFFFF,
+                blue: 0,
+                alpha: 0xFFFF,
+            },
+        ),
+        (
+            double_to_fixed(2.0 / 3.0),
+            Color {
+                red: 0,
+                green: 0,
+                blue: 0xFFFF,
+                alpha: 0xFFFF,
+            },
+        ),
+        (
+            double_to_fixed(1.0),
+            Color {
+                red: 0xFFFF,
+                green: 0,
+                blue: 0,
+                alpha: 0xFFFF,
+            },
+        ),
     ];
 
     let center = Pointfix {
@@ -111,7 +117,6 @@ fn main() -> Result {
             y: ((height as i32) << 16) + 2000,
         },
         &stops,
-        &colors,
     )?;
     let radial_gradient = conn.create_radial_gradient(
         center.clone(), 
@@ -119,9 +124,8 @@ fn main() -> Result {
         0,
         200 << 16,
         &stops,
-        &colors,
     )?;
-    let conical_gradient = conn.create_conical_gradient(center, 0, &stops, &colors)?;
+    let conical_gradient = conn.create_conical_gradient(center, 0, &stops)?;
 
     loop {
         let event = conn.wait_for_event()?;
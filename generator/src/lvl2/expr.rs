@@ -56,7 +56,7 @@ pub enum ExpressionItem {
     Value(i64),
     BinaryOp(BinaryOp),
     UnaryOp(UnaryOp),
-    /// ((length as usize) * 4) - index
+    /// ((length as usize) * 4) - index, or `None` if that would underflow
     Remainder,
     SumOf(Box<str>, bool),
     ListExprRef,
@@ -84,6 +84,14 @@ impl Expression {
         }
     }
 
+    /// An expression that always evaluates to the same value.
+    #[inline]
+    pub fn fixed(value: i64) -> Self {
+        Self {
+            postfix: tiny_vec!([ExpressionItem; 1] => ExpressionItem::Value(value)),
+        }
+    }
+
     /// An expression dedicated to the remainder.
     #[inline]
     pub fn remainder() -> Self {
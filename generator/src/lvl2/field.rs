@@ -1,6 +1,6 @@
 // MIT/Apache2 License
 
-use super::{safe_name, Expression, Type};
+use super::{safe_name, Expression, FdField, Type};
 use crate::lvl1::StructureItem as Lvl1StructureItem;
 use heck::{CamelCase, SnakeCase};
 use std::{borrow::Cow, rc::Rc};
@@ -30,6 +30,9 @@ pub struct Field {
     pub ty: Type,
     pub doc: Option<String>,
     pub condition: Option<Rc<UseCondition>>,
+    /// If this field holds an enum or a mask that the protocol sends in a different number of bytes than
+    /// the enum or mask itself takes up, this is the number of bytes it's sent in.
+    pub wire_width: Option<usize>,
 }
 
 /// The list might be a string in disguise!
@@ -54,6 +57,7 @@ pub struct List {
 pub enum StructureItem {
     Field(Field),
     Padding { bytes: usize },
+    AlignPad { align: usize },
     List(List),
     LenSlot { ty: Type, owning_list: String },
 }
@@ -72,7 +76,7 @@ impl StructureItem {
     pub fn from_lvl1(
         lvl1: Lvl1StructureItem,
         resolution: &mut Option<(String, String)>,
-        fds: &mut Vec<String>,
+        fds: &mut Vec<FdField>,
     ) -> TinyVec<[Self; 1]> {
         match lvl1 {
             Lvl1StructureItem::Field(f) => {
@@ -107,10 +111,31 @@ impl StructureItem {
                         ty: Type::BasicType(ty.into()),
                         doc: None,
                         condition: None,
+                        wire_width: None,
                     }
                 })])
             }
-            Lvl1StructureItem::Padding { bytes, .. } => TinyVec::from([Self::Padding { bytes }]),
+            Lvl1StructureItem::Padding {
+                bytes,
+                is_align: false,
+            } => TinyVec::from([Self::Padding { bytes }]),
+            Lvl1StructureItem::Padding {
+                bytes,
+                is_align: true,
+            } => TinyVec::from([Self::AlignPad { align: bytes }]),
+            // a list of file descriptors is sent out-of-band, just like a single one; only its length
+            // field goes over the wire
+            Lvl1StructureItem::List(crate::lvl1::List {
+                ref ty,
+                ref name,
+                ref list_length,
+            }) if ty == "fd" => {
+                fds.push(FdField {
+                    name: name.clone(),
+                    count: list_length.clone().into(),
+                });
+                TinyVec::new()
+            }
             Lvl1StructureItem::List(l) => {
                 TinyVec::from([StructureItem::List({
                     let crate::lvl1::List {
@@ -136,7 +161,10 @@ impl StructureItem {
                 })])
             }
             Lvl1StructureItem::Fd { name } => {
-                fds.push(name);
+                fds.push(FdField {
+                    name,
+                    count: Expression::fixed(1),
+                });
                 TinyVec::new()
             }
             Lvl1StructureItem::ValueParam(v) => {
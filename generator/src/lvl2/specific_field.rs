@@ -50,6 +50,11 @@ pub fn configure_fields(fields: &mut TinyVec<[StructureItem; 6]>, variant: Struc
     // firstly, ensure that all fields are unique
     let mut hashes: Vec<u64> = Vec::with_capacity(fields.len());
     fields.retain(|field| {
+        // pads carry no name, so two pads of the same size are not duplicates
+        if let StructureItem::AlignPad { .. } | StructureItem::Padding { .. } = field {
+            return true;
+        }
+
         let mut hasher: ahash::AHasher = Default::default();
         field.hash(&mut hasher);
         let hash = hasher.finish();
@@ -69,7 +74,10 @@ pub fn configure_fields(fields: &mut TinyVec<[StructureItem; 6]>, variant: Struc
     | StructVariant::Event(false) = variant
     {
         if fields.is_empty()
-            || matches!(fields[0], StructureItem::List(_))
+            || matches!(
+                fields[0],
+                StructureItem::List(_) | StructureItem::AlignPad { .. }
+            )
             || (is_extension && matches!(variant, StructVariant::Request))
             || doesnt_need_pad_pop(&fields[0])
         {
@@ -2,7 +2,7 @@
 
 use super::{
     configure_fields, create_generator, safe_name, set_is_extension, EnumRepr, EnumReprGenerator,
-    Expression, Field, Item as Lvl2Item, Item, List, MaybeString, Struct, StructSpecial,
+    Expression, FdField, Field, Item as Lvl2Item, Item, List, MaybeString, Struct, StructSpecial,
     StructVariant, StructureItem, Type, XidType,
 };
 use crate::lvl1::{
@@ -24,6 +24,8 @@ pub struct Lvl2State {
     nonenum_typenames: BTreeSet<Box<str>>,
     // enums that have not been identified to a specific type yet
     unresolved_enums: BTreeMap<String, EnumReprGenerator>,
+    // the type each enum was identified to, for fields that use it at a different width
+    enum_reprs: BTreeMap<String, Cow<'static, str>>,
     // a list of errors, kept here to help resolve errorcopies
     errors: BTreeMap<Box<str>, Struct>,
     // a list of events, kept here to help resolve eventcopies
@@ -32,6 +34,17 @@ pub struct Lvl2State {
     pub xidtypes: Vec<Box<str>>,
 }
 
+/// The number of bytes an enum representation takes up, if it's an integer.
+#[inline]
+fn enum_repr_width(repr: &str) -> Option<usize> {
+    match repr {
+        "u8" => Some(1),
+        "u16" => Some(2),
+        "u32" => Some(4),
+        _ => None,
+    }
+}
+
 #[inline]
 fn enum_repr_conv(repr: String) -> Cow<'static, str> {
     match repr.as_str() {
@@ -48,6 +61,7 @@ impl Lvl2State {
         Self {
             nonenum_typenames: BTreeSet::new(),
             unresolved_enums: BTreeMap::new(),
+            enum_reprs: BTreeMap::new(),
             errors: BTreeMap::<Box<str>, Struct>::new(),
             events: BTreeMap::new(),
             xidtypes: vec![],
@@ -128,24 +142,31 @@ impl Lvl2State {
         &mut self,
         mut fields: TinyVec<[crate::lvl1::StructureItem; 6]>,
         variant: StructVariant,
-        fds: &mut Vec<String>,
+        fds: &mut Vec<FdField>,
     ) -> (TinyVec<[StructureItem; 6]>, TinyVec<[Lvl2Item; 1]>) {
         let mut side_effect_enums = TinyVec::new();
         let mut align_indices: BTreeMap<usize, usize> = BTreeMap::new();
         let mut index: usize = 0;
+        let mut follows_list = false;
 
+        // align pads that directly follow a list are folded into that list's padding; any other align pad
+        // is kept, and aligns the offset within the structure instead
         fields.retain(|i| {
             if let Lvl1StructureItem::Padding {
                 is_align: true,
                 bytes,
             } = i
             {
-                align_indices.insert(index - 1, *bytes);
-                false
-            } else {
-                index += 1;
-                true
+                if follows_list {
+                    align_indices.insert(index - 1, *bytes);
+                    follows_list = false;
+                    return false;
+                }
             }
+
+            follows_list = matches!(i, Lvl1StructureItem::List(_));
+            index += 1;
+            true
         });
 
         let mut fields = fields
@@ -153,12 +174,29 @@ impl Lvl2State {
             .enumerate()
             .flat_map(|(i, f)| {
                 let mut resolution = None;
-                let res = StructureItem::from_lvl1(f, &mut resolution, fds);
+                let mut res = StructureItem::from_lvl1(f, &mut resolution, fds);
 
                 if let Some((idname, ty)) = resolution {
+                    let repr = enum_repr_conv(ty);
                     if let Some(gen) = self.unresolved_enums.remove(&idname) {
-                        side_effect_enums
-                            .push(Item::Enum((gen)(Type::BasicType(enum_repr_conv(ty)))));
+                        side_effect_enums.push(Item::Enum((gen)(Type::BasicType(repr.clone()))));
+                        self.enum_reprs.insert(idname, repr);
+                    } else if let Some(resolved) = self.enum_reprs.get(&idname) {
+                        // the enum takes the width of the first field that uses it, but later fields may
+                        // send it in a different width
+                        let enum_ty = Type::BasicType(idname.to_camel_case().into());
+                        match (enum_repr_width(&repr), enum_repr_width(resolved)) {
+                            (Some(wire), Some(own)) if wire != own => {
+                                res.iter_mut().for_each(|r| {
+                                    if let StructureItem::Field(f) = r {
+                                        if f.ty == enum_ty {
+                                            f.wire_width = Some(wire);
+                                        }
+                                    }
+                                })
+                            }
+                            _ => (),
+                        }
                     }
                 }
                 res.into_iter().map(move |r| (i, r))
@@ -189,7 +227,7 @@ impl Lvl2State {
     /// Convert a Lvl1 Item to an Lvl2 Item.
     #[inline]
     pub fn convert_item(&mut self, item: Lvl1Item) -> Option<TinyVec<[Item; 1]>> {
-        let mut fds: Vec<String> = vec![];
+        let mut fds: Vec<FdField> = vec![];
         match item {
             // imports and typedefs are directly used in lvl2
             Lvl1Item::Import(i) => Some(TinyVec::from([Item::Import(i)])),
@@ -252,7 +290,7 @@ impl Lvl2State {
 
                 let reply = match reply {
                     Some(XStruct { name, fields, docs }) => {
-                        let mut fds2: Vec<String> = vec![];
+                        let mut fds2: Vec<FdField> = vec![];
                         let (fields2, se2) =
                             self.convert_fields(fields, StructVariant::Reply, &mut fds2);
                         let (brief, desc) = (None, None);
@@ -1,6 +1,6 @@
 // MIT/Apache2 License
 
-use super::StructureItem;
+use super::{Expression, StructureItem};
 
 /// Represents a structure.
 #[derive(Debug, Clone)]
@@ -9,10 +9,19 @@ pub struct Struct {
     pub brief: Option<Box<str>>,
     pub desc: Option<Box<str>>,
     pub fields: Vec<StructureItem>,
-    pub fds: Vec<String>,
+    pub fds: Vec<FdField>,
     pub special: StructSpecial,
 }
 
+/// A file descriptor field. File descriptors are sent out-of-band, so they take up no space in the structure
+/// itself.
+#[derive(Debug, Clone)]
+pub struct FdField {
+    pub name: String,
+    /// The number of file descriptors this field holds.
+    pub count: Expression,
+}
+
 /// Bits of a struct that represent something special.
 #[derive(Debug, Clone)]
 pub enum StructSpecial {
@@ -81,20 +81,29 @@ impl Expression {
                             },
                         }),
                     },
-                    Some(ExpressionItem::Remainder) => syn::Expr::Binary(syn::ExprBinary {
+                    // a length field shorter than what's already been read means the bytes are bad, so
+                    // bail out of from_bytes instead of underflowing
+                    Some(ExpressionItem::Remainder) => syn::Expr::Try(syn::ExprTry {
                         attrs: vec![],
-                        left: Box::new(syn::Expr::Paren(syn::ExprParen {
+                        expr: Box::new(syn::Expr::MethodCall(syn::ExprMethodCall {
                             attrs: vec![],
-                            paren_token: Default::default(),
-                            expr: Box::new(syn::Expr::Binary(syn::ExprBinary {
+                            receiver: Box::new(syn::Expr::Paren(syn::ExprParen {
                                 attrs: vec![],
-                                left: Box::new(cast_to_usize(str_to_exprpath("length"))),
-                                op: syn::BinOp::Mul(Default::default()),
-                                right: Box::new(int_litexpr_int(4)),
+                                paren_token: Default::default(),
+                                expr: Box::new(syn::Expr::Binary(syn::ExprBinary {
+                                    attrs: vec![],
+                                    left: Box::new(cast_to_usize(str_to_exprpath("length"))),
+                                    op: syn::BinOp::Mul(Default::default()),
+                                    right: Box::new(int_litexpr_int(4)),
+                                })),
                             })),
+                            dot_token: Default::default(),
+                            method: syn::Ident::new("checked_sub", Span::call_site()),
+                            turbofish: None,
+                            paren_token: Default::default(),
+                            args: iter::once(str_to_exprpath("index")).collect(),
                         })),
-                        op: syn::BinOp::Sub(Default::default()),
-                        right: Box::new(str_to_exprpath("index")),
+                        question_token: Default::default(),
                     }),
                     Some(ExpressionItem::SumOf(slist, uses_extended)) => {
                         let sexpr = if uses_extended {
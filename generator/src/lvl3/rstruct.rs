@@ -105,10 +105,15 @@ impl RStruct {
             self.fields
                 .iter()
                 .map(|f| match f {
+                    StructureItem::Field(Field {
+                        wire_width: Some(width),
+                        ..
+                    }) => SizeSumPart::Bytes(*width),
                     StructureItem::Field(Field { name, .. }) => {
                         SizeSumPart::SizeofField(name.clone().into_boxed_str())
                     }
                     StructureItem::Padding { bytes } => SizeSumPart::Bytes(*bytes),
+                    StructureItem::AlignPad { align } => SizeSumPart::AlignTo(*align),
                     StructureItem::List(List {
                         name, ty, padding, ..
                     }) => SizeSumPart::ListTimesSize(
@@ -133,7 +138,10 @@ impl RStruct {
         let stmts = iter::once(super::CreateIndexVariable.into())
             .chain(self.fields.iter().flat_map(|f| match f {
                 StructureItem::Field(Field {
-                    name, condition, ..
+                    name,
+                    condition,
+                    wire_width,
+                    ..
                 }) => {
                     let (cond_pass, cond_init) =
                         cond_vars(condition, &mut conds, &mut last_cond_index, true);
@@ -144,6 +152,7 @@ impl RStruct {
                             super::AppendToIndexStatement {
                                 name: name.clone().into_boxed_str(),
                                 condition: cond_pass,
+                                wire_width: *wire_width,
                             }
                             .into(),
                         ))
@@ -152,6 +161,7 @@ impl RStruct {
                 StructureItem::Padding { bytes } => {
                     vec![super::PadIndexStatement(*bytes).into()]
                 }
+                StructureItem::AlignPad { align } => vec![super::AlignIndex(*align).into()],
                 StructureItem::LenSlot { owning_list, ty } => {
                     vec![super::AppendLengthToIndex {
                         owner: owning_list.clone().into_boxed_str(),
@@ -192,6 +202,7 @@ impl RStruct {
                 name,
                 ty,
                 condition,
+                wire_width,
                 ..
             }) => {
                 let (cond_pass, cond_init) =
@@ -205,6 +216,7 @@ impl RStruct {
                             ty: Type::from_lvl2(ty.clone()),
                             use_slice: true,
                             condition: cond_pass,
+                            wire_width: *wire_width,
                         }
                         .into(),
                     ))
@@ -213,6 +225,7 @@ impl RStruct {
             StructureItem::Padding { bytes } => {
                 vec![super::IncrementIndex::Number(*bytes).into()]
             }
+            StructureItem::AlignPad { align } => vec![super::AlignIndex(*align).into()],
             StructureItem::LenSlot { ty, owning_list } => {
                 // create a random name
                 let len_name = format!("len{}", i);
@@ -225,6 +238,7 @@ impl RStruct {
                     ty: Type::from_lvl2(ty.clone()),
                     use_slice: true,
                     condition: None,
+                    wire_width: None,
                 }
                 .into()]
             }
@@ -357,6 +371,10 @@ fn from_lvl2(s: Lvl2Struct, is_reply: bool, ext_name: Option<&str>) -> (RStruct,
     } = s;
     let mut traits = vec![];
 
+    // only the first file descriptor field is ever filled in; see `Asb::fd_getting`
+    let fd_count = fds.get(0).map(|fd| fd.count.clone());
+    let fds: Vec<String> = fds.into_iter().map(|fd| fd.name).collect();
+
     // special-dependent stuff
     let other: Option<RStruct> = if is_reply {
         if !fields.iter().any(|f| {
@@ -399,6 +417,7 @@ fn from_lvl2(s: Lvl2Struct, is_reply: bool, ext_name: Option<&str>) -> (RStruct,
                         Some(ref reply) => !reply.fds.is_empty(),
                         None => false,
                     },
+                    fd_count,
                 ));
                 name = format!("{}Request", name).into_boxed_str();
                 match reply {
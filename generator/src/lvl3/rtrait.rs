@@ -4,8 +4,9 @@ use super::{
     syn_util::{
         int_litexpr_int, item_field, str_to_exprpath, str_to_path, str_to_pathseg, str_to_ty,
     },
-    InputParameter, Method, ParameterUsage, ToSyn, Type,
+    ExprWrapper, InputParameter, Method, ParameterUsage, ToSyn, Type,
 };
+use crate::lvl2::Expression;
 use proc_macro2::Span;
 use std::{borrow::Cow, iter, ops::Deref, rc::Rc};
 
@@ -13,7 +14,7 @@ use std::{borrow::Cow, iter, ops::Deref, rc::Rc};
 pub enum Trait {
     Event(u64),
     Error(u64),
-    Request(u64, Type, Option<String>, bool),
+    Request(u64, Type, Option<String>, bool, Option<Expression>),
     Xid,
     EnumDefault(Box<str>),
     FromXid(Box<str>),
@@ -61,6 +62,44 @@ fn ref_const(val: bool) -> syn::ImplItem {
     })
 }
 
+#[inline]
+fn name_const(name: &str) -> syn::ImplItem {
+    syn::ImplItem::Const(syn::ImplItemConst {
+        attrs: vec![],
+        vis: syn::Visibility::Inherited,
+        defaultness: None,
+        const_token: Default::default(),
+        ident: syn::Ident::new("NAME", Span::call_site()),
+        colon_token: Default::default(),
+        ty: Type::Ref(Box::new(Type::Basic("str".into())), false, Some("'static")).to_syn_ty(),
+        eq_token: Default::default(),
+        expr: syn::Expr::Lit(syn::ExprLit {
+            attrs: vec![],
+            lit: syn::Lit::Str(syn::LitStr::new(name, Span::call_site())),
+        }),
+        semi_token: Default::default(),
+    })
+}
+
+#[inline]
+fn fd_count_method(count: &Expression) -> syn::ImplItem {
+    let mut method = Method::new(
+        "fd_count".into(),
+        Some(ParameterUsage::Ref),
+        vec![],
+        Some(Type::Basic("usize".into())),
+    );
+    let count = match count.fixed_size() {
+        Some(count) => int_litexpr_int(count),
+        None => match count.to_length_expr(true, true) {
+            syn::Expr::Paren(paren) => *paren.expr,
+            count => count,
+        },
+    };
+    method.statements = vec![ExprWrapper(count).into()];
+    method.to_syn_impl_item(true)
+}
+
 #[inline]
 fn extension_const(ext: Option<&str>) -> syn::ImplItem {
     syn::ImplItem::Const(syn::ImplItemConst {
@@ -141,7 +180,7 @@ impl Trait {
                         .into_iter()
                         .collect(),
                     },
-                    Self::Request(_, _, _, _) => str_to_path("Request"),
+                    Self::Request(_, _, _, _, _) => str_to_path("Request"),
                     Self::Xid => str_to_path("XidType"),
                     Self::EnumDefault(_) => str_to_path("Default"),
                     Self::FromXid(ref from) => syn::Path {
@@ -211,22 +250,27 @@ impl Trait {
             items: match self {
                 Self::Event(opcode) => vec![opcode_const(opcode)],
                 Self::Error(opcode) => vec![opcode_const(opcode)],
-                Self::Request(opcode, reply_name, ext_name, expects_fds) => vec![
-                    opcode_const(opcode),
-                    extension_const(ext_name.as_deref()),
-                    ref_const(expects_fds),
-                    syn::ImplItem::Type(syn::ImplItemType {
-                        attrs: vec![],
-                        vis: syn::Visibility::Inherited,
-                        defaultness: None,
-                        type_token: Default::default(),
-                        ident: syn::Ident::new("Reply", Span::call_site()),
-                        generics: Default::default(),
-                        eq_token: Default::default(),
-                        ty: reply_name.to_syn_ty(),
-                        semi_token: Default::default(),
-                    }),
-                ],
+                Self::Request(opcode, reply_name, ext_name, expects_fds, fd_count) => {
+                    let mut items = vec![
+                        opcode_const(opcode),
+                        extension_const(ext_name.as_deref()),
+                        ref_const(expects_fds),
+                        name_const(tyname.strip_suffix("Request").unwrap_or(tyname)),
+                        syn::ImplItem::Type(syn::ImplItemType {
+                            attrs: vec![],
+                            vis: syn::Visibility::Inherited,
+                            defaultness: None,
+                            type_token: Default::default(),
+                            ident: syn::Ident::new("Reply", Span::call_site()),
+                            generics: Default::default(),
+                            eq_token: Default::default(),
+                            ty: reply_name.to_syn_ty(),
+                            semi_token: Default::default(),
+                        }),
+                    ];
+                    items.extend(fd_count.map(|count| fd_count_method(&count)));
+                    items
+                }
                 Self::Xid => vec![
                     {
                         let mut method = Method::new(
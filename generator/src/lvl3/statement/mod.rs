@@ -53,26 +53,47 @@ impl Statement for ReturnIndexStatement {
     }
 }
 
-/// A statement to call `index += self.[0].as_bytes(&mut bytes[index..]);`
+/// A statement to call `index += self.[0].as_bytes(&mut bytes[index..]);`, or
+/// `index += as_bytes_with_width(&self.[0], [1], &mut bytes[index..]);` if the field is sent in a different
+/// width than its type's.
 #[derive(Clone, Debug)]
 pub struct AppendToIndexStatement {
     pub name: Box<str>,
     pub condition: Option<(Rc<UseCondition>, Box<str>)>,
+    pub wire_width: Option<usize>,
 }
 
 impl Statement for AppendToIndexStatement {
     #[inline]
     fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        let field = item_field(str_to_exprpath("self"), &self.name);
         let inn = syn::Stmt::Semi(
-            index_plus_equal(syn::Expr::Call(syn::ExprCall {
-                attrs: vec![],
-                func: Box::new(item_field(
-                    item_field(str_to_exprpath("self"), &self.name),
-                    "as_bytes",
-                )),
-                paren_token: Default::default(),
-                args: iter::once(bytes_slice(true)).collect(),
-            })),
+            index_plus_equal(match self.wire_width {
+                None => syn::Expr::Call(syn::ExprCall {
+                    attrs: vec![],
+                    func: Box::new(item_field(field, "as_bytes")),
+                    paren_token: Default::default(),
+                    args: iter::once(bytes_slice(true)).collect(),
+                }),
+                Some(width) => syn::Expr::Call(syn::ExprCall {
+                    attrs: vec![],
+                    func: Box::new(str_to_exprpath("as_bytes_with_width")),
+                    paren_token: Default::default(),
+                    args: vec![
+                        syn::Expr::Reference(syn::ExprReference {
+                            attrs: vec![],
+                            and_token: Default::default(),
+                            raw: Default::default(),
+                            mutability: None,
+                            expr: Box::new(field),
+                        }),
+                        int_litexpr_int(width),
+                        bytes_slice(true),
+                    ]
+                    .into_iter()
+                    .collect(),
+                }),
+            }),
             Default::default(),
         );
         vec![match self.condition {
@@ -174,6 +195,8 @@ pub struct LoadStatementVariable {
     pub ty: Type,
     pub use_slice: bool,
     pub condition: Option<(Rc<UseCondition>, Box<str>)>,
+    /// Read the value with `from_bytes_with_width` instead, from a field this many bytes wide.
+    pub wire_width: Option<usize>,
 }
 
 impl Statement for LoadStatementVariable {
@@ -214,30 +237,39 @@ impl Statement for LoadStatementVariable {
                     ),
                 }),
                 expr: Box::new({
-                    let a = syn::Expr::Call(syn::ExprCall {
-                        attrs: vec![],
-                        func: Box::new(syn::Expr::Path(syn::ExprPath {
+                    let slice = if self.use_slice {
+                        bytes_slice(false)
+                    } else {
+                        str_to_exprpath("bytes")
+                    };
+                    let a = match self.wire_width {
+                        None => syn::Expr::Call(syn::ExprCall {
                             attrs: vec![],
-                            qself: Some(syn::QSelf {
-                                lt_token: Default::default(),
-                                gt_token: Default::default(),
-                                ty: Box::new(self.ty.to_syn_ty()),
-                                as_token: None,
-                                position: 0,
-                            }),
-                            path: syn::Path {
-                                leading_colon: Some(Default::default()),
-                                segments: iter::once(str_to_pathseg("from_bytes")).collect(),
-                            },
-                        })),
-                        paren_token: Default::default(),
-                        args: iter::once(if self.use_slice {
-                            bytes_slice(false)
-                        } else {
-                            str_to_exprpath("bytes")
-                        })
-                        .collect(),
-                    });
+                            func: Box::new(syn::Expr::Path(syn::ExprPath {
+                                attrs: vec![],
+                                qself: Some(syn::QSelf {
+                                    lt_token: Default::default(),
+                                    gt_token: Default::default(),
+                                    ty: Box::new(self.ty.to_syn_ty()),
+                                    as_token: None,
+                                    position: 0,
+                                }),
+                                path: syn::Path {
+                                    leading_colon: Some(Default::default()),
+                                    segments: iter::once(str_to_pathseg("from_bytes")).collect(),
+                                },
+                            })),
+                            paren_token: Default::default(),
+                            args: iter::once(slice).collect(),
+                        }),
+                        // the type is inferred from the binding
+                        Some(width) => syn::Expr::Call(syn::ExprCall {
+                            attrs: vec![],
+                            func: Box::new(str_to_exprpath("from_bytes_with_width")),
+                            paren_token: Default::default(),
+                            args: vec![slice, int_litexpr_int(width)].into_iter().collect(),
+                        }),
+                    };
 
                     syn::Expr::Try(syn::ExprTry {
                         attrs: vec![],
@@ -308,8 +340,32 @@ impl Statement for LoadStatementVariable {
     }
 }
 
+/// The rest of the byte buffer, starting at "index". When reading, this is `bytes.get(index..)?`, so that a
+/// truncated buffer makes `from_bytes` return `None` instead of panicking.
 #[inline]
 pub fn bytes_slice(is_mut: bool) -> syn::Expr {
+    if !is_mut {
+        return syn::Expr::Try(syn::ExprTry {
+            attrs: vec![],
+            expr: Box::new(syn::Expr::MethodCall(syn::ExprMethodCall {
+                attrs: vec![],
+                receiver: Box::new(str_to_exprpath("bytes")),
+                dot_token: Default::default(),
+                method: syn::Ident::new("get", Span::call_site()),
+                turbofish: None,
+                paren_token: Default::default(),
+                args: iter::once(syn::Expr::Range(syn::ExprRange {
+                    attrs: vec![],
+                    from: Some(Box::new(str_to_exprpath("index"))),
+                    limits: syn::RangeLimits::HalfOpen(Default::default()),
+                    to: None,
+                }))
+                .collect(),
+            })),
+            question_token: Default::default(),
+        });
+    }
+
     syn::Expr::Reference(syn::ExprReference {
         attrs: vec![],
         and_token: Default::default(),
@@ -469,6 +525,7 @@ impl Statement for MatchBytesToEnum {
             ty: Type::Basic(self.underlying.clone()),
             use_slice: false,
             condition: None,
+            wire_width: None,
         }
         .to_syn_statement();
 
@@ -593,6 +650,8 @@ pub enum SizeSumPart {
     SizeofField(Box<str>),
     SizeofType(Type),
     ListTimesSize(Box<str>, MaybeString, Option<usize>),
+    /// Padding up to the given alignment. This depends on the sum so far, so it is handled by `SumOfSizes`.
+    AlignTo(usize),
 }
 
 impl SizeSumPart {
@@ -600,6 +659,8 @@ impl SizeSumPart {
     pub fn to_expr(&self) -> syn::Expr {
         match self {
             Self::Bytes(bytes) => int_litexpr_int(bytes),
+            // an offset of zero is always aligned
+            Self::AlignTo(_) => int_litexpr_int(0),
             Self::SizeofType(ty) => syn::Expr::Call(syn::ExprCall {
                 attrs: vec![],
                 func: Box::new(syn::Expr::Path(syn::ExprPath {
@@ -763,12 +824,47 @@ impl Statement for SumOfSizes {
                 None => return vec![],
             };
             syn::Stmt::Expr(self.0.iter().skip(1).fold(first, |sum, current| {
-                syn::Expr::Binary(syn::ExprBinary {
-                    attrs: vec![],
-                    left: Box::new(sum),
-                    op: syn::BinOp::Add(Default::default()),
-                    right: Box::new(current.to_expr()),
-                })
+                match current {
+                    // bind the size so far, then add the padding needed to align it; the block is wrapped in
+                    // parentheses so it still parses as an expression when more sizes are added to it
+                    SizeSumPart::AlignTo(align) => syn::Expr::Paren(syn::ExprParen {
+                        attrs: vec![],
+                        paren_token: Default::default(),
+                        expr: Box::new(syn::Expr::Block(syn::ExprBlock {
+                            attrs: vec![],
+                            label: None,
+                            block: syn::Block {
+                                brace_token: Default::default(),
+                                stmts: vec![
+                                    syn::Stmt::Semi(
+                                        let_statement(
+                                            "size",
+                                            Type::Basic("usize".into()),
+                                            sum,
+                                            false,
+                                        ),
+                                        Default::default(),
+                                    ),
+                                    syn::Stmt::Expr(syn::Expr::Binary(syn::ExprBinary {
+                                        attrs: vec![],
+                                        left: Box::new(str_to_exprpath("size")),
+                                        op: syn::BinOp::Add(Default::default()),
+                                        right: Box::new(align_pad_expr(
+                                            str_to_exprpath("size"),
+                                            *align,
+                                        )),
+                                    })),
+                                ],
+                            },
+                        })),
+                    }),
+                    current => syn::Expr::Binary(syn::ExprBinary {
+                        attrs: vec![],
+                        left: Box::new(sum),
+                        op: syn::BinOp::Add(Default::default()),
+                        right: Box::new(current.to_expr()),
+                    }),
+                }
             }))
         }]
     }
@@ -979,6 +1075,7 @@ impl Statement for ForwardFromBytes {
             ty: self.inner_ty.clone(),
             use_slice: false,
             condition: None,
+            wire_width: None,
         }
         .to_syn_statement();
 
@@ -1123,6 +1220,7 @@ pub enum SumStatement {
     ForwardFromBytes(ForwardFromBytes),
     CreateAlignToAndBlockLen(CreateAlignToAndBlockLen),
     SetAlignAndAddPadding(SetAlignAndAddPadding),
+    AlignIndex(AlignIndex),
     FromBytesList(FromBytesList),
     AsBytesList(AsBytesList),
     AppendLengthToIndex(AppendLengthToIndex),
@@ -1167,6 +1265,7 @@ sst_from_impl! { ForwardAsBytes, ForwardAsBytes }
 sst_from_impl! { ForwardFromBytes, ForwardFromBytes }
 sst_from_impl! { CreateAlignToAndBlockLen, CreateAlignToAndBlockLen }
 sst_from_impl! { SetAlignAndAddPadding, SetAlignAndAddPadding }
+sst_from_impl! { AlignIndex, AlignIndex }
 sst_from_impl! { FromBytesList, FromBytesList }
 sst_from_impl! { AsBytesList, AsBytesList }
 sst_from_impl! { AppendLengthToIndex, AppendLengthToIndex }
@@ -1203,6 +1302,7 @@ impl Statement for SumStatement {
             Self::ForwardFromBytes(ffb) => ffb.to_syn_statement(),
             Self::CreateAlignToAndBlockLen(c) => c.to_syn_statement(),
             Self::SetAlignAndAddPadding(saaap) => saaap.to_syn_statement(),
+            Self::AlignIndex(ai) => ai.to_syn_statement(),
             Self::FromBytesList(fbl) => fbl.to_syn_statement(),
             Self::AsBytesList(asl) => asl.to_syn_statement(),
             Self::AppendLengthToIndex(ai) => ai.to_syn_statement(),
@@ -101,3 +101,28 @@ impl Statement for SetAlignAndAddPadding {
         )]
     }
 }
+
+/// Align the index to the given number of bytes, relative to the start of the structure.
+#[derive(Debug, Clone, Copy)]
+pub struct AlignIndex(pub usize);
+
+impl Statement for AlignIndex {
+    #[inline]
+    fn to_syn_statement(&self) -> Vec<syn::Stmt> {
+        vec![syn::Stmt::Semi(
+            index_plus_equal(align_pad_expr(str_to_exprpath("index"), self.0)),
+            Default::default(),
+        )]
+    }
+}
+
+/// `buffer_pad(offset, align)`, the number of bytes needed to align `offset`.
+#[inline]
+pub fn align_pad_expr(offset: syn::Expr, align: usize) -> syn::Expr {
+    syn::Expr::Call(syn::ExprCall {
+        attrs: vec![],
+        func: Box::new(str_to_exprpath("buffer_pad")),
+        paren_token: Default::default(),
+        args: vec![offset, int_litexpr_int(align)].into_iter().collect(),
+    })
+}
@@ -36,7 +36,27 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     // open the XML reader
     let mut reader = Reader::from_reader(file);
+    let output = generate(&mut reader);
 
+    write!(
+        outfile,
+        "// This file was automatically generated.
+// It is considered to be licensed under the MIT and Apache 2.0 licenses.
+
+#![allow(warnings)]
+
+use super::prelude::*;
+
+{}",
+        output
+    )?;
+
+    Ok(())
+}
+
+/// Run an XML protocol description through every stage of the generator, returning the generated Rust code.
+#[inline]
+fn generate<B: BufRead>(reader: &mut Reader<B>) -> String {
     // Stage 1: Read from Level 0 representation (XML) into Level 1 representation. Result is a
     //          vector of Level 1 items.
     let mut buf = vec![];
@@ -85,26 +105,50 @@ fn main() -> Result<(), Box<dyn Error>> {
         items: lvl4_items,
     };
 
-    // Stage 5: Convert to string and save to file
+    // Stage 5: Convert to string
     let tokens = lvl4_file.into_token_stream();
     let output_pre_regex = format!("{}", tokens);
     let re = Regex::new(r"\bBool\b").unwrap();
-    let output = re.replace_all(&output_pre_regex, "bool");
-    write!(
-        outfile,
-        "// This file was automatically generated.
-// It is considered to be licensed under the MIT and Apache 2.0 licenses.
-
-#![allow(warnings)]
-
-use super::prelude::*;
-
-{}",
-        output
-    )?;
-
-    Ok(())
+    re.replace_all(&output_pre_regex, "bool").into_owned()
 }
 
 #[inline]
 pub fn any_field_length(_fields: &[lvl2::StructureItem]) {}
+
+#[cfg(test)]
+mod tests {
+    use super::generate;
+    use quick_xml::Reader;
+    use quote::ToTokens;
+    use regex::Regex;
+
+    #[test]
+    fn align_pad_after_odd_sized_field() {
+        let xml = r#"<xcb header="aligntest">
+  <struct name="Aligned">
+    <field type="CARD8" name="a" />
+    <pad align="8" />
+    <field type="CARD32" name="b" />
+  </struct>
+</xcb>"#;
+        let mut reader = Reader::from_str(xml);
+        let output = syn::parse_file(&generate(&mut reader)).unwrap();
+
+        // the output is checked in as `src/auto/aligntest.rs`, where the crate's own tests compile it and
+        // round-trip it through `as_bytes` and `from_bytes`, so it has to stay in step with the generator
+        let mut checked_in = syn::parse_file(include_str!("../../src/auto/aligntest.rs")).unwrap();
+        checked_in.attrs.clear();
+        // the checked in copy starts with `use super::prelude::*;`, which the generator leaves out
+        checked_in.items.remove(0);
+
+        // rustfmt moves trailing commas around in the checked in copy, and neither they nor the spacing
+        // between tokens change what it means
+        let trailing_commas = Regex::new(r",\s*([)\]}])").unwrap();
+        let tokens = |file: syn::File| {
+            let tokens = file.into_token_stream().to_string();
+            let tokens = trailing_commas.replace_all(&tokens, "$1");
+            tokens.split_whitespace().collect::<String>()
+        };
+        assert_eq!(tokens(output), tokens(checked_in));
+    }
+}
@@ -82,6 +82,10 @@ impl AsByteSequence for Action {
 
     #[inline]
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        // every action takes up the same space, even if its fields end before that
+        if bytes.len() < ACTION_SIZE {
+            return None;
+        }
         let ty = SaType::from_bytes(bytes)?.0;
 
         let this = match ty {
@@ -111,3 +115,26 @@ impl AsByteSequence for Action {
         Some((this, ACTION_SIZE))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Action, ACTION_SIZE};
+    use crate::auto::AsByteSequence;
+
+    #[test]
+    fn truncated_actions_are_refused() {
+        // try every action type, with random contents
+        for ty in 0..=u8::MAX {
+            let mut bytes = [0; ACTION_SIZE];
+            bytes.iter_mut().for_each(|b| *b = fastrand::u8(..));
+            bytes[0] = ty;
+
+            for len in 0..ACTION_SIZE {
+                assert!(Action::from_bytes(&bytes[..len]).is_none());
+            }
+            if let Some((_, sz)) = Action::from_bytes(&bytes) {
+                assert_eq!(sz, ACTION_SIZE);
+            }
+        }
+    }
+}
@@ -3,9 +3,14 @@
 use alloc::{string::String, vec::Vec};
 
 #[cfg(feature = "std")]
-use alloc::vec;
+use alloc::{string::ToString, vec};
 #[cfg(feature = "std")]
-use std::{env, fs::File, io::Read};
+use std::{
+    env,
+    fs::File,
+    io::Read,
+    net::{IpAddr, Ipv4Addr},
+};
 
 #[cfg(feature = "async")]
 use blocking::{unblock, Unblock};
@@ -23,6 +28,98 @@ pub struct AuthInfo {
     pub number: Vec<u8>,
 }
 
+/// Address family of an entry whose address is an IPv4 address.
+#[cfg(feature = "std")]
+const FAMILY_INTERNET: u16 = 0;
+/// Address family of an entry whose address is an IPv6 address.
+#[cfg(feature = "std")]
+const FAMILY_INTERNET6: u16 = 6;
+/// Address family of an entry for connections made on the machine itself. The address is the machine's
+/// hostname, not `localhost` or the socket's path.
+#[cfg(feature = "std")]
+const FAMILY_LOCAL: u16 = 256;
+/// Address family of an entry that matches any address.
+#[cfg(feature = "std")]
+const FAMILY_WILD: u16 = 65535;
+
+/// The server that an entry in the `XAUTHORITY` file is picked for.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct AuthTarget {
+    family: u16,
+    address: Vec<u8>,
+    display: u16,
+}
+
+#[cfg(feature = "std")]
+impl AuthTarget {
+    /// A display on this machine, reached over a Unix socket.
+    #[inline]
+    pub(crate) fn local(display: u16) -> Self {
+        Self::local_with_hostname(hostname().unwrap_or_default(), display)
+    }
+
+    #[inline]
+    fn local_with_hostname(hostname: Vec<u8>, display: u16) -> Self {
+        Self {
+            family: FAMILY_LOCAL,
+            address: hostname,
+            display,
+        }
+    }
+
+    /// A display reached over TCP at the given address. Servers on the loopback interface write their
+    /// entries under the machine's hostname, the same as for Unix sockets.
+    #[inline]
+    pub(crate) fn ip(ip: IpAddr, display: u16) -> Self {
+        // IPv4 peers of a dual-stack socket show up as IPv4-mapped addresses
+        let ip = match ip {
+            IpAddr::V6(ip) if ip.segments()[..6] == [0, 0, 0, 0, 0, 0xFFFF] => {
+                let octets = ip.octets();
+                IpAddr::V4(Ipv4Addr::new(
+                    octets[12], octets[13], octets[14], octets[15],
+                ))
+            }
+            ip => ip,
+        };
+
+        if ip.is_loopback() {
+            return Self::local(display);
+        }
+
+        let (family, address) = match ip {
+            IpAddr::V4(ip) => (FAMILY_INTERNET, ip.octets().to_vec()),
+            IpAddr::V6(ip) => (FAMILY_INTERNET6, ip.octets().to_vec()),
+        };
+
+        Self {
+            family,
+            address,
+            display,
+        }
+    }
+}
+
+/// The machine's hostname, which entries for local connections are written under.
+#[cfg(all(feature = "std", unix))]
+#[inline]
+fn hostname() -> Option<Vec<u8>> {
+    let mut buffer = [0; 256];
+    match nix::unistd::gethostname(&mut buffer) {
+        Ok(name) => Some(name.to_bytes().to_vec()),
+        Err(e) => {
+            log::warn!("Unable to get the hostname: {}", e);
+            None
+        }
+    }
+}
+
+#[cfg(all(feature = "std", not(unix)))]
+#[inline]
+fn hostname() -> Option<Vec<u8>> {
+    None
+}
+
 /// Helper: from a set of bytes, deserialize a "counted string"
 #[cfg(feature = "std")]
 #[inline]
@@ -137,34 +234,69 @@ impl AuthInfo {
         Self::from_stream_async(&mut file).await
     }
 
-    /// Helper function to "get" an authorization info or return the default.
-    /// TODO: match up display ID
+    /// Helper function to "get" an authorization info, or an empty one if there isn't any. Many servers
+    /// accept connections over local sockets without authorization, so it's still worth trying to connect.
+    /// This is used when the address of the server isn't known, so it just takes the first entry.
     #[inline]
     pub(crate) fn get() -> Self {
-        if let Some(mut v) = Self::from_xauthority() {
-            if v.is_empty() {
-                Default::default()
-            } else {
-                v.remove(0)
-            }
+        Self::first_or_empty(Self::from_xauthority())
+    }
+
+    #[cfg(feature = "async")]
+    #[inline]
+    pub(crate) async fn get_async() -> Self {
+        Self::first_or_empty(Self::from_xauthority_async().await)
+    }
+
+    /// Helper function to get the authorization info for the given server, or an empty one if none of the
+    /// entries are for it.
+    #[inline]
+    pub(crate) fn get_for(target: &AuthTarget) -> Self {
+        Self::matching_or_empty(Self::from_xauthority(), target)
+    }
+
+    #[cfg(feature = "async")]
+    #[inline]
+    pub(crate) async fn get_for_async(target: AuthTarget) -> Self {
+        Self::matching_or_empty(Self::from_xauthority_async().await, &target)
+    }
+
+    /// Whether or not this entry is meant for the given server. Entries without a display number are meant
+    /// for every display on their host.
+    #[inline]
+    fn matches(&self, target: &AuthTarget, display: &[u8]) -> bool {
+        let address_matches = self.family == FAMILY_WILD
+            || (self.family == target.family && self.address == target.address);
+        address_matches && (self.number.is_empty() || self.number == display)
+    }
+
+    #[inline]
+    fn matching_or_empty(entries: Option<Vec<Self>>, target: &AuthTarget) -> Self {
+        let display = target.display.to_string();
+        let entry = entries.and_then(|entries| {
+            entries
+                .into_iter()
+                .find(|entry| entry.matches(target, display.as_bytes()))
+        });
+
+        if let Some(entry) = entry {
+            entry
         } else {
-            log::error!("Failed to get AuthInfo from XAUTHORITY, using empty auth info");
+            log::debug!(
+                "No entries in XAUTHORITY match the display, connecting without authorization"
+            );
             Default::default()
         }
     }
 
-    #[cfg(feature = "async")]
     #[inline]
-    pub(crate) async fn get_async() -> Self {
-        match Self::from_xauthority_async().await {
-            Some(mut v) => {
-                if v.is_empty() {
-                    Default::default()
-                } else {
-                    v.remove(0)
-                }
+    fn first_or_empty(entries: Option<Vec<Self>>) -> Self {
+        match entries {
+            Some(mut entries) if !entries.is_empty() => entries.remove(0),
+            _ => {
+                log::debug!("No entries found in XAUTHORITY, connecting without authorization");
+                Default::default()
             }
-            None => Default::default(),
         }
     }
 }
@@ -177,3 +309,50 @@ impl AuthInfo {
         Default::default()
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{AuthInfo, AuthTarget, FAMILY_INTERNET, FAMILY_INTERNET6, FAMILY_LOCAL};
+    use alloc::vec::Vec;
+    use std::net::Ipv6Addr;
+
+    fn entry(file: &mut Vec<u8>, family: u16, address: &[u8], number: &[u8], data: &[u8]) {
+        file.extend_from_slice(&family.to_be_bytes());
+        for field in &[address, number, b"MIT-MAGIC-COOKIE-1", data] {
+            file.extend_from_slice(&(field.len() as u16).to_be_bytes());
+            file.extend_from_slice(field);
+        }
+    }
+
+    fn pick(file: &[u8], target: &AuthTarget) -> Vec<u8> {
+        AuthInfo::matching_or_empty(AuthInfo::many_from_buffer(file), target).data
+    }
+
+    #[test]
+    fn local_entries_match_the_hostname() {
+        let mut file = Vec::new();
+        entry(&mut file, FAMILY_INTERNET, &[127, 0, 0, 1], b"0", b"inet");
+        entry(&mut file, FAMILY_LOCAL, b"otherhost", b"0", b"other");
+        entry(&mut file, FAMILY_LOCAL, b"myhost", b"1", b"one");
+        entry(&mut file, FAMILY_LOCAL, b"myhost", b"0", b"zero");
+
+        let target = |display| AuthTarget::local_with_hostname(b"myhost".to_vec(), display);
+        assert_eq!(pick(&file, &target(0)), b"zero");
+        assert_eq!(pick(&file, &target(1)), b"one");
+        assert!(pick(&file, &target(2)).is_empty());
+    }
+
+    #[test]
+    fn internet_entries_match_the_address() {
+        let v6: Ipv6Addr = "2001:db8::7".parse().unwrap();
+        let mut file = Vec::new();
+        entry(&mut file, FAMILY_INTERNET, &[10, 0, 0, 2], b"0", b"v4");
+        entry(&mut file, FAMILY_INTERNET6, &v6.octets(), b"", b"v6");
+
+        let target = |ip: &str| AuthTarget::ip(ip.parse().unwrap(), 0);
+        assert_eq!(pick(&file, &target("10.0.0.2")), b"v4");
+        assert_eq!(pick(&file, &target("::ffff:10.0.0.2")), b"v4");
+        assert_eq!(pick(&file, &target("2001:db8::7")), b"v6");
+        assert!(pick(&file, &target("10.0.0.3")).is_empty());
+    }
+}
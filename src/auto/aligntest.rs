@@ -0,0 +1,41 @@
+// This file was automatically generated.
+// It is considered to be licensed under the MIT and Apache 2.0 licenses.
+
+#![allow(warnings)]
+
+use super::prelude::*;
+
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Aligned {
+    pub a: Card8,
+    pub b: Card32,
+}
+impl Aligned {}
+impl AsByteSequence for Aligned {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.a.as_bytes(&mut bytes[index..]);
+        index += buffer_pad(index, 8);
+        index += self.b.as_bytes(&mut bytes[index..]);
+        index
+    }
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let mut index: usize = 0;
+        log::trace!("Deserializing Aligned from byte buffer");
+        let (a, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        index += buffer_pad(index, 8);
+        let (b, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        Some((Aligned { a: a, b: b }, index))
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        ({
+            let size: usize = self.a.size();
+            size + buffer_pad(size, 8)
+        }) + self.b.size()
+    }
+}
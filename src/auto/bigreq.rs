@@ -24,10 +24,10 @@ impl AsByteSequence for EnableRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing EnableRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             EnableRequest {
@@ -46,6 +46,7 @@ impl Request for EnableRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("BIG-REQUESTS");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Enable";
     type Reply = EnableReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -71,14 +72,15 @@ impl AsByteSequence for EnableReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing EnableReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (maximum_request_length, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (maximum_request_length, sz): (Card32, usize) =
+            <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             EnableReply {
@@ -30,14 +30,16 @@ impl AsByteSequence for QueryVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (client_major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (client_major_version, sz): (Card32, usize) =
+            <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (client_minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (client_minor_version, sz): (Card32, usize) =
+            <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionRequest {
@@ -62,6 +64,7 @@ impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("Composite");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -90,16 +93,16 @@ impl AsByteSequence for QueryVersionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         Some((
@@ -148,14 +151,14 @@ impl AsByteSequence for RedirectWindowRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing RedirectWindowRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (update, sz): (Redirect, usize) = <Redirect>::from_bytes(&bytes[index..])?;
+        let (update, sz): (Redirect, usize) = <Redirect>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -177,6 +180,7 @@ impl Request for RedirectWindowRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = Some("Composite");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "RedirectWindow";
     type Reply = ();
 }
 #[repr(u8)]
@@ -234,14 +238,14 @@ impl AsByteSequence for RedirectSubwindowsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing RedirectSubwindowsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (update, sz): (Redirect, usize) = <Redirect>::from_bytes(&bytes[index..])?;
+        let (update, sz): (Redirect, usize) = <Redirect>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -263,6 +267,7 @@ impl Request for RedirectSubwindowsRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = Some("Composite");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "RedirectSubwindows";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -289,14 +294,14 @@ impl AsByteSequence for UnredirectWindowRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing UnredirectWindowRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (update, sz): (Redirect, usize) = <Redirect>::from_bytes(&bytes[index..])?;
+        let (update, sz): (Redirect, usize) = <Redirect>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -318,6 +323,7 @@ impl Request for UnredirectWindowRequest {
     const OPCODE: u8 = 3;
     const EXTENSION: Option<&'static str> = Some("Composite");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UnredirectWindow";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -344,14 +350,14 @@ impl AsByteSequence for UnredirectSubwindowsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing UnredirectSubwindowsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (update, sz): (Redirect, usize) = <Redirect>::from_bytes(&bytes[index..])?;
+        let (update, sz): (Redirect, usize) = <Redirect>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -373,6 +379,7 @@ impl Request for UnredirectSubwindowsRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = Some("Composite");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UnredirectSubwindows";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -398,14 +405,14 @@ impl AsByteSequence for CreateRegionFromBorderClipRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateRegionFromBorderClipRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CreateRegionFromBorderClipRequest {
@@ -426,6 +433,7 @@ impl Request for CreateRegionFromBorderClipRequest {
     const OPCODE: u8 = 5;
     const EXTENSION: Option<&'static str> = Some("Composite");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateRegionFromBorderClip";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -451,14 +459,14 @@ impl AsByteSequence for NameWindowPixmapRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing NameWindowPixmapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             NameWindowPixmapRequest {
@@ -479,6 +487,7 @@ impl Request for NameWindowPixmapRequest {
     const OPCODE: u8 = 6;
     const EXTENSION: Option<&'static str> = Some("Composite");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "NameWindowPixmap";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -502,12 +511,12 @@ impl AsByteSequence for GetOverlayWindowRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetOverlayWindowRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetOverlayWindowRequest {
@@ -527,6 +536,7 @@ impl Request for GetOverlayWindowRequest {
     const OPCODE: u8 = 7;
     const EXTENSION: Option<&'static str> = Some("Composite");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetOverlayWindow";
     type Reply = GetOverlayWindowReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -553,14 +563,14 @@ impl AsByteSequence for GetOverlayWindowReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetOverlayWindowReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (overlay_win, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (overlay_win, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         Some((
@@ -604,12 +614,12 @@ impl AsByteSequence for ReleaseOverlayWindowRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ReleaseOverlayWindowRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ReleaseOverlayWindowRequest {
@@ -629,5 +639,6 @@ impl Request for ReleaseOverlayWindowRequest {
     const OPCODE: u8 = 8;
     const EXTENSION: Option<&'static str> = Some("Composite");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ReleaseOverlayWindow";
     type Reply = ();
 }
@@ -51,14 +51,16 @@ impl AsByteSequence for QueryVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (client_major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (client_major_version, sz): (Card32, usize) =
+            <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (client_minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (client_minor_version, sz): (Card32, usize) =
+            <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionRequest {
@@ -83,6 +85,7 @@ impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DAMAGE");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -111,16 +114,16 @@ impl AsByteSequence for QueryVersionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         Some((
@@ -171,16 +174,16 @@ impl AsByteSequence for CreateRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (damage, sz): (Damage, usize) = <Damage>::from_bytes(&bytes[index..])?;
+        let (damage, sz): (Damage, usize) = <Damage>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (level, sz): (ReportLevel, usize) = <ReportLevel>::from_bytes(&bytes[index..])?;
+        let (level, sz): (ReportLevel, usize) = <ReportLevel>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -209,6 +212,7 @@ impl Request for CreateRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = Some("DAMAGE");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Create";
     type Reply = ();
 }
 #[repr(u8)]
@@ -267,12 +271,12 @@ impl AsByteSequence for DestroyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (damage, sz): (Damage, usize) = <Damage>::from_bytes(&bytes[index..])?;
+        let (damage, sz): (Damage, usize) = <Damage>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DestroyRequest {
@@ -292,6 +296,7 @@ impl Request for DestroyRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = Some("DAMAGE");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Destroy";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -319,16 +324,16 @@ impl AsByteSequence for SubtractRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SubtractRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (damage, sz): (Damage, usize) = <Damage>::from_bytes(&bytes[index..])?;
+        let (damage, sz): (Damage, usize) = <Damage>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (repair, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (repair, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (parts, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (parts, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SubtractRequest {
@@ -355,6 +360,7 @@ impl Request for SubtractRequest {
     const OPCODE: u8 = 3;
     const EXTENSION: Option<&'static str> = Some("DAMAGE");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Subtract";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -380,14 +386,14 @@ impl AsByteSequence for AddRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AddRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             AddRequest {
@@ -408,6 +414,7 @@ impl Request for AddRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = Some("DAMAGE");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Add";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -440,21 +447,21 @@ impl AsByteSequence for NotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing NotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (level, sz): (ReportLevel, usize) = <ReportLevel>::from_bytes(&bytes[index..])?;
+        let (level, sz): (ReportLevel, usize) = <ReportLevel>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (damage, sz): (Damage, usize) = <Damage>::from_bytes(&bytes[index..])?;
+        let (damage, sz): (Damage, usize) = <Damage>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (area, sz): (Rectangle, usize) = <Rectangle>::from_bytes(&bytes[index..])?;
+        let (area, sz): (Rectangle, usize) = <Rectangle>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (geometry, sz): (Rectangle, usize) = <Rectangle>::from_bytes(&bytes[index..])?;
+        let (geometry, sz): (Rectangle, usize) = <Rectangle>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             NotifyEvent {
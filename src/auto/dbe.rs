@@ -0,0 +1,882 @@
+// This file was automatically generated.
+// It is considered to be licensed under the MIT and Apache 2.0 licenses.
+
+#![allow(warnings)]
+
+use super::prelude::*;
+
+use super::xproto::*;
+#[repr(transparent)]
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BackBuffer {
+    pub xid: XID,
+}
+impl BackBuffer {
+    #[inline]
+    pub const fn const_from_xid(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+impl XidType for BackBuffer {
+    #[inline]
+    fn xid(&self) -> XID {
+        self.xid
+    }
+    #[inline]
+    fn from_xid(xid: XID) -> Self {
+        Self { xid: xid }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct SwapInfo {
+    pub window: Window,
+    pub swap_action: SwapAction,
+}
+impl SwapInfo {}
+impl AsByteSequence for SwapInfo {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.window.as_bytes(&mut bytes[index..]);
+        index += self.swap_action.as_bytes(&mut bytes[index..]);
+        index += 3;
+        index
+    }
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let mut index: usize = 0;
+        log::trace!("Deserializing SwapInfo from byte buffer");
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (swap_action, sz): (SwapAction, usize) = <SwapAction>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        index += 3;
+        Some((
+            SwapInfo {
+                window: window,
+                swap_action: swap_action,
+            },
+            index,
+        ))
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        self.window.size() + self.swap_action.size() + 3
+    }
+}
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub enum SwapAction {
+    Undefined = 0,
+    Background = 1,
+    Untouched = 2,
+    Copied = 3,
+}
+impl AsByteSequence for SwapAction {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        (*self as u8).as_bytes(bytes)
+    }
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let (underlying, sz): (u8, usize) = <u8>::from_bytes(bytes)?;
+        match underlying {
+            0 => Some((Self::Undefined, sz)),
+            1 => Some((Self::Background, sz)),
+            2 => Some((Self::Untouched, sz)),
+            3 => Some((Self::Copied, sz)),
+            _ => None,
+        }
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        ::core::mem::size_of::<u8>()
+    }
+}
+impl Default for SwapAction {
+    #[inline]
+    fn default() -> SwapAction {
+        SwapAction::Undefined
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct BufferAttributes {
+    pub window: Window,
+}
+impl BufferAttributes {}
+impl AsByteSequence for BufferAttributes {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.window.as_bytes(&mut bytes[index..]);
+        index
+    }
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let mut index: usize = 0;
+        log::trace!("Deserializing BufferAttributes from byte buffer");
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        Some((BufferAttributes { window: window }, index))
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        self.window.size()
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct VisualInfo {
+    pub visual_id: Visualid,
+    pub depth: Card8,
+    pub perf_level: Card8,
+}
+impl VisualInfo {}
+impl AsByteSequence for VisualInfo {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.visual_id.as_bytes(&mut bytes[index..]);
+        index += self.depth.as_bytes(&mut bytes[index..]);
+        index += self.perf_level.as_bytes(&mut bytes[index..]);
+        index += 2;
+        index
+    }
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let mut index: usize = 0;
+        log::trace!("Deserializing VisualInfo from byte buffer");
+        let (visual_id, sz): (Visualid, usize) = <Visualid>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (perf_level, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        index += 2;
+        Some((
+            VisualInfo {
+                visual_id: visual_id,
+                depth: depth,
+                perf_level: perf_level,
+            },
+            index,
+        ))
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        self.visual_id.size() + self.depth.size() + self.perf_level.size() + 2
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct VisualInfos {
+    pub infos: Vec<VisualInfo>,
+}
+impl VisualInfos {}
+impl AsByteSequence for VisualInfos {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += (self.infos.len() as Card32).as_bytes(&mut bytes[index..]);
+        let block_len: usize = vector_as_bytes(&self.infos, &mut bytes[index..]);
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<VisualInfo>());
+        index
+    }
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let mut index: usize = 0;
+        log::trace!("Deserializing VisualInfos from byte buffer");
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (infos, block_len): (Vec<VisualInfo>, usize) =
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<VisualInfo>());
+        Some((VisualInfos { infos: infos }, index))
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        ::core::mem::size_of::<Card32>() + {
+            let block_len: usize = self.infos.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<VisualInfo>());
+            block_len + pad
+        }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct QueryVersionRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub major_version: Card8,
+    pub minor_version: Card8,
+}
+impl QueryVersionRequest {}
+impl AsByteSequence for QueryVersionRequest {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.req_type.as_bytes(&mut bytes[index..]);
+        index += 1;
+        index += self.length.as_bytes(&mut bytes[index..]);
+        index += self.major_version.as_bytes(&mut bytes[index..]);
+        index += self.minor_version.as_bytes(&mut bytes[index..]);
+        index += 2;
+        index
+    }
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let mut index: usize = 0;
+        log::trace!("Deserializing QueryVersionRequest from byte buffer");
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (major_version, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (minor_version, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        index += 2;
+        Some((
+            QueryVersionRequest {
+                req_type: req_type,
+                length: length,
+                major_version: major_version,
+                minor_version: minor_version,
+            },
+            index,
+        ))
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        self.req_type.size()
+            + 1
+            + self.length.size()
+            + self.major_version.size()
+            + self.minor_version.size()
+            + 2
+    }
+}
+impl Request for QueryVersionRequest {
+    const OPCODE: u8 = 0;
+    const EXTENSION: Option<&'static str> = Some("DOUBLE-BUFFER");
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
+    type Reply = QueryVersionReply;
+}
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct QueryVersionReply {
+    pub reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: Card8,
+    pub minor_version: Card8,
+}
+impl QueryVersionReply {}
+impl AsByteSequence for QueryVersionReply {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.reply_type.as_bytes(&mut bytes[index..]);
+        index += 1;
+        index += self.sequence.as_bytes(&mut bytes[index..]);
+        index += self.length.as_bytes(&mut bytes[index..]);
+        index += self.major_version.as_bytes(&mut bytes[index..]);
+        index += self.minor_version.as_bytes(&mut bytes[index..]);
+        index += 22;
+        index
+    }
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let mut index: usize = 0;
+        log::trace!("Deserializing QueryVersionReply from byte buffer");
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (major_version, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (minor_version, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        index += 22;
+        Some((
+            QueryVersionReply {
+                reply_type: reply_type,
+                sequence: sequence,
+                length: length,
+                major_version: major_version,
+                minor_version: minor_version,
+            },
+            index,
+        ))
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        self.reply_type.size()
+            + 1
+            + self.sequence.size()
+            + self.length.size()
+            + self.major_version.size()
+            + self.minor_version.size()
+            + 22
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct AllocateBackBufferRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub window: Window,
+    pub buffer: BackBuffer,
+    pub swap_action: SwapAction,
+}
+impl AllocateBackBufferRequest {}
+impl AsByteSequence for AllocateBackBufferRequest {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.req_type.as_bytes(&mut bytes[index..]);
+        index += 1;
+        index += self.length.as_bytes(&mut bytes[index..]);
+        index += self.window.as_bytes(&mut bytes[index..]);
+        index += self.buffer.as_bytes(&mut bytes[index..]);
+        index += self.swap_action.as_bytes(&mut bytes[index..]);
+        index += 3;
+        index
+    }
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let mut index: usize = 0;
+        log::trace!("Deserializing AllocateBackBufferRequest from byte buffer");
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (buffer, sz): (BackBuffer, usize) = <BackBuffer>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (swap_action, sz): (SwapAction, usize) = <SwapAction>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        index += 3;
+        Some((
+            AllocateBackBufferRequest {
+                req_type: req_type,
+                length: length,
+                window: window,
+                buffer: buffer,
+                swap_action: swap_action,
+            },
+            index,
+        ))
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        self.req_type.size()
+            + 1
+            + self.length.size()
+            + self.window.size()
+            + self.buffer.size()
+            + self.swap_action.size()
+            + 3
+    }
+}
+impl Request for AllocateBackBufferRequest {
+    const OPCODE: u8 = 1;
+    const EXTENSION: Option<&'static str> = Some("DOUBLE-BUFFER");
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "AllocateBackBuffer";
+    type Reply = ();
+}
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct DeallocateBackBufferRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub buffer: BackBuffer,
+}
+impl DeallocateBackBufferRequest {}
+impl AsByteSequence for DeallocateBackBufferRequest {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.req_type.as_bytes(&mut bytes[index..]);
+        index += 1;
+        index += self.length.as_bytes(&mut bytes[index..]);
+        index += self.buffer.as_bytes(&mut bytes[index..]);
+        index
+    }
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let mut index: usize = 0;
+        log::trace!("Deserializing DeallocateBackBufferRequest from byte buffer");
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (buffer, sz): (BackBuffer, usize) = <BackBuffer>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        Some((
+            DeallocateBackBufferRequest {
+                req_type: req_type,
+                length: length,
+                buffer: buffer,
+            },
+            index,
+        ))
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        self.req_type.size() + 1 + self.length.size() + self.buffer.size()
+    }
+}
+impl Request for DeallocateBackBufferRequest {
+    const OPCODE: u8 = 2;
+    const EXTENSION: Option<&'static str> = Some("DOUBLE-BUFFER");
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DeallocateBackBuffer";
+    type Reply = ();
+}
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct SwapBuffersRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub actions: Vec<SwapInfo>,
+}
+impl SwapBuffersRequest {}
+impl AsByteSequence for SwapBuffersRequest {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.req_type.as_bytes(&mut bytes[index..]);
+        index += 1;
+        index += self.length.as_bytes(&mut bytes[index..]);
+        index += (self.actions.len() as Card32).as_bytes(&mut bytes[index..]);
+        let block_len: usize = vector_as_bytes(&self.actions, &mut bytes[index..]);
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<SwapInfo>());
+        index
+    }
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let mut index: usize = 0;
+        log::trace!("Deserializing SwapBuffersRequest from byte buffer");
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (actions, block_len): (Vec<SwapInfo>, usize) =
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<SwapInfo>());
+        Some((
+            SwapBuffersRequest {
+                req_type: req_type,
+                length: length,
+                actions: actions,
+            },
+            index,
+        ))
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        self.req_type.size() + 1 + self.length.size() + ::core::mem::size_of::<Card32>() + {
+            let block_len: usize = self.actions.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SwapInfo>());
+            block_len + pad
+        }
+    }
+}
+impl Request for SwapBuffersRequest {
+    const OPCODE: u8 = 3;
+    const EXTENSION: Option<&'static str> = Some("DOUBLE-BUFFER");
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SwapBuffers";
+    type Reply = ();
+}
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct BeginIdiomRequest {
+    pub req_type: u8,
+    pub length: u16,
+}
+impl BeginIdiomRequest {}
+impl AsByteSequence for BeginIdiomRequest {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.req_type.as_bytes(&mut bytes[index..]);
+        index += 1;
+        index += self.length.as_bytes(&mut bytes[index..]);
+        index
+    }
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let mut index: usize = 0;
+        log::trace!("Deserializing BeginIdiomRequest from byte buffer");
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        Some((
+            BeginIdiomRequest {
+                req_type: req_type,
+                length: length,
+            },
+            index,
+        ))
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        self.req_type.size() + 1 + self.length.size()
+    }
+}
+impl Request for BeginIdiomRequest {
+    const OPCODE: u8 = 4;
+    const EXTENSION: Option<&'static str> = Some("DOUBLE-BUFFER");
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "BeginIdiom";
+    type Reply = ();
+}
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct EndIdiomRequest {
+    pub req_type: u8,
+    pub length: u16,
+}
+impl EndIdiomRequest {}
+impl AsByteSequence for EndIdiomRequest {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.req_type.as_bytes(&mut bytes[index..]);
+        index += 1;
+        index += self.length.as_bytes(&mut bytes[index..]);
+        index
+    }
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let mut index: usize = 0;
+        log::trace!("Deserializing EndIdiomRequest from byte buffer");
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        Some((
+            EndIdiomRequest {
+                req_type: req_type,
+                length: length,
+            },
+            index,
+        ))
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        self.req_type.size() + 1 + self.length.size()
+    }
+}
+impl Request for EndIdiomRequest {
+    const OPCODE: u8 = 5;
+    const EXTENSION: Option<&'static str> = Some("DOUBLE-BUFFER");
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "EndIdiom";
+    type Reply = ();
+}
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct GetVisualInfoRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub drawables: Vec<Drawable>,
+}
+impl GetVisualInfoRequest {}
+impl AsByteSequence for GetVisualInfoRequest {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.req_type.as_bytes(&mut bytes[index..]);
+        index += 1;
+        index += self.length.as_bytes(&mut bytes[index..]);
+        index += (self.drawables.len() as Card32).as_bytes(&mut bytes[index..]);
+        let block_len: usize = vector_as_bytes(&self.drawables, &mut bytes[index..]);
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Drawable>());
+        index
+    }
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let mut index: usize = 0;
+        log::trace!("Deserializing GetVisualInfoRequest from byte buffer");
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (drawables, block_len): (Vec<Drawable>, usize) =
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<Drawable>());
+        Some((
+            GetVisualInfoRequest {
+                req_type: req_type,
+                length: length,
+                drawables: drawables,
+            },
+            index,
+        ))
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        self.req_type.size() + 1 + self.length.size() + ::core::mem::size_of::<Card32>() + {
+            let block_len: usize = self.drawables.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Drawable>());
+            block_len + pad
+        }
+    }
+}
+impl Request for GetVisualInfoRequest {
+    const OPCODE: u8 = 6;
+    const EXTENSION: Option<&'static str> = Some("DOUBLE-BUFFER");
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetVisualInfo";
+    type Reply = GetVisualInfoReply;
+}
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct GetVisualInfoReply {
+    pub reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub supported_visuals: Vec<VisualInfos>,
+}
+impl GetVisualInfoReply {}
+impl AsByteSequence for GetVisualInfoReply {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.reply_type.as_bytes(&mut bytes[index..]);
+        index += 1;
+        index += self.sequence.as_bytes(&mut bytes[index..]);
+        index += self.length.as_bytes(&mut bytes[index..]);
+        index += (self.supported_visuals.len() as Card32).as_bytes(&mut bytes[index..]);
+        index += 20;
+        let block_len: usize = vector_as_bytes(&self.supported_visuals, &mut bytes[index..]);
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<VisualInfos>());
+        index
+    }
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let mut index: usize = 0;
+        log::trace!("Deserializing GetVisualInfoReply from byte buffer");
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        index += 20;
+        let (supported_visuals, block_len): (Vec<VisualInfos>, usize) =
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
+        index += block_len;
+        index += buffer_pad(block_len, ::core::mem::align_of::<VisualInfos>());
+        Some((
+            GetVisualInfoReply {
+                reply_type: reply_type,
+                sequence: sequence,
+                length: length,
+                supported_visuals: supported_visuals,
+            },
+            index,
+        ))
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        self.reply_type.size()
+            + 1
+            + self.sequence.size()
+            + self.length.size()
+            + ::core::mem::size_of::<Card32>()
+            + 20
+            + {
+                let block_len: usize = self.supported_visuals.iter().map(|i| i.size()).sum();
+                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<VisualInfos>());
+                block_len + pad
+            }
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct GetBackBufferAttributesRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub buffer: BackBuffer,
+}
+impl GetBackBufferAttributesRequest {}
+impl AsByteSequence for GetBackBufferAttributesRequest {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.req_type.as_bytes(&mut bytes[index..]);
+        index += 1;
+        index += self.length.as_bytes(&mut bytes[index..]);
+        index += self.buffer.as_bytes(&mut bytes[index..]);
+        index
+    }
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let mut index: usize = 0;
+        log::trace!("Deserializing GetBackBufferAttributesRequest from byte buffer");
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        index += 1;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (buffer, sz): (BackBuffer, usize) = <BackBuffer>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        Some((
+            GetBackBufferAttributesRequest {
+                req_type: req_type,
+                length: length,
+                buffer: buffer,
+            },
+            index,
+        ))
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        self.req_type.size() + 1 + self.length.size() + self.buffer.size()
+    }
+}
+impl Request for GetBackBufferAttributesRequest {
+    const OPCODE: u8 = 7;
+    const EXTENSION: Option<&'static str> = Some("DOUBLE-BUFFER");
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetBackBufferAttributes";
+    type Reply = GetBackBufferAttributesReply;
+}
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct GetBackBufferAttributesReply {
+    pub reply_type: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub attributes: BufferAttributes,
+}
+impl GetBackBufferAttributesReply {}
+impl AsByteSequence for GetBackBufferAttributesReply {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.reply_type.as_bytes(&mut bytes[index..]);
+        index += 1;
+        index += self.sequence.as_bytes(&mut bytes[index..]);
+        index += self.length.as_bytes(&mut bytes[index..]);
+        index += self.attributes.as_bytes(&mut bytes[index..]);
+        index += 20;
+        index
+    }
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let mut index: usize = 0;
+        log::trace!("Deserializing GetBackBufferAttributesReply from byte buffer");
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        index += 1;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (attributes, sz): (BufferAttributes, usize) =
+            <BufferAttributes>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        index += 20;
+        Some((
+            GetBackBufferAttributesReply {
+                reply_type: reply_type,
+                sequence: sequence,
+                length: length,
+                attributes: attributes,
+            },
+            index,
+        ))
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        self.reply_type.size()
+            + 1
+            + self.sequence.size()
+            + self.length.size()
+            + self.attributes.size()
+            + 20
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct BadBufferError {
+    pub _error_type: u8,
+    pub error_code: u8,
+    pub major_code: u8,
+    pub minor_code: u8,
+    pub sequence: u16,
+    pub bad_buffer: BackBuffer,
+}
+impl BadBufferError {}
+impl AsByteSequence for BadBufferError {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self._error_type.as_bytes(&mut bytes[index..]);
+        index += self.error_code.as_bytes(&mut bytes[index..]);
+        index += self.major_code.as_bytes(&mut bytes[index..]);
+        index += self.minor_code.as_bytes(&mut bytes[index..]);
+        index += self.sequence.as_bytes(&mut bytes[index..]);
+        index += self.bad_buffer.as_bytes(&mut bytes[index..]);
+        index
+    }
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let mut index: usize = 0;
+        log::trace!("Deserializing BadBufferError from byte buffer");
+        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (error_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (major_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (bad_buffer, sz): (BackBuffer, usize) = <BackBuffer>::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        Some((
+            BadBufferError {
+                _error_type: _error_type,
+                error_code: error_code,
+                major_code: major_code,
+                minor_code: minor_code,
+                sequence: sequence,
+                bad_buffer: bad_buffer,
+            },
+            index,
+        ))
+    }
+    #[inline]
+    fn size(&self) -> usize {
+        self._error_type.size()
+            + self.error_code.size()
+            + self.major_code.size()
+            + self.minor_code.size()
+            + self.sequence.size()
+            + self.bad_buffer.size()
+    }
+}
+impl crate::auto::Error for BadBufferError {
+    const OPCODE: u8 = 0;
+}
@@ -28,14 +28,16 @@ impl AsByteSequence for GetVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (client_major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (client_major_version, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (client_minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (client_minor_version, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetVersionRequest {
@@ -60,6 +62,7 @@ impl Request for GetVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DPMS");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetVersion";
     type Reply = GetVersionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -87,16 +90,18 @@ impl AsByteSequence for GetVersionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (server_major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (server_major_version, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (server_minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (server_minor_version, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetVersionReply {
@@ -138,10 +143,10 @@ impl AsByteSequence for CapableRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CapableRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CapableRequest {
@@ -160,6 +165,7 @@ impl Request for CapableRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = Some("DPMS");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Capable";
     type Reply = CapableReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -186,14 +192,14 @@ impl AsByteSequence for CapableReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CapableReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (capable, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (capable, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 23;
         Some((
@@ -235,10 +241,10 @@ impl AsByteSequence for GetTimeoutsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetTimeoutsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetTimeoutsRequest {
@@ -257,6 +263,7 @@ impl Request for GetTimeoutsRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = Some("DPMS");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetTimeouts";
     type Reply = GetTimeoutsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -287,18 +294,18 @@ impl AsByteSequence for GetTimeoutsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetTimeoutsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (standby_timeout, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (standby_timeout, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (suspend_timeout, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (suspend_timeout, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (off_timeout, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (off_timeout, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 18;
         Some((
@@ -350,16 +357,16 @@ impl AsByteSequence for SetTimeoutsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetTimeoutsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (standby_timeout, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (standby_timeout, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (suspend_timeout, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (suspend_timeout, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (off_timeout, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (off_timeout, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetTimeoutsRequest {
@@ -386,6 +393,7 @@ impl Request for SetTimeoutsRequest {
     const OPCODE: u8 = 3;
     const EXTENSION: Option<&'static str> = Some("DPMS");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetTimeouts";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -407,10 +415,10 @@ impl AsByteSequence for EnableRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing EnableRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             EnableRequest {
@@ -429,6 +437,7 @@ impl Request for EnableRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = Some("DPMS");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Enable";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -450,10 +459,10 @@ impl AsByteSequence for DisableRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DisableRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DisableRequest {
@@ -472,6 +481,7 @@ impl Request for DisableRequest {
     const OPCODE: u8 = 5;
     const EXTENSION: Option<&'static str> = Some("DPMS");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Disable";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -495,12 +505,12 @@ impl AsByteSequence for ForceLevelRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ForceLevelRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (power_level, sz): (DpmsMode, usize) = <DpmsMode>::from_bytes(&bytes[index..])?;
+        let (power_level, sz): (DpmsMode, usize) = <DpmsMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ForceLevelRequest {
@@ -520,6 +530,7 @@ impl Request for ForceLevelRequest {
     const OPCODE: u8 = 6;
     const EXTENSION: Option<&'static str> = Some("DPMS");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ForceLevel";
     type Reply = ();
 }
 #[repr(u16)]
@@ -576,10 +587,10 @@ impl AsByteSequence for InfoRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing InfoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             InfoRequest {
@@ -598,6 +609,7 @@ impl Request for InfoRequest {
     const OPCODE: u8 = 7;
     const EXTENSION: Option<&'static str> = Some("DPMS");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Info";
     type Reply = InfoReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -626,16 +638,16 @@ impl AsByteSequence for InfoReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing InfoReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (power_level, sz): (DpmsMode, usize) = <DpmsMode>::from_bytes(&bytes[index..])?;
+        let (power_level, sz): (DpmsMode, usize) = <DpmsMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (state, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 21;
         Some((
@@ -30,15 +30,15 @@ impl AsByteSequence for Dri2Buffer {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Dri2Buffer from byte buffer");
-        let (attachment, sz): (Attachment, usize) = <Attachment>::from_bytes(&bytes[index..])?;
+        let (attachment, sz): (Attachment, usize) = <Attachment>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (name, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (name, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pitch, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pitch, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cpp, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (cpp, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Dri2Buffer {
@@ -127,9 +127,9 @@ impl AsByteSequence for AttachFormat {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AttachFormat from byte buffer");
-        let (attachment, sz): (Attachment, usize) = <Attachment>::from_bytes(&bytes[index..])?;
+        let (attachment, sz): (Attachment, usize) = <Attachment>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             AttachFormat {
@@ -167,14 +167,14 @@ impl AsByteSequence for QueryVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionRequest {
@@ -199,6 +199,7 @@ impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI2");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -226,16 +227,16 @@ impl AsByteSequence for QueryVersionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionReply {
@@ -281,14 +282,14 @@ impl AsByteSequence for ConnectRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ConnectRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (driver_type, sz): (DriverType, usize) = <DriverType>::from_bytes(&bytes[index..])?;
+        let (driver_type, sz): (DriverType, usize) = <DriverType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ConnectRequest {
@@ -309,6 +310,7 @@ impl Request for ConnectRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = Some("DRI2");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Connect";
     type Reply = ConnectReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -348,31 +350,31 @@ impl AsByteSequence for ConnectReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ConnectReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (driver_name_length, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (driver_name_length, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         let (driver_name, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], (driver_name_length as usize) as usize)?;
+            string_from_bytes(bytes.get(index..)?, (driver_name_length as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         let (alignment_pad, block_len): (Vec<Void>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             ((((driver_name_length as usize) + (3)) & (!(3))) - (driver_name_length as usize))
                 as usize,
         )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Void>());
         let (device_name, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -468,14 +470,14 @@ impl AsByteSequence for AuthenticateRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AuthenticateRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (magic, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (magic, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             AuthenticateRequest {
@@ -496,6 +498,7 @@ impl Request for AuthenticateRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = Some("DRI2");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Authenticate";
     type Reply = AuthenticateReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -521,14 +524,14 @@ impl AsByteSequence for AuthenticateReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AuthenticateReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (authenticated, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (authenticated, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             AuthenticateReply {
@@ -570,12 +573,12 @@ impl AsByteSequence for CreateDrawableRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateDrawableRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CreateDrawableRequest {
@@ -595,6 +598,7 @@ impl Request for CreateDrawableRequest {
     const OPCODE: u8 = 3;
     const EXTENSION: Option<&'static str> = Some("DRI2");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateDrawable";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -618,12 +622,12 @@ impl AsByteSequence for DestroyDrawableRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroyDrawableRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DestroyDrawableRequest {
@@ -643,6 +647,7 @@ impl Request for DestroyDrawableRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = Some("DRI2");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroyDrawable";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -672,17 +677,19 @@ impl AsByteSequence for GetBuffersRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetBuffersRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (count, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (count, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (attachments, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (attachments, block_len): (Vec<Card32>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -709,6 +716,7 @@ impl Request for GetBuffersRequest {
     const OPCODE: u8 = 5;
     const EXTENSION: Option<&'static str> = Some("DRI2");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetBuffers";
     type Reply = GetBuffersReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -742,22 +750,22 @@ impl AsByteSequence for GetBuffersReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetBuffersReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (buffers, block_len): (Vec<Dri2Buffer>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Dri2Buffer>());
         Some((
@@ -816,18 +824,18 @@ impl AsByteSequence for CopyRegionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CopyRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (region, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dest, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (dest, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (src, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CopyRegionRequest {
@@ -856,6 +864,7 @@ impl Request for CopyRegionRequest {
     const OPCODE: u8 = 6;
     const EXTENSION: Option<&'static str> = Some("DRI2");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CopyRegion";
     type Reply = CopyRegionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -879,12 +888,12 @@ impl AsByteSequence for CopyRegionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CopyRegionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CopyRegionReply {
@@ -927,17 +936,19 @@ impl AsByteSequence for GetBuffersWithFormatRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetBuffersWithFormatRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (count, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (count, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (attachments, block_len): (Vec<AttachFormat>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (attachments, block_len): (Vec<AttachFormat>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<AttachFormat>());
         Some((
@@ -964,6 +975,7 @@ impl Request for GetBuffersWithFormatRequest {
     const OPCODE: u8 = 7;
     const EXTENSION: Option<&'static str> = Some("DRI2");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetBuffersWithFormat";
     type Reply = GetBuffersWithFormatReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -997,22 +1009,22 @@ impl AsByteSequence for GetBuffersWithFormatReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetBuffersWithFormatReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (buffers, block_len): (Vec<Dri2Buffer>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Dri2Buffer>());
         Some((
@@ -1077,24 +1089,24 @@ impl AsByteSequence for SwapBuffersRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SwapBuffersRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target_msc_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target_msc_hi, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target_msc_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target_msc_lo, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (divisor_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (divisor_hi, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (divisor_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (divisor_lo, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (remainder_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (remainder_hi, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (remainder_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (remainder_lo, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SwapBuffersRequest {
@@ -1129,6 +1141,7 @@ impl Request for SwapBuffersRequest {
     const OPCODE: u8 = 8;
     const EXTENSION: Option<&'static str> = Some("DRI2");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SwapBuffers";
     type Reply = SwapBuffersReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1156,16 +1169,16 @@ impl AsByteSequence for SwapBuffersReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SwapBuffersReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (swap_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (swap_hi, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (swap_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (swap_lo, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SwapBuffersReply {
@@ -1209,12 +1222,12 @@ impl AsByteSequence for GetMscRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMscRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetMscRequest {
@@ -1234,6 +1247,7 @@ impl Request for GetMscRequest {
     const OPCODE: u8 = 9;
     const EXTENSION: Option<&'static str> = Some("DRI2");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetMsc";
     type Reply = GetMscReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1269,24 +1283,24 @@ impl AsByteSequence for GetMscReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMscReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ust_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ust_hi, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ust_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ust_lo, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (msc_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (msc_hi, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (msc_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (msc_lo, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sbc_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (sbc_hi, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sbc_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (sbc_lo, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetMscReply {
@@ -1350,24 +1364,24 @@ impl AsByteSequence for WaitMscRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing WaitMscRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target_msc_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target_msc_hi, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target_msc_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target_msc_lo, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (divisor_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (divisor_hi, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (divisor_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (divisor_lo, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (remainder_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (remainder_hi, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (remainder_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (remainder_lo, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             WaitMscRequest {
@@ -1402,6 +1416,7 @@ impl Request for WaitMscRequest {
     const OPCODE: u8 = 10;
     const EXTENSION: Option<&'static str> = Some("DRI2");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "WaitMsc";
     type Reply = WaitMscReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1437,24 +1452,24 @@ impl AsByteSequence for WaitMscReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing WaitMscReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ust_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ust_hi, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ust_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ust_lo, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (msc_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (msc_hi, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (msc_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (msc_lo, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sbc_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (sbc_hi, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sbc_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (sbc_lo, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             WaitMscReply {
@@ -1510,16 +1525,16 @@ impl AsByteSequence for WaitSbcRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing WaitSbcRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target_sbc_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target_sbc_hi, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target_sbc_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target_sbc_lo, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             WaitSbcRequest {
@@ -1546,6 +1561,7 @@ impl Request for WaitSbcRequest {
     const OPCODE: u8 = 11;
     const EXTENSION: Option<&'static str> = Some("DRI2");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "WaitSbc";
     type Reply = WaitSbcReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1581,24 +1597,24 @@ impl AsByteSequence for WaitSbcReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing WaitSbcReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ust_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ust_hi, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ust_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ust_lo, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (msc_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (msc_hi, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (msc_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (msc_lo, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sbc_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (sbc_hi, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sbc_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (sbc_lo, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             WaitSbcReply {
@@ -1652,14 +1668,14 @@ impl AsByteSequence for SwapIntervalRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SwapIntervalRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (interval, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (interval, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SwapIntervalRequest {
@@ -1680,6 +1696,7 @@ impl Request for SwapIntervalRequest {
     const OPCODE: u8 = 12;
     const EXTENSION: Option<&'static str> = Some("DRI2");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SwapInterval";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1705,14 +1722,14 @@ impl AsByteSequence for GetParamRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetParamRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (param, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (param, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetParamRequest {
@@ -1733,6 +1750,7 @@ impl Request for GetParamRequest {
     const OPCODE: u8 = 13;
     const EXTENSION: Option<&'static str> = Some("DRI2");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetParam";
     type Reply = GetParamReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1761,17 +1779,17 @@ impl AsByteSequence for GetParamReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetParamReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (is_param_recognized, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (is_param_recognized, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (value_hi, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (value_lo, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetParamReply {
@@ -1862,25 +1880,25 @@ impl AsByteSequence for BufferSwapCompleteEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing BufferSwapCompleteEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_type_, sz): (EventType, usize) = <EventType>::from_bytes(&bytes[index..])?;
+        let (event_type_, sz): (EventType, usize) = <EventType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ust_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ust_hi, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ust_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ust_lo, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (msc_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (msc_hi, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (msc_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (msc_lo, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sbc, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (sbc, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             BufferSwapCompleteEvent {
@@ -1936,12 +1954,12 @@ impl AsByteSequence for InvalidateBuffersEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing InvalidateBuffersEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             InvalidateBuffersEvent {
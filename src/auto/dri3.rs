@@ -29,14 +29,14 @@ impl AsByteSequence for QueryVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionRequest {
@@ -61,6 +61,7 @@ impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("DRI3");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -88,16 +89,16 @@ impl AsByteSequence for QueryVersionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionReply {
@@ -143,14 +144,14 @@ impl AsByteSequence for OpenRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing OpenRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (provider, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (provider, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             OpenRequest {
@@ -171,6 +172,7 @@ impl Request for OpenRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = Some("DRI3");
     const REPLY_EXPECTS_FDS: bool = true;
+    const NAME: &'static str = "Open";
     type Reply = OpenReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -197,13 +199,13 @@ impl AsByteSequence for OpenReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing OpenReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (nfd, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (nfd, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 24;
         Some((
@@ -262,26 +264,26 @@ impl AsByteSequence for PixmapFromBufferRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PixmapFromBufferRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (size, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (size, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (stride, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (stride, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bpp, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (bpp, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PixmapFromBufferRequest {
@@ -323,7 +325,12 @@ impl Request for PixmapFromBufferRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = Some("DRI3");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PixmapFromBuffer";
     type Reply = ();
+    #[inline]
+    fn fd_count(&self) -> usize {
+        1
+    }
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
 pub struct BufferFromPixmapRequest {
@@ -346,12 +353,12 @@ impl AsByteSequence for BufferFromPixmapRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing BufferFromPixmapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             BufferFromPixmapRequest {
@@ -371,6 +378,7 @@ impl Request for BufferFromPixmapRequest {
     const OPCODE: u8 = 3;
     const EXTENSION: Option<&'static str> = Some("DRI3");
     const REPLY_EXPECTS_FDS: bool = true;
+    const NAME: &'static str = "BufferFromPixmap";
     type Reply = BufferFromPixmapReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -409,25 +417,25 @@ impl AsByteSequence for BufferFromPixmapReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing BufferFromPixmapReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (nfd, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (nfd, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (size, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (size, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (stride, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (stride, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bpp, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (bpp, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         Some((
@@ -493,16 +501,16 @@ impl AsByteSequence for FenceFromFdRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FenceFromFdRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fence, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (fence, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (initially_triggered, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (initially_triggered, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -536,7 +544,12 @@ impl Request for FenceFromFdRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = Some("DRI3");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FenceFromFd";
     type Reply = ();
+    #[inline]
+    fn fd_count(&self) -> usize {
+        1
+    }
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
 pub struct FdFromFenceRequest {
@@ -561,14 +574,14 @@ impl AsByteSequence for FdFromFenceRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FdFromFenceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fence, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (fence, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             FdFromFenceRequest {
@@ -589,6 +602,7 @@ impl Request for FdFromFenceRequest {
     const OPCODE: u8 = 5;
     const EXTENSION: Option<&'static str> = Some("DRI3");
     const REPLY_EXPECTS_FDS: bool = true;
+    const NAME: &'static str = "FdFromFence";
     type Reply = FdFromFenceReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -615,13 +629,13 @@ impl AsByteSequence for FdFromFenceReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FdFromFenceReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (nfd, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (nfd, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 24;
         Some((
@@ -670,16 +684,16 @@ impl AsByteSequence for GetSupportedModifiersRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetSupportedModifiersRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bpp, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (bpp, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -708,6 +722,7 @@ impl Request for GetSupportedModifiersRequest {
     const OPCODE: u8 = 6;
     const EXTENSION: Option<&'static str> = Some("DRI3");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetSupportedModifiers";
     type Reply = GetSupportedModifiersReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -742,24 +757,24 @@ impl AsByteSequence for GetSupportedModifiersReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetSupportedModifiersReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         let (window_modifiers, block_len): (Vec<Card64>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card64>());
         let (screen_modifiers, block_len): (Vec<Card64>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card64>());
         Some((
@@ -800,6 +815,7 @@ pub struct PixmapFromBuffersRequest {
     pub length: u16,
     pub pixmap: Pixmap,
     pub window: Window,
+    pub num_buffers: Card8,
     pub width: Card16,
     pub height: Card16,
     pub stride0: Card32,
@@ -825,7 +841,7 @@ impl AsByteSequence for PixmapFromBuffersRequest {
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.pixmap.as_bytes(&mut bytes[index..]);
         index += self.window.as_bytes(&mut bytes[index..]);
-        index += (self.buffers.len() as Card8).as_bytes(&mut bytes[index..]);
+        index += self.num_buffers.as_bytes(&mut bytes[index..]);
         index += 3;
         index += self.width.as_bytes(&mut bytes[index..]);
         index += self.height.as_bytes(&mut bytes[index..]);
@@ -841,64 +857,58 @@ impl AsByteSequence for PixmapFromBuffersRequest {
         index += self.bpp.as_bytes(&mut bytes[index..]);
         index += 2;
         index += self.modifier.as_bytes(&mut bytes[index..]);
-        let block_len: usize = vector_as_bytes(&self.buffers, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Fd>());
         index
     }
     #[inline]
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PixmapFromBuffersRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (num_buffers, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (stride0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (stride0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (offset0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (stride1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (stride1, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (offset1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset1, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (stride2, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (stride2, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (offset2, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset2, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (stride3, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (stride3, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (offset3, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset3, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bpp, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (bpp, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (modifier, sz): (Card64, usize) = <Card64>::from_bytes(&bytes[index..])?;
+        let (modifier, sz): (Card64, usize) = <Card64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (buffers, block_len): (Vec<Fd>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Fd>());
         Some((
             PixmapFromBuffersRequest {
                 req_type: req_type,
                 length: length,
                 pixmap: pixmap,
                 window: window,
+                num_buffers: num_buffers,
                 width: width,
                 height: height,
                 stride0: stride0,
@@ -912,7 +922,7 @@ impl AsByteSequence for PixmapFromBuffersRequest {
                 depth: depth,
                 bpp: bpp,
                 modifier: modifier,
-                buffers: buffers,
+                buffers: vec![],
             },
             index,
         ))
@@ -924,7 +934,7 @@ impl AsByteSequence for PixmapFromBuffersRequest {
             + self.length.size()
             + self.pixmap.size()
             + self.window.size()
-            + ::core::mem::size_of::<Card8>()
+            + self.num_buffers.size()
             + 3
             + self.width.size()
             + self.height.size()
@@ -940,18 +950,22 @@ impl AsByteSequence for PixmapFromBuffersRequest {
             + self.bpp.size()
             + 2
             + self.modifier.size()
-            + {
-                let block_len: usize = self.buffers.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Fd>());
-                block_len + pad
-            }
+    }
+    #[inline]
+    fn file_descriptors(&mut self) -> Option<&mut Vec<Fd>> {
+        Some(&mut self.buffers)
     }
 }
 impl Request for PixmapFromBuffersRequest {
     const OPCODE: u8 = 7;
     const EXTENSION: Option<&'static str> = Some("DRI3");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PixmapFromBuffers";
     type Reply = ();
+    #[inline]
+    fn fd_count(&self) -> usize {
+        self.num_buffers as usize
+    }
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
 pub struct BuffersFromPixmapRequest {
@@ -974,12 +988,12 @@ impl AsByteSequence for BuffersFromPixmapRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing BuffersFromPixmapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             BuffersFromPixmapRequest {
@@ -998,7 +1012,8 @@ impl AsByteSequence for BuffersFromPixmapRequest {
 impl Request for BuffersFromPixmapRequest {
     const OPCODE: u8 = 8;
     const EXTENSION: Option<&'static str> = Some("DRI3");
-    const REPLY_EXPECTS_FDS: bool = false;
+    const REPLY_EXPECTS_FDS: bool = true;
+    const NAME: &'static str = "BuffersFromPixmap";
     type Reply = BuffersFromPixmapReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1038,47 +1053,40 @@ impl AsByteSequence for BuffersFromPixmapReply {
         let block_len: usize = vector_as_bytes(&self.offsets, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-        let block_len: usize = vector_as_bytes(&self.buffers, &mut bytes[index..]);
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Fd>());
         index
     }
     #[inline]
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing BuffersFromPixmapReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (nfd, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (nfd, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (modifier, sz): (Card64, usize) = <Card64>::from_bytes(&bytes[index..])?;
+        let (modifier, sz): (Card64, usize) = <Card64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bpp, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (bpp, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 6;
         let (strides, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (nfd as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (nfd as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (offsets, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (nfd as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (nfd as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-        let (buffers, block_len): (Vec<Fd>, usize) =
-            vector_from_bytes(&bytes[index..], (nfd as usize) as usize)?;
-        index += block_len;
-        index += buffer_pad(block_len, ::core::mem::align_of::<Fd>());
         Some((
             BuffersFromPixmapReply {
                 reply_type: reply_type,
@@ -1092,7 +1100,7 @@ impl AsByteSequence for BuffersFromPixmapReply {
                 bpp: bpp,
                 strides: strides,
                 offsets: offsets,
-                buffers: buffers,
+                buffers: vec![],
             },
             index,
         ))
@@ -1120,10 +1128,9 @@ impl AsByteSequence for BuffersFromPixmapReply {
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
                 block_len + pad
             }
-            + {
-                let block_len: usize = self.buffers.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Fd>());
-                block_len + pad
-            }
+    }
+    #[inline]
+    fn file_descriptors(&mut self) -> Option<&mut Vec<Fd>> {
+        Some(&mut self.buffers)
     }
 }
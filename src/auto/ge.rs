@@ -28,14 +28,16 @@ impl AsByteSequence for QueryVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (client_major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (client_major_version, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (client_minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (client_minor_version, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionRequest {
@@ -60,6 +62,7 @@ impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("Generic Event Extension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -88,16 +91,16 @@ impl AsByteSequence for QueryVersionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         Some((
@@ -185,15 +185,17 @@ impl AsByteSequence for RenderRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing RenderRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (data, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (data, block_len): (Vec<Byte>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -219,6 +221,7 @@ impl Request for RenderRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Render";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -251,21 +254,21 @@ impl AsByteSequence for RenderLargeRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing RenderLargeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (request_num, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (request_num, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (request_total, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (request_total, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (data, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -300,6 +303,7 @@ impl Request for RenderLargeRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "RenderLarge";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -332,22 +336,22 @@ impl AsByteSequence for CreateContextRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (context, sz): (super::glx::Context, usize) =
-            <super::glx::Context>::from_bytes(&bytes[index..])?;
+            <super::glx::Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(&bytes[index..])?;
+        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (share_list, sz): (super::glx::Context, usize) =
-            <super::glx::Context>::from_bytes(&bytes[index..])?;
+            <super::glx::Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (is_direct, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (is_direct, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -380,6 +384,7 @@ impl Request for CreateContextRequest {
     const OPCODE: u8 = 3;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateContext";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -403,13 +408,13 @@ impl AsByteSequence for DestroyContextRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroyContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (context, sz): (super::glx::Context, usize) =
-            <super::glx::Context>::from_bytes(&bytes[index..])?;
+            <super::glx::Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DestroyContextRequest {
@@ -429,6 +434,7 @@ impl Request for DestroyContextRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroyContext";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -456,18 +462,19 @@ impl AsByteSequence for MakeCurrentRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing MakeCurrentRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (drawable, sz): (super::glx::Drawable, usize) =
-            <super::glx::Drawable>::from_bytes(&bytes[index..])?;
+            <super::glx::Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (context, sz): (super::glx::Context, usize) =
-            <super::glx::Context>::from_bytes(&bytes[index..])?;
+            <super::glx::Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (old_context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (old_context_tag, sz): (ContextTag, usize) =
+            <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             MakeCurrentRequest {
@@ -494,6 +501,7 @@ impl Request for MakeCurrentRequest {
     const OPCODE: u8 = 5;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "MakeCurrent";
     type Reply = MakeCurrentReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -520,14 +528,14 @@ impl AsByteSequence for MakeCurrentReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing MakeCurrentReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         Some((
@@ -571,13 +579,13 @@ impl AsByteSequence for IsDirectRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing IsDirectRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (context, sz): (super::glx::Context, usize) =
-            <super::glx::Context>::from_bytes(&bytes[index..])?;
+            <super::glx::Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             IsDirectRequest {
@@ -597,6 +605,7 @@ impl Request for IsDirectRequest {
     const OPCODE: u8 = 6;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "IsDirect";
     type Reply = IsDirectReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -623,14 +632,14 @@ impl AsByteSequence for IsDirectReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing IsDirectReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (is_direct, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (is_direct, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 23;
         Some((
@@ -676,14 +685,14 @@ impl AsByteSequence for QueryVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionRequest {
@@ -708,6 +717,7 @@ impl Request for QueryVersionRequest {
     const OPCODE: u8 = 7;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -736,16 +746,16 @@ impl AsByteSequence for QueryVersionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         Some((
@@ -791,12 +801,12 @@ impl AsByteSequence for WaitGlRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing WaitGlRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             WaitGlRequest {
@@ -816,6 +826,7 @@ impl Request for WaitGlRequest {
     const OPCODE: u8 = 8;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "WaitGl";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -839,12 +850,12 @@ impl AsByteSequence for WaitXRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing WaitXRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             WaitXRequest {
@@ -864,6 +875,7 @@ impl Request for WaitXRequest {
     const OPCODE: u8 = 9;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "WaitX";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -893,20 +905,21 @@ impl AsByteSequence for CopyContextRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CopyContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (src, sz): (super::glx::Context, usize) =
-            <super::glx::Context>::from_bytes(&bytes[index..])?;
+            <super::glx::Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (dest, sz): (super::glx::Context, usize) =
-            <super::glx::Context>::from_bytes(&bytes[index..])?;
+            <super::glx::Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (src_context_tag, sz): (ContextTag, usize) =
+            <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CopyContextRequest {
@@ -935,6 +948,7 @@ impl Request for CopyContextRequest {
     const OPCODE: u8 = 10;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CopyContext";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -960,15 +974,15 @@ impl AsByteSequence for SwapBuffersRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SwapBuffersRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (drawable, sz): (super::glx::Drawable, usize) =
-            <super::glx::Drawable>::from_bytes(&bytes[index..])?;
+            <super::glx::Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SwapBuffersRequest {
@@ -993,6 +1007,7 @@ impl Request for SwapBuffersRequest {
     const OPCODE: u8 = 11;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SwapBuffers";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1024,20 +1039,20 @@ impl AsByteSequence for UseXFontRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing UseXFontRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (font, sz): (Font, usize) = <Font>::from_bytes(&bytes[index..])?;
+        let (font, sz): (Font, usize) = <Font>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (first, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (count, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (count, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (list_base, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (list_base, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             UseXFontRequest {
@@ -1068,6 +1083,7 @@ impl Request for UseXFontRequest {
     const OPCODE: u8 = 12;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UseXFont";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1097,20 +1113,20 @@ impl AsByteSequence for CreateGlxPixmapRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateGlxPixmapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(&bytes[index..])?;
+        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (pixmap, sz): (super::xproto::Pixmap, usize) =
-            <super::xproto::Pixmap>::from_bytes(&bytes[index..])?;
+            <super::xproto::Pixmap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (glx_pixmap, sz): (super::glx::Pixmap, usize) =
-            <super::glx::Pixmap>::from_bytes(&bytes[index..])?;
+            <super::glx::Pixmap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CreateGlxPixmapRequest {
@@ -1139,6 +1155,7 @@ impl Request for CreateGlxPixmapRequest {
     const OPCODE: u8 = 13;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateGlxPixmap";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1162,12 +1179,12 @@ impl AsByteSequence for GetVisualConfigsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetVisualConfigsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetVisualConfigsRequest {
@@ -1187,6 +1204,7 @@ impl Request for GetVisualConfigsRequest {
     const OPCODE: u8 = 14;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetVisualConfigs";
     type Reply = GetVisualConfigsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1219,20 +1237,20 @@ impl AsByteSequence for GetVisualConfigsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetVisualConfigsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_visuals, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_visuals, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_properties, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_properties, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         let (property_list, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (length as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (length as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -1284,13 +1302,13 @@ impl AsByteSequence for DestroyGlxPixmapRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroyGlxPixmapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (glx_pixmap, sz): (super::glx::Pixmap, usize) =
-            <super::glx::Pixmap>::from_bytes(&bytes[index..])?;
+            <super::glx::Pixmap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DestroyGlxPixmapRequest {
@@ -1310,6 +1328,7 @@ impl Request for DestroyGlxPixmapRequest {
     const OPCODE: u8 = 15;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroyGlxPixmap";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1339,17 +1358,19 @@ impl AsByteSequence for VendorPrivateRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing VendorPrivateRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vendor_code, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (vendor_code, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (data, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (data, block_len): (Vec<Byte>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -1381,6 +1402,7 @@ impl Request for VendorPrivateRequest {
     const OPCODE: u8 = 16;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "VendorPrivate";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1410,17 +1432,19 @@ impl AsByteSequence for VendorPrivateWithReplyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing VendorPrivateWithReplyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vendor_code, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (vendor_code, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (data, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (data, block_len): (Vec<Byte>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -1452,6 +1476,7 @@ impl Request for VendorPrivateWithReplyRequest {
     const OPCODE: u8 = 17;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "VendorPrivateWithReply";
     type Reply = VendorPrivateWithReplyReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1483,19 +1508,19 @@ impl AsByteSequence for VendorPrivateWithReplyReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing VendorPrivateWithReplyReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (retval, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (retval, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (data1, sz): ([Byte; 24], usize) = <[Byte; 24]>::from_bytes(&bytes[index..])?;
+        let (data1, sz): ([Byte; 24], usize) = <[Byte; 24]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (data2, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize) * (4)) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((length as usize) * (4)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -1546,12 +1571,12 @@ impl AsByteSequence for QueryExtensionsStringRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryExtensionsStringRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryExtensionsStringRequest {
@@ -1571,6 +1596,7 @@ impl Request for QueryExtensionsStringRequest {
     const OPCODE: u8 = 18;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryExtensionsString";
     type Reply = QueryExtensionsStringReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1598,15 +1624,15 @@ impl AsByteSequence for QueryExtensionsStringReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryExtensionsStringReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (n, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (n, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         Some((
@@ -1653,14 +1679,14 @@ impl AsByteSequence for QueryServerStringRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryServerStringRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (name, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (name, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryServerStringRequest {
@@ -1681,6 +1707,7 @@ impl Request for QueryServerStringRequest {
     const OPCODE: u8 = 19;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryServerString";
     type Reply = QueryServerStringReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1711,19 +1738,19 @@ impl AsByteSequence for QueryServerStringReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryServerStringReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         let (string, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -1780,19 +1807,19 @@ impl AsByteSequence for ClientInfoRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ClientInfoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (string, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -1825,6 +1852,7 @@ impl Request for ClientInfoRequest {
     const OPCODE: u8 = 20;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ClientInfo";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1848,12 +1876,12 @@ impl AsByteSequence for GetFbConfigsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetFbConfigsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetFbConfigsRequest {
@@ -1873,6 +1901,7 @@ impl Request for GetFbConfigsRequest {
     const OPCODE: u8 = 21;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetFbConfigs";
     type Reply = GetFbConfigsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1905,20 +1934,20 @@ impl AsByteSequence for GetFbConfigsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetFbConfigsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_fb_configs, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_fb_configs, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_properties, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_properties, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         let (property_list, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (length as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (length as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -1982,25 +2011,25 @@ impl AsByteSequence for CreatePixmapRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreatePixmapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fbconfig, sz): (Fbconfig, usize) = <Fbconfig>::from_bytes(&bytes[index..])?;
+        let (fbconfig, sz): (Fbconfig, usize) = <Fbconfig>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (pixmap, sz): (super::xproto::Pixmap, usize) =
-            <super::xproto::Pixmap>::from_bytes(&bytes[index..])?;
+            <super::xproto::Pixmap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (glx_pixmap, sz): (super::glx::Pixmap, usize) =
-            <super::glx::Pixmap>::from_bytes(&bytes[index..])?;
+            <super::glx::Pixmap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_attribs, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_attribs, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (attribs, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], ((num_attribs as usize) * (2)) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((num_attribs as usize) * (2)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -2038,6 +2067,7 @@ impl Request for CreatePixmapRequest {
     const OPCODE: u8 = 22;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreatePixmap";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2061,13 +2091,13 @@ impl AsByteSequence for DestroyPixmapRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroyPixmapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (glx_pixmap, sz): (super::glx::Pixmap, usize) =
-            <super::glx::Pixmap>::from_bytes(&bytes[index..])?;
+            <super::glx::Pixmap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DestroyPixmapRequest {
@@ -2087,6 +2117,7 @@ impl Request for DestroyPixmapRequest {
     const OPCODE: u8 = 23;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroyPixmap";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2121,24 +2152,24 @@ impl AsByteSequence for CreateNewContextRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateNewContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (context, sz): (super::glx::Context, usize) =
-            <super::glx::Context>::from_bytes(&bytes[index..])?;
+            <super::glx::Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fbconfig, sz): (Fbconfig, usize) = <Fbconfig>::from_bytes(&bytes[index..])?;
+        let (fbconfig, sz): (Fbconfig, usize) = <Fbconfig>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (render_type, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (render_type, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (share_list, sz): (super::glx::Context, usize) =
-            <super::glx::Context>::from_bytes(&bytes[index..])?;
+            <super::glx::Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (is_direct, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (is_direct, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -2173,6 +2204,7 @@ impl Request for CreateNewContextRequest {
     const OPCODE: u8 = 24;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateNewContext";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2196,13 +2228,13 @@ impl AsByteSequence for QueryContextRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (context, sz): (super::glx::Context, usize) =
-            <super::glx::Context>::from_bytes(&bytes[index..])?;
+            <super::glx::Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryContextRequest {
@@ -2222,6 +2254,7 @@ impl Request for QueryContextRequest {
     const OPCODE: u8 = 25;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryContext";
     type Reply = QueryContextReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2252,18 +2285,18 @@ impl AsByteSequence for QueryContextReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_attribs, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_attribs, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (attribs, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], ((num_attribs as usize) * (2)) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((num_attribs as usize) * (2)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -2319,21 +2352,22 @@ impl AsByteSequence for MakeContextCurrentRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing MakeContextCurrentRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (old_context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (old_context_tag, sz): (ContextTag, usize) =
+            <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (drawable, sz): (super::glx::Drawable, usize) =
-            <super::glx::Drawable>::from_bytes(&bytes[index..])?;
+            <super::glx::Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (read_drawable, sz): (super::glx::Drawable, usize) =
-            <super::glx::Drawable>::from_bytes(&bytes[index..])?;
+            <super::glx::Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (context, sz): (super::glx::Context, usize) =
-            <super::glx::Context>::from_bytes(&bytes[index..])?;
+            <super::glx::Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             MakeContextCurrentRequest {
@@ -2362,6 +2396,7 @@ impl Request for MakeContextCurrentRequest {
     const OPCODE: u8 = 26;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "MakeContextCurrent";
     type Reply = MakeContextCurrentReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2388,14 +2423,14 @@ impl AsByteSequence for MakeContextCurrentReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing MakeContextCurrentReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         Some((
@@ -2449,21 +2484,21 @@ impl AsByteSequence for CreatePbufferRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreatePbufferRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fbconfig, sz): (Fbconfig, usize) = <Fbconfig>::from_bytes(&bytes[index..])?;
+        let (fbconfig, sz): (Fbconfig, usize) = <Fbconfig>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pbuffer, sz): (Pbuffer, usize) = <Pbuffer>::from_bytes(&bytes[index..])?;
+        let (pbuffer, sz): (Pbuffer, usize) = <Pbuffer>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_attribs, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_attribs, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (attribs, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], ((num_attribs as usize) * (2)) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((num_attribs as usize) * (2)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -2499,6 +2534,7 @@ impl Request for CreatePbufferRequest {
     const OPCODE: u8 = 27;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreatePbuffer";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2522,12 +2558,12 @@ impl AsByteSequence for DestroyPbufferRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroyPbufferRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pbuffer, sz): (Pbuffer, usize) = <Pbuffer>::from_bytes(&bytes[index..])?;
+        let (pbuffer, sz): (Pbuffer, usize) = <Pbuffer>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DestroyPbufferRequest {
@@ -2547,6 +2583,7 @@ impl Request for DestroyPbufferRequest {
     const OPCODE: u8 = 28;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroyPbuffer";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2570,13 +2607,13 @@ impl AsByteSequence for GetDrawableAttributesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDrawableAttributesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (drawable, sz): (super::glx::Drawable, usize) =
-            <super::glx::Drawable>::from_bytes(&bytes[index..])?;
+            <super::glx::Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetDrawableAttributesRequest {
@@ -2596,6 +2633,7 @@ impl Request for GetDrawableAttributesRequest {
     const OPCODE: u8 = 29;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetDrawableAttributes";
     type Reply = GetDrawableAttributesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2626,18 +2664,18 @@ impl AsByteSequence for GetDrawableAttributesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDrawableAttributesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_attribs, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_attribs, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (attribs, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], ((num_attribs as usize) * (2)) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((num_attribs as usize) * (2)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -2693,18 +2731,18 @@ impl AsByteSequence for ChangeDrawableAttributesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeDrawableAttributesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (drawable, sz): (super::glx::Drawable, usize) =
-            <super::glx::Drawable>::from_bytes(&bytes[index..])?;
+            <super::glx::Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_attribs, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_attribs, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (attribs, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], ((num_attribs as usize) * (2)) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((num_attribs as usize) * (2)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -2736,6 +2774,7 @@ impl Request for ChangeDrawableAttributesRequest {
     const OPCODE: u8 = 30;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeDrawableAttributes";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2771,25 +2810,25 @@ impl AsByteSequence for CreateWindowRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateWindowRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fbconfig, sz): (Fbconfig, usize) = <Fbconfig>::from_bytes(&bytes[index..])?;
+        let (fbconfig, sz): (Fbconfig, usize) = <Fbconfig>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (window, sz): (super::xproto::Window, usize) =
-            <super::xproto::Window>::from_bytes(&bytes[index..])?;
+            <super::xproto::Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (glx_window, sz): (super::glx::Window, usize) =
-            <super::glx::Window>::from_bytes(&bytes[index..])?;
+            <super::glx::Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_attribs, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_attribs, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (attribs, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], ((num_attribs as usize) * (2)) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((num_attribs as usize) * (2)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -2827,6 +2866,7 @@ impl Request for CreateWindowRequest {
     const OPCODE: u8 = 31;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateWindow";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2850,13 +2890,13 @@ impl AsByteSequence for DeleteWindowRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeleteWindowRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (glxwindow, sz): (super::glx::Window, usize) =
-            <super::glx::Window>::from_bytes(&bytes[index..])?;
+            <super::glx::Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DeleteWindowRequest {
@@ -2876,6 +2916,7 @@ impl Request for DeleteWindowRequest {
     const OPCODE: u8 = 32;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DeleteWindow";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2917,31 +2958,33 @@ impl AsByteSequence for SetClientInfoArbRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetClientInfoArbRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_versions, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_versions, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gl_versions, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], ((num_versions as usize) * (2)) as usize)?;
+        let (gl_versions, block_len): (Vec<Card32>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((num_versions as usize) * (2)) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (gl_extension_string, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         let (glx_extension_string, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len1 as usize)?;
+            string_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -2989,6 +3032,7 @@ impl Request for SetClientInfoArbRequest {
     const OPCODE: u8 = 33;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetClientInfoArb";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3027,28 +3071,28 @@ impl AsByteSequence for CreateContextAttribsArbRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateContextAttribsArbRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (context, sz): (super::glx::Context, usize) =
-            <super::glx::Context>::from_bytes(&bytes[index..])?;
+            <super::glx::Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fbconfig, sz): (Fbconfig, usize) = <Fbconfig>::from_bytes(&bytes[index..])?;
+        let (fbconfig, sz): (Fbconfig, usize) = <Fbconfig>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (share_list, sz): (super::glx::Context, usize) =
-            <super::glx::Context>::from_bytes(&bytes[index..])?;
+            <super::glx::Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (is_direct, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (is_direct, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (num_attribs, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_attribs, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (attribs, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], ((num_attribs as usize) * (2)) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((num_attribs as usize) * (2)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -3089,6 +3133,7 @@ impl Request for CreateContextAttribsArbRequest {
     const OPCODE: u8 = 34;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateContextAttribsArb";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3130,31 +3175,33 @@ impl AsByteSequence for SetClientInfo2ArbRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetClientInfo2ArbRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_versions, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_versions, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gl_versions, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], ((num_versions as usize) * (3)) as usize)?;
+        let (gl_versions, block_len): (Vec<Card32>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((num_versions as usize) * (3)) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (gl_extension_string, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         let (glx_extension_string, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len1 as usize)?;
+            string_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -3202,6 +3249,7 @@ impl Request for SetClientInfo2ArbRequest {
     const OPCODE: u8 = 35;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetClientInfo2Arb";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3229,16 +3277,16 @@ impl AsByteSequence for NewListRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing NewListRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (list, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (list, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             NewListRequest {
@@ -3265,6 +3313,7 @@ impl Request for NewListRequest {
     const OPCODE: u8 = 101;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "NewList";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3288,12 +3337,12 @@ impl AsByteSequence for EndListRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing EndListRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             EndListRequest {
@@ -3313,6 +3362,7 @@ impl Request for EndListRequest {
     const OPCODE: u8 = 102;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "EndList";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3340,16 +3390,16 @@ impl AsByteSequence for DeleteListsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeleteListsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (list, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (list, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (range, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (range, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DeleteListsRequest {
@@ -3376,6 +3426,7 @@ impl Request for DeleteListsRequest {
     const OPCODE: u8 = 103;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DeleteLists";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3401,14 +3452,14 @@ impl AsByteSequence for GenListsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GenListsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (range, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (range, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GenListsRequest {
@@ -3429,6 +3480,7 @@ impl Request for GenListsRequest {
     const OPCODE: u8 = 104;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GenLists";
     type Reply = GenListsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3454,14 +3506,14 @@ impl AsByteSequence for GenListsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GenListsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ret_val, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ret_val, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GenListsReply {
@@ -3503,16 +3555,16 @@ impl AsByteSequence for FeedbackBufferRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FeedbackBufferRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (size, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (size, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             FeedbackBufferRequest {
@@ -3539,6 +3591,7 @@ impl Request for FeedbackBufferRequest {
     const OPCODE: u8 = 105;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FeedbackBuffer";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3564,14 +3617,14 @@ impl AsByteSequence for SelectBufferRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectBufferRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (size, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (size, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SelectBufferRequest {
@@ -3592,6 +3645,7 @@ impl Request for SelectBufferRequest {
     const OPCODE: u8 = 106;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SelectBuffer";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3617,14 +3671,14 @@ impl AsByteSequence for RenderModeRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing RenderModeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             RenderModeRequest {
@@ -3645,6 +3699,7 @@ impl Request for RenderModeRequest {
     const OPCODE: u8 = 107;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "RenderMode";
     type Reply = RenderModeReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3678,22 +3733,22 @@ impl AsByteSequence for RenderModeReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing RenderModeReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ret_val, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ret_val, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (new_mode, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (new_mode, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -3746,12 +3801,12 @@ impl AsByteSequence for FinishRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FinishRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             FinishRequest {
@@ -3771,6 +3826,7 @@ impl Request for FinishRequest {
     const OPCODE: u8 = 108;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Finish";
     type Reply = FinishReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3794,12 +3850,12 @@ impl AsByteSequence for FinishReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FinishReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             FinishReply {
@@ -3840,16 +3896,16 @@ impl AsByteSequence for PixelStorefRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PixelStorefRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PixelStorefRequest {
@@ -3876,6 +3932,7 @@ impl Request for PixelStorefRequest {
     const OPCODE: u8 = 109;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PixelStoref";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3903,16 +3960,16 @@ impl AsByteSequence for PixelStoreiRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PixelStoreiRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PixelStoreiRequest {
@@ -3939,6 +3996,7 @@ impl Request for PixelStoreiRequest {
     const OPCODE: u8 = 110;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PixelStorei";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3978,28 +4036,28 @@ impl AsByteSequence for ReadPixelsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ReadPixelsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (swap_bytes, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (swap_bytes, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (lsb_first, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (lsb_first, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ReadPixelsRequest {
@@ -4038,6 +4096,7 @@ impl Request for ReadPixelsRequest {
     const OPCODE: u8 = 111;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ReadPixels";
     type Reply = ReadPixelsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4066,16 +4125,16 @@ impl AsByteSequence for ReadPixelsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ReadPixelsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 24;
         let (data, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize) * (4)) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((length as usize) * (4)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -4120,14 +4179,14 @@ impl AsByteSequence for GetBooleanvRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetBooleanvRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetBooleanvRequest {
@@ -4148,6 +4207,7 @@ impl Request for GetBooleanvRequest {
     const OPCODE: u8 = 112;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetBooleanv";
     type Reply = GetBooleanvReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4180,21 +4240,21 @@ impl AsByteSequence for GetBooleanvReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetBooleanvReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 15;
         let (data, block_len): (Vec<bool>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<bool>());
         Some((
@@ -4248,14 +4308,14 @@ impl AsByteSequence for GetClipPlaneRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetClipPlaneRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (plane, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (plane, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetClipPlaneRequest {
@@ -4276,6 +4336,7 @@ impl Request for GetClipPlaneRequest {
     const OPCODE: u8 = 113;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetClipPlane";
     type Reply = GetClipPlaneReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4304,16 +4365,16 @@ impl AsByteSequence for GetClipPlaneReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetClipPlaneReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 24;
         let (data, block_len): (Vec<Float64>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize) / (2)) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((length as usize) / (2)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Float64>());
         Some((
@@ -4358,14 +4419,14 @@ impl AsByteSequence for GetDoublevRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDoublevRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetDoublevRequest {
@@ -4386,6 +4447,7 @@ impl Request for GetDoublevRequest {
     const OPCODE: u8 = 114;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetDoublev";
     type Reply = GetDoublevReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4418,21 +4480,21 @@ impl AsByteSequence for GetDoublevReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDoublevReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Float64, usize) = <Float64>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Float64, usize) = <Float64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 8;
         let (data, block_len): (Vec<Float64>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Float64>());
         Some((
@@ -4484,12 +4546,12 @@ impl AsByteSequence for GetErrorRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetErrorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetErrorRequest {
@@ -4509,6 +4571,7 @@ impl Request for GetErrorRequest {
     const OPCODE: u8 = 115;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetError";
     type Reply = GetErrorReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4534,14 +4597,14 @@ impl AsByteSequence for GetErrorReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetErrorReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (error, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (error, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetErrorReply {
@@ -4581,14 +4644,14 @@ impl AsByteSequence for GetFloatvRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetFloatvRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetFloatvRequest {
@@ -4609,6 +4672,7 @@ impl Request for GetFloatvRequest {
     const OPCODE: u8 = 116;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetFloatv";
     type Reply = GetFloatvReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4641,21 +4705,21 @@ impl AsByteSequence for GetFloatvReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetFloatvReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Float32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Float32>());
         Some((
@@ -4709,14 +4773,14 @@ impl AsByteSequence for GetIntegervRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetIntegervRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetIntegervRequest {
@@ -4737,6 +4801,7 @@ impl Request for GetIntegervRequest {
     const OPCODE: u8 = 117;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetIntegerv";
     type Reply = GetIntegervReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4769,21 +4834,21 @@ impl AsByteSequence for GetIntegervReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetIntegervReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
         Some((
@@ -4839,16 +4904,16 @@ impl AsByteSequence for GetLightfvRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetLightfvRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (light, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (light, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetLightfvRequest {
@@ -4875,6 +4940,7 @@ impl Request for GetLightfvRequest {
     const OPCODE: u8 = 118;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetLightfv";
     type Reply = GetLightfvReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4907,21 +4973,21 @@ impl AsByteSequence for GetLightfvReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetLightfvReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Float32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Float32>());
         Some((
@@ -4977,16 +5043,16 @@ impl AsByteSequence for GetLightivRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetLightivRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (light, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (light, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetLightivRequest {
@@ -5013,6 +5079,7 @@ impl Request for GetLightivRequest {
     const OPCODE: u8 = 119;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetLightiv";
     type Reply = GetLightivReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5045,21 +5112,21 @@ impl AsByteSequence for GetLightivReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetLightivReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
         Some((
@@ -5115,16 +5182,16 @@ impl AsByteSequence for GetMapdvRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMapdvRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (query, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (query, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetMapdvRequest {
@@ -5151,6 +5218,7 @@ impl Request for GetMapdvRequest {
     const OPCODE: u8 = 120;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetMapdv";
     type Reply = GetMapdvReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5183,21 +5251,21 @@ impl AsByteSequence for GetMapdvReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMapdvReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Float64, usize) = <Float64>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Float64, usize) = <Float64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 8;
         let (data, block_len): (Vec<Float64>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Float64>());
         Some((
@@ -5253,16 +5321,16 @@ impl AsByteSequence for GetMapfvRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMapfvRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (query, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (query, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetMapfvRequest {
@@ -5289,6 +5357,7 @@ impl Request for GetMapfvRequest {
     const OPCODE: u8 = 121;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetMapfv";
     type Reply = GetMapfvReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5321,21 +5390,21 @@ impl AsByteSequence for GetMapfvReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMapfvReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Float32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Float32>());
         Some((
@@ -5391,16 +5460,16 @@ impl AsByteSequence for GetMapivRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMapivRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (query, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (query, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetMapivRequest {
@@ -5427,6 +5496,7 @@ impl Request for GetMapivRequest {
     const OPCODE: u8 = 122;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetMapiv";
     type Reply = GetMapivReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5459,21 +5529,21 @@ impl AsByteSequence for GetMapivReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMapivReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
         Some((
@@ -5529,16 +5599,16 @@ impl AsByteSequence for GetMaterialfvRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMaterialfvRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (face, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (face, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetMaterialfvRequest {
@@ -5565,6 +5635,7 @@ impl Request for GetMaterialfvRequest {
     const OPCODE: u8 = 123;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetMaterialfv";
     type Reply = GetMaterialfvReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5597,21 +5668,21 @@ impl AsByteSequence for GetMaterialfvReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMaterialfvReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Float32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Float32>());
         Some((
@@ -5667,16 +5738,16 @@ impl AsByteSequence for GetMaterialivRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMaterialivRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (face, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (face, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetMaterialivRequest {
@@ -5703,6 +5774,7 @@ impl Request for GetMaterialivRequest {
     const OPCODE: u8 = 124;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetMaterialiv";
     type Reply = GetMaterialivReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5735,21 +5807,21 @@ impl AsByteSequence for GetMaterialivReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMaterialivReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
         Some((
@@ -5803,14 +5875,14 @@ impl AsByteSequence for GetPixelMapfvRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPixelMapfvRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (map, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (map, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetPixelMapfvRequest {
@@ -5831,6 +5903,7 @@ impl Request for GetPixelMapfvRequest {
     const OPCODE: u8 = 125;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetPixelMapfv";
     type Reply = GetPixelMapfvReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5863,21 +5936,21 @@ impl AsByteSequence for GetPixelMapfvReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPixelMapfvReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Float32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Float32>());
         Some((
@@ -5931,14 +6004,14 @@ impl AsByteSequence for GetPixelMapuivRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPixelMapuivRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (map, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (map, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetPixelMapuivRequest {
@@ -5959,6 +6032,7 @@ impl Request for GetPixelMapuivRequest {
     const OPCODE: u8 = 126;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetPixelMapuiv";
     type Reply = GetPixelMapuivReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5991,21 +6065,21 @@ impl AsByteSequence for GetPixelMapuivReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPixelMapuivReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -6059,14 +6133,14 @@ impl AsByteSequence for GetPixelMapusvRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPixelMapusvRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (map, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (map, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetPixelMapusvRequest {
@@ -6087,6 +6161,7 @@ impl Request for GetPixelMapusvRequest {
     const OPCODE: u8 = 127;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetPixelMapusv";
     type Reply = GetPixelMapusvReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -6119,21 +6194,21 @@ impl AsByteSequence for GetPixelMapusvReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPixelMapusvReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         let (data, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
         Some((
@@ -6187,14 +6262,14 @@ impl AsByteSequence for GetPolygonStippleRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPolygonStippleRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (lsb_first, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (lsb_first, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetPolygonStippleRequest {
@@ -6219,6 +6294,7 @@ impl Request for GetPolygonStippleRequest {
     const OPCODE: u8 = 128;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetPolygonStipple";
     type Reply = GetPolygonStippleReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -6247,16 +6323,16 @@ impl AsByteSequence for GetPolygonStippleReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPolygonStippleReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 24;
         let (data, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize) * (4)) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((length as usize) * (4)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -6301,14 +6377,14 @@ impl AsByteSequence for GetStringRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetStringRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (name, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (name, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetStringRequest {
@@ -6329,6 +6405,7 @@ impl Request for GetStringRequest {
     const OPCODE: u8 = 129;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetString";
     type Reply = GetStringReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -6359,19 +6436,19 @@ impl AsByteSequence for GetStringReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetStringReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         let (string, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -6425,16 +6502,16 @@ impl AsByteSequence for GetTexEnvfvRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetTexEnvfvRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetTexEnvfvRequest {
@@ -6461,6 +6538,7 @@ impl Request for GetTexEnvfvRequest {
     const OPCODE: u8 = 130;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetTexEnvfv";
     type Reply = GetTexEnvfvReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -6493,21 +6571,21 @@ impl AsByteSequence for GetTexEnvfvReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetTexEnvfvReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Float32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Float32>());
         Some((
@@ -6563,16 +6641,16 @@ impl AsByteSequence for GetTexEnvivRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetTexEnvivRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetTexEnvivRequest {
@@ -6599,6 +6677,7 @@ impl Request for GetTexEnvivRequest {
     const OPCODE: u8 = 131;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetTexEnviv";
     type Reply = GetTexEnvivReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -6631,21 +6710,21 @@ impl AsByteSequence for GetTexEnvivReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetTexEnvivReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
         Some((
@@ -6701,16 +6780,16 @@ impl AsByteSequence for GetTexGendvRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetTexGendvRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (coord, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (coord, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetTexGendvRequest {
@@ -6737,6 +6816,7 @@ impl Request for GetTexGendvRequest {
     const OPCODE: u8 = 132;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetTexGendv";
     type Reply = GetTexGendvReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -6769,21 +6849,21 @@ impl AsByteSequence for GetTexGendvReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetTexGendvReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Float64, usize) = <Float64>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Float64, usize) = <Float64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 8;
         let (data, block_len): (Vec<Float64>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Float64>());
         Some((
@@ -6839,16 +6919,16 @@ impl AsByteSequence for GetTexGenfvRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetTexGenfvRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (coord, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (coord, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetTexGenfvRequest {
@@ -6875,6 +6955,7 @@ impl Request for GetTexGenfvRequest {
     const OPCODE: u8 = 133;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetTexGenfv";
     type Reply = GetTexGenfvReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -6907,21 +6988,21 @@ impl AsByteSequence for GetTexGenfvReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetTexGenfvReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Float32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Float32>());
         Some((
@@ -6977,16 +7058,16 @@ impl AsByteSequence for GetTexGenivRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetTexGenivRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (coord, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (coord, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetTexGenivRequest {
@@ -7013,6 +7094,7 @@ impl Request for GetTexGenivRequest {
     const OPCODE: u8 = 134;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetTexGeniv";
     type Reply = GetTexGenivReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -7045,21 +7127,21 @@ impl AsByteSequence for GetTexGenivReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetTexGenivReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
         Some((
@@ -7121,22 +7203,22 @@ impl AsByteSequence for GetTexImageRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetTexImageRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (level, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (level, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (swap_bytes, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (swap_bytes, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetTexImageRequest {
@@ -7169,6 +7251,7 @@ impl Request for GetTexImageRequest {
     const OPCODE: u8 = 135;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetTexImage";
     type Reply = GetTexImageReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -7204,23 +7287,23 @@ impl AsByteSequence for GetTexImageReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetTexImageReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 8;
-        let (width, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (depth, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
         let (data, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize) * (4)) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((length as usize) * (4)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -7279,16 +7362,16 @@ impl AsByteSequence for GetTexParameterfvRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetTexParameterfvRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetTexParameterfvRequest {
@@ -7315,6 +7398,7 @@ impl Request for GetTexParameterfvRequest {
     const OPCODE: u8 = 136;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetTexParameterfv";
     type Reply = GetTexParameterfvReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -7347,21 +7431,21 @@ impl AsByteSequence for GetTexParameterfvReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetTexParameterfvReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Float32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Float32>());
         Some((
@@ -7417,16 +7501,16 @@ impl AsByteSequence for GetTexParameterivRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetTexParameterivRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetTexParameterivRequest {
@@ -7453,6 +7537,7 @@ impl Request for GetTexParameterivRequest {
     const OPCODE: u8 = 137;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetTexParameteriv";
     type Reply = GetTexParameterivReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -7485,21 +7570,21 @@ impl AsByteSequence for GetTexParameterivReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetTexParameterivReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
         Some((
@@ -7557,18 +7642,18 @@ impl AsByteSequence for GetTexLevelParameterfvRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetTexLevelParameterfvRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (level, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (level, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetTexLevelParameterfvRequest {
@@ -7597,6 +7682,7 @@ impl Request for GetTexLevelParameterfvRequest {
     const OPCODE: u8 = 138;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetTexLevelParameterfv";
     type Reply = GetTexLevelParameterfvReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -7629,21 +7715,21 @@ impl AsByteSequence for GetTexLevelParameterfvReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetTexLevelParameterfvReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Float32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Float32>());
         Some((
@@ -7701,18 +7787,18 @@ impl AsByteSequence for GetTexLevelParameterivRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetTexLevelParameterivRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (level, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (level, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetTexLevelParameterivRequest {
@@ -7741,6 +7827,7 @@ impl Request for GetTexLevelParameterivRequest {
     const OPCODE: u8 = 139;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetTexLevelParameteriv";
     type Reply = GetTexLevelParameterivReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -7773,21 +7860,21 @@ impl AsByteSequence for GetTexLevelParameterivReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetTexLevelParameterivReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
         Some((
@@ -7841,14 +7928,14 @@ impl AsByteSequence for IsEnabledRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing IsEnabledRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (capability, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (capability, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             IsEnabledRequest {
@@ -7873,6 +7960,7 @@ impl Request for IsEnabledRequest {
     const OPCODE: u8 = 140;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "IsEnabled";
     type Reply = IsEnabledReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -7898,14 +7986,14 @@ impl AsByteSequence for IsEnabledReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing IsEnabledReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ret_val, sz): (Bool32, usize) = <Bool32>::from_bytes(&bytes[index..])?;
+        let (ret_val, sz): (Bool32, usize) = <Bool32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             IsEnabledReply {
@@ -7945,14 +8033,14 @@ impl AsByteSequence for IsListRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing IsListRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (list, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (list, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             IsListRequest {
@@ -7973,6 +8061,7 @@ impl Request for IsListRequest {
     const OPCODE: u8 = 141;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "IsList";
     type Reply = IsListReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -7998,14 +8087,14 @@ impl AsByteSequence for IsListReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing IsListReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ret_val, sz): (Bool32, usize) = <Bool32>::from_bytes(&bytes[index..])?;
+        let (ret_val, sz): (Bool32, usize) = <Bool32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             IsListReply {
@@ -8043,12 +8132,12 @@ impl AsByteSequence for FlushRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FlushRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             FlushRequest {
@@ -8068,6 +8157,7 @@ impl Request for FlushRequest {
     const OPCODE: u8 = 142;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Flush";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -8096,17 +8186,17 @@ impl AsByteSequence for AreTexturesResidentRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AreTexturesResidentRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (textures, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -8137,6 +8227,7 @@ impl Request for AreTexturesResidentRequest {
     const OPCODE: u8 = 143;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "AreTexturesResident";
     type Reply = AreTexturesResidentReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -8167,18 +8258,18 @@ impl AsByteSequence for AreTexturesResidentReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AreTexturesResidentReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ret_val, sz): (Bool32, usize) = <Bool32>::from_bytes(&bytes[index..])?;
+        let (ret_val, sz): (Bool32, usize) = <Bool32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (data, block_len): (Vec<bool>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize) * (4)) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((length as usize) * (4)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<bool>());
         Some((
@@ -8233,17 +8324,17 @@ impl AsByteSequence for DeleteTexturesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeleteTexturesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (textures, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -8274,6 +8365,7 @@ impl Request for DeleteTexturesRequest {
     const OPCODE: u8 = 144;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DeleteTextures";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -8299,14 +8391,14 @@ impl AsByteSequence for GenTexturesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GenTexturesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (n, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GenTexturesRequest {
@@ -8327,6 +8419,7 @@ impl Request for GenTexturesRequest {
     const OPCODE: u8 = 145;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GenTextures";
     type Reply = GenTexturesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -8355,16 +8448,16 @@ impl AsByteSequence for GenTexturesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GenTexturesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 24;
         let (data, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (length as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (length as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -8409,14 +8502,14 @@ impl AsByteSequence for IsTextureRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing IsTextureRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (texture, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (texture, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             IsTextureRequest {
@@ -8441,6 +8534,7 @@ impl Request for IsTextureRequest {
     const OPCODE: u8 = 146;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "IsTexture";
     type Reply = IsTextureReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -8466,14 +8560,14 @@ impl AsByteSequence for IsTextureReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing IsTextureReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ret_val, sz): (Bool32, usize) = <Bool32>::from_bytes(&bytes[index..])?;
+        let (ret_val, sz): (Bool32, usize) = <Bool32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             IsTextureReply {
@@ -8519,20 +8613,20 @@ impl AsByteSequence for GetColorTableRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetColorTableRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (swap_bytes, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (swap_bytes, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetColorTableRequest {
@@ -8563,6 +8657,7 @@ impl Request for GetColorTableRequest {
     const OPCODE: u8 = 147;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetColorTable";
     type Reply = GetColorTableReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -8594,19 +8689,19 @@ impl AsByteSequence for GetColorTableReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetColorTableReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 8;
-        let (width, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize) * (4)) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((length as usize) * (4)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -8661,16 +8756,16 @@ impl AsByteSequence for GetColorTableParameterfvRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetColorTableParameterfvRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetColorTableParameterfvRequest {
@@ -8697,6 +8792,7 @@ impl Request for GetColorTableParameterfvRequest {
     const OPCODE: u8 = 148;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetColorTableParameterfv";
     type Reply = GetColorTableParameterfvReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -8729,21 +8825,21 @@ impl AsByteSequence for GetColorTableParameterfvReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetColorTableParameterfvReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Float32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Float32>());
         Some((
@@ -8799,16 +8895,16 @@ impl AsByteSequence for GetColorTableParameterivRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetColorTableParameterivRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetColorTableParameterivRequest {
@@ -8835,6 +8931,7 @@ impl Request for GetColorTableParameterivRequest {
     const OPCODE: u8 = 149;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetColorTableParameteriv";
     type Reply = GetColorTableParameterivReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -8867,21 +8964,21 @@ impl AsByteSequence for GetColorTableParameterivReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetColorTableParameterivReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
         Some((
@@ -8941,20 +9038,20 @@ impl AsByteSequence for GetConvolutionFilterRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetConvolutionFilterRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (swap_bytes, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (swap_bytes, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetConvolutionFilterRequest {
@@ -8985,6 +9082,7 @@ impl Request for GetConvolutionFilterRequest {
     const OPCODE: u8 = 150;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetConvolutionFilter";
     type Reply = GetConvolutionFilterReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -9008,6 +9106,7 @@ impl AsByteSequence for GetConvolutionFilterReply {
         index += 8;
         index += self.width.as_bytes(&mut bytes[index..]);
         index += self.height.as_bytes(&mut bytes[index..]);
+        index += 8;
         let block_len: usize = vector_as_bytes(&self.data, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
@@ -9017,20 +9116,21 @@ impl AsByteSequence for GetConvolutionFilterReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetConvolutionFilterReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 8;
-        let (width, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
+        index += 8;
         let (data, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize) * (4)) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((length as usize) * (4)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -9054,6 +9154,7 @@ impl AsByteSequence for GetConvolutionFilterReply {
             + 8
             + self.width.size()
             + self.height.size()
+            + 8
             + {
                 let block_len: usize = self.data.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
@@ -9086,16 +9187,16 @@ impl AsByteSequence for GetConvolutionParameterfvRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetConvolutionParameterfvRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetConvolutionParameterfvRequest {
@@ -9122,6 +9223,7 @@ impl Request for GetConvolutionParameterfvRequest {
     const OPCODE: u8 = 151;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetConvolutionParameterfv";
     type Reply = GetConvolutionParameterfvReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -9154,21 +9256,21 @@ impl AsByteSequence for GetConvolutionParameterfvReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetConvolutionParameterfvReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Float32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Float32>());
         Some((
@@ -9224,16 +9326,16 @@ impl AsByteSequence for GetConvolutionParameterivRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetConvolutionParameterivRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetConvolutionParameterivRequest {
@@ -9260,6 +9362,7 @@ impl Request for GetConvolutionParameterivRequest {
     const OPCODE: u8 = 152;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetConvolutionParameteriv";
     type Reply = GetConvolutionParameterivReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -9292,21 +9395,21 @@ impl AsByteSequence for GetConvolutionParameterivReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetConvolutionParameterivReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
         Some((
@@ -9366,20 +9469,20 @@ impl AsByteSequence for GetSeparableFilterRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetSeparableFilterRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (swap_bytes, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (swap_bytes, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetSeparableFilterRequest {
@@ -9410,6 +9513,7 @@ impl Request for GetSeparableFilterRequest {
     const OPCODE: u8 = 153;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetSeparableFilter";
     type Reply = GetSeparableFilterReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -9433,6 +9537,7 @@ impl AsByteSequence for GetSeparableFilterReply {
         index += 8;
         index += self.row_w.as_bytes(&mut bytes[index..]);
         index += self.col_h.as_bytes(&mut bytes[index..]);
+        index += 8;
         let block_len: usize = vector_as_bytes(&self.rows_and_cols, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
@@ -9442,20 +9547,21 @@ impl AsByteSequence for GetSeparableFilterReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetSeparableFilterReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 8;
-        let (row_w, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (row_w, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (col_h, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (col_h, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
+        index += 8;
         let (rows_and_cols, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize) * (4)) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((length as usize) * (4)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -9479,6 +9585,7 @@ impl AsByteSequence for GetSeparableFilterReply {
             + 8
             + self.row_w.size()
             + self.col_h.size()
+            + 8
             + {
                 let block_len: usize = self.rows_and_cols.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Byte>());
@@ -9517,22 +9624,22 @@ impl AsByteSequence for GetHistogramRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetHistogramRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (swap_bytes, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (swap_bytes, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (reset, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (reset, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetHistogramRequest {
@@ -9565,6 +9672,7 @@ impl Request for GetHistogramRequest {
     const OPCODE: u8 = 154;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetHistogram";
     type Reply = GetHistogramReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -9596,19 +9704,19 @@ impl AsByteSequence for GetHistogramReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetHistogramReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 8;
-        let (width, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize) * (4)) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((length as usize) * (4)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -9663,16 +9771,16 @@ impl AsByteSequence for GetHistogramParameterfvRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetHistogramParameterfvRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetHistogramParameterfvRequest {
@@ -9699,6 +9807,7 @@ impl Request for GetHistogramParameterfvRequest {
     const OPCODE: u8 = 155;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetHistogramParameterfv";
     type Reply = GetHistogramParameterfvReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -9731,21 +9840,21 @@ impl AsByteSequence for GetHistogramParameterfvReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetHistogramParameterfvReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Float32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Float32>());
         Some((
@@ -9801,16 +9910,16 @@ impl AsByteSequence for GetHistogramParameterivRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetHistogramParameterivRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetHistogramParameterivRequest {
@@ -9837,6 +9946,7 @@ impl Request for GetHistogramParameterivRequest {
     const OPCODE: u8 = 156;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetHistogramParameteriv";
     type Reply = GetHistogramParameterivReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -9869,21 +9979,21 @@ impl AsByteSequence for GetHistogramParameterivReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetHistogramParameterivReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
         Some((
@@ -9945,22 +10055,22 @@ impl AsByteSequence for GetMinmaxRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMinmaxRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (swap_bytes, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (swap_bytes, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (reset, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (reset, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetMinmaxRequest {
@@ -9993,6 +10103,7 @@ impl Request for GetMinmaxRequest {
     const OPCODE: u8 = 157;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetMinmax";
     type Reply = GetMinmaxReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -10021,16 +10132,16 @@ impl AsByteSequence for GetMinmaxReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMinmaxReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 24;
         let (data, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize) * (4)) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((length as usize) * (4)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -10077,16 +10188,16 @@ impl AsByteSequence for GetMinmaxParameterfvRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMinmaxParameterfvRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetMinmaxParameterfvRequest {
@@ -10113,6 +10224,7 @@ impl Request for GetMinmaxParameterfvRequest {
     const OPCODE: u8 = 158;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetMinmaxParameterfv";
     type Reply = GetMinmaxParameterfvReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -10145,21 +10257,21 @@ impl AsByteSequence for GetMinmaxParameterfvReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMinmaxParameterfvReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Float32, usize) = <Float32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Float32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Float32>());
         Some((
@@ -10215,16 +10327,16 @@ impl AsByteSequence for GetMinmaxParameterivRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMinmaxParameterivRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetMinmaxParameterivRequest {
@@ -10251,6 +10363,7 @@ impl Request for GetMinmaxParameterivRequest {
     const OPCODE: u8 = 159;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetMinmaxParameteriv";
     type Reply = GetMinmaxParameterivReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -10283,21 +10396,21 @@ impl AsByteSequence for GetMinmaxParameterivReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMinmaxParameterivReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
         Some((
@@ -10353,16 +10466,16 @@ impl AsByteSequence for GetCompressedTexImageArbRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCompressedTexImageArbRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (level, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (level, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetCompressedTexImageArbRequest {
@@ -10389,6 +10502,7 @@ impl Request for GetCompressedTexImageArbRequest {
     const OPCODE: u8 = 160;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetCompressedTexImageArb";
     type Reply = GetCompressedTexImageArbReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -10420,19 +10534,19 @@ impl AsByteSequence for GetCompressedTexImageArbReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCompressedTexImageArbReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 8;
-        let (size, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (size, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize) * (4)) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((length as usize) * (4)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -10488,17 +10602,17 @@ impl AsByteSequence for DeleteQueriesArbRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeleteQueriesArbRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (ids, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -10529,6 +10643,7 @@ impl Request for DeleteQueriesArbRequest {
     const OPCODE: u8 = 161;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DeleteQueriesArb";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -10554,14 +10669,14 @@ impl AsByteSequence for GenQueriesArbRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GenQueriesArbRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (n, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GenQueriesArbRequest {
@@ -10582,6 +10697,7 @@ impl Request for GenQueriesArbRequest {
     const OPCODE: u8 = 162;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GenQueriesArb";
     type Reply = GenQueriesArbReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -10610,16 +10726,16 @@ impl AsByteSequence for GenQueriesArbReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GenQueriesArbReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 24;
         let (data, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (length as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (length as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -10664,14 +10780,14 @@ impl AsByteSequence for IsQueryArbRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing IsQueryArbRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             IsQueryArbRequest {
@@ -10692,6 +10808,7 @@ impl Request for IsQueryArbRequest {
     const OPCODE: u8 = 163;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "IsQueryArb";
     type Reply = IsQueryArbReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -10717,14 +10834,14 @@ impl AsByteSequence for IsQueryArbReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing IsQueryArbReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ret_val, sz): (Bool32, usize) = <Bool32>::from_bytes(&bytes[index..])?;
+        let (ret_val, sz): (Bool32, usize) = <Bool32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             IsQueryArbReply {
@@ -10766,16 +10883,16 @@ impl AsByteSequence for GetQueryivArbRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetQueryivArbRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetQueryivArbRequest {
@@ -10802,6 +10919,7 @@ impl Request for GetQueryivArbRequest {
     const OPCODE: u8 = 164;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetQueryivArb";
     type Reply = GetQueryivArbReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -10834,21 +10952,21 @@ impl AsByteSequence for GetQueryivArbReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetQueryivArbReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
         Some((
@@ -10904,16 +11022,16 @@ impl AsByteSequence for GetQueryObjectivArbRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetQueryObjectivArbRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetQueryObjectivArbRequest {
@@ -10940,6 +11058,7 @@ impl Request for GetQueryObjectivArbRequest {
     const OPCODE: u8 = 165;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetQueryObjectivArb";
     type Reply = GetQueryObjectivArbReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -10972,21 +11091,21 @@ impl AsByteSequence for GetQueryObjectivArbReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetQueryObjectivArbReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
         Some((
@@ -11042,16 +11161,16 @@ impl AsByteSequence for GetQueryObjectuivArbRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetQueryObjectuivArbRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(&bytes[index..])?;
+        let (context_tag, sz): (ContextTag, usize) = <ContextTag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pname, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetQueryObjectuivArbRequest {
@@ -11078,6 +11197,7 @@ impl Request for GetQueryObjectuivArbRequest {
     const OPCODE: u8 = 166;
     const EXTENSION: Option<&'static str> = Some("GLX");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetQueryObjectuivArb";
     type Reply = GetQueryObjectuivArbReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -11110,21 +11230,21 @@ impl AsByteSequence for GetQueryObjectuivArbReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetQueryObjectuivArbReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (datum, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (datum, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -11711,26 +11831,26 @@ impl AsByteSequence for BufferSwapCompleteEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing BufferSwapCompleteEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_type_, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (event_type_, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (drawable, sz): (super::glx::Drawable, usize) =
-            <super::glx::Drawable>::from_bytes(&bytes[index..])?;
+            <super::glx::Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ust_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ust_hi, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ust_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ust_lo, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (msc_hi, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (msc_hi, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (msc_lo, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (msc_lo, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sbc, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (sbc, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             BufferSwapCompleteEvent {
@@ -11805,31 +11925,31 @@ impl AsByteSequence for PbufferClobberEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PbufferClobberEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_type_, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (event_type_, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (draw_type, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (draw_type, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (drawable, sz): (super::glx::Drawable, usize) =
-            <super::glx::Drawable>::from_bytes(&bytes[index..])?;
+            <super::glx::Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (b_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (b_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (aux_buffer, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (aux_buffer, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (count, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (count, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
         Some((
@@ -17,8 +17,8 @@ use core::mem;
 #[allow(dead_code)]
 pub(crate) mod prelude {
     pub(crate) use super::{
-        buffer_pad, string_as_bytes, string_from_bytes, vector_as_bytes, vector_from_bytes,
-        AsByteSequence,
+        as_bytes_with_width, buffer_pad, from_bytes_with_width, string_as_bytes, string_from_bytes,
+        vector_as_bytes, vector_from_bytes, AsByteSequence,
     };
     pub use crate::{client_message_data::ClientMessageData, Fd, Request, XidType, XID};
     pub use alloc::{string::String, vec, vec::Vec};
@@ -85,13 +85,14 @@ pub(crate) fn vector_from_bytes<T: AsByteSequence>(
     #[cfg(debug_assertions)]
     log::trace!("Deserializing vector of byte length {} from bytes", len);
 
-    // allocate the vector
-    let mut items: Vec<T> = Vec::with_capacity(len);
+    // allocate the vector; the length comes from the server, so don't trust it any further than the number
+    // of bytes that are actually there
+    let mut items: Vec<T> = Vec::with_capacity(len.min(bytes.len()));
 
     // pull items from the bytes vector and create elements
     let mut current_index = 0;
     for _ in 0..len {
-        let (item, sz) = T::from_bytes(&bytes[current_index..])?;
+        let (item, sz) = T::from_bytes(bytes.get(current_index..)?)?;
         items.push(item);
         current_index += sz;
     }
@@ -104,7 +105,7 @@ pub(crate) fn vector_from_bytes<T: AsByteSequence>(
 pub(crate) fn string_from_bytes(bytes: &[u8], len: usize) -> Option<(String, usize)> {
     log::trace!("Deserializing string of length {} from bytes", len);
 
-    let chars: Vec<u8> = bytes.iter().take(len).copied().collect();
+    let chars: Vec<u8> = bytes.get(..len)?.to_vec();
 
     // convert bytes into a real string
     match String::from_utf8(chars) {
@@ -142,6 +143,60 @@ pub(crate) fn string_as_bytes(string: &str, bytes: &mut [u8]) -> usize {
     vector_as_bytes(string.as_bytes(), bytes)
 }
 
+/// Internal use function to write an enum or a mask into a field that is `width` bytes wide, for fields the
+/// protocol sends in a different width than the type's own. The value is truncated if it doesn't fit.
+#[inline]
+pub(crate) fn as_bytes_with_width<T: AsByteSequence>(
+    item: &T,
+    width: usize,
+    bytes: &mut [u8],
+) -> usize {
+    let mut own = [0; 8];
+    let len = item.as_bytes(&mut own);
+    uint_as_bytes(
+        uint_from_bytes(&own[..len]).unwrap_or(0),
+        &mut bytes[..width],
+    );
+    width
+}
+
+/// Internal use function to read an enum or a mask out of a field that is `width` bytes wide.
+#[inline]
+pub(crate) fn from_bytes_with_width<T: AsByteSequence + Default>(
+    bytes: &[u8],
+    width: usize,
+) -> Option<(T, usize)> {
+    let value = uint_from_bytes(bytes.get(..width)?)?;
+    let mut own = [0; 8];
+    let own = own.get_mut(..T::default().size())?;
+    uint_as_bytes(value, own);
+    let (item, _) = T::from_bytes(own)?;
+    Some((item, width))
+}
+
+/// Read an unsigned integer as wide as the given bytes.
+#[inline]
+fn uint_from_bytes(bytes: &[u8]) -> Option<u64> {
+    Some(match bytes.len() {
+        1 => u8::from_bytes(bytes)?.0.into(),
+        2 => u16::from_bytes(bytes)?.0.into(),
+        4 => u32::from_bytes(bytes)?.0.into(),
+        _ => u64::from_bytes(bytes)?.0,
+    })
+}
+
+/// Write an unsigned integer as wide as the given bytes, truncating it if it doesn't fit.
+#[allow(clippy::cast_possible_truncation)]
+#[inline]
+fn uint_as_bytes(value: u64, bytes: &mut [u8]) {
+    match bytes.len() {
+        1 => (value as u8).as_bytes(bytes),
+        2 => (value as u16).as_bytes(bytes),
+        4 => (value as u32).as_bytes(bytes),
+        _ => value.as_bytes(bytes),
+    };
+}
+
 /// The addition necessary to pad out the buffer, given the align and the current block length.
 #[inline]
 pub(crate) const fn buffer_pad(block_len: usize, align_to: usize) -> usize {
@@ -162,7 +217,7 @@ impl AsByteSequence for u8 {
 
     #[inline]
     fn from_bytes(bytes: &[u8]) -> Option<(u8, usize)> {
-        Some((bytes[0], 1))
+        Some((*bytes.first()?, 1))
     }
 }
 
@@ -180,7 +235,7 @@ impl AsByteSequence for i8 {
 
     #[inline]
     fn from_bytes(bytes: &[u8]) -> Option<(i8, usize)> {
-        Some((bytes[0] as i8, 1))
+        Some((*bytes.first()? as i8, 1))
     }
 }
 
@@ -198,7 +253,7 @@ impl AsByteSequence for bool {
 
     #[inline]
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        match bytes[0] {
+        match *bytes.first()? {
             0 => Some((false, 1)),
             _ => Some((true, 1)),
         }
@@ -242,13 +297,10 @@ macro_rules! impl_fundamental_num {
                 #[cfg(debug_assertions)]
                 log::trace!("Deserializing {} from bytes", stringify!($t));
 
-                cfg_if::cfg_if! {
-                    if #[cfg(debug_assertions)] {
-                        if bytes.len() < $sz {
-                            log::error!("There are {} bytes in the slice, expected {}", bytes.len(), $sz);
-                            return None;
-                        }
-                    }
+                // these bytes come from the server, so a short buffer is an error rather than a bug
+                if bytes.len() < $sz {
+                    log::error!("There are {} bytes in the slice, expected {}", bytes.len(), $sz);
+                    return None;
                 }
 
                 let mut bytes_array: [u8; $sz] = [0; $sz];
@@ -291,7 +343,7 @@ macro_rules! impl_array {
                 log::trace!("Deserializing an array of length {}", $len);
 
                 for _ in 0..($len) {
-                    let (item, sz) = T::from_bytes(&bytes[index..])?;
+                    let (item, sz) = T::from_bytes(bytes.get(index..)?)?;
                     if let Some(_) = v.try_push(item) {
                         log::error!("Array of {} elements overflowed", $len);
                         return None;
@@ -327,8 +379,8 @@ impl AsByteSequence for String {
         log::trace!("Deserializing string of unknown length");
 
         let end = match memchr::memrchr(0, bytes) {
-            Some(posn) => posn - 1,
-            None => bytes.len() - 1,
+            Some(posn) => posn.saturating_sub(1),
+            None => bytes.len().saturating_sub(1),
         };
 
         let s = String::from_utf8_lossy(&bytes[..end]).to_string();
@@ -340,6 +392,8 @@ impl AsByteSequence for String {
 pub mod bigreq;
 #[cfg(feature = "damage")]
 pub mod damage;
+#[cfg(feature = "dbe")]
+pub mod dbe;
 #[cfg(feature = "dpms")]
 pub mod dpms;
 #[cfg(feature = "dri2")]
@@ -397,3 +451,169 @@ pub mod xtest;
 pub mod xv;
 #[cfg(feature = "xvmc")]
 pub mod xvmc;
+
+/// Generated from the `<pad align>` test in the generator, so that its output is compiled and exercised.
+#[cfg(test)]
+mod aligntest;
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        xproto::{
+            Depth, GetGeometryReply, GetPropertyReply, ListFontsWithInfoReply, Screen, Setup,
+            Visualtype,
+        },
+        AsByteSequence,
+    };
+    use alloc::{string::String, vec, vec::Vec};
+
+    /// Parse every truncation of the value's bytes, and a pile of random bytes, none of which may panic. The
+    /// random bytes come from a fixed seed, so a failure can be reproduced.
+    fn survives_bad_input<T: AsByteSequence>(value: &T) {
+        let mut bytes = vec![0; value.size()];
+        let len = value.as_bytes(&mut bytes);
+        assert!(T::from_bytes(&bytes[..len]).is_some());
+        assert!(T::from_bytes(&[]).is_none());
+        for end in 0..len {
+            let _ = T::from_bytes(&bytes[..end]);
+        }
+
+        let rng = fastrand::Rng::with_seed(0x6272_6561_6478);
+        for _ in 0..256 {
+            let garbage: Vec<u8> = (0..rng.usize(..len * 2)).map(|_| rng.u8(..)).collect();
+            let _ = T::from_bytes(&garbage);
+        }
+    }
+
+    #[test]
+    fn short_input_does_not_panic() {
+        survives_bad_input(&0u32);
+        survives_bad_input(&true);
+        survives_bad_input(&GetGeometryReply::default());
+        survives_bad_input(&GetPropertyReply {
+            value: vec![1, 2, 3, 4, 5],
+            ..Default::default()
+        });
+        survives_bad_input(&ListFontsWithInfoReply {
+            name: String::from("fixed"),
+            ..Default::default()
+        });
+        survives_bad_input(&Setup {
+            vendor: String::from("breadx"),
+            roots: vec![Screen {
+                allowed_depths: vec![Depth {
+                    depth: 24,
+                    visuals: vec![Visualtype::default()],
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn short_request_length_is_rejected() {
+        use super::xproto::QueryTextExtentsRequest;
+
+        // a length of one unit is shorter than the request's own header
+        let mut bytes = vec![0; 8];
+        QueryTextExtentsRequest {
+            req_type: 48,
+            length: 1,
+            ..Default::default()
+        }
+        .as_bytes(&mut bytes);
+        assert!(QueryTextExtentsRequest::from_bytes(&bytes).is_none());
+    }
+
+    #[cfg(feature = "input")]
+    #[test]
+    fn input_event_is_not_parsed() {
+        assert!(crate::event::input::InputEvent::from_bytes(&[0; 32]).is_none());
+    }
+
+    #[test]
+    fn masks_are_sent_in_the_field_width() {
+        use super::xproto::{
+            ChangeActivePointerGrabRequest, EventMask, GetWindowAttributesReply, GrabButtonRequest,
+            GrabPointerRequest,
+        };
+
+        // EventMask is 32 bits wide, but these requests only have room for 16
+        assert_eq!(GrabPointerRequest::default().size(), 24);
+        assert_eq!(GrabButtonRequest::default().size(), 24);
+        assert_eq!(ChangeActivePointerGrabRequest::default().size(), 16);
+        assert_eq!(GetWindowAttributesReply::default().size(), 44);
+
+        let req = GrabPointerRequest {
+            event_mask: EventMask { inner: 0x0000_8004 },
+            time: 0x1234_5678,
+            ..Default::default()
+        };
+        let mut bytes = vec![0; req.size()];
+        assert_eq!(req.as_bytes(&mut bytes), 24);
+        assert_eq!(&bytes[8..10], &0x8004u16.to_ne_bytes());
+        assert_eq!(&bytes[20..], &0x1234_5678u32.to_ne_bytes());
+        let (parsed, len) = GrabPointerRequest::from_bytes(&bytes).unwrap();
+        assert_eq!(len, 24);
+        assert_eq!(parsed.event_mask.inner, 0x8004);
+        assert_eq!(parsed.time, 0x1234_5678);
+    }
+
+    #[test]
+    fn narrow_fields_round_trip() {
+        use super::{
+            as_bytes_with_width, from_bytes_with_width,
+            xproto::{EventMask, GetWindowAttributesReply},
+        };
+
+        // only the narrow field loses its upper bits
+        let mut bytes = [0; 2];
+        assert_eq!(
+            as_bytes_with_width(&EventMask { inner: 0x0001_8004 }, 2, &mut bytes),
+            2
+        );
+        assert_eq!(bytes, 0x8004u16.to_ne_bytes());
+        let (mask, len): (EventMask, usize) = from_bytes_with_width(&bytes, 2).unwrap();
+        assert_eq!((mask.inner, len), (0x8004, 2));
+        assert!(from_bytes_with_width::<EventMask>(&bytes[..1], 2).is_none());
+
+        let repl = GetWindowAttributesReply {
+            all_event_masks: EventMask { inner: 0x00ff_0001 },
+            your_event_mask: EventMask { inner: 0x0001_0002 },
+            do_not_propagate_mask: EventMask { inner: 0x3f7f },
+            ..Default::default()
+        };
+        let mut bytes = vec![0; repl.size()];
+        assert_eq!(repl.as_bytes(&mut bytes), 44);
+        assert_eq!(&bytes[32..36], &0x00ff_0001u32.to_ne_bytes());
+        assert_eq!(&bytes[36..40], &0x0001_0002u32.to_ne_bytes());
+        assert_eq!(&bytes[40..42], &0x3f7fu16.to_ne_bytes());
+
+        let (parsed, len) = GetWindowAttributesReply::from_bytes(&bytes).unwrap();
+        assert_eq!(len, 44);
+        assert_eq!(parsed.all_event_masks.inner, 0x00ff_0001);
+        assert_eq!(parsed.your_event_mask.inner, 0x0001_0002);
+        assert_eq!(parsed.do_not_propagate_mask.inner, 0x3f7f);
+        assert!(GetWindowAttributesReply::from_bytes(&bytes[..41]).is_none());
+    }
+
+    #[test]
+    fn align_pad_round_trips() {
+        use super::aligntest::Aligned;
+
+        let value = Aligned {
+            a: 0x12,
+            b: 0x3456_789a,
+        };
+        // the single byte is padded out to the next 8-byte boundary
+        assert_eq!(value.size(), 12);
+        let mut bytes = vec![0; value.size()];
+        assert_eq!(value.as_bytes(&mut bytes), 12);
+        assert_eq!(bytes[0], 0x12);
+        assert_eq!(&bytes[1..8], &[0; 7]);
+        assert_eq!(&bytes[8..], &0x3456_789au32.to_ne_bytes());
+        assert_eq!(Aligned::from_bytes(&bytes), Some((value, 12)));
+        assert!(Aligned::from_bytes(&bytes[..11]).is_none());
+    }
+}
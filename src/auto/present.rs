@@ -27,9 +27,9 @@ impl AsByteSequence for Notify {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Notify from byte buffer");
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (serial, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (serial, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Notify {
@@ -67,14 +67,14 @@ impl AsByteSequence for QueryVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionRequest {
@@ -99,6 +99,7 @@ impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("Present");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -126,16 +127,16 @@ impl AsByteSequence for QueryVersionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionReply {
@@ -210,42 +211,44 @@ impl AsByteSequence for PixmapRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PixmapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (serial, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (serial, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (valid, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (valid, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (update, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (update, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x_off, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x_off, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y_off, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y_off, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target_crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
+        let (target_crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (wait_fence, sz): (Fence, usize) = <Fence>::from_bytes(&bytes[index..])?;
+        let (wait_fence, sz): (Fence, usize) = <Fence>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (idle_fence, sz): (Fence, usize) = <Fence>::from_bytes(&bytes[index..])?;
+        let (idle_fence, sz): (Fence, usize) = <Fence>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (options, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (options, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (target_msc, sz): (Card64, usize) = <Card64>::from_bytes(&bytes[index..])?;
+        let (target_msc, sz): (Card64, usize) = <Card64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (divisor, sz): (Card64, usize) = <Card64>::from_bytes(&bytes[index..])?;
+        let (divisor, sz): (Card64, usize) = <Card64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (remainder, sz): (Card64, usize) = <Card64>::from_bytes(&bytes[index..])?;
+        let (remainder, sz): (Card64, usize) = <Card64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (notifies, block_len): (Vec<Notify>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (notifies, block_len): (Vec<Notify>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Notify>());
         Some((
@@ -302,6 +305,7 @@ impl Request for PixmapRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = Some("Present");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Pixmap";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -334,21 +338,21 @@ impl AsByteSequence for NotifyMscRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing NotifyMscRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (serial, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (serial, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (target_msc, sz): (Card64, usize) = <Card64>::from_bytes(&bytes[index..])?;
+        let (target_msc, sz): (Card64, usize) = <Card64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (divisor, sz): (Card64, usize) = <Card64>::from_bytes(&bytes[index..])?;
+        let (divisor, sz): (Card64, usize) = <Card64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (remainder, sz): (Card64, usize) = <Card64>::from_bytes(&bytes[index..])?;
+        let (remainder, sz): (Card64, usize) = <Card64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             NotifyMscRequest {
@@ -380,6 +384,7 @@ impl Request for NotifyMscRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = Some("Present");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "NotifyMsc";
     type Reply = ();
 }
 #[repr(transparent)]
@@ -428,16 +433,16 @@ impl AsByteSequence for SelectInputRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectInputRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (eid, sz): (Event, usize) = <Event>::from_bytes(&bytes[index..])?;
+        let (eid, sz): (Event, usize) = <Event>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_mask, sz): (EventMask, usize) = <EventMask>::from_bytes(&bytes[index..])?;
+        let (event_mask, sz): (EventMask, usize) = <EventMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SelectInputRequest {
@@ -464,6 +469,7 @@ impl Request for SelectInputRequest {
     const OPCODE: u8 = 3;
     const EXTENSION: Option<&'static str> = Some("Present");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SelectInput";
     type Reply = ();
 }
 #[repr(transparent)]
@@ -626,12 +632,12 @@ impl AsByteSequence for QueryCapabilitiesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryCapabilitiesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryCapabilitiesRequest {
@@ -651,6 +657,7 @@ impl Request for QueryCapabilitiesRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = Some("Present");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryCapabilities";
     type Reply = QueryCapabilitiesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -676,14 +683,14 @@ impl AsByteSequence for QueryCapabilitiesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryCapabilitiesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (capabilities, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (capabilities, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryCapabilitiesReply {
@@ -1057,23 +1064,23 @@ impl AsByteSequence for CompleteNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CompleteNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (kind, sz): (CompleteKind, usize) = <CompleteKind>::from_bytes(&bytes[index..])?;
+        let (kind, sz): (CompleteKind, usize) = <CompleteKind>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (CompleteMode, usize) = <CompleteMode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (CompleteMode, usize) = <CompleteMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Event, usize) = <Event>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Event, usize) = <Event>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (serial, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (serial, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ust, sz): (Card64, usize) = <Card64>::from_bytes(&bytes[index..])?;
+        let (ust, sz): (Card64, usize) = <Card64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (msc, sz): (Card64, usize) = <Card64>::from_bytes(&bytes[index..])?;
+        let (msc, sz): (Card64, usize) = <Card64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CompleteNotifyEvent {
@@ -1147,32 +1154,32 @@ impl AsByteSequence for ConfigureNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ConfigureNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Event, usize) = <Event>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Event, usize) = <Event>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (off_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (off_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (off_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (off_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pixmap_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (pixmap_width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pixmap_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (pixmap_height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pixmap_flags, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pixmap_flags, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ConfigureNotifyEvent {
@@ -1241,18 +1248,18 @@ impl AsByteSequence for GenericEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GenericEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (extension, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (extension, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (evtype, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (evtype, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (event, sz): (Event, usize) = <Event>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Event, usize) = <Event>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GenericEvent {
@@ -1309,20 +1316,20 @@ impl AsByteSequence for IdleNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing IdleNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Event, usize) = <Event>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Event, usize) = <Event>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (serial, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (serial, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (idle_fence, sz): (Fence, usize) = <Fence>::from_bytes(&bytes[index..])?;
+        let (idle_fence, sz): (Fence, usize) = <Fence>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             IdleNotifyEvent {
@@ -134,13 +134,13 @@ impl AsByteSequence for ScreenSize {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ScreenSize from byte buffer");
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mwidth, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mwidth, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mheight, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mheight, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ScreenSize {
@@ -176,10 +176,10 @@ impl AsByteSequence for RefreshRates {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing RefreshRates from byte buffer");
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (rates, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
         Some((RefreshRates { rates: rates }, index))
@@ -216,14 +216,14 @@ impl AsByteSequence for QueryVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionRequest {
@@ -248,6 +248,7 @@ impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -276,16 +277,16 @@ impl AsByteSequence for QueryVersionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         Some((
@@ -342,22 +343,23 @@ impl AsByteSequence for SetScreenConfigRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetScreenConfigRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (size_id, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (size_id, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (rotation, sz): (Rotation, usize) = <Rotation>::from_bytes(&bytes[index..])?;
+        let (rotation, sz): (Rotation, usize) = <Rotation>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (rate, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (rate, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -392,6 +394,7 @@ impl Request for SetScreenConfigRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetScreenConfig";
     type Reply = SetScreenConfigReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -425,21 +428,22 @@ impl AsByteSequence for SetScreenConfigReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetScreenConfigReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (SetConfig, usize) = <SetConfig>::from_bytes(&bytes[index..])?;
+        let (status, sz): (SetConfig, usize) = <SetConfig>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (new_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (new_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (subpixel_order, sz): (SubPixel, usize) = <SubPixel>::from_bytes(&bytes[index..])?;
+        let (subpixel_order, sz): (SubPixel, usize) = <SubPixel>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 10;
         Some((
@@ -703,14 +707,14 @@ impl AsByteSequence for SelectInputRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectInputRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (enable, sz): (NotifyMask, usize) = <NotifyMask>::from_bytes(&bytes[index..])?;
+        let (enable, sz): (NotifyMask, usize) = <NotifyMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -732,6 +736,7 @@ impl Request for SelectInputRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SelectInput";
     type Reply = ();
 }
 #[repr(transparent)]
@@ -966,12 +971,12 @@ impl AsByteSequence for GetScreenInfoRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetScreenInfoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetScreenInfoRequest {
@@ -991,6 +996,7 @@ impl Request for GetScreenInfoRequest {
     const OPCODE: u8 = 5;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetScreenInfo";
     type Reply = GetScreenInfoReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1016,7 +1022,7 @@ impl AsByteSequence for GetScreenInfoReply {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.reply_type.as_bytes(&mut bytes[index..]);
-        index += self.rotations.as_bytes(&mut bytes[index..]);
+        index += as_bytes_with_width(&self.rotations, 1, &mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.root.as_bytes(&mut bytes[index..]);
@@ -1040,37 +1046,38 @@ impl AsByteSequence for GetScreenInfoReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetScreenInfoReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (rotations, sz): (Rotation, usize) = <Rotation>::from_bytes(&bytes[index..])?;
+        let (rotations, sz): (Rotation, usize) = from_bytes_with_width(bytes.get(index..)?, 1)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_sizes, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (n_sizes, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (size_id, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (size_id, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (rotation, sz): (Rotation, usize) = <Rotation>::from_bytes(&bytes[index..])?;
+        let (rotation, sz): (Rotation, usize) = <Rotation>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (rate, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (rate, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_info, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (n_info, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (sizes, block_len): (Vec<ScreenSize>, usize) =
-            vector_from_bytes(&bytes[index..], (n_sizes as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (n_sizes as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ScreenSize>());
         let (rates, block_len): (Vec<RefreshRates>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             ((n_info as usize) - (n_sizes as usize)) as usize,
         )?;
         index += block_len;
@@ -1098,7 +1105,7 @@ impl AsByteSequence for GetScreenInfoReply {
     #[inline]
     fn size(&self) -> usize {
         self.reply_type.size()
-            + self.rotations.size()
+            + 1
             + self.sequence.size()
             + self.length.size()
             + self.root.size()
@@ -1143,12 +1150,12 @@ impl AsByteSequence for GetScreenSizeRangeRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetScreenSizeRangeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetScreenSizeRangeRequest {
@@ -1168,6 +1175,7 @@ impl Request for GetScreenSizeRangeRequest {
     const OPCODE: u8 = 6;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetScreenSizeRange";
     type Reply = GetScreenSizeRangeReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1200,20 +1208,20 @@ impl AsByteSequence for GetScreenSizeRangeReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetScreenSizeRangeReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (min_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (min_width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (min_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (min_height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (max_width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (max_height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         Some((
@@ -1271,20 +1279,20 @@ impl AsByteSequence for SetScreenSizeRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetScreenSizeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mm_width, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (mm_width, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mm_height, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (mm_height, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetScreenSizeRequest {
@@ -1315,6 +1323,7 @@ impl Request for SetScreenSizeRequest {
     const OPCODE: u8 = 7;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetScreenSize";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1357,31 +1366,31 @@ impl AsByteSequence for ModeInfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ModeInfo from byte buffer");
-        let (id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dot_clock, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (dot_clock, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hsync_start, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsync_start, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hsync_end, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsync_end, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hskew, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hskew, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vsync_start, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsync_start, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vsync_end, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsync_end, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (name_len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (name_len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode_flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(&bytes[index..])?;
+        let (mode_flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ModeInfo {
@@ -1759,12 +1768,12 @@ impl AsByteSequence for GetScreenResourcesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetScreenResourcesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetScreenResourcesRequest {
@@ -1784,6 +1793,7 @@ impl Request for GetScreenResourcesRequest {
     const OPCODE: u8 = 8;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetScreenResources";
     type Reply = GetScreenResourcesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1832,40 +1842,41 @@ impl AsByteSequence for GetScreenResourcesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetScreenResourcesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len3, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len3, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 8;
         let (crtcs, block_len): (Vec<Crtc>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
         let (outputs, block_len): (Vec<Output>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
         let (modes, block_len): (Vec<ModeInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len2 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ModeInfo>());
         let (names, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], len3 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len3 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -1941,14 +1952,15 @@ impl AsByteSequence for GetOutputInfoRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetOutputInfoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
+        let (output, sz): (Output, usize) = <Output>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetOutputInfoRequest {
@@ -1973,6 +1985,7 @@ impl Request for GetOutputInfoRequest {
     const OPCODE: u8 = 9;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetOutputInfo";
     type Reply = GetOutputInfoReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2031,50 +2044,50 @@ impl AsByteSequence for GetOutputInfoReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetOutputInfoReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (SetConfig, usize) = <SetConfig>::from_bytes(&bytes[index..])?;
+        let (status, sz): (SetConfig, usize) = <SetConfig>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
+        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mm_width, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (mm_width, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mm_height, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (mm_height, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (connection, sz): (Connection, usize) = <Connection>::from_bytes(&bytes[index..])?;
+        let (connection, sz): (Connection, usize) = <Connection>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (subpixel_order, sz): (SubPixel, usize) = <SubPixel>::from_bytes(&bytes[index..])?;
+        let (subpixel_order, sz): (SubPixel, usize) = <SubPixel>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_preferred, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (num_preferred, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len3, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len3, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (crtcs, block_len): (Vec<Crtc>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
         let (modes, block_len): (Vec<Mode>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Mode>());
         let (clones, block_len): (Vec<Output>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len2 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
         let (name, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], len3 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len3 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -2191,12 +2204,12 @@ impl AsByteSequence for ListOutputPropertiesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListOutputPropertiesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
+        let (output, sz): (Output, usize) = <Output>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ListOutputPropertiesRequest {
@@ -2216,6 +2229,7 @@ impl Request for ListOutputPropertiesRequest {
     const OPCODE: u8 = 10;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListOutputProperties";
     type Reply = ListOutputPropertiesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2245,18 +2259,18 @@ impl AsByteSequence for ListOutputPropertiesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListOutputPropertiesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
         let (atoms, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         Some((
@@ -2307,14 +2321,14 @@ impl AsByteSequence for QueryOutputPropertyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryOutputPropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
+        let (output, sz): (Output, usize) = <Output>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryOutputPropertyRequest {
@@ -2335,6 +2349,7 @@ impl Request for QueryOutputPropertyRequest {
     const OPCODE: u8 = 11;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryOutputProperty";
     type Reply = QueryOutputPropertyReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2369,22 +2384,22 @@ impl AsByteSequence for QueryOutputPropertyReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryOutputPropertyReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pending, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (pending, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (range, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (range, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (immutable, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (immutable, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 21;
         let (valid_values, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], (length as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (length as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
         Some((
@@ -2449,22 +2464,24 @@ impl AsByteSequence for ConfigureOutputPropertyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ConfigureOutputPropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
+        let (output, sz): (Output, usize) = <Output>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pending, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (pending, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (range, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (range, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (values, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (values, block_len): (Vec<Int32>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
         Some((
@@ -2501,6 +2518,7 @@ impl Request for ConfigureOutputPropertyRequest {
     const OPCODE: u8 = 12;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ConfigureOutputProperty";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2539,26 +2557,26 @@ impl AsByteSequence for ChangeOutputPropertyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeOutputPropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
+        let (output, sz): (Output, usize) = <Output>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (PropMode, usize) = <PropMode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (PropMode, usize) = <PropMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (num_units, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_units, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (data, block_len): (Vec<Void>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (((num_units as usize) * (format as usize)) / (8)) as usize,
         )?;
         index += block_len;
@@ -2601,6 +2619,7 @@ impl Request for ChangeOutputPropertyRequest {
     const OPCODE: u8 = 13;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeOutputProperty";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2626,14 +2645,14 @@ impl AsByteSequence for DeleteOutputPropertyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeleteOutputPropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
+        let (output, sz): (Output, usize) = <Output>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DeleteOutputPropertyRequest {
@@ -2654,6 +2673,7 @@ impl Request for DeleteOutputPropertyRequest {
     const OPCODE: u8 = 14;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DeleteOutputProperty";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2690,24 +2710,24 @@ impl AsByteSequence for GetOutputPropertyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetOutputPropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
+        let (output, sz): (Output, usize) = <Output>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (long_offset, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (long_offset, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (long_length, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (long_length, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (delete, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (delete, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pending, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (pending, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -2744,6 +2764,7 @@ impl Request for GetOutputPropertyRequest {
     const OPCODE: u8 = 15;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetOutputProperty";
     type Reply = GetOutputPropertyReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2779,23 +2800,23 @@ impl AsByteSequence for GetOutputPropertyReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetOutputPropertyReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bytes_after, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bytes_after, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_items, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_items, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Byte>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             ((num_items as usize) * ((format as usize) / (8))) as usize,
         )?;
         index += block_len;
@@ -2858,17 +2879,19 @@ impl AsByteSequence for CreateModeRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateModeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode_info, sz): (ModeInfo, usize) = <ModeInfo>::from_bytes(&bytes[index..])?;
+        let (mode_info, sz): (ModeInfo, usize) = <ModeInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (name, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (name, block_len): (String, usize) = string_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -2900,6 +2923,7 @@ impl Request for CreateModeRequest {
     const OPCODE: u8 = 16;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateMode";
     type Reply = CreateModeReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2926,14 +2950,14 @@ impl AsByteSequence for CreateModeReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateModeReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         Some((
@@ -2977,12 +3001,12 @@ impl AsByteSequence for DestroyModeRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroyModeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DestroyModeRequest {
@@ -3002,6 +3026,7 @@ impl Request for DestroyModeRequest {
     const OPCODE: u8 = 17;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroyMode";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3027,14 +3052,14 @@ impl AsByteSequence for AddOutputModeRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AddOutputModeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
+        let (output, sz): (Output, usize) = <Output>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             AddOutputModeRequest {
@@ -3055,6 +3080,7 @@ impl Request for AddOutputModeRequest {
     const OPCODE: u8 = 18;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "AddOutputMode";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3080,14 +3106,14 @@ impl AsByteSequence for DeleteOutputModeRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeleteOutputModeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
+        let (output, sz): (Output, usize) = <Output>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DeleteOutputModeRequest {
@@ -3108,6 +3134,7 @@ impl Request for DeleteOutputModeRequest {
     const OPCODE: u8 = 19;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DeleteOutputMode";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3133,14 +3160,15 @@ impl AsByteSequence for GetCrtcInfoRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCrtcInfoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
+        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetCrtcInfoRequest {
@@ -3165,6 +3193,7 @@ impl Request for GetCrtcInfoRequest {
     const OPCODE: u8 = 20;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetCrtcInfo";
     type Reply = GetCrtcInfoReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3215,40 +3244,40 @@ impl AsByteSequence for GetCrtcInfoReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCrtcInfoReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (SetConfig, usize) = <SetConfig>::from_bytes(&bytes[index..])?;
+        let (status, sz): (SetConfig, usize) = <SetConfig>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (rotation, sz): (Rotation, usize) = <Rotation>::from_bytes(&bytes[index..])?;
+        let (rotation, sz): (Rotation, usize) = <Rotation>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (rotations, sz): (Rotation, usize) = <Rotation>::from_bytes(&bytes[index..])?;
+        let (rotations, sz): (Rotation, usize) = <Rotation>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (outputs, block_len): (Vec<Output>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
         let (possible, block_len): (Vec<Output>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
         Some((
@@ -3337,28 +3366,31 @@ impl AsByteSequence for SetCrtcConfigRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetCrtcConfigRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
+        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (rotation, sz): (Rotation, usize) = <Rotation>::from_bytes(&bytes[index..])?;
+        let (rotation, sz): (Rotation, usize) = <Rotation>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (outputs, block_len): (Vec<Output>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (outputs, block_len): (Vec<Output>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
         Some((
@@ -3401,6 +3433,7 @@ impl Request for SetCrtcConfigRequest {
     const OPCODE: u8 = 21;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetCrtcConfig";
     type Reply = SetCrtcConfigReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3428,15 +3461,15 @@ impl AsByteSequence for SetCrtcConfigReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetCrtcConfigReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (SetConfig, usize) = <SetConfig>::from_bytes(&bytes[index..])?;
+        let (status, sz): (SetConfig, usize) = <SetConfig>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         Some((
@@ -3481,12 +3514,12 @@ impl AsByteSequence for GetCrtcGammaSizeRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCrtcGammaSizeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
+        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetCrtcGammaSizeRequest {
@@ -3506,6 +3539,7 @@ impl Request for GetCrtcGammaSizeRequest {
     const OPCODE: u8 = 22;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetCrtcGammaSize";
     type Reply = GetCrtcGammaSizeReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3532,14 +3566,14 @@ impl AsByteSequence for GetCrtcGammaSizeReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCrtcGammaSizeReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (size, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (size, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
         Some((
@@ -3583,12 +3617,12 @@ impl AsByteSequence for GetCrtcGammaRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCrtcGammaRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
+        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetCrtcGammaRequest {
@@ -3608,6 +3642,7 @@ impl Request for GetCrtcGammaRequest {
     const OPCODE: u8 = 23;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetCrtcGamma";
     type Reply = GetCrtcGammaReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3646,26 +3681,26 @@ impl AsByteSequence for GetCrtcGammaReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCrtcGammaReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (size, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (size, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
         let (red, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (size as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (size as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
         let (green, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (size as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (size as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
         let (blue, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (size as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (size as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
         Some((
@@ -3742,26 +3777,26 @@ impl AsByteSequence for SetCrtcGammaRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetCrtcGammaRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
+        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (size, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (size, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (red, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (size as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (size as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
         let (green, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (size as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (size as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
         let (blue, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (size as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (size as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
         Some((
@@ -3806,6 +3841,7 @@ impl Request for SetCrtcGammaRequest {
     const OPCODE: u8 = 24;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetCrtcGamma";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3829,12 +3865,12 @@ impl AsByteSequence for GetScreenResourcesCurrentRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetScreenResourcesCurrentRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetScreenResourcesCurrentRequest {
@@ -3854,6 +3890,7 @@ impl Request for GetScreenResourcesCurrentRequest {
     const OPCODE: u8 = 25;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetScreenResourcesCurrent";
     type Reply = GetScreenResourcesCurrentReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3902,40 +3939,41 @@ impl AsByteSequence for GetScreenResourcesCurrentReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetScreenResourcesCurrentReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len3, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len3, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 8;
         let (crtcs, block_len): (Vec<Crtc>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
         let (outputs, block_len): (Vec<Output>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
         let (modes, block_len): (Vec<ModeInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len2 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ModeInfo>());
         let (names, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], len3 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len3 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -4021,24 +4059,26 @@ impl AsByteSequence for SetCrtcTransformRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetCrtcTransformRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
+        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (transform, sz): (Transform, usize) = <Transform>::from_bytes(&bytes[index..])?;
+        let (transform, sz): (Transform, usize) = <Transform>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (filter_name, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, 4);
-        let (filter_params, block_len): (Vec<Fixed>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (filter_params, block_len): (Vec<Fixed>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
         Some((
@@ -4078,6 +4118,7 @@ impl Request for SetCrtcTransformRequest {
     const OPCODE: u8 = 26;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetCrtcTransform";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4101,12 +4142,12 @@ impl AsByteSequence for GetCrtcTransformRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCrtcTransformRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
+        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetCrtcTransformRequest {
@@ -4126,6 +4167,7 @@ impl Request for GetCrtcTransformRequest {
     const OPCODE: u8 = 27;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetCrtcTransform";
     type Reply = GetCrtcTransformReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4177,43 +4219,45 @@ impl AsByteSequence for GetCrtcTransformReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCrtcTransformReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pending_transform, sz): (Transform, usize) = <Transform>::from_bytes(&bytes[index..])?;
+        let (pending_transform, sz): (Transform, usize) =
+            <Transform>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (has_transforms, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (has_transforms, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (current_transform, sz): (Transform, usize) = <Transform>::from_bytes(&bytes[index..])?;
+        let (current_transform, sz): (Transform, usize) =
+            <Transform>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len3, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len3, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (pending_filter_name, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, 4);
         let (pending_params, block_len): (Vec<Fixed>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
         let (current_filter_name, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len2 as usize)?;
+            string_from_bytes(bytes.get(index..)?, len2 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, 4);
         let (current_params, block_len): (Vec<Fixed>, usize) =
-            vector_from_bytes(&bytes[index..], len3 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len3 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
         Some((
@@ -4290,12 +4334,12 @@ impl AsByteSequence for GetPanningRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPanningRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
+        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetPanningRequest {
@@ -4315,6 +4359,7 @@ impl Request for GetPanningRequest {
     const OPCODE: u8 = 28;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetPanning";
     type Reply = GetPanningReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4365,39 +4410,39 @@ impl AsByteSequence for GetPanningReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPanningReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (SetConfig, usize) = <SetConfig>::from_bytes(&bytes[index..])?;
+        let (status, sz): (SetConfig, usize) = <SetConfig>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (left, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (left, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (top, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (top, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (track_left, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (track_left, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (track_top, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (track_top, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (track_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (track_width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (track_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (track_height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (border_left, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (border_left, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (border_top, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (border_top, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (border_right, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (border_right, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (border_bottom, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (border_bottom, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetPanningReply {
@@ -4490,38 +4535,38 @@ impl AsByteSequence for SetPanningRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetPanningRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
+        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (left, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (left, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (top, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (top, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (track_left, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (track_left, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (track_top, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (track_top, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (track_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (track_width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (track_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (track_height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (border_left, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (border_left, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (border_top, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (border_top, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (border_right, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (border_right, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (border_bottom, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (border_bottom, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetPanningRequest {
@@ -4570,6 +4615,7 @@ impl Request for SetPanningRequest {
     const OPCODE: u8 = 29;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetPanning";
     type Reply = SetPanningReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4596,15 +4642,15 @@ impl AsByteSequence for SetPanningReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetPanningReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (SetConfig, usize) = <SetConfig>::from_bytes(&bytes[index..])?;
+        let (status, sz): (SetConfig, usize) = <SetConfig>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetPanningReply {
@@ -4649,14 +4695,14 @@ impl AsByteSequence for SetOutputPrimaryRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetOutputPrimaryRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
+        let (output, sz): (Output, usize) = <Output>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetOutputPrimaryRequest {
@@ -4677,6 +4723,7 @@ impl Request for SetOutputPrimaryRequest {
     const OPCODE: u8 = 30;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetOutputPrimary";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4700,12 +4747,12 @@ impl AsByteSequence for GetOutputPrimaryRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetOutputPrimaryRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetOutputPrimaryRequest {
@@ -4725,6 +4772,7 @@ impl Request for GetOutputPrimaryRequest {
     const OPCODE: u8 = 31;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetOutputPrimary";
     type Reply = GetOutputPrimaryReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4750,14 +4798,14 @@ impl AsByteSequence for GetOutputPrimaryReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetOutputPrimaryReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
+        let (output, sz): (Output, usize) = <Output>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetOutputPrimaryReply {
@@ -4795,12 +4843,12 @@ impl AsByteSequence for GetProvidersRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetProvidersRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetProvidersRequest {
@@ -4820,6 +4868,7 @@ impl Request for GetProvidersRequest {
     const OPCODE: u8 = 32;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetProviders";
     type Reply = GetProvidersReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4851,20 +4900,20 @@ impl AsByteSequence for GetProvidersReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetProvidersReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 18;
         let (providers, block_len): (Vec<Provider>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Provider>());
         Some((
@@ -4917,14 +4966,15 @@ impl AsByteSequence for GetProviderInfoRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetProviderInfoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetProviderInfoRequest {
@@ -4949,6 +4999,7 @@ impl Request for GetProviderInfoRequest {
     const OPCODE: u8 = 33;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetProviderInfo";
     type Reply = GetProviderInfoReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5003,50 +5054,51 @@ impl AsByteSequence for GetProviderInfoReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetProviderInfoReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (status, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (capabilities, sz): (ProviderCapability, usize) =
-            <ProviderCapability>::from_bytes(&bytes[index..])?;
+            <ProviderCapability>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (num_associated_providers, sz): (Card16, usize) =
-            <Card16>::from_bytes(&bytes[index..])?;
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 8;
         let (crtcs, block_len): (Vec<Crtc>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
         let (outputs, block_len): (Vec<Output>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
         let (associated_providers, block_len): (Vec<Provider>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (num_associated_providers as usize) as usize,
         )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Provider>());
         let (associated_capability, block_len): (Vec<Card32>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (num_associated_providers as usize) as usize,
         )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len2 as usize)?;
+        let (name, block_len): (String, usize) =
+            string_from_bytes(bytes.get(index..)?, len2 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -5271,16 +5323,17 @@ impl AsByteSequence for SetProviderOffloadSinkRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetProviderOffloadSinkRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sink_provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (sink_provider, sz): (Provider, usize) = <Provider>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetProviderOffloadSinkRequest {
@@ -5307,6 +5360,7 @@ impl Request for SetProviderOffloadSinkRequest {
     const OPCODE: u8 = 34;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetProviderOffloadSink";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5334,16 +5388,17 @@ impl AsByteSequence for SetProviderOutputSourceRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetProviderOutputSourceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (source_provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (source_provider, sz): (Provider, usize) = <Provider>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetProviderOutputSourceRequest {
@@ -5370,6 +5425,7 @@ impl Request for SetProviderOutputSourceRequest {
     const OPCODE: u8 = 35;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetProviderOutputSource";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5393,12 +5449,12 @@ impl AsByteSequence for ListProviderPropertiesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListProviderPropertiesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ListProviderPropertiesRequest {
@@ -5418,6 +5474,7 @@ impl Request for ListProviderPropertiesRequest {
     const OPCODE: u8 = 36;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListProviderProperties";
     type Reply = ListProviderPropertiesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5447,18 +5504,18 @@ impl AsByteSequence for ListProviderPropertiesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListProviderPropertiesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
         let (atoms, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         Some((
@@ -5509,14 +5566,14 @@ impl AsByteSequence for QueryProviderPropertyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryProviderPropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryProviderPropertyRequest {
@@ -5537,6 +5594,7 @@ impl Request for QueryProviderPropertyRequest {
     const OPCODE: u8 = 37;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryProviderProperty";
     type Reply = QueryProviderPropertyReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5571,22 +5629,22 @@ impl AsByteSequence for QueryProviderPropertyReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryProviderPropertyReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pending, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (pending, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (range, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (range, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (immutable, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (immutable, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 21;
         let (valid_values, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], (length as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (length as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
         Some((
@@ -5651,22 +5709,24 @@ impl AsByteSequence for ConfigureProviderPropertyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ConfigureProviderPropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pending, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (pending, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (range, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (range, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (values, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (values, block_len): (Vec<Int32>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
         Some((
@@ -5703,6 +5763,7 @@ impl Request for ConfigureProviderPropertyRequest {
     const OPCODE: u8 = 38;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ConfigureProviderProperty";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5741,26 +5802,26 @@ impl AsByteSequence for ChangeProviderPropertyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeProviderPropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (num_items, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_items, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (data, block_len): (Vec<Void>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             ((num_items as usize) * ((format as usize) / (8))) as usize,
         )?;
         index += block_len;
@@ -5803,6 +5864,7 @@ impl Request for ChangeProviderPropertyRequest {
     const OPCODE: u8 = 39;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeProviderProperty";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5828,14 +5890,14 @@ impl AsByteSequence for DeleteProviderPropertyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeleteProviderPropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DeleteProviderPropertyRequest {
@@ -5856,6 +5918,7 @@ impl Request for DeleteProviderPropertyRequest {
     const OPCODE: u8 = 40;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DeleteProviderProperty";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5892,24 +5955,24 @@ impl AsByteSequence for GetProviderPropertyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetProviderPropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (long_offset, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (long_offset, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (long_length, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (long_length, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (delete, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (delete, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pending, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (pending, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -5946,6 +6009,7 @@ impl Request for GetProviderPropertyRequest {
     const OPCODE: u8 = 41;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetProviderProperty";
     type Reply = GetProviderPropertyReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5981,23 +6045,23 @@ impl AsByteSequence for GetProviderPropertyReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetProviderPropertyReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bytes_after, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bytes_after, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_items, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_items, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Void>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             ((num_items as usize) * ((format as usize) / (8))) as usize,
         )?;
         index += block_len;
@@ -6067,24 +6131,24 @@ impl AsByteSequence for CrtcChange {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CrtcChange from byte buffer");
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
+        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (rotation, sz): (Rotation, usize) = <Rotation>::from_bytes(&bytes[index..])?;
+        let (rotation, sz): (Rotation, usize) = <Rotation>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CrtcChange {
@@ -6147,23 +6211,24 @@ impl AsByteSequence for OutputChange {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing OutputChange from byte buffer");
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
+        let (output, sz): (Output, usize) = <Output>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(&bytes[index..])?;
+        let (crtc, sz): (Crtc, usize) = <Crtc>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (Mode, usize) = <Mode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (rotation, sz): (Rotation, usize) = <Rotation>::from_bytes(&bytes[index..])?;
+        let (rotation, sz): (Rotation, usize) = <Rotation>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (connection, sz): (Connection, usize) = <Connection>::from_bytes(&bytes[index..])?;
+        let (connection, sz): (Connection, usize) = <Connection>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (subpixel_order, sz): (SubPixel, usize) = <SubPixel>::from_bytes(&bytes[index..])?;
+        let (subpixel_order, sz): (SubPixel, usize) = <SubPixel>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             OutputChange {
@@ -6218,15 +6283,15 @@ impl AsByteSequence for OutputProperty {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing OutputProperty from byte buffer");
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (output, sz): (Output, usize) = <Output>::from_bytes(&bytes[index..])?;
+        let (output, sz): (Output, usize) = <Output>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (atom, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (atom, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (Property, usize) = <Property>::from_bytes(&bytes[index..])?;
+        let (status, sz): (Property, usize) = <Property>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 11;
         Some((
@@ -6271,11 +6336,11 @@ impl AsByteSequence for ProviderChange {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ProviderChange from byte buffer");
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         Some((
@@ -6317,15 +6382,15 @@ impl AsByteSequence for ProviderProperty {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ProviderProperty from byte buffer");
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(&bytes[index..])?;
+        let (provider, sz): (Provider, usize) = <Provider>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (atom, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (atom, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (state, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 11;
         Some((
@@ -6368,9 +6433,9 @@ impl AsByteSequence for ResourceChange {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ResourceChange from byte buffer");
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         Some((
@@ -6423,28 +6488,30 @@ impl AsByteSequence for MonitorInfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing MonitorInfo from byte buffer");
-        let (name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (primary, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (primary, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (automatic, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (automatic, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width_in_millimeters, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (width_in_millimeters, sz): (Card32, usize) =
+            <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height_in_millimeters, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (height_in_millimeters, sz): (Card32, usize) =
+            <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (outputs, block_len): (Vec<Output>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
         Some((
@@ -6505,14 +6572,14 @@ impl AsByteSequence for GetMonitorsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMonitorsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (get_active, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (get_active, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetMonitorsRequest {
@@ -6533,6 +6600,7 @@ impl Request for GetMonitorsRequest {
     const OPCODE: u8 = 42;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetMonitors";
     type Reply = GetMonitorsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -6566,22 +6634,22 @@ impl AsByteSequence for GetMonitorsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMonitorsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_outputs, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (n_outputs, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (monitors, block_len): (Vec<MonitorInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<MonitorInfo>());
         Some((
@@ -6636,14 +6704,15 @@ impl AsByteSequence for SetMonitorRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetMonitorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (monitorinfo, sz): (MonitorInfo, usize) = <MonitorInfo>::from_bytes(&bytes[index..])?;
+        let (monitorinfo, sz): (MonitorInfo, usize) =
+            <MonitorInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetMonitorRequest {
@@ -6664,6 +6733,7 @@ impl Request for SetMonitorRequest {
     const OPCODE: u8 = 43;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetMonitor";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -6689,14 +6759,14 @@ impl AsByteSequence for DeleteMonitorRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeleteMonitorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DeleteMonitorRequest {
@@ -6717,6 +6787,7 @@ impl Request for DeleteMonitorRequest {
     const OPCODE: u8 = 44;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DeleteMonitor";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -6752,25 +6823,25 @@ impl AsByteSequence for CreateLeaseRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateLeaseRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (lid, sz): (Lease, usize) = <Lease>::from_bytes(&bytes[index..])?;
+        let (lid, sz): (Lease, usize) = <Lease>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (crtcs, block_len): (Vec<Crtc>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Crtc>());
         let (outputs, block_len): (Vec<Output>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Output>());
         Some((
@@ -6810,6 +6881,7 @@ impl Request for CreateLeaseRequest {
     const OPCODE: u8 = 45;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = true;
+    const NAME: &'static str = "CreateLease";
     type Reply = CreateLeaseReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -6836,13 +6908,13 @@ impl AsByteSequence for CreateLeaseReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateLeaseReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (nfd, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (nfd, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 24;
         Some((
@@ -6888,14 +6960,14 @@ impl AsByteSequence for FreeLeaseRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FreeLeaseRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (lid, sz): (Lease, usize) = <Lease>::from_bytes(&bytes[index..])?;
+        let (lid, sz): (Lease, usize) = <Lease>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (terminate, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (terminate, sz): (Byte, usize) = <Byte>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             FreeLeaseRequest {
@@ -6916,6 +6988,7 @@ impl Request for FreeLeaseRequest {
     const OPCODE: u8 = 46;
     const EXTENSION: Option<&'static str> = Some("RANDR");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FreeLease";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -6941,13 +7014,13 @@ impl AsByteSequence for LeaseNotify {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing LeaseNotify from byte buffer");
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (lease, sz): (Lease, usize) = <Lease>::from_bytes(&bytes[index..])?;
+        let (lease, sz): (Lease, usize) = <Lease>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (created, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (created, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 15;
         Some((
@@ -7128,13 +7201,13 @@ impl AsByteSequence for NotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing NotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sub_code, sz): (Notify, usize) = <Notify>::from_bytes(&bytes[index..])?;
+        let (sub_code, sz): (Notify, usize) = <Notify>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (u, sz): (NotifyData, usize) = <NotifyData>::from_bytes(&bytes[index..])?;
+        let (u, sz): (NotifyData, usize) = <NotifyData>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             NotifyEvent {
@@ -7176,7 +7249,7 @@ impl AsByteSequence for ScreenChangeNotifyEvent {
     fn as_bytes(&self, bytes: &mut [u8]) -> usize {
         let mut index: usize = 0;
         index += self.event_type.as_bytes(&mut bytes[index..]);
-        index += self.rotation.as_bytes(&mut bytes[index..]);
+        index += as_bytes_with_width(&self.rotation, 1, &mut bytes[index..]);
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.timestamp.as_bytes(&mut bytes[index..]);
         index += self.config_timestamp.as_bytes(&mut bytes[index..]);
@@ -7194,31 +7267,32 @@ impl AsByteSequence for ScreenChangeNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ScreenChangeNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (rotation, sz): (Rotation, usize) = <Rotation>::from_bytes(&bytes[index..])?;
+        let (rotation, sz): (Rotation, usize) = from_bytes_with_width(bytes.get(index..)?, 1)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (config_timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (config_timestamp, sz): (Timestamp, usize) =
+            <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (request_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (request_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (size_id, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (size_id, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (subpixel_order, sz): (SubPixel, usize) = <SubPixel>::from_bytes(&bytes[index..])?;
+        let (subpixel_order, sz): (SubPixel, usize) = <SubPixel>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mwidth, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mwidth, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mheight, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mheight, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ScreenChangeNotifyEvent {
@@ -7242,7 +7316,7 @@ impl AsByteSequence for ScreenChangeNotifyEvent {
     #[inline]
     fn size(&self) -> usize {
         self.event_type.size()
-            + self.rotation.size()
+            + 1
             + self.sequence.size()
             + self.timestamp.size()
             + self.config_timestamp.size()
@@ -44,9 +44,9 @@ impl AsByteSequence for Range8 {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Range8 from byte buffer");
-        let (first, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (last, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (last, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Range8 {
@@ -79,9 +79,9 @@ impl AsByteSequence for Range16 {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Range16 from byte buffer");
-        let (first, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (first, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (last, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (last, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Range16 {
@@ -114,9 +114,9 @@ impl AsByteSequence for ExtRange {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ExtRange from byte buffer");
-        let (major, sz): (Range8, usize) = <Range8>::from_bytes(&bytes[index..])?;
+        let (major, sz): (Range8, usize) = <Range8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor, sz): (Range16, usize) = <Range16>::from_bytes(&bytes[index..])?;
+        let (minor, sz): (Range16, usize) = <Range16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ExtRange {
@@ -163,23 +163,23 @@ impl AsByteSequence for Range {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Range from byte buffer");
-        let (core_requests, sz): (Range8, usize) = <Range8>::from_bytes(&bytes[index..])?;
+        let (core_requests, sz): (Range8, usize) = <Range8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (core_replies, sz): (Range8, usize) = <Range8>::from_bytes(&bytes[index..])?;
+        let (core_replies, sz): (Range8, usize) = <Range8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ext_requests, sz): (ExtRange, usize) = <ExtRange>::from_bytes(&bytes[index..])?;
+        let (ext_requests, sz): (ExtRange, usize) = <ExtRange>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ext_replies, sz): (ExtRange, usize) = <ExtRange>::from_bytes(&bytes[index..])?;
+        let (ext_replies, sz): (ExtRange, usize) = <ExtRange>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (delivered_events, sz): (Range8, usize) = <Range8>::from_bytes(&bytes[index..])?;
+        let (delivered_events, sz): (Range8, usize) = <Range8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_events, sz): (Range8, usize) = <Range8>::from_bytes(&bytes[index..])?;
+        let (device_events, sz): (Range8, usize) = <Range8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (errors, sz): (Range8, usize) = <Range8>::from_bytes(&bytes[index..])?;
+        let (errors, sz): (Range8, usize) = <Range8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (client_started, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (client_started, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (client_died, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (client_died, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Range {
@@ -232,12 +232,13 @@ impl AsByteSequence for ClientInfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ClientInfo from byte buffer");
-        let (client_resource, sz): (ClientSpec, usize) = <ClientSpec>::from_bytes(&bytes[index..])?;
+        let (client_resource, sz): (ClientSpec, usize) =
+            <ClientSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (ranges, block_len): (Vec<Range>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Range>());
         Some((
@@ -280,14 +281,14 @@ impl AsByteSequence for QueryVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionRequest {
@@ -312,6 +313,7 @@ impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RECORD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -339,16 +341,16 @@ impl AsByteSequence for QueryVersionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionReply {
@@ -405,28 +407,28 @@ impl AsByteSequence for CreateContextRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (context, sz): (super::record::Context, usize) =
-            <super::record::Context>::from_bytes(&bytes[index..])?;
+            <super::record::Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (element_header, sz): (ElementHeader, usize) =
-            <ElementHeader>::from_bytes(&bytes[index..])?;
+            <ElementHeader>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (client_specs, block_len): (Vec<ClientSpec>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ClientSpec>());
         let (ranges, block_len): (Vec<Range>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Range>());
         Some((
@@ -467,6 +469,7 @@ impl Request for CreateContextRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = Some("RECORD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateContext";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -503,28 +506,28 @@ impl AsByteSequence for RegisterClientsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing RegisterClientsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (context, sz): (super::record::Context, usize) =
-            <super::record::Context>::from_bytes(&bytes[index..])?;
+            <super::record::Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (element_header, sz): (ElementHeader, usize) =
-            <ElementHeader>::from_bytes(&bytes[index..])?;
+            <ElementHeader>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (client_specs, block_len): (Vec<ClientSpec>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ClientSpec>());
         let (ranges, block_len): (Vec<Range>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Range>());
         Some((
@@ -565,6 +568,7 @@ impl Request for RegisterClientsRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = Some("RECORD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "RegisterClients";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -593,18 +597,18 @@ impl AsByteSequence for UnregisterClientsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing UnregisterClientsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (context, sz): (super::record::Context, usize) =
-            <super::record::Context>::from_bytes(&bytes[index..])?;
+            <super::record::Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (client_specs, block_len): (Vec<ClientSpec>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ClientSpec>());
         Some((
@@ -635,6 +639,7 @@ impl Request for UnregisterClientsRequest {
     const OPCODE: u8 = 3;
     const EXTENSION: Option<&'static str> = Some("RECORD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UnregisterClients";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -658,13 +663,13 @@ impl AsByteSequence for GetContextRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (context, sz): (super::record::Context, usize) =
-            <super::record::Context>::from_bytes(&bytes[index..])?;
+            <super::record::Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetContextRequest {
@@ -684,6 +689,7 @@ impl Request for GetContextRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = Some("RECORD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetContext";
     type Reply = GetContextReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -717,23 +723,23 @@ impl AsByteSequence for GetContextReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (enabled, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (enabled, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (element_header, sz): (ElementHeader, usize) =
-            <ElementHeader>::from_bytes(&bytes[index..])?;
+            <ElementHeader>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         let (intercepted_clients, block_len): (Vec<ClientInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ClientInfo>());
         Some((
@@ -786,13 +792,13 @@ impl AsByteSequence for EnableContextRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing EnableContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (context, sz): (super::record::Context, usize) =
-            <super::record::Context>::from_bytes(&bytes[index..])?;
+            <super::record::Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             EnableContextRequest {
@@ -812,6 +818,7 @@ impl Request for EnableContextRequest {
     const OPCODE: u8 = 5;
     const EXTENSION: Option<&'static str> = Some("RECORD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "EnableContext";
     type Reply = EnableContextReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -852,29 +859,29 @@ impl AsByteSequence for EnableContextReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing EnableContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (category, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (category, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (element_header, sz): (ElementHeader, usize) =
-            <ElementHeader>::from_bytes(&bytes[index..])?;
+            <ElementHeader>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (client_swapped, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (client_swapped, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (xid_base, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (xid_base, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (server_time, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (server_time, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (rec_sequence_num, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (rec_sequence_num, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 8;
         let (data, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize) * (4)) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((length as usize) * (4)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -934,13 +941,13 @@ impl AsByteSequence for DisableContextRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DisableContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (context, sz): (super::record::Context, usize) =
-            <super::record::Context>::from_bytes(&bytes[index..])?;
+            <super::record::Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DisableContextRequest {
@@ -960,6 +967,7 @@ impl Request for DisableContextRequest {
     const OPCODE: u8 = 6;
     const EXTENSION: Option<&'static str> = Some("RECORD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DisableContext";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -983,13 +991,13 @@ impl AsByteSequence for FreeContextRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FreeContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (context, sz): (super::record::Context, usize) =
-            <super::record::Context>::from_bytes(&bytes[index..])?;
+            <super::record::Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             FreeContextRequest {
@@ -1009,6 +1017,7 @@ impl Request for FreeContextRequest {
     const OPCODE: u8 = 7;
     const EXTENSION: Option<&'static str> = Some("RECORD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FreeContext";
     type Reply = ();
 }
 #[repr(i32)]
@@ -1187,17 +1196,17 @@ impl AsByteSequence for BadContextError {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing BadContextError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (major_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (invalid_record, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (invalid_record, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             BadContextError {
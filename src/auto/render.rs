@@ -101,21 +101,21 @@ impl AsByteSequence for Directformat {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Directformat from byte buffer");
-        let (red_shift, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (red_shift, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (red_mask, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (red_mask, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (green_shift, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (green_shift, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (green_mask, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (green_mask, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (blue_shift, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (blue_shift, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (blue_mask, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (blue_mask, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (alpha_shift, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (alpha_shift, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (alpha_mask, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (alpha_mask, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Directformat {
@@ -168,16 +168,16 @@ impl AsByteSequence for Pictforminfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Pictforminfo from byte buffer");
-        let (id, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Pictformat, usize) = <Pictformat>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (PictType, usize) = <PictType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (PictType, usize) = <PictType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (direct, sz): (Directformat, usize) = <Directformat>::from_bytes(&bytes[index..])?;
+        let (direct, sz): (Directformat, usize) = <Directformat>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (colormap, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+        let (colormap, sz): (Colormap, usize) = <Colormap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Pictforminfo {
@@ -249,9 +249,9 @@ impl AsByteSequence for Pictvisual {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Pictvisual from byte buffer");
-        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(&bytes[index..])?;
+        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Pictvisual {
@@ -289,14 +289,14 @@ impl AsByteSequence for Pictdepth {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Pictdepth from byte buffer");
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
         let (visuals, block_len): (Vec<Pictvisual>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Pictvisual>());
         Some((
@@ -337,12 +337,12 @@ impl AsByteSequence for Pictscreen {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Pictscreen from byte buffer");
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fallback, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (fallback, sz): (Pictformat, usize) = <Pictformat>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (depths, block_len): (Vec<Pictdepth>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Pictdepth>());
         Some((
@@ -386,15 +386,15 @@ impl AsByteSequence for Indexvalue {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Indexvalue from byte buffer");
-        let (pixel, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pixel, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (red, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (red, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (green, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (green, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (blue, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (blue, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (alpha, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (alpha, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Indexvalue {
@@ -438,13 +438,13 @@ impl AsByteSequence for Color {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Color from byte buffer");
-        let (red, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (red, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (green, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (green, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (blue, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (blue, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (alpha, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (alpha, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Color {
@@ -479,9 +479,9 @@ impl AsByteSequence for Pointfix {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Pointfix from byte buffer");
-        let (x, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Fixed, usize) = <Fixed>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Fixed, usize) = <Fixed>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((Pointfix { x: x, y: y }, index))
     }
@@ -508,9 +508,9 @@ impl AsByteSequence for Linefix {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Linefix from byte buffer");
-        let (p1, sz): (Pointfix, usize) = <Pointfix>::from_bytes(&bytes[index..])?;
+        let (p1, sz): (Pointfix, usize) = <Pointfix>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (p2, sz): (Pointfix, usize) = <Pointfix>::from_bytes(&bytes[index..])?;
+        let (p2, sz): (Pointfix, usize) = <Pointfix>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((Linefix { p1: p1, p2: p2 }, index))
     }
@@ -539,11 +539,11 @@ impl AsByteSequence for Triangle {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Triangle from byte buffer");
-        let (p1, sz): (Pointfix, usize) = <Pointfix>::from_bytes(&bytes[index..])?;
+        let (p1, sz): (Pointfix, usize) = <Pointfix>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (p2, sz): (Pointfix, usize) = <Pointfix>::from_bytes(&bytes[index..])?;
+        let (p2, sz): (Pointfix, usize) = <Pointfix>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (p3, sz): (Pointfix, usize) = <Pointfix>::from_bytes(&bytes[index..])?;
+        let (p3, sz): (Pointfix, usize) = <Pointfix>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Triangle {
@@ -581,13 +581,13 @@ impl AsByteSequence for Trapezoid {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Trapezoid from byte buffer");
-        let (top, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (top, sz): (Fixed, usize) = <Fixed>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bottom, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (bottom, sz): (Fixed, usize) = <Fixed>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (left, sz): (Linefix, usize) = <Linefix>::from_bytes(&bytes[index..])?;
+        let (left, sz): (Linefix, usize) = <Linefix>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (right, sz): (Linefix, usize) = <Linefix>::from_bytes(&bytes[index..])?;
+        let (right, sz): (Linefix, usize) = <Linefix>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Trapezoid {
@@ -630,17 +630,17 @@ impl AsByteSequence for Glyphinfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Glyphinfo from byte buffer");
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x_off, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x_off, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y_off, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y_off, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Glyphinfo {
@@ -687,14 +687,16 @@ impl AsByteSequence for QueryVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (client_major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (client_major_version, sz): (Card32, usize) =
+            <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (client_minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (client_minor_version, sz): (Card32, usize) =
+            <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionRequest {
@@ -719,6 +721,7 @@ impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -747,16 +750,16 @@ impl AsByteSequence for QueryVersionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         Some((
@@ -800,10 +803,10 @@ impl AsByteSequence for QueryPictFormatsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryPictFormatsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryPictFormatsRequest {
@@ -822,6 +825,7 @@ impl Request for QueryPictFormatsRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryPictFormats";
     type Reply = QueryPictFormatsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -865,34 +869,34 @@ impl AsByteSequence for QueryPictFormatsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryPictFormatsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_depths, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_depths, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_visuals, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_visuals, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len2, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
         let (formats, block_len): (Vec<Pictforminfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Pictforminfo>());
         let (screens, block_len): (Vec<Pictscreen>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Pictscreen>());
         let (subpixels, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len2 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -959,12 +963,12 @@ impl AsByteSequence for QueryPictIndexValuesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryPictIndexValuesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryPictIndexValuesRequest {
@@ -984,6 +988,7 @@ impl Request for QueryPictIndexValuesRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryPictIndexValues";
     type Reply = QueryPictIndexValuesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1013,18 +1018,18 @@ impl AsByteSequence for QueryPictIndexValuesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryPictIndexValuesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (values, block_len): (Vec<Indexvalue>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Indexvalue>());
         Some((
@@ -1132,71 +1137,72 @@ impl AsByteSequence for CreatePictureRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreatePictureRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pid, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (pid, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value_mask, sz): (Cp, usize) = <Cp>::from_bytes(&bytes[index..])?;
+        let (value_mask, sz): (Cp, usize) = <Cp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let cond0 = (value_mask);
         let repeat: Repeat = if cond0.repeat() {
-            let (repeat, sz): (Repeat, usize) = <Repeat>::from_bytes(&bytes[index..])?;
+            let (repeat, sz): (Repeat, usize) = <Repeat>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             repeat
         } else {
             Default::default()
         };
         let alphamap: Picture = if cond0.alpha_map() {
-            let (alphamap, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+            let (alphamap, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             alphamap
         } else {
             Default::default()
         };
         let alphaxorigin: Int32 = if cond0.alpha_x_origin() {
-            let (alphaxorigin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (alphaxorigin, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             alphaxorigin
         } else {
             Default::default()
         };
         let alphayorigin: Int32 = if cond0.alpha_y_origin() {
-            let (alphayorigin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (alphayorigin, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             alphayorigin
         } else {
             Default::default()
         };
         let clipxorigin: Int32 = if cond0.clip_x_origin() {
-            let (clipxorigin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (clipxorigin, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             clipxorigin
         } else {
             Default::default()
         };
         let clipyorigin: Int32 = if cond0.clip_y_origin() {
-            let (clipyorigin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (clipyorigin, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             clipyorigin
         } else {
             Default::default()
         };
         let clipmask: Pixmap = if cond0.clip_mask() {
-            let (clipmask, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+            let (clipmask, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             clipmask
         } else {
             Default::default()
         };
         let graphicsexposure: Card32 = if cond0.graphics_exposure() {
-            let (graphicsexposure, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (graphicsexposure, sz): (Card32, usize) =
+                <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             graphicsexposure
         } else {
@@ -1204,35 +1210,35 @@ impl AsByteSequence for CreatePictureRequest {
         };
         let subwindowmode: SubwindowMode = if cond0.subwindow_mode() {
             let (subwindowmode, sz): (SubwindowMode, usize) =
-                <SubwindowMode>::from_bytes(&bytes[index..])?;
+                <SubwindowMode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             subwindowmode
         } else {
             Default::default()
         };
         let polyedge: PolyEdge = if cond0.poly_edge() {
-            let (polyedge, sz): (PolyEdge, usize) = <PolyEdge>::from_bytes(&bytes[index..])?;
+            let (polyedge, sz): (PolyEdge, usize) = <PolyEdge>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             polyedge
         } else {
             Default::default()
         };
         let polymode: PolyMode = if cond0.poly_mode() {
-            let (polymode, sz): (PolyMode, usize) = <PolyMode>::from_bytes(&bytes[index..])?;
+            let (polymode, sz): (PolyMode, usize) = <PolyMode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             polymode
         } else {
             Default::default()
         };
         let dither: Atom = if cond0.dither() {
-            let (dither, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (dither, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             dither
         } else {
             Default::default()
         };
         let componentalpha: Card32 = if cond0.component_alpha() {
-            let (componentalpha, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (componentalpha, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             componentalpha
         } else {
@@ -1291,6 +1297,7 @@ impl Request for CreatePictureRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreatePicture";
     type Reply = ();
 }
 #[repr(transparent)]
@@ -1670,67 +1677,68 @@ impl AsByteSequence for ChangePictureRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangePictureRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value_mask, sz): (Cp, usize) = <Cp>::from_bytes(&bytes[index..])?;
+        let (value_mask, sz): (Cp, usize) = <Cp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let cond0 = (value_mask);
         let repeat: Repeat = if cond0.repeat() {
-            let (repeat, sz): (Repeat, usize) = <Repeat>::from_bytes(&bytes[index..])?;
+            let (repeat, sz): (Repeat, usize) = <Repeat>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             repeat
         } else {
             Default::default()
         };
         let alphamap: Picture = if cond0.alpha_map() {
-            let (alphamap, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+            let (alphamap, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             alphamap
         } else {
             Default::default()
         };
         let alphaxorigin: Int32 = if cond0.alpha_x_origin() {
-            let (alphaxorigin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (alphaxorigin, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             alphaxorigin
         } else {
             Default::default()
         };
         let alphayorigin: Int32 = if cond0.alpha_y_origin() {
-            let (alphayorigin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (alphayorigin, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             alphayorigin
         } else {
             Default::default()
         };
         let clipxorigin: Int32 = if cond0.clip_x_origin() {
-            let (clipxorigin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (clipxorigin, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             clipxorigin
         } else {
             Default::default()
         };
         let clipyorigin: Int32 = if cond0.clip_y_origin() {
-            let (clipyorigin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (clipyorigin, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             clipyorigin
         } else {
             Default::default()
         };
         let clipmask: Pixmap = if cond0.clip_mask() {
-            let (clipmask, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+            let (clipmask, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             clipmask
         } else {
             Default::default()
         };
         let graphicsexposure: Card32 = if cond0.graphics_exposure() {
-            let (graphicsexposure, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (graphicsexposure, sz): (Card32, usize) =
+                <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             graphicsexposure
         } else {
@@ -1738,35 +1746,35 @@ impl AsByteSequence for ChangePictureRequest {
         };
         let subwindowmode: SubwindowMode = if cond0.subwindow_mode() {
             let (subwindowmode, sz): (SubwindowMode, usize) =
-                <SubwindowMode>::from_bytes(&bytes[index..])?;
+                <SubwindowMode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             subwindowmode
         } else {
             Default::default()
         };
         let polyedge: PolyEdge = if cond0.poly_edge() {
-            let (polyedge, sz): (PolyEdge, usize) = <PolyEdge>::from_bytes(&bytes[index..])?;
+            let (polyedge, sz): (PolyEdge, usize) = <PolyEdge>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             polyedge
         } else {
             Default::default()
         };
         let polymode: PolyMode = if cond0.poly_mode() {
-            let (polymode, sz): (PolyMode, usize) = <PolyMode>::from_bytes(&bytes[index..])?;
+            let (polymode, sz): (PolyMode, usize) = <PolyMode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             polymode
         } else {
             Default::default()
         };
         let dither: Atom = if cond0.dither() {
-            let (dither, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (dither, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             dither
         } else {
             Default::default()
         };
         let componentalpha: Card32 = if cond0.component_alpha() {
-            let (componentalpha, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (componentalpha, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             componentalpha
         } else {
@@ -1821,6 +1829,7 @@ impl Request for ChangePictureRequest {
     const OPCODE: u8 = 5;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangePicture";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1852,19 +1861,21 @@ impl AsByteSequence for SetPictureClipRectanglesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetPictureClipRectanglesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (clip_x_origin, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (clip_x_origin, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (clip_y_origin, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (clip_y_origin, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (rectangles, block_len): (Vec<Rectangle>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (rectangles, block_len): (Vec<Rectangle>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
         Some((
@@ -1898,6 +1909,7 @@ impl Request for SetPictureClipRectanglesRequest {
     const OPCODE: u8 = 6;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetPictureClipRectangles";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1921,12 +1933,12 @@ impl AsByteSequence for FreePictureRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FreePictureRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             FreePictureRequest {
@@ -1946,6 +1958,7 @@ impl Request for FreePictureRequest {
     const OPCODE: u8 = 7;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FreePicture";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1992,35 +2005,35 @@ impl AsByteSequence for CompositeRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CompositeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(&bytes[index..])?;
+        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (src, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (src, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mask, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (mask, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mask_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (mask_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mask_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (mask_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (dst_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (dst_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CompositeRequest {
@@ -2066,6 +2079,7 @@ impl Request for CompositeRequest {
     const OPCODE: u8 = 8;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Composite";
     type Reply = ();
 }
 #[repr(u8)]
@@ -2238,26 +2252,28 @@ impl AsByteSequence for TrapezoidsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing TrapezoidsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(&bytes[index..])?;
+        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (src, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (src, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mask_format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (mask_format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (traps, block_len): (Vec<Trapezoid>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (traps, block_len): (Vec<Trapezoid>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Trapezoid>());
         Some((
@@ -2298,6 +2314,7 @@ impl Request for TrapezoidsRequest {
     const OPCODE: u8 = 10;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Trapezoids";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2336,26 +2353,28 @@ impl AsByteSequence for TrianglesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing TrianglesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(&bytes[index..])?;
+        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (src, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (src, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mask_format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (mask_format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (triangles, block_len): (Vec<Triangle>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (triangles, block_len): (Vec<Triangle>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Triangle>());
         Some((
@@ -2396,6 +2415,7 @@ impl Request for TrianglesRequest {
     const OPCODE: u8 = 11;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Triangles";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2434,26 +2454,28 @@ impl AsByteSequence for TriStripRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing TriStripRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(&bytes[index..])?;
+        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (src, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (src, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mask_format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (mask_format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (points, block_len): (Vec<Pointfix>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (points, block_len): (Vec<Pointfix>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Pointfix>());
         Some((
@@ -2494,6 +2516,7 @@ impl Request for TriStripRequest {
     const OPCODE: u8 = 12;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "TriStrip";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2532,26 +2555,28 @@ impl AsByteSequence for TriFanRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing TriFanRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(&bytes[index..])?;
+        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (src, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (src, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mask_format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (mask_format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (points, block_len): (Vec<Pointfix>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (points, block_len): (Vec<Pointfix>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Pointfix>());
         Some((
@@ -2592,6 +2617,7 @@ impl Request for TriFanRequest {
     const OPCODE: u8 = 13;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "TriFan";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2617,14 +2643,14 @@ impl AsByteSequence for CreateGlyphSetRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateGlyphSetRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gsid, sz): (Glyphset, usize) = <Glyphset>::from_bytes(&bytes[index..])?;
+        let (gsid, sz): (Glyphset, usize) = <Glyphset>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CreateGlyphSetRequest {
@@ -2645,6 +2671,7 @@ impl Request for CreateGlyphSetRequest {
     const OPCODE: u8 = 17;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateGlyphSet";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2670,14 +2697,14 @@ impl AsByteSequence for ReferenceGlyphSetRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ReferenceGlyphSetRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gsid, sz): (Glyphset, usize) = <Glyphset>::from_bytes(&bytes[index..])?;
+        let (gsid, sz): (Glyphset, usize) = <Glyphset>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (existing, sz): (Glyphset, usize) = <Glyphset>::from_bytes(&bytes[index..])?;
+        let (existing, sz): (Glyphset, usize) = <Glyphset>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ReferenceGlyphSetRequest {
@@ -2698,6 +2725,7 @@ impl Request for ReferenceGlyphSetRequest {
     const OPCODE: u8 = 18;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ReferenceGlyphSet";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2721,12 +2749,12 @@ impl AsByteSequence for FreeGlyphSetRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FreeGlyphSetRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (glyphset, sz): (Glyphset, usize) = <Glyphset>::from_bytes(&bytes[index..])?;
+        let (glyphset, sz): (Glyphset, usize) = <Glyphset>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             FreeGlyphSetRequest {
@@ -2746,6 +2774,7 @@ impl Request for FreeGlyphSetRequest {
     const OPCODE: u8 = 19;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FreeGlyphSet";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2783,25 +2812,27 @@ impl AsByteSequence for AddGlyphsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AddGlyphsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (glyphset, sz): (Glyphset, usize) = <Glyphset>::from_bytes(&bytes[index..])?;
+        let (glyphset, sz): (Glyphset, usize) = <Glyphset>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (glyphs_len, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (glyphs_len, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (glyphids, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (glyphs_len as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (glyphs_len as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (glyphs, block_len): (Vec<Glyphinfo>, usize) =
-            vector_from_bytes(&bytes[index..], (glyphs_len as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (glyphs_len as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Glyphinfo>());
-        let (data, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (data, block_len): (Vec<Byte>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -2845,6 +2876,7 @@ impl Request for AddGlyphsRequest {
     const OPCODE: u8 = 20;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "AddGlyphs";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2872,15 +2904,17 @@ impl AsByteSequence for FreeGlyphsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FreeGlyphsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (glyphset, sz): (Glyphset, usize) = <Glyphset>::from_bytes(&bytes[index..])?;
+        let (glyphset, sz): (Glyphset, usize) = <Glyphset>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (glyphs, block_len): (Vec<Glyph>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (glyphs, block_len): (Vec<Glyph>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Glyph>());
         Some((
@@ -2906,6 +2940,7 @@ impl Request for FreeGlyphsRequest {
     const OPCODE: u8 = 22;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FreeGlyphs";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2946,28 +2981,30 @@ impl AsByteSequence for CompositeGlyphs8Request {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CompositeGlyphs8Request from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(&bytes[index..])?;
+        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (src, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (src, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mask_format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (mask_format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (glyphset, sz): (Glyphset, usize) = <Glyphset>::from_bytes(&bytes[index..])?;
+        let (glyphset, sz): (Glyphset, usize) = <Glyphset>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (glyphcmds, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (glyphcmds, block_len): (Vec<Byte>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -3010,6 +3047,7 @@ impl Request for CompositeGlyphs8Request {
     const OPCODE: u8 = 23;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CompositeGlyphs8";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3050,28 +3088,30 @@ impl AsByteSequence for CompositeGlyphs16Request {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CompositeGlyphs16Request from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(&bytes[index..])?;
+        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (src, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (src, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mask_format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (mask_format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (glyphset, sz): (Glyphset, usize) = <Glyphset>::from_bytes(&bytes[index..])?;
+        let (glyphset, sz): (Glyphset, usize) = <Glyphset>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (glyphcmds, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (glyphcmds, block_len): (Vec<Byte>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -3114,6 +3154,7 @@ impl Request for CompositeGlyphs16Request {
     const OPCODE: u8 = 24;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CompositeGlyphs16";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3154,28 +3195,30 @@ impl AsByteSequence for CompositeGlyphs32Request {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CompositeGlyphs32Request from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(&bytes[index..])?;
+        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (src, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (src, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mask_format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(&bytes[index..])?;
+        let (mask_format, sz): (Pictformat, usize) = <Pictformat>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (glyphset, sz): (Glyphset, usize) = <Glyphset>::from_bytes(&bytes[index..])?;
+        let (glyphset, sz): (Glyphset, usize) = <Glyphset>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (glyphcmds, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (glyphcmds, block_len): (Vec<Byte>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -3218,6 +3261,7 @@ impl Request for CompositeGlyphs32Request {
     const OPCODE: u8 = 25;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CompositeGlyphs32";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3250,20 +3294,22 @@ impl AsByteSequence for FillRectanglesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FillRectanglesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(&bytes[index..])?;
+        let (op, sz): (PictOp, usize) = <PictOp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (dst, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (color, sz): (Color, usize) = <Color>::from_bytes(&bytes[index..])?;
+        let (color, sz): (Color, usize) = <Color>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (rects, block_len): (Vec<Rectangle>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (rects, block_len): (Vec<Rectangle>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
         Some((
@@ -3298,6 +3344,7 @@ impl Request for FillRectanglesRequest {
     const OPCODE: u8 = 26;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FillRectangles";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3327,18 +3374,18 @@ impl AsByteSequence for CreateCursorRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateCursorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cid, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (cid, sz): (Cursor, usize) = <Cursor>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (source, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (source, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CreateCursorRequest {
@@ -3367,6 +3414,7 @@ impl Request for CreateCursorRequest {
     const OPCODE: u8 = 27;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateCursor";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3401,23 +3449,23 @@ impl AsByteSequence for Transform {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Transform from byte buffer");
-        let (matrix11, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (matrix11, sz): (Fixed, usize) = <Fixed>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (matrix12, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (matrix12, sz): (Fixed, usize) = <Fixed>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (matrix13, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (matrix13, sz): (Fixed, usize) = <Fixed>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (matrix21, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (matrix21, sz): (Fixed, usize) = <Fixed>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (matrix22, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (matrix22, sz): (Fixed, usize) = <Fixed>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (matrix23, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (matrix23, sz): (Fixed, usize) = <Fixed>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (matrix31, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (matrix31, sz): (Fixed, usize) = <Fixed>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (matrix32, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (matrix32, sz): (Fixed, usize) = <Fixed>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (matrix33, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (matrix33, sz): (Fixed, usize) = <Fixed>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Transform {
@@ -3470,14 +3518,14 @@ impl AsByteSequence for SetPictureTransformRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetPictureTransformRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (transform, sz): (Transform, usize) = <Transform>::from_bytes(&bytes[index..])?;
+        let (transform, sz): (Transform, usize) = <Transform>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetPictureTransformRequest {
@@ -3498,6 +3546,7 @@ impl Request for SetPictureTransformRequest {
     const OPCODE: u8 = 28;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetPictureTransform";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3521,12 +3570,12 @@ impl AsByteSequence for QueryFiltersRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryFiltersRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryFiltersRequest {
@@ -3546,6 +3595,7 @@ impl Request for QueryFiltersRequest {
     const OPCODE: u8 = 29;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryFilters";
     type Reply = QueryFiltersReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3580,24 +3630,24 @@ impl AsByteSequence for QueryFiltersReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryFiltersReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         let (aliases, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
         let (filters, block_len): (Vec<Str>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Str>());
         Some((
@@ -3663,22 +3713,24 @@ impl AsByteSequence for SetPictureFilterRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetPictureFilterRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (filter, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, 4);
-        let (values, block_len): (Vec<Fixed>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (values, block_len): (Vec<Fixed>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
         Some((
@@ -3716,6 +3768,7 @@ impl Request for SetPictureFilterRequest {
     const OPCODE: u8 = 30;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetPictureFilter";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3736,9 +3789,9 @@ impl AsByteSequence for Animcursorelt {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Animcursorelt from byte buffer");
-        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (delay, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (delay, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Animcursorelt {
@@ -3778,15 +3831,17 @@ impl AsByteSequence for CreateAnimCursorRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateAnimCursorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cid, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (cid, sz): (Cursor, usize) = <Cursor>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cursors, block_len): (Vec<Animcursorelt>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (cursors, block_len): (Vec<Animcursorelt>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Animcursorelt>());
         Some((
@@ -3812,6 +3867,7 @@ impl Request for CreateAnimCursorRequest {
     const OPCODE: u8 = 31;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateAnimCursor";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3834,11 +3890,11 @@ impl AsByteSequence for Spanfix {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Spanfix from byte buffer");
-        let (l, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (l, sz): (Fixed, usize) = <Fixed>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (r, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (r, sz): (Fixed, usize) = <Fixed>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Fixed, usize) = <Fixed>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((Spanfix { l: l, r: r, y: y }, index))
     }
@@ -3865,9 +3921,9 @@ impl AsByteSequence for Trap {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Trap from byte buffer");
-        let (top, sz): (Spanfix, usize) = <Spanfix>::from_bytes(&bytes[index..])?;
+        let (top, sz): (Spanfix, usize) = <Spanfix>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bot, sz): (Spanfix, usize) = <Spanfix>::from_bytes(&bytes[index..])?;
+        let (bot, sz): (Spanfix, usize) = <Spanfix>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((Trap { top: top, bot: bot }, index))
     }
@@ -3905,19 +3961,21 @@ impl AsByteSequence for AddTrapsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AddTrapsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x_off, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x_off, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y_off, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y_off, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (traps, block_len): (Vec<Trap>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (traps, block_len): (Vec<Trap>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Trap>());
         Some((
@@ -3951,6 +4009,7 @@ impl Request for AddTrapsRequest {
     const OPCODE: u8 = 32;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "AddTraps";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3976,14 +4035,14 @@ impl AsByteSequence for CreateSolidFillRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateSolidFillRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (color, sz): (Color, usize) = <Color>::from_bytes(&bytes[index..])?;
+        let (color, sz): (Color, usize) = <Color>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CreateSolidFillRequest {
@@ -4004,6 +4063,7 @@ impl Request for CreateSolidFillRequest {
     const OPCODE: u8 = 33;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateSolidFill";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4041,25 +4101,25 @@ impl AsByteSequence for CreateLinearGradientRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateLinearGradientRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (p1, sz): (Pointfix, usize) = <Pointfix>::from_bytes(&bytes[index..])?;
+        let (p1, sz): (Pointfix, usize) = <Pointfix>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (p2, sz): (Pointfix, usize) = <Pointfix>::from_bytes(&bytes[index..])?;
+        let (p2, sz): (Pointfix, usize) = <Pointfix>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_stops, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_stops, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (stops, block_len): (Vec<Fixed>, usize) =
-            vector_from_bytes(&bytes[index..], (num_stops as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_stops as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
         let (colors, block_len): (Vec<Color>, usize) =
-            vector_from_bytes(&bytes[index..], (num_stops as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_stops as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Color>());
         Some((
@@ -4101,6 +4161,7 @@ impl Request for CreateLinearGradientRequest {
     const OPCODE: u8 = 34;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateLinearGradient";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4142,29 +4203,29 @@ impl AsByteSequence for CreateRadialGradientRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateRadialGradientRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (inner, sz): (Pointfix, usize) = <Pointfix>::from_bytes(&bytes[index..])?;
+        let (inner, sz): (Pointfix, usize) = <Pointfix>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (outer, sz): (Pointfix, usize) = <Pointfix>::from_bytes(&bytes[index..])?;
+        let (outer, sz): (Pointfix, usize) = <Pointfix>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (inner_radius, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (inner_radius, sz): (Fixed, usize) = <Fixed>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (outer_radius, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (outer_radius, sz): (Fixed, usize) = <Fixed>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_stops, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_stops, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (stops, block_len): (Vec<Fixed>, usize) =
-            vector_from_bytes(&bytes[index..], (num_stops as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_stops as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
         let (colors, block_len): (Vec<Color>, usize) =
-            vector_from_bytes(&bytes[index..], (num_stops as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_stops as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Color>());
         Some((
@@ -4210,6 +4271,7 @@ impl Request for CreateRadialGradientRequest {
     const OPCODE: u8 = 35;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateRadialGradient";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4247,25 +4309,25 @@ impl AsByteSequence for CreateConicalGradientRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateConicalGradientRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (center, sz): (Pointfix, usize) = <Pointfix>::from_bytes(&bytes[index..])?;
+        let (center, sz): (Pointfix, usize) = <Pointfix>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (angle, sz): (Fixed, usize) = <Fixed>::from_bytes(&bytes[index..])?;
+        let (angle, sz): (Fixed, usize) = <Fixed>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_stops, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_stops, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (stops, block_len): (Vec<Fixed>, usize) =
-            vector_from_bytes(&bytes[index..], (num_stops as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_stops as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fixed>());
         let (colors, block_len): (Vec<Color>, usize) =
-            vector_from_bytes(&bytes[index..], (num_stops as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_stops as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Color>());
         Some((
@@ -4307,6 +4369,7 @@ impl Request for CreateConicalGradientRequest {
     const OPCODE: u8 = 36;
     const EXTENSION: Option<&'static str> = Some("RENDER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateConicalGradient";
     type Reply = ();
 }
 #[repr(i32)]
@@ -24,9 +24,9 @@ impl AsByteSequence for Client {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Client from byte buffer");
-        let (resource_base, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (resource_base, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (resource_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (resource_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Client {
@@ -59,9 +59,9 @@ impl AsByteSequence for Type {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Type from byte buffer");
-        let (resource_type, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (resource_type, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (count, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (count, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Type {
@@ -94,9 +94,9 @@ impl AsByteSequence for ClientIdSpec {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ClientIdSpec from byte buffer");
-        let (client, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (client, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mask, sz): (ClientIdMask, usize) = <ClientIdMask>::from_bytes(&bytes[index..])?;
+        let (mask, sz): (ClientIdMask, usize) = <ClientIdMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ClientIdSpec {
@@ -233,12 +233,12 @@ impl AsByteSequence for ClientIdValue {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ClientIdValue from byte buffer");
-        let (spec, sz): (ClientIdSpec, usize) = <ClientIdSpec>::from_bytes(&bytes[index..])?;
+        let (spec, sz): (ClientIdSpec, usize) = <ClientIdSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (value, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize) / (4)) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((length as usize) / (4)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -277,9 +277,9 @@ impl AsByteSequence for ResourceIdSpec {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ResourceIdSpec from byte buffer");
-        let (resource, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (resource, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ResourceIdSpec {
@@ -316,13 +316,14 @@ impl AsByteSequence for ResourceSizeSpec {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ResourceSizeSpec from byte buffer");
-        let (spec, sz): (ResourceIdSpec, usize) = <ResourceIdSpec>::from_bytes(&bytes[index..])?;
+        let (spec, sz): (ResourceIdSpec, usize) =
+            <ResourceIdSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bytes_, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bytes_, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ref_count, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ref_count, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (use_count, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (use_count, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ResourceSizeSpec {
@@ -361,12 +362,12 @@ impl AsByteSequence for ResourceSizeValue {
         let mut index: usize = 0;
         log::trace!("Deserializing ResourceSizeValue from byte buffer");
         let (size, sz): (ResourceSizeSpec, usize) =
-            <ResourceSizeSpec>::from_bytes(&bytes[index..])?;
+            <ResourceSizeSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (cross_references, block_len): (Vec<ResourceSizeSpec>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ResourceSizeSpec>());
         Some((
@@ -409,14 +410,14 @@ impl AsByteSequence for QueryVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (client_major, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (client_major, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (client_minor, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (client_minor, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionRequest {
@@ -441,6 +442,7 @@ impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("X-Resource");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -468,16 +470,16 @@ impl AsByteSequence for QueryVersionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (server_major, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (server_major, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (server_minor, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (server_minor, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionReply {
@@ -519,10 +521,10 @@ impl AsByteSequence for QueryClientsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryClientsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryClientsRequest {
@@ -541,6 +543,7 @@ impl Request for QueryClientsRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = Some("X-Resource");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryClients";
     type Reply = QueryClientsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -570,18 +573,18 @@ impl AsByteSequence for QueryClientsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryClientsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (clients, block_len): (Vec<Client>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Client>());
         Some((
@@ -630,12 +633,12 @@ impl AsByteSequence for QueryClientResourcesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryClientResourcesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xid, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (xid, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryClientResourcesRequest {
@@ -655,6 +658,7 @@ impl Request for QueryClientResourcesRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = Some("X-Resource");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryClientResources";
     type Reply = QueryClientResourcesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -684,18 +688,18 @@ impl AsByteSequence for QueryClientResourcesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryClientResourcesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (types, block_len): (Vec<Type>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Type>());
         Some((
@@ -744,12 +748,12 @@ impl AsByteSequence for QueryClientPixmapBytesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryClientPixmapBytesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xid, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (xid, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryClientPixmapBytesRequest {
@@ -769,6 +773,7 @@ impl Request for QueryClientPixmapBytesRequest {
     const OPCODE: u8 = 3;
     const EXTENSION: Option<&'static str> = Some("X-Resource");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryClientPixmapBytes";
     type Reply = QueryClientPixmapBytesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -796,16 +801,16 @@ impl AsByteSequence for QueryClientPixmapBytesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryClientPixmapBytesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bytes_, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bytes_, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bytes_overflow, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bytes_overflow, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryClientPixmapBytesReply {
@@ -852,15 +857,15 @@ impl AsByteSequence for QueryClientIdsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryClientIdsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (specs, block_len): (Vec<ClientIdSpec>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ClientIdSpec>());
         Some((
@@ -885,6 +890,7 @@ impl Request for QueryClientIdsRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = Some("X-Resource");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryClientIds";
     type Reply = QueryClientIdsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -914,18 +920,18 @@ impl AsByteSequence for QueryClientIdsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryClientIdsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (ids, block_len): (Vec<ClientIdValue>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ClientIdValue>());
         Some((
@@ -979,17 +985,17 @@ impl AsByteSequence for QueryResourceBytesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryResourceBytesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (client, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (client, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (specs, block_len): (Vec<ResourceIdSpec>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ResourceIdSpec>());
         Some((
@@ -1020,6 +1026,7 @@ impl Request for QueryResourceBytesRequest {
     const OPCODE: u8 = 5;
     const EXTENSION: Option<&'static str> = Some("X-Resource");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryResourceBytes";
     type Reply = QueryResourceBytesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1049,18 +1056,18 @@ impl AsByteSequence for QueryResourceBytesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryResourceBytesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (sizes, block_len): (Vec<ResourceSizeValue>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ResourceSizeValue>());
         Some((
@@ -30,14 +30,14 @@ impl AsByteSequence for QueryVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (client_major_version, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (client_major_version, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (client_minor_version, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (client_minor_version, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -64,6 +64,7 @@ impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("MIT-SCREEN-SAVER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -92,16 +93,18 @@ impl AsByteSequence for QueryVersionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (server_major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (server_major_version, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (server_minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (server_minor_version, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         Some((
@@ -147,12 +150,12 @@ impl AsByteSequence for QueryInfoRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryInfoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryInfoRequest {
@@ -172,6 +175,7 @@ impl Request for QueryInfoRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = Some("MIT-SCREEN-SAVER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryInfo";
     type Reply = QueryInfoReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -207,23 +211,23 @@ impl AsByteSequence for QueryInfoReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryInfoReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (state, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (saver_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (saver_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ms_until_server, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ms_until_server, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ms_since_user_input, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ms_since_user_input, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (event_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (kind, sz): (Kind, usize) = <Kind>::from_bytes(&bytes[index..])?;
+        let (kind, sz): (Kind, usize) = <Kind>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 7;
         Some((
@@ -311,14 +315,14 @@ impl AsByteSequence for SelectInputRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectInputRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_mask, sz): (Event, usize) = <Event>::from_bytes(&bytes[index..])?;
+        let (event_mask, sz): (Event, usize) = <Event>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SelectInputRequest {
@@ -343,6 +347,7 @@ impl Request for SelectInputRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = Some("MIT-SCREEN-SAVER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SelectInput";
     type Reply = ();
 }
 #[repr(transparent)]
@@ -545,69 +550,71 @@ impl AsByteSequence for SetAttributesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetAttributesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (border_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (border_width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (class, sz): (WindowClass, usize) = <WindowClass>::from_bytes(&bytes[index..])?;
+        let (class, sz): (WindowClass, usize) = <WindowClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(&bytes[index..])?;
+        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value_mask, sz): (Cw, usize) = <Cw>::from_bytes(&bytes[index..])?;
+        let (value_mask, sz): (Cw, usize) = <Cw>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let cond0 = (value_mask);
         let background_pixmap: Pixmap = if cond0.back_pixmap() {
-            let (background_pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+            let (background_pixmap, sz): (Pixmap, usize) =
+                <Pixmap>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             background_pixmap
         } else {
             Default::default()
         };
         let background_pixel: Card32 = if cond0.back_pixel() {
-            let (background_pixel, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (background_pixel, sz): (Card32, usize) =
+                <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             background_pixel
         } else {
             Default::default()
         };
         let border_pixmap: Pixmap = if cond0.border_pixmap() {
-            let (border_pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+            let (border_pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             border_pixmap
         } else {
             Default::default()
         };
         let border_pixel: Card32 = if cond0.border_pixel() {
-            let (border_pixel, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (border_pixel, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             border_pixel
         } else {
             Default::default()
         };
         let bit_gravity: Gravity = if cond0.bit_gravity() {
-            let (bit_gravity, sz): (Gravity, usize) = <Gravity>::from_bytes(&bytes[index..])?;
+            let (bit_gravity, sz): (Gravity, usize) = <Gravity>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             bit_gravity
         } else {
             Default::default()
         };
         let win_gravity: Gravity = if cond0.win_gravity() {
-            let (win_gravity, sz): (Gravity, usize) = <Gravity>::from_bytes(&bytes[index..])?;
+            let (win_gravity, sz): (Gravity, usize) = <Gravity>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             win_gravity
         } else {
@@ -615,42 +622,44 @@ impl AsByteSequence for SetAttributesRequest {
         };
         let backing_store: BackingStore = if cond0.backing_store() {
             let (backing_store, sz): (BackingStore, usize) =
-                <BackingStore>::from_bytes(&bytes[index..])?;
+                <BackingStore>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             backing_store
         } else {
             Default::default()
         };
         let backing_planes: Card32 = if cond0.backing_planes() {
-            let (backing_planes, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (backing_planes, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             backing_planes
         } else {
             Default::default()
         };
         let backing_pixel: Card32 = if cond0.backing_pixel() {
-            let (backing_pixel, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (backing_pixel, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             backing_pixel
         } else {
             Default::default()
         };
         let override_redirect: Bool32 = if cond0.override_redirect() {
-            let (override_redirect, sz): (Bool32, usize) = <Bool32>::from_bytes(&bytes[index..])?;
+            let (override_redirect, sz): (Bool32, usize) =
+                <Bool32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             override_redirect
         } else {
             Default::default()
         };
         let save_under: Bool32 = if cond0.save_under() {
-            let (save_under, sz): (Bool32, usize) = <Bool32>::from_bytes(&bytes[index..])?;
+            let (save_under, sz): (Bool32, usize) = <Bool32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             save_under
         } else {
             Default::default()
         };
         let event_mask: EventMask = if cond0.event_mask() {
-            let (event_mask, sz): (EventMask, usize) = <EventMask>::from_bytes(&bytes[index..])?;
+            let (event_mask, sz): (EventMask, usize) =
+                <EventMask>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             event_mask
         } else {
@@ -658,21 +667,21 @@ impl AsByteSequence for SetAttributesRequest {
         };
         let do_not_propogate_mask: EventMask = if cond0.dont_propagate() {
             let (do_not_propogate_mask, sz): (EventMask, usize) =
-                <EventMask>::from_bytes(&bytes[index..])?;
+                <EventMask>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             do_not_propogate_mask
         } else {
             Default::default()
         };
         let colormap: Colormap = if cond0.colormap() {
-            let (colormap, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+            let (colormap, sz): (Colormap, usize) = <Colormap>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             colormap
         } else {
             Default::default()
         };
         let cursor: Cursor = if cond0.cursor() {
-            let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+            let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             cursor
         } else {
@@ -747,6 +756,7 @@ impl Request for SetAttributesRequest {
     const OPCODE: u8 = 3;
     const EXTENSION: Option<&'static str> = Some("MIT-SCREEN-SAVER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetAttributes";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -770,12 +780,12 @@ impl AsByteSequence for UnsetAttributesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing UnsetAttributesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             UnsetAttributesRequest {
@@ -795,6 +805,7 @@ impl Request for UnsetAttributesRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = Some("MIT-SCREEN-SAVER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UnsetAttributes";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -818,12 +829,12 @@ impl AsByteSequence for SuspendRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SuspendRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (suspend, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (suspend, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SuspendRequest {
@@ -843,6 +854,7 @@ impl Request for SuspendRequest {
     const OPCODE: u8 = 5;
     const EXTENSION: Option<&'static str> = Some("MIT-SCREEN-SAVER");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Suspend";
     type Reply = ();
 }
 #[repr(u8)]
@@ -911,21 +923,21 @@ impl AsByteSequence for NotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing NotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (State, usize) = <State>::from_bytes(&bytes[index..])?;
+        let (state, sz): (State, usize) = <State>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (kind, sz): (Kind, usize) = <Kind>::from_bytes(&bytes[index..])?;
+        let (kind, sz): (Kind, usize) = <Kind>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (forced, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (forced, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 14;
         Some((
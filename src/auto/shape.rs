@@ -60,10 +60,10 @@ impl AsByteSequence for QueryVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionRequest {
@@ -82,6 +82,7 @@ impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SHAPE");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -109,16 +110,16 @@ impl AsByteSequence for QueryVersionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionReply {
@@ -177,26 +178,29 @@ impl AsByteSequence for RectanglesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing RectanglesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (operation, sz): (So, usize) = <So>::from_bytes(&bytes[index..])?;
+        let (operation, sz): (So, usize) = <So>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (destination_kind, sz): (Sk, usize) = <Sk>::from_bytes(&bytes[index..])?;
+        let (destination_kind, sz): (Sk, usize) = <Sk>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ordering, sz): (ClipOrdering, usize) = <ClipOrdering>::from_bytes(&bytes[index..])?;
+        let (ordering, sz): (ClipOrdering, usize) =
+            <ClipOrdering>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (destination_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (destination_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x_offset, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x_offset, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y_offset, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y_offset, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (rectangles, block_len): (Vec<Rectangle>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (rectangles, block_len): (Vec<Rectangle>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
         Some((
@@ -237,6 +241,7 @@ impl Request for RectanglesRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = Some("SHAPE");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Rectangles";
     type Reply = ();
 }
 #[repr(u8)]
@@ -308,23 +313,23 @@ impl AsByteSequence for MaskRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing MaskRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (operation, sz): (So, usize) = <So>::from_bytes(&bytes[index..])?;
+        let (operation, sz): (So, usize) = <So>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (destination_kind, sz): (Sk, usize) = <Sk>::from_bytes(&bytes[index..])?;
+        let (destination_kind, sz): (Sk, usize) = <Sk>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (destination_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (destination_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x_offset, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x_offset, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y_offset, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y_offset, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (source_bitmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (source_bitmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             MaskRequest {
@@ -358,6 +363,7 @@ impl Request for MaskRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = Some("SHAPE");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Mask";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -394,25 +400,25 @@ impl AsByteSequence for CombineRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CombineRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (operation, sz): (So, usize) = <So>::from_bytes(&bytes[index..])?;
+        let (operation, sz): (So, usize) = <So>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (destination_kind, sz): (Sk, usize) = <Sk>::from_bytes(&bytes[index..])?;
+        let (destination_kind, sz): (Sk, usize) = <Sk>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (source_kind, sz): (Sk, usize) = <Sk>::from_bytes(&bytes[index..])?;
+        let (source_kind, sz): (Sk, usize) = <Sk>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (destination_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (destination_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x_offset, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x_offset, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y_offset, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y_offset, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (source_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (source_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CombineRequest {
@@ -448,6 +454,7 @@ impl Request for CombineRequest {
     const OPCODE: u8 = 3;
     const EXTENSION: Option<&'static str> = Some("SHAPE");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Combine";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -478,19 +485,19 @@ impl AsByteSequence for OffsetRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing OffsetRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (destination_kind, sz): (Sk, usize) = <Sk>::from_bytes(&bytes[index..])?;
+        let (destination_kind, sz): (Sk, usize) = <Sk>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (destination_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (destination_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x_offset, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x_offset, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y_offset, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y_offset, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             OffsetRequest {
@@ -520,6 +527,7 @@ impl Request for OffsetRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = Some("SHAPE");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Offset";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -543,12 +551,12 @@ impl AsByteSequence for QueryExtentsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryExtentsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (destination_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (destination_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryExtentsRequest {
@@ -568,6 +576,7 @@ impl Request for QueryExtentsRequest {
     const OPCODE: u8 = 5;
     const EXTENSION: Option<&'static str> = Some("SHAPE");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryExtents";
     type Reply = QueryExtentsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -616,37 +625,39 @@ impl AsByteSequence for QueryExtentsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryExtentsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bounding_shaped, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (bounding_shaped, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (clip_shaped, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (clip_shaped, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (bounding_shape_extents_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (bounding_shape_extents_x, sz): (Int16, usize) =
+            <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bounding_shape_extents_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (bounding_shape_extents_y, sz): (Int16, usize) =
+            <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (bounding_shape_extents_width, sz): (Card16, usize) =
-            <Card16>::from_bytes(&bytes[index..])?;
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (bounding_shape_extents_height, sz): (Card16, usize) =
-            <Card16>::from_bytes(&bytes[index..])?;
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (clip_shape_extents_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (clip_shape_extents_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (clip_shape_extents_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (clip_shape_extents_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (clip_shape_extents_width, sz): (Card16, usize) =
-            <Card16>::from_bytes(&bytes[index..])?;
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (clip_shape_extents_height, sz): (Card16, usize) =
-            <Card16>::from_bytes(&bytes[index..])?;
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryExtentsReply {
@@ -710,14 +721,14 @@ impl AsByteSequence for SelectInputRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectInputRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (destination_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (destination_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (enable, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (enable, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -744,6 +755,7 @@ impl Request for SelectInputRequest {
     const OPCODE: u8 = 6;
     const EXTENSION: Option<&'static str> = Some("SHAPE");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SelectInput";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -767,12 +779,12 @@ impl AsByteSequence for InputSelectedRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing InputSelectedRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (destination_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (destination_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             InputSelectedRequest {
@@ -792,6 +804,7 @@ impl Request for InputSelectedRequest {
     const OPCODE: u8 = 7;
     const EXTENSION: Option<&'static str> = Some("SHAPE");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "InputSelected";
     type Reply = InputSelectedReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -816,13 +829,13 @@ impl AsByteSequence for InputSelectedReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing InputSelectedReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (enabled, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (enabled, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             InputSelectedReply {
@@ -863,14 +876,14 @@ impl AsByteSequence for GetRectanglesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetRectanglesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (source_kind, sz): (Sk, usize) = <Sk>::from_bytes(&bytes[index..])?;
+        let (source_kind, sz): (Sk, usize) = <Sk>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -897,6 +910,7 @@ impl Request for GetRectanglesRequest {
     const OPCODE: u8 = 8;
     const EXTENSION: Option<&'static str> = Some("SHAPE");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetRectangles";
     type Reply = GetRectanglesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -927,19 +941,20 @@ impl AsByteSequence for GetRectanglesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetRectanglesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ordering, sz): (ClipOrdering, usize) = <ClipOrdering>::from_bytes(&bytes[index..])?;
+        let (ordering, sz): (ClipOrdering, usize) =
+            <ClipOrdering>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (rectangles, block_len): (Vec<Rectangle>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
         Some((
@@ -1003,25 +1018,25 @@ impl AsByteSequence for NotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing NotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (shape_kind, sz): (Sk, usize) = <Sk>::from_bytes(&bytes[index..])?;
+        let (shape_kind, sz): (Sk, usize) = <Sk>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (affected_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (affected_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (extents_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (extents_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (extents_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (extents_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (extents_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (extents_width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (extents_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (extents_height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (server_time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (server_time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (shaped, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (shaped, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 11;
         Some((
@@ -46,10 +46,10 @@ impl AsByteSequence for QueryVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionRequest {
@@ -68,6 +68,7 @@ impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("MIT-SHM");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -103,23 +104,23 @@ impl AsByteSequence for QueryVersionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (shared_pixmaps, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (shared_pixmaps, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (uid, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (uid, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gid, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (gid, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pixmap_format, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (pixmap_format, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 15;
         Some((
@@ -177,16 +178,16 @@ impl AsByteSequence for AttachRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AttachRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(&bytes[index..])?;
+        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (shmid, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (shmid, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (read_only, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (read_only, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -215,6 +216,7 @@ impl Request for AttachRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = Some("MIT-SHM");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Attach";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -238,12 +240,12 @@ impl AsByteSequence for DetachRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DetachRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(&bytes[index..])?;
+        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DetachRequest {
@@ -263,6 +265,7 @@ impl Request for DetachRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = Some("MIT-SHM");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Detach";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -315,41 +318,41 @@ impl AsByteSequence for PutImageRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PutImageRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (total_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (total_width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (total_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (total_height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_x, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_y, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (src_width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (src_height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (dst_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (dst_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (send_event, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (send_event, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(&bytes[index..])?;
+        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (offset, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PutImageRequest {
@@ -401,6 +404,7 @@ impl Request for PutImageRequest {
     const OPCODE: u8 = 3;
     const EXTENSION: Option<&'static str> = Some("MIT-SHM");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PutImage";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -441,29 +445,29 @@ impl AsByteSequence for GetImageRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetImageRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (plane_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (plane_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(&bytes[index..])?;
+        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (offset, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetImageRequest {
@@ -503,6 +507,7 @@ impl Request for GetImageRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = Some("MIT-SHM");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetImage";
     type Reply = GetImageReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -531,17 +536,17 @@ impl AsByteSequence for GetImageReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetImageReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(&bytes[index..])?;
+        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (size, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (size, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetImageReply {
@@ -599,25 +604,25 @@ impl AsByteSequence for CreatePixmapRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreatePixmapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pid, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (pid, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(&bytes[index..])?;
+        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (offset, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CreatePixmapRequest {
@@ -653,6 +658,7 @@ impl Request for CreatePixmapRequest {
     const OPCODE: u8 = 5;
     const EXTENSION: Option<&'static str> = Some("MIT-SHM");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreatePixmap";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -680,14 +686,14 @@ impl AsByteSequence for AttachFdRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AttachFdRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(&bytes[index..])?;
+        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (read_only, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (read_only, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -719,7 +725,12 @@ impl Request for AttachFdRequest {
     const OPCODE: u8 = 6;
     const EXTENSION: Option<&'static str> = Some("MIT-SHM");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "AttachFd";
     type Reply = ();
+    #[inline]
+    fn fd_count(&self) -> usize {
+        1
+    }
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
 pub struct CreateSegmentRequest {
@@ -747,16 +758,16 @@ impl AsByteSequence for CreateSegmentRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateSegmentRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(&bytes[index..])?;
+        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (size, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (size, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (read_only, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (read_only, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -785,6 +796,7 @@ impl Request for CreateSegmentRequest {
     const OPCODE: u8 = 7;
     const EXTENSION: Option<&'static str> = Some("MIT-SHM");
     const REPLY_EXPECTS_FDS: bool = true;
+    const NAME: &'static str = "CreateSegment";
     type Reply = CreateSegmentReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -811,13 +823,13 @@ impl AsByteSequence for CreateSegmentReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateSegmentReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (nfd, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (nfd, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 24;
         Some((
@@ -861,6 +873,7 @@ impl AsByteSequence for CompletionEvent {
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.minor_event.as_bytes(&mut bytes[index..]);
         index += self.major_event.as_bytes(&mut bytes[index..]);
+        index += 1;
         index += self.shmseg.as_bytes(&mut bytes[index..]);
         index += self.offset.as_bytes(&mut bytes[index..]);
         index
@@ -869,20 +882,21 @@ impl AsByteSequence for CompletionEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CompletionEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_event, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_event, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_event, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (major_event, sz): (Byte, usize) = <Byte>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(&bytes[index..])?;
+        index += 1;
+        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (offset, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CompletionEvent {
@@ -905,6 +919,7 @@ impl AsByteSequence for CompletionEvent {
             + self.drawable.size()
             + self.minor_event.size()
             + self.major_event.size()
+            + 1
             + self.shmseg.size()
             + self.offset.size()
     }
@@ -92,13 +92,14 @@ impl AsByteSequence for Systemcounter {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Systemcounter from byte buffer");
-        let (counter, sz): (Counter, usize) = <Counter>::from_bytes(&bytes[index..])?;
+        let (counter, sz): (Counter, usize) = <Counter>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (resolution, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let (resolution, sz): (Int64, usize) = <Int64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) =
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, 4);
         Some((
@@ -141,13 +142,13 @@ impl AsByteSequence for Trigger {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Trigger from byte buffer");
-        let (counter, sz): (Counter, usize) = <Counter>::from_bytes(&bytes[index..])?;
+        let (counter, sz): (Counter, usize) = <Counter>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (wait_type, sz): (Valuetype, usize) = <Valuetype>::from_bytes(&bytes[index..])?;
+        let (wait_type, sz): (Valuetype, usize) = <Valuetype>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (wait_value, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let (wait_value, sz): (Int64, usize) = <Int64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (test_type, sz): (Testtype, usize) = <Testtype>::from_bytes(&bytes[index..])?;
+        let (test_type, sz): (Testtype, usize) = <Testtype>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Trigger {
@@ -248,9 +249,9 @@ impl AsByteSequence for Waitcondition {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Waitcondition from byte buffer");
-        let (trigger, sz): (Trigger, usize) = <Trigger>::from_bytes(&bytes[index..])?;
+        let (trigger, sz): (Trigger, usize) = <Trigger>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_threshold, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let (event_threshold, sz): (Int64, usize) = <Int64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Waitcondition {
@@ -288,14 +289,14 @@ impl AsByteSequence for InitializeRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing InitializeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (desired_major_version, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (desired_major_version, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (desired_minor_version, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (desired_minor_version, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             InitializeRequest {
@@ -320,6 +321,7 @@ impl Request for InitializeRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("SYNC");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Initialize";
     type Reply = InitializeReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -348,16 +350,16 @@ impl AsByteSequence for InitializeReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing InitializeReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
         Some((
@@ -401,10 +403,10 @@ impl AsByteSequence for ListSystemCountersRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListSystemCountersRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ListSystemCountersRequest {
@@ -423,6 +425,7 @@ impl Request for ListSystemCountersRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = Some("SYNC");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListSystemCounters";
     type Reply = ListSystemCountersReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -452,18 +455,18 @@ impl AsByteSequence for ListSystemCountersReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListSystemCountersReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (counters, block_len): (Vec<Systemcounter>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Systemcounter>());
         Some((
@@ -514,14 +517,14 @@ impl AsByteSequence for CreateCounterRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateCounterRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (id, sz): (Counter, usize) = <Counter>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Counter, usize) = <Counter>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (initial_value, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let (initial_value, sz): (Int64, usize) = <Int64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CreateCounterRequest {
@@ -542,6 +545,7 @@ impl Request for CreateCounterRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = Some("SYNC");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateCounter";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -565,12 +569,12 @@ impl AsByteSequence for DestroyCounterRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroyCounterRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (counter, sz): (Counter, usize) = <Counter>::from_bytes(&bytes[index..])?;
+        let (counter, sz): (Counter, usize) = <Counter>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DestroyCounterRequest {
@@ -590,6 +594,7 @@ impl Request for DestroyCounterRequest {
     const OPCODE: u8 = 6;
     const EXTENSION: Option<&'static str> = Some("SYNC");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroyCounter";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -613,12 +618,12 @@ impl AsByteSequence for QueryCounterRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryCounterRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (counter, sz): (Counter, usize) = <Counter>::from_bytes(&bytes[index..])?;
+        let (counter, sz): (Counter, usize) = <Counter>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryCounterRequest {
@@ -638,6 +643,7 @@ impl Request for QueryCounterRequest {
     const OPCODE: u8 = 5;
     const EXTENSION: Option<&'static str> = Some("SYNC");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryCounter";
     type Reply = QueryCounterReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -663,14 +669,14 @@ impl AsByteSequence for QueryCounterReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryCounterReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (counter_value, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let (counter_value, sz): (Int64, usize) = <Int64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryCounterReply {
@@ -714,13 +720,15 @@ impl AsByteSequence for AwaitRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AwaitRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (wait_list, block_len): (Vec<Waitcondition>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (wait_list, block_len): (Vec<Waitcondition>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Waitcondition>());
         Some((
@@ -745,6 +753,7 @@ impl Request for AwaitRequest {
     const OPCODE: u8 = 7;
     const EXTENSION: Option<&'static str> = Some("SYNC");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Await";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -770,14 +779,14 @@ impl AsByteSequence for ChangeCounterRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeCounterRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (counter, sz): (Counter, usize) = <Counter>::from_bytes(&bytes[index..])?;
+        let (counter, sz): (Counter, usize) = <Counter>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (amount, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let (amount, sz): (Int64, usize) = <Int64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ChangeCounterRequest {
@@ -798,6 +807,7 @@ impl Request for ChangeCounterRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = Some("SYNC");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeCounter";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -823,14 +833,14 @@ impl AsByteSequence for SetCounterRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetCounterRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (counter, sz): (Counter, usize) = <Counter>::from_bytes(&bytes[index..])?;
+        let (counter, sz): (Counter, usize) = <Counter>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let (value, sz): (Int64, usize) = <Int64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetCounterRequest {
@@ -851,6 +861,7 @@ impl Request for SetCounterRequest {
     const OPCODE: u8 = 3;
     const EXTENSION: Option<&'static str> = Some("SYNC");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetCounter";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -901,53 +912,54 @@ impl AsByteSequence for CreateAlarmRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateAlarmRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (id, sz): (Alarm, usize) = <Alarm>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Alarm, usize) = <Alarm>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value_mask, sz): (Ca, usize) = <Ca>::from_bytes(&bytes[index..])?;
+        let (value_mask, sz): (Ca, usize) = <Ca>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let cond0 = (value_mask);
         let counter: Counter = if cond0.counter() {
-            let (counter, sz): (Counter, usize) = <Counter>::from_bytes(&bytes[index..])?;
+            let (counter, sz): (Counter, usize) = <Counter>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             counter
         } else {
             Default::default()
         };
         let value_type: Valuetype = if cond0.value_type() {
-            let (value_type, sz): (Valuetype, usize) = <Valuetype>::from_bytes(&bytes[index..])?;
+            let (value_type, sz): (Valuetype, usize) =
+                <Valuetype>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             value_type
         } else {
             Default::default()
         };
         let value: Int64 = if cond0.value() {
-            let (value, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+            let (value, sz): (Int64, usize) = <Int64>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             value
         } else {
             Default::default()
         };
         let test_type: Testtype = if cond0.test_type() {
-            let (test_type, sz): (Testtype, usize) = <Testtype>::from_bytes(&bytes[index..])?;
+            let (test_type, sz): (Testtype, usize) = <Testtype>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             test_type
         } else {
             Default::default()
         };
         let delta: Int64 = if cond0.delta() {
-            let (delta, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+            let (delta, sz): (Int64, usize) = <Int64>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             delta
         } else {
             Default::default()
         };
         let events: Card32 = if cond0.events() {
-            let (events, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (events, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             events
         } else {
@@ -988,6 +1000,7 @@ impl Request for CreateAlarmRequest {
     const OPCODE: u8 = 8;
     const EXTENSION: Option<&'static str> = Some("SYNC");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateAlarm";
     type Reply = ();
 }
 #[repr(transparent)]
@@ -1213,53 +1226,54 @@ impl AsByteSequence for ChangeAlarmRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeAlarmRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (id, sz): (Alarm, usize) = <Alarm>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Alarm, usize) = <Alarm>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value_mask, sz): (Ca, usize) = <Ca>::from_bytes(&bytes[index..])?;
+        let (value_mask, sz): (Ca, usize) = <Ca>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let cond0 = (value_mask);
         let counter: Counter = if cond0.counter() {
-            let (counter, sz): (Counter, usize) = <Counter>::from_bytes(&bytes[index..])?;
+            let (counter, sz): (Counter, usize) = <Counter>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             counter
         } else {
             Default::default()
         };
         let value_type: Valuetype = if cond0.value_type() {
-            let (value_type, sz): (Valuetype, usize) = <Valuetype>::from_bytes(&bytes[index..])?;
+            let (value_type, sz): (Valuetype, usize) =
+                <Valuetype>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             value_type
         } else {
             Default::default()
         };
         let value: Int64 = if cond0.value() {
-            let (value, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+            let (value, sz): (Int64, usize) = <Int64>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             value
         } else {
             Default::default()
         };
         let test_type: Testtype = if cond0.test_type() {
-            let (test_type, sz): (Testtype, usize) = <Testtype>::from_bytes(&bytes[index..])?;
+            let (test_type, sz): (Testtype, usize) = <Testtype>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             test_type
         } else {
             Default::default()
         };
         let delta: Int64 = if cond0.delta() {
-            let (delta, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+            let (delta, sz): (Int64, usize) = <Int64>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             delta
         } else {
             Default::default()
         };
         let events: Card32 = if cond0.events() {
-            let (events, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (events, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             events
         } else {
@@ -1300,6 +1314,7 @@ impl Request for ChangeAlarmRequest {
     const OPCODE: u8 = 9;
     const EXTENSION: Option<&'static str> = Some("SYNC");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeAlarm";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1323,12 +1338,12 @@ impl AsByteSequence for DestroyAlarmRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroyAlarmRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (alarm, sz): (Alarm, usize) = <Alarm>::from_bytes(&bytes[index..])?;
+        let (alarm, sz): (Alarm, usize) = <Alarm>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DestroyAlarmRequest {
@@ -1348,6 +1363,7 @@ impl Request for DestroyAlarmRequest {
     const OPCODE: u8 = 11;
     const EXTENSION: Option<&'static str> = Some("SYNC");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroyAlarm";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1371,12 +1387,12 @@ impl AsByteSequence for QueryAlarmRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryAlarmRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (alarm, sz): (Alarm, usize) = <Alarm>::from_bytes(&bytes[index..])?;
+        let (alarm, sz): (Alarm, usize) = <Alarm>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryAlarmRequest {
@@ -1396,6 +1412,7 @@ impl Request for QueryAlarmRequest {
     const OPCODE: u8 = 10;
     const EXTENSION: Option<&'static str> = Some("SYNC");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryAlarm";
     type Reply = QueryAlarmReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1428,20 +1445,20 @@ impl AsByteSequence for QueryAlarmReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryAlarmReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (trigger, sz): (Trigger, usize) = <Trigger>::from_bytes(&bytes[index..])?;
+        let (trigger, sz): (Trigger, usize) = <Trigger>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (delta, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let (delta, sz): (Int64, usize) = <Int64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (events, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (events, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (Alarmstate, usize) = <Alarmstate>::from_bytes(&bytes[index..])?;
+        let (state, sz): (Alarmstate, usize) = <Alarmstate>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -1526,14 +1543,14 @@ impl AsByteSequence for SetPriorityRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetPriorityRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (priority, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (priority, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetPriorityRequest {
@@ -1554,6 +1571,7 @@ impl Request for SetPriorityRequest {
     const OPCODE: u8 = 12;
     const EXTENSION: Option<&'static str> = Some("SYNC");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetPriority";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1577,12 +1595,12 @@ impl AsByteSequence for GetPriorityRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPriorityRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetPriorityRequest {
@@ -1602,6 +1620,7 @@ impl Request for GetPriorityRequest {
     const OPCODE: u8 = 13;
     const EXTENSION: Option<&'static str> = Some("SYNC");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetPriority";
     type Reply = GetPriorityReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1627,14 +1646,14 @@ impl AsByteSequence for GetPriorityReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPriorityReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (priority, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (priority, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetPriorityReply {
@@ -1680,16 +1699,16 @@ impl AsByteSequence for CreateFenceRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateFenceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fence, sz): (Fence, usize) = <Fence>::from_bytes(&bytes[index..])?;
+        let (fence, sz): (Fence, usize) = <Fence>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (initially_triggered, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (initially_triggered, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CreateFenceRequest {
@@ -1716,6 +1735,7 @@ impl Request for CreateFenceRequest {
     const OPCODE: u8 = 14;
     const EXTENSION: Option<&'static str> = Some("SYNC");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateFence";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1739,12 +1759,12 @@ impl AsByteSequence for TriggerFenceRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing TriggerFenceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fence, sz): (Fence, usize) = <Fence>::from_bytes(&bytes[index..])?;
+        let (fence, sz): (Fence, usize) = <Fence>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             TriggerFenceRequest {
@@ -1764,6 +1784,7 @@ impl Request for TriggerFenceRequest {
     const OPCODE: u8 = 15;
     const EXTENSION: Option<&'static str> = Some("SYNC");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "TriggerFence";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1787,12 +1808,12 @@ impl AsByteSequence for ResetFenceRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ResetFenceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fence, sz): (Fence, usize) = <Fence>::from_bytes(&bytes[index..])?;
+        let (fence, sz): (Fence, usize) = <Fence>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ResetFenceRequest {
@@ -1812,6 +1833,7 @@ impl Request for ResetFenceRequest {
     const OPCODE: u8 = 16;
     const EXTENSION: Option<&'static str> = Some("SYNC");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ResetFence";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1835,12 +1857,12 @@ impl AsByteSequence for DestroyFenceRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroyFenceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fence, sz): (Fence, usize) = <Fence>::from_bytes(&bytes[index..])?;
+        let (fence, sz): (Fence, usize) = <Fence>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DestroyFenceRequest {
@@ -1860,6 +1882,7 @@ impl Request for DestroyFenceRequest {
     const OPCODE: u8 = 17;
     const EXTENSION: Option<&'static str> = Some("SYNC");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroyFence";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1883,12 +1906,12 @@ impl AsByteSequence for QueryFenceRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryFenceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fence, sz): (Fence, usize) = <Fence>::from_bytes(&bytes[index..])?;
+        let (fence, sz): (Fence, usize) = <Fence>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryFenceRequest {
@@ -1908,6 +1931,7 @@ impl Request for QueryFenceRequest {
     const OPCODE: u8 = 18;
     const EXTENSION: Option<&'static str> = Some("SYNC");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryFence";
     type Reply = QueryFenceReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1934,14 +1958,14 @@ impl AsByteSequence for QueryFenceReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryFenceReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (triggered, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (triggered, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 23;
         Some((
@@ -1987,13 +2011,15 @@ impl AsByteSequence for AwaitFenceRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AwaitFenceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fence_list, block_len): (Vec<Fence>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (fence_list, block_len): (Vec<Fence>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fence>());
         Some((
@@ -2018,6 +2044,7 @@ impl Request for AwaitFenceRequest {
     const OPCODE: u8 = 19;
     const EXTENSION: Option<&'static str> = Some("SYNC");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "AwaitFence";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2050,21 +2077,21 @@ impl AsByteSequence for AlarmError {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AlarmError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (major_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bad_alarm, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bad_alarm, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             AlarmError {
@@ -2125,21 +2152,21 @@ impl AsByteSequence for CounterError {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CounterError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (major_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bad_counter, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bad_counter, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CounterError {
@@ -2201,21 +2228,21 @@ impl AsByteSequence for AlarmNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AlarmNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (kind, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (kind, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (alarm, sz): (Alarm, usize) = <Alarm>::from_bytes(&bytes[index..])?;
+        let (alarm, sz): (Alarm, usize) = <Alarm>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (counter_value, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let (counter_value, sz): (Int64, usize) = <Int64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (alarm_value, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let (alarm_value, sz): (Int64, usize) = <Int64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (Alarmstate, usize) = <Alarmstate>::from_bytes(&bytes[index..])?;
+        let (state, sz): (Alarmstate, usize) = <Alarmstate>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -2281,23 +2308,23 @@ impl AsByteSequence for CounterNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CounterNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (kind, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (kind, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (counter, sz): (Counter, usize) = <Counter>::from_bytes(&bytes[index..])?;
+        let (counter, sz): (Counter, usize) = <Counter>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (wait_value, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let (wait_value, sz): (Int64, usize) = <Int64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (counter_value, sz): (Int64, usize) = <Int64>::from_bytes(&bytes[index..])?;
+        let (counter_value, sz): (Int64, usize) = <Int64>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (count, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (count, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (destroyed, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (destroyed, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
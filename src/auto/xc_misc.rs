@@ -28,14 +28,16 @@ impl AsByteSequence for GetVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (client_major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (client_major_version, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (client_minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (client_minor_version, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetVersionRequest {
@@ -60,6 +62,7 @@ impl Request for GetVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XC-MISC");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetVersion";
     type Reply = GetVersionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -87,16 +90,18 @@ impl AsByteSequence for GetVersionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (server_major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (server_major_version, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (server_minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (server_minor_version, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetVersionReply {
@@ -138,10 +143,10 @@ impl AsByteSequence for GetXidRangeRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetXidRangeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetXidRangeRequest {
@@ -160,6 +165,7 @@ impl Request for GetXidRangeRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = Some("XC-MISC");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetXidRange";
     type Reply = GetXidRangeReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -187,16 +193,16 @@ impl AsByteSequence for GetXidRangeReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetXidRangeReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (start_id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (start_id, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (count, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (count, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetXidRangeReply {
@@ -240,12 +246,12 @@ impl AsByteSequence for GetXidListRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetXidListRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (count, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (count, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetXidListRequest {
@@ -265,6 +271,7 @@ impl Request for GetXidListRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = Some("XC-MISC");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetXidList";
     type Reply = GetXidListReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -294,18 +301,18 @@ impl AsByteSequence for GetXidListReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetXidListReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (ids, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -27,13 +27,13 @@ impl AsByteSequence for DrmClipRect {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DrmClipRect from byte buffer");
-        let (x1, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x1, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y1, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y1, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x2, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x2, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x3, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x3, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DrmClipRect {
@@ -69,10 +69,10 @@ impl AsByteSequence for QueryVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionRequest {
@@ -91,6 +91,7 @@ impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -120,18 +121,18 @@ impl AsByteSequence for QueryVersionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dri_major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (dri_major_version, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dri_minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (dri_minor_version, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dri_minor_patch, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (dri_minor_patch, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionReply {
@@ -177,12 +178,12 @@ impl AsByteSequence for QueryDirectRenderingCapableRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryDirectRenderingCapableRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryDirectRenderingCapableRequest {
@@ -202,6 +203,7 @@ impl Request for QueryDirectRenderingCapableRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryDirectRenderingCapable";
     type Reply = QueryDirectRenderingCapableReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -227,14 +229,14 @@ impl AsByteSequence for QueryDirectRenderingCapableReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryDirectRenderingCapableReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (is_capable, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (is_capable, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryDirectRenderingCapableReply {
@@ -276,12 +278,12 @@ impl AsByteSequence for OpenConnectionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing OpenConnectionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             OpenConnectionRequest {
@@ -301,6 +303,7 @@ impl Request for OpenConnectionRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "OpenConnection";
     type Reply = OpenConnectionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -334,22 +337,22 @@ impl AsByteSequence for OpenConnectionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing OpenConnectionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sarea_handle_low, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (sarea_handle_low, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sarea_handle_high, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (sarea_handle_high, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (bus_id, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -402,12 +405,12 @@ impl AsByteSequence for CloseConnectionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CloseConnectionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CloseConnectionRequest {
@@ -427,6 +430,7 @@ impl Request for CloseConnectionRequest {
     const OPCODE: u8 = 3;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CloseConnection";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -450,12 +454,12 @@ impl AsByteSequence for GetClientDriverNameRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetClientDriverNameRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetClientDriverNameRequest {
@@ -475,6 +479,7 @@ impl Request for GetClientDriverNameRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetClientDriverName";
     type Reply = GetClientDriverNameReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -516,27 +521,27 @@ impl AsByteSequence for GetClientDriverNameReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetClientDriverNameReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (client_driver_major_version, sz): (Card32, usize) =
-            <Card32>::from_bytes(&bytes[index..])?;
+            <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (client_driver_minor_version, sz): (Card32, usize) =
-            <Card32>::from_bytes(&bytes[index..])?;
+            <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (client_driver_patch_version, sz): (Card32, usize) =
-            <Card32>::from_bytes(&bytes[index..])?;
+            <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 8;
         let (client_driver_name, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -595,16 +600,16 @@ impl AsByteSequence for CreateContextRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (visual, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (visual, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CreateContextRequest {
@@ -631,6 +636,7 @@ impl Request for CreateContextRequest {
     const OPCODE: u8 = 5;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateContext";
     type Reply = CreateContextReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -656,14 +662,14 @@ impl AsByteSequence for CreateContextReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hw_context, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (hw_context, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CreateContextReply {
@@ -707,14 +713,14 @@ impl AsByteSequence for DestroyContextRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroyContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DestroyContextRequest {
@@ -735,6 +741,7 @@ impl Request for DestroyContextRequest {
     const OPCODE: u8 = 6;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroyContext";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -760,14 +767,14 @@ impl AsByteSequence for CreateDrawableRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateDrawableRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CreateDrawableRequest {
@@ -788,6 +795,7 @@ impl Request for CreateDrawableRequest {
     const OPCODE: u8 = 7;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateDrawable";
     type Reply = CreateDrawableReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -813,14 +821,14 @@ impl AsByteSequence for CreateDrawableReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateDrawableReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hw_drawable_handle, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (hw_drawable_handle, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CreateDrawableReply {
@@ -864,14 +872,14 @@ impl AsByteSequence for DestroyDrawableRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroyDrawableRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DestroyDrawableRequest {
@@ -892,6 +900,7 @@ impl Request for DestroyDrawableRequest {
     const OPCODE: u8 = 8;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroyDrawable";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -917,14 +926,14 @@ impl AsByteSequence for GetDrawableInfoRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDrawableInfoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetDrawableInfoRequest {
@@ -945,6 +954,7 @@ impl Request for GetDrawableInfoRequest {
     const OPCODE: u8 = 9;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetDrawableInfo";
     type Reply = GetDrawableInfoReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -994,39 +1004,41 @@ impl AsByteSequence for GetDrawableInfoReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDrawableInfoReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable_table_index, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (drawable_table_index, sz): (Card32, usize) =
+            <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable_table_stamp, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (drawable_table_stamp, sz): (Card32, usize) =
+            <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable_origin_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drawable_origin_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable_origin_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drawable_origin_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable_size_w, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drawable_size_w, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable_size_h, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drawable_size_h, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (back_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (back_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (back_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (back_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (clip_rects, block_len): (Vec<DrmClipRect>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<DrmClipRect>());
         let (back_clip_rects, block_len): (Vec<DrmClipRect>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<DrmClipRect>());
         Some((
@@ -1097,12 +1109,12 @@ impl AsByteSequence for GetDeviceInfoRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDeviceInfoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetDeviceInfoRequest {
@@ -1122,6 +1134,7 @@ impl Request for GetDeviceInfoRequest {
     const OPCODE: u8 = 10;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetDeviceInfo";
     type Reply = GetDeviceInfoReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1160,28 +1173,30 @@ impl AsByteSequence for GetDeviceInfoReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDeviceInfoReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (framebuffer_handle_low, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (framebuffer_handle_low, sz): (Card32, usize) =
+            <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (framebuffer_handle_high, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (framebuffer_handle_high, sz): (Card32, usize) =
+            <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (framebuffer_origin_offset, sz): (Card32, usize) =
-            <Card32>::from_bytes(&bytes[index..])?;
+            <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (framebuffer_size, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (framebuffer_size, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (framebuffer_stride, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (framebuffer_stride, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (device_private, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -1241,14 +1256,14 @@ impl AsByteSequence for AuthConnectionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AuthConnectionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (magic, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (magic, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             AuthConnectionRequest {
@@ -1269,6 +1284,7 @@ impl Request for AuthConnectionRequest {
     const OPCODE: u8 = 11;
     const EXTENSION: Option<&'static str> = Some("XFree86-DRI");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "AuthConnection";
     type Reply = AuthConnectionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1294,14 +1310,14 @@ impl AsByteSequence for AuthConnectionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AuthConnectionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (authenticated, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (authenticated, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             AuthConnectionReply {
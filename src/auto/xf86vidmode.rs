@@ -48,31 +48,31 @@ impl AsByteSequence for ModeInfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ModeInfo from byte buffer");
-        let (dotclock, sz): (Dotclock, usize) = <Dotclock>::from_bytes(&bytes[index..])?;
+        let (dotclock, sz): (Dotclock, usize) = <Dotclock>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hskew, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (hskew, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
-        let (privsize, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (privsize, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ModeInfo {
@@ -430,10 +430,10 @@ impl AsByteSequence for QueryVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionRequest {
@@ -452,6 +452,7 @@ impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -479,16 +480,16 @@ impl AsByteSequence for QueryVersionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionReply {
@@ -533,12 +534,12 @@ impl AsByteSequence for GetModeLineRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetModeLineRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -559,6 +560,7 @@ impl Request for GetModeLineRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetModeLine";
     type Reply = GetModeLineReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -611,41 +613,41 @@ impl AsByteSequence for GetModeLineReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetModeLineReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dotclock, sz): (Dotclock, usize) = <Dotclock>::from_bytes(&bytes[index..])?;
+        let (dotclock, sz): (Dotclock, usize) = <Dotclock>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hskew, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hskew, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (private, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
         Some((
@@ -744,39 +746,39 @@ impl AsByteSequence for ModModeLineRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ModModeLineRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hskew, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hskew, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (private, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
         Some((
@@ -829,6 +831,7 @@ impl Request for ModModeLineRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ModModeLine";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -854,14 +857,14 @@ impl AsByteSequence for SwitchModeRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SwitchModeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (zoom, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (zoom, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SwitchModeRequest {
@@ -882,6 +885,7 @@ impl Request for SwitchModeRequest {
     const OPCODE: u8 = 3;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SwitchMode";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -906,12 +910,12 @@ impl AsByteSequence for GetMonitorRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMonitorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -932,6 +936,7 @@ impl Request for GetMonitorRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetMonitor";
     type Reply = GetMonitorReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -981,42 +986,42 @@ impl AsByteSequence for GetMonitorReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMonitorReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vendor_length, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (vendor_length, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len2, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (hsync, block_len): (Vec<Syncrange>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Syncrange>());
         let (vsync, block_len): (Vec<Syncrange>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len2 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Syncrange>());
         let (vendor, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], (vendor_length as usize) as usize)?;
+            string_from_bytes(bytes.get(index..)?, (vendor_length as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         let (alignment_pad, block_len): (Vec<Void>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             ((((vendor_length as usize) + (3)) & (!(3))) - (vendor_length as usize)) as usize,
         )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Void>());
         let (model, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -1095,14 +1100,14 @@ impl AsByteSequence for LockModeSwitchRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing LockModeSwitchRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (lock, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (lock, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             LockModeSwitchRequest {
@@ -1123,6 +1128,7 @@ impl Request for LockModeSwitchRequest {
     const OPCODE: u8 = 5;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "LockModeSwitch";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1147,12 +1153,12 @@ impl AsByteSequence for GetAllModeLinesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetAllModeLinesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -1173,6 +1179,7 @@ impl Request for GetAllModeLinesRequest {
     const OPCODE: u8 = 6;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetAllModeLines";
     type Reply = GetAllModeLinesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1202,18 +1209,18 @@ impl AsByteSequence for GetAllModeLinesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetAllModeLinesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (modeinfo, block_len): (Vec<ModeInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ModeInfo>());
         Some((
@@ -1303,7 +1310,9 @@ impl AsByteSequence for AddModeLineRequest {
         index += self.after_vsyncstart.as_bytes(&mut bytes[index..]);
         index += self.after_vsyncend.as_bytes(&mut bytes[index..]);
         index += self.after_vtotal.as_bytes(&mut bytes[index..]);
+        index += 2;
         index += self.after_flags.as_bytes(&mut bytes[index..]);
+        index += 12;
         let block_len: usize = vector_as_bytes(&self.private, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
@@ -1313,63 +1322,65 @@ impl AsByteSequence for AddModeLineRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AddModeLineRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dotclock, sz): (Dotclock, usize) = <Dotclock>::from_bytes(&bytes[index..])?;
+        let (dotclock, sz): (Dotclock, usize) = <Dotclock>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hskew, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hskew, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (after_dotclock, sz): (Dotclock, usize) = <Dotclock>::from_bytes(&bytes[index..])?;
+        let (after_dotclock, sz): (Dotclock, usize) = <Dotclock>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (after_hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (after_hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (after_hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (after_hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (after_hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (after_hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (after_htotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (after_htotal, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (after_hskew, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (after_hskew, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (after_vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (after_vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (after_vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (after_vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (after_vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (after_vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (after_vtotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (after_vtotal, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (after_flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(&bytes[index..])?;
+        index += 2;
+        let (after_flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
+        index += 12;
         let (private, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
         Some((
@@ -1434,7 +1445,9 @@ impl AsByteSequence for AddModeLineRequest {
             + self.after_vsyncstart.size()
             + self.after_vsyncend.size()
             + self.after_vtotal.size()
+            + 2
             + self.after_flags.size()
+            + 12
             + {
                 let block_len: usize = self.private.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
@@ -1446,6 +1459,7 @@ impl Request for AddModeLineRequest {
     const OPCODE: u8 = 7;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "AddModeLine";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1498,41 +1512,41 @@ impl AsByteSequence for DeleteModeLineRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeleteModeLineRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dotclock, sz): (Dotclock, usize) = <Dotclock>::from_bytes(&bytes[index..])?;
+        let (dotclock, sz): (Dotclock, usize) = <Dotclock>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hskew, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hskew, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (private, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
         Some((
@@ -1587,6 +1601,7 @@ impl Request for DeleteModeLineRequest {
     const OPCODE: u8 = 8;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DeleteModeLine";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1639,41 +1654,41 @@ impl AsByteSequence for ValidateModeLineRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ValidateModeLineRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dotclock, sz): (Dotclock, usize) = <Dotclock>::from_bytes(&bytes[index..])?;
+        let (dotclock, sz): (Dotclock, usize) = <Dotclock>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hskew, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hskew, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (private, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
         Some((
@@ -1728,6 +1743,7 @@ impl Request for ValidateModeLineRequest {
     const OPCODE: u8 = 9;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ValidateModeLine";
     type Reply = ValidateModeLineReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1754,14 +1770,14 @@ impl AsByteSequence for ValidateModeLineReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ValidateModeLineReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (status, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         Some((
@@ -1834,41 +1850,41 @@ impl AsByteSequence for SwitchToModeRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SwitchToModeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dotclock, sz): (Dotclock, usize) = <Dotclock>::from_bytes(&bytes[index..])?;
+        let (dotclock, sz): (Dotclock, usize) = <Dotclock>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hdisplay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hsyncend, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (htotal, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (hskew, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (hskew, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vdisplay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncstart, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vsyncend, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vtotal, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (ModeFlag, usize) = <ModeFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (private, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
         Some((
@@ -1923,6 +1939,7 @@ impl Request for SwitchToModeRequest {
     const OPCODE: u8 = 10;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SwitchToMode";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1947,12 +1964,12 @@ impl AsByteSequence for GetViewPortRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetViewPortRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -1973,6 +1990,7 @@ impl Request for GetViewPortRequest {
     const OPCODE: u8 = 11;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetViewPort";
     type Reply = GetViewPortReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2001,16 +2019,16 @@ impl AsByteSequence for GetViewPortReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetViewPortReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         Some((
@@ -2061,17 +2079,17 @@ impl AsByteSequence for SetViewPortRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetViewPortRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (x, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetViewPortRequest {
@@ -2099,6 +2117,7 @@ impl Request for SetViewPortRequest {
     const OPCODE: u8 = 12;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetViewPort";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2123,12 +2142,12 @@ impl AsByteSequence for GetDotClocksRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDotClocksRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -2149,6 +2168,7 @@ impl Request for GetDotClocksRequest {
     const OPCODE: u8 = 13;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetDotClocks";
     type Reply = GetDotClocksReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2183,22 +2203,22 @@ impl AsByteSequence for GetDotClocksReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDotClocksReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (ClockFlag, usize) = <ClockFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (ClockFlag, usize) = <ClockFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (clocks, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (clocks, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (maxclocks, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (maxclocks, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (clock, block_len): (Vec<Card32>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (((1) - ((flags as usize) & (1))) * (clocks as usize)) as usize,
         )?;
         index += block_len;
@@ -2257,14 +2277,14 @@ impl AsByteSequence for SetClientVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetClientVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (major, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetClientVersionRequest {
@@ -2285,6 +2305,7 @@ impl Request for SetClientVersionRequest {
     const OPCODE: u8 = 14;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetClientVersion";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2316,19 +2337,19 @@ impl AsByteSequence for SetGammaRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetGammaRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (red, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (red, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (green, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (green, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (blue, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (blue, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         Some((
@@ -2360,6 +2381,7 @@ impl Request for SetGammaRequest {
     const OPCODE: u8 = 15;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetGamma";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2384,12 +2406,12 @@ impl AsByteSequence for GetGammaRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetGammaRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 26;
         Some((
@@ -2410,6 +2432,7 @@ impl Request for GetGammaRequest {
     const OPCODE: u8 = 16;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetGamma";
     type Reply = GetGammaReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2440,18 +2463,18 @@ impl AsByteSequence for GetGammaReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetGammaReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (red, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (red, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (green, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (green, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (blue, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (blue, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         Some((
@@ -2501,14 +2524,14 @@ impl AsByteSequence for GetGammaRampRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetGammaRampRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (size, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (size, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetGammaRampRequest {
@@ -2529,6 +2552,7 @@ impl Request for GetGammaRampRequest {
     const OPCODE: u8 = 17;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetGammaRamp";
     type Reply = GetGammaRampReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2567,26 +2591,32 @@ impl AsByteSequence for GetGammaRampReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetGammaRampReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (size, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (size, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
-        let (red, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (((size as usize) + (1)) & (!(1))) as usize)?;
+        let (red, block_len): (Vec<Card16>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            (((size as usize) + (1)) & (!(1))) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
-        let (green, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (((size as usize) + (1)) & (!(1))) as usize)?;
+        let (green, block_len): (Vec<Card16>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            (((size as usize) + (1)) & (!(1))) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
-        let (blue, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (((size as usize) + (1)) & (!(1))) as usize)?;
+        let (blue, block_len): (Vec<Card16>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            (((size as usize) + (1)) & (!(1))) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
         Some((
@@ -2662,25 +2692,31 @@ impl AsByteSequence for SetGammaRampRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetGammaRampRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (size, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (size, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (red, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (((size as usize) + (1)) & (!(1))) as usize)?;
+        let (red, block_len): (Vec<Card16>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            (((size as usize) + (1)) & (!(1))) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
-        let (green, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (((size as usize) + (1)) & (!(1))) as usize)?;
+        let (green, block_len): (Vec<Card16>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            (((size as usize) + (1)) & (!(1))) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
-        let (blue, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (((size as usize) + (1)) & (!(1))) as usize)?;
+        let (blue, block_len): (Vec<Card16>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            (((size as usize) + (1)) & (!(1))) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
         Some((
@@ -2724,6 +2760,7 @@ impl Request for SetGammaRampRequest {
     const OPCODE: u8 = 18;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetGammaRamp";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2748,12 +2785,12 @@ impl AsByteSequence for GetGammaRampSizeRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetGammaRampSizeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -2774,6 +2811,7 @@ impl Request for GetGammaRampSizeRequest {
     const OPCODE: u8 = 19;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetGammaRampSize";
     type Reply = GetGammaRampSizeReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2800,14 +2838,14 @@ impl AsByteSequence for GetGammaRampSizeReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetGammaRampSizeReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (size, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (size, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
         Some((
@@ -2852,12 +2890,12 @@ impl AsByteSequence for GetPermissionsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPermissionsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -2878,6 +2916,7 @@ impl Request for GetPermissionsRequest {
     const OPCODE: u8 = 20;
     const EXTENSION: Option<&'static str> = Some("XFree86-VidModeExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetPermissions";
     type Reply = GetPermissionsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2904,14 +2943,14 @@ impl AsByteSequence for GetPermissionsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPermissionsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (permissions, sz): (Permission, usize) = <Permission>::from_bytes(&bytes[index..])?;
+        let (permissions, sz): (Permission, usize) = <Permission>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         Some((
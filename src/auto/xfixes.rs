@@ -31,14 +31,16 @@ impl AsByteSequence for QueryVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (client_major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (client_major_version, sz): (Card32, usize) =
+            <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (client_minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (client_minor_version, sz): (Card32, usize) =
+            <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionRequest {
@@ -63,6 +65,7 @@ impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -91,16 +94,16 @@ impl AsByteSequence for QueryVersionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         Some((
@@ -153,19 +156,20 @@ impl AsByteSequence for ChangeSaveSetRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeSaveSetRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (SaveSetMode, usize) = <SaveSetMode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (SaveSetMode, usize) = <SaveSetMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (SaveSetTarget, usize) = <SaveSetTarget>::from_bytes(&bytes[index..])?;
+        let (target, sz): (SaveSetTarget, usize) =
+            <SaveSetTarget>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (map, sz): (SaveSetMapping, usize) = <SaveSetMapping>::from_bytes(&bytes[index..])?;
+        let (map, sz): (SaveSetMapping, usize) = <SaveSetMapping>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ChangeSaveSetRequest {
@@ -195,6 +199,7 @@ impl Request for ChangeSaveSetRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeSaveSet";
     type Reply = ();
 }
 #[repr(u8)]
@@ -348,17 +353,17 @@ impl AsByteSequence for SelectSelectionInputRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectSelectionInputRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (selection, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (selection, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (event_mask, sz): (SelectionEventMask, usize) =
-            <SelectionEventMask>::from_bytes(&bytes[index..])?;
+            <SelectionEventMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SelectSelectionInputRequest {
@@ -385,6 +390,7 @@ impl Request for SelectSelectionInputRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SelectSelectionInput";
     type Reply = ();
 }
 #[repr(transparent)]
@@ -531,15 +537,15 @@ impl AsByteSequence for SelectCursorInputRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectCursorInputRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (event_mask, sz): (CursorNotifyMask, usize) =
-            <CursorNotifyMask>::from_bytes(&bytes[index..])?;
+            <CursorNotifyMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SelectCursorInputRequest {
@@ -560,6 +566,7 @@ impl Request for SelectCursorInputRequest {
     const OPCODE: u8 = 3;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SelectCursorInput";
     type Reply = ();
 }
 #[repr(transparent)]
@@ -664,10 +671,10 @@ impl AsByteSequence for GetCursorImageRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCursorImageRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetCursorImageRequest {
@@ -686,6 +693,7 @@ impl Request for GetCursorImageRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetCursorImage";
     type Reply = GetCursorImageReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -728,30 +736,30 @@ impl AsByteSequence for GetCursorImageReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCursorImageReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xhot, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (xhot, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (yhot, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (yhot, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cursor_serial, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (cursor_serial, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 8;
         let (cursor_image, block_len): (Vec<Card32>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             ((width as usize) * (height as usize)) as usize,
         )?;
         index += block_len;
@@ -840,15 +848,17 @@ impl AsByteSequence for CreateRegionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (rectangles, block_len): (Vec<Rectangle>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (rectangles, block_len): (Vec<Rectangle>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
         Some((
@@ -874,6 +884,7 @@ impl Request for CreateRegionRequest {
     const OPCODE: u8 = 5;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateRegion";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -899,14 +910,14 @@ impl AsByteSequence for CreateRegionFromBitmapRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateRegionFromBitmapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bitmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (bitmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CreateRegionFromBitmapRequest {
@@ -927,6 +938,7 @@ impl Request for CreateRegionFromBitmapRequest {
     const OPCODE: u8 = 6;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateRegionFromBitmap";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -955,16 +967,16 @@ impl AsByteSequence for CreateRegionFromWindowRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateRegionFromWindowRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (kind, sz): (Sk, usize) = <Sk>::from_bytes(&bytes[index..])?;
+        let (kind, sz): (Sk, usize) = <Sk>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -993,6 +1005,7 @@ impl Request for CreateRegionFromWindowRequest {
     const OPCODE: u8 = 7;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateRegionFromWindow";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1018,14 +1031,14 @@ impl AsByteSequence for CreateRegionFromGcRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateRegionFromGcRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CreateRegionFromGcRequest {
@@ -1046,6 +1059,7 @@ impl Request for CreateRegionFromGcRequest {
     const OPCODE: u8 = 8;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateRegionFromGc";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1071,14 +1085,14 @@ impl AsByteSequence for CreateRegionFromPictureRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateRegionFromPictureRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CreateRegionFromPictureRequest {
@@ -1099,6 +1113,7 @@ impl Request for CreateRegionFromPictureRequest {
     const OPCODE: u8 = 9;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateRegionFromPicture";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1122,12 +1137,12 @@ impl AsByteSequence for DestroyRegionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroyRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DestroyRegionRequest {
@@ -1147,6 +1162,7 @@ impl Request for DestroyRegionRequest {
     const OPCODE: u8 = 10;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroyRegion";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1174,15 +1190,17 @@ impl AsByteSequence for SetRegionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (rectangles, block_len): (Vec<Rectangle>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (rectangles, block_len): (Vec<Rectangle>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
         Some((
@@ -1208,6 +1226,7 @@ impl Request for SetRegionRequest {
     const OPCODE: u8 = 11;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetRegion";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1233,14 +1252,14 @@ impl AsByteSequence for CopyRegionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CopyRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (source, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (source, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (destination, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (destination, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CopyRegionRequest {
@@ -1261,6 +1280,7 @@ impl Request for CopyRegionRequest {
     const OPCODE: u8 = 12;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CopyRegion";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1288,16 +1308,16 @@ impl AsByteSequence for UnionRegionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing UnionRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (source1, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (source1, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (source2, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (source2, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (destination, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (destination, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             UnionRegionRequest {
@@ -1324,6 +1344,7 @@ impl Request for UnionRegionRequest {
     const OPCODE: u8 = 13;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UnionRegion";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1351,16 +1372,16 @@ impl AsByteSequence for IntersectRegionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing IntersectRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (source1, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (source1, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (source2, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (source2, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (destination, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (destination, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             IntersectRegionRequest {
@@ -1387,6 +1408,7 @@ impl Request for IntersectRegionRequest {
     const OPCODE: u8 = 14;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "IntersectRegion";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1414,16 +1436,16 @@ impl AsByteSequence for SubtractRegionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SubtractRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (source1, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (source1, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (source2, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (source2, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (destination, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (destination, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SubtractRegionRequest {
@@ -1450,6 +1472,7 @@ impl Request for SubtractRegionRequest {
     const OPCODE: u8 = 15;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SubtractRegion";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1477,16 +1500,16 @@ impl AsByteSequence for InvertRegionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing InvertRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (source, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (source, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bounds, sz): (Rectangle, usize) = <Rectangle>::from_bytes(&bytes[index..])?;
+        let (bounds, sz): (Rectangle, usize) = <Rectangle>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (destination, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (destination, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             InvertRegionRequest {
@@ -1513,6 +1536,7 @@ impl Request for InvertRegionRequest {
     const OPCODE: u8 = 16;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "InvertRegion";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1540,16 +1564,16 @@ impl AsByteSequence for TranslateRegionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing TranslateRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dx, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (dx, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dy, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (dy, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             TranslateRegionRequest {
@@ -1576,6 +1600,7 @@ impl Request for TranslateRegionRequest {
     const OPCODE: u8 = 17;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "TranslateRegion";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1601,14 +1626,14 @@ impl AsByteSequence for RegionExtentsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing RegionExtentsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (source, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (source, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (destination, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (destination, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             RegionExtentsRequest {
@@ -1629,6 +1654,7 @@ impl Request for RegionExtentsRequest {
     const OPCODE: u8 = 18;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "RegionExtents";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1652,12 +1678,12 @@ impl AsByteSequence for FetchRegionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FetchRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             FetchRegionRequest {
@@ -1677,6 +1703,7 @@ impl Request for FetchRegionRequest {
     const OPCODE: u8 = 19;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FetchRegion";
     type Reply = FetchRegionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1707,18 +1734,18 @@ impl AsByteSequence for FetchRegionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FetchRegionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (extents, sz): (Rectangle, usize) = <Rectangle>::from_bytes(&bytes[index..])?;
+        let (extents, sz): (Rectangle, usize) = <Rectangle>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         let (rectangles, block_len): (Vec<Rectangle>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize) / (2)) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((length as usize) / (2)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
         Some((
@@ -1774,18 +1801,18 @@ impl AsByteSequence for SetGcClipRegionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetGcClipRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x_origin, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x_origin, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y_origin, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y_origin, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetGcClipRegionRequest {
@@ -1814,6 +1841,7 @@ impl Request for SetGcClipRegionRequest {
     const OPCODE: u8 = 20;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetGcClipRegion";
     type Reply = ();
 }
 pub const REGION_NONE: Region = <Region>::const_from_xid(0);
@@ -1847,21 +1875,21 @@ impl AsByteSequence for SetWindowShapeRegionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetWindowShapeRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dest, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (dest, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dest_kind, sz): (Sk, usize) = <Sk>::from_bytes(&bytes[index..])?;
+        let (dest_kind, sz): (Sk, usize) = <Sk>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (x_offset, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x_offset, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y_offset, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y_offset, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetWindowShapeRegionRequest {
@@ -1893,6 +1921,7 @@ impl Request for SetWindowShapeRegionRequest {
     const OPCODE: u8 = 21;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetWindowShapeRegion";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1922,18 +1951,18 @@ impl AsByteSequence for SetPictureClipRegionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetPictureClipRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(&bytes[index..])?;
+        let (picture, sz): (Picture, usize) = <Picture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (region, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (region, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x_origin, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x_origin, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y_origin, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y_origin, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetPictureClipRegionRequest {
@@ -1962,6 +1991,7 @@ impl Request for SetPictureClipRegionRequest {
     const OPCODE: u8 = 22;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetPictureClipRegion";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1991,17 +2021,18 @@ impl AsByteSequence for SetCursorNameRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetCursorNameRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) =
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -2033,6 +2064,7 @@ impl Request for SetCursorNameRequest {
     const OPCODE: u8 = 23;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetCursorName";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2056,12 +2088,12 @@ impl AsByteSequence for GetCursorNameRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCursorNameRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetCursorNameRequest {
@@ -2081,6 +2113,7 @@ impl Request for GetCursorNameRequest {
     const OPCODE: u8 = 24;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetCursorName";
     type Reply = GetCursorNameReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2112,19 +2145,20 @@ impl AsByteSequence for GetCursorNameReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCursorNameReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (atom, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (atom, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 18;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) =
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -2173,10 +2207,10 @@ impl AsByteSequence for GetCursorImageAndNameRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCursorImageAndNameRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetCursorImageAndNameRequest {
@@ -2195,6 +2229,7 @@ impl Request for GetCursorImageAndNameRequest {
     const OPCODE: u8 = 25;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetCursorImageAndName";
     type Reply = GetCursorImageAndNameReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2244,39 +2279,40 @@ impl AsByteSequence for GetCursorImageAndNameReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCursorImageAndNameReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xhot, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (xhot, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (yhot, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (yhot, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cursor_serial, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (cursor_serial, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cursor_atom, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (cursor_atom, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (cursor_image, block_len): (Vec<Card32>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             ((width as usize) * (height as usize)) as usize,
         )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) =
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -2349,14 +2385,14 @@ impl AsByteSequence for ChangeCursorRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeCursorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (source, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (source, sz): (Cursor, usize) = <Cursor>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (destination, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (destination, sz): (Cursor, usize) = <Cursor>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ChangeCursorRequest {
@@ -2377,6 +2413,7 @@ impl Request for ChangeCursorRequest {
     const OPCODE: u8 = 26;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeCursor";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2406,17 +2443,18 @@ impl AsByteSequence for ChangeCursorByNameRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeCursorByNameRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (src, sz): (Cursor, usize) = <Cursor>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) =
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -2448,6 +2486,7 @@ impl Request for ChangeCursorByNameRequest {
     const OPCODE: u8 = 27;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeCursorByName";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2481,22 +2520,22 @@ impl AsByteSequence for ExpandRegionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ExpandRegionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (source, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (source, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (destination, sz): (Region, usize) = <Region>::from_bytes(&bytes[index..])?;
+        let (destination, sz): (Region, usize) = <Region>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (left, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (left, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (right, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (right, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (top, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (top, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bottom, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (bottom, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ExpandRegionRequest {
@@ -2529,6 +2568,7 @@ impl Request for ExpandRegionRequest {
     const OPCODE: u8 = 28;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ExpandRegion";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2552,12 +2592,12 @@ impl AsByteSequence for HideCursorRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing HideCursorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             HideCursorRequest {
@@ -2577,6 +2617,7 @@ impl Request for HideCursorRequest {
     const OPCODE: u8 = 29;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "HideCursor";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2600,12 +2641,12 @@ impl AsByteSequence for ShowCursorRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ShowCursorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ShowCursorRequest {
@@ -2625,6 +2666,7 @@ impl Request for ShowCursorRequest {
     const OPCODE: u8 = 30;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ShowCursor";
     type Reply = ();
 }
 #[repr(transparent)]
@@ -2687,31 +2729,31 @@ impl AsByteSequence for CreatePointerBarrierRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreatePointerBarrierRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (barrier, sz): (Barrier, usize) = <Barrier>::from_bytes(&bytes[index..])?;
+        let (barrier, sz): (Barrier, usize) = <Barrier>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (x1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (y1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (x2, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (y2, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (directions, sz): (BarrierDirections, usize) =
-            <BarrierDirections>::from_bytes(&bytes[index..])?;
+            <BarrierDirections>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (devices, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
         Some((
@@ -2755,6 +2797,7 @@ impl Request for CreatePointerBarrierRequest {
     const OPCODE: u8 = 31;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreatePointerBarrier";
     type Reply = ();
 }
 #[repr(transparent)]
@@ -2912,12 +2955,12 @@ impl AsByteSequence for DeletePointerBarrierRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeletePointerBarrierRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (barrier, sz): (Barrier, usize) = <Barrier>::from_bytes(&bytes[index..])?;
+        let (barrier, sz): (Barrier, usize) = <Barrier>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DeletePointerBarrierRequest {
@@ -2937,6 +2980,7 @@ impl Request for DeletePointerBarrierRequest {
     const OPCODE: u8 = 32;
     const EXTENSION: Option<&'static str> = Some("XFIXES");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DeletePointerBarrier";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2968,19 +3012,19 @@ impl AsByteSequence for CursorNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CursorNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (subtype, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (subtype, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cursor_serial, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (cursor_serial, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         Some((
@@ -3042,22 +3086,23 @@ impl AsByteSequence for SelectionNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectionNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (subtype, sz): (SelectionEvent, usize) = <SelectionEvent>::from_bytes(&bytes[index..])?;
+        let (subtype, sz): (SelectionEvent, usize) =
+            <SelectionEvent>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (owner, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (owner, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (selection, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (selection, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (timestamp, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (selection_timestamp, sz): (Timestamp, usize) =
-            <Timestamp>::from_bytes(&bytes[index..])?;
+            <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 8;
         Some((
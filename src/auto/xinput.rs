@@ -29,9 +29,9 @@ impl AsByteSequence for Fp3232 {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Fp3232 from byte buffer");
-        let (integral, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (integral, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (frac, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (frac, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Fp3232 {
@@ -71,15 +71,16 @@ impl AsByteSequence for GetExtensionVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetExtensionVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) =
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -104,6 +105,7 @@ impl Request for GetExtensionVersionRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetExtensionVersion";
     type Reply = GetExtensionVersionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -135,19 +137,19 @@ impl AsByteSequence for GetExtensionVersionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetExtensionVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (server_major, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (server_major, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (server_minor, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (server_minor, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (present, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (present, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 19;
         Some((
@@ -198,13 +200,13 @@ impl AsByteSequence for DeviceInfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceInfo from byte buffer");
-        let (device_type, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (device_type, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_class_info, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (num_class_info, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_use, sz): (DeviceUse, usize) = <DeviceUse>::from_bytes(&bytes[index..])?;
+        let (device_use, sz): (DeviceUse, usize) = <DeviceUse>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -288,15 +290,15 @@ impl AsByteSequence for KeyInfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing KeyInfo from byte buffer");
-        let (class_id, sz): (InputClass, usize) = <InputClass>::from_bytes(&bytes[index..])?;
+        let (class_id, sz): (InputClass, usize) = <InputClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (min_keycode, sz): (KeyCode, usize) = <KeyCode>::from_bytes(&bytes[index..])?;
+        let (min_keycode, sz): (KeyCode, usize) = <KeyCode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_keycode, sz): (KeyCode, usize) = <KeyCode>::from_bytes(&bytes[index..])?;
+        let (max_keycode, sz): (KeyCode, usize) = <KeyCode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_keys, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (num_keys, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -381,11 +383,11 @@ impl AsByteSequence for ButtonInfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ButtonInfo from byte buffer");
-        let (class_id, sz): (InputClass, usize) = <InputClass>::from_bytes(&bytes[index..])?;
+        let (class_id, sz): (InputClass, usize) = <InputClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_buttons, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (num_buttons, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ButtonInfo {
@@ -421,11 +423,11 @@ impl AsByteSequence for AxisInfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AxisInfo from byte buffer");
-        let (resolution, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (resolution, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minimum, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (minimum, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (maximum, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (maximum, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             AxisInfo {
@@ -468,18 +470,18 @@ impl AsByteSequence for ValuatorInfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ValuatorInfo from byte buffer");
-        let (class_id, sz): (InputClass, usize) = <InputClass>::from_bytes(&bytes[index..])?;
+        let (class_id, sz): (InputClass, usize) = <InputClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (ValuatorMode, usize) = <ValuatorMode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (ValuatorMode, usize) = <ValuatorMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (motion_size, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (motion_size, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (axes, block_len): (Vec<AxisInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<AxisInfo>());
         Some((
@@ -587,27 +589,27 @@ impl AsByteSequence for InputInfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing InputInfo from byte buffer");
-        let (class_id, sz): (InputClass, usize) = <InputClass>::from_bytes(&bytes[index..])?;
+        let (class_id, sz): (InputClass, usize) = <InputClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let cond0 = (class_id);
         let min_keycode: KeyCode = if cond0 == InputClass::Key {
-            let (min_keycode, sz): (KeyCode, usize) = <KeyCode>::from_bytes(&bytes[index..])?;
+            let (min_keycode, sz): (KeyCode, usize) = <KeyCode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             min_keycode
         } else {
             Default::default()
         };
         let max_keycode: KeyCode = if cond0 == InputClass::Key {
-            let (max_keycode, sz): (KeyCode, usize) = <KeyCode>::from_bytes(&bytes[index..])?;
+            let (max_keycode, sz): (KeyCode, usize) = <KeyCode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             max_keycode
         } else {
             Default::default()
         };
         let num_keys: Card16 = if cond0 == InputClass::Key {
-            let (num_keys, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (num_keys, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             num_keys
         } else {
@@ -615,30 +617,31 @@ impl AsByteSequence for InputInfo {
         };
         index += 2;
         let num_buttons: Card16 = if cond0 == InputClass::Button {
-            let (num_buttons, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (num_buttons, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             num_buttons
         } else {
             Default::default()
         };
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let mode: ValuatorMode = if cond0 == InputClass::Valuator {
-            let (mode, sz): (ValuatorMode, usize) = <ValuatorMode>::from_bytes(&bytes[index..])?;
+            let (mode, sz): (ValuatorMode, usize) =
+                <ValuatorMode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             mode
         } else {
             Default::default()
         };
         let motion_size: Card32 = if cond0 == InputClass::Valuator {
-            let (motion_size, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (motion_size, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             motion_size
         } else {
             Default::default()
         };
         let (axes, block_len): (Vec<AxisInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<AxisInfo>());
         Some((
@@ -694,10 +697,10 @@ impl AsByteSequence for DeviceName {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceName from byte buffer");
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (string, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((DeviceName { string: string }, index))
@@ -730,10 +733,10 @@ impl AsByteSequence for ListInputDevicesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListInputDevicesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ListInputDevicesRequest {
@@ -752,6 +755,7 @@ impl Request for ListInputDevicesRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListInputDevices";
     type Reply = ListInputDevicesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -791,23 +795,23 @@ impl AsByteSequence for ListInputDevicesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListInputDevicesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (devices_len, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (devices_len, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 23;
         let (devices, block_len): (Vec<DeviceInfo>, usize) =
-            vector_from_bytes(&bytes[index..], (devices_len as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (devices_len as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<DeviceInfo>());
         let (infos, block_len): (Vec<InputInfo>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (devices
                 .iter()
                 .map(|a| (a.num_class_info as usize) as usize)
@@ -816,7 +820,7 @@ impl AsByteSequence for ListInputDevicesReply {
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<InputInfo>());
         let (names, block_len): (Vec<Str>, usize) =
-            vector_from_bytes(&bytes[index..], (devices_len as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (devices_len as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, 4);
         Some((
@@ -877,10 +881,10 @@ impl AsByteSequence for InputClassInfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing InputClassInfo from byte buffer");
-        let (class_id, sz): (InputClass, usize) = <InputClass>::from_bytes(&bytes[index..])?;
+        let (class_id, sz): (InputClass, usize) = <InputClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (event_type_base, sz): (EventTypeBase, usize) =
-            <EventTypeBase>::from_bytes(&bytes[index..])?;
+            <EventTypeBase>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             InputClassInfo {
@@ -917,12 +921,12 @@ impl AsByteSequence for OpenDeviceRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing OpenDeviceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -943,6 +947,7 @@ impl Request for OpenDeviceRequest {
     const OPCODE: u8 = 3;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "OpenDevice";
     type Reply = OpenDeviceReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -973,19 +978,19 @@ impl AsByteSequence for OpenDeviceReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing OpenDeviceReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 23;
         let (class_info, block_len): (Vec<InputClassInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, 4);
         Some((
@@ -1036,12 +1041,12 @@ impl AsByteSequence for CloseDeviceRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CloseDeviceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -1062,6 +1067,7 @@ impl Request for CloseDeviceRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CloseDevice";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1088,14 +1094,14 @@ impl AsByteSequence for SetDeviceModeRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetDeviceModeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (ValuatorMode, usize) = <ValuatorMode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (ValuatorMode, usize) = <ValuatorMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -1117,6 +1123,7 @@ impl Request for SetDeviceModeRequest {
     const OPCODE: u8 = 5;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetDeviceMode";
     type Reply = SetDeviceModeReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1144,15 +1151,15 @@ impl AsByteSequence for SetDeviceModeReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetDeviceModeReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (GrabStatus, usize) = <GrabStatus>::from_bytes(&bytes[index..])?;
+        let (status, sz): (GrabStatus, usize) = <GrabStatus>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 23;
         Some((
@@ -1203,18 +1210,18 @@ impl AsByteSequence for SelectExtensionEventRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectExtensionEventRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (classes, block_len): (Vec<EventClass>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<EventClass>());
         Some((
@@ -1246,6 +1253,7 @@ impl Request for SelectExtensionEventRequest {
     const OPCODE: u8 = 6;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SelectExtensionEvent";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1269,12 +1277,12 @@ impl AsByteSequence for GetSelectedExtensionEventsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetSelectedExtensionEventsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetSelectedExtensionEventsRequest {
@@ -1294,6 +1302,7 @@ impl Request for GetSelectedExtensionEventsRequest {
     const OPCODE: u8 = 7;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetSelectedExtensionEvents";
     type Reply = GetSelectedExtensionEventsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1329,25 +1338,25 @@ impl AsByteSequence for GetSelectedExtensionEventsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetSelectedExtensionEventsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (this_classes, block_len): (Vec<EventClass>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<EventClass>());
         let (all_classes, block_len): (Vec<EventClass>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<EventClass>());
         Some((
@@ -1412,20 +1421,20 @@ impl AsByteSequence for ChangeDeviceDontPropagateListRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeDeviceDontPropagateListRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (PropagateMode, usize) = <PropagateMode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (PropagateMode, usize) = <PropagateMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         let (classes, block_len): (Vec<EventClass>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<EventClass>());
         Some((
@@ -1459,6 +1468,7 @@ impl Request for ChangeDeviceDontPropagateListRequest {
     const OPCODE: u8 = 8;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeDeviceDontPropagateList";
     type Reply = ();
 }
 #[repr(u8)]
@@ -1513,12 +1523,12 @@ impl AsByteSequence for GetDeviceDontPropagateListRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDeviceDontPropagateListRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetDeviceDontPropagateListRequest {
@@ -1538,6 +1548,7 @@ impl Request for GetDeviceDontPropagateListRequest {
     const OPCODE: u8 = 9;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetDeviceDontPropagateList";
     type Reply = GetDeviceDontPropagateListReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1568,19 +1579,19 @@ impl AsByteSequence for GetDeviceDontPropagateListReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDeviceDontPropagateListReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
         let (classes, block_len): (Vec<EventClass>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<EventClass>());
         Some((
@@ -1631,12 +1642,12 @@ impl AsByteSequence for ChangeKeyboardDeviceRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeKeyboardDeviceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -1657,6 +1668,7 @@ impl Request for ChangeKeyboardDeviceRequest {
     const OPCODE: u8 = 11;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeKeyboardDevice";
     type Reply = ChangeKeyboardDeviceReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1684,15 +1696,15 @@ impl AsByteSequence for ChangeKeyboardDeviceReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeKeyboardDeviceReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (GrabStatus, usize) = <GrabStatus>::from_bytes(&bytes[index..])?;
+        let (status, sz): (GrabStatus, usize) = <GrabStatus>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 23;
         Some((
@@ -1742,16 +1754,16 @@ impl AsByteSequence for ChangePointerDeviceRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangePointerDeviceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x_axis, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (x_axis, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y_axis, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (y_axis, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -1780,6 +1792,7 @@ impl Request for ChangePointerDeviceRequest {
     const OPCODE: u8 = 12;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangePointerDevice";
     type Reply = ChangePointerDeviceReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1807,15 +1820,15 @@ impl AsByteSequence for ChangePointerDeviceReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangePointerDeviceReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (GrabStatus, usize) = <GrabStatus>::from_bytes(&bytes[index..])?;
+        let (status, sz): (GrabStatus, usize) = <GrabStatus>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 23;
         Some((
@@ -1876,28 +1889,30 @@ impl AsByteSequence for GrabDeviceRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GrabDeviceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (this_device_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(&bytes[index..])?;
+        let (this_device_mode, sz): (GrabMode, usize) =
+            <GrabMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (other_device_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(&bytes[index..])?;
+        let (other_device_mode, sz): (GrabMode, usize) =
+            <GrabMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (owner_events, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (owner_events, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (classes, block_len): (Vec<EventClass>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<EventClass>());
         Some((
@@ -1939,6 +1954,7 @@ impl Request for GrabDeviceRequest {
     const OPCODE: u8 = 13;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GrabDevice";
     type Reply = GrabDeviceReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1966,15 +1982,15 @@ impl AsByteSequence for GrabDeviceReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GrabDeviceReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (GrabStatus, usize) = <GrabStatus>::from_bytes(&bytes[index..])?;
+        let (status, sz): (GrabStatus, usize) = <GrabStatus>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 23;
         Some((
@@ -2022,14 +2038,14 @@ impl AsByteSequence for UngrabDeviceRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing UngrabDeviceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -2051,6 +2067,7 @@ impl Request for UngrabDeviceRequest {
     const OPCODE: u8 = 14;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UngrabDevice";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2094,32 +2111,34 @@ impl AsByteSequence for GrabDeviceKeyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GrabDeviceKeyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (modifiers, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (modifiers, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (modifier_device, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (modifier_device, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (grabbed_device, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (grabbed_device, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (key, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (key, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (this_device_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(&bytes[index..])?;
+        let (this_device_mode, sz): (GrabMode, usize) =
+            <GrabMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (other_device_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(&bytes[index..])?;
+        let (other_device_mode, sz): (GrabMode, usize) =
+            <GrabMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (owner_events, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (owner_events, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (classes, block_len): (Vec<EventClass>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<EventClass>());
         Some((
@@ -2165,6 +2184,7 @@ impl Request for GrabDeviceKeyRequest {
     const OPCODE: u8 = 15;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GrabDeviceKey";
     type Reply = ();
 }
 #[repr(u8)]
@@ -2225,20 +2245,20 @@ impl AsByteSequence for UngrabDeviceKeyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing UngrabDeviceKeyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (modifiers, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (modifiers, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (modifier_device, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (modifier_device, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (key, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (key, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (grabbed_device, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (grabbed_device, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             UngrabDeviceKeyRequest {
@@ -2269,6 +2289,7 @@ impl Request for UngrabDeviceKeyRequest {
     const OPCODE: u8 = 16;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UngrabDeviceKey";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2312,32 +2333,34 @@ impl AsByteSequence for GrabDeviceButtonRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GrabDeviceButtonRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (grabbed_device, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (grabbed_device, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (modifier_device, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (modifier_device, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (modifiers, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (modifiers, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (this_device_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(&bytes[index..])?;
+        let (this_device_mode, sz): (GrabMode, usize) =
+            <GrabMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (other_device_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(&bytes[index..])?;
+        let (other_device_mode, sz): (GrabMode, usize) =
+            <GrabMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (button, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (button, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (owner_events, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (owner_events, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (classes, block_len): (Vec<EventClass>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<EventClass>());
         Some((
@@ -2383,6 +2406,7 @@ impl Request for GrabDeviceButtonRequest {
     const OPCODE: u8 = 17;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GrabDeviceButton";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2415,20 +2439,20 @@ impl AsByteSequence for UngrabDeviceButtonRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing UngrabDeviceButtonRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (modifiers, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (modifiers, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (modifier_device, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (modifier_device, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (button, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (button, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (grabbed_device, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (grabbed_device, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -2461,6 +2485,7 @@ impl Request for UngrabDeviceButtonRequest {
     const OPCODE: u8 = 18;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UngrabDeviceButton";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2489,16 +2514,17 @@ impl AsByteSequence for AllowDeviceEventsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AllowDeviceEventsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (DeviceInputMode, usize) = <DeviceInputMode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (DeviceInputMode, usize) =
+            <DeviceInputMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -2527,6 +2553,7 @@ impl Request for AllowDeviceEventsRequest {
     const OPCODE: u8 = 19;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "AllowDeviceEvents";
     type Reply = ();
 }
 #[repr(u8)]
@@ -2590,12 +2617,12 @@ impl AsByteSequence for GetDeviceFocusRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDeviceFocusRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -2616,6 +2643,7 @@ impl Request for GetDeviceFocusRequest {
     const OPCODE: u8 = 20;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetDeviceFocus";
     type Reply = GetDeviceFocusReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2647,19 +2675,19 @@ impl AsByteSequence for GetDeviceFocusReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDeviceFocusReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (focus, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (focus, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (revert_to, sz): (InputFocus, usize) = <InputFocus>::from_bytes(&bytes[index..])?;
+        let (revert_to, sz): (InputFocus, usize) = <InputFocus>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 15;
         Some((
@@ -2715,18 +2743,18 @@ impl AsByteSequence for SetDeviceFocusRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetDeviceFocusRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (focus, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (focus, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (revert_to, sz): (InputFocus, usize) = <InputFocus>::from_bytes(&bytes[index..])?;
+        let (revert_to, sz): (InputFocus, usize) = <InputFocus>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -2757,6 +2785,7 @@ impl Request for SetDeviceFocusRequest {
     const OPCODE: u8 = 21;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetDeviceFocus";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2796,28 +2825,30 @@ impl AsByteSequence for KbdFeedbackState {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing KbdFeedbackState from byte buffer");
-        let (class_id, sz): (FeedbackClass, usize) = <FeedbackClass>::from_bytes(&bytes[index..])?;
+        let (class_id, sz): (FeedbackClass, usize) =
+            <FeedbackClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pitch, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (pitch, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (duration, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (duration, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (led_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (led_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (led_values, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (led_values, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (global_auto_repeat, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (global_auto_repeat, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (click, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (click, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (percent, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (percent, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (auto_repeats, sz): ([Card8; 32], usize) = <[Card8; 32]>::from_bytes(&bytes[index..])?;
+        let (auto_repeats, sz): ([Card8; 32], usize) =
+            <[Card8; 32]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             KbdFeedbackState {
@@ -2918,18 +2949,19 @@ impl AsByteSequence for PtrFeedbackState {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PtrFeedbackState from byte buffer");
-        let (class_id, sz): (FeedbackClass, usize) = <FeedbackClass>::from_bytes(&bytes[index..])?;
+        let (class_id, sz): (FeedbackClass, usize) =
+            <FeedbackClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (accel_num, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (accel_num, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (accel_denom, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (accel_denom, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (threshold, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (threshold, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PtrFeedbackState {
@@ -2980,17 +3012,18 @@ impl AsByteSequence for IntegerFeedbackState {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing IntegerFeedbackState from byte buffer");
-        let (class_id, sz): (FeedbackClass, usize) = <FeedbackClass>::from_bytes(&bytes[index..])?;
+        let (class_id, sz): (FeedbackClass, usize) =
+            <FeedbackClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (resolution, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (resolution, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (min_value, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (min_value, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_value, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (max_value, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             IntegerFeedbackState {
@@ -3041,18 +3074,19 @@ impl AsByteSequence for StringFeedbackState {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing StringFeedbackState from byte buffer");
-        let (class_id, sz): (FeedbackClass, usize) = <FeedbackClass>::from_bytes(&bytes[index..])?;
+        let (class_id, sz): (FeedbackClass, usize) =
+            <FeedbackClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_symbols, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (max_symbols, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (keysyms, block_len): (Vec<Keysym>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Keysym>());
         Some((
@@ -3107,18 +3141,19 @@ impl AsByteSequence for BellFeedbackState {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing BellFeedbackState from byte buffer");
-        let (class_id, sz): (FeedbackClass, usize) = <FeedbackClass>::from_bytes(&bytes[index..])?;
+        let (class_id, sz): (FeedbackClass, usize) =
+            <FeedbackClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (percent, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (percent, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (pitch, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (pitch, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (duration, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (duration, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             BellFeedbackState {
@@ -3167,15 +3202,16 @@ impl AsByteSequence for LedFeedbackState {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing LedFeedbackState from byte buffer");
-        let (class_id, sz): (FeedbackClass, usize) = <FeedbackClass>::from_bytes(&bytes[index..])?;
+        let (class_id, sz): (FeedbackClass, usize) =
+            <FeedbackClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (led_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (led_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (led_values, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (led_values, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             LedFeedbackState {
@@ -3304,137 +3340,139 @@ impl AsByteSequence for FeedbackState {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FeedbackState from byte buffer");
-        let (class_id, sz): (FeedbackClass, usize) = <FeedbackClass>::from_bytes(&bytes[index..])?;
+        let (class_id, sz): (FeedbackClass, usize) =
+            <FeedbackClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let cond0 = (class_id);
         let pitch: Card16 = if cond0 == FeedbackClass::Keyboard {
-            let (pitch, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (pitch, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             pitch
         } else {
             Default::default()
         };
         let duration: Card16 = if cond0 == FeedbackClass::Keyboard {
-            let (duration, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (duration, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             duration
         } else {
             Default::default()
         };
         let led_mask: Card32 = if cond0 == FeedbackClass::Keyboard {
-            let (led_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (led_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             led_mask
         } else {
             Default::default()
         };
         let led_values: Card32 = if cond0 == FeedbackClass::Keyboard {
-            let (led_values, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (led_values, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             led_values
         } else {
             Default::default()
         };
         let global_auto_repeat: bool = if cond0 == FeedbackClass::Keyboard {
-            let (global_auto_repeat, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+            let (global_auto_repeat, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             global_auto_repeat
         } else {
             Default::default()
         };
         let click: Card8 = if cond0 == FeedbackClass::Keyboard {
-            let (click, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (click, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             click
         } else {
             Default::default()
         };
         let percent: Card8 = if cond0 == FeedbackClass::Keyboard {
-            let (percent, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (percent, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             percent
         } else {
             Default::default()
         };
         index += 1;
-        let (auto_repeats, sz): ([Card8; 32], usize) = <[Card8; 32]>::from_bytes(&bytes[index..])?;
+        let (auto_repeats, sz): ([Card8; 32], usize) =
+            <[Card8; 32]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let accel_num: Card16 = if cond0 == FeedbackClass::Pointer {
-            let (accel_num, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (accel_num, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             accel_num
         } else {
             Default::default()
         };
         let accel_denom: Card16 = if cond0 == FeedbackClass::Pointer {
-            let (accel_denom, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (accel_denom, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             accel_denom
         } else {
             Default::default()
         };
         let threshold: Card16 = if cond0 == FeedbackClass::Pointer {
-            let (threshold, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (threshold, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             threshold
         } else {
             Default::default()
         };
         let max_symbols: Card16 = if cond0 == FeedbackClass::String {
-            let (max_symbols, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (max_symbols, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             max_symbols
         } else {
             Default::default()
         };
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (keysyms, block_len): (Vec<Keysym>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Keysym>());
         let resolution: Card32 = if cond0 == FeedbackClass::Integer {
-            let (resolution, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (resolution, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             resolution
         } else {
             Default::default()
         };
         let min_value: Int32 = if cond0 == FeedbackClass::Integer {
-            let (min_value, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (min_value, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             min_value
         } else {
             Default::default()
         };
         let max_value: Int32 = if cond0 == FeedbackClass::Integer {
-            let (max_value, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (max_value, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             max_value
         } else {
             Default::default()
         };
         let led_mask_: Card32 = if cond0 == FeedbackClass::Led {
-            let (led_mask_, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (led_mask_, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             led_mask_
         } else {
             Default::default()
         };
         let led_values_: Card32 = if cond0 == FeedbackClass::Led {
-            let (led_values_, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (led_values_, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             led_values_
         } else {
             Default::default()
         };
         let percent_: Card8 = if cond0 == FeedbackClass::Bell {
-            let (percent_, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (percent_, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             percent_
         } else {
@@ -3442,14 +3480,14 @@ impl AsByteSequence for FeedbackState {
         };
         index += 3;
         let pitch_: Card16 = if cond0 == FeedbackClass::Bell {
-            let (pitch_, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (pitch_, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             pitch_
         } else {
             Default::default()
         };
         let duration_: Card16 = if cond0 == FeedbackClass::Bell {
-            let (duration_, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (duration_, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             duration_
         } else {
@@ -3543,12 +3581,12 @@ impl AsByteSequence for GetFeedbackControlRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetFeedbackControlRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -3569,6 +3607,7 @@ impl Request for GetFeedbackControlRequest {
     const OPCODE: u8 = 22;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetFeedbackControl";
     type Reply = GetFeedbackControlReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3599,19 +3638,19 @@ impl AsByteSequence for GetFeedbackControlReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetFeedbackControlReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
         let (feedbacks, block_len): (Vec<FeedbackState>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<FeedbackState>());
         Some((
@@ -3676,27 +3715,28 @@ impl AsByteSequence for KbdFeedbackCtl {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing KbdFeedbackCtl from byte buffer");
-        let (class_id, sz): (FeedbackClass, usize) = <FeedbackClass>::from_bytes(&bytes[index..])?;
+        let (class_id, sz): (FeedbackClass, usize) =
+            <FeedbackClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (key, sz): (KeyCode, usize) = <KeyCode>::from_bytes(&bytes[index..])?;
+        let (key, sz): (KeyCode, usize) = <KeyCode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (auto_repeat_mode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (auto_repeat_mode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (key_click_percent, sz): (Int8, usize) = <Int8>::from_bytes(&bytes[index..])?;
+        let (key_click_percent, sz): (Int8, usize) = <Int8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bell_percent, sz): (Int8, usize) = <Int8>::from_bytes(&bytes[index..])?;
+        let (bell_percent, sz): (Int8, usize) = <Int8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bell_pitch, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (bell_pitch, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bell_duration, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (bell_duration, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (led_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (led_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (led_values, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (led_values, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             KbdFeedbackCtl {
@@ -3757,18 +3797,19 @@ impl AsByteSequence for PtrFeedbackCtl {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PtrFeedbackCtl from byte buffer");
-        let (class_id, sz): (FeedbackClass, usize) = <FeedbackClass>::from_bytes(&bytes[index..])?;
+        let (class_id, sz): (FeedbackClass, usize) =
+            <FeedbackClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (num, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (num, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (denom, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (denom, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (threshold, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (threshold, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PtrFeedbackCtl {
@@ -3815,13 +3856,14 @@ impl AsByteSequence for IntegerFeedbackCtl {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing IntegerFeedbackCtl from byte buffer");
-        let (class_id, sz): (FeedbackClass, usize) = <FeedbackClass>::from_bytes(&bytes[index..])?;
+        let (class_id, sz): (FeedbackClass, usize) =
+            <FeedbackClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (int_to_display, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (int_to_display, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             IntegerFeedbackCtl {
@@ -3867,17 +3909,18 @@ impl AsByteSequence for StringFeedbackCtl {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing StringFeedbackCtl from byte buffer");
-        let (class_id, sz): (FeedbackClass, usize) = <FeedbackClass>::from_bytes(&bytes[index..])?;
+        let (class_id, sz): (FeedbackClass, usize) =
+            <FeedbackClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (keysyms, block_len): (Vec<Keysym>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Keysym>());
         Some((
@@ -3931,18 +3974,19 @@ impl AsByteSequence for BellFeedbackCtl {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing BellFeedbackCtl from byte buffer");
-        let (class_id, sz): (FeedbackClass, usize) = <FeedbackClass>::from_bytes(&bytes[index..])?;
+        let (class_id, sz): (FeedbackClass, usize) =
+            <FeedbackClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (percent, sz): (Int8, usize) = <Int8>::from_bytes(&bytes[index..])?;
+        let (percent, sz): (Int8, usize) = <Int8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (pitch, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (pitch, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (duration, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (duration, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             BellFeedbackCtl {
@@ -3991,15 +4035,16 @@ impl AsByteSequence for LedFeedbackCtl {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing LedFeedbackCtl from byte buffer");
-        let (class_id, sz): (FeedbackClass, usize) = <FeedbackClass>::from_bytes(&bytes[index..])?;
+        let (class_id, sz): (FeedbackClass, usize) =
+            <FeedbackClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (led_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (led_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (led_values, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (led_values, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             LedFeedbackCtl {
@@ -4088,6 +4133,7 @@ impl AsByteSequence for FeedbackCtl {
         if cond0 == FeedbackClass::Pointer {
             index += self.threshold.as_bytes(&mut bytes[index..]);
         }
+        index += 2;
         index += (self.keysyms.len() as Card16).as_bytes(&mut bytes[index..]);
         let block_len: usize = vector_as_bytes(&self.keysyms, &mut bytes[index..]);
         index += block_len;
@@ -4117,64 +4163,65 @@ impl AsByteSequence for FeedbackCtl {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FeedbackCtl from byte buffer");
-        let (class_id, sz): (FeedbackClass, usize) = <FeedbackClass>::from_bytes(&bytes[index..])?;
+        let (class_id, sz): (FeedbackClass, usize) =
+            <FeedbackClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let cond0 = (class_id);
         let key: KeyCode = if cond0 == FeedbackClass::Keyboard {
-            let (key, sz): (KeyCode, usize) = <KeyCode>::from_bytes(&bytes[index..])?;
+            let (key, sz): (KeyCode, usize) = <KeyCode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             key
         } else {
             Default::default()
         };
         let auto_repeat_mode: Card8 = if cond0 == FeedbackClass::Keyboard {
-            let (auto_repeat_mode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (auto_repeat_mode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             auto_repeat_mode
         } else {
             Default::default()
         };
         let key_click_percent: Int8 = if cond0 == FeedbackClass::Keyboard {
-            let (key_click_percent, sz): (Int8, usize) = <Int8>::from_bytes(&bytes[index..])?;
+            let (key_click_percent, sz): (Int8, usize) = <Int8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             key_click_percent
         } else {
             Default::default()
         };
         let bell_percent: Int8 = if cond0 == FeedbackClass::Keyboard {
-            let (bell_percent, sz): (Int8, usize) = <Int8>::from_bytes(&bytes[index..])?;
+            let (bell_percent, sz): (Int8, usize) = <Int8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             bell_percent
         } else {
             Default::default()
         };
         let bell_pitch: Int16 = if cond0 == FeedbackClass::Keyboard {
-            let (bell_pitch, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+            let (bell_pitch, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             bell_pitch
         } else {
             Default::default()
         };
         let bell_duration: Int16 = if cond0 == FeedbackClass::Keyboard {
-            let (bell_duration, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+            let (bell_duration, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             bell_duration
         } else {
             Default::default()
         };
         let led_mask: Card32 = if cond0 == FeedbackClass::Keyboard {
-            let (led_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (led_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             led_mask
         } else {
             Default::default()
         };
         let led_values: Card32 = if cond0 == FeedbackClass::Keyboard {
-            let (led_values, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (led_values, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             led_values
         } else {
@@ -4182,55 +4229,56 @@ impl AsByteSequence for FeedbackCtl {
         };
         index += 2;
         let num: Int16 = if cond0 == FeedbackClass::Pointer {
-            let (num, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+            let (num, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             num
         } else {
             Default::default()
         };
         let denom: Int16 = if cond0 == FeedbackClass::Pointer {
-            let (denom, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+            let (denom, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             denom
         } else {
             Default::default()
         };
         let threshold: Int16 = if cond0 == FeedbackClass::Pointer {
-            let (threshold, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+            let (threshold, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             threshold
         } else {
             Default::default()
         };
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        index += 2;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (keysyms, block_len): (Vec<Keysym>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Keysym>());
         let int_to_display: Int32 = if cond0 == FeedbackClass::Integer {
-            let (int_to_display, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (int_to_display, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             int_to_display
         } else {
             Default::default()
         };
         let led_mask_: Card32 = if cond0 == FeedbackClass::Led {
-            let (led_mask_, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (led_mask_, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             led_mask_
         } else {
             Default::default()
         };
         let led_values_: Card32 = if cond0 == FeedbackClass::Led {
-            let (led_values_, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (led_values_, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             led_values_
         } else {
             Default::default()
         };
         let percent: Int8 = if cond0 == FeedbackClass::Bell {
-            let (percent, sz): (Int8, usize) = <Int8>::from_bytes(&bytes[index..])?;
+            let (percent, sz): (Int8, usize) = <Int8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             percent
         } else {
@@ -4238,14 +4286,14 @@ impl AsByteSequence for FeedbackCtl {
         };
         index += 3;
         let pitch: Int16 = if cond0 == FeedbackClass::Bell {
-            let (pitch, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+            let (pitch, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             pitch
         } else {
             Default::default()
         };
         let duration: Int16 = if cond0 == FeedbackClass::Bell {
-            let (duration, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+            let (duration, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             duration
         } else {
@@ -4295,6 +4343,7 @@ impl AsByteSequence for FeedbackCtl {
             + self.num.size()
             + self.denom.size()
             + self.threshold.size()
+            + 2
             + ::core::mem::size_of::<Card16>()
             + {
                 let block_len: usize = self.keysyms.iter().map(|i| i.size()).sum();
@@ -4338,20 +4387,20 @@ impl AsByteSequence for ChangeFeedbackControlRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeFeedbackControlRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (mask, sz): (ChangeFeedbackControlMask, usize) =
-            <ChangeFeedbackControlMask>::from_bytes(&bytes[index..])?;
+            <ChangeFeedbackControlMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (feedback, sz): (FeedbackCtl, usize) = <FeedbackCtl>::from_bytes(&bytes[index..])?;
+        let (feedback, sz): (FeedbackCtl, usize) = <FeedbackCtl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ChangeFeedbackControlRequest {
@@ -4381,6 +4430,7 @@ impl Request for ChangeFeedbackControlRequest {
     const OPCODE: u8 = 23;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeFeedbackControl";
     type Reply = ();
 }
 #[repr(transparent)]
@@ -4620,16 +4670,16 @@ impl AsByteSequence for GetDeviceKeyMappingRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDeviceKeyMappingRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_keycode, sz): (KeyCode, usize) = <KeyCode>::from_bytes(&bytes[index..])?;
+        let (first_keycode, sz): (KeyCode, usize) = <KeyCode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (count, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (count, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -4658,6 +4708,7 @@ impl Request for GetDeviceKeyMappingRequest {
     const OPCODE: u8 = 24;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetDeviceKeyMapping";
     type Reply = GetDeviceKeyMappingReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4689,19 +4740,19 @@ impl AsByteSequence for GetDeviceKeyMappingReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDeviceKeyMappingReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (keysyms_per_keycode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (keysyms_per_keycode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 23;
         let (keysyms, block_len): (Vec<Keysym>, usize) =
-            vector_from_bytes(&bytes[index..], (length as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (length as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Keysym>());
         Some((
@@ -4762,21 +4813,21 @@ impl AsByteSequence for ChangeDeviceKeyMappingRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeDeviceKeyMappingRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_keycode, sz): (KeyCode, usize) = <KeyCode>::from_bytes(&bytes[index..])?;
+        let (first_keycode, sz): (KeyCode, usize) = <KeyCode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (keysyms_per_keycode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (keysyms_per_keycode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (keycode_count, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (keycode_count, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (keysyms, block_len): (Vec<Keysym>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             ((keycode_count as usize) * (keysyms_per_keycode as usize)) as usize,
         )?;
         index += block_len;
@@ -4814,6 +4865,7 @@ impl Request for ChangeDeviceKeyMappingRequest {
     const OPCODE: u8 = 25;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeDeviceKeyMapping";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4838,12 +4890,12 @@ impl AsByteSequence for GetDeviceModifierMappingRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDeviceModifierMappingRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -4864,6 +4916,7 @@ impl Request for GetDeviceModifierMappingRequest {
     const OPCODE: u8 = 26;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetDeviceModifierMapping";
     type Reply = GetDeviceModifierMappingReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4895,19 +4948,19 @@ impl AsByteSequence for GetDeviceModifierMappingReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDeviceModifierMappingReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (keycodes_per_modifier, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (keycodes_per_modifier, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 23;
         let (keymaps, block_len): (Vec<Card8>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             ((keycodes_per_modifier as usize) * (8)) as usize,
         )?;
         index += block_len;
@@ -4967,18 +5020,18 @@ impl AsByteSequence for SetDeviceModifierMappingRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetDeviceModifierMappingRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (keycodes_per_modifier, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (keycodes_per_modifier, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (keymaps, block_len): (Vec<Card8>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             ((keycodes_per_modifier as usize) * (8)) as usize,
         )?;
         index += block_len;
@@ -5013,6 +5066,7 @@ impl Request for SetDeviceModifierMappingRequest {
     const OPCODE: u8 = 27;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetDeviceModifierMapping";
     type Reply = SetDeviceModifierMappingReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5040,15 +5094,16 @@ impl AsByteSequence for SetDeviceModifierMappingReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetDeviceModifierMappingReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (MappingStatus, usize) = <MappingStatus>::from_bytes(&bytes[index..])?;
+        let (status, sz): (MappingStatus, usize) =
+            <MappingStatus>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 23;
         Some((
@@ -5094,12 +5149,12 @@ impl AsByteSequence for GetDeviceButtonMappingRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDeviceButtonMappingRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -5120,6 +5175,7 @@ impl Request for GetDeviceButtonMappingRequest {
     const OPCODE: u8 = 28;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetDeviceButtonMapping";
     type Reply = GetDeviceButtonMappingReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5150,19 +5206,19 @@ impl AsByteSequence for GetDeviceButtonMappingReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDeviceButtonMappingReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 23;
         let (map, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, 4);
         Some((
@@ -5218,18 +5274,18 @@ impl AsByteSequence for SetDeviceButtonMappingRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetDeviceButtonMappingRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (map, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
         Some((
@@ -5261,6 +5317,7 @@ impl Request for SetDeviceButtonMappingRequest {
     const OPCODE: u8 = 29;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetDeviceButtonMapping";
     type Reply = SetDeviceButtonMappingReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5288,15 +5345,16 @@ impl AsByteSequence for SetDeviceButtonMappingReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetDeviceButtonMappingReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (MappingStatus, usize) = <MappingStatus>::from_bytes(&bytes[index..])?;
+        let (status, sz): (MappingStatus, usize) =
+            <MappingStatus>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 23;
         Some((
@@ -5343,14 +5401,14 @@ impl AsByteSequence for KeyState {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing KeyState from byte buffer");
-        let (class_id, sz): (InputClass, usize) = <InputClass>::from_bytes(&bytes[index..])?;
+        let (class_id, sz): (InputClass, usize) = <InputClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (num_keys, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (keys, sz): ([Card8; 32], usize) = <[Card8; 32]>::from_bytes(&bytes[index..])?;
+        let (keys, sz): ([Card8; 32], usize) = <[Card8; 32]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             KeyState {
@@ -5390,14 +5448,14 @@ impl AsByteSequence for ButtonState {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ButtonState from byte buffer");
-        let (class_id, sz): (InputClass, usize) = <InputClass>::from_bytes(&bytes[index..])?;
+        let (class_id, sz): (InputClass, usize) = <InputClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_buttons, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (num_buttons, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (buttons, sz): ([Card8; 32], usize) = <[Card8; 32]>::from_bytes(&bytes[index..])?;
+        let (buttons, sz): ([Card8; 32], usize) = <[Card8; 32]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ButtonState {
@@ -5439,17 +5497,17 @@ impl AsByteSequence for ValuatorState {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ValuatorState from byte buffer");
-        let (class_id, sz): (InputClass, usize) = <InputClass>::from_bytes(&bytes[index..])?;
+        let (class_id, sz): (InputClass, usize) = <InputClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (mode, sz): (ValuatorStateModeMask, usize) =
-            <ValuatorStateModeMask>::from_bytes(&bytes[index..])?;
+            <ValuatorStateModeMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (valuators, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
         Some((
@@ -5602,6 +5660,7 @@ impl AsByteSequence for InputState {
         if cond0 == InputClass::Button {
             index += self.num_buttons.as_bytes(&mut bytes[index..]);
         }
+        index += 1;
         index += self.buttons.as_bytes(&mut bytes[index..]);
         index += (self.valuators.len() as Card8).as_bytes(&mut bytes[index..]);
         if cond0 == InputClass::Valuator {
@@ -5616,42 +5675,43 @@ impl AsByteSequence for InputState {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing InputState from byte buffer");
-        let (class_id, sz): (InputClass, usize) = <InputClass>::from_bytes(&bytes[index..])?;
+        let (class_id, sz): (InputClass, usize) = <InputClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let cond0 = (class_id);
         let num_keys: Card8 = if cond0 == InputClass::Key {
-            let (num_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (num_keys, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             num_keys
         } else {
             Default::default()
         };
         index += 1;
-        let (keys, sz): ([Card8; 32], usize) = <[Card8; 32]>::from_bytes(&bytes[index..])?;
+        let (keys, sz): ([Card8; 32], usize) = <[Card8; 32]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let num_buttons: Card8 = if cond0 == InputClass::Button {
-            let (num_buttons, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (num_buttons, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             num_buttons
         } else {
             Default::default()
         };
-        let (buttons, sz): ([Card8; 32], usize) = <[Card8; 32]>::from_bytes(&bytes[index..])?;
+        index += 1;
+        let (buttons, sz): ([Card8; 32], usize) = <[Card8; 32]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let mode: ValuatorStateModeMask = if cond0 == InputClass::Valuator {
             let (mode, sz): (ValuatorStateModeMask, usize) =
-                <ValuatorStateModeMask>::from_bytes(&bytes[index..])?;
+                <ValuatorStateModeMask>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             mode
         } else {
             Default::default()
         };
         let (valuators, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
         Some((
@@ -5676,6 +5736,7 @@ impl AsByteSequence for InputState {
             + 1
             + self.keys.size()
             + self.num_buttons.size()
+            + 1
             + self.buttons.size()
             + ::core::mem::size_of::<Card8>()
             + self.mode.size()
@@ -5708,12 +5769,12 @@ impl AsByteSequence for QueryDeviceStateRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryDeviceStateRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -5734,6 +5795,7 @@ impl Request for QueryDeviceStateRequest {
     const OPCODE: u8 = 30;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryDeviceState";
     type Reply = QueryDeviceStateReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5764,19 +5826,19 @@ impl AsByteSequence for QueryDeviceStateReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryDeviceStateReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 23;
         let (classes, block_len): (Vec<InputState>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<InputState>());
         Some((
@@ -5832,18 +5894,18 @@ impl AsByteSequence for DeviceBellRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceBellRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (feedback_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (feedback_class, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (feedback_class, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (percent, sz): (Int8, usize) = <Int8>::from_bytes(&bytes[index..])?;
+        let (percent, sz): (Int8, usize) = <Int8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DeviceBellRequest {
@@ -5872,6 +5934,7 @@ impl Request for DeviceBellRequest {
     const OPCODE: u8 = 32;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DeviceBell";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5903,20 +5966,20 @@ impl AsByteSequence for SetDeviceValuatorsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetDeviceValuatorsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_valuator, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_valuator, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         let (valuators, block_len): (Vec<Int32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Int32>());
         Some((
@@ -5950,6 +6013,7 @@ impl Request for SetDeviceValuatorsRequest {
     const OPCODE: u8 = 33;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetDeviceValuators";
     type Reply = SetDeviceValuatorsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5977,15 +6041,15 @@ impl AsByteSequence for SetDeviceValuatorsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetDeviceValuatorsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (GrabStatus, usize) = <GrabStatus>::from_bytes(&bytes[index..])?;
+        let (status, sz): (GrabStatus, usize) = <GrabStatus>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 23;
         Some((
@@ -6042,22 +6106,22 @@ impl AsByteSequence for DeviceResolutionState {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceResolutionState from byte buffer");
         let (control_id, sz): (DeviceControl, usize) =
-            <DeviceControl>::from_bytes(&bytes[index..])?;
+            <DeviceControl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_valuators, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_valuators, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (resolution_values, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (num_valuators as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_valuators as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (resolution_min, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (num_valuators as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_valuators as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (resolution_max, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (num_valuators as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_valuators as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -6166,25 +6230,25 @@ impl AsByteSequence for DeviceAbsCalibState {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceAbsCalibState from byte buffer");
         let (control_id, sz): (DeviceControl, usize) =
-            <DeviceControl>::from_bytes(&bytes[index..])?;
+            <DeviceControl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (min_x, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (min_x, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_x, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (max_x, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (min_y, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (min_y, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_y, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (max_y, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flip_x, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (flip_x, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flip_y, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (flip_y, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (rotation, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (rotation, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (button_threshold, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (button_threshold, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DeviceAbsCalibState {
@@ -6247,21 +6311,21 @@ impl AsByteSequence for DeviceAbsAreaState {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceAbsAreaState from byte buffer");
         let (control_id, sz): (DeviceControl, usize) =
-            <DeviceControl>::from_bytes(&bytes[index..])?;
+            <DeviceControl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (offset_x, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset_x, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (offset_y, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset_y, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (following, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (following, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DeviceAbsAreaState {
@@ -6313,13 +6377,13 @@ impl AsByteSequence for DeviceCoreState {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceCoreState from byte buffer");
         let (control_id, sz): (DeviceControl, usize) =
-            <DeviceControl>::from_bytes(&bytes[index..])?;
+            <DeviceControl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (status, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (iscore, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (iscore, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -6359,11 +6423,11 @@ impl AsByteSequence for DeviceEnableState {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceEnableState from byte buffer");
         let (control_id, sz): (DeviceControl, usize) =
-            <DeviceControl>::from_bytes(&bytes[index..])?;
+            <DeviceControl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (enable, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (enable, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -6486,95 +6550,96 @@ impl AsByteSequence for DeviceState {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceState from byte buffer");
         let (control_id, sz): (DeviceControl, usize) =
-            <DeviceControl>::from_bytes(&bytes[index..])?;
+            <DeviceControl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let cond0 = (control_id);
         let num_valuators: Card32 = if cond0 == DeviceControl::Resolution {
-            let (num_valuators, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (num_valuators, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             num_valuators
         } else {
             Default::default()
         };
         let (resolution_values, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (num_valuators as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_valuators as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (resolution_min, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (num_valuators as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_valuators as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (resolution_max, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (num_valuators as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_valuators as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let min_x: Int32 = if cond0 == DeviceControl::AbsCalib {
-            let (min_x, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (min_x, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             min_x
         } else {
             Default::default()
         };
         let max_x: Int32 = if cond0 == DeviceControl::AbsCalib {
-            let (max_x, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (max_x, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             max_x
         } else {
             Default::default()
         };
         let min_y: Int32 = if cond0 == DeviceControl::AbsCalib {
-            let (min_y, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (min_y, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             min_y
         } else {
             Default::default()
         };
         let max_y: Int32 = if cond0 == DeviceControl::AbsCalib {
-            let (max_y, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (max_y, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             max_y
         } else {
             Default::default()
         };
         let flip_x: Card32 = if cond0 == DeviceControl::AbsCalib {
-            let (flip_x, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (flip_x, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             flip_x
         } else {
             Default::default()
         };
         let flip_y: Card32 = if cond0 == DeviceControl::AbsCalib {
-            let (flip_y, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (flip_y, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             flip_y
         } else {
             Default::default()
         };
         let rotation: Card32 = if cond0 == DeviceControl::AbsCalib {
-            let (rotation, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (rotation, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             rotation
         } else {
             Default::default()
         };
         let button_threshold: Card32 = if cond0 == DeviceControl::AbsCalib {
-            let (button_threshold, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (button_threshold, sz): (Card32, usize) =
+                <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             button_threshold
         } else {
             Default::default()
         };
         let status: Card8 = if cond0 == DeviceControl::Core {
-            let (status, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (status, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             status
         } else {
             Default::default()
         };
         let iscore: Card8 = if cond0 == DeviceControl::Core {
-            let (iscore, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (iscore, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             iscore
         } else {
@@ -6582,7 +6647,7 @@ impl AsByteSequence for DeviceState {
         };
         index += 2;
         let enable: Card8 = if cond0 == DeviceControl::Enable {
-            let (enable, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (enable, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             enable
         } else {
@@ -6590,42 +6655,42 @@ impl AsByteSequence for DeviceState {
         };
         index += 3;
         let offset_x: Card32 = if cond0 == DeviceControl::AbsArea {
-            let (offset_x, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (offset_x, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             offset_x
         } else {
             Default::default()
         };
         let offset_y: Card32 = if cond0 == DeviceControl::AbsArea {
-            let (offset_y, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (offset_y, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             offset_y
         } else {
             Default::default()
         };
         let width: Card32 = if cond0 == DeviceControl::AbsArea {
-            let (width, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (width, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             width
         } else {
             Default::default()
         };
         let height: Card32 = if cond0 == DeviceControl::AbsArea {
-            let (height, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (height, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             height
         } else {
             Default::default()
         };
         let screen: Card32 = if cond0 == DeviceControl::AbsArea {
-            let (screen, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (screen, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             screen
         } else {
             Default::default()
         };
         let following: Card32 = if cond0 == DeviceControl::AbsArea {
-            let (following, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (following, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             following
         } else {
@@ -6725,15 +6790,15 @@ impl AsByteSequence for GetDeviceControlRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDeviceControlRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (control_id, sz): (DeviceControl, usize) =
-            <DeviceControl>::from_bytes(&bytes[index..])?;
+            <DeviceControl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -6760,6 +6825,7 @@ impl Request for GetDeviceControlRequest {
     const OPCODE: u8 = 34;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetDeviceControl";
     type Reply = GetDeviceControlReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -6789,18 +6855,18 @@ impl AsByteSequence for GetDeviceControlReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDeviceControlReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (status, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 23;
-        let (control, sz): (DeviceState, usize) = <DeviceState>::from_bytes(&bytes[index..])?;
+        let (control, sz): (DeviceState, usize) = <DeviceState>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetDeviceControlReply {
@@ -6852,17 +6918,17 @@ impl AsByteSequence for DeviceResolutionCtl {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceResolutionCtl from byte buffer");
         let (control_id, sz): (DeviceControl, usize) =
-            <DeviceControl>::from_bytes(&bytes[index..])?;
+            <DeviceControl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_valuator, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_valuator, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (resolution_values, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -6924,25 +6990,25 @@ impl AsByteSequence for DeviceAbsCalibCtl {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceAbsCalibCtl from byte buffer");
         let (control_id, sz): (DeviceControl, usize) =
-            <DeviceControl>::from_bytes(&bytes[index..])?;
+            <DeviceControl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (min_x, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (min_x, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_x, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (max_x, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (min_y, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (min_y, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_y, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (max_y, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flip_x, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (flip_x, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flip_y, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (flip_y, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (rotation, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (rotation, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (button_threshold, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (button_threshold, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DeviceAbsCalibCtl {
@@ -7005,21 +7071,21 @@ impl AsByteSequence for DeviceAbsAreaCtrl {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceAbsAreaCtrl from byte buffer");
         let (control_id, sz): (DeviceControl, usize) =
-            <DeviceControl>::from_bytes(&bytes[index..])?;
+            <DeviceControl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (offset_x, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset_x, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (offset_y, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset_y, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (screen, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (screen, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (following, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (following, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DeviceAbsAreaCtrl {
@@ -7069,11 +7135,11 @@ impl AsByteSequence for DeviceCoreCtrl {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceCoreCtrl from byte buffer");
         let (control_id, sz): (DeviceControl, usize) =
-            <DeviceControl>::from_bytes(&bytes[index..])?;
+            <DeviceControl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (status, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -7112,11 +7178,11 @@ impl AsByteSequence for DeviceEnableCtrl {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceEnableCtrl from byte buffer");
         let (control_id, sz): (DeviceControl, usize) =
-            <DeviceControl>::from_bytes(&bytes[index..])?;
+            <DeviceControl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (enable, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (enable, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -7203,6 +7269,7 @@ impl AsByteSequence for DeviceCtl {
         if cond0 == DeviceControl::Enable {
             index += self.enable.as_bytes(&mut bytes[index..]);
         }
+        index += 3;
         if cond0 == DeviceControl::AbsArea {
             index += self.offset_x.as_bytes(&mut bytes[index..]);
         }
@@ -7228,83 +7295,84 @@ impl AsByteSequence for DeviceCtl {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceCtl from byte buffer");
         let (control_id, sz): (DeviceControl, usize) =
-            <DeviceControl>::from_bytes(&bytes[index..])?;
+            <DeviceControl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let cond0 = (control_id);
         let first_valuator: Card8 = if cond0 == DeviceControl::Resolution {
-            let (first_valuator, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (first_valuator, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             first_valuator
         } else {
             Default::default()
         };
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (resolution_values, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let min_x: Int32 = if cond0 == DeviceControl::AbsCalib {
-            let (min_x, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (min_x, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             min_x
         } else {
             Default::default()
         };
         let max_x: Int32 = if cond0 == DeviceControl::AbsCalib {
-            let (max_x, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (max_x, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             max_x
         } else {
             Default::default()
         };
         let min_y: Int32 = if cond0 == DeviceControl::AbsCalib {
-            let (min_y, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (min_y, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             min_y
         } else {
             Default::default()
         };
         let max_y: Int32 = if cond0 == DeviceControl::AbsCalib {
-            let (max_y, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (max_y, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             max_y
         } else {
             Default::default()
         };
         let flip_x: Card32 = if cond0 == DeviceControl::AbsCalib {
-            let (flip_x, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (flip_x, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             flip_x
         } else {
             Default::default()
         };
         let flip_y: Card32 = if cond0 == DeviceControl::AbsCalib {
-            let (flip_y, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (flip_y, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             flip_y
         } else {
             Default::default()
         };
         let rotation: Card32 = if cond0 == DeviceControl::AbsCalib {
-            let (rotation, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (rotation, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             rotation
         } else {
             Default::default()
         };
         let button_threshold: Card32 = if cond0 == DeviceControl::AbsCalib {
-            let (button_threshold, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (button_threshold, sz): (Card32, usize) =
+                <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             button_threshold
         } else {
             Default::default()
         };
         let status: Card8 = if cond0 == DeviceControl::Core {
-            let (status, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (status, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             status
         } else {
@@ -7312,49 +7380,50 @@ impl AsByteSequence for DeviceCtl {
         };
         index += 3;
         let enable: Card8 = if cond0 == DeviceControl::Enable {
-            let (enable, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (enable, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             enable
         } else {
             Default::default()
         };
+        index += 3;
         let offset_x: Card32 = if cond0 == DeviceControl::AbsArea {
-            let (offset_x, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (offset_x, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             offset_x
         } else {
             Default::default()
         };
         let offset_y: Card32 = if cond0 == DeviceControl::AbsArea {
-            let (offset_y, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (offset_y, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             offset_y
         } else {
             Default::default()
         };
         let width: Int32 = if cond0 == DeviceControl::AbsArea {
-            let (width, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (width, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             width
         } else {
             Default::default()
         };
         let height: Int32 = if cond0 == DeviceControl::AbsArea {
-            let (height, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (height, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             height
         } else {
             Default::default()
         };
         let screen: Int32 = if cond0 == DeviceControl::AbsArea {
-            let (screen, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (screen, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             screen
         } else {
             Default::default()
         };
         let following: Card32 = if cond0 == DeviceControl::AbsArea {
-            let (following, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (following, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             following
         } else {
@@ -7409,6 +7478,7 @@ impl AsByteSequence for DeviceCtl {
             + self.status.size()
             + 3
             + self.enable.size()
+            + 3
             + self.offset_x.size()
             + self.offset_y.size()
             + self.width.size()
@@ -7443,18 +7513,18 @@ impl AsByteSequence for ChangeDeviceControlRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeDeviceControlRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (control_id, sz): (DeviceControl, usize) =
-            <DeviceControl>::from_bytes(&bytes[index..])?;
+            <DeviceControl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (control, sz): (DeviceCtl, usize) = <DeviceCtl>::from_bytes(&bytes[index..])?;
+        let (control, sz): (DeviceCtl, usize) = <DeviceCtl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ChangeDeviceControlRequest {
@@ -7482,6 +7552,7 @@ impl Request for ChangeDeviceControlRequest {
     const OPCODE: u8 = 35;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeDeviceControl";
     type Reply = ChangeDeviceControlReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -7509,15 +7580,15 @@ impl AsByteSequence for ChangeDeviceControlReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeDeviceControlReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (status, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 23;
         Some((
@@ -7563,12 +7634,12 @@ impl AsByteSequence for ListDevicePropertiesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListDevicePropertiesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -7589,6 +7660,7 @@ impl Request for ListDevicePropertiesRequest {
     const OPCODE: u8 = 36;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListDeviceProperties";
     type Reply = ListDevicePropertiesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -7619,19 +7691,19 @@ impl AsByteSequence for ListDevicePropertiesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListDevicePropertiesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
         let (atoms, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         Some((
@@ -7692,10 +7764,11 @@ impl AsByteSequence for ChangeDevicePropertyRequest {
         let block_len: usize = vector_as_bytes(&self.data8, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
+        index += buffer_pad(index, 4);
         let block_len: usize = vector_as_bytes(&self.data16, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        index += buffer_pad(index, 4);
         let block_len: usize = vector_as_bytes(&self.data32, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
@@ -7705,35 +7778,37 @@ impl AsByteSequence for ChangeDevicePropertyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeDevicePropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (PropertyFormat, usize) = <PropertyFormat>::from_bytes(&bytes[index..])?;
+        let (format, sz): (PropertyFormat, usize) =
+            <PropertyFormat>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (PropMode, usize) = <PropMode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (PropMode, usize) = <PropMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (num_items, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_items, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (data8, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], (num_items as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_items as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
+        index += buffer_pad(index, 4);
         let (data16, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (num_items as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_items as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        index += buffer_pad(index, 4);
         let (data32, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (num_items as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_items as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -7755,38 +7830,42 @@ impl AsByteSequence for ChangeDevicePropertyRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.property.size()
-            + self.ty.size()
-            + self.device_id.size()
-            + self.format.size()
-            + self.mode.size()
-            + 1
-            + self.num_items.size()
-            + {
-                let block_len: usize = self.data8.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
-            + 4
-            + {
+        ({
+            let size: usize = ({
+                let size: usize = self.req_type.size()
+                    + 1
+                    + self.length.size()
+                    + self.property.size()
+                    + self.ty.size()
+                    + self.device_id.size()
+                    + self.format.size()
+                    + self.mode.size()
+                    + 1
+                    + self.num_items.size()
+                    + {
+                        let block_len: usize = self.data8.iter().map(|i| i.size()).sum();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                        block_len + pad
+                    };
+                size + buffer_pad(size, 4)
+            }) + {
                 let block_len: usize = self.data16.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
                 block_len + pad
-            }
-            + {
-                let block_len: usize = self.data32.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-                block_len + pad
-            }
+            };
+            size + buffer_pad(size, 4)
+        }) + {
+            let block_len: usize = self.data32.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+            block_len + pad
+        }
     }
 }
 impl Request for ChangeDevicePropertyRequest {
     const OPCODE: u8 = 37;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeDeviceProperty";
     type Reply = ();
 }
 #[repr(u8)]
@@ -7846,14 +7925,14 @@ impl AsByteSequence for DeleteDevicePropertyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeleteDevicePropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -7880,6 +7959,7 @@ impl Request for DeleteDevicePropertyRequest {
     const OPCODE: u8 = 38;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DeleteDeviceProperty";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -7914,22 +7994,22 @@ impl AsByteSequence for GetDevicePropertyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDevicePropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (offset, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (delete, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (delete, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -7964,6 +8044,7 @@ impl Request for GetDevicePropertyRequest {
     const OPCODE: u8 = 39;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetDeviceProperty";
     type Reply = GetDevicePropertyReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -7999,10 +8080,11 @@ impl AsByteSequence for GetDevicePropertyReply {
         let block_len: usize = vector_as_bytes(&self.data8, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
+        index += buffer_pad(index, 4);
         let block_len: usize = vector_as_bytes(&self.data16, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        index += buffer_pad(index, 4);
         let block_len: usize = vector_as_bytes(&self.data32, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
@@ -8012,36 +8094,38 @@ impl AsByteSequence for GetDevicePropertyReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDevicePropertyReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xi_reply_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bytes_after, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bytes_after, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_items, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_items, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (PropertyFormat, usize) = <PropertyFormat>::from_bytes(&bytes[index..])?;
+        let (format, sz): (PropertyFormat, usize) =
+            <PropertyFormat>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 10;
         let (data8, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], (num_items as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_items as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
+        index += buffer_pad(index, 4);
         let (data16, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (num_items as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_items as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        index += buffer_pad(index, 4);
         let (data32, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (num_items as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_items as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -8064,32 +8148,35 @@ impl AsByteSequence for GetDevicePropertyReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.xi_reply_type.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.ty.size()
-            + self.bytes_after.size()
-            + self.num_items.size()
-            + self.format.size()
-            + self.device_id.size()
-            + 10
-            + {
-                let block_len: usize = self.data8.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
-            + 4
-            + {
+        ({
+            let size: usize = ({
+                let size: usize = self.reply_type.size()
+                    + self.xi_reply_type.size()
+                    + self.sequence.size()
+                    + self.length.size()
+                    + self.ty.size()
+                    + self.bytes_after.size()
+                    + self.num_items.size()
+                    + self.format.size()
+                    + self.device_id.size()
+                    + 10
+                    + {
+                        let block_len: usize = self.data8.iter().map(|i| i.size()).sum();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                        block_len + pad
+                    };
+                size + buffer_pad(size, 4)
+            }) + {
                 let block_len: usize = self.data16.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
                 block_len + pad
-            }
-            + {
-                let block_len: usize = self.data32.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-                block_len + pad
-            }
+            };
+            size + buffer_pad(size, 4)
+        }) + {
+            let block_len: usize = self.data32.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+            block_len + pad
+        }
     }
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -8114,13 +8201,13 @@ impl AsByteSequence for GroupInfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GroupInfo from byte buffer");
-        let (base, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (base, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (latched, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (latched, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (locked, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (locked, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (effective, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (effective, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GroupInfo {
@@ -8159,13 +8246,13 @@ impl AsByteSequence for ModifierInfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ModifierInfo from byte buffer");
-        let (base, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (base, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (latched, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (latched, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (locked, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (locked, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (effective, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (effective, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ModifierInfo {
@@ -8206,14 +8293,14 @@ impl AsByteSequence for XiQueryPointerRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiQueryPointerRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -8240,6 +8327,7 @@ impl Request for XiQueryPointerRequest {
     const OPCODE: u8 = 40;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "XiQueryPointer";
     type Reply = XiQueryPointerReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -8274,6 +8362,7 @@ impl AsByteSequence for XiQueryPointerReply {
         index += self.win_x.as_bytes(&mut bytes[index..]);
         index += self.win_y.as_bytes(&mut bytes[index..]);
         index += self.same_screen.as_bytes(&mut bytes[index..]);
+        index += 1;
         index += (self.buttons.len() as Card16).as_bytes(&mut bytes[index..]);
         index += self.mods.as_bytes(&mut bytes[index..]);
         index += self.group.as_bytes(&mut bytes[index..]);
@@ -8286,35 +8375,36 @@ impl AsByteSequence for XiQueryPointerReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiQueryPointerReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (win_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (win_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (win_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (win_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        index += 1;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(&bytes[index..])?;
+        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(&bytes[index..])?;
+        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (buttons, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -8349,6 +8439,7 @@ impl AsByteSequence for XiQueryPointerReply {
             + self.win_x.size()
             + self.win_y.size()
             + self.same_screen.size()
+            + 1
             + ::core::mem::size_of::<Card16>()
             + self.mods.size()
             + self.group.size()
@@ -8428,28 +8519,28 @@ impl AsByteSequence for XiWarpPointerRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiWarpPointerRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_win, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (src_win, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst_win, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (dst_win, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (src_width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (src_height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (dst_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (dst_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -8490,6 +8581,7 @@ impl Request for XiWarpPointerRequest {
     const OPCODE: u8 = 41;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "XiWarpPointer";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -8518,16 +8610,16 @@ impl AsByteSequence for XiChangeCursorRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiChangeCursorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -8556,6 +8648,7 @@ impl Request for XiChangeCursorRequest {
     const OPCODE: u8 = 42;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "XiChangeCursor";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -8586,17 +8679,18 @@ impl AsByteSequence for AddMaster {
         let mut index: usize = 0;
         log::trace!("Deserializing AddMaster from byte buffer");
         let (ty, sz): (HierarchyChangeType, usize) =
-            <HierarchyChangeType>::from_bytes(&bytes[index..])?;
+            <HierarchyChangeType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (send_core, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (send_core, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (enable, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (enable, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) =
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, 4);
         Some((
@@ -8687,18 +8781,18 @@ impl AsByteSequence for RemoveMaster {
         let mut index: usize = 0;
         log::trace!("Deserializing RemoveMaster from byte buffer");
         let (ty, sz): (HierarchyChangeType, usize) =
-            <HierarchyChangeType>::from_bytes(&bytes[index..])?;
+            <HierarchyChangeType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (return_mode, sz): (ChangeMode, usize) = <ChangeMode>::from_bytes(&bytes[index..])?;
+        let (return_mode, sz): (ChangeMode, usize) = <ChangeMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (return_pointer, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (return_pointer, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (return_keyboard, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (return_keyboard, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             RemoveMaster {
@@ -8777,13 +8871,13 @@ impl AsByteSequence for AttachSlave {
         let mut index: usize = 0;
         log::trace!("Deserializing AttachSlave from byte buffer");
         let (ty, sz): (HierarchyChangeType, usize) =
-            <HierarchyChangeType>::from_bytes(&bytes[index..])?;
+            <HierarchyChangeType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (master, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (master, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             AttachSlave {
@@ -8822,11 +8916,11 @@ impl AsByteSequence for DetachSlave {
         let mut index: usize = 0;
         log::trace!("Deserializing DetachSlave from byte buffer");
         let (ty, sz): (HierarchyChangeType, usize) =
-            <HierarchyChangeType>::from_bytes(&bytes[index..])?;
+            <HierarchyChangeType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -8876,7 +8970,7 @@ impl AsByteSequence for HierarchyChange {
         let block_len: usize = string_as_bytes(&self.name, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        index += 4;
+        index += buffer_pad(index, 4);
         if cond0 == HierarchyChangeType::RemoveMaster {
             index += self.deviceid.as_bytes(&mut bytes[index..]);
         }
@@ -8907,40 +9001,42 @@ impl AsByteSequence for HierarchyChange {
         let mut index: usize = 0;
         log::trace!("Deserializing HierarchyChange from byte buffer");
         let (ty, sz): (HierarchyChangeType, usize) =
-            <HierarchyChangeType>::from_bytes(&bytes[index..])?;
+            <HierarchyChangeType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let cond0 = (ty);
         let send_core: bool = if cond0 == HierarchyChangeType::AddMaster {
-            let (send_core, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+            let (send_core, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             send_core
         } else {
             Default::default()
         };
         let enable: bool = if cond0 == HierarchyChangeType::AddMaster {
-            let (enable, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+            let (enable, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             enable
         } else {
             Default::default()
         };
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) =
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-        index += 4;
+        index += buffer_pad(index, 4);
         let deviceid: DeviceId = if cond0 == HierarchyChangeType::RemoveMaster {
-            let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+            let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             deviceid
         } else {
             Default::default()
         };
         let return_mode: ChangeMode = if cond0 == HierarchyChangeType::RemoveMaster {
-            let (return_mode, sz): (ChangeMode, usize) = <ChangeMode>::from_bytes(&bytes[index..])?;
+            let (return_mode, sz): (ChangeMode, usize) =
+                <ChangeMode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             return_mode
         } else {
@@ -8948,35 +9044,37 @@ impl AsByteSequence for HierarchyChange {
         };
         index += 1;
         let return_pointer: DeviceId = if cond0 == HierarchyChangeType::RemoveMaster {
-            let (return_pointer, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+            let (return_pointer, sz): (DeviceId, usize) =
+                <DeviceId>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             return_pointer
         } else {
             Default::default()
         };
         let return_keyboard: DeviceId = if cond0 == HierarchyChangeType::RemoveMaster {
-            let (return_keyboard, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+            let (return_keyboard, sz): (DeviceId, usize) =
+                <DeviceId>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             return_keyboard
         } else {
             Default::default()
         };
         let deviceid_: DeviceId = if cond0 == HierarchyChangeType::AttachSlave {
-            let (deviceid_, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+            let (deviceid_, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             deviceid_
         } else {
             Default::default()
         };
         let master: DeviceId = if cond0 == HierarchyChangeType::AttachSlave {
-            let (master, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+            let (master, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             master
         } else {
             Default::default()
         };
         let deviceid__: DeviceId = if cond0 == HierarchyChangeType::DetachSlave {
-            let (deviceid__, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+            let (deviceid__, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             deviceid__
         } else {
@@ -9003,18 +9101,19 @@ impl AsByteSequence for HierarchyChange {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.ty.size()
-            + self.len.size()
-            + ::core::mem::size_of::<Card16>()
-            + self.send_core.size()
-            + self.enable.size()
-            + {
-                let block_len: usize = self.name.len();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
-                block_len + pad
-            }
-            + 4
-            + self.deviceid.size()
+        ({
+            let size: usize = self.ty.size()
+                + self.len.size()
+                + ::core::mem::size_of::<Card16>()
+                + self.send_core.size()
+                + self.enable.size()
+                + {
+                    let block_len: usize = self.name.len();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<c_char>());
+                    block_len + pad
+                };
+            size + buffer_pad(size, 4)
+        }) + self.deviceid.size()
             + self.return_mode.size()
             + 1
             + self.return_pointer.size()
@@ -9050,16 +9149,16 @@ impl AsByteSequence for XiChangeHierarchyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiChangeHierarchyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         let (changes, block_len): (Vec<HierarchyChange>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<HierarchyChange>());
         Some((
@@ -9084,6 +9183,7 @@ impl Request for XiChangeHierarchyRequest {
     const OPCODE: u8 = 43;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "XiChangeHierarchy";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -9110,14 +9210,14 @@ impl AsByteSequence for XiSetClientPointerRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiSetClientPointerRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -9144,6 +9244,7 @@ impl Request for XiSetClientPointerRequest {
     const OPCODE: u8 = 44;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "XiSetClientPointer";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -9167,12 +9268,12 @@ impl AsByteSequence for XiGetClientPointerRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiGetClientPointerRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             XiGetClientPointerRequest {
@@ -9192,6 +9293,7 @@ impl Request for XiGetClientPointerRequest {
     const OPCODE: u8 = 45;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "XiGetClientPointer";
     type Reply = XiGetClientPointerReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -9212,6 +9314,7 @@ impl AsByteSequence for XiGetClientPointerReply {
         index += self.sequence.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.set.as_bytes(&mut bytes[index..]);
+        index += 1;
         index += self.deviceid.as_bytes(&mut bytes[index..]);
         index += 20;
         index
@@ -9220,16 +9323,17 @@ impl AsByteSequence for XiGetClientPointerReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiGetClientPointerReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (set, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (set, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        index += 1;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         Some((
@@ -9250,6 +9354,7 @@ impl AsByteSequence for XiGetClientPointerReply {
             + self.sequence.size()
             + self.length.size()
             + self.set.size()
+            + 1
             + self.deviceid.size()
             + 20
     }
@@ -9275,12 +9380,12 @@ impl AsByteSequence for EventMask {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing EventMask from byte buffer");
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -9327,18 +9432,18 @@ impl AsByteSequence for XiSelectEventsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiSelectEventsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (masks, block_len): (Vec<EventMask>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<EventMask>());
         Some((
@@ -9370,6 +9475,7 @@ impl Request for XiSelectEventsRequest {
     const OPCODE: u8 = 46;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "XiSelectEvents";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -9395,14 +9501,14 @@ impl AsByteSequence for XiQueryVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiQueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             XiQueryVersionRequest {
@@ -9427,6 +9533,7 @@ impl Request for XiQueryVersionRequest {
     const OPCODE: u8 = 47;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "XiQueryVersion";
     type Reply = XiQueryVersionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -9455,16 +9562,16 @@ impl AsByteSequence for XiQueryVersionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiQueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         Some((
@@ -9519,22 +9626,23 @@ impl AsByteSequence for ButtonClass {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ButtonClass from byte buffer");
-        let (ty, sz): (DeviceClassType, usize) = <DeviceClassType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (DeviceClassType, usize) =
+            <DeviceClassType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_buttons, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (num_buttons, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (state, block_len): (Vec<Card32>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (((num_buttons as usize) + (31)) / (32)) as usize,
         )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (labels, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], (num_buttons as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_buttons as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         Some((
@@ -9629,16 +9737,17 @@ impl AsByteSequence for KeyClass {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing KeyClass from byte buffer");
-        let (ty, sz): (DeviceClassType, usize) = <DeviceClassType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (DeviceClassType, usize) =
+            <DeviceClassType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (keys, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -9693,20 +9802,21 @@ impl AsByteSequence for ScrollClass {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ScrollClass from byte buffer");
-        let (ty, sz): (DeviceClassType, usize) = <DeviceClassType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (DeviceClassType, usize) =
+            <DeviceClassType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (number, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (number, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (scroll_type, sz): (ScrollType, usize) = <ScrollType>::from_bytes(&bytes[index..])?;
+        let (scroll_type, sz): (ScrollType, usize) = <ScrollType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (flags, sz): (ScrollFlags, usize) = <ScrollFlags>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (ScrollFlags, usize) = <ScrollFlags>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (increment, sz): (Fp3232, usize) = <Fp3232>::from_bytes(&bytes[index..])?;
+        let (increment, sz): (Fp3232, usize) = <Fp3232>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ScrollClass {
@@ -9888,15 +9998,16 @@ impl AsByteSequence for TouchClass {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing TouchClass from byte buffer");
-        let (ty, sz): (DeviceClassType, usize) = <DeviceClassType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (DeviceClassType, usize) =
+            <DeviceClassType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (TouchMode, usize) = <TouchMode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (TouchMode, usize) = <TouchMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_touches, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (num_touches, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             TouchClass {
@@ -9984,25 +10095,26 @@ impl AsByteSequence for ValuatorClass {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ValuatorClass from byte buffer");
-        let (ty, sz): (DeviceClassType, usize) = <DeviceClassType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (DeviceClassType, usize) =
+            <DeviceClassType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (number, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (number, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (label, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (label, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (min, sz): (Fp3232, usize) = <Fp3232>::from_bytes(&bytes[index..])?;
+        let (min, sz): (Fp3232, usize) = <Fp3232>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max, sz): (Fp3232, usize) = <Fp3232>::from_bytes(&bytes[index..])?;
+        let (max, sz): (Fp3232, usize) = <Fp3232>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value, sz): (Fp3232, usize) = <Fp3232>::from_bytes(&bytes[index..])?;
+        let (value, sz): (Fp3232, usize) = <Fp3232>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (resolution, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (resolution, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (ValuatorMode, usize) = <ValuatorMode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (ValuatorMode, usize) = <ValuatorMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -10128,80 +10240,82 @@ impl AsByteSequence for DeviceClass {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceClass from byte buffer");
-        let (ty, sz): (DeviceClassType, usize) = <DeviceClassType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (DeviceClassType, usize) =
+            <DeviceClassType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (keys, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let cond0 = (ty);
         let num_buttons: Card16 = if cond0 == DeviceClassType::Button {
-            let (num_buttons, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (num_buttons, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             num_buttons
         } else {
             Default::default()
         };
         let (state, block_len): (Vec<Card32>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (((num_buttons as usize) + (31)) / (32)) as usize,
         )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (labels, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], (num_buttons as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_buttons as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         let number: Card16 = if cond0 == DeviceClassType::Valuator {
-            let (number, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (number, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             number
         } else {
             Default::default()
         };
         let label: Atom = if cond0 == DeviceClassType::Valuator {
-            let (label, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (label, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             label
         } else {
             Default::default()
         };
         let min: Fp3232 = if cond0 == DeviceClassType::Valuator {
-            let (min, sz): (Fp3232, usize) = <Fp3232>::from_bytes(&bytes[index..])?;
+            let (min, sz): (Fp3232, usize) = <Fp3232>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             min
         } else {
             Default::default()
         };
         let max: Fp3232 = if cond0 == DeviceClassType::Valuator {
-            let (max, sz): (Fp3232, usize) = <Fp3232>::from_bytes(&bytes[index..])?;
+            let (max, sz): (Fp3232, usize) = <Fp3232>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             max
         } else {
             Default::default()
         };
         let value: Fp3232 = if cond0 == DeviceClassType::Valuator {
-            let (value, sz): (Fp3232, usize) = <Fp3232>::from_bytes(&bytes[index..])?;
+            let (value, sz): (Fp3232, usize) = <Fp3232>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             value
         } else {
             Default::default()
         };
         let resolution: Card32 = if cond0 == DeviceClassType::Valuator {
-            let (resolution, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (resolution, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             resolution
         } else {
             Default::default()
         };
         let mode: ValuatorMode = if cond0 == DeviceClassType::Valuator {
-            let (mode, sz): (ValuatorMode, usize) = <ValuatorMode>::from_bytes(&bytes[index..])?;
+            let (mode, sz): (ValuatorMode, usize) =
+                <ValuatorMode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             mode
         } else {
@@ -10209,14 +10323,15 @@ impl AsByteSequence for DeviceClass {
         };
         index += 3;
         let number_: Card16 = if cond0 == DeviceClassType::Scroll {
-            let (number_, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (number_, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             number_
         } else {
             Default::default()
         };
         let scroll_type: ScrollType = if cond0 == DeviceClassType::Scroll {
-            let (scroll_type, sz): (ScrollType, usize) = <ScrollType>::from_bytes(&bytes[index..])?;
+            let (scroll_type, sz): (ScrollType, usize) =
+                <ScrollType>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             scroll_type
         } else {
@@ -10224,28 +10339,28 @@ impl AsByteSequence for DeviceClass {
         };
         index += 2;
         let flags: ScrollFlags = if cond0 == DeviceClassType::Scroll {
-            let (flags, sz): (ScrollFlags, usize) = <ScrollFlags>::from_bytes(&bytes[index..])?;
+            let (flags, sz): (ScrollFlags, usize) = <ScrollFlags>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             flags
         } else {
             Default::default()
         };
         let increment: Fp3232 = if cond0 == DeviceClassType::Scroll {
-            let (increment, sz): (Fp3232, usize) = <Fp3232>::from_bytes(&bytes[index..])?;
+            let (increment, sz): (Fp3232, usize) = <Fp3232>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             increment
         } else {
             Default::default()
         };
         let mode_: TouchMode = if cond0 == DeviceClassType::Touch {
-            let (mode_, sz): (TouchMode, usize) = <TouchMode>::from_bytes(&bytes[index..])?;
+            let (mode_, sz): (TouchMode, usize) = <TouchMode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             mode_
         } else {
             Default::default()
         };
         let num_touches: Card8 = if cond0 == DeviceClassType::Touch {
-            let (num_touches, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (num_touches, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             num_touches
         } else {
@@ -10349,24 +10464,25 @@ impl AsByteSequence for XiDeviceInfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiDeviceInfo from byte buffer");
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (DeviceType, usize) = <DeviceType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (DeviceType, usize) = <DeviceType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (attachment, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (attachment, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (enabled, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (enabled, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len1 as usize)?;
+        let (name, block_len): (String, usize) =
+            string_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, 4);
         let (classes, block_len): (Vec<DeviceClass>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<DeviceClass>());
         Some((
@@ -10461,12 +10577,12 @@ impl AsByteSequence for XiQueryDeviceRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiQueryDeviceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -10487,6 +10603,7 @@ impl Request for XiQueryDeviceRequest {
     const OPCODE: u8 = 48;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "XiQueryDevice";
     type Reply = XiQueryDeviceReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -10516,18 +10633,18 @@ impl AsByteSequence for XiQueryDeviceReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiQueryDeviceReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
         let (infos, block_len): (Vec<XiDeviceInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<XiDeviceInfo>());
         Some((
@@ -10581,16 +10698,16 @@ impl AsByteSequence for XiSetFocusRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiSetFocusRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -10619,6 +10736,7 @@ impl Request for XiSetFocusRequest {
     const OPCODE: u8 = 49;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "XiSetFocus";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -10643,12 +10761,12 @@ impl AsByteSequence for XiGetFocusRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiGetFocusRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -10669,6 +10787,7 @@ impl Request for XiGetFocusRequest {
     const OPCODE: u8 = 50;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "XiGetFocus";
     type Reply = XiGetFocusReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -10695,14 +10814,14 @@ impl AsByteSequence for XiGetFocusReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiGetFocusReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (focus, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (focus, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         Some((
@@ -10764,30 +10883,31 @@ impl AsByteSequence for XiGrabDeviceRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiGrabDeviceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (paired_device_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(&bytes[index..])?;
+        let (paired_device_mode, sz): (GrabMode, usize) =
+            <GrabMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (owner_events, sz): (GrabOwner, usize) = <GrabOwner>::from_bytes(&bytes[index..])?;
+        let (owner_events, sz): (GrabOwner, usize) = <GrabOwner>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -10831,6 +10951,7 @@ impl Request for XiGrabDeviceRequest {
     const OPCODE: u8 = 51;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "XiGrabDevice";
     type Reply = XiGrabDeviceReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -10857,14 +10978,14 @@ impl AsByteSequence for XiGrabDeviceReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiGrabDeviceReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (GrabStatus, usize) = <GrabStatus>::from_bytes(&bytes[index..])?;
+        let (status, sz): (GrabStatus, usize) = <GrabStatus>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 23;
         Some((
@@ -10942,14 +11063,14 @@ impl AsByteSequence for XiUngrabDeviceRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiUngrabDeviceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -10971,6 +11092,7 @@ impl Request for XiUngrabDeviceRequest {
     const OPCODE: u8 = 52;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "XiUngrabDevice";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -11003,21 +11125,21 @@ impl AsByteSequence for XiAllowEventsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiAllowEventsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_mode, sz): (EventMode, usize) = <EventMode>::from_bytes(&bytes[index..])?;
+        let (event_mode, sz): (EventMode, usize) = <EventMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (touchid, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (touchid, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             XiAllowEventsRequest {
@@ -11049,6 +11171,7 @@ impl Request for XiAllowEventsRequest {
     const OPCODE: u8 = 53;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "XiAllowEvents";
     type Reply = ();
 }
 #[repr(u8)]
@@ -11113,9 +11236,9 @@ impl AsByteSequence for GrabModifierInfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GrabModifierInfo from byte buffer");
-        let (modifiers, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (modifiers, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (GrabStatus, usize) = <GrabStatus>::from_bytes(&bytes[index..])?;
+        let (status, sz): (GrabStatus, usize) = <GrabStatus>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -11262,40 +11385,41 @@ impl AsByteSequence for XiPassiveGrabDeviceRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiPassiveGrabDeviceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (grab_type, sz): (GrabType, usize) = <GrabType>::from_bytes(&bytes[index..])?;
+        let (grab_type, sz): (GrabType, usize) = <GrabType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (grab_mode, sz): (GrabMode22, usize) = <GrabMode22>::from_bytes(&bytes[index..])?;
+        let (grab_mode, sz): (GrabMode22, usize) = <GrabMode22>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (paired_device_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(&bytes[index..])?;
+        let (paired_device_mode, sz): (GrabMode, usize) =
+            <GrabMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (owner_events, sz): (GrabOwner, usize) = <GrabOwner>::from_bytes(&bytes[index..])?;
+        let (owner_events, sz): (GrabOwner, usize) = <GrabOwner>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (modifiers, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -11350,6 +11474,7 @@ impl Request for XiPassiveGrabDeviceRequest {
     const OPCODE: u8 = 54;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "XiPassiveGrabDevice";
     type Reply = XiPassiveGrabDeviceReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -11379,18 +11504,18 @@ impl AsByteSequence for XiPassiveGrabDeviceReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiPassiveGrabDeviceReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
         let (modifiers, block_len): (Vec<GrabModifierInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<GrabModifierInfo>());
         Some((
@@ -11521,24 +11646,24 @@ impl AsByteSequence for XiPassiveUngrabDeviceRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiPassiveUngrabDeviceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (grab_type, sz): (GrabType, usize) = <GrabType>::from_bytes(&bytes[index..])?;
+        let (grab_type, sz): (GrabType, usize) = <GrabType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         let (modifiers, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -11576,6 +11701,7 @@ impl Request for XiPassiveUngrabDeviceRequest {
     const OPCODE: u8 = 55;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "XiPassiveUngrabDevice";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -11600,12 +11726,12 @@ impl AsByteSequence for XiListPropertiesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiListPropertiesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -11626,6 +11752,7 @@ impl Request for XiListPropertiesRequest {
     const OPCODE: u8 = 56;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "XiListProperties";
     type Reply = XiListPropertiesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -11655,18 +11782,18 @@ impl AsByteSequence for XiListPropertiesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiListPropertiesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
         let (properties, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         Some((
@@ -11725,10 +11852,11 @@ impl AsByteSequence for XiChangePropertyRequest {
         let block_len: usize = vector_as_bytes(&self.data8, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
+        index += buffer_pad(index, 4);
         let block_len: usize = vector_as_bytes(&self.data16, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        index += buffer_pad(index, 4);
         let block_len: usize = vector_as_bytes(&self.data32, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
@@ -11738,34 +11866,36 @@ impl AsByteSequence for XiChangePropertyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiChangePropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (PropMode, usize) = <PropMode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (PropMode, usize) = <PropMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (PropertyFormat, usize) = <PropertyFormat>::from_bytes(&bytes[index..])?;
+        let (format, sz): (PropertyFormat, usize) =
+            <PropertyFormat>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_items, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_items, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (data8, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], (num_items as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_items as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
+        index += buffer_pad(index, 4);
         let (data16, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (num_items as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_items as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        index += buffer_pad(index, 4);
         let (data32, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (num_items as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_items as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -11787,37 +11917,41 @@ impl AsByteSequence for XiChangePropertyRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.deviceid.size()
-            + self.mode.size()
-            + self.format.size()
-            + self.property.size()
-            + self.ty.size()
-            + self.num_items.size()
-            + {
-                let block_len: usize = self.data8.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
-            + 4
-            + {
+        ({
+            let size: usize = ({
+                let size: usize = self.req_type.size()
+                    + 1
+                    + self.length.size()
+                    + self.deviceid.size()
+                    + self.mode.size()
+                    + self.format.size()
+                    + self.property.size()
+                    + self.ty.size()
+                    + self.num_items.size()
+                    + {
+                        let block_len: usize = self.data8.iter().map(|i| i.size()).sum();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                        block_len + pad
+                    };
+                size + buffer_pad(size, 4)
+            }) + {
                 let block_len: usize = self.data16.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
                 block_len + pad
-            }
-            + {
-                let block_len: usize = self.data32.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-                block_len + pad
-            }
+            };
+            size + buffer_pad(size, 4)
+        }) + {
+            let block_len: usize = self.data32.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+            block_len + pad
+        }
     }
 }
 impl Request for XiChangePropertyRequest {
     const OPCODE: u8 = 57;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "XiChangeProperty";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -11844,15 +11978,15 @@ impl AsByteSequence for XiDeletePropertyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiDeletePropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             XiDeletePropertyRequest {
@@ -11878,6 +12012,7 @@ impl Request for XiDeletePropertyRequest {
     const OPCODE: u8 = 58;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "XiDeleteProperty";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -11912,23 +12047,23 @@ impl AsByteSequence for XiGetPropertyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiGetPropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (delete, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (delete, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (offset, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             XiGetPropertyRequest {
@@ -11962,6 +12097,7 @@ impl Request for XiGetPropertyRequest {
     const OPCODE: u8 = 59;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "XiGetProperty";
     type Reply = XiGetPropertyReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -11994,10 +12130,11 @@ impl AsByteSequence for XiGetPropertyReply {
         let block_len: usize = vector_as_bytes(&self.data8, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
+        index += buffer_pad(index, 4);
         let block_len: usize = vector_as_bytes(&self.data16, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        index += buffer_pad(index, 4);
         let block_len: usize = vector_as_bytes(&self.data32, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
@@ -12007,33 +12144,35 @@ impl AsByteSequence for XiGetPropertyReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiGetPropertyReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bytes_after, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bytes_after, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_items, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_items, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (PropertyFormat, usize) = <PropertyFormat>::from_bytes(&bytes[index..])?;
+        let (format, sz): (PropertyFormat, usize) =
+            <PropertyFormat>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 11;
         let (data8, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], (num_items as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_items as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
+        index += buffer_pad(index, 4);
         let (data16, block_len): (Vec<Card16>, usize) =
-            vector_from_bytes(&bytes[index..], (num_items as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_items as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card16>());
+        index += buffer_pad(index, 4);
         let (data32, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (num_items as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_items as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -12054,31 +12193,34 @@ impl AsByteSequence for XiGetPropertyReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + 1
-            + self.sequence.size()
-            + self.length.size()
-            + self.ty.size()
-            + self.bytes_after.size()
-            + self.num_items.size()
-            + self.format.size()
-            + 11
-            + {
-                let block_len: usize = self.data8.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
-            + 4
-            + {
+        ({
+            let size: usize = ({
+                let size: usize = self.reply_type.size()
+                    + 1
+                    + self.sequence.size()
+                    + self.length.size()
+                    + self.ty.size()
+                    + self.bytes_after.size()
+                    + self.num_items.size()
+                    + self.format.size()
+                    + 11
+                    + {
+                        let block_len: usize = self.data8.iter().map(|i| i.size()).sum();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                        block_len + pad
+                    };
+                size + buffer_pad(size, 4)
+            }) + {
                 let block_len: usize = self.data16.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card16>());
                 block_len + pad
-            }
-            + {
-                let block_len: usize = self.data32.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
-                block_len + pad
-            }
+            };
+            size + buffer_pad(size, 4)
+        }) + {
+            let block_len: usize = self.data32.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card32>());
+            block_len + pad
+        }
     }
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -12102,12 +12244,12 @@ impl AsByteSequence for XiGetSelectedEventsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiGetSelectedEventsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             XiGetSelectedEventsRequest {
@@ -12127,6 +12269,7 @@ impl Request for XiGetSelectedEventsRequest {
     const OPCODE: u8 = 60;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "XiGetSelectedEvents";
     type Reply = XiGetSelectedEventsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -12156,18 +12299,18 @@ impl AsByteSequence for XiGetSelectedEventsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiGetSelectedEventsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
         let (masks, block_len): (Vec<EventMask>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<EventMask>());
         Some((
@@ -12216,12 +12359,12 @@ impl AsByteSequence for BarrierReleasePointerInfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing BarrierReleasePointerInfo from byte buffer");
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (barrier, sz): (Barrier, usize) = <Barrier>::from_bytes(&bytes[index..])?;
+        let (barrier, sz): (Barrier, usize) = <Barrier>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (eventid, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (eventid, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             BarrierReleasePointerInfo {
@@ -12264,15 +12407,15 @@ impl AsByteSequence for XiBarrierReleasePointerRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing XiBarrierReleasePointerRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (barriers, block_len): (Vec<BarrierReleasePointerInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(
             block_len,
@@ -12303,6 +12446,7 @@ impl Request for XiBarrierReleasePointerRequest {
     const OPCODE: u8 = 61;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "XiBarrierReleasePointer";
     type Reply = ();
 }
 #[repr(transparent)]
@@ -12744,7 +12888,7 @@ impl AsByteSequence for HierarchyInfo {
         let mut index: usize = 0;
         index += self.deviceid.as_bytes(&mut bytes[index..]);
         index += self.attachment.as_bytes(&mut bytes[index..]);
-        index += self.ty.as_bytes(&mut bytes[index..]);
+        index += as_bytes_with_width(&self.ty, 1, &mut bytes[index..]);
         index += self.enabled.as_bytes(&mut bytes[index..]);
         index += 2;
         index += self.flags.as_bytes(&mut bytes[index..]);
@@ -12754,16 +12898,16 @@ impl AsByteSequence for HierarchyInfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing HierarchyInfo from byte buffer");
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (attachment, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (attachment, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (DeviceType, usize) = <DeviceType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (DeviceType, usize) = from_bytes_with_width(bytes.get(index..)?, 1)?;
         index += sz;
-        let (enabled, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (enabled, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (flags, sz): (HierarchyMask, usize) = <HierarchyMask>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (HierarchyMask, usize) = <HierarchyMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             HierarchyInfo {
@@ -12780,7 +12924,7 @@ impl AsByteSequence for HierarchyInfo {
     fn size(&self) -> usize {
         self.deviceid.size()
             + self.attachment.size()
-            + self.ty.size()
+            + 1
             + self.enabled.size()
             + 2
             + self.flags.size()
@@ -13295,28 +13439,28 @@ impl AsByteSequence for SendExtensionEventRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SendExtensionEventRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (destination, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (destination, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (propagate, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (propagate, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         let (events, block_len): (Vec<EventForSend>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<EventForSend>());
         let (classes, block_len): (Vec<EventClass>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<EventClass>());
         Some((
@@ -13359,6 +13503,7 @@ impl Request for SendExtensionEventRequest {
     const OPCODE: u8 = 31;
     const EXTENSION: Option<&'static str> = Some("XInputExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SendExtensionEvent";
     type Reply = ();
 }
 #[repr(transparent)]
@@ -14106,36 +14251,36 @@ impl AsByteSequence for BarrierHitEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing BarrierHitEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (eventid, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (eventid, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (barrier, sz): (Barrier, usize) = <Barrier>::from_bytes(&bytes[index..])?;
+        let (barrier, sz): (Barrier, usize) = <Barrier>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dtime, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (dtime, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (BarrierFlags, usize) = <BarrierFlags>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (BarrierFlags, usize) = <BarrierFlags>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dx, sz): (Fp3232, usize) = <Fp3232>::from_bytes(&bytes[index..])?;
+        let (dx, sz): (Fp3232, usize) = <Fp3232>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dy, sz): (Fp3232, usize) = <Fp3232>::from_bytes(&bytes[index..])?;
+        let (dy, sz): (Fp3232, usize) = <Fp3232>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             BarrierHitEvent {
@@ -14226,36 +14371,36 @@ impl AsByteSequence for BarrierLeaveEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing BarrierLeaveEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (eventid, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (eventid, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (barrier, sz): (Barrier, usize) = <Barrier>::from_bytes(&bytes[index..])?;
+        let (barrier, sz): (Barrier, usize) = <Barrier>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dtime, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (dtime, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (BarrierFlags, usize) = <BarrierFlags>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (BarrierFlags, usize) = <BarrierFlags>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dx, sz): (Fp3232, usize) = <Fp3232>::from_bytes(&bytes[index..])?;
+        let (dx, sz): (Fp3232, usize) = <Fp3232>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dy, sz): (Fp3232, usize) = <Fp3232>::from_bytes(&bytes[index..])?;
+        let (dy, sz): (Fp3232, usize) = <Fp3232>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             BarrierLeaveEvent {
@@ -14362,54 +14507,54 @@ impl AsByteSequence for ButtonPressEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ButtonPressEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (flags, sz): (PointerEventFlags, usize) =
-            <PointerEventFlags>::from_bytes(&bytes[index..])?;
+            <PointerEventFlags>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(&bytes[index..])?;
+        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(&bytes[index..])?;
+        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (button_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (valuator_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (axisvalues, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
@@ -14547,54 +14692,54 @@ impl AsByteSequence for ButtonReleaseEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ButtonReleaseEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (flags, sz): (PointerEventFlags, usize) =
-            <PointerEventFlags>::from_bytes(&bytes[index..])?;
+            <PointerEventFlags>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(&bytes[index..])?;
+        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(&bytes[index..])?;
+        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (button_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (valuator_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (axisvalues, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
@@ -14696,15 +14841,15 @@ impl AsByteSequence for ChangeDeviceNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeDeviceNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Byte, usize) = <Byte>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (request, sz): (ChangeDevice, usize) = <ChangeDevice>::from_bytes(&bytes[index..])?;
+        let (request, sz): (ChangeDevice, usize) = <ChangeDevice>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 23;
         Some((
@@ -14773,33 +14918,33 @@ impl AsByteSequence for DeviceButtonPressEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceButtonPressEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Byte, usize) = <Byte>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(&bytes[index..])?;
+        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DeviceButtonPressEvent {
@@ -14884,33 +15029,33 @@ impl AsByteSequence for DeviceButtonReleaseEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceButtonReleaseEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Byte, usize) = <Byte>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(&bytes[index..])?;
+        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DeviceButtonReleaseEvent {
@@ -14975,13 +15120,13 @@ impl AsByteSequence for DeviceButtonStateNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceButtonStateNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Byte, usize) = <Byte>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (buttons, sz): ([Card8; 28], usize) = <[Card8; 28]>::from_bytes(&bytes[index..])?;
+        let (buttons, sz): ([Card8; 28], usize) = <[Card8; 28]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DeviceButtonStateNotifyEvent {
@@ -15033,23 +15178,23 @@ impl AsByteSequence for DeviceChangedEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceChangedEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (reason, sz): (ChangeReason, usize) = <ChangeReason>::from_bytes(&bytes[index..])?;
+        let (reason, sz): (ChangeReason, usize) = <ChangeReason>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 11;
         let (classes, block_len): (Vec<DeviceClass>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<DeviceClass>());
         Some((
@@ -15114,21 +15259,21 @@ impl AsByteSequence for DeviceFocusInEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceFocusInEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (detail, sz): (super::xproto::NotifyDetail, usize) =
-            <super::xproto::NotifyDetail>::from_bytes(&bytes[index..])?;
+            <super::xproto::NotifyDetail>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (mode, sz): (super::xproto::NotifyMode, usize) =
-            <super::xproto::NotifyMode>::from_bytes(&bytes[index..])?;
+            <super::xproto::NotifyMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 18;
         Some((
@@ -15188,21 +15333,21 @@ impl AsByteSequence for DeviceFocusOutEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceFocusOutEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (detail, sz): (super::xproto::NotifyDetail, usize) =
-            <super::xproto::NotifyDetail>::from_bytes(&bytes[index..])?;
+            <super::xproto::NotifyDetail>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (mode, sz): (super::xproto::NotifyMode, usize) =
-            <super::xproto::NotifyMode>::from_bytes(&bytes[index..])?;
+            <super::xproto::NotifyMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 18;
         Some((
@@ -15275,33 +15420,33 @@ impl AsByteSequence for DeviceKeyPressEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceKeyPressEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Byte, usize) = <Byte>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(&bytes[index..])?;
+        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DeviceKeyPressEvent {
@@ -15386,33 +15531,33 @@ impl AsByteSequence for DeviceKeyReleaseEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceKeyReleaseEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Byte, usize) = <Byte>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(&bytes[index..])?;
+        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DeviceKeyReleaseEvent {
@@ -15477,13 +15622,13 @@ impl AsByteSequence for DeviceKeyStateNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceKeyStateNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Byte, usize) = <Byte>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (keys, sz): ([Card8; 28], usize) = <[Card8; 28]>::from_bytes(&bytes[index..])?;
+        let (keys, sz): ([Card8; 28], usize) = <[Card8; 28]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DeviceKeyStateNotifyEvent {
@@ -15533,20 +15678,20 @@ impl AsByteSequence for DeviceMappingNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceMappingNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Byte, usize) = <Byte>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (request, sz): (Mapping, usize) = <Mapping>::from_bytes(&bytes[index..])?;
+        let (request, sz): (Mapping, usize) = <Mapping>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_keycode, sz): (KeyCode, usize) = <KeyCode>::from_bytes(&bytes[index..])?;
+        let (first_keycode, sz): (KeyCode, usize) = <KeyCode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (count, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (count, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         Some((
@@ -15620,33 +15765,33 @@ impl AsByteSequence for DeviceMotionNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceMotionNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Byte, usize) = <Byte>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(&bytes[index..])?;
+        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DeviceMotionNotifyEvent {
@@ -15717,18 +15862,19 @@ impl AsByteSequence for DevicePresenceNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DevicePresenceNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (devchange, sz): (DeviceChange, usize) = <DeviceChange>::from_bytes(&bytes[index..])?;
+        let (devchange, sz): (DeviceChange, usize) =
+            <DeviceChange>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Byte, usize) = <Byte>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (control, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (control, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         Some((
@@ -15785,18 +15931,18 @@ impl AsByteSequence for DevicePropertyNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DevicePropertyNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (Property, usize) = <Property>::from_bytes(&bytes[index..])?;
+        let (state, sz): (Property, usize) = <Property>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 19;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DevicePropertyNotifyEvent {
@@ -15860,28 +16006,28 @@ impl AsByteSequence for DeviceStateNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceStateNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Byte, usize) = <Byte>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (num_keys, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_buttons, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (num_buttons, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_valuators, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (num_valuators, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (classes_reported, sz): (ClassesReportedMask, usize) =
-            <ClassesReportedMask>::from_bytes(&bytes[index..])?;
+            <ClassesReportedMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (buttons, sz): ([Card8; 4], usize) = <[Card8; 4]>::from_bytes(&bytes[index..])?;
+        let (buttons, sz): ([Card8; 4], usize) = <[Card8; 4]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (keys, sz): ([Card8; 4], usize) = <[Card8; 4]>::from_bytes(&bytes[index..])?;
+        let (keys, sz): ([Card8; 4], usize) = <[Card8; 4]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (valuators, sz): ([Card32; 3], usize) = <[Card32; 3]>::from_bytes(&bytes[index..])?;
+        let (valuators, sz): ([Card32; 3], usize) = <[Card32; 3]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DeviceStateNotifyEvent {
@@ -15946,19 +16092,19 @@ impl AsByteSequence for DeviceValuatorEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceValuatorEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_state, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (device_state, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_valuators, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (num_valuators, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_valuator, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_valuator, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (valuators, sz): ([Int32; 6], usize) = <[Int32; 6]>::from_bytes(&bytes[index..])?;
+        let (valuators, sz): ([Int32; 6], usize) = <[Int32; 6]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DeviceValuatorEvent {
@@ -16042,48 +16188,48 @@ impl AsByteSequence for EnterEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing EnterEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (mode, sz): (super::xinput::NotifyMode, usize) =
-            <super::xinput::NotifyMode>::from_bytes(&bytes[index..])?;
+            <super::xinput::NotifyMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (detail, sz): (super::xinput::NotifyDetail, usize) =
-            <super::xinput::NotifyDetail>::from_bytes(&bytes[index..])?;
+            <super::xinput::NotifyDetail>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (focus, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (focus, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(&bytes[index..])?;
+        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(&bytes[index..])?;
+        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (buttons, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -16197,48 +16343,48 @@ impl AsByteSequence for FocusInEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FocusInEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (mode, sz): (super::xinput::NotifyMode, usize) =
-            <super::xinput::NotifyMode>::from_bytes(&bytes[index..])?;
+            <super::xinput::NotifyMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (detail, sz): (super::xinput::NotifyDetail, usize) =
-            <super::xinput::NotifyDetail>::from_bytes(&bytes[index..])?;
+            <super::xinput::NotifyDetail>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (focus, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (focus, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(&bytes[index..])?;
+        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(&bytes[index..])?;
+        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (buttons, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -16352,48 +16498,48 @@ impl AsByteSequence for FocusOutEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FocusOutEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (mode, sz): (super::xinput::NotifyMode, usize) =
-            <super::xinput::NotifyMode>::from_bytes(&bytes[index..])?;
+            <super::xinput::NotifyMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (detail, sz): (super::xinput::NotifyDetail, usize) =
-            <super::xinput::NotifyDetail>::from_bytes(&bytes[index..])?;
+            <super::xinput::NotifyDetail>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (focus, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (focus, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(&bytes[index..])?;
+        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(&bytes[index..])?;
+        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (buttons, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -16482,21 +16628,21 @@ impl AsByteSequence for HierarchyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing HierarchyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (HierarchyMask, usize) = <HierarchyMask>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (HierarchyMask, usize) = <HierarchyMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 10;
         let (infos, block_len): (Vec<HierarchyInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<HierarchyInfo>());
         Some((
@@ -16591,53 +16737,53 @@ impl AsByteSequence for KeyPressEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing KeyPressEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (flags, sz): (KeyEventFlags, usize) = <KeyEventFlags>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (KeyEventFlags, usize) = <KeyEventFlags>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(&bytes[index..])?;
+        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(&bytes[index..])?;
+        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (button_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (valuator_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (axisvalues, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
@@ -16775,53 +16921,53 @@ impl AsByteSequence for KeyReleaseEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing KeyReleaseEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (flags, sz): (KeyEventFlags, usize) = <KeyEventFlags>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (KeyEventFlags, usize) = <KeyEventFlags>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(&bytes[index..])?;
+        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(&bytes[index..])?;
+        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (button_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (valuator_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (axisvalues, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
@@ -16953,48 +17099,48 @@ impl AsByteSequence for LeaveEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing LeaveEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (mode, sz): (super::xinput::NotifyMode, usize) =
-            <super::xinput::NotifyMode>::from_bytes(&bytes[index..])?;
+            <super::xinput::NotifyMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (detail, sz): (super::xinput::NotifyDetail, usize) =
-            <super::xinput::NotifyDetail>::from_bytes(&bytes[index..])?;
+            <super::xinput::NotifyDetail>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (focus, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (focus, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(&bytes[index..])?;
+        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(&bytes[index..])?;
+        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (buttons, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -17114,54 +17260,54 @@ impl AsByteSequence for MotionEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing MotionEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (flags, sz): (PointerEventFlags, usize) =
-            <PointerEventFlags>::from_bytes(&bytes[index..])?;
+            <PointerEventFlags>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(&bytes[index..])?;
+        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(&bytes[index..])?;
+        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (button_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (valuator_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (axisvalues, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
@@ -17265,17 +17411,17 @@ impl AsByteSequence for PropertyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PropertyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (what, sz): (PropertyFlag, usize) = <PropertyFlag>::from_bytes(&bytes[index..])?;
+        let (what, sz): (PropertyFlag, usize) = <PropertyFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 11;
         Some((
@@ -17346,33 +17492,33 @@ impl AsByteSequence for ProximityInEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ProximityInEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Byte, usize) = <Byte>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(&bytes[index..])?;
+        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ProximityInEvent {
@@ -17457,33 +17603,33 @@ impl AsByteSequence for ProximityOutEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ProximityOutEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Byte, usize) = <Byte>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(&bytes[index..])?;
+        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ProximityOutEvent {
@@ -17568,30 +17714,30 @@ impl AsByteSequence for RawButtonPressEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing RawButtonPressEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (flags, sz): (PointerEventFlags, usize) =
-            <PointerEventFlags>::from_bytes(&bytes[index..])?;
+            <PointerEventFlags>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
         let (valuator_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (axisvalues, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
@@ -17603,7 +17749,7 @@ impl AsByteSequence for RawButtonPressEvent {
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fp3232>());
         let (axisvalues_raw, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
@@ -17703,30 +17849,30 @@ impl AsByteSequence for RawButtonReleaseEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing RawButtonReleaseEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (flags, sz): (PointerEventFlags, usize) =
-            <PointerEventFlags>::from_bytes(&bytes[index..])?;
+            <PointerEventFlags>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
         let (valuator_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (axisvalues, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
@@ -17738,7 +17884,7 @@ impl AsByteSequence for RawButtonReleaseEvent {
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fp3232>());
         let (axisvalues_raw, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
@@ -17838,29 +17984,29 @@ impl AsByteSequence for RawKeyPressEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing RawKeyPressEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (KeyEventFlags, usize) = <KeyEventFlags>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (KeyEventFlags, usize) = <KeyEventFlags>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
         let (valuator_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (axisvalues, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
@@ -17872,7 +18018,7 @@ impl AsByteSequence for RawKeyPressEvent {
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fp3232>());
         let (axisvalues_raw, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
@@ -17972,29 +18118,29 @@ impl AsByteSequence for RawKeyReleaseEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing RawKeyReleaseEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (KeyEventFlags, usize) = <KeyEventFlags>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (KeyEventFlags, usize) = <KeyEventFlags>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
         let (valuator_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (axisvalues, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
@@ -18006,7 +18152,7 @@ impl AsByteSequence for RawKeyReleaseEvent {
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fp3232>());
         let (axisvalues_raw, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
@@ -18106,30 +18252,30 @@ impl AsByteSequence for RawMotionEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing RawMotionEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (flags, sz): (PointerEventFlags, usize) =
-            <PointerEventFlags>::from_bytes(&bytes[index..])?;
+            <PointerEventFlags>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
         let (valuator_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (axisvalues, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
@@ -18141,7 +18287,7 @@ impl AsByteSequence for RawMotionEvent {
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fp3232>());
         let (axisvalues_raw, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
@@ -18241,29 +18387,30 @@ impl AsByteSequence for RawTouchBeginEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing RawTouchBeginEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (TouchEventFlags, usize) = <TouchEventFlags>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (TouchEventFlags, usize) =
+            <TouchEventFlags>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
         let (valuator_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (axisvalues, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
@@ -18275,7 +18422,7 @@ impl AsByteSequence for RawTouchBeginEvent {
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fp3232>());
         let (axisvalues_raw, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
@@ -18375,29 +18522,30 @@ impl AsByteSequence for RawTouchEndEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing RawTouchEndEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (TouchEventFlags, usize) = <TouchEventFlags>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (TouchEventFlags, usize) =
+            <TouchEventFlags>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
         let (valuator_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (axisvalues, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
@@ -18409,7 +18557,7 @@ impl AsByteSequence for RawTouchEndEvent {
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fp3232>());
         let (axisvalues_raw, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
@@ -18509,29 +18657,30 @@ impl AsByteSequence for RawTouchUpdateEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing RawTouchUpdateEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (TouchEventFlags, usize) = <TouchEventFlags>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (TouchEventFlags, usize) =
+            <TouchEventFlags>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
         let (valuator_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (axisvalues, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
@@ -18543,7 +18692,7 @@ impl AsByteSequence for RawTouchUpdateEvent {
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fp3232>());
         let (axisvalues_raw, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
@@ -18662,53 +18811,54 @@ impl AsByteSequence for TouchBeginEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing TouchBeginEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (flags, sz): (TouchEventFlags, usize) = <TouchEventFlags>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (TouchEventFlags, usize) =
+            <TouchEventFlags>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(&bytes[index..])?;
+        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(&bytes[index..])?;
+        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (button_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (valuator_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (axisvalues, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
@@ -18846,53 +18996,54 @@ impl AsByteSequence for TouchEndEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing TouchEndEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (flags, sz): (TouchEventFlags, usize) = <TouchEventFlags>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (TouchEventFlags, usize) =
+            <TouchEventFlags>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(&bytes[index..])?;
+        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(&bytes[index..])?;
+        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (button_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (valuator_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (axisvalues, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
@@ -19005,27 +19156,27 @@ impl AsByteSequence for TouchOwnershipEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing TouchOwnershipEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (touchid, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (touchid, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (flags, sz): (TouchOwnershipFlags, usize) =
-            <TouchOwnershipFlags>::from_bytes(&bytes[index..])?;
+            <TouchOwnershipFlags>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 8;
         Some((
@@ -19124,53 +19275,54 @@ impl AsByteSequence for TouchUpdateEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing TouchUpdateEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (deviceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Fp1616, usize) = <Fp1616>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(&bytes[index..])?;
+        let (sourceid, sz): (DeviceId, usize) = <DeviceId>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (flags, sz): (TouchEventFlags, usize) = <TouchEventFlags>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (TouchEventFlags, usize) =
+            <TouchEventFlags>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(&bytes[index..])?;
+        let (mods, sz): (ModifierInfo, usize) = <ModifierInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(&bytes[index..])?;
+        let (group, sz): (GroupInfo, usize) = <GroupInfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (button_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (valuator_mask, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (axisvalues, block_len): (Vec<Fp3232>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (valuator_mask
                 .iter()
                 .map(|a| {
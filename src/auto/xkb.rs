@@ -40,22 +40,23 @@ impl AsByteSequence for IndicatorMap {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing IndicatorMap from byte buffer");
-        let (flags, sz): (ImFlag, usize) = <ImFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (ImFlag, usize) = <ImFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (which_groups, sz): (ImGroupsWhich, usize) =
-            <ImGroupsWhich>::from_bytes(&bytes[index..])?;
+            <ImGroupsWhich>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (groups, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(&bytes[index..])?;
+        let (groups, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (which_mods, sz): (ImModsWhich, usize) = <ImModsWhich>::from_bytes(&bytes[index..])?;
+        let (which_mods, sz): (ImModsWhich, usize) =
+            <ImModsWhich>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vmods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (vmods, sz): (VMod, usize) = <VMod>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ctrls, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(&bytes[index..])?;
+        let (ctrls, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             IndicatorMap {
@@ -1324,11 +1325,11 @@ impl AsByteSequence for ModDef {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ModDef from byte buffer");
-        let (mask, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mask, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vmods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (vmods, sz): (VMod, usize) = <VMod>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ModDef {
@@ -1360,7 +1361,7 @@ impl AsByteSequence for KeyName {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing KeyName from byte buffer");
-        let (name, sz): ([c_char; 4], usize) = <[c_char; 4]>::from_bytes(&bytes[index..])?;
+        let (name, sz): ([c_char; 4], usize) = <[c_char; 4]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((KeyName { name: name }, index))
     }
@@ -1387,9 +1388,9 @@ impl AsByteSequence for KeyAlias {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing KeyAlias from byte buffer");
-        let (real, sz): ([c_char; 4], usize) = <[c_char; 4]>::from_bytes(&bytes[index..])?;
+        let (real, sz): ([c_char; 4], usize) = <[c_char; 4]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (alias, sz): ([c_char; 4], usize) = <[c_char; 4]>::from_bytes(&bytes[index..])?;
+        let (alias, sz): ([c_char; 4], usize) = <[c_char; 4]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             KeyAlias {
@@ -1428,14 +1429,14 @@ impl AsByteSequence for CountedString16 {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CountedString16 from byte buffer");
-        let (length, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (string, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], (length as usize) as usize)?;
+            string_from_bytes(bytes.get(index..)?, (length as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         let (alignment_pad, block_len): (Vec<Void>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             ((((length as usize) + (5)) & (!(3))) - ((length as usize) + (2))) as usize,
         )?;
         index += block_len;
@@ -1489,15 +1490,15 @@ impl AsByteSequence for KtMapEntry {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing KtMapEntry from byte buffer");
-        let (active, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (active, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods_mask, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mods_mask, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (level, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (level, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mods_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods_vmods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (mods_vmods, sz): (VMod, usize) = <VMod>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -1556,25 +1557,25 @@ impl AsByteSequence for KeyType {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing KeyType from byte buffer");
-        let (mods_mask, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mods_mask, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mods_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods_vmods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (mods_vmods, sz): (VMod, usize) = <VMod>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_levels, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (num_levels, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_map_entries, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_map_entries, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (has_preserve, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (has_preserve, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         let (map, block_len): (Vec<KtMapEntry>, usize) =
-            vector_from_bytes(&bytes[index..], (n_map_entries as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (n_map_entries as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KtMapEntry>());
         let (preserve, block_len): (Vec<ModDef>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             ((has_preserve as usize) * (n_map_entries as usize)) as usize,
         )?;
         index += block_len;
@@ -1639,16 +1640,16 @@ impl AsByteSequence for KeySymMap {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing KeySymMap from byte buffer");
-        let (kt_index, sz): ([Card8; 4], usize) = <[Card8; 4]>::from_bytes(&bytes[index..])?;
+        let (kt_index, sz): ([Card8; 4], usize) = <[Card8; 4]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group_info, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (group_info, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (syms, block_len): (Vec<Keysym>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Keysym>());
         Some((
@@ -1692,9 +1693,9 @@ impl AsByteSequence for CommonBehavior {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CommonBehavior from byte buffer");
-        let (ty, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (data, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (data, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((CommonBehavior { ty: ty, data: data }, index))
     }
@@ -1720,7 +1721,7 @@ impl AsByteSequence for DefaultBehavior {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DefaultBehavior from byte buffer");
-        let (ty, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((DefaultBehavior { ty: ty }, index))
@@ -1749,9 +1750,9 @@ impl AsByteSequence for RadioGroupBehavior {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing RadioGroupBehavior from byte buffer");
-        let (ty, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (group, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             RadioGroupBehavior {
@@ -1784,9 +1785,9 @@ impl AsByteSequence for OverlayBehavior {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing OverlayBehavior from byte buffer");
-        let (ty, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (key, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((OverlayBehavior { ty: ty, key: key }, index))
     }
@@ -1817,9 +1818,9 @@ impl AsByteSequence for SetBehavior {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetBehavior from byte buffer");
-        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (behavior, sz): (Behavior, usize) = <Behavior>::from_bytes(&bytes[index..])?;
+        let (behavior, sz): (Behavior, usize) = <Behavior>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -1853,9 +1854,9 @@ impl AsByteSequence for SetExplicit {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetExplicit from byte buffer");
-        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (explicit, sz): (Explicit, usize) = <Explicit>::from_bytes(&bytes[index..])?;
+        let (explicit, sz): (Explicit, usize) = <Explicit>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetExplicit {
@@ -2099,9 +2100,9 @@ impl AsByteSequence for KeyModMap {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing KeyModMap from byte buffer");
-        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             KeyModMap {
@@ -2135,10 +2136,10 @@ impl AsByteSequence for KeyVModMap {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing KeyVModMap from byte buffer");
-        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (vmods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (vmods, sz): (VMod, usize) = <VMod>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             KeyVModMap {
@@ -2173,11 +2174,11 @@ impl AsByteSequence for KtSetMapEntry {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing KtSetMapEntry from byte buffer");
-        let (level, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (level, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             KtSetMapEntry {
@@ -2228,25 +2229,25 @@ impl AsByteSequence for SetKeyType {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetKeyType from byte buffer");
-        let (mask, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mask, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_levels, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (num_levels, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_map_entries, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_map_entries, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (preserve, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (preserve, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         let (entries, block_len): (Vec<KtSetMapEntry>, usize) =
-            vector_from_bytes(&bytes[index..], (n_map_entries as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (n_map_entries as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KtSetMapEntry>());
         let (preserve_entries, block_len): (Vec<KtSetMapEntry>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             ((preserve as usize) * (n_map_entries as usize)) as usize,
         )?;
         index += block_len;
@@ -2309,13 +2310,13 @@ impl AsByteSequence for Outline {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Outline from byte buffer");
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (corner_radius, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (corner_radius, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (points, block_len): (Vec<Point>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Point>());
         Some((
@@ -2361,17 +2362,17 @@ impl AsByteSequence for Shape {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Shape from byte buffer");
-        let (name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (primary_ndx, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (primary_ndx, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (approx_ndx, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (approx_ndx, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         let (outlines, block_len): (Vec<Outline>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Outline>());
         Some((
@@ -2420,13 +2421,13 @@ impl AsByteSequence for Key {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Key from byte buffer");
-        let (name, sz): ([String8; 4], usize) = <[String8; 4]>::from_bytes(&bytes[index..])?;
+        let (name, sz): ([String8; 4], usize) = <[String8; 4]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gap, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (gap, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (shape_ndx, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (shape_ndx, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (color_ndx, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (color_ndx, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Key {
@@ -2461,9 +2462,9 @@ impl AsByteSequence for OverlayKey {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing OverlayKey from byte buffer");
-        let (over, sz): ([String8; 4], usize) = <[String8; 4]>::from_bytes(&bytes[index..])?;
+        let (over, sz): ([String8; 4], usize) = <[String8; 4]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (under, sz): ([String8; 4], usize) = <[String8; 4]>::from_bytes(&bytes[index..])?;
+        let (under, sz): ([String8; 4], usize) = <[String8; 4]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             OverlayKey {
@@ -2500,13 +2501,13 @@ impl AsByteSequence for OverlayRow {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing OverlayRow from byte buffer");
-        let (row_under, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (row_under, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (keys, block_len): (Vec<OverlayKey>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<OverlayKey>());
         Some((
@@ -2548,13 +2549,13 @@ impl AsByteSequence for Overlay {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Overlay from byte buffer");
-        let (name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         let (rows, block_len): (Vec<OverlayRow>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<OverlayRow>());
         Some((
@@ -2600,17 +2601,17 @@ impl AsByteSequence for Row {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Row from byte buffer");
-        let (top, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (top, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (left, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (left, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vertical, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (vertical, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (keys, block_len): (Vec<Key>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Key>());
         Some((
@@ -2659,12 +2660,12 @@ impl AsByteSequence for Listing {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Listing from byte buffer");
-        let (flags, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (string, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], (length as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (length as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, 2);
         Some((
@@ -2719,24 +2720,24 @@ impl AsByteSequence for DeviceLedInfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeviceLedInfo from byte buffer");
-        let (led_class, sz): (LedClass, usize) = <LedClass>::from_bytes(&bytes[index..])?;
+        let (led_class, sz): (LedClass, usize) = <LedClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (led_id, sz): (IdSpec, usize) = <IdSpec>::from_bytes(&bytes[index..])?;
+        let (led_id, sz): (IdSpec, usize) = <IdSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (names_present, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (names_present, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (maps_present, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (maps_present, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (phys_indicators, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (phys_indicators, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (state, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], ((names_present).count_ones()) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((names_present).count_ones()) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         let (maps, block_len): (Vec<IndicatorMap>, usize) =
-            vector_from_bytes(&bytes[index..], ((maps_present).count_ones()) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((maps_present).count_ones()) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
         Some((
@@ -2866,7 +2867,7 @@ impl AsByteSequence for SaNoAction {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaNoAction from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 7;
         Some((SaNoAction { ty: ty }, index))
@@ -2972,17 +2973,17 @@ impl AsByteSequence for SaSetMods {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaSetMods from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (Sa, usize) = <Sa>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Sa, usize) = <Sa>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mask, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mask, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vmods_high, sz): (VModsHigh, usize) = <VModsHigh>::from_bytes(&bytes[index..])?;
+        let (vmods_high, sz): (VModsHigh, usize) = <VModsHigh>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vmods_low, sz): (VModsLow, usize) = <VModsLow>::from_bytes(&bytes[index..])?;
+        let (vmods_low, sz): (VModsLow, usize) = <VModsLow>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -3570,11 +3571,11 @@ impl AsByteSequence for SaSetGroup {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaSetGroup from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (Sa, usize) = <Sa>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Sa, usize) = <Sa>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group, sz): (Int8, usize) = <Int8>::from_bytes(&bytes[index..])?;
+        let (group, sz): (Int8, usize) = <Int8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 5;
         Some((
@@ -3620,17 +3621,17 @@ impl AsByteSequence for SaMovePtr {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaMovePtr from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (SaMovePtrFlag, usize) = <SaMovePtrFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (SaMovePtrFlag, usize) = <SaMovePtrFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x_high, sz): (Int8, usize) = <Int8>::from_bytes(&bytes[index..])?;
+        let (x_high, sz): (Int8, usize) = <Int8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x_low, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (x_low, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y_high, sz): (Int8, usize) = <Int8>::from_bytes(&bytes[index..])?;
+        let (y_high, sz): (Int8, usize) = <Int8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y_low, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (y_low, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -3796,13 +3797,13 @@ impl AsByteSequence for SaPtrBtn {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaPtrBtn from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (count, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (count, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (button, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (button, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
         Some((
@@ -3842,12 +3843,12 @@ impl AsByteSequence for SaLockPtrBtn {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaLockPtrBtn from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (button, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (button, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
         Some((
@@ -3887,15 +3888,15 @@ impl AsByteSequence for SaSetPtrDflt {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaSetPtrDflt from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (flags, sz): (SaSetPtrDfltFlag, usize) =
-            <SaSetPtrDfltFlag>::from_bytes(&bytes[index..])?;
+            <SaSetPtrDfltFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (affect, sz): (SaSetPtrDfltFlag, usize) =
-            <SaSetPtrDfltFlag>::from_bytes(&bytes[index..])?;
+            <SaSetPtrDfltFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value, sz): (Int8, usize) = <Int8>::from_bytes(&bytes[index..])?;
+        let (value, sz): (Int8, usize) = <Int8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
         Some((
@@ -4043,22 +4044,22 @@ impl AsByteSequence for SaIsoLock {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaIsoLock from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (SaIsoLockFlag, usize) = <SaIsoLockFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (SaIsoLockFlag, usize) = <SaIsoLockFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mask, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mask, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group, sz): (Int8, usize) = <Int8>::from_bytes(&bytes[index..])?;
+        let (group, sz): (Int8, usize) = <Int8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (affect, sz): (SaIsoLockNoAffect, usize) =
-            <SaIsoLockNoAffect>::from_bytes(&bytes[index..])?;
+            <SaIsoLockNoAffect>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vmods_high, sz): (VModsHigh, usize) = <VModsHigh>::from_bytes(&bytes[index..])?;
+        let (vmods_high, sz): (VModsHigh, usize) = <VModsHigh>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vmods_low, sz): (VModsLow, usize) = <VModsLow>::from_bytes(&bytes[index..])?;
+        let (vmods_low, sz): (VModsLow, usize) = <VModsLow>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SaIsoLock {
@@ -4376,7 +4377,7 @@ impl AsByteSequence for SaTerminate {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaTerminate from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 7;
         Some((SaTerminate { ty: ty }, index))
@@ -4407,11 +4408,11 @@ impl AsByteSequence for SaSwitchScreen {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaSwitchScreen from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (new_screen, sz): (Int8, usize) = <Int8>::from_bytes(&bytes[index..])?;
+        let (new_screen, sz): (Int8, usize) = <Int8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 5;
         Some((
@@ -4450,14 +4451,14 @@ impl AsByteSequence for SaSetControls {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaSetControls from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         let (bool_ctrls_high, sz): (BoolCtrlsHigh, usize) =
-            <BoolCtrlsHigh>::from_bytes(&bytes[index..])?;
+            <BoolCtrlsHigh>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (bool_ctrls_low, sz): (BoolCtrlsLow, usize) =
-            <BoolCtrlsLow>::from_bytes(&bytes[index..])?;
+            <BoolCtrlsLow>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -4863,12 +4864,12 @@ impl AsByteSequence for SaActionMessage {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaActionMessage from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (flags, sz): (ActionMessageFlag, usize) =
-            <ActionMessageFlag>::from_bytes(&bytes[index..])?;
+            <ActionMessageFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (message, sz): ([Card8; 6], usize) = <[Card8; 6]>::from_bytes(&bytes[index..])?;
+        let (message, sz): ([Card8; 6], usize) = <[Card8; 6]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SaActionMessage {
@@ -5031,21 +5032,22 @@ impl AsByteSequence for SaRedirectKey {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaRedirectKey from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (newkey, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (newkey, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mask, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mask, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (real_modifiers, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (real_modifiers, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vmods_mask_high, sz): (VModsHigh, usize) = <VModsHigh>::from_bytes(&bytes[index..])?;
+        let (vmods_mask_high, sz): (VModsHigh, usize) =
+            <VModsHigh>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vmods_mask_low, sz): (VModsLow, usize) = <VModsLow>::from_bytes(&bytes[index..])?;
+        let (vmods_mask_low, sz): (VModsLow, usize) = <VModsLow>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vmods_high, sz): (VModsHigh, usize) = <VModsHigh>::from_bytes(&bytes[index..])?;
+        let (vmods_high, sz): (VModsHigh, usize) = <VModsHigh>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vmods_low, sz): (VModsLow, usize) = <VModsLow>::from_bytes(&bytes[index..])?;
+        let (vmods_low, sz): (VModsLow, usize) = <VModsLow>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SaRedirectKey {
@@ -5098,15 +5100,15 @@ impl AsByteSequence for SaDeviceBtn {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaDeviceBtn from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (count, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (count, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (button, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (button, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -5154,14 +5156,15 @@ impl AsByteSequence for SaLockDeviceBtn {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaLockDeviceBtn from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (LockDeviceFlags, usize) = <LockDeviceFlags>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (LockDeviceFlags, usize) =
+            <LockDeviceFlags>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (button, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (button, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -5309,21 +5312,21 @@ impl AsByteSequence for SaDeviceValuator {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SaDeviceValuator from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (val1what, sz): (SaValWhat, usize) = <SaValWhat>::from_bytes(&bytes[index..])?;
+        let (val1what, sz): (SaValWhat, usize) = <SaValWhat>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (val1index, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (val1index, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (val1value, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (val1value, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (val2what, sz): (SaValWhat, usize) = <SaValWhat>::from_bytes(&bytes[index..])?;
+        let (val2what, sz): (SaValWhat, usize) = <SaValWhat>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (val2index, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (val2index, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (val2value, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (val2value, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SaDeviceValuator {
@@ -5408,9 +5411,9 @@ impl AsByteSequence for SiAction {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SiAction from byte buffer");
-        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (SaType, usize) = <SaType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (data, sz): ([Card8; 7], usize) = <[Card8; 7]>::from_bytes(&bytes[index..])?;
+        let (data, sz): ([Card8; 7], usize) = <[Card8; 7]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((SiAction { ty: ty, data: data }, index))
     }
@@ -5445,17 +5448,17 @@ impl AsByteSequence for SymInterpret {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SymInterpret from byte buffer");
-        let (sym, sz): (Keysym, usize) = <Keysym>::from_bytes(&bytes[index..])?;
+        let (sym, sz): (Keysym, usize) = <Keysym>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (match_, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (match_, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (virtual_mod, sz): (VModsLow, usize) = <VModsLow>::from_bytes(&bytes[index..])?;
+        let (virtual_mod, sz): (VModsLow, usize) = <VModsLow>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (action, sz): (SiAction, usize) = <SiAction>::from_bytes(&bytes[index..])?;
+        let (action, sz): (SiAction, usize) = <SiAction>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SymInterpret {
@@ -5539,14 +5542,14 @@ impl AsByteSequence for UseExtensionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing UseExtensionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (wanted_major, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (wanted_major, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (wanted_minor, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (wanted_minor, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             UseExtensionRequest {
@@ -5571,6 +5574,7 @@ impl Request for UseExtensionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UseExtension";
     type Reply = UseExtensionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5600,17 +5604,17 @@ impl AsByteSequence for UseExtensionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing UseExtensionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (supported, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (supported, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (server_major, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (server_major, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (server_minor, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (server_minor, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         Some((
@@ -5756,27 +5760,27 @@ impl AsByteSequence for SelectEventsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectEventsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (affect_which, sz): (EventType, usize) = <EventType>::from_bytes(&bytes[index..])?;
+        let (affect_which, sz): (EventType, usize) = <EventType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (clear, sz): (EventType, usize) = <EventType>::from_bytes(&bytes[index..])?;
+        let (clear, sz): (EventType, usize) = <EventType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (select_all, sz): (EventType, usize) = <EventType>::from_bytes(&bytes[index..])?;
+        let (select_all, sz): (EventType, usize) = <EventType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (affect_map, sz): (MapPart, usize) = <MapPart>::from_bytes(&bytes[index..])?;
+        let (affect_map, sz): (MapPart, usize) = <MapPart>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (map, sz): (MapPart, usize) = <MapPart>::from_bytes(&bytes[index..])?;
+        let (map, sz): (MapPart, usize) = <MapPart>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let cond0 = ((affect_which) & ((!(clear)) & (!(select_all))));
         let affect_new_keyboard: NknDetail = if cond0.new_keyboard_notify() {
             let (affect_new_keyboard, sz): (NknDetail, usize) =
-                <NknDetail>::from_bytes(&bytes[index..])?;
+                <NknDetail>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             affect_new_keyboard
         } else {
@@ -5784,35 +5788,37 @@ impl AsByteSequence for SelectEventsRequest {
         };
         let new_keyboard_details: NknDetail = if cond0.new_keyboard_notify() {
             let (new_keyboard_details, sz): (NknDetail, usize) =
-                <NknDetail>::from_bytes(&bytes[index..])?;
+                <NknDetail>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             new_keyboard_details
         } else {
             Default::default()
         };
         let affect_state: StatePart = if cond0.state_notify() {
-            let (affect_state, sz): (StatePart, usize) = <StatePart>::from_bytes(&bytes[index..])?;
+            let (affect_state, sz): (StatePart, usize) =
+                <StatePart>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             affect_state
         } else {
             Default::default()
         };
         let state_details: StatePart = if cond0.state_notify() {
-            let (state_details, sz): (StatePart, usize) = <StatePart>::from_bytes(&bytes[index..])?;
+            let (state_details, sz): (StatePart, usize) =
+                <StatePart>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             state_details
         } else {
             Default::default()
         };
         let affect_ctrls: Control = if cond0.controls_notify() {
-            let (affect_ctrls, sz): (Control, usize) = <Control>::from_bytes(&bytes[index..])?;
+            let (affect_ctrls, sz): (Control, usize) = <Control>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             affect_ctrls
         } else {
             Default::default()
         };
         let ctrl_details: Control = if cond0.controls_notify() {
-            let (ctrl_details, sz): (Control, usize) = <Control>::from_bytes(&bytes[index..])?;
+            let (ctrl_details, sz): (Control, usize) = <Control>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             ctrl_details
         } else {
@@ -5820,7 +5826,7 @@ impl AsByteSequence for SelectEventsRequest {
         };
         let affect_indicator_state: Card32 = if cond0.indicator_state_notify() {
             let (affect_indicator_state, sz): (Card32, usize) =
-                <Card32>::from_bytes(&bytes[index..])?;
+                <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             affect_indicator_state
         } else {
@@ -5828,7 +5834,7 @@ impl AsByteSequence for SelectEventsRequest {
         };
         let indicator_state_details: Card32 = if cond0.indicator_state_notify() {
             let (indicator_state_details, sz): (Card32, usize) =
-                <Card32>::from_bytes(&bytes[index..])?;
+                <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             indicator_state_details
         } else {
@@ -5836,7 +5842,7 @@ impl AsByteSequence for SelectEventsRequest {
         };
         let affect_indicator_map: Card32 = if cond0.indicator_map_notify() {
             let (affect_indicator_map, sz): (Card32, usize) =
-                <Card32>::from_bytes(&bytes[index..])?;
+                <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             affect_indicator_map
         } else {
@@ -5844,7 +5850,7 @@ impl AsByteSequence for SelectEventsRequest {
         };
         let indicator_map_details: Card32 = if cond0.indicator_map_notify() {
             let (indicator_map_details, sz): (Card32, usize) =
-                <Card32>::from_bytes(&bytes[index..])?;
+                <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             indicator_map_details
         } else {
@@ -5852,7 +5858,7 @@ impl AsByteSequence for SelectEventsRequest {
         };
         let affect_names: NameDetail = if cond0.names_notify() {
             let (affect_names, sz): (NameDetail, usize) =
-                <NameDetail>::from_bytes(&bytes[index..])?;
+                <NameDetail>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             affect_names
         } else {
@@ -5860,49 +5866,52 @@ impl AsByteSequence for SelectEventsRequest {
         };
         let names_details: NameDetail = if cond0.names_notify() {
             let (names_details, sz): (NameDetail, usize) =
-                <NameDetail>::from_bytes(&bytes[index..])?;
+                <NameDetail>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             names_details
         } else {
             Default::default()
         };
         let affect_compat: CmDetail = if cond0.compat_map_notify() {
-            let (affect_compat, sz): (CmDetail, usize) = <CmDetail>::from_bytes(&bytes[index..])?;
+            let (affect_compat, sz): (CmDetail, usize) =
+                <CmDetail>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             affect_compat
         } else {
             Default::default()
         };
         let compat_details: CmDetail = if cond0.compat_map_notify() {
-            let (compat_details, sz): (CmDetail, usize) = <CmDetail>::from_bytes(&bytes[index..])?;
+            let (compat_details, sz): (CmDetail, usize) =
+                <CmDetail>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             compat_details
         } else {
             Default::default()
         };
         let affect_bell: Card8 = if cond0.bell_notify() {
-            let (affect_bell, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (affect_bell, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             affect_bell
         } else {
             Default::default()
         };
         let bell_details: Card8 = if cond0.bell_notify() {
-            let (bell_details, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (bell_details, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             bell_details
         } else {
             Default::default()
         };
         let affect_msg_details: Card8 = if cond0.action_message() {
-            let (affect_msg_details, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (affect_msg_details, sz): (Card8, usize) =
+                <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             affect_msg_details
         } else {
             Default::default()
         };
         let msg_details: Card8 = if cond0.action_message() {
-            let (msg_details, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (msg_details, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             msg_details
         } else {
@@ -5910,7 +5919,7 @@ impl AsByteSequence for SelectEventsRequest {
         };
         let affect_access_x: AxnDetail = if cond0.access_x_notify() {
             let (affect_access_x, sz): (AxnDetail, usize) =
-                <AxnDetail>::from_bytes(&bytes[index..])?;
+                <AxnDetail>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             affect_access_x
         } else {
@@ -5918,7 +5927,7 @@ impl AsByteSequence for SelectEventsRequest {
         };
         let access_x_details: AxnDetail = if cond0.access_x_notify() {
             let (access_x_details, sz): (AxnDetail, usize) =
-                <AxnDetail>::from_bytes(&bytes[index..])?;
+                <AxnDetail>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             access_x_details
         } else {
@@ -5926,7 +5935,7 @@ impl AsByteSequence for SelectEventsRequest {
         };
         let affect_ext_dev: XiFeature = if cond0.extension_device_notify() {
             let (affect_ext_dev, sz): (XiFeature, usize) =
-                <XiFeature>::from_bytes(&bytes[index..])?;
+                <XiFeature>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             affect_ext_dev
         } else {
@@ -5934,7 +5943,7 @@ impl AsByteSequence for SelectEventsRequest {
         };
         let extdev_details: XiFeature = if cond0.extension_device_notify() {
             let (extdev_details, sz): (XiFeature, usize) =
-                <XiFeature>::from_bytes(&bytes[index..])?;
+                <XiFeature>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             extdev_details
         } else {
@@ -6015,6 +6024,7 @@ impl Request for SelectEventsRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SelectEvents";
     type Reply = ();
 }
 #[repr(transparent)]
@@ -6552,33 +6562,33 @@ impl AsByteSequence for BellRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing BellRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (bell_class, sz): (BellClassSpec, usize) =
-            <BellClassSpec>::from_bytes(&bytes[index..])?;
+            <BellClassSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bell_id, sz): (IdSpec, usize) = <IdSpec>::from_bytes(&bytes[index..])?;
+        let (bell_id, sz): (IdSpec, usize) = <IdSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (percent, sz): (Int8, usize) = <Int8>::from_bytes(&bytes[index..])?;
+        let (percent, sz): (Int8, usize) = <Int8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (force_sound, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (force_sound, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_only, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (event_only, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (pitch, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (pitch, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (duration, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (duration, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             BellRequest {
@@ -6621,6 +6631,7 @@ impl Request for BellRequest {
     const OPCODE: u8 = 3;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Bell";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -6645,12 +6656,12 @@ impl AsByteSequence for GetStateRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetStateRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -6671,6 +6682,7 @@ impl Request for GetStateRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetState";
     type Reply = GetStateReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -6725,42 +6737,44 @@ impl AsByteSequence for GetStateReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetStateReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (base_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (base_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (latched_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (latched_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (locked_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (locked_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group, sz): (Group, usize) = <Group>::from_bytes(&bytes[index..])?;
+        let (group, sz): (Group, usize) = <Group>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (locked_group, sz): (Group, usize) = <Group>::from_bytes(&bytes[index..])?;
+        let (locked_group, sz): (Group, usize) = <Group>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (base_group, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (base_group, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (latched_group, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (latched_group, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (compat_state, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (compat_state, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (grab_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (grab_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (compat_grab_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (compat_grab_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (lookup_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (lookup_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (compat_lookup_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (compat_lookup_mods, sz): (ModMask, usize) =
+            <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (ptr_btn_state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(&bytes[index..])?;
+        let (ptr_btn_state, sz): (KeyButMask, usize) =
+            <KeyButMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 6;
         Some((
@@ -6874,6 +6888,7 @@ impl AsByteSequence for LatchLockStateRequest {
         index += self.group_lock.as_bytes(&mut bytes[index..]);
         index += self.affect_mod_latches.as_bytes(&mut bytes[index..]);
         index += 1;
+        index += 1;
         index += self.latch_group.as_bytes(&mut bytes[index..]);
         index += self.group_latch.as_bytes(&mut bytes[index..]);
         index
@@ -6882,27 +6897,29 @@ impl AsByteSequence for LatchLockStateRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing LatchLockStateRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (affect_mod_locks, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (affect_mod_locks, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mod_locks, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mod_locks, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (lock_group, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (lock_group, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group_lock, sz): (Group, usize) = <Group>::from_bytes(&bytes[index..])?;
+        let (group_lock, sz): (Group, usize) = <Group>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (affect_mod_latches, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (affect_mod_latches, sz): (ModMask, usize) =
+            <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (latch_group, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        index += 1;
+        let (latch_group, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group_latch, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (group_latch, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             LatchLockStateRequest {
@@ -6932,6 +6949,7 @@ impl AsByteSequence for LatchLockStateRequest {
             + self.group_lock.size()
             + self.affect_mod_latches.size()
             + 1
+            + 1
             + self.latch_group.size()
             + self.group_latch.size()
     }
@@ -6940,6 +6958,7 @@ impl Request for LatchLockStateRequest {
     const OPCODE: u8 = 5;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "LatchLockState";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -6964,12 +6983,12 @@ impl AsByteSequence for GetControlsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetControlsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -6990,6 +7009,7 @@ impl Request for GetControlsRequest {
     const OPCODE: u8 = 6;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetControls";
     type Reply = GetControlsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -7074,74 +7094,79 @@ impl AsByteSequence for GetControlsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetControlsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mouse_keys_dflt_btn, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (mouse_keys_dflt_btn, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_groups, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (num_groups, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (groups_wrap, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (groups_wrap, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (internal_mods_mask, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (internal_mods_mask, sz): (ModMask, usize) =
+            <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ignore_lock_mods_mask, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (ignore_lock_mods_mask, sz): (ModMask, usize) =
+            <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (internal_mods_real_mods, sz): (ModMask, usize) =
-            <ModMask>::from_bytes(&bytes[index..])?;
+            <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (ignore_lock_mods_real_mods, sz): (ModMask, usize) =
-            <ModMask>::from_bytes(&bytes[index..])?;
+            <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (internal_mods_vmods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (internal_mods_vmods, sz): (VMod, usize) = <VMod>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ignore_lock_mods_vmods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (ignore_lock_mods_vmods, sz): (VMod, usize) = <VMod>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (repeat_delay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (repeat_delay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (repeat_interval, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (repeat_interval, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (slow_keys_delay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (slow_keys_delay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (debounce_delay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (debounce_delay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mouse_keys_delay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mouse_keys_delay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mouse_keys_interval, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mouse_keys_interval, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mouse_keys_time_to_max, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mouse_keys_time_to_max, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mouse_keys_max_speed, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mouse_keys_max_speed, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mouse_keys_curve, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (mouse_keys_curve, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (access_x_option, sz): (AxOption, usize) = <AxOption>::from_bytes(&bytes[index..])?;
+        let (access_x_option, sz): (AxOption, usize) = <AxOption>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (access_x_timeout, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (access_x_timeout, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (access_x_timeout_options_mask, sz): (AxOption, usize) =
-            <AxOption>::from_bytes(&bytes[index..])?;
+            <AxOption>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (access_x_timeout_options_values, sz): (AxOption, usize) =
-            <AxOption>::from_bytes(&bytes[index..])?;
+            <AxOption>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (access_x_timeout_mask, sz): (BoolCtrl, usize) =
-            <BoolCtrl>::from_bytes(&bytes[index..])?;
+            <BoolCtrl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (access_x_timeout_values, sz): (BoolCtrl, usize) =
-            <BoolCtrl>::from_bytes(&bytes[index..])?;
+            <BoolCtrl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (enabled_controls, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(&bytes[index..])?;
+        let (enabled_controls, sz): (BoolCtrl, usize) =
+            <BoolCtrl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (per_key_repeat, sz): ([Card8; 32], usize) =
-            <[Card8; 32]>::from_bytes(&bytes[index..])?;
+            <[Card8; 32]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetControlsReply {
@@ -7588,81 +7613,88 @@ impl AsByteSequence for SetControlsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetControlsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (affect_internal_real_mods, sz): (ModMask, usize) =
-            <ModMask>::from_bytes(&bytes[index..])?;
+            <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (internal_real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (internal_real_mods, sz): (ModMask, usize) =
+            <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (affect_ignore_lock_real_mods, sz): (ModMask, usize) =
-            <ModMask>::from_bytes(&bytes[index..])?;
+            <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ignore_lock_real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (ignore_lock_real_mods, sz): (ModMask, usize) =
+            <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (affect_internal_virtual_mods, sz): (VMod, usize) =
-            <VMod>::from_bytes(&bytes[index..])?;
+            <VMod>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (internal_virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (internal_virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (affect_ignore_lock_virtual_mods, sz): (VMod, usize) =
-            <VMod>::from_bytes(&bytes[index..])?;
+            <VMod>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ignore_lock_virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (ignore_lock_virtual_mods, sz): (VMod, usize) =
+            <VMod>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mouse_keys_dflt_btn, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (mouse_keys_dflt_btn, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (groups_wrap, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (groups_wrap, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (access_x_options, sz): (AxOption, usize) = <AxOption>::from_bytes(&bytes[index..])?;
+        let (access_x_options, sz): (AxOption, usize) =
+            <AxOption>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (affect_enabled_controls, sz): (BoolCtrl, usize) =
-            <BoolCtrl>::from_bytes(&bytes[index..])?;
+            <BoolCtrl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (enabled_controls, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(&bytes[index..])?;
+        let (enabled_controls, sz): (BoolCtrl, usize) =
+            <BoolCtrl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (change_controls, sz): (Control, usize) = <Control>::from_bytes(&bytes[index..])?;
+        let (change_controls, sz): (Control, usize) = <Control>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (repeat_delay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (repeat_delay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (repeat_interval, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (repeat_interval, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (slow_keys_delay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (slow_keys_delay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (debounce_delay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (debounce_delay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mouse_keys_delay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mouse_keys_delay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mouse_keys_interval, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mouse_keys_interval, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mouse_keys_time_to_max, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mouse_keys_time_to_max, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mouse_keys_max_speed, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mouse_keys_max_speed, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mouse_keys_curve, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (mouse_keys_curve, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (access_x_timeout, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (access_x_timeout, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (access_x_timeout_mask, sz): (BoolCtrl, usize) =
-            <BoolCtrl>::from_bytes(&bytes[index..])?;
+            <BoolCtrl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (access_x_timeout_values, sz): (BoolCtrl, usize) =
-            <BoolCtrl>::from_bytes(&bytes[index..])?;
+            <BoolCtrl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (access_x_timeout_options_mask, sz): (AxOption, usize) =
-            <AxOption>::from_bytes(&bytes[index..])?;
+            <AxOption>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (access_x_timeout_options_values, sz): (AxOption, usize) =
-            <AxOption>::from_bytes(&bytes[index..])?;
+            <AxOption>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (per_key_repeat, sz): ([Card8; 32], usize) =
-            <[Card8; 32]>::from_bytes(&bytes[index..])?;
+            <[Card8; 32]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetControlsRequest {
@@ -7744,6 +7776,7 @@ impl Request for SetControlsRequest {
     const OPCODE: u8 = 7;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetControls";
     type Reply = ();
 }
 #[repr(transparent)]
@@ -7959,46 +7992,49 @@ impl AsByteSequence for GetMapRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (full, sz): (MapPart, usize) = <MapPart>::from_bytes(&bytes[index..])?;
+        let (full, sz): (MapPart, usize) = <MapPart>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (partial, sz): (MapPart, usize) = <MapPart>::from_bytes(&bytes[index..])?;
+        let (partial, sz): (MapPart, usize) = <MapPart>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_types, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_types, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_key_sym, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_sym, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_key_syms, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_key_syms, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_key_action, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_action, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_key_actions, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_key_actions, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_key_behavior, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_behavior, sz): (Keycode, usize) =
+            <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_key_behaviors, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_key_behaviors, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_key_explicit, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_explicit, sz): (Keycode, usize) =
+            <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_key_explicit, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_key_explicit, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_mod_map_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_mod_map_key, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_v_mod_map_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_v_mod_map_key, sz): (Keycode, usize) =
+            <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_v_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_v_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -8057,6 +8093,7 @@ impl Request for GetMapRequest {
     const OPCODE: u8 = 8;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetMap";
     type Reply = GetMapReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -8137,7 +8174,7 @@ impl AsByteSequence for GetMapReply {
         let block_len: usize = vector_as_bytes(&self.acts_rtrn_count, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
+        index += buffer_pad(index, 4);
         let block_len: usize = vector_as_bytes(&self.acts_rtrn_acts, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Action>());
@@ -8147,12 +8184,15 @@ impl AsByteSequence for GetMapReply {
         let block_len: usize = vector_as_bytes(&self.vmods_rtrn, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+        index += buffer_pad(index, 4);
         let block_len: usize = vector_as_bytes(&self.explicit_rtrn, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
+        index += buffer_pad(index, 4);
         let block_len: usize = vector_as_bytes(&self.modmap_rtrn, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
+        index += buffer_pad(index, 4);
         let block_len: usize = vector_as_bytes(&self.vmodmap_rtrn, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
@@ -8162,101 +8202,107 @@ impl AsByteSequence for GetMapReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMapReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (present, sz): (MapPart, usize) = <MapPart>::from_bytes(&bytes[index..])?;
+        let (present, sz): (MapPart, usize) = <MapPart>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (total_types, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (total_types, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_key_sym, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_sym, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (total_syms, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (total_syms, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_key_action, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_action, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len3, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len3, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_key_behavior, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_behavior, sz): (Keycode, usize) =
+            <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_key_behaviors, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_key_behaviors, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len4, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len4, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_key_explicit, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_explicit, sz): (Keycode, usize) =
+            <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_key_explicit, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_key_explicit, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len5, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len5, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_mod_map_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_mod_map_key, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len6, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len6, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_v_mod_map_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_v_mod_map_key, sz): (Keycode, usize) =
+            <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_v_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_v_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len7, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len7, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (types_rtrn, block_len): (Vec<KeyType>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KeyType>());
         let (syms_rtrn, block_len): (Vec<KeySymMap>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
         let (acts_rtrn_count, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len3 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len3 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
+        index += buffer_pad(index, 4);
         let (acts_rtrn_acts, block_len): (Vec<Action>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len2 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Action>());
         let (behaviors_rtrn, block_len): (Vec<SetBehavior>, usize) =
-            vector_from_bytes(&bytes[index..], len4 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len4 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
         let (vmods_rtrn, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], ((virtual_mods).count_ones()) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((virtual_mods).count_ones()) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+        index += buffer_pad(index, 4);
         let (explicit_rtrn, block_len): (Vec<SetExplicit>, usize) =
-            vector_from_bytes(&bytes[index..], len5 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len5 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
+        index += buffer_pad(index, 4);
         let (modmap_rtrn, block_len): (Vec<KeyModMap>, usize) =
-            vector_from_bytes(&bytes[index..], len6 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len6 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
+        index += buffer_pad(index, 4);
         let (vmodmap_rtrn, block_len): (Vec<KeyVModMap>, usize) =
-            vector_from_bytes(&bytes[index..], len7 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len7 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
         Some((
@@ -8297,83 +8343,95 @@ impl AsByteSequence for GetMapReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.device_id.size()
-            + self.sequence.size()
-            + self.length.size()
-            + 2
-            + self.min_key_code.size()
-            + self.max_key_code.size()
-            + self.present.size()
-            + self.first_type.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.total_types.size()
-            + self.first_key_sym.size()
-            + self.total_syms.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_key_action.size()
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_key_behavior.size()
-            + self.n_key_behaviors.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_key_explicit.size()
-            + self.n_key_explicit.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_mod_map_key.size()
-            + self.n_mod_map_keys.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_v_mod_map_key.size()
-            + self.n_v_mod_map_keys.size()
-            + ::core::mem::size_of::<Card8>()
-            + 1
-            + self.virtual_mods.size()
-            + {
-                let block_len: usize = self.types_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyType>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.syms_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.acts_rtrn_count.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
-            + 4
-            + {
-                let block_len: usize = self.acts_rtrn_acts.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Action>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.behaviors_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.vmods_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.explicit_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
-                block_len + pad
-            }
-            + {
+        ({
+            let size: usize = ({
+                let size: usize = ({
+                    let size: usize = ({
+                        let size: usize = self.reply_type.size()
+                            + self.device_id.size()
+                            + self.sequence.size()
+                            + self.length.size()
+                            + 2
+                            + self.min_key_code.size()
+                            + self.max_key_code.size()
+                            + self.present.size()
+                            + self.first_type.size()
+                            + ::core::mem::size_of::<Card8>()
+                            + self.total_types.size()
+                            + self.first_key_sym.size()
+                            + self.total_syms.size()
+                            + ::core::mem::size_of::<Card8>()
+                            + self.first_key_action.size()
+                            + ::core::mem::size_of::<Card16>()
+                            + ::core::mem::size_of::<Card8>()
+                            + self.first_key_behavior.size()
+                            + self.n_key_behaviors.size()
+                            + ::core::mem::size_of::<Card8>()
+                            + self.first_key_explicit.size()
+                            + self.n_key_explicit.size()
+                            + ::core::mem::size_of::<Card8>()
+                            + self.first_mod_map_key.size()
+                            + self.n_mod_map_keys.size()
+                            + ::core::mem::size_of::<Card8>()
+                            + self.first_v_mod_map_key.size()
+                            + self.n_v_mod_map_keys.size()
+                            + ::core::mem::size_of::<Card8>()
+                            + 1
+                            + self.virtual_mods.size()
+                            + {
+                                let block_len: usize =
+                                    self.types_rtrn.iter().map(|i| i.size()).sum();
+                                let pad: usize =
+                                    buffer_pad(block_len, ::core::mem::align_of::<KeyType>());
+                                block_len + pad
+                            }
+                            + {
+                                let block_len: usize =
+                                    self.syms_rtrn.iter().map(|i| i.size()).sum();
+                                let pad: usize =
+                                    buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
+                                block_len + pad
+                            }
+                            + {
+                                let block_len: usize =
+                                    self.acts_rtrn_count.iter().map(|i| i.size()).sum();
+                                let pad: usize =
+                                    buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                                block_len + pad
+                            };
+                        size + buffer_pad(size, 4)
+                    }) + {
+                        let block_len: usize = self.acts_rtrn_acts.iter().map(|i| i.size()).sum();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Action>());
+                        block_len + pad
+                    } + {
+                        let block_len: usize = self.behaviors_rtrn.iter().map(|i| i.size()).sum();
+                        let pad: usize =
+                            buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
+                        block_len + pad
+                    } + {
+                        let block_len: usize = self.vmods_rtrn.iter().map(|i| i.size()).sum();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                        block_len + pad
+                    };
+                    size + buffer_pad(size, 4)
+                }) + {
+                    let block_len: usize = self.explicit_rtrn.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
+                    block_len + pad
+                };
+                size + buffer_pad(size, 4)
+            }) + {
                 let block_len: usize = self.modmap_rtrn.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
                 block_len + pad
-            }
-            + {
-                let block_len: usize = self.vmodmap_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
-                block_len + pad
-            }
+            };
+            size + buffer_pad(size, 4)
+        }) + {
+            let block_len: usize = self.vmodmap_rtrn.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
+            block_len + pad
+        }
     }
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -8451,7 +8509,7 @@ impl AsByteSequence for SetMapRequest {
         let block_len: usize = vector_as_bytes(&self.actions_count, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
+        index += buffer_pad(index, 4);
         let block_len: usize = vector_as_bytes(&self.actions, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Action>());
@@ -8461,6 +8519,7 @@ impl AsByteSequence for SetMapRequest {
         let block_len: usize = vector_as_bytes(&self.vmods, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+        index += buffer_pad(index, 4);
         let block_len: usize = vector_as_bytes(&self.explicit, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
@@ -8476,98 +8535,102 @@ impl AsByteSequence for SetMapRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetMapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (present, sz): (MapPart, usize) = <MapPart>::from_bytes(&bytes[index..])?;
+        let (present, sz): (MapPart, usize) = <MapPart>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (SetMapFlags, usize) = <SetMapFlags>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (SetMapFlags, usize) = <SetMapFlags>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_key_sym, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_sym, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (total_syms, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (total_syms, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_key_action, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_action, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len2, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len3, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len3, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_key_behavior, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_behavior, sz): (Keycode, usize) =
+            <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_key_behaviors, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_key_behaviors, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len4, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len4, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_key_explicit, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_explicit, sz): (Keycode, usize) =
+            <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_key_explicit, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_key_explicit, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len5, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len5, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_mod_map_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_mod_map_key, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len6, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len6, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_v_mod_map_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_v_mod_map_key, sz): (Keycode, usize) =
+            <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_v_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_v_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len7, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len7, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (types, block_len): (Vec<SetKeyType>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<SetKeyType>());
         let (syms, block_len): (Vec<KeySymMap>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
         let (actions_count, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len2 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
+        index += buffer_pad(index, 4);
         let (actions, block_len): (Vec<Action>, usize) =
-            vector_from_bytes(&bytes[index..], len3 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len3 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Action>());
         let (behaviors, block_len): (Vec<SetBehavior>, usize) =
-            vector_from_bytes(&bytes[index..], len4 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len4 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
         let (vmods, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], ((virtual_mods).count_ones()) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((virtual_mods).count_ones()) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+        index += buffer_pad(index, 4);
         let (explicit, block_len): (Vec<SetExplicit>, usize) =
-            vector_from_bytes(&bytes[index..], len5 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len5 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
         let (modmap, block_len): (Vec<KeyModMap>, usize) =
-            vector_from_bytes(&bytes[index..], len6 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len6 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
         let (vmodmap, block_len): (Vec<KeyVModMap>, usize) =
-            vector_from_bytes(&bytes[index..], len7 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len7 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
         Some((
@@ -8607,87 +8670,89 @@ impl AsByteSequence for SetMapRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.device_spec.size()
-            + self.present.size()
-            + self.flags.size()
-            + self.min_key_code.size()
-            + self.max_key_code.size()
-            + self.first_type.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_key_sym.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.total_syms.size()
-            + self.first_key_action.size()
-            + ::core::mem::size_of::<Card8>()
-            + ::core::mem::size_of::<Card16>()
-            + self.first_key_behavior.size()
-            + self.n_key_behaviors.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_key_explicit.size()
-            + self.n_key_explicit.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_mod_map_key.size()
-            + self.n_mod_map_keys.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_v_mod_map_key.size()
-            + self.n_v_mod_map_keys.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.virtual_mods.size()
-            + {
-                let block_len: usize = self.types.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SetKeyType>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.syms.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.actions_count.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
-            + 4
-            + {
+        ({
+            let size: usize = ({
+                let size: usize = self.req_type.size()
+                    + 1
+                    + self.length.size()
+                    + self.device_spec.size()
+                    + self.present.size()
+                    + self.flags.size()
+                    + self.min_key_code.size()
+                    + self.max_key_code.size()
+                    + self.first_type.size()
+                    + ::core::mem::size_of::<Card8>()
+                    + self.first_key_sym.size()
+                    + ::core::mem::size_of::<Card8>()
+                    + self.total_syms.size()
+                    + self.first_key_action.size()
+                    + ::core::mem::size_of::<Card8>()
+                    + ::core::mem::size_of::<Card16>()
+                    + self.first_key_behavior.size()
+                    + self.n_key_behaviors.size()
+                    + ::core::mem::size_of::<Card8>()
+                    + self.first_key_explicit.size()
+                    + self.n_key_explicit.size()
+                    + ::core::mem::size_of::<Card8>()
+                    + self.first_mod_map_key.size()
+                    + self.n_mod_map_keys.size()
+                    + ::core::mem::size_of::<Card8>()
+                    + self.first_v_mod_map_key.size()
+                    + self.n_v_mod_map_keys.size()
+                    + ::core::mem::size_of::<Card8>()
+                    + self.virtual_mods.size()
+                    + {
+                        let block_len: usize = self.types.iter().map(|i| i.size()).sum();
+                        let pad: usize =
+                            buffer_pad(block_len, ::core::mem::align_of::<SetKeyType>());
+                        block_len + pad
+                    }
+                    + {
+                        let block_len: usize = self.syms.iter().map(|i| i.size()).sum();
+                        let pad: usize =
+                            buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
+                        block_len + pad
+                    }
+                    + {
+                        let block_len: usize = self.actions_count.iter().map(|i| i.size()).sum();
+                        let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                        block_len + pad
+                    };
+                size + buffer_pad(size, 4)
+            }) + {
                 let block_len: usize = self.actions.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Action>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.behaviors.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
                 block_len + pad
-            }
-            + {
+            } + {
                 let block_len: usize = self.vmods.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
                 block_len + pad
-            }
-            + {
-                let block_len: usize = self.explicit.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.modmap.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.vmodmap.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
-                block_len + pad
-            }
+            };
+            size + buffer_pad(size, 4)
+        }) + {
+            let block_len: usize = self.explicit.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.modmap.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.vmodmap.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
+            block_len + pad
+        }
     }
 }
 impl Request for SetMapRequest {
     const OPCODE: u8 = 9;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetMap";
     type Reply = ();
 }
 #[repr(transparent)]
@@ -8819,20 +8884,20 @@ impl AsByteSequence for GetCompatMapRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCompatMapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (groups, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(&bytes[index..])?;
+        let (groups, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (get_all_si, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (get_all_si, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_si, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (first_si, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_si, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (n_si, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetCompatMapRequest {
@@ -8863,6 +8928,7 @@ impl Request for GetCompatMapRequest {
     const OPCODE: u8 = 10;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetCompatMap";
     type Reply = GetCompatMapReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -8904,30 +8970,30 @@ impl AsByteSequence for GetCompatMapReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetCompatMapReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (groups_rtrn, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(&bytes[index..])?;
+        let (groups_rtrn, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (first_si_rtrn, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (first_si_rtrn, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_total_si, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (n_total_si, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         let (si_rtrn, block_len): (Vec<SymInterpret>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<SymInterpret>());
         let (group_rtrn, block_len): (Vec<ModDef>, usize) =
-            vector_from_bytes(&bytes[index..], ((groups_rtrn).count_ones()) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((groups_rtrn).count_ones()) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ModDef>());
         Some((
@@ -9009,31 +9075,31 @@ impl AsByteSequence for SetCompatMapRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetCompatMapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (recompute_actions, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (recompute_actions, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (truncate_si, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (truncate_si, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (groups, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(&bytes[index..])?;
+        let (groups, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_si, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (first_si, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (si, block_len): (Vec<SymInterpret>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<SymInterpret>());
         let (group_maps, block_len): (Vec<ModDef>, usize) =
-            vector_from_bytes(&bytes[index..], ((groups).count_ones()) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((groups).count_ones()) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ModDef>());
         Some((
@@ -9080,6 +9146,7 @@ impl Request for SetCompatMapRequest {
     const OPCODE: u8 = 11;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetCompatMap";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -9104,12 +9171,12 @@ impl AsByteSequence for GetIndicatorStateRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetIndicatorStateRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -9130,6 +9197,7 @@ impl Request for GetIndicatorStateRequest {
     const OPCODE: u8 = 12;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetIndicatorState";
     type Reply = GetIndicatorStateReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -9157,15 +9225,15 @@ impl AsByteSequence for GetIndicatorStateReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetIndicatorStateReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (state, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         Some((
@@ -9213,15 +9281,15 @@ impl AsByteSequence for GetIndicatorMapRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetIndicatorMapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (which, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (which, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetIndicatorMapRequest {
@@ -9247,6 +9315,7 @@ impl Request for GetIndicatorMapRequest {
     const OPCODE: u8 = 13;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetIndicatorMap";
     type Reply = GetIndicatorMapReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -9282,23 +9351,23 @@ impl AsByteSequence for GetIndicatorMapReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetIndicatorMapReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (which, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (which, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (real_indicators, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (real_indicators, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_indicators, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_indicators, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 15;
         let (maps, block_len): (Vec<IndicatorMap>, usize) =
-            vector_from_bytes(&bytes[index..], ((which).count_ones()) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((which).count_ones()) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
         Some((
@@ -9360,18 +9429,18 @@ impl AsByteSequence for SetIndicatorMapRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetIndicatorMapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (which, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (which, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (maps, block_len): (Vec<IndicatorMap>, usize) =
-            vector_from_bytes(&bytes[index..], ((which).count_ones()) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((which).count_ones()) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
         Some((
@@ -9404,6 +9473,7 @@ impl Request for SetIndicatorMapRequest {
     const OPCODE: u8 = 14;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetIndicatorMap";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -9434,19 +9504,19 @@ impl AsByteSequence for GetNamedIndicatorRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetNamedIndicatorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (led_class, sz): (LedClass, usize) = <LedClass>::from_bytes(&bytes[index..])?;
+        let (led_class, sz): (LedClass, usize) = <LedClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (led_id, sz): (IdSpec, usize) = <IdSpec>::from_bytes(&bytes[index..])?;
+        let (led_id, sz): (IdSpec, usize) = <IdSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (indicator, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (indicator, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetNamedIndicatorRequest {
@@ -9476,6 +9546,7 @@ impl Request for GetNamedIndicatorRequest {
     const OPCODE: u8 = 15;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetNamedIndicator";
     type Reply = GetNamedIndicatorReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -9529,43 +9600,44 @@ impl AsByteSequence for GetNamedIndicatorReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetNamedIndicatorReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (indicator, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (indicator, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (found, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (found, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (on, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (on, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (real_indicator, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (real_indicator, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ndx, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (ndx, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (map_flags, sz): (ImFlag, usize) = <ImFlag>::from_bytes(&bytes[index..])?;
+        let (map_flags, sz): (ImFlag, usize) = <ImFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (map_which_groups, sz): (ImGroupsWhich, usize) =
-            <ImGroupsWhich>::from_bytes(&bytes[index..])?;
+            <ImGroupsWhich>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (map_groups, sz): (SetOfGroups, usize) = <SetOfGroups>::from_bytes(&bytes[index..])?;
+        let (map_groups, sz): (SetOfGroups, usize) =
+            <SetOfGroups>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (map_which_mods, sz): (ImModsWhich, usize) =
-            <ImModsWhich>::from_bytes(&bytes[index..])?;
+            <ImModsWhich>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (map_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (map_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (map_real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (map_real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (map_vmod, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (map_vmod, sz): (VMod, usize) = <VMod>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (map_ctrls, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(&bytes[index..])?;
+        let (map_ctrls, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (supported, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (supported, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -9749,44 +9821,45 @@ impl AsByteSequence for SetNamedIndicatorRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetNamedIndicatorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (led_class, sz): (LedClass, usize) = <LedClass>::from_bytes(&bytes[index..])?;
+        let (led_class, sz): (LedClass, usize) = <LedClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (led_id, sz): (IdSpec, usize) = <IdSpec>::from_bytes(&bytes[index..])?;
+        let (led_id, sz): (IdSpec, usize) = <IdSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (indicator, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (indicator, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (set_state, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (set_state, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (on, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (on, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (set_map, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (set_map, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (create_map, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (create_map, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (map_flags, sz): (ImFlag, usize) = <ImFlag>::from_bytes(&bytes[index..])?;
+        let (map_flags, sz): (ImFlag, usize) = <ImFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (map_which_groups, sz): (ImGroupsWhich, usize) =
-            <ImGroupsWhich>::from_bytes(&bytes[index..])?;
+            <ImGroupsWhich>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (map_groups, sz): (SetOfGroups, usize) = <SetOfGroups>::from_bytes(&bytes[index..])?;
+        let (map_groups, sz): (SetOfGroups, usize) =
+            <SetOfGroups>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (map_which_mods, sz): (ImModsWhich, usize) =
-            <ImModsWhich>::from_bytes(&bytes[index..])?;
+            <ImModsWhich>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (map_real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (map_real_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (map_vmods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (map_vmods, sz): (VMod, usize) = <VMod>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (map_ctrls, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(&bytes[index..])?;
+        let (map_ctrls, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetNamedIndicatorRequest {
@@ -9839,6 +9912,7 @@ impl Request for SetNamedIndicatorRequest {
     const OPCODE: u8 = 16;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetNamedIndicator";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -9865,15 +9939,15 @@ impl AsByteSequence for GetNamesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetNamesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (which, sz): (NameDetail, usize) = <NameDetail>::from_bytes(&bytes[index..])?;
+        let (which, sz): (NameDetail, usize) = <NameDetail>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetNamesRequest {
@@ -9899,6 +9973,7 @@ impl Request for GetNamesRequest {
     const OPCODE: u8 = 17;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetNames";
     type Reply = GetNamesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -9979,6 +10054,7 @@ impl AsByteSequence for GetNamesReply {
         let block_len: usize = vector_as_bytes(&self.n_levels_per_type, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+        index += buffer_pad(index, 4);
         let block_len: usize = vector_as_bytes(&self.kt_level_names, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
@@ -10006,92 +10082,93 @@ impl AsByteSequence for GetNamesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetNamesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (which, sz): (NameDetail, usize) = <NameDetail>::from_bytes(&bytes[index..])?;
+        let (which, sz): (NameDetail, usize) = <NameDetail>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_types, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_types, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group_names, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(&bytes[index..])?;
+        let (group_names, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (indicators, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (indicators, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len2, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_kt_levels, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (n_kt_levels, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
         let cond0 = (which);
         let keycodes_name: Atom = if cond0.keycodes() {
-            let (keycodes_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (keycodes_name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             keycodes_name
         } else {
             Default::default()
         };
         let geometry_name: Atom = if cond0.geometry() {
-            let (geometry_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (geometry_name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             geometry_name
         } else {
             Default::default()
         };
         let symbols_name: Atom = if cond0.symbols() {
-            let (symbols_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (symbols_name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             symbols_name
         } else {
             Default::default()
         };
         let phys_symbols_name: Atom = if cond0.phys_symbols() {
-            let (phys_symbols_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (phys_symbols_name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             phys_symbols_name
         } else {
             Default::default()
         };
         let types_name: Atom = if cond0.types() {
-            let (types_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (types_name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             types_name
         } else {
             Default::default()
         };
         let compat_name: Atom = if cond0.compat() {
-            let (compat_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (compat_name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             compat_name
         } else {
             Default::default()
         };
         let (type_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], (n_types as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (n_types as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         let (n_levels_per_type, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], (n_types as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (n_types as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+        index += buffer_pad(index, 4);
         let (kt_level_names, block_len): (Vec<Atom>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (n_levels_per_type
                 .iter()
                 .map(|a| {
@@ -10102,27 +10179,27 @@ impl AsByteSequence for GetNamesReply {
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         let (indicator_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], ((indicators).count_ones()) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((indicators).count_ones()) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         let (virtual_mod_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], ((virtual_mods).count_ones()) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((virtual_mods).count_ones()) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         let (groups, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], ((group_names).count_ones()) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((group_names).count_ones()) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         let (key_names, block_len): (Vec<KeyName>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
         let (key_aliases, block_len): (Vec<KeyAlias>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len2 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
         let (radio_group_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         Some((
@@ -10161,74 +10238,70 @@ impl AsByteSequence for GetNamesReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.device_id.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.which.size()
-            + self.min_key_code.size()
-            + self.max_key_code.size()
-            + self.n_types.size()
-            + self.group_names.size()
-            + self.virtual_mods.size()
-            + self.first_key.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.indicators.size()
-            + ::core::mem::size_of::<Card8>()
-            + ::core::mem::size_of::<Card8>()
-            + self.n_kt_levels.size()
-            + 4
-            + self.keycodes_name.size()
-            + self.geometry_name.size()
-            + self.symbols_name.size()
-            + self.phys_symbols_name.size()
-            + self.types_name.size()
-            + self.compat_name.size()
-            + {
-                let block_len: usize = self.type_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.n_levels_per_type.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.kt_level_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.indicator_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.virtual_mod_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.groups.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.key_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.key_aliases.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.radio_group_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
+        ({
+            let size: usize = self.reply_type.size()
+                + self.device_id.size()
+                + self.sequence.size()
+                + self.length.size()
+                + self.which.size()
+                + self.min_key_code.size()
+                + self.max_key_code.size()
+                + self.n_types.size()
+                + self.group_names.size()
+                + self.virtual_mods.size()
+                + self.first_key.size()
+                + ::core::mem::size_of::<Card8>()
+                + self.indicators.size()
+                + ::core::mem::size_of::<Card8>()
+                + ::core::mem::size_of::<Card8>()
+                + self.n_kt_levels.size()
+                + 4
+                + self.keycodes_name.size()
+                + self.geometry_name.size()
+                + self.symbols_name.size()
+                + self.phys_symbols_name.size()
+                + self.types_name.size()
+                + self.compat_name.size()
+                + {
+                    let block_len: usize = self.type_names.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+                    block_len + pad
+                }
+                + {
+                    let block_len: usize = self.n_levels_per_type.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                    block_len + pad
+                };
+            size + buffer_pad(size, 4)
+        }) + {
+            let block_len: usize = self.kt_level_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.indicator_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.virtual_mod_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.groups.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.key_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.key_aliases.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.radio_group_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        }
     }
 }
 #[repr(transparent)]
@@ -10629,7 +10702,7 @@ impl AsByteSequence for SetNamesRequest {
         let block_len: usize = vector_as_bytes(&self.n_levels_per_type, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
+        index += buffer_pad(index, 4);
         let block_len: usize = vector_as_bytes(&self.kt_level_names, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
@@ -10657,94 +10730,95 @@ impl AsByteSequence for SetNamesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetNamesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (which, sz): (NameDetail, usize) = <NameDetail>::from_bytes(&bytes[index..])?;
+        let (which, sz): (NameDetail, usize) = <NameDetail>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_types, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_types, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_kt_levelt, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_kt_levelt, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_kt_levels, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_kt_levels, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (indicators, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (indicators, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group_names, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(&bytes[index..])?;
+        let (group_names, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len2, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (total_kt_level_names, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (total_kt_level_names, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let cond0 = (which);
         let keycodes_name: Atom = if cond0.keycodes() {
-            let (keycodes_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (keycodes_name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             keycodes_name
         } else {
             Default::default()
         };
         let geometry_name: Atom = if cond0.geometry() {
-            let (geometry_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (geometry_name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             geometry_name
         } else {
             Default::default()
         };
         let symbols_name: Atom = if cond0.symbols() {
-            let (symbols_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (symbols_name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             symbols_name
         } else {
             Default::default()
         };
         let phys_symbols_name: Atom = if cond0.phys_symbols() {
-            let (phys_symbols_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (phys_symbols_name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             phys_symbols_name
         } else {
             Default::default()
         };
         let types_name: Atom = if cond0.types() {
-            let (types_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (types_name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             types_name
         } else {
             Default::default()
         };
         let compat_name: Atom = if cond0.compat() {
-            let (compat_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (compat_name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             compat_name
         } else {
             Default::default()
         };
         let (type_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], (n_types as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (n_types as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         let (n_levels_per_type, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], (n_types as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (n_types as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
+        index += buffer_pad(index, 4);
         let (kt_level_names, block_len): (Vec<Atom>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (n_levels_per_type
                 .iter()
                 .map(|a| {
@@ -10755,27 +10829,27 @@ impl AsByteSequence for SetNamesRequest {
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         let (indicator_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], ((indicators).count_ones()) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((indicators).count_ones()) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         let (virtual_mod_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], ((virtual_mods).count_ones()) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((virtual_mods).count_ones()) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         let (groups, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], ((group_names).count_ones()) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((group_names).count_ones()) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         let (key_names, block_len): (Vec<KeyName>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
         let (key_aliases, block_len): (Vec<KeyAlias>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len2 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
         let (radio_group_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         Some((
@@ -10814,82 +10888,78 @@ impl AsByteSequence for SetNamesRequest {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.req_type.size()
-            + 1
-            + self.length.size()
-            + self.device_spec.size()
-            + self.virtual_mods.size()
-            + self.which.size()
-            + self.first_type.size()
-            + self.n_types.size()
-            + self.first_kt_levelt.size()
-            + self.n_kt_levels.size()
-            + self.indicators.size()
-            + self.group_names.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_key.size()
-            + ::core::mem::size_of::<Card8>()
-            + ::core::mem::size_of::<Card8>()
-            + 1
-            + self.total_kt_level_names.size()
-            + self.keycodes_name.size()
-            + self.geometry_name.size()
-            + self.symbols_name.size()
-            + self.phys_symbols_name.size()
-            + self.types_name.size()
-            + self.compat_name.size()
-            + {
-                let block_len: usize = self.type_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.n_levels_per_type.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
-            + 4
-            + {
-                let block_len: usize = self.kt_level_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.indicator_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.virtual_mod_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.groups.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.key_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.key_aliases.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.radio_group_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
+        ({
+            let size: usize = self.req_type.size()
+                + 1
+                + self.length.size()
+                + self.device_spec.size()
+                + self.virtual_mods.size()
+                + self.which.size()
+                + self.first_type.size()
+                + self.n_types.size()
+                + self.first_kt_levelt.size()
+                + self.n_kt_levels.size()
+                + self.indicators.size()
+                + self.group_names.size()
+                + ::core::mem::size_of::<Card8>()
+                + self.first_key.size()
+                + ::core::mem::size_of::<Card8>()
+                + ::core::mem::size_of::<Card8>()
+                + 1
+                + self.total_kt_level_names.size()
+                + self.keycodes_name.size()
+                + self.geometry_name.size()
+                + self.symbols_name.size()
+                + self.phys_symbols_name.size()
+                + self.types_name.size()
+                + self.compat_name.size()
+                + {
+                    let block_len: usize = self.type_names.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+                    block_len + pad
+                }
+                + {
+                    let block_len: usize = self.n_levels_per_type.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                    block_len + pad
+                };
+            size + buffer_pad(size, 4)
+        }) + {
+            let block_len: usize = self.kt_level_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.indicator_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.virtual_mod_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.groups.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.key_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.key_aliases.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.radio_group_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        }
     }
 }
 impl Request for SetNamesRequest {
     const OPCODE: u8 = 18;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetNames";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -10924,23 +10994,25 @@ impl AsByteSequence for PerClientFlagsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PerClientFlagsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (change, sz): (PerClientFlag, usize) = <PerClientFlag>::from_bytes(&bytes[index..])?;
+        let (change, sz): (PerClientFlag, usize) =
+            <PerClientFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value, sz): (PerClientFlag, usize) = <PerClientFlag>::from_bytes(&bytes[index..])?;
+        let (value, sz): (PerClientFlag, usize) = <PerClientFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ctrls_to_change, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(&bytes[index..])?;
+        let (ctrls_to_change, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (auto_ctrls, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(&bytes[index..])?;
+        let (auto_ctrls, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (auto_ctrls_values, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(&bytes[index..])?;
+        let (auto_ctrls_values, sz): (BoolCtrl, usize) =
+            <BoolCtrl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PerClientFlagsRequest {
@@ -10974,6 +11046,7 @@ impl Request for PerClientFlagsRequest {
     const OPCODE: u8 = 21;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PerClientFlags";
     type Reply = PerClientFlagsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -11007,21 +11080,23 @@ impl AsByteSequence for PerClientFlagsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PerClientFlagsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (supported, sz): (PerClientFlag, usize) = <PerClientFlag>::from_bytes(&bytes[index..])?;
+        let (supported, sz): (PerClientFlag, usize) =
+            <PerClientFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value, sz): (PerClientFlag, usize) = <PerClientFlag>::from_bytes(&bytes[index..])?;
+        let (value, sz): (PerClientFlag, usize) = <PerClientFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (auto_ctrls, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(&bytes[index..])?;
+        let (auto_ctrls, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (auto_ctrls_values, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(&bytes[index..])?;
+        let (auto_ctrls_values, sz): (BoolCtrl, usize) =
+            <BoolCtrl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 8;
         Some((
@@ -11231,14 +11306,14 @@ impl AsByteSequence for ListComponentsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListComponentsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_names, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (max_names, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ListComponentsRequest {
@@ -11263,6 +11338,7 @@ impl Request for ListComponentsRequest {
     const OPCODE: u8 = 22;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListComponents";
     type Reply = ListComponentsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -11320,51 +11396,51 @@ impl AsByteSequence for ListComponentsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListComponentsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len3, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len3, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len4, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len4, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len5, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len5, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (extra, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (extra, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 10;
         let (keymaps, block_len): (Vec<Listing>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
         let (keycodes, block_len): (Vec<Listing>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
         let (types, block_len): (Vec<Listing>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len2 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
         let (compat_maps, block_len): (Vec<Listing>, usize) =
-            vector_from_bytes(&bytes[index..], len3 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len3 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
         let (symbols, block_len): (Vec<Listing>, usize) =
-            vector_from_bytes(&bytes[index..], len4 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len4 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
         let (geometries, block_len): (Vec<Listing>, usize) =
-            vector_from_bytes(&bytes[index..], len5 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len5 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Listing>());
         Some((
@@ -11458,18 +11534,18 @@ impl AsByteSequence for GetKbdByNameRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetKbdByNameRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (need, sz): (GbnDetail, usize) = <GbnDetail>::from_bytes(&bytes[index..])?;
+        let (need, sz): (GbnDetail, usize) = <GbnDetail>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (want, sz): (GbnDetail, usize) = <GbnDetail>::from_bytes(&bytes[index..])?;
+        let (want, sz): (GbnDetail, usize) = <GbnDetail>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (load, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (load, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -11500,6 +11576,7 @@ impl Request for GetKbdByNameRequest {
     const OPCODE: u8 = 23;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetKbdByName";
     type Reply = GetKbdByNameReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -11708,7 +11785,7 @@ impl AsByteSequence for GetKbdByNameReply {
         let block_len: usize = vector_as_bytes(&self.acts_rtrn_count, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
+        index += buffer_pad(index, 4);
         let block_len: usize = vector_as_bytes(&self.acts_rtrn_acts, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Action>());
@@ -11718,12 +11795,15 @@ impl AsByteSequence for GetKbdByNameReply {
         let block_len: usize = vector_as_bytes(&self.vmods_rtrn, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+        index += buffer_pad(index, 4);
         let block_len: usize = vector_as_bytes(&self.explicit_rtrn, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
+        index += buffer_pad(index, 4);
         let block_len: usize = vector_as_bytes(&self.modmap_rtrn, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
+        index += buffer_pad(index, 4);
         let block_len: usize = vector_as_bytes(&self.vmodmap_rtrn, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
@@ -11742,6 +11822,7 @@ impl AsByteSequence for GetKbdByNameReply {
         if cond0.compat_map() {
             index += self.groups_rtrn.as_bytes(&mut bytes[index..]);
         }
+        index += 1;
         if cond0.compat_map() {
             index += self.first_si_rtrn.as_bytes(&mut bytes[index..]);
         }
@@ -11749,6 +11830,7 @@ impl AsByteSequence for GetKbdByNameReply {
         if cond0.compat_map() {
             index += self.n_total_si.as_bytes(&mut bytes[index..]);
         }
+        index += 16;
         let block_len: usize = vector_as_bytes(&self.si_rtrn, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<SymInterpret>());
@@ -11820,6 +11902,7 @@ impl AsByteSequence for GetKbdByNameReply {
         if cond0.other_names() {
             index += self.n_kt_levels.as_bytes(&mut bytes[index..]);
         }
+        index += 4;
         if cond0.other_names() {
             index += self.keycodes_name.as_bytes(&mut bytes[index..]);
         }
@@ -11844,6 +11927,7 @@ impl AsByteSequence for GetKbdByNameReply {
         let block_len: usize = vector_as_bytes(&self.n_levels_per_type, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+        index += buffer_pad(index, 4);
         let block_len: usize = vector_as_bytes(&self.kt_level_names, &mut bytes[index..]);
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
@@ -11883,6 +11967,7 @@ impl AsByteSequence for GetKbdByNameReply {
         if cond0.geometry() {
             index += self.geometry_found.as_bytes(&mut bytes[index..]);
         }
+        index += 1;
         if cond0.geometry() {
             index += self.width_mm.as_bytes(&mut bytes[index..]);
         }
@@ -11922,51 +12007,51 @@ impl AsByteSequence for GetKbdByNameReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetKbdByNameReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (loaded, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (loaded, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (new_keyboard, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (new_keyboard, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (found, sz): (GbnDetail, usize) = <GbnDetail>::from_bytes(&bytes[index..])?;
+        let (found, sz): (GbnDetail, usize) = <GbnDetail>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (reported, sz): (GbnDetail, usize) = <GbnDetail>::from_bytes(&bytes[index..])?;
+        let (reported, sz): (GbnDetail, usize) = <GbnDetail>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         let cond0 = (reported);
         let getmap_type: Card8 = if cond0.server_symbols() {
-            let (getmap_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (getmap_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             getmap_type
         } else {
             Default::default()
         };
         let type_device_id: Card8 = if cond0.server_symbols() {
-            let (type_device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (type_device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             type_device_id
         } else {
             Default::default()
         };
         let getmap_sequence: Card16 = if cond0.server_symbols() {
-            let (getmap_sequence, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (getmap_sequence, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             getmap_sequence
         } else {
             Default::default()
         };
         let getmap_length: Card32 = if cond0.server_symbols() {
-            let (getmap_length, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (getmap_length, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             getmap_length
         } else {
@@ -11974,254 +12059,267 @@ impl AsByteSequence for GetKbdByNameReply {
         };
         index += 2;
         let type_min_key_code: Keycode = if cond0.server_symbols() {
-            let (type_min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+            let (type_min_key_code, sz): (Keycode, usize) =
+                <Keycode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             type_min_key_code
         } else {
             Default::default()
         };
         let type_max_key_code: Keycode = if cond0.server_symbols() {
-            let (type_max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+            let (type_max_key_code, sz): (Keycode, usize) =
+                <Keycode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             type_max_key_code
         } else {
             Default::default()
         };
         let present: MapPart = if cond0.server_symbols() {
-            let (present, sz): (MapPart, usize) = <MapPart>::from_bytes(&bytes[index..])?;
+            let (present, sz): (MapPart, usize) = <MapPart>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             present
         } else {
             Default::default()
         };
         let first_type: Card8 = if cond0.server_symbols() {
-            let (first_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (first_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             first_type
         } else {
             Default::default()
         };
         let n_types: Card8 = if cond0.server_symbols() {
-            let (n_types, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (n_types, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             n_types
         } else {
             Default::default()
         };
         let total_types: Card8 = if cond0.server_symbols() {
-            let (total_types, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (total_types, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             total_types
         } else {
             Default::default()
         };
         let first_key_sym: Keycode = if cond0.server_symbols() {
-            let (first_key_sym, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+            let (first_key_sym, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             first_key_sym
         } else {
             Default::default()
         };
         let total_syms: Card16 = if cond0.server_symbols() {
-            let (total_syms, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (total_syms, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             total_syms
         } else {
             Default::default()
         };
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let first_key_action: Keycode = if cond0.server_symbols() {
-            let (first_key_action, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+            let (first_key_action, sz): (Keycode, usize) =
+                <Keycode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             first_key_action
         } else {
             Default::default()
         };
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len2, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let first_key_behavior: Keycode = if cond0.server_symbols() {
             let (first_key_behavior, sz): (Keycode, usize) =
-                <Keycode>::from_bytes(&bytes[index..])?;
+                <Keycode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             first_key_behavior
         } else {
             Default::default()
         };
         let n_key_behaviors: Card8 = if cond0.server_symbols() {
-            let (n_key_behaviors, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (n_key_behaviors, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             n_key_behaviors
         } else {
             Default::default()
         };
-        let (len3, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len3, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let first_key_explicit: Keycode = if cond0.server_symbols() {
             let (first_key_explicit, sz): (Keycode, usize) =
-                <Keycode>::from_bytes(&bytes[index..])?;
+                <Keycode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             first_key_explicit
         } else {
             Default::default()
         };
         let n_key_explicit: Card8 = if cond0.server_symbols() {
-            let (n_key_explicit, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (n_key_explicit, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             n_key_explicit
         } else {
             Default::default()
         };
-        let (len4, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len4, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let first_mod_map_key: Keycode = if cond0.server_symbols() {
-            let (first_mod_map_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+            let (first_mod_map_key, sz): (Keycode, usize) =
+                <Keycode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             first_mod_map_key
         } else {
             Default::default()
         };
         let n_mod_map_keys: Card8 = if cond0.server_symbols() {
-            let (n_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (n_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             n_mod_map_keys
         } else {
             Default::default()
         };
-        let (len5, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len5, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let first_v_mod_map_key: Keycode = if cond0.server_symbols() {
             let (first_v_mod_map_key, sz): (Keycode, usize) =
-                <Keycode>::from_bytes(&bytes[index..])?;
+                <Keycode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             first_v_mod_map_key
         } else {
             Default::default()
         };
         let n_v_mod_map_keys: Card8 = if cond0.server_symbols() {
-            let (n_v_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (n_v_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             n_v_mod_map_keys
         } else {
             Default::default()
         };
-        let (len6, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len6, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         let virtual_mods: VMod = if cond0.server_symbols() {
-            let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+            let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             virtual_mods
         } else {
             Default::default()
         };
         let (types_rtrn, block_len): (Vec<KeyType>, usize) =
-            vector_from_bytes(&bytes[index..], (n_types as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (n_types as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KeyType>());
         let (syms_rtrn, block_len): (Vec<KeySymMap>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
         let (acts_rtrn_count, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len2 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-        index += 4;
+        index += buffer_pad(index, 4);
         let (acts_rtrn_acts, block_len): (Vec<Action>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Action>());
         let (behaviors_rtrn, block_len): (Vec<SetBehavior>, usize) =
-            vector_from_bytes(&bytes[index..], len3 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len3 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
         let (vmods_rtrn, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], ((virtual_mods).count_ones()) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((virtual_mods).count_ones()) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+        index += buffer_pad(index, 4);
         let (explicit_rtrn, block_len): (Vec<SetExplicit>, usize) =
-            vector_from_bytes(&bytes[index..], len4 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len4 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
+        index += buffer_pad(index, 4);
         let (modmap_rtrn, block_len): (Vec<KeyModMap>, usize) =
-            vector_from_bytes(&bytes[index..], len5 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len5 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
+        index += buffer_pad(index, 4);
         let (vmodmap_rtrn, block_len): (Vec<KeyVModMap>, usize) =
-            vector_from_bytes(&bytes[index..], len6 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len6 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
         let compatmap_type: Card8 = if cond0.compat_map() {
-            let (compatmap_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (compatmap_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             compatmap_type
         } else {
             Default::default()
         };
         let compat_device_id: Card8 = if cond0.compat_map() {
-            let (compat_device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (compat_device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             compat_device_id
         } else {
             Default::default()
         };
         let compatmap_sequence: Card16 = if cond0.compat_map() {
-            let (compatmap_sequence, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (compatmap_sequence, sz): (Card16, usize) =
+                <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             compatmap_sequence
         } else {
             Default::default()
         };
         let compatmap_length: Card32 = if cond0.compat_map() {
-            let (compatmap_length, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (compatmap_length, sz): (Card32, usize) =
+                <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             compatmap_length
         } else {
             Default::default()
         };
         let groups_rtrn: SetOfGroup = if cond0.compat_map() {
-            let (groups_rtrn, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(&bytes[index..])?;
+            let (groups_rtrn, sz): (SetOfGroup, usize) =
+                <SetOfGroup>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             groups_rtrn
         } else {
             Default::default()
         };
+        index += 1;
         let first_si_rtrn: Card16 = if cond0.compat_map() {
-            let (first_si_rtrn, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (first_si_rtrn, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             first_si_rtrn
         } else {
             Default::default()
         };
-        let (len7, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len7, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let n_total_si: Card16 = if cond0.compat_map() {
-            let (n_total_si, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (n_total_si, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             n_total_si
         } else {
             Default::default()
         };
+        index += 16;
         let (si_rtrn, block_len): (Vec<SymInterpret>, usize) =
-            vector_from_bytes(&bytes[index..], len7 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len7 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<SymInterpret>());
         let (group_rtrn, block_len): (Vec<ModDef>, usize) =
-            vector_from_bytes(&bytes[index..], ((groups_rtrn).count_ones()) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((groups_rtrn).count_ones()) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ModDef>());
         let indicatormap_type: Card8 = if cond0.indicator_maps() {
-            let (indicatormap_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (indicatormap_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             indicatormap_type
         } else {
             Default::default()
         };
         let indicator_device_id: Card8 = if cond0.indicator_maps() {
-            let (indicator_device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (indicator_device_id, sz): (Card8, usize) =
+                <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             indicator_device_id
         } else {
@@ -12229,189 +12327,196 @@ impl AsByteSequence for GetKbdByNameReply {
         };
         let indicatormap_sequence: Card16 = if cond0.indicator_maps() {
             let (indicatormap_sequence, sz): (Card16, usize) =
-                <Card16>::from_bytes(&bytes[index..])?;
+                <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             indicatormap_sequence
         } else {
             Default::default()
         };
         let indicatormap_length: Card32 = if cond0.indicator_maps() {
-            let (indicatormap_length, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (indicatormap_length, sz): (Card32, usize) =
+                <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             indicatormap_length
         } else {
             Default::default()
         };
         let which: Card32 = if cond0.indicator_maps() {
-            let (which, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (which, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             which
         } else {
             Default::default()
         };
         let real_indicators: Card32 = if cond0.indicator_maps() {
-            let (real_indicators, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (real_indicators, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             real_indicators
         } else {
             Default::default()
         };
-        let (len8, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len8, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 15;
         let (maps, block_len): (Vec<IndicatorMap>, usize) =
-            vector_from_bytes(&bytes[index..], len8 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len8 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
         let keyname_type: Card8 = if cond0.other_names() {
-            let (keyname_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (keyname_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             keyname_type
         } else {
             Default::default()
         };
         let key_device_id: Card8 = if cond0.other_names() {
-            let (key_device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (key_device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             key_device_id
         } else {
             Default::default()
         };
         let keyname_sequence: Card16 = if cond0.other_names() {
-            let (keyname_sequence, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (keyname_sequence, sz): (Card16, usize) =
+                <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             keyname_sequence
         } else {
             Default::default()
         };
         let keyname_length: Card32 = if cond0.other_names() {
-            let (keyname_length, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (keyname_length, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             keyname_length
         } else {
             Default::default()
         };
         let which_: NameDetail = if cond0.other_names() {
-            let (which_, sz): (NameDetail, usize) = <NameDetail>::from_bytes(&bytes[index..])?;
+            let (which_, sz): (NameDetail, usize) = <NameDetail>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             which_
         } else {
             Default::default()
         };
         let key_min_key_code: Keycode = if cond0.other_names() {
-            let (key_min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+            let (key_min_key_code, sz): (Keycode, usize) =
+                <Keycode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             key_min_key_code
         } else {
             Default::default()
         };
         let key_max_key_code: Keycode = if cond0.other_names() {
-            let (key_max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+            let (key_max_key_code, sz): (Keycode, usize) =
+                <Keycode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             key_max_key_code
         } else {
             Default::default()
         };
         let n_types_: Card8 = if cond0.other_names() {
-            let (n_types_, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (n_types_, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             n_types_
         } else {
             Default::default()
         };
         let group_names: SetOfGroup = if cond0.other_names() {
-            let (group_names, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(&bytes[index..])?;
+            let (group_names, sz): (SetOfGroup, usize) =
+                <SetOfGroup>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             group_names
         } else {
             Default::default()
         };
         let virtual_mods_: VMod = if cond0.other_names() {
-            let (virtual_mods_, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+            let (virtual_mods_, sz): (VMod, usize) = <VMod>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             virtual_mods_
         } else {
             Default::default()
         };
         let first_key: Keycode = if cond0.other_names() {
-            let (first_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+            let (first_key, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             first_key
         } else {
             Default::default()
         };
-        let (len9, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len9, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let indicators: Card32 = if cond0.other_names() {
-            let (indicators, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (indicators, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             indicators
         } else {
             Default::default()
         };
-        let (len10, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len10, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len11, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len11, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let n_kt_levels: Card16 = if cond0.other_names() {
-            let (n_kt_levels, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (n_kt_levels, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             n_kt_levels
         } else {
             Default::default()
         };
+        index += 4;
         let keycodes_name: Atom = if cond0.other_names() {
-            let (keycodes_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (keycodes_name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             keycodes_name
         } else {
             Default::default()
         };
         let geometry_name: Atom = if cond0.other_names() {
-            let (geometry_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (geometry_name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             geometry_name
         } else {
             Default::default()
         };
         let symbols_name: Atom = if cond0.other_names() {
-            let (symbols_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (symbols_name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             symbols_name
         } else {
             Default::default()
         };
         let phys_symbols_name: Atom = if cond0.other_names() {
-            let (phys_symbols_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (phys_symbols_name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             phys_symbols_name
         } else {
             Default::default()
         };
         let types_name: Atom = if cond0.other_names() {
-            let (types_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (types_name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             types_name
         } else {
             Default::default()
         };
         let compat_name: Atom = if cond0.other_names() {
-            let (compat_name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (compat_name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             compat_name
         } else {
             Default::default()
         };
         let (type_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], (n_types as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (n_types as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         let (n_levels_per_type, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], (n_types as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (n_types as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+        index += buffer_pad(index, 4);
         let (kt_level_names, block_len): (Vec<Atom>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (n_levels_per_type
                 .iter()
                 .map(|a| {
@@ -12422,136 +12527,139 @@ impl AsByteSequence for GetKbdByNameReply {
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         let (indicator_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], ((indicators).count_ones()) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((indicators).count_ones()) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         let (virtual_mod_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], ((virtual_mods).count_ones()) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((virtual_mods).count_ones()) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         let (groups, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], ((group_names).count_ones()) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((group_names).count_ones()) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         let (key_names, block_len): (Vec<KeyName>, usize) =
-            vector_from_bytes(&bytes[index..], len9 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len9 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
         let (key_aliases, block_len): (Vec<KeyAlias>, usize) =
-            vector_from_bytes(&bytes[index..], len11 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len11 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
         let (radio_group_names, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], len10 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len10 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         let geometry_type: Card8 = if cond0.geometry() {
-            let (geometry_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (geometry_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             geometry_type
         } else {
             Default::default()
         };
         let geometry_device_id: Card8 = if cond0.geometry() {
-            let (geometry_device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (geometry_device_id, sz): (Card8, usize) =
+                <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             geometry_device_id
         } else {
             Default::default()
         };
         let geometry_sequence: Card16 = if cond0.geometry() {
-            let (geometry_sequence, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (geometry_sequence, sz): (Card16, usize) =
+                <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             geometry_sequence
         } else {
             Default::default()
         };
         let geometry_length: Card32 = if cond0.geometry() {
-            let (geometry_length, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (geometry_length, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             geometry_length
         } else {
             Default::default()
         };
         let name: Atom = if cond0.geometry() {
-            let (name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+            let (name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             name
         } else {
             Default::default()
         };
         let geometry_found: bool = if cond0.geometry() {
-            let (geometry_found, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+            let (geometry_found, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             geometry_found
         } else {
             Default::default()
         };
+        index += 1;
         let width_mm: Card16 = if cond0.geometry() {
-            let (width_mm, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (width_mm, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             width_mm
         } else {
             Default::default()
         };
         let height_mm: Card16 = if cond0.geometry() {
-            let (height_mm, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (height_mm, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             height_mm
         } else {
             Default::default()
         };
         let n_properties: Card16 = if cond0.geometry() {
-            let (n_properties, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (n_properties, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             n_properties
         } else {
             Default::default()
         };
         let n_colors: Card16 = if cond0.geometry() {
-            let (n_colors, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (n_colors, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             n_colors
         } else {
             Default::default()
         };
         let n_shapes: Card16 = if cond0.geometry() {
-            let (n_shapes, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (n_shapes, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             n_shapes
         } else {
             Default::default()
         };
         let n_sections: Card16 = if cond0.geometry() {
-            let (n_sections, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (n_sections, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             n_sections
         } else {
             Default::default()
         };
         let n_doodads: Card16 = if cond0.geometry() {
-            let (n_doodads, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (n_doodads, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             n_doodads
         } else {
             Default::default()
         };
         let n_key_aliases: Card16 = if cond0.geometry() {
-            let (n_key_aliases, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+            let (n_key_aliases, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             n_key_aliases
         } else {
             Default::default()
         };
         let base_color_ndx: Card8 = if cond0.geometry() {
-            let (base_color_ndx, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (base_color_ndx, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             base_color_ndx
         } else {
             Default::default()
         };
         let label_color_ndx: Card8 = if cond0.geometry() {
-            let (label_color_ndx, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+            let (label_color_ndx, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             label_color_ndx
         } else {
@@ -12559,7 +12667,7 @@ impl AsByteSequence for GetKbdByNameReply {
         };
         let label_font: CountedString16 = if cond0.geometry() {
             let (label_font, sz): (CountedString16, usize) =
-                <CountedString16>::from_bytes(&bytes[index..])?;
+                <CountedString16>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             label_font
         } else {
@@ -12675,198 +12783,213 @@ impl AsByteSequence for GetKbdByNameReply {
     }
     #[inline]
     fn size(&self) -> usize {
-        self.reply_type.size()
-            + self.device_id.size()
-            + self.sequence.size()
-            + self.length.size()
-            + self.min_key_code.size()
-            + self.max_key_code.size()
-            + self.loaded.size()
-            + self.new_keyboard.size()
-            + self.found.size()
-            + self.reported.size()
-            + 16
-            + self.getmap_type.size()
-            + self.type_device_id.size()
-            + self.getmap_sequence.size()
-            + self.getmap_length.size()
-            + 2
-            + self.type_min_key_code.size()
-            + self.type_max_key_code.size()
-            + self.present.size()
-            + self.first_type.size()
-            + self.n_types.size()
-            + self.total_types.size()
-            + self.first_key_sym.size()
-            + self.total_syms.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_key_action.size()
-            + ::core::mem::size_of::<Card16>()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_key_behavior.size()
-            + self.n_key_behaviors.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_key_explicit.size()
-            + self.n_key_explicit.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_mod_map_key.size()
-            + self.n_mod_map_keys.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.first_v_mod_map_key.size()
-            + self.n_v_mod_map_keys.size()
-            + ::core::mem::size_of::<Card8>()
-            + 1
-            + self.virtual_mods.size()
-            + {
-                let block_len: usize = self.types_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyType>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.syms_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.acts_rtrn_count.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
-            + 4
-            + {
-                let block_len: usize = self.acts_rtrn_acts.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Action>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.behaviors_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.vmods_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.explicit_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.modmap_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
-                block_len + pad
-            }
-            + {
+        ({
+            let size: usize = ({
+                let size: usize = ({
+                    let size: usize = ({
+                        let size: usize = ({
+                            let size: usize = self.reply_type.size()
+                                + self.device_id.size()
+                                + self.sequence.size()
+                                + self.length.size()
+                                + self.min_key_code.size()
+                                + self.max_key_code.size()
+                                + self.loaded.size()
+                                + self.new_keyboard.size()
+                                + self.found.size()
+                                + self.reported.size()
+                                + 16
+                                + self.getmap_type.size()
+                                + self.type_device_id.size()
+                                + self.getmap_sequence.size()
+                                + self.getmap_length.size()
+                                + 2
+                                + self.type_min_key_code.size()
+                                + self.type_max_key_code.size()
+                                + self.present.size()
+                                + self.first_type.size()
+                                + self.n_types.size()
+                                + self.total_types.size()
+                                + self.first_key_sym.size()
+                                + self.total_syms.size()
+                                + ::core::mem::size_of::<Card8>()
+                                + self.first_key_action.size()
+                                + ::core::mem::size_of::<Card16>()
+                                + ::core::mem::size_of::<Card8>()
+                                + self.first_key_behavior.size()
+                                + self.n_key_behaviors.size()
+                                + ::core::mem::size_of::<Card8>()
+                                + self.first_key_explicit.size()
+                                + self.n_key_explicit.size()
+                                + ::core::mem::size_of::<Card8>()
+                                + self.first_mod_map_key.size()
+                                + self.n_mod_map_keys.size()
+                                + ::core::mem::size_of::<Card8>()
+                                + self.first_v_mod_map_key.size()
+                                + self.n_v_mod_map_keys.size()
+                                + ::core::mem::size_of::<Card8>()
+                                + 1
+                                + self.virtual_mods.size()
+                                + {
+                                    let block_len: usize =
+                                        self.types_rtrn.iter().map(|i| i.size()).sum();
+                                    let pad: usize =
+                                        buffer_pad(block_len, ::core::mem::align_of::<KeyType>());
+                                    block_len + pad
+                                }
+                                + {
+                                    let block_len: usize =
+                                        self.syms_rtrn.iter().map(|i| i.size()).sum();
+                                    let pad: usize =
+                                        buffer_pad(block_len, ::core::mem::align_of::<KeySymMap>());
+                                    block_len + pad
+                                }
+                                + {
+                                    let block_len: usize =
+                                        self.acts_rtrn_count.iter().map(|i| i.size()).sum();
+                                    let pad: usize =
+                                        buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                                    block_len + pad
+                                };
+                            size + buffer_pad(size, 4)
+                        }) + {
+                            let block_len: usize =
+                                self.acts_rtrn_acts.iter().map(|i| i.size()).sum();
+                            let pad: usize =
+                                buffer_pad(block_len, ::core::mem::align_of::<Action>());
+                            block_len + pad
+                        } + {
+                            let block_len: usize =
+                                self.behaviors_rtrn.iter().map(|i| i.size()).sum();
+                            let pad: usize =
+                                buffer_pad(block_len, ::core::mem::align_of::<SetBehavior>());
+                            block_len + pad
+                        } + {
+                            let block_len: usize = self.vmods_rtrn.iter().map(|i| i.size()).sum();
+                            let pad: usize =
+                                buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                            block_len + pad
+                        };
+                        size + buffer_pad(size, 4)
+                    }) + {
+                        let block_len: usize = self.explicit_rtrn.iter().map(|i| i.size()).sum();
+                        let pad: usize =
+                            buffer_pad(block_len, ::core::mem::align_of::<SetExplicit>());
+                        block_len + pad
+                    };
+                    size + buffer_pad(size, 4)
+                }) + {
+                    let block_len: usize = self.modmap_rtrn.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyModMap>());
+                    block_len + pad
+                };
+                size + buffer_pad(size, 4)
+            }) + {
                 let block_len: usize = self.vmodmap_rtrn.iter().map(|i| i.size()).sum();
                 let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyVModMap>());
                 block_len + pad
-            }
-            + self.compatmap_type.size()
-            + self.compat_device_id.size()
-            + self.compatmap_sequence.size()
-            + self.compatmap_length.size()
-            + self.groups_rtrn.size()
-            + self.first_si_rtrn.size()
-            + ::core::mem::size_of::<Card16>()
-            + self.n_total_si.size()
-            + {
-                let block_len: usize = self.si_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SymInterpret>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.group_rtrn.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ModDef>());
-                block_len + pad
-            }
-            + self.indicatormap_type.size()
-            + self.indicator_device_id.size()
-            + self.indicatormap_sequence.size()
-            + self.indicatormap_length.size()
-            + self.which.size()
-            + self.real_indicators.size()
-            + ::core::mem::size_of::<Card8>()
-            + 15
-            + {
-                let block_len: usize = self.maps.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
-                block_len + pad
-            }
-            + self.keyname_type.size()
-            + self.key_device_id.size()
-            + self.keyname_sequence.size()
-            + self.keyname_length.size()
-            + self.which_.size()
-            + self.key_min_key_code.size()
-            + self.key_max_key_code.size()
-            + self.n_types_.size()
-            + self.group_names.size()
-            + self.virtual_mods_.size()
-            + self.first_key.size()
-            + ::core::mem::size_of::<Card8>()
-            + self.indicators.size()
-            + ::core::mem::size_of::<Card8>()
-            + ::core::mem::size_of::<Card8>()
-            + self.n_kt_levels.size()
-            + self.keycodes_name.size()
-            + self.geometry_name.size()
-            + self.symbols_name.size()
-            + self.phys_symbols_name.size()
-            + self.types_name.size()
-            + self.compat_name.size()
-            + {
-                let block_len: usize = self.type_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.n_levels_per_type.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.kt_level_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.indicator_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.virtual_mod_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.groups.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.key_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.key_aliases.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
-                block_len + pad
-            }
-            + {
-                let block_len: usize = self.radio_group_names.iter().map(|i| i.size()).sum();
-                let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
-                block_len + pad
-            }
-            + self.geometry_type.size()
+            } + self.compatmap_type.size()
+                + self.compat_device_id.size()
+                + self.compatmap_sequence.size()
+                + self.compatmap_length.size()
+                + self.groups_rtrn.size()
+                + 1
+                + self.first_si_rtrn.size()
+                + ::core::mem::size_of::<Card16>()
+                + self.n_total_si.size()
+                + 16
+                + {
+                    let block_len: usize = self.si_rtrn.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<SymInterpret>());
+                    block_len + pad
+                }
+                + {
+                    let block_len: usize = self.group_rtrn.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<ModDef>());
+                    block_len + pad
+                }
+                + self.indicatormap_type.size()
+                + self.indicator_device_id.size()
+                + self.indicatormap_sequence.size()
+                + self.indicatormap_length.size()
+                + self.which.size()
+                + self.real_indicators.size()
+                + ::core::mem::size_of::<Card8>()
+                + 15
+                + {
+                    let block_len: usize = self.maps.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<IndicatorMap>());
+                    block_len + pad
+                }
+                + self.keyname_type.size()
+                + self.key_device_id.size()
+                + self.keyname_sequence.size()
+                + self.keyname_length.size()
+                + self.which_.size()
+                + self.key_min_key_code.size()
+                + self.key_max_key_code.size()
+                + self.n_types_.size()
+                + self.group_names.size()
+                + self.virtual_mods_.size()
+                + self.first_key.size()
+                + ::core::mem::size_of::<Card8>()
+                + self.indicators.size()
+                + ::core::mem::size_of::<Card8>()
+                + ::core::mem::size_of::<Card8>()
+                + self.n_kt_levels.size()
+                + 4
+                + self.keycodes_name.size()
+                + self.geometry_name.size()
+                + self.symbols_name.size()
+                + self.phys_symbols_name.size()
+                + self.types_name.size()
+                + self.compat_name.size()
+                + {
+                    let block_len: usize = self.type_names.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+                    block_len + pad
+                }
+                + {
+                    let block_len: usize = self.n_levels_per_type.iter().map(|i| i.size()).sum();
+                    let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Card8>());
+                    block_len + pad
+                };
+            size + buffer_pad(size, 4)
+        }) + {
+            let block_len: usize = self.kt_level_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.indicator_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.virtual_mod_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.groups.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.key_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyName>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.key_aliases.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<KeyAlias>());
+            block_len + pad
+        } + {
+            let block_len: usize = self.radio_group_names.iter().map(|i| i.size()).sum();
+            let pad: usize = buffer_pad(block_len, ::core::mem::align_of::<Atom>());
+            block_len + pad
+        } + self.geometry_type.size()
             + self.geometry_device_id.size()
             + self.geometry_sequence.size()
             + self.geometry_length.size()
             + self.name.size()
             + self.geometry_found.size()
+            + 1
             + self.width_mm.size()
             + self.height_mm.size()
             + self.n_properties.size()
@@ -13125,25 +13248,25 @@ impl AsByteSequence for GetDeviceInfoRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDeviceInfoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (wanted, sz): (XiFeature, usize) = <XiFeature>::from_bytes(&bytes[index..])?;
+        let (wanted, sz): (XiFeature, usize) = <XiFeature>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (all_buttons, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (all_buttons, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_button, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_button, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_buttons, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_buttons, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (led_class, sz): (LedClass, usize) = <LedClass>::from_bytes(&bytes[index..])?;
+        let (led_class, sz): (LedClass, usize) = <LedClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (led_id, sz): (IdSpec, usize) = <IdSpec>::from_bytes(&bytes[index..])?;
+        let (led_id, sz): (IdSpec, usize) = <IdSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetDeviceInfoRequest {
@@ -13179,6 +13302,7 @@ impl Request for GetDeviceInfoRequest {
     const OPCODE: u8 = 24;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetDeviceInfo";
     type Reply = GetDeviceInfoReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -13241,53 +13365,53 @@ impl AsByteSequence for GetDeviceInfoReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetDeviceInfoReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (present, sz): (XiFeature, usize) = <XiFeature>::from_bytes(&bytes[index..])?;
+        let (present, sz): (XiFeature, usize) = <XiFeature>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (supported, sz): (XiFeature, usize) = <XiFeature>::from_bytes(&bytes[index..])?;
+        let (supported, sz): (XiFeature, usize) = <XiFeature>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (unsupported, sz): (XiFeature, usize) = <XiFeature>::from_bytes(&bytes[index..])?;
+        let (unsupported, sz): (XiFeature, usize) = <XiFeature>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_btn_wanted, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_btn_wanted, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_btns_wanted, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_btns_wanted, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_btn_rtrn, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_btn_rtrn, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (total_btns, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (total_btns, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (has_own_state, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (has_own_state, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dflt_kbd_fb, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (dflt_kbd_fb, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dflt_led_fb, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (dflt_led_fb, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (dev_type, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (dev_type, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (name, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len2 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, 4);
         let (btn_actions, block_len): (Vec<Action>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Action>());
         let (leds, block_len): (Vec<DeviceLedInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<DeviceLedInfo>());
         Some((
@@ -13544,27 +13668,27 @@ impl AsByteSequence for SetDeviceInfoRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetDeviceInfoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(&bytes[index..])?;
+        let (device_spec, sz): (DeviceSpec, usize) = <DeviceSpec>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_btn, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_btn, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (change, sz): (XiFeature, usize) = <XiFeature>::from_bytes(&bytes[index..])?;
+        let (change, sz): (XiFeature, usize) = <XiFeature>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (btn_actions, block_len): (Vec<Action>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Action>());
         let (leds, block_len): (Vec<DeviceLedInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<DeviceLedInfo>());
         Some((
@@ -13606,6 +13730,7 @@ impl Request for SetDeviceInfoRequest {
     const OPCODE: u8 = 25;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetDeviceInfo";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -13641,24 +13766,24 @@ impl AsByteSequence for SetDebuggingFlagsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetDebuggingFlagsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (affect_flags, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (affect_flags, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (affect_ctrls, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (affect_ctrls, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ctrls, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (ctrls, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (message, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
         Some((
@@ -13696,6 +13821,7 @@ impl Request for SetDebuggingFlagsRequest {
     const OPCODE: u8 = 101;
     const EXTENSION: Option<&'static str> = Some("XKEYBOARD");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetDebuggingFlags";
     type Reply = SetDebuggingFlagsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -13728,20 +13854,20 @@ impl AsByteSequence for SetDebuggingFlagsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetDebuggingFlagsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (current_flags, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (current_flags, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (current_ctrls, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (current_ctrls, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (supported_flags, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (supported_flags, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (supported_ctrls, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (supported_ctrls, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 8;
         Some((
@@ -15087,21 +15213,21 @@ impl AsByteSequence for KeyboardError {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing KeyboardError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (major_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (value, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 21;
         Some((
@@ -15167,23 +15293,23 @@ impl AsByteSequence for AccessXNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AccessXNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detailt, sz): (AxnDetail, usize) = <AxnDetail>::from_bytes(&bytes[index..])?;
+        let (detailt, sz): (AxnDetail, usize) = <AxnDetail>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (slow_keys_delay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (slow_keys_delay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (debounce_delay, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (debounce_delay, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         Some((
@@ -15255,27 +15381,27 @@ impl AsByteSequence for ActionMessageEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ActionMessageEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (press, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (press, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (key_event_follows, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (key_event_follows, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group, sz): (Group, usize) = <Group>::from_bytes(&bytes[index..])?;
+        let (group, sz): (Group, usize) = <Group>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (message, sz): ([String8; 8], usize) = <[String8; 8]>::from_bytes(&bytes[index..])?;
+        let (message, sz): ([String8; 8], usize) = <[String8; 8]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 10;
         Some((
@@ -15355,32 +15481,32 @@ impl AsByteSequence for BellNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing BellNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (bell_class, sz): (BellClassResult, usize) =
-            <BellClassResult>::from_bytes(&bytes[index..])?;
+            <BellClassResult>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bell_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (bell_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (percent, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (percent, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pitch, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (pitch, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (duration, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (duration, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_only, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (event_only, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 7;
         Some((
@@ -15456,23 +15582,24 @@ impl AsByteSequence for CompatMapNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CompatMapNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (changed_groups, sz): (SetOfGroup, usize) = <SetOfGroup>::from_bytes(&bytes[index..])?;
+        let (changed_groups, sz): (SetOfGroup, usize) =
+            <SetOfGroup>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_si, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (first_si, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_si, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (n_si, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_total_si, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (n_total_si, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         Some((
@@ -15549,33 +15676,34 @@ impl AsByteSequence for ControlsNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ControlsNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_groups, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (num_groups, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (changed_controls, sz): (Control, usize) = <Control>::from_bytes(&bytes[index..])?;
+        let (changed_controls, sz): (Control, usize) = <Control>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (enabled_controls, sz): (BoolCtrl, usize) = <BoolCtrl>::from_bytes(&bytes[index..])?;
+        let (enabled_controls, sz): (BoolCtrl, usize) =
+            <BoolCtrl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (enabled_control_changes, sz): (BoolCtrl, usize) =
-            <BoolCtrl>::from_bytes(&bytes[index..])?;
+            <BoolCtrl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_type_, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (event_type_, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (request_major, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (request_major, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (request_minor, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (request_minor, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
         Some((
@@ -15663,35 +15791,35 @@ impl AsByteSequence for ExtensionDeviceNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ExtensionDeviceNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (reason, sz): (XiFeature, usize) = <XiFeature>::from_bytes(&bytes[index..])?;
+        let (reason, sz): (XiFeature, usize) = <XiFeature>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (led_class, sz): (LedClassResult, usize) =
-            <LedClassResult>::from_bytes(&bytes[index..])?;
+            <LedClassResult>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (led_id, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (led_id, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (leds_defined, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (leds_defined, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (led_state, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (led_state, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_button, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_button, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_buttons, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_buttons, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (supported, sz): (XiFeature, usize) = <XiFeature>::from_bytes(&bytes[index..])?;
+        let (supported, sz): (XiFeature, usize) = <XiFeature>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (unsupported, sz): (XiFeature, usize) = <XiFeature>::from_bytes(&bytes[index..])?;
+        let (unsupported, sz): (XiFeature, usize) = <XiFeature>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -15767,20 +15895,20 @@ impl AsByteSequence for IndicatorMapNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing IndicatorMapNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (state, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (state, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (map_changed, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (map_changed, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         Some((
@@ -15842,20 +15970,20 @@ impl AsByteSequence for IndicatorStateNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing IndicatorStateNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (state, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (state, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state_changed, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (state_changed, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         Some((
@@ -15950,53 +16078,56 @@ impl AsByteSequence for MapNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing MapNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ptr_btn_actions, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (ptr_btn_actions, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (changed, sz): (MapPart, usize) = <MapPart>::from_bytes(&bytes[index..])?;
+        let (changed, sz): (MapPart, usize) = <MapPart>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_types, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_types, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_key_sym, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_sym, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_key_syms, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_key_syms, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_key_act, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_act, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_key_acts, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_key_acts, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_key_behavior, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_behavior, sz): (Keycode, usize) =
+            <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_key_behavior, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_key_behavior, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_key_explicit, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key_explicit, sz): (Keycode, usize) =
+            <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_key_explicit, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_key_explicit, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_mod_map_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_mod_map_key, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_v_mod_map_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_v_mod_map_key, sz): (Keycode, usize) =
+            <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_v_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_v_mod_map_keys, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -16092,11 +16223,12 @@ impl AsByteSequence for NamesNotifyEvent {
         index += self.time.as_bytes(&mut bytes[index..]);
         index += self.device_id.as_bytes(&mut bytes[index..]);
         index += 1;
-        index += self.changed.as_bytes(&mut bytes[index..]);
+        index += as_bytes_with_width(&self.changed, 2, &mut bytes[index..]);
         index += self.first_type.as_bytes(&mut bytes[index..]);
         index += self.n_types.as_bytes(&mut bytes[index..]);
         index += self.first_level_name.as_bytes(&mut bytes[index..]);
         index += self.n_level_names.as_bytes(&mut bytes[index..]);
+        index += 1;
         index += self.n_radio_groups.as_bytes(&mut bytes[index..]);
         index += self.n_key_aliases.as_bytes(&mut bytes[index..]);
         index += self.changed_group_names.as_bytes(&mut bytes[index..]);
@@ -16111,41 +16243,42 @@ impl AsByteSequence for NamesNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing NamesNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (changed, sz): (NameDetail, usize) = <NameDetail>::from_bytes(&bytes[index..])?;
+        let (changed, sz): (NameDetail, usize) = from_bytes_with_width(bytes.get(index..)?, 2)?;
         index += sz;
-        let (first_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_types, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_types, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_level_name, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_level_name, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_level_names, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_level_names, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_radio_groups, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        index += 1;
+        let (n_radio_groups, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_key_aliases, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_key_aliases, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (changed_group_names, sz): (SetOfGroup, usize) =
-            <SetOfGroup>::from_bytes(&bytes[index..])?;
+            <SetOfGroup>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (changed_virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(&bytes[index..])?;
+        let (changed_virtual_mods, sz): (VMod, usize) = <VMod>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_key, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (n_keys, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (n_keys, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (changed_indicators, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (changed_indicators, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
         Some((
@@ -16179,11 +16312,12 @@ impl AsByteSequence for NamesNotifyEvent {
             + self.time.size()
             + self.device_id.size()
             + 1
-            + self.changed.size()
+            + 2
             + self.first_type.size()
             + self.n_types.size()
             + self.first_level_name.size()
             + self.n_level_names.size()
+            + 1
             + self.n_radio_groups.size()
             + self.n_key_aliases.size()
             + self.changed_group_names.size()
@@ -16238,31 +16372,31 @@ impl AsByteSequence for NewKeyboardNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing NewKeyboardNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (old_device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (old_device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (old_min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (old_min_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (old_max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (old_max_key_code, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (request_major, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (request_major, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (request_minor, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (request_minor, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (changed, sz): (NknDetail, usize) = <NknDetail>::from_bytes(&bytes[index..])?;
+        let (changed, sz): (NknDetail, usize) = <NknDetail>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 14;
         Some((
@@ -16367,53 +16501,55 @@ impl AsByteSequence for StateNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing StateNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (xkb_type, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (device_id, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (base_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (base_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (latched_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (latched_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (locked_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (locked_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (group, sz): (Group, usize) = <Group>::from_bytes(&bytes[index..])?;
+        let (group, sz): (Group, usize) = <Group>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (base_group, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (base_group, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (latched_group, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (latched_group, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (locked_group, sz): (Group, usize) = <Group>::from_bytes(&bytes[index..])?;
+        let (locked_group, sz): (Group, usize) = <Group>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (compat_state, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (compat_state, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (grab_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (grab_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (compat_grab_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (compat_grab_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (lookup_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (lookup_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (compat_loockup_mods, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (compat_loockup_mods, sz): (ModMask, usize) =
+            <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ptr_btn_state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(&bytes[index..])?;
+        let (ptr_btn_state, sz): (KeyButMask, usize) =
+            <KeyButMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (changed, sz): (StatePart, usize) = <StatePart>::from_bytes(&bytes[index..])?;
+        let (changed, sz): (StatePart, usize) = <StatePart>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_type_, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (event_type_, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (request_major, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (request_major, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (request_minor, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (request_minor, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             StateNotifyEvent {
@@ -31,16 +31,16 @@ impl AsByteSequence for Printer {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Printer from byte buffer");
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (name, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, 4);
-        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (description, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, 4);
         Some((
@@ -107,10 +107,10 @@ impl AsByteSequence for PrintQueryVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintQueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintQueryVersionRequest {
@@ -129,6 +129,7 @@ impl Request for PrintQueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintQueryVersion";
     type Reply = PrintQueryVersionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -156,16 +157,16 @@ impl AsByteSequence for PrintQueryVersionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintQueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (major_version, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_version, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintQueryVersionReply {
@@ -217,21 +218,21 @@ impl AsByteSequence for PrintGetPrinterListRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetPrinterListRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (printer_name, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
         let (locale, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
         Some((
@@ -267,6 +268,7 @@ impl Request for PrintGetPrinterListRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintGetPrinterList";
     type Reply = PrintGetPrinterListReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -296,18 +298,18 @@ impl AsByteSequence for PrintGetPrinterListReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetPrinterListReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (printers, block_len): (Vec<Printer>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Printer>());
         Some((
@@ -354,10 +356,10 @@ impl AsByteSequence for PrintRehashPrinterListRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintRehashPrinterListRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintRehashPrinterListRequest {
@@ -376,6 +378,7 @@ impl Request for PrintRehashPrinterListRequest {
     const OPCODE: u8 = 20;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintRehashPrinterList";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -409,23 +412,23 @@ impl AsByteSequence for CreateContextRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (context_id, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (printer_name, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
         let (locale, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
         Some((
@@ -463,6 +466,7 @@ impl Request for CreateContextRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateContext";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -486,12 +490,12 @@ impl AsByteSequence for PrintSetContextRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintSetContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintSetContextRequest {
@@ -511,6 +515,7 @@ impl Request for PrintSetContextRequest {
     const OPCODE: u8 = 3;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintSetContext";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -532,10 +537,10 @@ impl AsByteSequence for PrintGetContextRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintGetContextRequest {
@@ -554,6 +559,7 @@ impl Request for PrintGetContextRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintGetContext";
     type Reply = PrintGetContextReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -579,14 +585,14 @@ impl AsByteSequence for PrintGetContextReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintGetContextReply {
@@ -624,12 +630,12 @@ impl AsByteSequence for PrintDestroyContextRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintDestroyContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintDestroyContextRequest {
@@ -649,6 +655,7 @@ impl Request for PrintDestroyContextRequest {
     const OPCODE: u8 = 5;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintDestroyContext";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -670,10 +677,10 @@ impl AsByteSequence for PrintGetScreenOfContextRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetScreenOfContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintGetScreenOfContextRequest {
@@ -692,6 +699,7 @@ impl Request for PrintGetScreenOfContextRequest {
     const OPCODE: u8 = 6;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintGetScreenOfContext";
     type Reply = PrintGetScreenOfContextReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -717,14 +725,14 @@ impl AsByteSequence for PrintGetScreenOfContextReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetScreenOfContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintGetScreenOfContextReply {
@@ -762,12 +770,12 @@ impl AsByteSequence for PrintStartJobRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintStartJobRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (output_mode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (output_mode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintStartJobRequest {
@@ -787,6 +795,7 @@ impl Request for PrintStartJobRequest {
     const OPCODE: u8 = 7;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintStartJob";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -810,12 +819,12 @@ impl AsByteSequence for PrintEndJobRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintEndJobRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cancel, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (cancel, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintEndJobRequest {
@@ -835,6 +844,7 @@ impl Request for PrintEndJobRequest {
     const OPCODE: u8 = 8;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintEndJob";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -858,12 +868,12 @@ impl AsByteSequence for PrintStartDocRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintStartDocRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (driver_mode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (driver_mode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintStartDocRequest {
@@ -883,6 +893,7 @@ impl Request for PrintStartDocRequest {
     const OPCODE: u8 = 9;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintStartDoc";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -906,12 +917,12 @@ impl AsByteSequence for PrintEndDocRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintEndDocRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cancel, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (cancel, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintEndDocRequest {
@@ -931,6 +942,7 @@ impl Request for PrintEndDocRequest {
     const OPCODE: u8 = 10;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintEndDoc";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -969,29 +981,29 @@ impl AsByteSequence for PrintPutDocumentDataRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintPutDocumentDataRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (data, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         let (doc_format, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
         let (options, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len2 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
         Some((
@@ -1036,6 +1048,7 @@ impl Request for PrintPutDocumentDataRequest {
     const OPCODE: u8 = 11;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintPutDocumentData";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1061,14 +1074,14 @@ impl AsByteSequence for PrintGetDocumentDataRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetDocumentDataRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_bytes, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (max_bytes, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintGetDocumentDataRequest {
@@ -1089,6 +1102,7 @@ impl Request for PrintGetDocumentDataRequest {
     const OPCODE: u8 = 12;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintGetDocumentData";
     type Reply = PrintGetDocumentDataReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1122,22 +1136,22 @@ impl AsByteSequence for PrintGetDocumentDataReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetDocumentDataReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status_code, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (status_code, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (finished_flag, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (finished_flag, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (data, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -1190,12 +1204,12 @@ impl AsByteSequence for PrintStartPageRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintStartPageRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintStartPageRequest {
@@ -1215,6 +1229,7 @@ impl Request for PrintStartPageRequest {
     const OPCODE: u8 = 13;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintStartPage";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1239,12 +1254,12 @@ impl AsByteSequence for PrintEndPageRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintEndPageRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cancel, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (cancel, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -1265,6 +1280,7 @@ impl Request for PrintEndPageRequest {
     const OPCODE: u8 = 14;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintEndPage";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1290,14 +1306,14 @@ impl AsByteSequence for PrintSelectInputRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintSelectInputRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (event_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintSelectInputRequest {
@@ -1318,6 +1334,7 @@ impl Request for PrintSelectInputRequest {
     const OPCODE: u8 = 15;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintSelectInput";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1341,12 +1358,12 @@ impl AsByteSequence for PrintInputSelectedRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintInputSelectedRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintInputSelectedRequest {
@@ -1366,6 +1383,7 @@ impl Request for PrintInputSelectedRequest {
     const OPCODE: u8 = 16;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintInputSelected";
     type Reply = PrintInputSelectedReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1393,16 +1411,16 @@ impl AsByteSequence for PrintInputSelectedReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintInputSelectedReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (event_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (all_events_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (all_events_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintInputSelectedReply {
@@ -1449,14 +1467,14 @@ impl AsByteSequence for PrintGetAttributesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetAttributesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pool, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (pool, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -1478,6 +1496,7 @@ impl Request for PrintGetAttributesRequest {
     const OPCODE: u8 = 17;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintGetAttributes";
     type Reply = PrintGetAttributesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1507,18 +1526,18 @@ impl AsByteSequence for PrintGetAttributesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetAttributesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (attributes, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
         Some((
@@ -1575,20 +1594,20 @@ impl AsByteSequence for PrintGetOneAttributesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetOneAttributesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pool, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (pool, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         let (name, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
         Some((
@@ -1622,6 +1641,7 @@ impl Request for PrintGetOneAttributesRequest {
     const OPCODE: u8 = 19;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintGetOneAttributes";
     type Reply = PrintGetOneAttributesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1651,18 +1671,18 @@ impl AsByteSequence for PrintGetOneAttributesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetOneAttributesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (value, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
         Some((
@@ -1722,22 +1742,24 @@ impl AsByteSequence for PrintSetAttributesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintSetAttributesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (string_len, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (string_len, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pool, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (pool, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (rule, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (rule, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (attributes, block_len): (Vec<String8>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (attributes, block_len): (Vec<String8>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<String8>());
         Some((
@@ -1774,6 +1796,7 @@ impl Request for PrintSetAttributesRequest {
     const OPCODE: u8 = 18;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintSetAttributes";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1797,12 +1820,12 @@ impl AsByteSequence for PrintGetPageDimensionsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetPageDimensionsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintGetPageDimensionsRequest {
@@ -1822,6 +1845,7 @@ impl Request for PrintGetPageDimensionsRequest {
     const OPCODE: u8 = 21;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintGetPageDimensions";
     type Reply = PrintGetPageDimensionsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1857,24 +1881,24 @@ impl AsByteSequence for PrintGetPageDimensionsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetPageDimensionsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (offset_x, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (offset_x, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (offset_y, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (offset_y, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (reproducible_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (reproducible_width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (reproducible_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (reproducible_height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintGetPageDimensionsReply {
@@ -1924,10 +1948,10 @@ impl AsByteSequence for PrintQueryScreensRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintQueryScreensRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintQueryScreensRequest {
@@ -1946,6 +1970,7 @@ impl Request for PrintQueryScreensRequest {
     const OPCODE: u8 = 22;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintQueryScreens";
     type Reply = PrintQueryScreensReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1975,18 +2000,18 @@ impl AsByteSequence for PrintQueryScreensReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintQueryScreensReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (roots, block_len): (Vec<Window>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Window>());
         Some((
@@ -2037,14 +2062,14 @@ impl AsByteSequence for PrintSetImageResolutionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintSetImageResolutionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (image_resolution, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (image_resolution, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintSetImageResolutionRequest {
@@ -2069,6 +2094,7 @@ impl Request for PrintSetImageResolutionRequest {
     const OPCODE: u8 = 23;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintSetImageResolution";
     type Reply = PrintSetImageResolutionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2095,15 +2121,16 @@ impl AsByteSequence for PrintSetImageResolutionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintSetImageResolutionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (status, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (previous_resolutions, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (previous_resolutions, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintSetImageResolutionReply {
@@ -2146,12 +2173,12 @@ impl AsByteSequence for PrintGetImageResolutionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetImageResolutionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintGetImageResolutionRequest {
@@ -2171,6 +2198,7 @@ impl Request for PrintGetImageResolutionRequest {
     const OPCODE: u8 = 24;
     const EXTENSION: Option<&'static str> = Some("XpExtension");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PrintGetImageResolution";
     type Reply = PrintGetImageResolutionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2196,14 +2224,14 @@ impl AsByteSequence for PrintGetImageResolutionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PrintGetImageResolutionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (image_resolution, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (image_resolution, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PrintGetImageResolutionReply {
@@ -2457,13 +2485,13 @@ impl AsByteSequence for AttributNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AttributNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             AttributNotifyEvent {
@@ -2507,15 +2535,15 @@ impl AsByteSequence for NotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing NotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Pcontext, usize) = <Pcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cancel, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (cancel, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             NotifyEvent {
@@ -23,9 +23,9 @@ impl AsByteSequence for Char2b {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Char2b from byte buffer");
-        let (byte1, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (byte1, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (byte2, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (byte2, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Char2b {
@@ -278,9 +278,9 @@ impl AsByteSequence for Point {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Point from byte buffer");
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((Point { x: x, y: y }, index))
     }
@@ -311,13 +311,13 @@ impl AsByteSequence for Rectangle {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Rectangle from byte buffer");
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Rectangle {
@@ -360,17 +360,17 @@ impl AsByteSequence for Arc {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Arc from byte buffer");
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (angle1, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (angle1, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (angle2, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (angle2, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Arc {
@@ -415,11 +415,11 @@ impl AsByteSequence for Format {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Format from byte buffer");
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bits_per_pixel, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (bits_per_pixel, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (scanline_pad, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (scanline_pad, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 5;
         Some((
@@ -465,19 +465,19 @@ impl AsByteSequence for Visualtype {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Visualtype from byte buffer");
-        let (visual_id, sz): (Visualid, usize) = <Visualid>::from_bytes(&bytes[index..])?;
+        let (visual_id, sz): (Visualid, usize) = <Visualid>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (class, sz): (VisualClass, usize) = <VisualClass>::from_bytes(&bytes[index..])?;
+        let (class, sz): (VisualClass, usize) = <VisualClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bits_per_rgb_value, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (bits_per_rgb_value, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (colormap_entries, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (colormap_entries, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (red_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (red_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (green_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (green_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (blue_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (blue_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
         Some((
@@ -567,14 +567,14 @@ impl AsByteSequence for Depth {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Depth from byte buffer");
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
         let (visuals, block_len): (Vec<Visualtype>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Visualtype>());
         Some((
@@ -643,42 +643,45 @@ impl AsByteSequence for Screen {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Screen from byte buffer");
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (default_colormap, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+        let (default_colormap, sz): (Colormap, usize) =
+            <Colormap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (white_pixel, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (white_pixel, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (black_pixel, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (black_pixel, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (current_input_masks, sz): (EventMask, usize) =
-            <EventMask>::from_bytes(&bytes[index..])?;
+            <EventMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width_in_pixels, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width_in_pixels, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height_in_pixels, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height_in_pixels, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width_in_millimeters, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width_in_millimeters, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height_in_millimeters, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height_in_millimeters, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (min_installed_maps, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (min_installed_maps, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_installed_maps, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (max_installed_maps, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_visual, sz): (Visualid, usize) = <Visualid>::from_bytes(&bytes[index..])?;
+        let (root_visual, sz): (Visualid, usize) = <Visualid>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (backing_stores, sz): (BackingStore, usize) =
-            <BackingStore>::from_bytes(&bytes[index..])?;
+            <BackingStore>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (save_unders, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (save_unders, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (root_depth, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (allowed_depths, block_len): (Vec<Depth>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Depth>());
         Some((
@@ -1312,24 +1315,26 @@ impl AsByteSequence for SetupRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetupRequest from byte buffer");
-        let (byte_order, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (byte_order, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (protocol_major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (protocol_major_version, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (protocol_minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (protocol_minor_version, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (authorization_protocol_name, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, 4);
         let (authorization_protocol_data, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, 4);
         Some((
@@ -1395,18 +1400,20 @@ impl AsByteSequence for SetupFailed {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetupFailed from byte buffer");
-        let (status, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (status, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (protocol_major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (protocol_major_version, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (protocol_minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (protocol_minor_version, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (reason, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -1457,13 +1464,13 @@ impl AsByteSequence for SetupAuthenticate {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetupAuthenticate from byte buffer");
-        let (status, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (status, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 5;
-        let (length, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (reason, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], ((length as usize) * (4)) as usize)?;
+            string_from_bytes(bytes.get(index..)?, ((length as usize) * (4)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -1549,58 +1556,61 @@ impl AsByteSequence for Setup {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Setup from byte buffer");
-        let (status, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (status, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (protocol_major_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (protocol_major_version, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (protocol_minor_version, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (protocol_minor_version, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (release_number, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (release_number, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (resource_id_base, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (resource_id_base, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (resource_id_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (resource_id_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (motion_buffer_size, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (motion_buffer_size, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (maximum_request_length, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (maximum_request_length, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len2, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len2, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (image_byte_order, sz): (ImageOrder, usize) =
-            <ImageOrder>::from_bytes(&bytes[index..])?;
+            <ImageOrder>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (bitmap_format_bit_order, sz): (ImageOrder, usize) =
-            <ImageOrder>::from_bytes(&bytes[index..])?;
+            <ImageOrder>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (bitmap_format_scanline_unit, sz): (Card8, usize) =
-            <Card8>::from_bytes(&bytes[index..])?;
+            <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (bitmap_format_scanline_pad, sz): (Card8, usize) =
-            <Card8>::from_bytes(&bytes[index..])?;
+            <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (min_keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (min_keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (max_keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
         let (vendor, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, 4);
         let (pixmap_formats, block_len): (Vec<Format>, usize) =
-            vector_from_bytes(&bytes[index..], len2 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len2 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Format>());
         let (roots, block_len): (Vec<Screen>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Screen>());
         Some((
@@ -2698,70 +2708,72 @@ impl AsByteSequence for CreateWindowRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateWindowRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (wid, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (wid, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (parent, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (parent, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (border_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (border_width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (class, sz): (WindowClass, usize) = <WindowClass>::from_bytes(&bytes[index..])?;
+        let (class, sz): (WindowClass, usize) = <WindowClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(&bytes[index..])?;
+        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value_mask, sz): (Cw, usize) = <Cw>::from_bytes(&bytes[index..])?;
+        let (value_mask, sz): (Cw, usize) = <Cw>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let cond0 = (value_mask);
         let background_pixmap: Pixmap = if cond0.back_pixmap() {
-            let (background_pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+            let (background_pixmap, sz): (Pixmap, usize) =
+                <Pixmap>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             background_pixmap
         } else {
             Default::default()
         };
         let background_pixel: Card32 = if cond0.back_pixel() {
-            let (background_pixel, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (background_pixel, sz): (Card32, usize) =
+                <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             background_pixel
         } else {
             Default::default()
         };
         let border_pixmap: Pixmap = if cond0.border_pixmap() {
-            let (border_pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+            let (border_pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             border_pixmap
         } else {
             Default::default()
         };
         let border_pixel: Card32 = if cond0.border_pixel() {
-            let (border_pixel, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (border_pixel, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             border_pixel
         } else {
             Default::default()
         };
         let bit_gravity: Gravity = if cond0.bit_gravity() {
-            let (bit_gravity, sz): (Gravity, usize) = <Gravity>::from_bytes(&bytes[index..])?;
+            let (bit_gravity, sz): (Gravity, usize) = <Gravity>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             bit_gravity
         } else {
             Default::default()
         };
         let win_gravity: Gravity = if cond0.win_gravity() {
-            let (win_gravity, sz): (Gravity, usize) = <Gravity>::from_bytes(&bytes[index..])?;
+            let (win_gravity, sz): (Gravity, usize) = <Gravity>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             win_gravity
         } else {
@@ -2769,42 +2781,44 @@ impl AsByteSequence for CreateWindowRequest {
         };
         let backing_store: BackingStore = if cond0.backing_store() {
             let (backing_store, sz): (BackingStore, usize) =
-                <BackingStore>::from_bytes(&bytes[index..])?;
+                <BackingStore>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             backing_store
         } else {
             Default::default()
         };
         let backing_planes: Card32 = if cond0.backing_planes() {
-            let (backing_planes, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (backing_planes, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             backing_planes
         } else {
             Default::default()
         };
         let backing_pixel: Card32 = if cond0.backing_pixel() {
-            let (backing_pixel, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (backing_pixel, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             backing_pixel
         } else {
             Default::default()
         };
         let override_redirect: Bool32 = if cond0.override_redirect() {
-            let (override_redirect, sz): (Bool32, usize) = <Bool32>::from_bytes(&bytes[index..])?;
+            let (override_redirect, sz): (Bool32, usize) =
+                <Bool32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             override_redirect
         } else {
             Default::default()
         };
         let save_under: Bool32 = if cond0.save_under() {
-            let (save_under, sz): (Bool32, usize) = <Bool32>::from_bytes(&bytes[index..])?;
+            let (save_under, sz): (Bool32, usize) = <Bool32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             save_under
         } else {
             Default::default()
         };
         let event_mask: EventMask = if cond0.event_mask() {
-            let (event_mask, sz): (EventMask, usize) = <EventMask>::from_bytes(&bytes[index..])?;
+            let (event_mask, sz): (EventMask, usize) =
+                <EventMask>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             event_mask
         } else {
@@ -2812,21 +2826,21 @@ impl AsByteSequence for CreateWindowRequest {
         };
         let do_not_propogate_mask: EventMask = if cond0.dont_propagate() {
             let (do_not_propogate_mask, sz): (EventMask, usize) =
-                <EventMask>::from_bytes(&bytes[index..])?;
+                <EventMask>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             do_not_propogate_mask
         } else {
             Default::default()
         };
         let colormap: Colormap = if cond0.colormap() {
-            let (colormap, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+            let (colormap, sz): (Colormap, usize) = <Colormap>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             colormap
         } else {
             Default::default()
         };
         let cursor: Cursor = if cond0.cursor() {
-            let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+            let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             cursor
         } else {
@@ -2902,6 +2916,7 @@ impl Request for CreateWindowRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateWindow";
     type Reply = ();
 }
 #[repr(u16)]
@@ -3358,53 +3373,55 @@ impl AsByteSequence for ChangeWindowAttributesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeWindowAttributesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value_mask, sz): (Cw, usize) = <Cw>::from_bytes(&bytes[index..])?;
+        let (value_mask, sz): (Cw, usize) = <Cw>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let cond0 = (value_mask);
         let background_pixmap: Pixmap = if cond0.back_pixmap() {
-            let (background_pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+            let (background_pixmap, sz): (Pixmap, usize) =
+                <Pixmap>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             background_pixmap
         } else {
             Default::default()
         };
         let background_pixel: Card32 = if cond0.back_pixel() {
-            let (background_pixel, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (background_pixel, sz): (Card32, usize) =
+                <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             background_pixel
         } else {
             Default::default()
         };
         let border_pixmap: Pixmap = if cond0.border_pixmap() {
-            let (border_pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+            let (border_pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             border_pixmap
         } else {
             Default::default()
         };
         let border_pixel: Card32 = if cond0.border_pixel() {
-            let (border_pixel, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (border_pixel, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             border_pixel
         } else {
             Default::default()
         };
         let bit_gravity: Gravity = if cond0.bit_gravity() {
-            let (bit_gravity, sz): (Gravity, usize) = <Gravity>::from_bytes(&bytes[index..])?;
+            let (bit_gravity, sz): (Gravity, usize) = <Gravity>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             bit_gravity
         } else {
             Default::default()
         };
         let win_gravity: Gravity = if cond0.win_gravity() {
-            let (win_gravity, sz): (Gravity, usize) = <Gravity>::from_bytes(&bytes[index..])?;
+            let (win_gravity, sz): (Gravity, usize) = <Gravity>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             win_gravity
         } else {
@@ -3412,42 +3429,44 @@ impl AsByteSequence for ChangeWindowAttributesRequest {
         };
         let backing_store: BackingStore = if cond0.backing_store() {
             let (backing_store, sz): (BackingStore, usize) =
-                <BackingStore>::from_bytes(&bytes[index..])?;
+                <BackingStore>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             backing_store
         } else {
             Default::default()
         };
         let backing_planes: Card32 = if cond0.backing_planes() {
-            let (backing_planes, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (backing_planes, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             backing_planes
         } else {
             Default::default()
         };
         let backing_pixel: Card32 = if cond0.backing_pixel() {
-            let (backing_pixel, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (backing_pixel, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             backing_pixel
         } else {
             Default::default()
         };
         let override_redirect: Bool32 = if cond0.override_redirect() {
-            let (override_redirect, sz): (Bool32, usize) = <Bool32>::from_bytes(&bytes[index..])?;
+            let (override_redirect, sz): (Bool32, usize) =
+                <Bool32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             override_redirect
         } else {
             Default::default()
         };
         let save_under: Bool32 = if cond0.save_under() {
-            let (save_under, sz): (Bool32, usize) = <Bool32>::from_bytes(&bytes[index..])?;
+            let (save_under, sz): (Bool32, usize) = <Bool32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             save_under
         } else {
             Default::default()
         };
         let event_mask: EventMask = if cond0.event_mask() {
-            let (event_mask, sz): (EventMask, usize) = <EventMask>::from_bytes(&bytes[index..])?;
+            let (event_mask, sz): (EventMask, usize) =
+                <EventMask>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             event_mask
         } else {
@@ -3455,21 +3474,21 @@ impl AsByteSequence for ChangeWindowAttributesRequest {
         };
         let do_not_propogate_mask: EventMask = if cond0.dont_propagate() {
             let (do_not_propogate_mask, sz): (EventMask, usize) =
-                <EventMask>::from_bytes(&bytes[index..])?;
+                <EventMask>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             do_not_propogate_mask
         } else {
             Default::default()
         };
         let colormap: Colormap = if cond0.colormap() {
-            let (colormap, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+            let (colormap, sz): (Colormap, usize) = <Colormap>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             colormap
         } else {
             Default::default()
         };
         let cursor: Cursor = if cond0.cursor() {
-            let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+            let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             cursor
         } else {
@@ -3528,6 +3547,7 @@ impl Request for ChangeWindowAttributesRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeWindowAttributes";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3551,12 +3571,12 @@ impl AsByteSequence for GetWindowAttributesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetWindowAttributesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetWindowAttributesRequest {
@@ -3576,6 +3596,7 @@ impl Request for GetWindowAttributesRequest {
     const OPCODE: u8 = 3;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetWindowAttributes";
     type Reply = GetWindowAttributesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3621,7 +3642,7 @@ impl AsByteSequence for GetWindowAttributesReply {
         index += self.colormap.as_bytes(&mut bytes[index..]);
         index += self.all_event_masks.as_bytes(&mut bytes[index..]);
         index += self.your_event_mask.as_bytes(&mut bytes[index..]);
-        index += self.do_not_propagate_mask.as_bytes(&mut bytes[index..]);
+        index += as_bytes_with_width(&self.do_not_propagate_mask, 2, &mut bytes[index..]);
         index += 2;
         index
     }
@@ -3629,43 +3650,45 @@ impl AsByteSequence for GetWindowAttributesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetWindowAttributesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (backing_store, sz): (BackingStore, usize) =
-            <BackingStore>::from_bytes(&bytes[index..])?;
+            <BackingStore>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(&bytes[index..])?;
+        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (class, sz): (WindowClass, usize) = <WindowClass>::from_bytes(&bytes[index..])?;
+        let (class, sz): (WindowClass, usize) = <WindowClass>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bit_gravity, sz): (Gravity, usize) = <Gravity>::from_bytes(&bytes[index..])?;
+        let (bit_gravity, sz): (Gravity, usize) = <Gravity>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (win_gravity, sz): (Gravity, usize) = <Gravity>::from_bytes(&bytes[index..])?;
+        let (win_gravity, sz): (Gravity, usize) = <Gravity>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (backing_planes, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (backing_planes, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (backing_pixel, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (backing_pixel, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (save_under, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (save_under, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (map_is_installed, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (map_is_installed, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (map_state, sz): (MapState, usize) = <MapState>::from_bytes(&bytes[index..])?;
+        let (map_state, sz): (MapState, usize) = <MapState>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (override_redirect, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (override_redirect, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (colormap, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+        let (colormap, sz): (Colormap, usize) = <Colormap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (all_event_masks, sz): (EventMask, usize) = <EventMask>::from_bytes(&bytes[index..])?;
+        let (all_event_masks, sz): (EventMask, usize) =
+            <EventMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (your_event_mask, sz): (EventMask, usize) = <EventMask>::from_bytes(&bytes[index..])?;
+        let (your_event_mask, sz): (EventMask, usize) =
+            <EventMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (do_not_propagate_mask, sz): (EventMask, usize) =
-            <EventMask>::from_bytes(&bytes[index..])?;
+            from_bytes_with_width(bytes.get(index..)?, 2)?;
         index += sz;
         index += 2;
         Some((
@@ -3711,7 +3734,7 @@ impl AsByteSequence for GetWindowAttributesReply {
             + self.colormap.size()
             + self.all_event_masks.size()
             + self.your_event_mask.size()
-            + self.do_not_propagate_mask.size()
+            + 2
             + 2
     }
 }
@@ -3818,12 +3841,12 @@ impl AsByteSequence for DestroyWindowRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroyWindowRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DestroyWindowRequest {
@@ -3843,6 +3866,7 @@ impl Request for DestroyWindowRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroyWindow";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3866,12 +3890,12 @@ impl AsByteSequence for DestroySubwindowsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroySubwindowsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DestroySubwindowsRequest {
@@ -3891,6 +3915,7 @@ impl Request for DestroySubwindowsRequest {
     const OPCODE: u8 = 5;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroySubwindows";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3915,13 +3940,13 @@ impl AsByteSequence for ChangeSaveSetRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeSaveSetRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (SetMode, usize) = <SetMode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (SetMode, usize) = <SetMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ChangeSaveSetRequest {
@@ -3942,6 +3967,7 @@ impl Request for ChangeSaveSetRequest {
     const OPCODE: u8 = 6;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeSaveSet";
     type Reply = ();
 }
 #[repr(u8)]
@@ -4002,18 +4028,18 @@ impl AsByteSequence for ReparentWindowRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ReparentWindowRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (parent, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (parent, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ReparentWindowRequest {
@@ -4042,6 +4068,7 @@ impl Request for ReparentWindowRequest {
     const OPCODE: u8 = 7;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ReparentWindow";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4065,12 +4092,12 @@ impl AsByteSequence for MapWindowRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing MapWindowRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             MapWindowRequest {
@@ -4090,6 +4117,7 @@ impl Request for MapWindowRequest {
     const OPCODE: u8 = 8;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "MapWindow";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4113,12 +4141,12 @@ impl AsByteSequence for MapSubwindowsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing MapSubwindowsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             MapSubwindowsRequest {
@@ -4138,6 +4166,7 @@ impl Request for MapSubwindowsRequest {
     const OPCODE: u8 = 9;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "MapSubwindows";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4161,12 +4190,12 @@ impl AsByteSequence for UnmapWindowRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing UnmapWindowRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             UnmapWindowRequest {
@@ -4186,6 +4215,7 @@ impl Request for UnmapWindowRequest {
     const OPCODE: u8 = 10;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UnmapWindow";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4209,12 +4239,12 @@ impl AsByteSequence for UnmapSubwindowsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing UnmapSubwindowsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             UnmapSubwindowsRequest {
@@ -4234,6 +4264,7 @@ impl Request for UnmapSubwindowsRequest {
     const OPCODE: u8 = 11;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UnmapSubwindows";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4289,61 +4320,63 @@ impl AsByteSequence for ConfigureWindowRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ConfigureWindowRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value_mask, sz): (ConfigWindow, usize) = <ConfigWindow>::from_bytes(&bytes[index..])?;
+        let (value_mask, sz): (ConfigWindow, usize) =
+            <ConfigWindow>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let cond0 = (value_mask);
         let x: Int32 = if cond0.x() {
-            let (x, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (x, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             x
         } else {
             Default::default()
         };
         let y: Int32 = if cond0.y() {
-            let (y, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (y, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             y
         } else {
             Default::default()
         };
         let width: Card32 = if cond0.width() {
-            let (width, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (width, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             width
         } else {
             Default::default()
         };
         let height: Card32 = if cond0.height() {
-            let (height, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (height, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             height
         } else {
             Default::default()
         };
         let border_width: Card32 = if cond0.border_width() {
-            let (border_width, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (border_width, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             border_width
         } else {
             Default::default()
         };
         let sibling: Window = if cond0.sibling() {
-            let (sibling, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+            let (sibling, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             sibling
         } else {
             Default::default()
         };
         let stack_mode: StackMode = if cond0.stack_mode() {
-            let (stack_mode, sz): (StackMode, usize) = <StackMode>::from_bytes(&bytes[index..])?;
+            let (stack_mode, sz): (StackMode, usize) =
+                <StackMode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             stack_mode
         } else {
@@ -4387,6 +4420,7 @@ impl Request for ConfigureWindowRequest {
     const OPCODE: u8 = 12;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ConfigureWindow";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4411,13 +4445,13 @@ impl AsByteSequence for CirculateWindowRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CirculateWindowRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (direction, sz): (Circulate, usize) = <Circulate>::from_bytes(&bytes[index..])?;
+        let (direction, sz): (Circulate, usize) = <Circulate>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CirculateWindowRequest {
@@ -4438,6 +4472,7 @@ impl Request for CirculateWindowRequest {
     const OPCODE: u8 = 13;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CirculateWindow";
     type Reply = ();
 }
 #[repr(u8)]
@@ -4492,12 +4527,12 @@ impl AsByteSequence for GetGeometryRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetGeometryRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetGeometryRequest {
@@ -4517,6 +4552,7 @@ impl Request for GetGeometryRequest {
     const OPCODE: u8 = 14;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetGeometry";
     type Reply = GetGeometryReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4554,25 +4590,25 @@ impl AsByteSequence for GetGeometryReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetGeometryReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (border_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (border_width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -4627,12 +4663,12 @@ impl AsByteSequence for QueryTreeRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryTreeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryTreeRequest {
@@ -4652,6 +4688,7 @@ impl Request for QueryTreeRequest {
     const OPCODE: u8 = 15;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryTree";
     type Reply = QueryTreeReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4685,22 +4722,22 @@ impl AsByteSequence for QueryTreeReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryTreeReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (parent, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (parent, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 14;
         let (children, block_len): (Vec<Window>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Window>());
         Some((
@@ -4758,16 +4795,17 @@ impl AsByteSequence for InternAtomRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing InternAtomRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (only_if_exists, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (only_if_exists, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) =
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -4798,6 +4836,7 @@ impl Request for InternAtomRequest {
     const OPCODE: u8 = 16;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "InternAtom";
     type Reply = InternAtomReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4823,14 +4862,14 @@ impl AsByteSequence for InternAtomReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing InternAtomReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (atom, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (atom, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             InternAtomReply {
@@ -4868,12 +4907,12 @@ impl AsByteSequence for GetAtomNameRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetAtomNameRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (atom, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (atom, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetAtomNameRequest {
@@ -4893,6 +4932,7 @@ impl Request for GetAtomNameRequest {
     const OPCODE: u8 = 17;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetAtomName";
     type Reply = GetAtomNameReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -4922,17 +4962,18 @@ impl AsByteSequence for GetAtomNameReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetAtomNameReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) =
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -4995,25 +5036,25 @@ impl AsByteSequence for ChangePropertyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangePropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (PropMode, usize) = <PropMode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (PropMode, usize) = <PropMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (data_len, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (data_len, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (data, block_len): (Vec<Void>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             (((data_len as usize) * (format as usize)) / (8)) as usize,
         )?;
         index += block_len;
@@ -5055,6 +5096,7 @@ impl Request for ChangePropertyRequest {
     const OPCODE: u8 = 18;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeProperty";
     type Reply = ();
 }
 #[repr(u8)]
@@ -5113,14 +5155,14 @@ impl AsByteSequence for DeletePropertyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DeletePropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DeletePropertyRequest {
@@ -5141,6 +5183,7 @@ impl Request for DeletePropertyRequest {
     const OPCODE: u8 = 19;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DeleteProperty";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5173,21 +5216,21 @@ impl AsByteSequence for GetPropertyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPropertyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (delete, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (delete, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (long_offset, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (long_offset, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (long_length, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (long_length, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetPropertyRequest {
@@ -5219,6 +5262,7 @@ impl Request for GetPropertyRequest {
     const OPCODE: u8 = 20;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetProperty";
     type Reply = GetPropertyReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5254,23 +5298,23 @@ impl AsByteSequence for GetPropertyReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPropertyReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bytes_after, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bytes_after, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value_len, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (value_len, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (value, block_len): (Vec<Void>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             ((value_len as usize) * ((format as usize) / (8))) as usize,
         )?;
         index += block_len;
@@ -5356,12 +5400,12 @@ impl AsByteSequence for ListPropertiesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListPropertiesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ListPropertiesRequest {
@@ -5381,6 +5425,7 @@ impl Request for ListPropertiesRequest {
     const OPCODE: u8 = 21;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListProperties";
     type Reply = ListPropertiesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5410,18 +5455,18 @@ impl AsByteSequence for ListPropertiesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListPropertiesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
         let (atoms, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         Some((
@@ -5474,16 +5519,16 @@ impl AsByteSequence for SetSelectionOwnerRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetSelectionOwnerRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (owner, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (owner, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (selection, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (selection, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetSelectionOwnerRequest {
@@ -5510,6 +5555,7 @@ impl Request for SetSelectionOwnerRequest {
     const OPCODE: u8 = 22;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetSelectionOwner";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5533,12 +5579,12 @@ impl AsByteSequence for GetSelectionOwnerRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetSelectionOwnerRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (selection, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (selection, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetSelectionOwnerRequest {
@@ -5558,6 +5604,7 @@ impl Request for GetSelectionOwnerRequest {
     const OPCODE: u8 = 23;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetSelectionOwner";
     type Reply = GetSelectionOwnerReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5583,14 +5630,14 @@ impl AsByteSequence for GetSelectionOwnerReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetSelectionOwnerReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (owner, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (owner, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetSelectionOwnerReply {
@@ -5636,20 +5683,20 @@ impl AsByteSequence for ConvertSelectionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ConvertSelectionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (requestor, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (requestor, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (selection, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (selection, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ConvertSelectionRequest {
@@ -5680,6 +5727,7 @@ impl Request for ConvertSelectionRequest {
     const OPCODE: u8 = 24;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ConvertSelection";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5708,17 +5756,17 @@ impl AsByteSequence for SendEventRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SendEventRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (propagate, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (propagate, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (destination, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (destination, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_mask, sz): (EventMask, usize) = <EventMask>::from_bytes(&bytes[index..])?;
+        let (event_mask, sz): (EventMask, usize) = <EventMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): ([c_char; 32], usize) = <[c_char; 32]>::from_bytes(&bytes[index..])?;
+        let (event, sz): ([c_char; 32], usize) = <[c_char; 32]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SendEventRequest {
@@ -5746,6 +5794,7 @@ impl Request for SendEventRequest {
     const OPCODE: u8 = 25;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SendEvent";
     type Reply = ();
 }
 #[repr(u32)]
@@ -5801,7 +5850,7 @@ impl AsByteSequence for GrabPointerRequest {
         index += self.owner_events.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.grab_window.as_bytes(&mut bytes[index..]);
-        index += self.event_mask.as_bytes(&mut bytes[index..]);
+        index += as_bytes_with_width(&self.event_mask, 2, &mut bytes[index..]);
         index += self.pointer_mode.as_bytes(&mut bytes[index..]);
         index += self.keyboard_mode.as_bytes(&mut bytes[index..]);
         index += self.confine_to.as_bytes(&mut bytes[index..]);
@@ -5813,25 +5862,25 @@ impl AsByteSequence for GrabPointerRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GrabPointerRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (owner_events, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (owner_events, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_mask, sz): (EventMask, usize) = <EventMask>::from_bytes(&bytes[index..])?;
+        let (event_mask, sz): (EventMask, usize) = from_bytes_with_width(bytes.get(index..)?, 2)?;
         index += sz;
-        let (pointer_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(&bytes[index..])?;
+        let (pointer_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (keyboard_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(&bytes[index..])?;
+        let (keyboard_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (confine_to, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (confine_to, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GrabPointerRequest {
@@ -5855,7 +5904,7 @@ impl AsByteSequence for GrabPointerRequest {
             + self.owner_events.size()
             + self.length.size()
             + self.grab_window.size()
-            + self.event_mask.size()
+            + 2
             + self.pointer_mode.size()
             + self.keyboard_mode.size()
             + self.confine_to.size()
@@ -5867,6 +5916,7 @@ impl Request for GrabPointerRequest {
     const OPCODE: u8 = 26;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GrabPointer";
     type Reply = GrabPointerReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -5891,13 +5941,13 @@ impl AsByteSequence for GrabPointerReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GrabPointerReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (GrabStatus, usize) = <GrabStatus>::from_bytes(&bytes[index..])?;
+        let (status, sz): (GrabStatus, usize) = <GrabStatus>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GrabPointerReply {
@@ -6004,12 +6054,12 @@ impl AsByteSequence for UngrabPointerRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing UngrabPointerRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             UngrabPointerRequest {
@@ -6029,6 +6079,7 @@ impl Request for UngrabPointerRequest {
     const OPCODE: u8 = 27;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UngrabPointer";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -6054,7 +6105,7 @@ impl AsByteSequence for GrabButtonRequest {
         index += self.owner_events.as_bytes(&mut bytes[index..]);
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.grab_window.as_bytes(&mut bytes[index..]);
-        index += self.event_mask.as_bytes(&mut bytes[index..]);
+        index += as_bytes_with_width(&self.event_mask, 2, &mut bytes[index..]);
         index += self.pointer_mode.as_bytes(&mut bytes[index..]);
         index += self.keyboard_mode.as_bytes(&mut bytes[index..]);
         index += self.confine_to.as_bytes(&mut bytes[index..]);
@@ -6068,28 +6119,28 @@ impl AsByteSequence for GrabButtonRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GrabButtonRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (owner_events, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (owner_events, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_mask, sz): (EventMask, usize) = <EventMask>::from_bytes(&bytes[index..])?;
+        let (event_mask, sz): (EventMask, usize) = from_bytes_with_width(bytes.get(index..)?, 2)?;
         index += sz;
-        let (pointer_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(&bytes[index..])?;
+        let (pointer_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (keyboard_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(&bytes[index..])?;
+        let (keyboard_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (confine_to, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (confine_to, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (button, sz): (ButtonIndex, usize) = <ButtonIndex>::from_bytes(&bytes[index..])?;
+        let (button, sz): (ButtonIndex, usize) = <ButtonIndex>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (modifiers, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (modifiers, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GrabButtonRequest {
@@ -6114,7 +6165,7 @@ impl AsByteSequence for GrabButtonRequest {
             + self.owner_events.size()
             + self.length.size()
             + self.grab_window.size()
-            + self.event_mask.size()
+            + 2
             + self.pointer_mode.size()
             + self.keyboard_mode.size()
             + self.confine_to.size()
@@ -6128,6 +6179,7 @@ impl Request for GrabButtonRequest {
     const OPCODE: u8 = 28;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GrabButton";
     type Reply = ();
 }
 #[repr(u8)]
@@ -6423,15 +6475,15 @@ impl AsByteSequence for UngrabButtonRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing UngrabButtonRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (button, sz): (ButtonIndex, usize) = <ButtonIndex>::from_bytes(&bytes[index..])?;
+        let (button, sz): (ButtonIndex, usize) = <ButtonIndex>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (modifiers, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (modifiers, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -6459,6 +6511,7 @@ impl Request for UngrabButtonRequest {
     const OPCODE: u8 = 29;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UngrabButton";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -6479,7 +6532,7 @@ impl AsByteSequence for ChangeActivePointerGrabRequest {
         index += self.length.as_bytes(&mut bytes[index..]);
         index += self.cursor.as_bytes(&mut bytes[index..]);
         index += self.time.as_bytes(&mut bytes[index..]);
-        index += self.event_mask.as_bytes(&mut bytes[index..]);
+        index += as_bytes_with_width(&self.event_mask, 2, &mut bytes[index..]);
         index += 2;
         index
     }
@@ -6487,16 +6540,16 @@ impl AsByteSequence for ChangeActivePointerGrabRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeActivePointerGrabRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_mask, sz): (EventMask, usize) = <EventMask>::from_bytes(&bytes[index..])?;
+        let (event_mask, sz): (EventMask, usize) = from_bytes_with_width(bytes.get(index..)?, 2)?;
         index += sz;
         index += 2;
         Some((
@@ -6517,7 +6570,7 @@ impl AsByteSequence for ChangeActivePointerGrabRequest {
             + self.length.size()
             + self.cursor.size()
             + self.time.size()
-            + self.event_mask.size()
+            + 2
             + 2
     }
 }
@@ -6525,6 +6578,7 @@ impl Request for ChangeActivePointerGrabRequest {
     const OPCODE: u8 = 30;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeActivePointerGrab";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -6556,19 +6610,19 @@ impl AsByteSequence for GrabKeyboardRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GrabKeyboardRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (owner_events, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (owner_events, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pointer_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(&bytes[index..])?;
+        let (pointer_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (keyboard_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(&bytes[index..])?;
+        let (keyboard_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -6600,6 +6654,7 @@ impl Request for GrabKeyboardRequest {
     const OPCODE: u8 = 31;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GrabKeyboard";
     type Reply = GrabKeyboardReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -6624,13 +6679,13 @@ impl AsByteSequence for GrabKeyboardReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GrabKeyboardReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (GrabStatus, usize) = <GrabStatus>::from_bytes(&bytes[index..])?;
+        let (status, sz): (GrabStatus, usize) = <GrabStatus>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GrabKeyboardReply {
@@ -6668,12 +6723,12 @@ impl AsByteSequence for UngrabKeyboardRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing UngrabKeyboardRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             UngrabKeyboardRequest {
@@ -6693,6 +6748,7 @@ impl Request for UngrabKeyboardRequest {
     const OPCODE: u8 = 32;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UngrabKeyboard";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -6726,21 +6782,21 @@ impl AsByteSequence for GrabKeyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GrabKeyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (owner_events, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (owner_events, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (modifiers, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (modifiers, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (key, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pointer_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(&bytes[index..])?;
+        let (pointer_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (keyboard_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(&bytes[index..])?;
+        let (keyboard_mode, sz): (GrabMode, usize) = <GrabMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -6774,6 +6830,7 @@ impl Request for GrabKeyRequest {
     const OPCODE: u8 = 33;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GrabKey";
     type Reply = ();
 }
 #[repr(u8)]
@@ -6830,15 +6887,15 @@ impl AsByteSequence for UngrabKeyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing UngrabKeyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (key, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (key, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (grab_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (modifiers, sz): (ModMask, usize) = <ModMask>::from_bytes(&bytes[index..])?;
+        let (modifiers, sz): (ModMask, usize) = <ModMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -6866,6 +6923,7 @@ impl Request for UngrabKeyRequest {
     const OPCODE: u8 = 34;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UngrabKey";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -6890,13 +6948,13 @@ impl AsByteSequence for AllowEventsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AllowEventsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (Allow, usize) = <Allow>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (Allow, usize) = <Allow>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             AllowEventsRequest {
@@ -6917,6 +6975,7 @@ impl Request for AllowEventsRequest {
     const OPCODE: u8 = 35;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "AllowEvents";
     type Reply = ();
 }
 #[repr(u8)]
@@ -6981,10 +7040,10 @@ impl AsByteSequence for GrabServerRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GrabServerRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GrabServerRequest {
@@ -7003,6 +7062,7 @@ impl Request for GrabServerRequest {
     const OPCODE: u8 = 36;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GrabServer";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -7024,10 +7084,10 @@ impl AsByteSequence for UngrabServerRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing UngrabServerRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             UngrabServerRequest {
@@ -7046,6 +7106,7 @@ impl Request for UngrabServerRequest {
     const OPCODE: u8 = 37;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UngrabServer";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -7069,12 +7130,12 @@ impl AsByteSequence for QueryPointerRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryPointerRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryPointerRequest {
@@ -7094,6 +7155,7 @@ impl Request for QueryPointerRequest {
     const OPCODE: u8 = 38;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryPointer";
     type Reply = QueryPointerReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -7133,27 +7195,27 @@ impl AsByteSequence for QueryPointerReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryPointerReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (win_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (win_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (win_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (win_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mask, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(&bytes[index..])?;
+        let (mask, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -7209,11 +7271,11 @@ impl AsByteSequence for Timecoord {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Timecoord from byte buffer");
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Timecoord {
@@ -7254,16 +7316,16 @@ impl AsByteSequence for GetMotionEventsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMotionEventsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (start, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (start, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (stop, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (stop, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetMotionEventsRequest {
@@ -7290,6 +7352,7 @@ impl Request for GetMotionEventsRequest {
     const OPCODE: u8 = 39;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetMotionEvents";
     type Reply = GetMotionEventsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -7319,18 +7382,18 @@ impl AsByteSequence for GetMotionEventsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetMotionEventsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (events, block_len): (Vec<Timecoord>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Timecoord>());
         Some((
@@ -7385,18 +7448,18 @@ impl AsByteSequence for TranslateCoordinatesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing TranslateCoordinatesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (src_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (dst_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             TranslateCoordinatesRequest {
@@ -7425,6 +7488,7 @@ impl Request for TranslateCoordinatesRequest {
     const OPCODE: u8 = 40;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "TranslateCoordinates";
     type Reply = TranslateCoordinatesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -7455,19 +7519,19 @@ impl AsByteSequence for TranslateCoordinatesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing TranslateCoordinatesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (dst_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (dst_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             TranslateCoordinatesReply {
@@ -7528,26 +7592,26 @@ impl AsByteSequence for WarpPointerRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing WarpPointerRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (src_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst_window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (dst_window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (src_width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (src_height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (dst_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (dst_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             WarpPointerRequest {
@@ -7584,6 +7648,7 @@ impl Request for WarpPointerRequest {
     const OPCODE: u8 = 41;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "WarpPointer";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -7610,15 +7675,15 @@ impl AsByteSequence for SetInputFocusRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetInputFocusRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (revert_to, sz): (InputFocus, usize) = <InputFocus>::from_bytes(&bytes[index..])?;
+        let (revert_to, sz): (InputFocus, usize) = <InputFocus>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (focus, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (focus, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetInputFocusRequest {
@@ -7644,6 +7709,7 @@ impl Request for SetInputFocusRequest {
     const OPCODE: u8 = 42;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetInputFocus";
     type Reply = ();
 }
 #[repr(u8)]
@@ -7700,10 +7766,10 @@ impl AsByteSequence for GetInputFocusRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetInputFocusRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetInputFocusRequest {
@@ -7722,6 +7788,7 @@ impl Request for GetInputFocusRequest {
     const OPCODE: u8 = 43;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetInputFocus";
     type Reply = GetInputFocusReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -7748,15 +7815,15 @@ impl AsByteSequence for GetInputFocusReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetInputFocusReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (revert_to, sz): (InputFocus, usize) = <InputFocus>::from_bytes(&bytes[index..])?;
+        let (revert_to, sz): (InputFocus, usize) = <InputFocus>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (focus, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (focus, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetInputFocusReply {
@@ -7797,10 +7864,10 @@ impl AsByteSequence for QueryKeymapRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryKeymapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryKeymapRequest {
@@ -7819,6 +7886,7 @@ impl Request for QueryKeymapRequest {
     const OPCODE: u8 = 44;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryKeymap";
     type Reply = QueryKeymapReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -7844,14 +7912,14 @@ impl AsByteSequence for QueryKeymapReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryKeymapReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (keys, sz): ([Card8; 32], usize) = <[Card8; 32]>::from_bytes(&bytes[index..])?;
+        let (keys, sz): ([Card8; 32], usize) = <[Card8; 32]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryKeymapReply {
@@ -7895,17 +7963,18 @@ impl AsByteSequence for OpenFontRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing OpenFontRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fid, sz): (Font, usize) = <Font>::from_bytes(&bytes[index..])?;
+        let (fid, sz): (Font, usize) = <Font>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) =
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -7937,6 +8006,7 @@ impl Request for OpenFontRequest {
     const OPCODE: u8 = 45;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "OpenFont";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -7960,12 +8030,12 @@ impl AsByteSequence for CloseFontRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CloseFontRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (font, sz): (Font, usize) = <Font>::from_bytes(&bytes[index..])?;
+        let (font, sz): (Font, usize) = <Font>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CloseFontRequest {
@@ -7985,6 +8055,7 @@ impl Request for CloseFontRequest {
     const OPCODE: u8 = 46;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CloseFont";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -8005,9 +8076,9 @@ impl AsByteSequence for Fontprop {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Fontprop from byte buffer");
-        let (name, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (name, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (value, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Fontprop {
@@ -8048,17 +8119,17 @@ impl AsByteSequence for Charinfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Charinfo from byte buffer");
-        let (left_side_bearing, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (left_side_bearing, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (right_side_bearing, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (right_side_bearing, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (character_width, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (character_width, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ascent, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (ascent, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (descent, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (descent, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (attributes, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (attributes, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Charinfo {
@@ -8103,12 +8174,12 @@ impl AsByteSequence for QueryFontRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryFontRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (font, sz): (Fontable, usize) = <Fontable>::from_bytes(&bytes[index..])?;
+        let (font, sz): (Fontable, usize) = <Fontable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryFontRequest {
@@ -8128,6 +8199,7 @@ impl Request for QueryFontRequest {
     const OPCODE: u8 = 47;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryFont";
     type Reply = QueryFontReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -8161,6 +8233,7 @@ impl AsByteSequence for QueryFontReply {
         index += self.min_bounds.as_bytes(&mut bytes[index..]);
         index += 4;
         index += self.max_bounds.as_bytes(&mut bytes[index..]);
+        index += 4;
         index += self.min_char_or_byte2.as_bytes(&mut bytes[index..]);
         index += self.max_char_or_byte2.as_bytes(&mut bytes[index..]);
         index += self.default_char.as_bytes(&mut bytes[index..]);
@@ -8184,46 +8257,47 @@ impl AsByteSequence for QueryFontReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryFontReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (min_bounds, sz): (Charinfo, usize) = <Charinfo>::from_bytes(&bytes[index..])?;
+        let (min_bounds, sz): (Charinfo, usize) = <Charinfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (max_bounds, sz): (Charinfo, usize) = <Charinfo>::from_bytes(&bytes[index..])?;
+        let (max_bounds, sz): (Charinfo, usize) = <Charinfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (min_char_or_byte2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        index += 4;
+        let (min_char_or_byte2, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_char_or_byte2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (max_char_or_byte2, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (default_char, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (default_char, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (draw_direction, sz): (FontDraw, usize) = <FontDraw>::from_bytes(&bytes[index..])?;
+        let (draw_direction, sz): (FontDraw, usize) = <FontDraw>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (min_byte1, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (min_byte1, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_byte1, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (max_byte1, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (all_chars_exist, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (all_chars_exist, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (font_ascent, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (font_ascent, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (font_descent, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (font_descent, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (properties, block_len): (Vec<Fontprop>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fontprop>());
         let (char_infos, block_len): (Vec<Charinfo>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Charinfo>());
         Some((
@@ -8257,6 +8331,7 @@ impl AsByteSequence for QueryFontReply {
             + self.min_bounds.size()
             + 4
             + self.max_bounds.size()
+            + 4
             + self.min_char_or_byte2.size()
             + self.max_char_or_byte2.size()
             + self.default_char.size()
@@ -8335,14 +8410,16 @@ impl AsByteSequence for QueryTextExtentsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryTextExtentsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (font, sz): (Fontable, usize) = <Fontable>::from_bytes(&bytes[index..])?;
+        let (font, sz): (Fontable, usize) = <Fontable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (string, block_len): (Vec<Char2b>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (string, block_len): (Vec<Char2b>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Char2b>());
         Some((
@@ -8368,6 +8445,7 @@ impl Request for QueryTextExtentsRequest {
     const OPCODE: u8 = 48;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryTextExtents";
     type Reply = QueryTextExtentsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -8406,27 +8484,27 @@ impl AsByteSequence for QueryTextExtentsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryTextExtentsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (draw_direction, sz): (FontDraw, usize) = <FontDraw>::from_bytes(&bytes[index..])?;
+        let (draw_direction, sz): (FontDraw, usize) = <FontDraw>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (font_ascent, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (font_ascent, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (font_descent, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (font_descent, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (overall_ascent, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (overall_ascent, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (overall_descent, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (overall_descent, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (overall_width, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (overall_width, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (overall_left, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (overall_left, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (overall_right, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (overall_right, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryTextExtentsReply {
@@ -8479,9 +8557,10 @@ impl AsByteSequence for Str {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Str from byte buffer");
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) =
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((Str { name: name }, index))
@@ -8521,17 +8600,17 @@ impl AsByteSequence for ListFontsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListFontsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_names, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (max_names, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (pattern, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -8562,6 +8641,7 @@ impl Request for ListFontsRequest {
     const OPCODE: u8 = 49;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListFonts";
     type Reply = ListFontsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -8591,18 +8671,18 @@ impl AsByteSequence for ListFontsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListFontsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
         let (names, block_len): (Vec<Str>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Str>());
         Some((
@@ -8656,17 +8736,17 @@ impl AsByteSequence for ListFontsWithInfoRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListFontsWithInfoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_names, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (max_names, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (pattern, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -8697,6 +8777,7 @@ impl Request for ListFontsWithInfoRequest {
     const OPCODE: u8 = 50;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListFontsWithInfo";
     type Reply = ListFontsWithInfoReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -8731,6 +8812,7 @@ impl AsByteSequence for ListFontsWithInfoReply {
         index += self.min_bounds.as_bytes(&mut bytes[index..]);
         index += 4;
         index += self.max_bounds.as_bytes(&mut bytes[index..]);
+        index += 4;
         index += self.min_char_or_byte2.as_bytes(&mut bytes[index..]);
         index += self.max_char_or_byte2.as_bytes(&mut bytes[index..]);
         index += self.default_char.as_bytes(&mut bytes[index..]);
@@ -8754,46 +8836,48 @@ impl AsByteSequence for ListFontsWithInfoReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListFontsWithInfoReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (min_bounds, sz): (Charinfo, usize) = <Charinfo>::from_bytes(&bytes[index..])?;
+        let (min_bounds, sz): (Charinfo, usize) = <Charinfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (max_bounds, sz): (Charinfo, usize) = <Charinfo>::from_bytes(&bytes[index..])?;
+        let (max_bounds, sz): (Charinfo, usize) = <Charinfo>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (min_char_or_byte2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        index += 4;
+        let (min_char_or_byte2, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_char_or_byte2, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (max_char_or_byte2, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (default_char, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (default_char, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (draw_direction, sz): (FontDraw, usize) = <FontDraw>::from_bytes(&bytes[index..])?;
+        let (draw_direction, sz): (FontDraw, usize) = <FontDraw>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (min_byte1, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (min_byte1, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_byte1, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (max_byte1, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (all_chars_exist, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (all_chars_exist, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (font_ascent, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (font_ascent, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (font_descent, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (font_descent, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (replies_hint, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (replies_hint, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (properties, block_len): (Vec<Fontprop>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Fontprop>());
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) =
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -8828,6 +8912,7 @@ impl AsByteSequence for ListFontsWithInfoReply {
             + self.min_bounds.size()
             + 4
             + self.max_bounds.size()
+            + 4
             + self.min_char_or_byte2.size()
             + self.max_char_or_byte2.size()
             + self.default_char.size()
@@ -8876,16 +8961,16 @@ impl AsByteSequence for SetFontPathRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetFontPathRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (font, block_len): (Vec<Str>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Str>());
         Some((
@@ -8910,6 +8995,7 @@ impl Request for SetFontPathRequest {
     const OPCODE: u8 = 51;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetFontPath";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -8931,10 +9017,10 @@ impl AsByteSequence for GetFontPathRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetFontPathRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetFontPathRequest {
@@ -8953,6 +9039,7 @@ impl Request for GetFontPathRequest {
     const OPCODE: u8 = 52;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetFontPath";
     type Reply = GetFontPathReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -8982,18 +9069,18 @@ impl AsByteSequence for GetFontPathReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetFontPathReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
         let (path, block_len): (Vec<Str>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Str>());
         Some((
@@ -9049,19 +9136,19 @@ impl AsByteSequence for CreatePixmapRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreatePixmapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pid, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (pid, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CreatePixmapRequest {
@@ -9091,6 +9178,7 @@ impl Request for CreatePixmapRequest {
     const OPCODE: u8 = 53;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreatePixmap";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -9114,12 +9202,12 @@ impl AsByteSequence for FreePixmapRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FreePixmapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (pixmap, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             FreePixmapRequest {
@@ -9139,6 +9227,7 @@ impl Request for FreePixmapRequest {
     const OPCODE: u8 = 54;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FreePixmap";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -9259,118 +9348,123 @@ impl AsByteSequence for CreateGcRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateGcRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cid, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (cid, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value_mask, sz): (Gc, usize) = <Gc>::from_bytes(&bytes[index..])?;
+        let (value_mask, sz): (Gc, usize) = <Gc>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let cond0 = (value_mask);
         let function: Gx = if cond0.function() {
-            let (function, sz): (Gx, usize) = <Gx>::from_bytes(&bytes[index..])?;
+            let (function, sz): (Gx, usize) = <Gx>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             function
         } else {
             Default::default()
         };
         let plane_mask: Card32 = if cond0.plane_mask() {
-            let (plane_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (plane_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             plane_mask
         } else {
             Default::default()
         };
         let foreground: Card32 = if cond0.foreground() {
-            let (foreground, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (foreground, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             foreground
         } else {
             Default::default()
         };
         let background: Card32 = if cond0.background() {
-            let (background, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (background, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             background
         } else {
             Default::default()
         };
         let line_width: Card32 = if cond0.line_width() {
-            let (line_width, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (line_width, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             line_width
         } else {
             Default::default()
         };
         let line_style: LineStyle = if cond0.line_style() {
-            let (line_style, sz): (LineStyle, usize) = <LineStyle>::from_bytes(&bytes[index..])?;
+            let (line_style, sz): (LineStyle, usize) =
+                <LineStyle>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             line_style
         } else {
             Default::default()
         };
         let cap_style: CapStyle = if cond0.cap_style() {
-            let (cap_style, sz): (CapStyle, usize) = <CapStyle>::from_bytes(&bytes[index..])?;
+            let (cap_style, sz): (CapStyle, usize) = <CapStyle>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             cap_style
         } else {
             Default::default()
         };
         let join_style: JoinStyle = if cond0.join_style() {
-            let (join_style, sz): (JoinStyle, usize) = <JoinStyle>::from_bytes(&bytes[index..])?;
+            let (join_style, sz): (JoinStyle, usize) =
+                <JoinStyle>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             join_style
         } else {
             Default::default()
         };
         let fill_style: FillStyle = if cond0.fill_style() {
-            let (fill_style, sz): (FillStyle, usize) = <FillStyle>::from_bytes(&bytes[index..])?;
+            let (fill_style, sz): (FillStyle, usize) =
+                <FillStyle>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             fill_style
         } else {
             Default::default()
         };
         let fill_rule: FillRule = if cond0.fill_rule() {
-            let (fill_rule, sz): (FillRule, usize) = <FillRule>::from_bytes(&bytes[index..])?;
+            let (fill_rule, sz): (FillRule, usize) = <FillRule>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             fill_rule
         } else {
             Default::default()
         };
         let tile: Pixmap = if cond0.tile() {
-            let (tile, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+            let (tile, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             tile
         } else {
             Default::default()
         };
         let stipple: Pixmap = if cond0.stipple() {
-            let (stipple, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+            let (stipple, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             stipple
         } else {
             Default::default()
         };
         let tile_stipple_x_origin: Int32 = if cond0.tile_stipple_origin_x() {
-            let (tile_stipple_x_origin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (tile_stipple_x_origin, sz): (Int32, usize) =
+                <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             tile_stipple_x_origin
         } else {
             Default::default()
         };
         let tile_stipple_y_origin: Int32 = if cond0.tile_stipple_origin_y() {
-            let (tile_stipple_y_origin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (tile_stipple_y_origin, sz): (Int32, usize) =
+                <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             tile_stipple_y_origin
         } else {
             Default::default()
         };
         let font: Font = if cond0.font() {
-            let (font, sz): (Font, usize) = <Font>::from_bytes(&bytes[index..])?;
+            let (font, sz): (Font, usize) = <Font>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             font
         } else {
@@ -9378,56 +9472,57 @@ impl AsByteSequence for CreateGcRequest {
         };
         let subwindow_mode: SubwindowMode = if cond0.subwindow_mode() {
             let (subwindow_mode, sz): (SubwindowMode, usize) =
-                <SubwindowMode>::from_bytes(&bytes[index..])?;
+                <SubwindowMode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             subwindow_mode
         } else {
             Default::default()
         };
         let graphics_exposures: Bool32 = if cond0.graphics_exposures() {
-            let (graphics_exposures, sz): (Bool32, usize) = <Bool32>::from_bytes(&bytes[index..])?;
+            let (graphics_exposures, sz): (Bool32, usize) =
+                <Bool32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             graphics_exposures
         } else {
             Default::default()
         };
         let clip_x_origin: Int32 = if cond0.clip_origin_x() {
-            let (clip_x_origin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (clip_x_origin, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             clip_x_origin
         } else {
             Default::default()
         };
         let clip_y_origin: Int32 = if cond0.clip_origin_y() {
-            let (clip_y_origin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (clip_y_origin, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             clip_y_origin
         } else {
             Default::default()
         };
         let clip_mask: Pixmap = if cond0.clip_mask() {
-            let (clip_mask, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+            let (clip_mask, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             clip_mask
         } else {
             Default::default()
         };
         let dash_offset: Card32 = if cond0.dash_offset() {
-            let (dash_offset, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (dash_offset, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             dash_offset
         } else {
             Default::default()
         };
         let dashes: Card32 = if cond0.dash_list() {
-            let (dashes, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (dashes, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             dashes
         } else {
             Default::default()
         };
         let arc_mode: ArcMode = if cond0.arc_mode() {
-            let (arc_mode, sz): (ArcMode, usize) = <ArcMode>::from_bytes(&bytes[index..])?;
+            let (arc_mode, sz): (ArcMode, usize) = <ArcMode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             arc_mode
         } else {
@@ -9504,6 +9599,7 @@ impl Request for CreateGcRequest {
     const OPCODE: u8 = 55;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateGc";
     type Reply = ();
 }
 #[repr(transparent)]
@@ -10103,116 +10199,121 @@ impl AsByteSequence for ChangeGcRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeGcRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value_mask, sz): (Gc, usize) = <Gc>::from_bytes(&bytes[index..])?;
+        let (value_mask, sz): (Gc, usize) = <Gc>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let cond0 = (value_mask);
         let function: Gx = if cond0.function() {
-            let (function, sz): (Gx, usize) = <Gx>::from_bytes(&bytes[index..])?;
+            let (function, sz): (Gx, usize) = <Gx>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             function
         } else {
             Default::default()
         };
         let plane_mask: Card32 = if cond0.plane_mask() {
-            let (plane_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (plane_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             plane_mask
         } else {
             Default::default()
         };
         let foreground: Card32 = if cond0.foreground() {
-            let (foreground, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (foreground, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             foreground
         } else {
             Default::default()
         };
         let background: Card32 = if cond0.background() {
-            let (background, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (background, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             background
         } else {
             Default::default()
         };
         let line_width: Card32 = if cond0.line_width() {
-            let (line_width, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (line_width, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             line_width
         } else {
             Default::default()
         };
         let line_style: LineStyle = if cond0.line_style() {
-            let (line_style, sz): (LineStyle, usize) = <LineStyle>::from_bytes(&bytes[index..])?;
+            let (line_style, sz): (LineStyle, usize) =
+                <LineStyle>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             line_style
         } else {
             Default::default()
         };
         let cap_style: CapStyle = if cond0.cap_style() {
-            let (cap_style, sz): (CapStyle, usize) = <CapStyle>::from_bytes(&bytes[index..])?;
+            let (cap_style, sz): (CapStyle, usize) = <CapStyle>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             cap_style
         } else {
             Default::default()
         };
         let join_style: JoinStyle = if cond0.join_style() {
-            let (join_style, sz): (JoinStyle, usize) = <JoinStyle>::from_bytes(&bytes[index..])?;
+            let (join_style, sz): (JoinStyle, usize) =
+                <JoinStyle>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             join_style
         } else {
             Default::default()
         };
         let fill_style: FillStyle = if cond0.fill_style() {
-            let (fill_style, sz): (FillStyle, usize) = <FillStyle>::from_bytes(&bytes[index..])?;
+            let (fill_style, sz): (FillStyle, usize) =
+                <FillStyle>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             fill_style
         } else {
             Default::default()
         };
         let fill_rule: FillRule = if cond0.fill_rule() {
-            let (fill_rule, sz): (FillRule, usize) = <FillRule>::from_bytes(&bytes[index..])?;
+            let (fill_rule, sz): (FillRule, usize) = <FillRule>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             fill_rule
         } else {
             Default::default()
         };
         let tile: Pixmap = if cond0.tile() {
-            let (tile, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+            let (tile, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             tile
         } else {
             Default::default()
         };
         let stipple: Pixmap = if cond0.stipple() {
-            let (stipple, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+            let (stipple, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             stipple
         } else {
             Default::default()
         };
         let tile_stipple_x_origin: Int32 = if cond0.tile_stipple_origin_x() {
-            let (tile_stipple_x_origin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (tile_stipple_x_origin, sz): (Int32, usize) =
+                <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             tile_stipple_x_origin
         } else {
             Default::default()
         };
         let tile_stipple_y_origin: Int32 = if cond0.tile_stipple_origin_y() {
-            let (tile_stipple_y_origin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (tile_stipple_y_origin, sz): (Int32, usize) =
+                <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             tile_stipple_y_origin
         } else {
             Default::default()
         };
         let font: Font = if cond0.font() {
-            let (font, sz): (Font, usize) = <Font>::from_bytes(&bytes[index..])?;
+            let (font, sz): (Font, usize) = <Font>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             font
         } else {
@@ -10220,56 +10321,57 @@ impl AsByteSequence for ChangeGcRequest {
         };
         let subwindow_mode: SubwindowMode = if cond0.subwindow_mode() {
             let (subwindow_mode, sz): (SubwindowMode, usize) =
-                <SubwindowMode>::from_bytes(&bytes[index..])?;
+                <SubwindowMode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             subwindow_mode
         } else {
             Default::default()
         };
         let graphics_exposures: Bool32 = if cond0.graphics_exposures() {
-            let (graphics_exposures, sz): (Bool32, usize) = <Bool32>::from_bytes(&bytes[index..])?;
+            let (graphics_exposures, sz): (Bool32, usize) =
+                <Bool32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             graphics_exposures
         } else {
             Default::default()
         };
         let clip_x_origin: Int32 = if cond0.clip_origin_x() {
-            let (clip_x_origin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (clip_x_origin, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             clip_x_origin
         } else {
             Default::default()
         };
         let clip_y_origin: Int32 = if cond0.clip_origin_y() {
-            let (clip_y_origin, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (clip_y_origin, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             clip_y_origin
         } else {
             Default::default()
         };
         let clip_mask: Pixmap = if cond0.clip_mask() {
-            let (clip_mask, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+            let (clip_mask, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             clip_mask
         } else {
             Default::default()
         };
         let dash_offset: Card32 = if cond0.dash_offset() {
-            let (dash_offset, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (dash_offset, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             dash_offset
         } else {
             Default::default()
         };
         let dashes: Card32 = if cond0.dash_list() {
-            let (dashes, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (dashes, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             dashes
         } else {
             Default::default()
         };
         let arc_mode: ArcMode = if cond0.arc_mode() {
-            let (arc_mode, sz): (ArcMode, usize) = <ArcMode>::from_bytes(&bytes[index..])?;
+            let (arc_mode, sz): (ArcMode, usize) = <ArcMode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             arc_mode
         } else {
@@ -10344,6 +10446,7 @@ impl Request for ChangeGcRequest {
     const OPCODE: u8 = 56;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeGc";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -10371,16 +10474,16 @@ impl AsByteSequence for CopyGcRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CopyGcRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (src_gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst_gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (dst_gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value_mask, sz): (Gc, usize) = <Gc>::from_bytes(&bytes[index..])?;
+        let (value_mask, sz): (Gc, usize) = <Gc>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CopyGcRequest {
@@ -10407,6 +10510,7 @@ impl Request for CopyGcRequest {
     const OPCODE: u8 = 57;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CopyGc";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -10437,19 +10541,19 @@ impl AsByteSequence for SetDashesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetDashesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dash_offset, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (dash_offset, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (dashes, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
         Some((
@@ -10482,6 +10586,7 @@ impl Request for SetDashesRequest {
     const OPCODE: u8 = 58;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetDashes";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -10514,20 +10619,23 @@ impl AsByteSequence for SetClipRectanglesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetClipRectanglesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ordering, sz): (ClipOrdering, usize) = <ClipOrdering>::from_bytes(&bytes[index..])?;
+        let (ordering, sz): (ClipOrdering, usize) =
+            <ClipOrdering>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (clip_x_origin, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (clip_x_origin, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (clip_y_origin, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (clip_y_origin, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (rectangles, block_len): (Vec<Rectangle>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (rectangles, block_len): (Vec<Rectangle>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
         Some((
@@ -10562,6 +10670,7 @@ impl Request for SetClipRectanglesRequest {
     const OPCODE: u8 = 59;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetClipRectangles";
     type Reply = ();
 }
 #[repr(u8)]
@@ -10620,12 +10729,12 @@ impl AsByteSequence for FreeGcRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FreeGcRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             FreeGcRequest {
@@ -10645,6 +10754,7 @@ impl Request for FreeGcRequest {
     const OPCODE: u8 = 60;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FreeGc";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -10677,21 +10787,21 @@ impl AsByteSequence for ClearAreaRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ClearAreaRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (exposures, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (exposures, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ClearAreaRequest {
@@ -10723,6 +10833,7 @@ impl Request for ClearAreaRequest {
     const OPCODE: u8 = 61;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ClearArea";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -10762,28 +10873,28 @@ impl AsByteSequence for CopyAreaRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CopyAreaRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (src_drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst_drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (dst_drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (dst_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (dst_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CopyAreaRequest {
@@ -10822,6 +10933,7 @@ impl Request for CopyAreaRequest {
     const OPCODE: u8 = 62;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CopyArea";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -10863,30 +10975,30 @@ impl AsByteSequence for CopyPlaneRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CopyPlaneRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (src_drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst_drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (dst_drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (dst_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (dst_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bit_plane, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bit_plane, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CopyPlaneRequest {
@@ -10927,6 +11039,7 @@ impl Request for CopyPlaneRequest {
     const OPCODE: u8 = 63;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CopyPlane";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -10957,18 +11070,21 @@ impl AsByteSequence for PolyPointRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PolyPointRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (coordinate_mode, sz): (CoordMode, usize) = <CoordMode>::from_bytes(&bytes[index..])?;
+        let (coordinate_mode, sz): (CoordMode, usize) =
+            <CoordMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (points, block_len): (Vec<Point>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (points, block_len): (Vec<Point>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Point>());
         Some((
@@ -11001,6 +11117,7 @@ impl Request for PolyPointRequest {
     const OPCODE: u8 = 64;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PolyPoint";
     type Reply = ();
 }
 #[repr(u8)]
@@ -11062,18 +11179,21 @@ impl AsByteSequence for PolyLineRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PolyLineRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (coordinate_mode, sz): (CoordMode, usize) = <CoordMode>::from_bytes(&bytes[index..])?;
+        let (coordinate_mode, sz): (CoordMode, usize) =
+            <CoordMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (points, block_len): (Vec<Point>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (points, block_len): (Vec<Point>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Point>());
         Some((
@@ -11106,6 +11226,7 @@ impl Request for PolyLineRequest {
     const OPCODE: u8 = 65;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PolyLine";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -11130,13 +11251,13 @@ impl AsByteSequence for Segment {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Segment from byte buffer");
-        let (x1, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x1, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y1, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y1, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x2, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x2, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y2, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y2, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Segment {
@@ -11180,17 +11301,19 @@ impl AsByteSequence for PolySegmentRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PolySegmentRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (segments, block_len): (Vec<Segment>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (segments, block_len): (Vec<Segment>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Segment>());
         Some((
@@ -11217,6 +11340,7 @@ impl Request for PolySegmentRequest {
     const OPCODE: u8 = 66;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PolySegment";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -11246,17 +11370,19 @@ impl AsByteSequence for PolyRectangleRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PolyRectangleRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (rectangles, block_len): (Vec<Rectangle>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (rectangles, block_len): (Vec<Rectangle>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
         Some((
@@ -11283,6 +11409,7 @@ impl Request for PolyRectangleRequest {
     const OPCODE: u8 = 67;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PolyRectangle";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -11312,17 +11439,19 @@ impl AsByteSequence for PolyArcRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PolyArcRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (arcs, block_len): (Vec<Arc>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (arcs, block_len): (Vec<Arc>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Arc>());
         Some((
@@ -11349,6 +11478,7 @@ impl Request for PolyArcRequest {
     const OPCODE: u8 = 68;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PolyArc";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -11383,22 +11513,25 @@ impl AsByteSequence for FillPolyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FillPolyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (shape, sz): (PolyShape, usize) = <PolyShape>::from_bytes(&bytes[index..])?;
+        let (shape, sz): (PolyShape, usize) = <PolyShape>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (coordinate_mode, sz): (CoordMode, usize) = <CoordMode>::from_bytes(&bytes[index..])?;
+        let (coordinate_mode, sz): (CoordMode, usize) =
+            <CoordMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (points, block_len): (Vec<Point>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (points, block_len): (Vec<Point>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Point>());
         Some((
@@ -11435,6 +11568,7 @@ impl Request for FillPolyRequest {
     const OPCODE: u8 = 69;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FillPoly";
     type Reply = ();
 }
 #[repr(u8)]
@@ -11497,17 +11631,19 @@ impl AsByteSequence for PolyFillRectangleRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PolyFillRectangleRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (rectangles, block_len): (Vec<Rectangle>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (rectangles, block_len): (Vec<Rectangle>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Rectangle>());
         Some((
@@ -11534,6 +11670,7 @@ impl Request for PolyFillRectangleRequest {
     const OPCODE: u8 = 70;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PolyFillRectangle";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -11563,17 +11700,19 @@ impl AsByteSequence for PolyFillArcRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PolyFillArcRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (arcs, block_len): (Vec<Arc>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (arcs, block_len): (Vec<Arc>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Arc>());
         Some((
@@ -11600,6 +11739,7 @@ impl Request for PolyFillArcRequest {
     const OPCODE: u8 = 71;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PolyFillArc";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -11643,31 +11783,33 @@ impl AsByteSequence for PutImageRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PutImageRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (ImageFormat, usize) = <ImageFormat>::from_bytes(&bytes[index..])?;
+        let (format, sz): (ImageFormat, usize) = <ImageFormat>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (dst_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (dst_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (dst_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (left_pad, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (left_pad, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (data, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (data, block_len): (Vec<Byte>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -11713,6 +11855,7 @@ impl Request for PutImageRequest {
     const OPCODE: u8 = 72;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PutImage";
     type Reply = ();
 }
 #[repr(u8)]
@@ -11780,23 +11923,23 @@ impl AsByteSequence for GetImageRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetImageRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (ImageFormat, usize) = <ImageFormat>::from_bytes(&bytes[index..])?;
+        let (format, sz): (ImageFormat, usize) = <ImageFormat>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (plane_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (plane_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetImageRequest {
@@ -11830,6 +11973,7 @@ impl Request for GetImageRequest {
     const OPCODE: u8 = 73;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetImage";
     type Reply = GetImageReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -11861,19 +12005,19 @@ impl AsByteSequence for GetImageReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetImageReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(&bytes[index..])?;
+        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (data, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize) * (4)) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, ((length as usize) * (4)) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -11934,21 +12078,23 @@ impl AsByteSequence for PolyText8Request {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PolyText8Request from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (items, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (items, block_len): (Vec<Byte>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -11984,6 +12130,7 @@ impl Request for PolyText8Request {
     const OPCODE: u8 = 74;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PolyText8";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -12017,21 +12164,23 @@ impl AsByteSequence for PolyText16Request {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PolyText16Request from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (items, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (items, block_len): (Vec<Byte>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -12067,6 +12216,7 @@ impl Request for PolyText16Request {
     const OPCODE: u8 = 75;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PolyText16";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -12100,22 +12250,22 @@ impl AsByteSequence for ImageText8Request {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ImageText8Request from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Byte, usize) = <Byte>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (string, block_len): (String, usize) =
-            string_from_bytes(&bytes[index..], len0 as usize)?;
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -12151,6 +12301,7 @@ impl Request for ImageText8Request {
     const OPCODE: u8 = 76;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ImageText8";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -12184,22 +12335,22 @@ impl AsByteSequence for ImageText16Request {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ImageText16Request from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Byte, usize) = <Byte>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (string, block_len): (Vec<Char2b>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Char2b>());
         Some((
@@ -12235,6 +12386,7 @@ impl Request for ImageText16Request {
     const OPCODE: u8 = 77;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ImageText16";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -12263,17 +12415,17 @@ impl AsByteSequence for CreateColormapRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateColormapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (alloc, sz): (ColormapAlloc, usize) = <ColormapAlloc>::from_bytes(&bytes[index..])?;
+        let (alloc, sz): (ColormapAlloc, usize) = <ColormapAlloc>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mid, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+        let (mid, sz): (Colormap, usize) = <Colormap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(&bytes[index..])?;
+        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CreateColormapRequest {
@@ -12301,6 +12453,7 @@ impl Request for CreateColormapRequest {
     const OPCODE: u8 = 78;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateColormap";
     type Reply = ();
 }
 #[repr(u8)]
@@ -12355,12 +12508,12 @@ impl AsByteSequence for FreeColormapRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FreeColormapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             FreeColormapRequest {
@@ -12380,6 +12533,7 @@ impl Request for FreeColormapRequest {
     const OPCODE: u8 = 79;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FreeColormap";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -12405,14 +12559,14 @@ impl AsByteSequence for CopyColormapAndFreeRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CopyColormapAndFreeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mid, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+        let (mid, sz): (Colormap, usize) = <Colormap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+        let (src_cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CopyColormapAndFreeRequest {
@@ -12433,6 +12587,7 @@ impl Request for CopyColormapAndFreeRequest {
     const OPCODE: u8 = 80;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CopyColormapAndFree";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -12456,12 +12611,12 @@ impl AsByteSequence for InstallColormapRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing InstallColormapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             InstallColormapRequest {
@@ -12481,6 +12636,7 @@ impl Request for InstallColormapRequest {
     const OPCODE: u8 = 81;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "InstallColormap";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -12504,12 +12660,12 @@ impl AsByteSequence for UninstallColormapRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing UninstallColormapRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             UninstallColormapRequest {
@@ -12529,6 +12685,7 @@ impl Request for UninstallColormapRequest {
     const OPCODE: u8 = 82;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UninstallColormap";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -12552,12 +12709,12 @@ impl AsByteSequence for ListInstalledColormapsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListInstalledColormapsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ListInstalledColormapsRequest {
@@ -12577,6 +12734,7 @@ impl Request for ListInstalledColormapsRequest {
     const OPCODE: u8 = 83;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListInstalledColormaps";
     type Reply = ListInstalledColormapsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -12606,18 +12764,18 @@ impl AsByteSequence for ListInstalledColormapsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListInstalledColormapsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
         let (cmaps, block_len): (Vec<Colormap>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Colormap>());
         Some((
@@ -12673,18 +12831,18 @@ impl AsByteSequence for AllocColorRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AllocColorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (red, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (red, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (green, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (green, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (blue, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (blue, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -12715,6 +12873,7 @@ impl Request for AllocColorRequest {
     const OPCODE: u8 = 84;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "AllocColor";
     type Reply = AllocColorReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -12747,21 +12906,21 @@ impl AsByteSequence for AllocColorReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AllocColorReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (red, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (red, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (green, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (green, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (blue, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (blue, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (pixel, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pixel, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             AllocColorReply {
@@ -12816,17 +12975,18 @@ impl AsByteSequence for AllocNamedColorRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AllocNamedColorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) =
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -12858,6 +13018,7 @@ impl Request for AllocNamedColorRequest {
     const OPCODE: u8 = 85;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "AllocNamedColor";
     type Reply = AllocNamedColorReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -12895,26 +13056,26 @@ impl AsByteSequence for AllocNamedColorReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AllocNamedColorReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pixel, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pixel, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (exact_red, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (exact_red, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (exact_green, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (exact_green, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (exact_blue, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (exact_blue, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (visual_red, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (visual_red, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (visual_green, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (visual_green, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (visual_blue, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (visual_blue, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             AllocNamedColorReply {
@@ -12973,17 +13134,17 @@ impl AsByteSequence for AllocColorCellsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AllocColorCellsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (contiguous, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (contiguous, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (colors, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (colors, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (planes, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (planes, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             AllocColorCellsRequest {
@@ -13011,6 +13172,7 @@ impl Request for AllocColorCellsRequest {
     const OPCODE: u8 = 86;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "AllocColorCells";
     type Reply = AllocColorCellsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -13045,24 +13207,24 @@ impl AsByteSequence for AllocColorCellsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AllocColorCellsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (pixels, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (masks, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -13127,21 +13289,21 @@ impl AsByteSequence for AllocColorPlanesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AllocColorPlanesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (contiguous, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (contiguous, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (colors, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (colors, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (reds, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (reds, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (greens, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (greens, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (blues, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (blues, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             AllocColorPlanesRequest {
@@ -13173,6 +13335,7 @@ impl Request for AllocColorPlanesRequest {
     const OPCODE: u8 = 87;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "AllocColorPlanes";
     type Reply = AllocColorPlanesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -13209,25 +13372,25 @@ impl AsByteSequence for AllocColorPlanesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AllocColorPlanesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (red_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (red_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (green_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (green_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (blue_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (blue_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 8;
         let (pixels, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -13289,17 +13452,19 @@ impl AsByteSequence for FreeColorsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FreeColorsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (plane_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (plane_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pixels, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (pixels, block_len): (Vec<Card32>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -13331,6 +13496,7 @@ impl Request for FreeColorsRequest {
     const OPCODE: u8 = 88;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FreeColors";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -13358,15 +13524,15 @@ impl AsByteSequence for Coloritem {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Coloritem from byte buffer");
-        let (pixel, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pixel, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (red, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (red, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (green, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (green, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (blue, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (blue, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (ColorFlag, usize) = <ColorFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (ColorFlag, usize) = <ColorFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -13532,15 +13698,17 @@ impl AsByteSequence for StoreColorsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing StoreColorsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (items, block_len): (Vec<Coloritem>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (items, block_len): (Vec<Coloritem>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Coloritem>());
         Some((
@@ -13566,6 +13734,7 @@ impl Request for StoreColorsRequest {
     const OPCODE: u8 = 89;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "StoreColors";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -13598,20 +13767,21 @@ impl AsByteSequence for StoreNamedColorRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing StoreNamedColorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (ColorFlag, usize) = <ColorFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (ColorFlag, usize) = <ColorFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pixel, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (pixel, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) =
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -13646,6 +13816,7 @@ impl Request for StoreNamedColorRequest {
     const OPCODE: u8 = 90;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "StoreNamedColor";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -13669,11 +13840,11 @@ impl AsByteSequence for Rgb {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Rgb from byte buffer");
-        let (red, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (red, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (green, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (green, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (blue, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (blue, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -13715,15 +13886,17 @@ impl AsByteSequence for QueryColorsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryColorsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pixels, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (pixels, block_len): (Vec<Card32>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -13749,6 +13922,7 @@ impl Request for QueryColorsRequest {
     const OPCODE: u8 = 91;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryColors";
     type Reply = QueryColorsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -13778,18 +13952,18 @@ impl AsByteSequence for QueryColorsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryColorsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
         let (colors, block_len): (Vec<Rgb>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Rgb>());
         Some((
@@ -13844,17 +14018,18 @@ impl AsByteSequence for LookupColorRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing LookupColorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+        let (cmap, sz): (Colormap, usize) = <Colormap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) =
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -13886,6 +14061,7 @@ impl Request for LookupColorRequest {
     const OPCODE: u8 = 92;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "LookupColor";
     type Reply = LookupColorReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -13921,24 +14097,24 @@ impl AsByteSequence for LookupColorReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing LookupColorReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (exact_red, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (exact_red, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (exact_green, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (exact_green, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (exact_blue, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (exact_blue, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (visual_red, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (visual_red, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (visual_green, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (visual_green, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (visual_blue, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (visual_blue, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             LookupColorReply {
@@ -14010,32 +14186,32 @@ impl AsByteSequence for CreateCursorRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateCursorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cid, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (cid, sz): (Cursor, usize) = <Cursor>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (source, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (source, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mask, sz): (Pixmap, usize) = <Pixmap>::from_bytes(&bytes[index..])?;
+        let (mask, sz): (Pixmap, usize) = <Pixmap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fore_red, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (fore_red, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fore_green, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (fore_green, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fore_blue, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (fore_blue, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (back_red, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (back_red, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (back_green, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (back_green, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (back_blue, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (back_blue, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CreateCursorRequest {
@@ -14078,6 +14254,7 @@ impl Request for CreateCursorRequest {
     const OPCODE: u8 = 93;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateCursor";
     type Reply = ();
 }
 pub const PIXMAP_NONE: Pixmap = <Pixmap>::const_from_xid(0);
@@ -14122,32 +14299,32 @@ impl AsByteSequence for CreateGlyphCursorRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateGlyphCursorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cid, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (cid, sz): (Cursor, usize) = <Cursor>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (source_font, sz): (Font, usize) = <Font>::from_bytes(&bytes[index..])?;
+        let (source_font, sz): (Font, usize) = <Font>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mask_font, sz): (Font, usize) = <Font>::from_bytes(&bytes[index..])?;
+        let (mask_font, sz): (Font, usize) = <Font>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (source_char, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (source_char, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mask_char, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (mask_char, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fore_red, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (fore_red, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fore_green, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (fore_green, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fore_blue, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (fore_blue, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (back_red, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (back_red, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (back_green, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (back_green, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (back_blue, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (back_blue, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CreateGlyphCursorRequest {
@@ -14190,6 +14367,7 @@ impl Request for CreateGlyphCursorRequest {
     const OPCODE: u8 = 94;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateGlyphCursor";
     type Reply = ();
 }
 pub const FONT_NONE: Font = <Font>::const_from_xid(0);
@@ -14214,12 +14392,12 @@ impl AsByteSequence for FreeCursorRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FreeCursorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             FreeCursorRequest {
@@ -14239,6 +14417,7 @@ impl Request for FreeCursorRequest {
     const OPCODE: u8 = 95;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "FreeCursor";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -14274,24 +14453,24 @@ impl AsByteSequence for RecolorCursorRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing RecolorCursorRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(&bytes[index..])?;
+        let (cursor, sz): (Cursor, usize) = <Cursor>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fore_red, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (fore_red, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fore_green, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (fore_green, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (fore_blue, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (fore_blue, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (back_red, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (back_red, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (back_green, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (back_green, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (back_blue, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (back_blue, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             RecolorCursorRequest {
@@ -14326,6 +14505,7 @@ impl Request for RecolorCursorRequest {
     const OPCODE: u8 = 96;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "RecolorCursor";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -14354,17 +14534,17 @@ impl AsByteSequence for QueryBestSizeRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryBestSizeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (class, sz): (QueryShapeOf, usize) = <QueryShapeOf>::from_bytes(&bytes[index..])?;
+        let (class, sz): (QueryShapeOf, usize) = <QueryShapeOf>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryBestSizeRequest {
@@ -14392,6 +14572,7 @@ impl Request for QueryBestSizeRequest {
     const OPCODE: u8 = 97;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryBestSize";
     type Reply = QueryBestSizeReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -14419,16 +14600,16 @@ impl AsByteSequence for QueryBestSizeReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryBestSizeReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryBestSizeReply {
@@ -14509,15 +14690,16 @@ impl AsByteSequence for QueryExtensionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryExtensionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) =
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<c_char>());
         Some((
@@ -14542,6 +14724,7 @@ impl Request for QueryExtensionRequest {
     const OPCODE: u8 = 98;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryExtension";
     type Reply = QueryExtensionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -14573,20 +14756,20 @@ impl AsByteSequence for QueryExtensionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryExtensionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (present, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (present, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_event, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_event, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_error, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (first_error, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryExtensionReply {
@@ -14632,10 +14815,10 @@ impl AsByteSequence for ListExtensionsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListExtensionsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ListExtensionsRequest {
@@ -14654,6 +14837,7 @@ impl Request for ListExtensionsRequest {
     const OPCODE: u8 = 99;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListExtensions";
     type Reply = ListExtensionsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -14682,17 +14866,17 @@ impl AsByteSequence for ListExtensionsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListExtensionsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 24;
         let (names, block_len): (Vec<Str>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Str>());
         Some((
@@ -14748,19 +14932,19 @@ impl AsByteSequence for ChangeKeyboardMappingRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeKeyboardMappingRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (keycode_count, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (keycode_count, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (keysyms_per_keycode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (keysyms_per_keycode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         let (keysyms, block_len): (Vec<Keysym>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             ((keycode_count as usize) * (keysyms_per_keycode as usize)) as usize,
         )?;
         index += block_len;
@@ -14796,6 +14980,7 @@ impl Request for ChangeKeyboardMappingRequest {
     const OPCODE: u8 = 100;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeKeyboardMapping";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -14821,14 +15006,14 @@ impl AsByteSequence for GetKeyboardMappingRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetKeyboardMappingRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (count, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (count, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetKeyboardMappingRequest {
@@ -14853,6 +15038,7 @@ impl Request for GetKeyboardMappingRequest {
     const OPCODE: u8 = 101;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetKeyboardMapping";
     type Reply = GetKeyboardMappingReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -14882,17 +15068,17 @@ impl AsByteSequence for GetKeyboardMappingReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetKeyboardMappingReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (keysyms_per_keycode, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (keysyms_per_keycode, sz): (Byte, usize) = <Byte>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 24;
         let (keysyms, block_len): (Vec<Keysym>, usize) =
-            vector_from_bytes(&bytes[index..], (length as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (length as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Keysym>());
         Some((
@@ -14974,58 +15160,58 @@ impl AsByteSequence for ChangeKeyboardControlRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeKeyboardControlRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value_mask, sz): (Kb, usize) = <Kb>::from_bytes(&bytes[index..])?;
+        let (value_mask, sz): (Kb, usize) = <Kb>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let cond0 = (value_mask);
         let key_click_percent: Int32 = if cond0.key_click_percent() {
-            let (key_click_percent, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (key_click_percent, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             key_click_percent
         } else {
             Default::default()
         };
         let bell_percent: Int32 = if cond0.bell_percent() {
-            let (bell_percent, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (bell_percent, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             bell_percent
         } else {
             Default::default()
         };
         let bell_pitch: Int32 = if cond0.bell_pitch() {
-            let (bell_pitch, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (bell_pitch, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             bell_pitch
         } else {
             Default::default()
         };
         let bell_duration: Int32 = if cond0.bell_duration() {
-            let (bell_duration, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+            let (bell_duration, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             bell_duration
         } else {
             Default::default()
         };
         let led: Card32 = if cond0.led() {
-            let (led, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+            let (led, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             led
         } else {
             Default::default()
         };
         let led_mode: LedMode = if cond0.led_mode() {
-            let (led_mode, sz): (LedMode, usize) = <LedMode>::from_bytes(&bytes[index..])?;
+            let (led_mode, sz): (LedMode, usize) = <LedMode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             led_mode
         } else {
             Default::default()
         };
         let key: Keycode32 = if cond0.key() {
-            let (key, sz): (Keycode32, usize) = <Keycode32>::from_bytes(&bytes[index..])?;
+            let (key, sz): (Keycode32, usize) = <Keycode32>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             key
         } else {
@@ -15033,7 +15219,7 @@ impl AsByteSequence for ChangeKeyboardControlRequest {
         };
         let auto_repeat_mode: AutoRepeatMode = if cond0.auto_repeat_mode() {
             let (auto_repeat_mode, sz): (AutoRepeatMode, usize) =
-                <AutoRepeatMode>::from_bytes(&bytes[index..])?;
+                <AutoRepeatMode>::from_bytes(bytes.get(index..)?)?;
             index += sz;
             auto_repeat_mode
         } else {
@@ -15076,6 +15262,7 @@ impl Request for ChangeKeyboardControlRequest {
     const OPCODE: u8 = 102;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeKeyboardControl";
     type Reply = ();
 }
 #[repr(transparent)]
@@ -15308,10 +15495,10 @@ impl AsByteSequence for GetKeyboardControlRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetKeyboardControlRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetKeyboardControlRequest {
@@ -15330,6 +15517,7 @@ impl Request for GetKeyboardControlRequest {
     const OPCODE: u8 = 103;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetKeyboardControl";
     type Reply = GetKeyboardControlReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -15367,27 +15555,28 @@ impl AsByteSequence for GetKeyboardControlReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetKeyboardControlReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (global_auto_repeat, sz): (AutoRepeatMode, usize) =
-            <AutoRepeatMode>::from_bytes(&bytes[index..])?;
+            <AutoRepeatMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (led_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (led_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (key_click_percent, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (key_click_percent, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bell_percent, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (bell_percent, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bell_pitch, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (bell_pitch, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bell_duration, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (bell_duration, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (auto_repeats, sz): ([Card8; 32], usize) = <[Card8; 32]>::from_bytes(&bytes[index..])?;
+        let (auto_repeats, sz): ([Card8; 32], usize) =
+            <[Card8; 32]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetKeyboardControlReply {
@@ -15473,11 +15662,11 @@ impl AsByteSequence for BellRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing BellRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (percent, sz): (Int8, usize) = <Int8>::from_bytes(&bytes[index..])?;
+        let (percent, sz): (Int8, usize) = <Int8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             BellRequest {
@@ -15497,6 +15686,7 @@ impl Request for BellRequest {
     const OPCODE: u8 = 104;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "Bell";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -15528,20 +15718,22 @@ impl AsByteSequence for ChangePointerControlRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangePointerControlRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (acceleration_numerator, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (acceleration_numerator, sz): (Int16, usize) =
+            <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (acceleration_denominator, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (acceleration_denominator, sz): (Int16, usize) =
+            <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (threshold, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (threshold, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (do_acceleration, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (do_acceleration, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (do_threshold, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (do_threshold, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ChangePointerControlRequest {
@@ -15572,6 +15764,7 @@ impl Request for ChangePointerControlRequest {
     const OPCODE: u8 = 105;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangePointerControl";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -15593,10 +15786,10 @@ impl AsByteSequence for GetPointerControlRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPointerControlRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetPointerControlRequest {
@@ -15615,6 +15808,7 @@ impl Request for GetPointerControlRequest {
     const OPCODE: u8 = 106;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetPointerControl";
     type Reply = GetPointerControlReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -15645,19 +15839,20 @@ impl AsByteSequence for GetPointerControlReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPointerControlReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (acceleration_numerator, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (acceleration_numerator, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (acceleration_denominator, sz): (Card16, usize) =
-            <Card16>::from_bytes(&bytes[index..])?;
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (threshold, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (threshold, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 18;
         Some((
@@ -15711,18 +15906,19 @@ impl AsByteSequence for SetScreenSaverRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetScreenSaverRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timeout, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (timeout, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (interval, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (interval, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (prefer_blanking, sz): (Blanking, usize) = <Blanking>::from_bytes(&bytes[index..])?;
+        let (prefer_blanking, sz): (Blanking, usize) = <Blanking>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (allow_exposures, sz): (Exposures, usize) = <Exposures>::from_bytes(&bytes[index..])?;
+        let (allow_exposures, sz): (Exposures, usize) =
+            <Exposures>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetScreenSaverRequest {
@@ -15751,6 +15947,7 @@ impl Request for SetScreenSaverRequest {
     const OPCODE: u8 = 107;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetScreenSaver";
     type Reply = ();
 }
 #[repr(u8)]
@@ -15838,10 +16035,10 @@ impl AsByteSequence for GetScreenSaverRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetScreenSaverRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetScreenSaverRequest {
@@ -15860,6 +16057,7 @@ impl Request for GetScreenSaverRequest {
     const OPCODE: u8 = 108;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetScreenSaver";
     type Reply = GetScreenSaverReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -15892,20 +16090,21 @@ impl AsByteSequence for GetScreenSaverReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetScreenSaverReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (timeout, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (timeout, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (interval, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (interval, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (prefer_blanking, sz): (Blanking, usize) = <Blanking>::from_bytes(&bytes[index..])?;
+        let (prefer_blanking, sz): (Blanking, usize) = <Blanking>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (allow_exposures, sz): (Exposures, usize) = <Exposures>::from_bytes(&bytes[index..])?;
+        let (allow_exposures, sz): (Exposures, usize) =
+            <Exposures>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 18;
         Some((
@@ -15962,19 +16161,19 @@ impl AsByteSequence for ChangeHostsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ChangeHostsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (HostMode, usize) = <HostMode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (HostMode, usize) = <HostMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (family, sz): (Family, usize) = <Family>::from_bytes(&bytes[index..])?;
+        let (family, sz): (Family, usize) = <Family>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (address, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Byte>());
         Some((
@@ -16007,6 +16206,7 @@ impl Request for ChangeHostsRequest {
     const OPCODE: u8 = 109;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ChangeHosts";
     type Reply = ();
 }
 #[repr(u8)]
@@ -16099,13 +16299,13 @@ impl AsByteSequence for Host {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Host from byte buffer");
-        let (family, sz): (Family, usize) = <Family>::from_bytes(&bytes[index..])?;
+        let (family, sz): (Family, usize) = <Family>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (address, block_len): (Vec<Byte>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, 4);
         Some((
@@ -16144,10 +16344,10 @@ impl AsByteSequence for ListHostsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListHostsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ListHostsRequest {
@@ -16166,6 +16366,7 @@ impl Request for ListHostsRequest {
     const OPCODE: u8 = 110;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListHosts";
     type Reply = ListHostsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -16196,19 +16397,19 @@ impl AsByteSequence for ListHostsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListHostsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (AccessControl, usize) = <AccessControl>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (AccessControl, usize) = <AccessControl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
         let (hosts, block_len): (Vec<Host>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Host>());
         Some((
@@ -16288,11 +16489,11 @@ impl AsByteSequence for SetAccessControlRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetAccessControlRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (AccessControl, usize) = <AccessControl>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (AccessControl, usize) = <AccessControl>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetAccessControlRequest {
@@ -16312,6 +16513,7 @@ impl Request for SetAccessControlRequest {
     const OPCODE: u8 = 111;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetAccessControl";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -16334,11 +16536,11 @@ impl AsByteSequence for SetCloseDownModeRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetCloseDownModeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (CloseDown, usize) = <CloseDown>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (CloseDown, usize) = <CloseDown>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetCloseDownModeRequest {
@@ -16358,6 +16560,7 @@ impl Request for SetCloseDownModeRequest {
     const OPCODE: u8 = 112;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetCloseDownMode";
     type Reply = ();
 }
 #[repr(u8)]
@@ -16414,12 +16617,12 @@ impl AsByteSequence for KillClientRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing KillClientRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (resource, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (resource, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             KillClientRequest {
@@ -16439,6 +16642,7 @@ impl Request for KillClientRequest {
     const OPCODE: u8 = 113;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "KillClient";
     type Reply = ();
 }
 #[repr(u32)]
@@ -16498,19 +16702,19 @@ impl AsByteSequence for RotatePropertiesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing RotatePropertiesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (delta, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (delta, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (atoms, block_len): (Vec<Atom>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Atom>());
         Some((
@@ -16543,6 +16747,7 @@ impl Request for RotatePropertiesRequest {
     const OPCODE: u8 = 114;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "RotateProperties";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -16565,11 +16770,11 @@ impl AsByteSequence for ForceScreenSaverRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ForceScreenSaverRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (ScreenSaver, usize) = <ScreenSaver>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (ScreenSaver, usize) = <ScreenSaver>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ForceScreenSaverRequest {
@@ -16589,6 +16794,7 @@ impl Request for ForceScreenSaverRequest {
     const OPCODE: u8 = 115;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ForceScreenSaver";
     type Reply = ();
 }
 #[repr(u8)]
@@ -16645,14 +16851,14 @@ impl AsByteSequence for SetPointerMappingRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetPointerMappingRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (map, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
         Some((
@@ -16677,6 +16883,7 @@ impl Request for SetPointerMappingRequest {
     const OPCODE: u8 = 116;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetPointerMapping";
     type Reply = SetPointerMappingReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -16701,13 +16908,14 @@ impl AsByteSequence for SetPointerMappingReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetPointerMappingReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (MappingStatus, usize) = <MappingStatus>::from_bytes(&bytes[index..])?;
+        let (status, sz): (MappingStatus, usize) =
+            <MappingStatus>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetPointerMappingReply {
@@ -16776,10 +16984,10 @@ impl AsByteSequence for GetPointerMappingRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPointerMappingRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetPointerMappingRequest {
@@ -16798,6 +17006,7 @@ impl Request for GetPointerMappingRequest {
     const OPCODE: u8 = 117;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetPointerMapping";
     type Reply = GetPointerMappingReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -16826,17 +17035,17 @@ impl AsByteSequence for GetPointerMappingReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPointerMappingReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 24;
         let (map, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
         Some((
@@ -16887,14 +17096,14 @@ impl AsByteSequence for SetModifierMappingRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetModifierMappingRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (keycodes_per_modifier, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (keycodes_per_modifier, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (keycodes, block_len): (Vec<Keycode>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             ((keycodes_per_modifier as usize) * (8)) as usize,
         )?;
         index += block_len;
@@ -16922,6 +17131,7 @@ impl Request for SetModifierMappingRequest {
     const OPCODE: u8 = 118;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetModifierMapping";
     type Reply = SetModifierMappingReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -16946,13 +17156,14 @@ impl AsByteSequence for SetModifierMappingReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetModifierMappingReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (status, sz): (MappingStatus, usize) = <MappingStatus>::from_bytes(&bytes[index..])?;
+        let (status, sz): (MappingStatus, usize) =
+            <MappingStatus>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetModifierMappingReply {
@@ -16988,10 +17199,10 @@ impl AsByteSequence for GetModifierMappingRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetModifierMappingRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetModifierMappingRequest {
@@ -17010,6 +17221,7 @@ impl Request for GetModifierMappingRequest {
     const OPCODE: u8 = 119;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetModifierMapping";
     type Reply = GetModifierMappingReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -17039,17 +17251,17 @@ impl AsByteSequence for GetModifierMappingReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetModifierMappingReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (keycodes_per_modifier, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (keycodes_per_modifier, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 24;
         let (keycodes, block_len): (Vec<Keycode>, usize) = vector_from_bytes(
-            &bytes[index..],
+            bytes.get(index..)?,
             ((keycodes_per_modifier as usize) * (8)) as usize,
         )?;
         index += block_len;
@@ -17098,10 +17310,10 @@ impl AsByteSequence for NoOperationRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing NoOperationRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             NoOperationRequest {
@@ -17120,6 +17332,7 @@ impl Request for NoOperationRequest {
     const OPCODE: u8 = 127;
     const EXTENSION: Option<&'static str> = None;
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "NoOperation";
     type Reply = ();
 }
 #[repr(i32)]
@@ -17714,21 +17927,21 @@ impl AsByteSequence for AccessError {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AccessError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (major_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -17792,21 +18005,21 @@ impl AsByteSequence for AllocError {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AllocError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (major_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -17870,21 +18083,21 @@ impl AsByteSequence for AtomError {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AtomError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (major_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -17948,21 +18161,21 @@ impl AsByteSequence for ColormapError {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ColormapError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (major_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -18026,21 +18239,21 @@ impl AsByteSequence for CursorError {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CursorError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (major_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -18104,21 +18317,21 @@ impl AsByteSequence for DrawableError {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DrawableError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (major_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -18182,21 +18395,21 @@ impl AsByteSequence for FontError {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FontError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (major_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -18260,21 +18473,21 @@ impl AsByteSequence for GContextError {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GContextError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (major_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -18338,21 +18551,21 @@ impl AsByteSequence for IdChoiceError {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing IdChoiceError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (major_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -18416,21 +18629,21 @@ impl AsByteSequence for ImplementationError {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ImplementationError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (major_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -18494,21 +18707,21 @@ impl AsByteSequence for LengthError {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing LengthError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (major_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -18572,21 +18785,21 @@ impl AsByteSequence for MatchError {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing MatchError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (major_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -18650,21 +18863,21 @@ impl AsByteSequence for NameError {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing NameError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (major_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -18728,21 +18941,21 @@ impl AsByteSequence for PixmapError {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PixmapError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (major_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -18806,21 +19019,21 @@ impl AsByteSequence for RequestError {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing RequestError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (major_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -18884,21 +19097,21 @@ impl AsByteSequence for ValueError {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ValueError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (major_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -18962,21 +19175,21 @@ impl AsByteSequence for WindowError {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing WindowError from byte buffer");
-        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (_error_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (error_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (error_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (major_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (minor_code, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (bad_value, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -19050,31 +19263,31 @@ impl AsByteSequence for ButtonPressEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ButtonPressEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Button, usize) = <Button>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Button, usize) = <Button>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(&bytes[index..])?;
+        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -19158,31 +19371,31 @@ impl AsByteSequence for ButtonReleaseEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ButtonReleaseEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Button, usize) = <Button>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Button, usize) = <Button>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(&bytes[index..])?;
+        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -19252,17 +19465,17 @@ impl AsByteSequence for CirculateNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CirculateNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (place, sz): (Place, usize) = <Place>::from_bytes(&bytes[index..])?;
+        let (place, sz): (Place, usize) = <Place>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -19318,17 +19531,17 @@ impl AsByteSequence for CirculateRequestEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CirculateRequestEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 4;
-        let (place, sz): (Place, usize) = <Place>::from_bytes(&bytes[index..])?;
+        let (place, sz): (Place, usize) = <Place>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -19383,18 +19596,18 @@ impl AsByteSequence for ClientMessageEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ClientMessageEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (format, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (format, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (data, sz): (ClientMessageData, usize) =
-            <ClientMessageData>::from_bytes(&bytes[index..])?;
+            <ClientMessageData>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ClientMessageEvent {
@@ -19449,18 +19662,18 @@ impl AsByteSequence for ColormapNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ColormapNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (colormap, sz): (Colormap, usize) = <Colormap>::from_bytes(&bytes[index..])?;
+        let (colormap, sz): (Colormap, usize) = <Colormap>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (new, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (new, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (ColormapState, usize) = <ColormapState>::from_bytes(&bytes[index..])?;
+        let (state, sz): (ColormapState, usize) = <ColormapState>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -19521,35 +19734,37 @@ impl AsByteSequence for ConfigureNotifyEvent {
         index += self.height.as_bytes(&mut bytes[index..]);
         index += self.border_width.as_bytes(&mut bytes[index..]);
         index += self.override_redirect.as_bytes(&mut bytes[index..]);
+        index += 1;
         index
     }
     #[inline]
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ConfigureNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (above_sibling, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (above_sibling, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (border_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (border_width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (override_redirect, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (override_redirect, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
+        index += 1;
         Some((
             ConfigureNotifyEvent {
                 event_type: event_type,
@@ -19581,6 +19796,7 @@ impl AsByteSequence for ConfigureNotifyEvent {
             + self.height.size()
             + self.border_width.size()
             + self.override_redirect.size()
+            + 1
     }
 }
 impl crate::auto::Event for ConfigureNotifyEvent {
@@ -19624,29 +19840,30 @@ impl AsByteSequence for ConfigureRequestEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ConfigureRequestEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (stack_mode, sz): (StackMode, usize) = <StackMode>::from_bytes(&bytes[index..])?;
+        let (stack_mode, sz): (StackMode, usize) = <StackMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (parent, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (parent, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sibling, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (sibling, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (border_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (border_width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value_mask, sz): (ConfigWindow, usize) = <ConfigWindow>::from_bytes(&bytes[index..])?;
+        let (value_mask, sz): (ConfigWindow, usize) =
+            <ConfigWindow>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ConfigureRequestEvent {
@@ -19714,33 +19931,35 @@ impl AsByteSequence for CreateNotifyEvent {
         index += self.height.as_bytes(&mut bytes[index..]);
         index += self.border_width.as_bytes(&mut bytes[index..]);
         index += self.override_redirect.as_bytes(&mut bytes[index..]);
+        index += 1;
         index
     }
     #[inline]
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (parent, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (parent, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (border_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (border_width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (override_redirect, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (override_redirect, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
+        index += 1;
         Some((
             CreateNotifyEvent {
                 event_type: event_type,
@@ -19770,6 +19989,7 @@ impl AsByteSequence for CreateNotifyEvent {
             + self.height.size()
             + self.border_width.size()
             + self.override_redirect.size()
+            + 1
     }
 }
 impl crate::auto::Event for CreateNotifyEvent {
@@ -19798,14 +20018,14 @@ impl AsByteSequence for DestroyNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroyNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DestroyNotifyEvent {
@@ -19867,33 +20087,33 @@ impl AsByteSequence for EnterNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing EnterNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (NotifyDetail, usize) = <NotifyDetail>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (NotifyDetail, usize) = <NotifyDetail>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(&bytes[index..])?;
+        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (NotifyMode, usize) = <NotifyMode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (NotifyMode, usize) = <NotifyMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (same_screen_focus, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (same_screen_focus, sz): (Byte, usize) = <Byte>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             EnterNotifyEvent {
@@ -19968,22 +20188,22 @@ impl AsByteSequence for ExposeEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ExposeEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (count, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (count, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
         Some((
@@ -20042,15 +20262,15 @@ impl AsByteSequence for FocusInEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FocusInEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (NotifyDetail, usize) = <NotifyDetail>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (NotifyDetail, usize) = <NotifyDetail>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (NotifyMode, usize) = <NotifyMode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (NotifyMode, usize) = <NotifyMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -20102,15 +20322,15 @@ impl AsByteSequence for FocusOutEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing FocusOutEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (NotifyDetail, usize) = <NotifyDetail>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (NotifyDetail, usize) = <NotifyDetail>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (NotifyMode, usize) = <NotifyMode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (NotifyMode, usize) = <NotifyMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -20156,10 +20376,10 @@ impl AsByteSequence for GeGenericEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GeGenericEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GeGenericEvent {
@@ -20213,26 +20433,26 @@ impl AsByteSequence for GraphicsExposureEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GraphicsExposureEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (count, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (count, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -20297,18 +20517,18 @@ impl AsByteSequence for GravityNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GravityNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GravityNotifyEvent {
@@ -20377,31 +20597,31 @@ impl AsByteSequence for KeyPressEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing KeyPressEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(&bytes[index..])?;
+        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -20485,31 +20705,31 @@ impl AsByteSequence for KeyReleaseEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing KeyReleaseEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(&bytes[index..])?;
+        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -20570,9 +20790,9 @@ impl AsByteSequence for KeymapNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing KeymapNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (keys, sz): ([Card8; 31], usize) = <[Card8; 31]>::from_bytes(&bytes[index..])?;
+        let (keys, sz): ([Card8; 31], usize) = <[Card8; 31]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             KeymapNotifyEvent {
@@ -20632,33 +20852,33 @@ impl AsByteSequence for LeaveNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing LeaveNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (NotifyDetail, usize) = <NotifyDetail>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (NotifyDetail, usize) = <NotifyDetail>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(&bytes[index..])?;
+        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mode, sz): (NotifyMode, usize) = <NotifyMode>::from_bytes(&bytes[index..])?;
+        let (mode, sz): (NotifyMode, usize) = <NotifyMode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (same_screen_focus, sz): (Byte, usize) = <Byte>::from_bytes(&bytes[index..])?;
+        let (same_screen_focus, sz): (Byte, usize) = <Byte>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             LeaveNotifyEvent {
@@ -20727,16 +20947,16 @@ impl AsByteSequence for MapNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing MapNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (override_redirect, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (override_redirect, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -20787,14 +21007,14 @@ impl AsByteSequence for MapRequestEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing MapRequestEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (parent, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (parent, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             MapRequestEvent {
@@ -20833,23 +21053,25 @@ impl AsByteSequence for MappingNotifyEvent {
         index += self.request.as_bytes(&mut bytes[index..]);
         index += self.first_keycode.as_bytes(&mut bytes[index..]);
         index += self.count.as_bytes(&mut bytes[index..]);
+        index += 1;
         index
     }
     #[inline]
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing MappingNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (request, sz): (Mapping, usize) = <Mapping>::from_bytes(&bytes[index..])?;
+        let (request, sz): (Mapping, usize) = <Mapping>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (first_keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(&bytes[index..])?;
+        let (first_keycode, sz): (Keycode, usize) = <Keycode>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (count, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (count, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
+        index += 1;
         Some((
             MappingNotifyEvent {
                 event_type: event_type,
@@ -20869,6 +21091,7 @@ impl AsByteSequence for MappingNotifyEvent {
             + self.request.size()
             + self.first_keycode.size()
             + self.count.size()
+            + 1
     }
 }
 impl crate::auto::Event for MappingNotifyEvent {
@@ -20915,31 +21138,31 @@ impl AsByteSequence for MotionNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing MotionNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (detail, sz): (Motion, usize) = <Motion>::from_bytes(&bytes[index..])?;
+        let (detail, sz): (Motion, usize) = <Motion>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (root, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (child, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (child, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (root_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (event_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(&bytes[index..])?;
+        let (state, sz): (KeyButMask, usize) = <KeyButMask>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (same_screen, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
         Some((
@@ -21001,23 +21224,25 @@ impl AsByteSequence for NoExposureEvent {
         index += self.drawable.as_bytes(&mut bytes[index..]);
         index += self.minor_opcode.as_bytes(&mut bytes[index..]);
         index += self.major_opcode.as_bytes(&mut bytes[index..]);
+        index += 1;
         index
     }
     #[inline]
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing NoExposureEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor_opcode, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (major_opcode, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
+        index += 1;
         Some((
             NoExposureEvent {
                 event_type: event_type,
@@ -21037,6 +21262,7 @@ impl AsByteSequence for NoExposureEvent {
             + self.drawable.size()
             + self.minor_opcode.size()
             + self.major_opcode.size()
+            + 1
     }
 }
 impl crate::auto::Event for NoExposureEvent {
@@ -21070,18 +21296,18 @@ impl AsByteSequence for PropertyNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PropertyNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (atom, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (atom, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (Property, usize) = <Property>::from_bytes(&bytes[index..])?;
+        let (state, sz): (Property, usize) = <Property>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -21143,22 +21369,22 @@ impl AsByteSequence for ReparentNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ReparentNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (parent, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (parent, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (override_redirect, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (override_redirect, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -21217,16 +21443,16 @@ impl AsByteSequence for ResizeRequestEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ResizeRequestEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ResizeRequestEvent {
@@ -21277,16 +21503,16 @@ impl AsByteSequence for SelectionClearEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectionClearEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (owner, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (owner, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (selection, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (selection, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SelectionClearEvent {
@@ -21341,20 +21567,20 @@ impl AsByteSequence for SelectionNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectionNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (requestor, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (requestor, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (selection, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (selection, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SelectionNotifyEvent {
@@ -21415,22 +21641,22 @@ impl AsByteSequence for SelectionRequestEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectionRequestEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (owner, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (owner, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (requestor, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (requestor, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (selection, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (selection, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (target, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (target, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (property, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (property, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SelectionRequestEvent {
@@ -21488,16 +21714,16 @@ impl AsByteSequence for UnmapNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing UnmapNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (event, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (event, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (from_configure, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (from_configure, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -21549,14 +21775,14 @@ impl AsByteSequence for VisibilityNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing VisibilityNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (state, sz): (Visibility, usize) = <Visibility>::from_bytes(&bytes[index..])?;
+        let (state, sz): (Visibility, usize) = <Visibility>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -67,9 +67,9 @@ impl AsByteSequence for Rational {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Rational from byte buffer");
-        let (numerator, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (numerator, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (denominator, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (denominator, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             Rational {
@@ -103,9 +103,9 @@ impl AsByteSequence for Format {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Format from byte buffer");
-        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(&bytes[index..])?;
+        let (visual, sz): (Visualid, usize) = <Visualid>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -152,22 +152,23 @@ impl AsByteSequence for AdaptorInfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AdaptorInfo from byte buffer");
-        let (base_id, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (base_id, sz): (Port, usize) = <Port>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_ports, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (num_ports, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len1, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (ty, sz): (Type, usize) = <Type>::from_bytes(&bytes[index..])?;
+        let (ty, sz): (Type, usize) = <Type>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) =
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, 4);
         let (formats, block_len): (Vec<Format>, usize) =
-            vector_from_bytes(&bytes[index..], len1 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len1 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Format>());
         Some((
@@ -386,18 +387,19 @@ impl AsByteSequence for EncodingInfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing EncodingInfo from byte buffer");
-        let (encoding, sz): (Encoding, usize) = <Encoding>::from_bytes(&bytes[index..])?;
+        let (encoding, sz): (Encoding, usize) = <Encoding>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (rate, sz): (Rational, usize) = <Rational>::from_bytes(&bytes[index..])?;
+        let (rate, sz): (Rational, usize) = <Rational>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) =
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, 4);
         Some((
@@ -461,26 +463,26 @@ impl AsByteSequence for Image {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing Image from byte buffer");
-        let (id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_planes, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_planes, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (pitches, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (num_planes as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_planes as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (offsets, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (num_planes as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_planes as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (data, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
         Some((
@@ -545,15 +547,16 @@ impl AsByteSequence for AttributeInfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing AttributeInfo from byte buffer");
-        let (flags, sz): (AttributeFlag, usize) = <AttributeFlag>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (AttributeFlag, usize) = <AttributeFlag>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (min, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (min, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (max, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (name, block_len): (String, usize) = string_from_bytes(&bytes[index..], len0 as usize)?;
+        let (name, block_len): (String, usize) =
+            string_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, 4);
         Some((
@@ -712,12 +715,14 @@ impl AsByteSequence for ImageFormatInfo {
         index += self.guid.as_bytes(&mut bytes[index..]);
         index += self.bpp.as_bytes(&mut bytes[index..]);
         index += self.num_planes.as_bytes(&mut bytes[index..]);
+        index += 2;
         index += self.depth.as_bytes(&mut bytes[index..]);
         index += 3;
         index += self.red_mask.as_bytes(&mut bytes[index..]);
         index += self.green_mask.as_bytes(&mut bytes[index..]);
         index += self.blue_mask.as_bytes(&mut bytes[index..]);
         index += self.format.as_bytes(&mut bytes[index..]);
+        index += 3;
         index += self.y_sample_bits.as_bytes(&mut bytes[index..]);
         index += self.u_sample_bits.as_bytes(&mut bytes[index..]);
         index += self.v_sample_bits.as_bytes(&mut bytes[index..]);
@@ -736,54 +741,57 @@ impl AsByteSequence for ImageFormatInfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ImageFormatInfo from byte buffer");
-        let (id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (ty, sz): (ImageFormatInfoType, usize) =
-            <ImageFormatInfoType>::from_bytes(&bytes[index..])?;
+            <ImageFormatInfoType>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (byte_order, sz): (ImageOrder, usize) = <ImageOrder>::from_bytes(&bytes[index..])?;
+        let (byte_order, sz): (ImageOrder, usize) = <ImageOrder>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 2;
-        let (guid, sz): ([Card8; 16], usize) = <[Card8; 16]>::from_bytes(&bytes[index..])?;
+        let (guid, sz): ([Card8; 16], usize) = <[Card8; 16]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (bpp, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (bpp, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_planes, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (num_planes, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        index += 2;
+        let (depth, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
-        let (red_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (red_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (green_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (green_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (blue_mask, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (blue_mask, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (format, sz): (ImageFormatInfoFormat, usize) =
-            <ImageFormatInfoFormat>::from_bytes(&bytes[index..])?;
+            <ImageFormatInfoFormat>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (y_sample_bits, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        index += 3;
+        let (y_sample_bits, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (u_sample_bits, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (u_sample_bits, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (v_sample_bits, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (v_sample_bits, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vhorz_y_period, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (vhorz_y_period, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vhorz_u_period, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (vhorz_u_period, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vhorz_v_period, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (vhorz_v_period, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vvert_y_period, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (vvert_y_period, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vvert_u_period, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (vvert_u_period, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vvert_v_period, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (vvert_v_period, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vcomp_order, sz): ([Card8; 32], usize) = <[Card8; 32]>::from_bytes(&bytes[index..])?;
+        let (vcomp_order, sz): ([Card8; 32], usize) =
+            <[Card8; 32]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (vscanline_order, sz): (ScanlineOrder, usize) =
-            <ScanlineOrder>::from_bytes(&bytes[index..])?;
+            <ScanlineOrder>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 11;
         Some((
@@ -823,12 +831,14 @@ impl AsByteSequence for ImageFormatInfo {
             + self.guid.size()
             + self.bpp.size()
             + self.num_planes.size()
+            + 2
             + self.depth.size()
             + 3
             + self.red_mask.size()
             + self.green_mask.size()
             + self.blue_mask.size()
             + self.format.size()
+            + 3
             + self.y_sample_bits.size()
             + self.u_sample_bits.size()
             + self.v_sample_bits.size()
@@ -992,10 +1002,10 @@ impl AsByteSequence for QueryExtensionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryExtensionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryExtensionRequest {
@@ -1014,6 +1024,7 @@ impl Request for QueryExtensionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryExtension";
     type Reply = QueryExtensionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1041,16 +1052,16 @@ impl AsByteSequence for QueryExtensionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryExtensionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (major, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (minor, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryExtensionReply {
@@ -1094,12 +1105,12 @@ impl AsByteSequence for QueryAdaptorsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryAdaptorsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (window, sz): (Window, usize) = <Window>::from_bytes(&bytes[index..])?;
+        let (window, sz): (Window, usize) = <Window>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryAdaptorsRequest {
@@ -1119,6 +1130,7 @@ impl Request for QueryAdaptorsRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = Some("XVideo");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryAdaptors";
     type Reply = QueryAdaptorsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1148,18 +1160,18 @@ impl AsByteSequence for QueryAdaptorsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryAdaptorsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
         let (info, block_len): (Vec<AdaptorInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<AdaptorInfo>());
         Some((
@@ -1208,12 +1220,12 @@ impl AsByteSequence for QueryEncodingsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryEncodingsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = <Port>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryEncodingsRequest {
@@ -1233,6 +1245,7 @@ impl Request for QueryEncodingsRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = Some("XVideo");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryEncodings";
     type Reply = QueryEncodingsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1262,18 +1275,18 @@ impl AsByteSequence for QueryEncodingsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryEncodingsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 22;
         let (info, block_len): (Vec<EncodingInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<EncodingInfo>());
         Some((
@@ -1324,14 +1337,14 @@ impl AsByteSequence for GrabPortRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GrabPortRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = <Port>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GrabPortRequest {
@@ -1352,6 +1365,7 @@ impl Request for GrabPortRequest {
     const OPCODE: u8 = 3;
     const EXTENSION: Option<&'static str> = Some("XVideo");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GrabPort";
     type Reply = GrabPortReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1376,13 +1390,14 @@ impl AsByteSequence for GrabPortReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GrabPortReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (result, sz): (GrabPortStatus, usize) = <GrabPortStatus>::from_bytes(&bytes[index..])?;
+        let (result, sz): (GrabPortStatus, usize) =
+            <GrabPortStatus>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GrabPortReply {
@@ -1461,14 +1476,14 @@ impl AsByteSequence for UngrabPortRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing UngrabPortRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = <Port>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             UngrabPortRequest {
@@ -1489,6 +1504,7 @@ impl Request for UngrabPortRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = Some("XVideo");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "UngrabPort";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1532,32 +1548,32 @@ impl AsByteSequence for PutVideoRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PutVideoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = <Port>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vid_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (vid_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vid_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (vid_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vid_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vid_w, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vid_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vid_h, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drw_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drw_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (drw_w, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (drw_h, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PutVideoRequest {
@@ -1600,6 +1616,7 @@ impl Request for PutVideoRequest {
     const OPCODE: u8 = 5;
     const EXTENSION: Option<&'static str> = Some("XVideo");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PutVideo";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1643,32 +1660,32 @@ impl AsByteSequence for PutStillRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PutStillRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = <Port>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vid_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (vid_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vid_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (vid_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vid_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vid_w, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vid_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vid_h, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drw_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drw_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (drw_w, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (drw_h, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PutStillRequest {
@@ -1711,6 +1728,7 @@ impl Request for PutStillRequest {
     const OPCODE: u8 = 6;
     const EXTENSION: Option<&'static str> = Some("XVideo");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PutStill";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1754,32 +1772,32 @@ impl AsByteSequence for GetVideoRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetVideoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = <Port>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vid_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (vid_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vid_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (vid_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vid_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vid_w, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vid_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vid_h, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drw_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drw_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (drw_w, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (drw_h, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetVideoRequest {
@@ -1822,6 +1840,7 @@ impl Request for GetVideoRequest {
     const OPCODE: u8 = 7;
     const EXTENSION: Option<&'static str> = Some("XVideo");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetVideo";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1865,32 +1884,32 @@ impl AsByteSequence for GetStillRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetStillRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = <Port>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vid_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (vid_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vid_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (vid_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vid_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vid_w, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vid_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vid_h, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drw_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drw_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (drw_w, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (drw_h, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetStillRequest {
@@ -1933,6 +1952,7 @@ impl Request for GetStillRequest {
     const OPCODE: u8 = 8;
     const EXTENSION: Option<&'static str> = Some("XVideo");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetStill";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1958,14 +1978,14 @@ impl AsByteSequence for StopVideoRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing StopVideoRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = <Port>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             StopVideoRequest {
@@ -1986,6 +2006,7 @@ impl Request for StopVideoRequest {
     const OPCODE: u8 = 9;
     const EXTENSION: Option<&'static str> = Some("XVideo");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "StopVideo";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2012,14 +2033,14 @@ impl AsByteSequence for SelectVideoNotifyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectVideoNotifyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (onoff, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (onoff, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -2041,6 +2062,7 @@ impl Request for SelectVideoNotifyRequest {
     const OPCODE: u8 = 10;
     const EXTENSION: Option<&'static str> = Some("XVideo");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SelectVideoNotify";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2067,14 +2089,14 @@ impl AsByteSequence for SelectPortNotifyRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SelectPortNotifyRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = <Port>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (onoff, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (onoff, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -2096,6 +2118,7 @@ impl Request for SelectPortNotifyRequest {
     const OPCODE: u8 = 11;
     const EXTENSION: Option<&'static str> = Some("XVideo");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SelectPortNotify";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2130,22 +2153,22 @@ impl AsByteSequence for QueryBestSizeRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryBestSizeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = <Port>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vid_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vid_w, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (vid_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (vid_h, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (drw_w, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (drw_h, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (motion, sz): (bool, usize) = <bool>::from_bytes(&bytes[index..])?;
+        let (motion, sz): (bool, usize) = <bool>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -2180,6 +2203,7 @@ impl Request for QueryBestSizeRequest {
     const OPCODE: u8 = 12;
     const EXTENSION: Option<&'static str> = Some("XVideo");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryBestSize";
     type Reply = QueryBestSizeReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2207,16 +2231,16 @@ impl AsByteSequence for QueryBestSizeReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryBestSizeReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (actual_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (actual_width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (actual_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (actual_height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryBestSizeReply {
@@ -2264,16 +2288,16 @@ impl AsByteSequence for SetPortAttributeRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SetPortAttributeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = <Port>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (attribute, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (attribute, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (value, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SetPortAttributeRequest {
@@ -2300,6 +2324,7 @@ impl Request for SetPortAttributeRequest {
     const OPCODE: u8 = 13;
     const EXTENSION: Option<&'static str> = Some("XVideo");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "SetPortAttribute";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2325,14 +2350,14 @@ impl AsByteSequence for GetPortAttributeRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPortAttributeRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = <Port>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (attribute, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (attribute, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetPortAttributeRequest {
@@ -2353,6 +2378,7 @@ impl Request for GetPortAttributeRequest {
     const OPCODE: u8 = 14;
     const EXTENSION: Option<&'static str> = Some("XVideo");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "GetPortAttribute";
     type Reply = GetPortAttributeReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2378,14 +2404,14 @@ impl AsByteSequence for GetPortAttributeReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing GetPortAttributeReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (value, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             GetPortAttributeReply {
@@ -2423,12 +2449,12 @@ impl AsByteSequence for QueryPortAttributesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryPortAttributesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = <Port>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryPortAttributesRequest {
@@ -2448,6 +2474,7 @@ impl Request for QueryPortAttributesRequest {
     const OPCODE: u8 = 15;
     const EXTENSION: Option<&'static str> = Some("XVideo");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryPortAttributes";
     type Reply = QueryPortAttributesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2479,20 +2506,20 @@ impl AsByteSequence for QueryPortAttributesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryPortAttributesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (text_size, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (text_size, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 16;
         let (attributes, block_len): (Vec<AttributeInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<AttributeInfo>());
         Some((
@@ -2543,12 +2570,12 @@ impl AsByteSequence for ListImageFormatsRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListImageFormatsRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = <Port>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ListImageFormatsRequest {
@@ -2568,6 +2595,7 @@ impl Request for ListImageFormatsRequest {
     const OPCODE: u8 = 16;
     const EXTENSION: Option<&'static str> = Some("XVideo");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListImageFormats";
     type Reply = ListImageFormatsReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2597,18 +2625,18 @@ impl AsByteSequence for ListImageFormatsReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListImageFormatsReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (format, block_len): (Vec<ImageFormatInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ImageFormatInfo>());
         Some((
@@ -2663,18 +2691,18 @@ impl AsByteSequence for QueryImageAttributesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryImageAttributesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = <Port>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryImageAttributesRequest {
@@ -2703,6 +2731,7 @@ impl Request for QueryImageAttributesRequest {
     const OPCODE: u8 = 17;
     const EXTENSION: Option<&'static str> = Some("XVideo");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryImageAttributes";
     type Reply = QueryImageAttributesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -2743,28 +2772,28 @@ impl AsByteSequence for QueryImageAttributesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryImageAttributesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_planes, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (num_planes, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (data_size, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (data_size, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (pitches, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (num_planes as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_planes as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         let (offsets, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (num_planes as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (num_planes as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -2856,41 +2885,43 @@ impl AsByteSequence for PutImageRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PutImageRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = <Port>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (src_w, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (src_h, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drw_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drw_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (drw_w, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (drw_h, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (data, block_len): (Vec<Card8>, usize) =
-            vector_from_bytes(&bytes[index..], ((length as usize * 4) - index) as usize)?;
+        let (data, block_len): (Vec<Card8>, usize) = vector_from_bytes(
+            bytes.get(index..)?,
+            ((length as usize * 4).checked_sub(index)?) as usize,
+        )?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card8>());
         Some((
@@ -2946,6 +2977,7 @@ impl Request for PutImageRequest {
     const OPCODE: u8 = 18;
     const EXTENSION: Option<&'static str> = Some("XVideo");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "PutImage";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3002,44 +3034,44 @@ impl AsByteSequence for ShmPutImageRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ShmPutImageRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = <Port>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(&bytes[index..])?;
+        let (gc, sz): (Gcontext, usize) = <Gcontext>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(&bytes[index..])?;
+        let (shmseg, sz): (Seg, usize) = <Seg>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (offset, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (offset, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (src_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (src_w, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (src_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (src_h, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_x, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drw_x, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_y, sz): (Int16, usize) = <Int16>::from_bytes(&bytes[index..])?;
+        let (drw_y, sz): (Int16, usize) = <Int16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_w, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (drw_w, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drw_h, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (drw_h, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (send_event, sz): (Card8, usize) = <Card8>::from_bytes(&bytes[index..])?;
+        let (send_event, sz): (Card8, usize) = <Card8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 3;
         Some((
@@ -3096,6 +3128,7 @@ impl Request for ShmPutImageRequest {
     const OPCODE: u8 = 19;
     const EXTENSION: Option<&'static str> = Some("XVideo");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ShmPutImage";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -3125,18 +3158,18 @@ impl AsByteSequence for PortNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing PortNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = <Port>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (attribute, sz): (Atom, usize) = <Atom>::from_bytes(&bytes[index..])?;
+        let (attribute, sz): (Atom, usize) = <Atom>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (value, sz): (Int32, usize) = <Int32>::from_bytes(&bytes[index..])?;
+        let (value, sz): (Int32, usize) = <Int32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             PortNotifyEvent {
@@ -3190,18 +3223,18 @@ impl AsByteSequence for VideoNotifyEvent {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing VideoNotifyEvent from byte buffer");
-        let (event_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (event_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         let (reason, sz): (VideoNotifyReason, usize) =
-            <VideoNotifyReason>::from_bytes(&bytes[index..])?;
+            <VideoNotifyReason>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(&bytes[index..])?;
+        let (time, sz): (Timestamp, usize) = <Timestamp>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(&bytes[index..])?;
+        let (drawable, sz): (Drawable, usize) = <Drawable>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (port, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port, sz): (Port, usize) = <Port>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             VideoNotifyEvent {
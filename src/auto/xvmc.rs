@@ -101,23 +101,25 @@ impl AsByteSequence for SurfaceInfo {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing SurfaceInfo from byte buffer");
-        let (id, sz): (Surface, usize) = <Surface>::from_bytes(&bytes[index..])?;
+        let (id, sz): (Surface, usize) = <Surface>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (chroma_format, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (chroma_format, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (pad0, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (pad0, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (max_width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (max_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (max_height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (subpicture_max_width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (subpicture_max_width, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (subpicture_max_height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (subpicture_max_height, sz): (Card16, usize) =
+            <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (mc_type, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (mc_type, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             SurfaceInfo {
@@ -166,10 +168,10 @@ impl AsByteSequence for QueryVersionRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionRequest {
@@ -188,6 +190,7 @@ impl Request for QueryVersionRequest {
     const OPCODE: u8 = 0;
     const EXTENSION: Option<&'static str> = Some("XVideo-MotionCompensation");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryVersion";
     type Reply = QueryVersionReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -215,16 +218,16 @@ impl AsByteSequence for QueryVersionReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing QueryVersionReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (major, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (major, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (minor, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (minor, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             QueryVersionReply {
@@ -268,12 +271,12 @@ impl AsByteSequence for ListSurfaceTypesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListSurfaceTypesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (port_id, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port_id, sz): (Port, usize) = <Port>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ListSurfaceTypesRequest {
@@ -293,6 +296,7 @@ impl Request for ListSurfaceTypesRequest {
     const OPCODE: u8 = 1;
     const EXTENSION: Option<&'static str> = Some("XVideo-MotionCompensation");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListSurfaceTypes";
     type Reply = ListSurfaceTypesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -322,18 +326,18 @@ impl AsByteSequence for ListSurfaceTypesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListSurfaceTypesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (surfaces, block_len): (Vec<SurfaceInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<SurfaceInfo>());
         Some((
@@ -392,22 +396,22 @@ impl AsByteSequence for CreateContextRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_id, sz): (Context, usize) = <Context>::from_bytes(&bytes[index..])?;
+        let (context_id, sz): (Context, usize) = <Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (port_id, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port_id, sz): (Port, usize) = <Port>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (surface_id, sz): (Surface, usize) = <Surface>::from_bytes(&bytes[index..])?;
+        let (surface_id, sz): (Surface, usize) = <Surface>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (flags, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CreateContextRequest {
@@ -440,6 +444,7 @@ impl Request for CreateContextRequest {
     const OPCODE: u8 = 2;
     const EXTENSION: Option<&'static str> = Some("XVideo-MotionCompensation");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateContext";
     type Reply = CreateContextReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -474,22 +479,22 @@ impl AsByteSequence for CreateContextReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateContextReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width_actual, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width_actual, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height_actual, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height_actual, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (flags_return, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (flags_return, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (priv_data, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (length as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (length as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -543,12 +548,12 @@ impl AsByteSequence for DestroyContextRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroyContextRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_id, sz): (Context, usize) = <Context>::from_bytes(&bytes[index..])?;
+        let (context_id, sz): (Context, usize) = <Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DestroyContextRequest {
@@ -568,6 +573,7 @@ impl Request for DestroyContextRequest {
     const OPCODE: u8 = 3;
     const EXTENSION: Option<&'static str> = Some("XVideo-MotionCompensation");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroyContext";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -593,14 +599,14 @@ impl AsByteSequence for CreateSurfaceRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateSurfaceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (surface_id, sz): (Surface, usize) = <Surface>::from_bytes(&bytes[index..])?;
+        let (surface_id, sz): (Surface, usize) = <Surface>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context_id, sz): (Context, usize) = <Context>::from_bytes(&bytes[index..])?;
+        let (context_id, sz): (Context, usize) = <Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CreateSurfaceRequest {
@@ -625,6 +631,7 @@ impl Request for CreateSurfaceRequest {
     const OPCODE: u8 = 4;
     const EXTENSION: Option<&'static str> = Some("XVideo-MotionCompensation");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateSurface";
     type Reply = CreateSurfaceReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -653,16 +660,16 @@ impl AsByteSequence for CreateSurfaceReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateSurfaceReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 24;
         let (priv_data, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (length as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (length as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -705,12 +712,12 @@ impl AsByteSequence for DestroySurfaceRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroySurfaceRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (surface_id, sz): (Surface, usize) = <Surface>::from_bytes(&bytes[index..])?;
+        let (surface_id, sz): (Surface, usize) = <Surface>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DestroySurfaceRequest {
@@ -730,6 +737,7 @@ impl Request for DestroySurfaceRequest {
     const OPCODE: u8 = 5;
     const EXTENSION: Option<&'static str> = Some("XVideo-MotionCompensation");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroySurface";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -761,20 +769,21 @@ impl AsByteSequence for CreateSubpictureRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateSubpictureRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (subpicture_id, sz): (Subpicture, usize) = <Subpicture>::from_bytes(&bytes[index..])?;
+        let (subpicture_id, sz): (Subpicture, usize) =
+            <Subpicture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (context, sz): (Context, usize) = <Context>::from_bytes(&bytes[index..])?;
+        let (context, sz): (Context, usize) = <Context>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (xvimage_id, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (xvimage_id, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             CreateSubpictureRequest {
@@ -805,6 +814,7 @@ impl Request for CreateSubpictureRequest {
     const OPCODE: u8 = 6;
     const EXTENSION: Option<&'static str> = Some("XVideo-MotionCompensation");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "CreateSubpicture";
     type Reply = CreateSubpictureReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -843,26 +853,27 @@ impl AsByteSequence for CreateSubpictureReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing CreateSubpictureReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (width_actual, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (width_actual, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (height_actual, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (height_actual, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (num_palette_entries, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (num_palette_entries, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (entry_bytes, sz): (Card16, usize) = <Card16>::from_bytes(&bytes[index..])?;
+        let (entry_bytes, sz): (Card16, usize) = <Card16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (component_order, sz): ([Card8; 4], usize) = <[Card8; 4]>::from_bytes(&bytes[index..])?;
+        let (component_order, sz): ([Card8; 4], usize) =
+            <[Card8; 4]>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 12;
         let (priv_data, block_len): (Vec<Card32>, usize) =
-            vector_from_bytes(&bytes[index..], (length as usize) as usize)?;
+            vector_from_bytes(bytes.get(index..)?, (length as usize) as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<Card32>());
         Some((
@@ -920,12 +931,13 @@ impl AsByteSequence for DestroySubpictureRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing DestroySubpictureRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (subpicture_id, sz): (Subpicture, usize) = <Subpicture>::from_bytes(&bytes[index..])?;
+        let (subpicture_id, sz): (Subpicture, usize) =
+            <Subpicture>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             DestroySubpictureRequest {
@@ -945,6 +957,7 @@ impl Request for DestroySubpictureRequest {
     const OPCODE: u8 = 7;
     const EXTENSION: Option<&'static str> = Some("XVideo-MotionCompensation");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "DestroySubpicture";
     type Reply = ();
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -970,14 +983,14 @@ impl AsByteSequence for ListSubpictureTypesRequest {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListSubpictureTypesRequest from byte buffer");
-        let (req_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (req_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (length, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (port_id, sz): (Port, usize) = <Port>::from_bytes(&bytes[index..])?;
+        let (port_id, sz): (Port, usize) = <Port>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (surface_id, sz): (Surface, usize) = <Surface>::from_bytes(&bytes[index..])?;
+        let (surface_id, sz): (Surface, usize) = <Surface>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         Some((
             ListSubpictureTypesRequest {
@@ -998,6 +1011,7 @@ impl Request for ListSubpictureTypesRequest {
     const OPCODE: u8 = 8;
     const EXTENSION: Option<&'static str> = Some("XVideo-MotionCompensation");
     const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "ListSubpictureTypes";
     type Reply = ListSubpictureTypesReply;
 }
 #[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
@@ -1027,18 +1041,18 @@ impl AsByteSequence for ListSubpictureTypesReply {
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut index: usize = 0;
         log::trace!("Deserializing ListSubpictureTypesReply from byte buffer");
-        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(&bytes[index..])?;
+        let (reply_type, sz): (u8, usize) = <u8>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 1;
-        let (sequence, sz): (u16, usize) = <u16>::from_bytes(&bytes[index..])?;
+        let (sequence, sz): (u16, usize) = <u16>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (length, sz): (u32, usize) = <u32>::from_bytes(&bytes[index..])?;
+        let (length, sz): (u32, usize) = <u32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
-        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(&bytes[index..])?;
+        let (len0, sz): (Card32, usize) = <Card32>::from_bytes(bytes.get(index..)?)?;
         index += sz;
         index += 20;
         let (types, block_len): (Vec<ImageFormatInfo>, usize) =
-            vector_from_bytes(&bytes[index..], len0 as usize)?;
+            vector_from_bytes(bytes.get(index..)?, len0 as usize)?;
         index += block_len;
         index += buffer_pad(block_len, ::core::mem::align_of::<ImageFormatInfo>());
         Some((
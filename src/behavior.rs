@@ -57,6 +57,9 @@ impl AsByteSequence for Behavior {
 
     #[inline]
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        if bytes.len() < BEHAVIOR_SIZE {
+            return None;
+        }
         let ty = BehaviorType::from_bytes(bytes);
 
         let res = match ty {
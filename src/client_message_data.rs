@@ -83,12 +83,9 @@ impl AsByteSequence for ClientMessageData {
 
     #[inline]
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
-        if bytes.len() < BYTE_LEN {
-            None
-        } else {
-            let mut res: [u32; LONG_LEN] = [0; LONG_LEN];
-            res.copy_from_slice(bytemuck::cast_slice(bytes));
-            Some((ClientMessageData { data: res }, BYTE_LEN))
-        }
+        // the bytes may be longer than the data, and aren't necessarily aligned for u32s
+        let mut res: [u32; LONG_LEN] = [0; LONG_LEN];
+        bytemuck::cast_slice_mut::<u32, u8>(&mut res).copy_from_slice(bytes.get(..BYTE_LEN)?);
+        Some((ClientMessageData { data: res }, BYTE_LEN))
     }
 }
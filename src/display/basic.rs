@@ -1,27 +1,33 @@
 // MIT/Apache2 License
 
 use super::{
-    bigreq, input, output, Connection, Display, DisplayBase, PendingReply, PendingRequest,
-    RequestInfo, EXT_KEY_SIZE,
+    bigreq, clamp_pending, free_requests, free_tracked_resources,
+    input::{self, EventRoutes, OrphanReplies},
+    output, Connection, Display, DisplayBase, EventDecoder, ExtensionPresence, OrphanReplyHook,
+    PendingReplies, PendingReply, PendingRequest, RequestInfo, ResourceKind, ResourceTracker,
+    EXT_KEY_SIZE,
 };
 use crate::{
-    auth_info::AuthInfo, auto::xproto::Setup, error::BreadError, event::Event, XidGenerator, XID,
+    auth_info::AuthInfo, auto::xproto::Setup, error::BreadError, event::Event, ServerTime,
+    XidGenerator, XID,
 };
-use alloc::{borrow::Cow, collections::VecDeque};
+use alloc::{borrow::Cow, collections::VecDeque, vec::Vec};
 use core::num::NonZeroU32;
 use hashbrown::HashMap;
 
 #[cfg(feature = "std")]
 use super::name::NameConnection;
 
+#[cfg(all(feature = "tokio-support", unix))]
+use super::TokioConnection;
 #[cfg(feature = "async")]
 use super::{
     common::{SendBuffer, WaitBuffer, WaitBufferReturn},
     name::AsyncNameConnection,
-    AsyncConnection, AsyncDisplay, RequestWorkaround,
+    send_without_waiting, AsyncConnection, AsyncDisplay, RequestWorkaround,
 };
 #[cfg(feature = "async")]
-use alloc::{vec, vec::Vec};
+use alloc::vec;
 #[cfg(feature = "async")]
 use core::{
     mem,
@@ -29,6 +35,16 @@ use core::{
 };
 
 /// An implementor of `Display` and `AsyncDisplay` that requires &mut access in order to use.
+///
+/// ## Dropping
+///
+/// Requests are written to the connection as soon as they are sent, so dropping a `BasicDisplay` never
+/// loses any requests. Dropping it closes the connection to the server, discards any replies or events
+/// that have not been handled yet, and closes any file descriptors held by those replies. Errors that
+/// occur while closing the connection are ignored; use [`BasicDisplay::close`] in order to observe them.
+///
+/// If resource tracking has been turned on with `track_resources`, the tracked resources are freed right
+/// before the connection is closed. For async connections, this blocks until the requests are written.
 #[derive(Debug)]
 pub struct BasicDisplay<Conn> {
     // NOTE: every field in this structure is pub(crate), because the implementations of From
@@ -65,7 +81,7 @@ pub struct BasicDisplay<Conn> {
     /// to ever hold many entries; it might be worth reconsidering its type.
     pub(crate) pending_errors: HashMap<u16, BreadError>,
     /// Map associating request numbers with replies sent by the server.
-    pub(crate) pending_replies: HashMap<u16, PendingReply>,
+    pub(crate) pending_replies: PendingReplies,
 
     // special events queue
     pub(crate) special_event_queues: HashMap<XID, VecDeque<Event>>,
@@ -74,14 +90,50 @@ pub struct BasicDisplay<Conn> {
 
     // store the interned atoms
     pub(crate) wm_protocols_atom: Option<NonZeroU32>,
+    pub(crate) xembed_atom: Option<NonZeroU32>,
+    pub(crate) xembed_info_atom: Option<NonZeroU32>,
+
+    // the most recent timestamp we've seen from the server
+    pub(crate) last_event_time: ServerTime,
+
+    // how many times the keyboard mapping has changed, counted through MappingNotify events
+    pub(crate) keymap_generation: u32,
+
+    // whether we've sent the Generic Event Extension our version yet
+    pub(crate) ge_negotiated: bool,
+
+    // the most replies we allow to be outstanding before applying backpressure
+    pub(crate) max_pending: usize,
+
+    // the current size of each screen's root window, kept up to date through ConfigureNotify events
+    pub(crate) root_sizes: Vec<(u16, u16)>,
+
+    // the resources to free when we're dropped, if we were asked to track them
+    pub(crate) resources: ResourceTracker,
+    // writes to the connection, for freeing resources on drop; the Drop impl can't require that Conn be
+    // a Connection, so the constructors fill this in
+    pub(crate) send_on_drop: Option<fn(&mut Conn, &[u8]) -> crate::Result>,
+    // sends the requests that are still being held back, before anything else is written on drop
+    pub(crate) flush_on_drop: Option<fn(&mut Conn, &mut dyn DisplayBase) -> crate::Result>,
+
+    // replies that arrived without a request waiting on them
+    pub(crate) orphan_replies: OrphanReplies,
+    // decoders for extension events
+    pub(crate) event_routes: EventRoutes,
 
     // tell whether or not we care about the output of zero-sized replies
     pub(crate) checked: bool,
 
-    // hashmap linking extension names to major opcodes
+    // hashmap linking extension names to major opcodes, or to None if the server doesn't support them
     // we use byte arrays instead of static string pointers
     // here because cache locality leads to an overall speedup (todo: verify)
-    pub(crate) extensions: HashMap<[u8; EXT_KEY_SIZE], u8>,
+    pub(crate) extensions: HashMap<[u8; EXT_KEY_SIZE], Option<u8>>,
+    // same as above, but for the first event code of each extension
+    pub(crate) extension_first_events: HashMap<[u8; EXT_KEY_SIZE], u8>,
+    // sequence numbers of QueryExtension requests sent ahead of time, whose replies haven't been used yet
+    pub(crate) pending_extension_queries: HashMap<[u8; EXT_KEY_SIZE], u16>,
+    // requests waiting on the opcode of their extension, or on such a request before them
+    pub(crate) deferred_requests: VecDeque<RequestInfo>,
 
     // internal buffer for polling for waiting
     #[cfg(feature = "async")]
@@ -112,13 +164,28 @@ impl<Conn> BasicDisplay<Conn> {
             // and expanding this map is considered to be a cold operation
             special_event_queues: HashMap::with_capacity(1),
             pending_requests: HashMap::with_capacity(4),
-            pending_replies: HashMap::with_capacity(4),
+            pending_replies: PendingReplies::with_capacity(4),
             pending_errors: HashMap::with_capacity(4),
             request_number: 1,
             wm_protocols_atom: None,
+            xembed_atom: None,
+            xembed_info_atom: None,
+            last_event_time: ServerTime::CURRENT_TIME,
+            keymap_generation: 0,
+            ge_negotiated: false,
+            max_pending: usize::MAX,
+            root_sizes: Vec::new(),
+            resources: Default::default(),
+            send_on_drop: None,
+            flush_on_drop: None,
+            orphan_replies: Default::default(),
+            event_routes: Default::default(),
             checked: cfg!(debug_assertions),
             //            context: HashMap::new(),
             extensions: HashMap::with_capacity(8),
+            extension_first_events: HashMap::with_capacity(8),
+            pending_extension_queries: HashMap::new(),
+            deferred_requests: VecDeque::new(),
             #[cfg(feature = "async")]
             wait_buffer: None,
             #[cfg(feature = "async")]
@@ -127,6 +194,18 @@ impl<Conn> BasicDisplay<Conn> {
             workarounders: vec![],
         }
     }
+
+    /// Set the maximum request length from the core limit and, if BIG-REQUESTS was enabled, the extended
+    /// limit it gave us. Both fields change together, so the limit and the request encoding never disagree.
+    #[inline]
+    fn set_request_limit(&mut self, core_len: u16, bigreq_len: Option<u32>) {
+        let (bigreq_enabled, len) = match bigreq_len {
+            Some(len) => (true, len as usize),
+            None => (false, core_len as usize),
+        };
+        self.bigreq_enabled = bigreq_enabled;
+        self.max_request_len = len.saturating_mul(4);
+    }
 }
 
 impl<Conn: Connection> BasicDisplay<Conn> {
@@ -139,16 +218,45 @@ impl<Conn: Connection> BasicDisplay<Conn> {
         let mut this = Self::from_connection_internal(connection, default_screen);
         let (setup, xid) = this.connection.as_mut().unwrap().establish(auth_info)?;
 
-        this.max_request_len = (setup.maximum_request_length as usize).saturating_mul(4);
+        this.set_request_limit(setup.maximum_request_length, None);
 
-        if let Some(max_request_len) = bigreq::try_bigreq(&mut this)? {
-            this.bigreq_enabled = true;
-            this.max_request_len = (max_request_len as usize).saturating_mul(4);
-        }
+        let bigreq_len = bigreq::try_bigreq(&mut this)?;
+        this.set_request_limit(setup.maximum_request_length, bigreq_len);
+        this.root_sizes = root_sizes(&setup);
         this.setup = setup;
         this.xid = xid;
+        this.send_on_drop = Some(|conn, bytes| conn.send_packet(bytes, &mut Vec::new()));
+        this.flush_on_drop = Some(|conn, display| output::flush_deferred_requests(display, conn));
         Ok(this)
     }
+
+    /// Close the connection to the X server, returning any error that occurs while shutting it down.
+    #[inline]
+    pub fn close(mut self) -> crate::Result {
+        match self.connection.take() {
+            Some(mut connection) => {
+                output::flush_deferred_requests(&mut self, &mut connection)?;
+                let resources = self.resources.take();
+                if !resources.is_empty() {
+                    connection.send_packet(&free_requests(&resources), &mut Vec::new())?;
+                }
+                connection.shutdown()
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Recover from a `ProtocolDesync` error by skipping ahead to a known packet boundary. Everything the
+    /// server sent in the meantime is lost, including events, and requests still waiting on a reply fail
+    /// with `ProtocolDesync`. This is a last resort for clients that would rather lose some data than
+    /// crash.
+    #[inline]
+    pub fn resync(&mut self) -> crate::Result {
+        let mut conn = self.connection.take().expect("Poisoned!");
+        let res = input::resync(self, &mut conn);
+        self.connection = Some(conn);
+        res
+    }
 }
 
 #[cfg(feature = "async")]
@@ -166,14 +274,14 @@ impl<Conn: AsyncConnection + Unpin> BasicDisplay<Conn> {
             .unwrap()
             .establish_async(auth_info)
             .await?;
-        this.max_request_len = (setup.maximum_request_length as usize).saturating_mul(4);
+        this.set_request_limit(setup.maximum_request_length, None);
 
-        if let Some(max_request_len) = bigreq::try_bigreq_async(&mut this).await? {
-            this.bigreq_enabled = true;
-            this.max_request_len = (max_request_len as usize).saturating_mul(4);
-        }
+        let bigreq_len = bigreq::try_bigreq_async(&mut this).await?;
+        this.set_request_limit(setup.maximum_request_length, bigreq_len);
+        this.root_sizes = root_sizes(&setup);
         this.setup = setup;
         this.xid = xid;
+        this.send_on_drop = Some(|conn, bytes| send_without_waiting(conn, bytes));
         Ok(this)
     }
 }
@@ -254,7 +362,7 @@ impl<Conn> DisplayBase for BasicDisplay<Conn> {
 
     #[inline]
     fn take_pending_reply(&mut self, req_id: u16) -> Option<PendingReply> {
-        self.pending_replies.remove(&req_id)
+        self.pending_replies.remove(req_id)
     }
 
     #[inline]
@@ -307,12 +415,67 @@ impl<Conn> DisplayBase for BasicDisplay<Conn> {
 
     #[inline]
     fn get_extension_opcode(&mut self, key: &[u8; EXT_KEY_SIZE]) -> Option<u8> {
-        self.extensions.get(key).copied()
+        self.extensions.get(key).copied().flatten()
     }
 
     #[inline]
     fn set_extension_opcode(&mut self, key: [u8; EXT_KEY_SIZE], opcode: u8) {
-        self.extensions.insert(key, opcode);
+        self.extensions.insert(key, Some(opcode));
+    }
+
+    #[inline]
+    fn get_extension_presence(&mut self, key: &[u8; EXT_KEY_SIZE]) -> ExtensionPresence {
+        self.extensions.get(key).copied().into()
+    }
+
+    #[inline]
+    fn set_extension_absent(&mut self, key: [u8; EXT_KEY_SIZE]) {
+        self.extensions.insert(key, None);
+    }
+
+    #[inline]
+    fn get_extension_first_event(&self, key: &[u8; EXT_KEY_SIZE]) -> Option<u8> {
+        self.extension_first_events.get(key).copied()
+    }
+
+    #[inline]
+    fn set_extension_first_event(&mut self, key: [u8; EXT_KEY_SIZE], first_event: u8) {
+        self.extension_first_events.insert(key, first_event);
+    }
+
+    #[inline]
+    fn add_pending_extension_query(&mut self, key: [u8; EXT_KEY_SIZE], req_id: u16) {
+        self.pending_extension_queries.insert(key, req_id);
+    }
+
+    #[inline]
+    fn take_pending_extension_query(&mut self, key: &[u8; EXT_KEY_SIZE]) -> Option<u16> {
+        self.pending_extension_queries.remove(key)
+    }
+
+    #[inline]
+    fn pending_extension_query(&self, key: &[u8; EXT_KEY_SIZE]) -> Option<u16> {
+        self.pending_extension_queries.get(key).copied()
+    }
+
+    #[inline]
+    fn defer_request(&mut self, request: RequestInfo) {
+        self.deferred_requests.push_back(request);
+    }
+
+    #[inline]
+    fn take_deferred_request(&mut self) -> Option<RequestInfo> {
+        self.deferred_requests.pop_front()
+    }
+
+    #[inline]
+    fn restore_deferred_request(&mut self, request: RequestInfo) {
+        self.deferred_requests.push_front(request);
+    }
+
+    #[inline]
+    fn has_deferred_requests(&self) -> bool {
+        !self.deferred_requests.is_empty()
     }
 
     #[inline]
@@ -324,6 +487,185 @@ impl<Conn> DisplayBase for BasicDisplay<Conn> {
     fn set_wm_protocols_atom(&mut self, a: NonZeroU32) {
         self.wm_protocols_atom = Some(a);
     }
+
+    #[inline]
+    fn xembed_atom(&self) -> Option<NonZeroU32> {
+        self.xembed_atom
+    }
+
+    #[inline]
+    fn set_xembed_atom(&mut self, a: NonZeroU32) {
+        self.xembed_atom = Some(a);
+    }
+
+    #[inline]
+    fn xembed_info_atom(&self) -> Option<NonZeroU32> {
+        self.xembed_info_atom
+    }
+
+    #[inline]
+    fn set_xembed_info_atom(&mut self, a: NonZeroU32) {
+        self.xembed_info_atom = Some(a);
+    }
+
+    #[inline]
+    fn last_event_time(&self) -> ServerTime {
+        self.last_event_time
+    }
+
+    #[inline]
+    fn set_last_event_time(&mut self, time: ServerTime) {
+        self.last_event_time = time;
+    }
+
+    #[inline]
+    fn keymap_generation(&self) -> u32 {
+        self.keymap_generation
+    }
+
+    #[inline]
+    fn set_keymap_generation(&mut self, generation: u32) {
+        self.keymap_generation = generation;
+    }
+
+    #[inline]
+    fn ge_negotiated(&self) -> bool {
+        self.ge_negotiated
+    }
+
+    #[inline]
+    fn set_ge_negotiated(&mut self) {
+        self.ge_negotiated = true;
+    }
+
+    #[inline]
+    fn max_pending(&self) -> usize {
+        self.max_pending
+    }
+
+    #[inline]
+    fn set_max_pending(&mut self, max: usize) {
+        self.max_pending = max;
+    }
+
+    #[inline]
+    fn awaited_reply_count(&self) -> usize {
+        self.pending_requests
+            .values()
+            .filter(|pereq| !pereq.flags.checked)
+            .count()
+    }
+
+    #[inline]
+    fn unread_reply_count(&self) -> usize {
+        self.pending_replies.len()
+    }
+
+    #[inline]
+    fn reserve_pending(&mut self, additional: usize) {
+        let additional = clamp_pending(additional);
+        self.pending_requests.reserve(additional);
+        self.pending_replies.reserve(additional);
+    }
+
+    #[inline]
+    fn root_size(&self, screen: usize) -> Option<(u16, u16)> {
+        self.root_sizes.get(screen).copied()
+    }
+
+    #[inline]
+    fn set_root_size(&mut self, screen: usize, width: u16, height: u16) {
+        if let Some(size) = self.root_sizes.get_mut(screen) {
+            *size = (width, height);
+        }
+    }
+
+    #[inline]
+    fn tracking_resources(&self) -> bool {
+        self.resources.enabled()
+    }
+
+    #[inline]
+    fn track_resources(&mut self, track: bool) {
+        self.resources.set_enabled(track);
+    }
+
+    #[inline]
+    fn track_resource(&mut self, xid: XID, kind: ResourceKind) {
+        self.resources.track(xid, kind);
+    }
+
+    #[inline]
+    fn untrack_resource(&mut self, xid: XID) {
+        self.resources.untrack(xid);
+    }
+
+    #[inline]
+    fn add_orphan_reply(&mut self, sequence: u16, reply: &[u8]) {
+        self.orphan_replies.add(sequence, reply);
+    }
+
+    #[inline]
+    fn orphan_reply_count(&self) -> usize {
+        self.orphan_replies.count()
+    }
+
+    #[inline]
+    fn on_orphan_reply(&mut self, hook: Option<OrphanReplyHook>) {
+        self.orphan_replies.set_hook(hook);
+    }
+
+    #[inline]
+    fn register_event_decoder(
+        &mut self,
+        extension: &'static str,
+        event_count: u8,
+        decoder: EventDecoder,
+    ) {
+        self.event_routes.register(extension, event_count, decoder);
+    }
+
+    #[inline]
+    fn event_decoder(&self, code: u8) -> Option<(u8, EventDecoder)> {
+        let first_events = &self.extension_first_events;
+        self.event_routes
+            .find(code, |key| first_events.get(key).copied())
+    }
+
+    #[inline]
+    fn has_event_decoder(&self, extension: &str) -> bool {
+        self.event_routes.contains(extension)
+    }
+}
+
+/// Get the initial sizes of the root windows from the setup.
+#[inline]
+fn root_sizes(setup: &Setup) -> Vec<(u16, u16)> {
+    setup
+        .roots
+        .iter()
+        .map(|screen| (screen.width_in_pixels, screen.height_in_pixels))
+        .collect()
+}
+
+impl<Conn> Drop for BasicDisplay<Conn> {
+    #[inline]
+    fn drop(&mut self) {
+        if let (Some(flush), Some(mut conn)) = (self.flush_on_drop, self.connection.take()) {
+            if let Err(e) = flush(&mut conn, self) {
+                log::error!("Failed to send held back requests: {}", e);
+            }
+            self.connection = Some(conn);
+        }
+
+        if let (Some(send), Some(conn)) = (self.send_on_drop, self.connection.as_mut()) {
+            free_tracked_resources(send, conn, self.resources.take());
+        }
+
+        // the connection closes itself when it is dropped, but the file descriptors in unclaimed replies
+        // are our responsibility
+        self.pending_replies.close();
+    }
 }
 
 impl<Connect: Connection> Display for BasicDisplay<Connect> {
@@ -349,10 +691,16 @@ impl<Connect: AsyncConnection + Unpin> AsyncDisplay for BasicDisplay<Connect> {
     #[inline]
     fn poll_wait(&mut self, cx: &mut Context<'_>) -> Poll<crate::Result> {
         let mut conn = self.connection.take().expect("Poisoned!");
+        let pending_requests = &self.pending_requests;
         let res = self
             .wait_buffer
             .get_or_insert_with(WaitBuffer::default)
-            .poll_wait(&mut conn, &self.workarounders, cx);
+            .poll_wait(
+                &mut conn,
+                &self.workarounders,
+                |seq| pending_requests.get(&seq).copied(),
+                cx,
+            );
         self.connection = Some(conn);
         let (bytes, fds) = match res {
             Poll::Ready(res) => {
@@ -400,13 +748,17 @@ pub type DisplayConnection = BasicDisplay<NameConnection>;
 #[cfg(all(feature = "std", feature = "async"))]
 pub type AsyncDisplayConnection = BasicDisplay<AsyncNameConnection>;
 
+#[cfg(all(feature = "tokio-support", unix))]
+pub type TokioDisplayConnection = BasicDisplay<TokioConnection>;
+
 #[cfg(feature = "std")]
 impl DisplayConnection {
     /// Create a new connection to the X server, given an optional name and authorization information.
     #[inline]
     pub fn create(name: Option<Cow<'_, str>>, auth_info: Option<AuthInfo>) -> crate::Result<Self> {
-        let (connection, screen) = NameConnection::connect_internal(name)?;
-        Self::from_connection(connection, screen, auth_info)
+        let (connection, screen, target) = NameConnection::connect_internal(name)?;
+        let auth_info = auth_info.unwrap_or_else(|| AuthInfo::get_for(&target));
+        Self::from_connection(connection, screen, Some(auth_info))
     }
 }
 
@@ -414,13 +766,189 @@ impl DisplayConnection {
 impl AsyncDisplayConnection {
     /// Create a new connection to the X server, given an optional name and authorization information, async
     /// redox.
+    ///
+    /// The socket is connected on `async-io`'s reactor. `async-io` can't resolve host names, so for TCP
+    /// displays that step runs on `blocking`'s thread pool, as does reading the `XAUTHORITY` file if no
+    /// authorization information is given. Neither blocks the executor. Use `create_tokio` instead to do all
+    /// of this on `tokio`'s runtime.
     #[cfg(feature = "async")]
     #[inline]
     pub async fn create_async(
         name: Option<Cow<'_, str>>,
         auth_info: Option<AuthInfo>,
     ) -> crate::Result<Self> {
-        let (connection, screen) = AsyncNameConnection::connect_internal_async(name).await?;
-        Self::from_connection_async(connection, screen, auth_info).await
+        let (connection, screen, target) =
+            AsyncNameConnection::connect_internal_async(name).await?;
+        let auth_info = match auth_info {
+            Some(auth_info) => auth_info,
+            None => AuthInfo::get_for_async(target).await,
+        };
+        Self::from_connection_async(connection, screen, Some(auth_info)).await
+    }
+}
+
+#[cfg(all(feature = "tokio-support", unix))]
+impl TokioDisplayConnection {
+    /// Create a new connection to the X server that runs on `tokio`'s reactor. None of this blocks the
+    /// runtime: host names are resolved and the socket is connected through `tokio`, and if no authorization
+    /// information is given, the `XAUTHORITY` file is read on `blocking`'s thread pool.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if it is not called from within a `tokio` runtime.
+    #[inline]
+    pub async fn create_tokio(
+        name: Option<Cow<'_, str>>,
+        auth_info: Option<AuthInfo>,
+    ) -> crate::Result<Self> {
+        let (connection, screen, target) = TokioConnection::connect_internal(name).await?;
+        let auth_info = match auth_info {
+            Some(auth_info) => auth_info,
+            None => AuthInfo::get_for_async(target).await,
+        };
+        Self::from_connection_async(connection, screen, Some(auth_info)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BasicDisplay;
+    use crate::{
+        auth_info::AuthInfo,
+        auto::xproto::{GetInputFocusRequest, Setup},
+        display::{mock_display, mock_setup, DisplayBase, DisplayExt, MockConnection},
+        BreadError,
+    };
+
+    #[test]
+    fn server_info_and_version_check() {
+        use alloc::string::ToString;
+
+        let setup = Setup {
+            protocol_minor_version: 0,
+            release_number: 12_101_004,
+            vendor: "The X.Org Foundation".to_string(),
+            ..mock_setup()
+        };
+        let mut conn = MockConnection::with_setup(&setup);
+        let display = mock_display(&mut conn);
+        let info = display.server_info();
+        assert_eq!(info.protocol_major_version, 11);
+        assert_eq!(info.vendor, "The X.Org Foundation");
+        assert_eq!(info.release_number, 12_101_004);
+
+        // a server from the future isn't one we can talk to
+        let setup = Setup {
+            protocol_major_version: 12,
+            protocol_minor_version: 3,
+            ..setup
+        };
+        let mut conn = MockConnection::with_setup(&setup);
+        assert!(matches!(
+            BasicDisplay::from_connection(&mut conn, 0, Some(AuthInfo::default())),
+            Err(BreadError::UnsupportedProtocolVersion {
+                major: 12,
+                minor: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn reserve_pending_is_clamped() {
+        use crate::auto::xproto::GetInputFocusReply;
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_reply(&GetInputFocusReply {
+            reply_type: 1,
+            sequence: 2,
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        // this would fail to allocate if it weren't clamped to the number of sequence numbers
+        display.reserve_pending(usize::MAX);
+        assert!(display.pending_requests.capacity() > usize::from(u16::MAX));
+        display
+            .exchange_request(GetInputFocusRequest::default())
+            .unwrap();
+    }
+
+    #[cfg(feature = "fixes")]
+    #[test]
+    fn held_back_requests_are_sent_before_closing() {
+        use crate::auto::xproto::{QueryExtensionReply, Window};
+        use alloc::vec::Vec;
+
+        fn hide_cursor(display: &mut BasicDisplay<&mut MockConnection>) {
+            use crate::auto::xfixes::HideCursorRequest;
+
+            display.set_checked(false);
+            display
+                .send_request(HideCursorRequest {
+                    window: Window::const_from_xid(1),
+                    ..Default::default()
+                })
+                .unwrap();
+            assert!(display.has_deferred_requests());
+        }
+
+        let query = QueryExtensionReply {
+            reply_type: 1,
+            sequence: 2,
+            present: true,
+            major_opcode: 140,
+            ..Default::default()
+        };
+        let mut expected = Vec::from(&[140, 29][..]);
+        expected.extend_from_slice(&2u16.to_ne_bytes());
+        expected.extend_from_slice(&1u32.to_ne_bytes());
+
+        // the request is still waiting on its extension's opcode when the display is dropped
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_reply(&query);
+        hide_cursor(&mut mock_display(&mut conn));
+        assert!(conn.outgoing().ends_with(&expected));
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_reply(&query);
+        let mut display = mock_display(&mut conn);
+        hide_cursor(&mut display);
+        display.close().unwrap();
+        assert!(conn.outgoing().ends_with(&expected));
+
+        // without the opcode, the request can't be sent, and close() says so
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        let mut display = mock_display(&mut conn);
+        hide_cursor(&mut display);
+        assert!(matches!(display.close(), Err(BreadError::ClosedConnection)));
+        assert!(!conn.outgoing().ends_with(&expected));
+    }
+
+    #[cfg(all(unix, feature = "std"))]
+    #[test]
+    fn unclaimed_reply_fds_are_closed_on_drop() {
+        use crate::{
+            auto::xproto::GetInputFocusReply,
+            display::{pipe, write_end_closed, Display},
+        };
+        use alloc::vec;
+
+        let (read, write) = pipe();
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_fds(vec![write]);
+        conn.feed_reply(&GetInputFocusReply {
+            reply_type: 1,
+            sequence: 2,
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        display
+            .send_request(GetInputFocusRequest::default())
+            .unwrap();
+        display.wait().unwrap();
+        assert_eq!(display.unread_reply_count(), 1);
+        drop(display);
+        assert!(write_end_closed(read));
     }
 }
@@ -16,9 +16,14 @@ use crate::auto::bigreq::EnableReply;
 /// Try to enable `bigreq` for this display.
 #[inline]
 pub(crate) fn try_bigreq<D: Display + ?Sized>(display: &mut D) -> crate::Result<Option<u32>> {
+    // a request to a missing extension still takes up a sequence number, so don't send one
+    if !display.has_extension("BIG-REQUESTS")? {
+        return Ok(None);
+    }
+
     match display.exchange_request(EnableRequest::default()) {
         Ok(repl) => Ok(Some(repl.maximum_request_length)),
-        Err(crate::BreadError::ExtensionNotPresent(_)) => Ok(None),
+        Err(crate::BreadError::ExtensionNotPresent { .. }) => Ok(None),
         Err(e) => Err(e),
     }
 }
@@ -36,7 +41,7 @@ pub(crate) fn try_bigreq_async<D: AsyncDisplay + ?Sized>(
         display.exchange_request_async(EnableRequest::default()),
         |repl| match repl {
             Ok(repl) => Ok(Some(repl.maximum_request_length)),
-            Err(crate::BreadError::ExtensionNotPresent(_)) => Ok(None),
+            Err(crate::BreadError::ExtensionNotPresent { .. }) => Ok(None),
             Err(e) => Err(e),
         },
     )
@@ -1,13 +1,17 @@
 // MIT/Apache2 License
 
 use super::{
-    input, output, BasicDisplay, Connection, Display, DisplayBase, PendingReply, PendingRequest,
-    RequestInfo, EXT_KEY_SIZE,
+    clamp_pending, free_requests, free_tracked_resources,
+    input::{self, EventRoutes, OrphanReplies},
+    output, BasicDisplay, Connection, Display, DisplayBase, EventDecoder, ExtensionPresence,
+    OrphanReplyHook, PendingReplies, PendingReply, PendingRequest, RequestInfo, ResourceKind,
+    ResourceTracker, EXT_KEY_SIZE,
 };
-use crate::{auto::xproto::Setup, BreadError, CellXidGenerator, Event, XID};
-use alloc::collections::VecDeque;
+use crate::{auto::xproto::Setup, BreadError, CellXidGenerator, Event, ServerTime, XID};
+use alloc::{collections::VecDeque, vec::Vec};
 use core::{
     cell::{Cell, RefCell},
+    mem,
     num::NonZeroU32,
 };
 use hashbrown::HashMap;
@@ -18,12 +22,9 @@ use super::{
     AsyncConnection, AsyncDisplay, RequestWorkaround,
 };
 #[cfg(feature = "async")]
-use alloc::{vec, vec::Vec};
+use alloc::vec;
 #[cfg(feature = "async")]
-use core::{
-    mem,
-    task::{Context, Poll},
-};
+use core::task::{Context, Poll};
 
 /// An implementor of [`Display`] and [`AsyncDisplay`] that uses [`Cell`] and [`RefCell`] in order to allow
 /// for immutable use of the `Display`. The primary downside is that it is not [`Sync`].
@@ -50,6 +51,12 @@ use core::{
 /// If you *can* restructure your program so that interior mutability is not required, it is considered better
 /// form to use `BasicDisplay`. However, if interior mutability is necessary, `CellDisplay` is preferred over
 /// `RefCell<BasicDisplay>`.
+///
+/// ## Dropping
+///
+/// Dropping a `CellDisplay` behaves the same way as dropping a [`BasicDisplay`]: tracked resources are freed,
+/// the connection is closed, and file descriptors held by unclaimed replies are closed alongside it. Use [`CellDisplay::close`] in
+/// order to observe errors that occur during shutdown.
 #[derive(Debug)]
 pub struct CellDisplay<Conn> {
     // the connection to the server
@@ -79,10 +86,25 @@ pub struct CellDisplay<Conn> {
 
     // store the interned atoms
     wm_protocols_atom: Cell<Option<NonZeroU32>>,
+    xembed_atom: Cell<Option<NonZeroU32>>,
+    xembed_info_atom: Cell<Option<NonZeroU32>>,
+
+    // the most recent timestamp we've seen from the server
+    last_event_time: Cell<ServerTime>,
+    keymap_generation: Cell<u32>,
+    ge_negotiated: Cell<bool>,
+
+    // the most replies we allow to be outstanding before applying backpressure
+    max_pending: Cell<usize>,
 
     // tell whether or not we care about the output of zero-sized replies
     checked: Cell<bool>,
 
+    // writes to the connection, for freeing tracked resources on drop
+    send_on_drop: Option<fn(&mut Conn, &[u8]) -> crate::Result>,
+    // sends the requests that are still being held back on drop
+    flush_on_drop: Option<fn(&mut Conn, &mut dyn DisplayBase) -> crate::Result>,
+
     // used for polling
     #[cfg(feature = "async")]
     wait_buffer: RefCell<Option<WaitBuffer>>,
@@ -98,57 +120,70 @@ struct Data {
     event_queue: VecDeque<Event>,
     pending_requests: HashMap<u16, PendingRequest>,
     pending_errors: HashMap<u16, BreadError>,
-    pending_replies: HashMap<u16, PendingReply>,
+    pending_replies: PendingReplies,
     special_event_queues: HashMap<XID, VecDeque<Event>>,
-    extensions: HashMap<[u8; EXT_KEY_SIZE], u8>,
+    extensions: HashMap<[u8; EXT_KEY_SIZE], Option<u8>>,
+    extension_first_events: HashMap<[u8; EXT_KEY_SIZE], u8>,
+    pending_extension_queries: HashMap<[u8; EXT_KEY_SIZE], u16>,
+    deferred_requests: VecDeque<RequestInfo>,
+    root_sizes: Vec<(u16, u16)>,
+    resources: ResourceTracker,
+    orphan_replies: OrphanReplies,
+    event_routes: EventRoutes,
     #[cfg(feature = "async")]
     workarounders: Vec<u16>,
 }
 
+impl Data {
+    #[inline]
+    fn reserve_pending(&mut self, additional: usize) {
+        let additional = clamp_pending(additional);
+        self.pending_requests.reserve(additional);
+        self.pending_replies.reserve(additional);
+    }
+}
+
 impl<Conn> From<BasicDisplay<Conn>> for CellDisplay<Conn> {
     /// Convert a `BasicDisplay` into a `CellDisplay`.
     #[inline]
-    fn from(display: BasicDisplay<Conn>) -> Self {
-        let BasicDisplay {
-            connection,
-            setup,
-            xid,
-            bigreq_enabled,
-            max_request_len,
-            default_screen,
-            event_queue,
-            pending_requests,
-            pending_errors,
-            pending_replies,
-            special_event_queues,
-            request_number,
-            wm_protocols_atom,
-            checked,
-            extensions,
-            ..
-        } = display;
-
+    fn from(mut display: BasicDisplay<Conn>) -> Self {
+        // BasicDisplay implements Drop, so we can't destructure it; take its fields instead
         Self {
-            connection,
+            connection: display.connection.take(),
             io_lock: Cell::new(false),
-            setup,
-            xid: xid.into(),
-            bigreq_enabled,
-            max_request_len,
-            default_screen,
+            setup: mem::take(&mut display.setup),
+            xid: display.xid.into(),
+            bigreq_enabled: display.bigreq_enabled,
+            max_request_len: display.max_request_len,
+            default_screen: display.default_screen,
             inner: RefCell::new(Data {
-                event_queue,
-                pending_requests,
-                pending_errors,
-                pending_replies,
-                special_event_queues,
-                extensions,
+                event_queue: mem::take(&mut display.event_queue),
+                pending_requests: mem::take(&mut display.pending_requests),
+                pending_errors: mem::take(&mut display.pending_errors),
+                pending_replies: mem::take(&mut display.pending_replies),
+                special_event_queues: mem::take(&mut display.special_event_queues),
+                extensions: mem::take(&mut display.extensions),
+                extension_first_events: mem::take(&mut display.extension_first_events),
+                pending_extension_queries: mem::take(&mut display.pending_extension_queries),
+                deferred_requests: mem::take(&mut display.deferred_requests),
+                root_sizes: mem::take(&mut display.root_sizes),
+                resources: mem::take(&mut display.resources),
+                orphan_replies: mem::take(&mut display.orphan_replies),
+                event_routes: mem::take(&mut display.event_routes),
                 #[cfg(feature = "async")]
                 workarounders: vec![],
             }),
-            request_number: Cell::new(request_number),
-            wm_protocols_atom: Cell::new(wm_protocols_atom),
-            checked: Cell::new(checked),
+            request_number: Cell::new(display.request_number),
+            wm_protocols_atom: Cell::new(display.wm_protocols_atom),
+            xembed_atom: Cell::new(display.xembed_atom),
+            xembed_info_atom: Cell::new(display.xembed_info_atom),
+            last_event_time: Cell::new(display.last_event_time),
+            keymap_generation: Cell::new(display.keymap_generation),
+            ge_negotiated: Cell::new(display.ge_negotiated),
+            max_pending: Cell::new(display.max_pending),
+            checked: Cell::new(display.checked),
+            send_on_drop: display.send_on_drop,
+            flush_on_drop: display.flush_on_drop,
             #[cfg(feature = "async")]
             wait_buffer: RefCell::new(None),
             #[cfg(feature = "async")]
@@ -181,6 +216,56 @@ impl<Conn> CellDisplay<Conn> {
     }
 }
 
+impl<Conn: Connection> CellDisplay<Conn> {
+    /// Close the connection to the X server, returning any error that occurs while shutting it down.
+    #[inline]
+    pub fn close(mut self) -> crate::Result {
+        match self.connection.take() {
+            Some(mut connection) => {
+                output::flush_deferred_requests(&mut self, &mut connection)?;
+                let resources = self.inner.get_mut().resources.take();
+                if !resources.is_empty() {
+                    connection.send_packet(&free_requests(&resources), &mut Vec::new())?;
+                }
+                connection.shutdown()
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Recover from a `ProtocolDesync` error by skipping ahead to a known packet boundary. See
+    /// `BasicDisplay::resync` for details.
+    #[inline]
+    pub fn resync(&mut self) -> crate::Result {
+        self.lock_internal();
+        let mut connection = self.connection.take().expect("Poisoned!");
+
+        let res = input::resync(self, &mut connection);
+
+        self.connection = Some(connection);
+        *self.io_lock.get_mut() = false;
+        res
+    }
+}
+
+impl<Conn> Drop for CellDisplay<Conn> {
+    #[inline]
+    fn drop(&mut self) {
+        if let (Some(flush), Some(mut conn)) = (self.flush_on_drop, self.connection.take()) {
+            if let Err(e) = flush(&mut conn, self) {
+                log::error!("Failed to send held back requests: {}", e);
+            }
+            self.connection = Some(conn);
+        }
+
+        if let (Some(send), Some(conn)) = (self.send_on_drop, self.connection.as_mut()) {
+            free_tracked_resources(send, conn, self.inner.get_mut().resources.take());
+        }
+
+        self.inner.get_mut().pending_replies.close();
+    }
+}
+
 impl<Conn> DisplayBase for CellDisplay<Conn> {
     #[inline]
     fn setup(&self) -> &Setup {
@@ -244,7 +329,7 @@ impl<Conn> DisplayBase for CellDisplay<Conn> {
     }
     #[inline]
     fn take_pending_reply(&mut self, req_id: u16) -> Option<PendingReply> {
-        self.inner.get_mut().pending_replies.remove(&req_id)
+        self.inner.get_mut().pending_replies.remove(req_id)
     }
     #[inline]
     fn create_special_event_queue(&mut self, xid: XID) {
@@ -293,11 +378,65 @@ impl<Conn> DisplayBase for CellDisplay<Conn> {
     }
     #[inline]
     fn get_extension_opcode(&mut self, key: &[u8; EXT_KEY_SIZE]) -> Option<u8> {
-        self.inner.get_mut().extensions.get(key).copied()
+        self.inner.get_mut().extensions.get(key).copied().flatten()
     }
     #[inline]
     fn set_extension_opcode(&mut self, key: [u8; EXT_KEY_SIZE], opcode: u8) {
-        self.inner.get_mut().extensions.insert(key, opcode);
+        self.inner.get_mut().extensions.insert(key, Some(opcode));
+    }
+    #[inline]
+    fn get_extension_presence(&mut self, key: &[u8; EXT_KEY_SIZE]) -> ExtensionPresence {
+        self.inner.get_mut().extensions.get(key).copied().into()
+    }
+    #[inline]
+    fn set_extension_absent(&mut self, key: [u8; EXT_KEY_SIZE]) {
+        self.inner.get_mut().extensions.insert(key, None);
+    }
+    #[inline]
+    fn get_extension_first_event(&self, key: &[u8; EXT_KEY_SIZE]) -> Option<u8> {
+        self.inner.borrow().extension_first_events.get(key).copied()
+    }
+    #[inline]
+    fn set_extension_first_event(&mut self, key: [u8; EXT_KEY_SIZE], first_event: u8) {
+        self.inner
+            .get_mut()
+            .extension_first_events
+            .insert(key, first_event);
+    }
+    #[inline]
+    fn add_pending_extension_query(&mut self, key: [u8; EXT_KEY_SIZE], req_id: u16) {
+        self.inner
+            .get_mut()
+            .pending_extension_queries
+            .insert(key, req_id);
+    }
+    #[inline]
+    fn take_pending_extension_query(&mut self, key: &[u8; EXT_KEY_SIZE]) -> Option<u16> {
+        self.inner.get_mut().pending_extension_queries.remove(key)
+    }
+    #[inline]
+    fn pending_extension_query(&self, key: &[u8; EXT_KEY_SIZE]) -> Option<u16> {
+        self.inner
+            .borrow()
+            .pending_extension_queries
+            .get(key)
+            .copied()
+    }
+    #[inline]
+    fn defer_request(&mut self, request: RequestInfo) {
+        self.inner.get_mut().deferred_requests.push_back(request);
+    }
+    #[inline]
+    fn take_deferred_request(&mut self) -> Option<RequestInfo> {
+        self.inner.get_mut().deferred_requests.pop_front()
+    }
+    #[inline]
+    fn restore_deferred_request(&mut self, request: RequestInfo) {
+        self.inner.get_mut().deferred_requests.push_front(request);
+    }
+    #[inline]
+    fn has_deferred_requests(&self) -> bool {
+        !self.inner.borrow().deferred_requests.is_empty()
     }
     #[inline]
     fn wm_protocols_atom(&self) -> Option<NonZeroU32> {
@@ -307,6 +446,154 @@ impl<Conn> DisplayBase for CellDisplay<Conn> {
     fn set_wm_protocols_atom(&mut self, a: NonZeroU32) {
         *self.wm_protocols_atom.get_mut() = Some(a);
     }
+    #[inline]
+    fn xembed_atom(&self) -> Option<NonZeroU32> {
+        self.xembed_atom.get()
+    }
+    #[inline]
+    fn set_xembed_atom(&mut self, a: NonZeroU32) {
+        *self.xembed_atom.get_mut() = Some(a);
+    }
+    #[inline]
+    fn xembed_info_atom(&self) -> Option<NonZeroU32> {
+        self.xembed_info_atom.get()
+    }
+    #[inline]
+    fn set_xembed_info_atom(&mut self, a: NonZeroU32) {
+        *self.xembed_info_atom.get_mut() = Some(a);
+    }
+
+    #[inline]
+    fn last_event_time(&self) -> ServerTime {
+        self.last_event_time.get()
+    }
+
+    #[inline]
+    fn set_last_event_time(&mut self, time: ServerTime) {
+        *self.last_event_time.get_mut() = time;
+    }
+
+    #[inline]
+    fn keymap_generation(&self) -> u32 {
+        self.keymap_generation.get()
+    }
+
+    #[inline]
+    fn set_keymap_generation(&mut self, generation: u32) {
+        *self.keymap_generation.get_mut() = generation;
+    }
+
+    #[inline]
+    fn ge_negotiated(&self) -> bool {
+        self.ge_negotiated.get()
+    }
+
+    #[inline]
+    fn set_ge_negotiated(&mut self) {
+        *self.ge_negotiated.get_mut() = true;
+    }
+
+    #[inline]
+    fn max_pending(&self) -> usize {
+        self.max_pending.get()
+    }
+
+    #[inline]
+    fn set_max_pending(&mut self, max: usize) {
+        *self.max_pending.get_mut() = max;
+    }
+
+    #[inline]
+    fn awaited_reply_count(&self) -> usize {
+        self.inner
+            .borrow()
+            .pending_requests
+            .values()
+            .filter(|pereq| !pereq.flags.checked)
+            .count()
+    }
+
+    #[inline]
+    fn unread_reply_count(&self) -> usize {
+        self.inner.borrow().pending_replies.len()
+    }
+
+    #[inline]
+    fn reserve_pending(&mut self, additional: usize) {
+        self.inner.get_mut().reserve_pending(additional);
+    }
+
+    #[inline]
+    fn root_size(&self, screen: usize) -> Option<(u16, u16)> {
+        self.inner.borrow().root_sizes.get(screen).copied()
+    }
+
+    #[inline]
+    fn set_root_size(&mut self, screen: usize, width: u16, height: u16) {
+        if let Some(size) = self.inner.get_mut().root_sizes.get_mut(screen) {
+            *size = (width, height);
+        }
+    }
+
+    #[inline]
+    fn tracking_resources(&self) -> bool {
+        self.inner.borrow().resources.enabled()
+    }
+
+    #[inline]
+    fn track_resources(&mut self, track: bool) {
+        self.inner.get_mut().resources.set_enabled(track);
+    }
+
+    #[inline]
+    fn track_resource(&mut self, xid: XID, kind: ResourceKind) {
+        self.inner.get_mut().resources.track(xid, kind);
+    }
+
+    #[inline]
+    fn untrack_resource(&mut self, xid: XID) {
+        self.inner.get_mut().resources.untrack(xid);
+    }
+
+    #[inline]
+    fn add_orphan_reply(&mut self, sequence: u16, reply: &[u8]) {
+        self.inner.get_mut().orphan_replies.add(sequence, reply);
+    }
+
+    #[inline]
+    fn orphan_reply_count(&self) -> usize {
+        self.inner.borrow().orphan_replies.count()
+    }
+
+    #[inline]
+    fn on_orphan_reply(&mut self, hook: Option<OrphanReplyHook>) {
+        self.inner.get_mut().orphan_replies.set_hook(hook);
+    }
+
+    #[inline]
+    fn register_event_decoder(
+        &mut self,
+        extension: &'static str,
+        event_count: u8,
+        decoder: EventDecoder,
+    ) {
+        self.inner
+            .get_mut()
+            .event_routes
+            .register(extension, event_count, decoder);
+    }
+
+    #[inline]
+    fn event_decoder(&self, code: u8) -> Option<(u8, EventDecoder)> {
+        let inner = self.inner.borrow();
+        inner
+            .event_routes
+            .find(code, |key| inner.extension_first_events.get(key).copied())
+    }
+    #[inline]
+    fn has_event_decoder(&self, extension: &str) -> bool {
+        self.inner.borrow().event_routes.contains(extension)
+    }
 }
 
 impl<Connect: Connection> Display for CellDisplay<Connect> {
@@ -350,7 +637,12 @@ impl<Connect: AsyncConnection + Unpin> AsyncDisplay for CellDisplay<Connect> {
             }
         };
         let data = self.inner.get_mut();
-        let res = wait_buffer.poll_wait(&mut conn, &data.workarounders, ctx);
+        let res = wait_buffer.poll_wait(
+            &mut conn,
+            &data.workarounders,
+            |seq| data.pending_requests.get(&seq).copied(),
+            ctx,
+        );
 
         self.connection = Some(conn);
 
@@ -463,7 +755,7 @@ impl<'a, Conn> DisplayBase for &'a CellDisplay<Conn> {
     }
     #[inline]
     fn take_pending_reply(&mut self, req_id: u16) -> Option<PendingReply> {
-        self.inner.borrow_mut().pending_replies.remove(&req_id)
+        self.inner.borrow_mut().pending_replies.remove(req_id)
     }
     #[inline]
     fn create_special_event_queue(&mut self, xid: XID) {
@@ -512,11 +804,76 @@ impl<'a, Conn> DisplayBase for &'a CellDisplay<Conn> {
     }
     #[inline]
     fn get_extension_opcode(&mut self, key: &[u8; EXT_KEY_SIZE]) -> Option<u8> {
-        self.inner.borrow_mut().extensions.get(key).copied()
+        self.inner
+            .borrow_mut()
+            .extensions
+            .get(key)
+            .copied()
+            .flatten()
     }
     #[inline]
     fn set_extension_opcode(&mut self, key: [u8; EXT_KEY_SIZE], opcode: u8) {
-        self.inner.borrow_mut().extensions.insert(key, opcode);
+        self.inner.borrow_mut().extensions.insert(key, Some(opcode));
+    }
+    #[inline]
+    fn get_extension_presence(&mut self, key: &[u8; EXT_KEY_SIZE]) -> ExtensionPresence {
+        self.inner.borrow_mut().extensions.get(key).copied().into()
+    }
+    #[inline]
+    fn set_extension_absent(&mut self, key: [u8; EXT_KEY_SIZE]) {
+        self.inner.borrow_mut().extensions.insert(key, None);
+    }
+    #[inline]
+    fn get_extension_first_event(&self, key: &[u8; EXT_KEY_SIZE]) -> Option<u8> {
+        self.inner.borrow().extension_first_events.get(key).copied()
+    }
+    #[inline]
+    fn set_extension_first_event(&mut self, key: [u8; EXT_KEY_SIZE], first_event: u8) {
+        self.inner
+            .borrow_mut()
+            .extension_first_events
+            .insert(key, first_event);
+    }
+    #[inline]
+    fn add_pending_extension_query(&mut self, key: [u8; EXT_KEY_SIZE], req_id: u16) {
+        self.inner
+            .borrow_mut()
+            .pending_extension_queries
+            .insert(key, req_id);
+    }
+    #[inline]
+    fn take_pending_extension_query(&mut self, key: &[u8; EXT_KEY_SIZE]) -> Option<u16> {
+        self.inner
+            .borrow_mut()
+            .pending_extension_queries
+            .remove(key)
+    }
+    #[inline]
+    fn pending_extension_query(&self, key: &[u8; EXT_KEY_SIZE]) -> Option<u16> {
+        self.inner
+            .borrow()
+            .pending_extension_queries
+            .get(key)
+            .copied()
+    }
+    #[inline]
+    fn defer_request(&mut self, request: RequestInfo) {
+        self.inner.borrow_mut().deferred_requests.push_back(request);
+    }
+    #[inline]
+    fn take_deferred_request(&mut self) -> Option<RequestInfo> {
+        self.inner.borrow_mut().deferred_requests.pop_front()
+    }
+    #[inline]
+    fn restore_deferred_request(&mut self, request: RequestInfo) {
+        self.inner
+            .borrow_mut()
+            .deferred_requests
+            .push_front(request);
+    }
+    #[inline]
+    fn has_deferred_requests(&self) -> bool {
+        !self.inner.borrow().deferred_requests.is_empty()
     }
     #[inline]
     fn wm_protocols_atom(&self) -> Option<NonZeroU32> {
@@ -526,6 +883,150 @@ impl<'a, Conn> DisplayBase for &'a CellDisplay<Conn> {
     fn set_wm_protocols_atom(&mut self, a: NonZeroU32) {
         self.wm_protocols_atom.set(Some(a));
     }
+    #[inline]
+    fn xembed_atom(&self) -> Option<NonZeroU32> {
+        self.xembed_atom.get()
+    }
+    #[inline]
+    fn set_xembed_atom(&mut self, a: NonZeroU32) {
+        self.xembed_atom.set(Some(a));
+    }
+    #[inline]
+    fn xembed_info_atom(&self) -> Option<NonZeroU32> {
+        self.xembed_info_atom.get()
+    }
+    #[inline]
+    fn set_xembed_info_atom(&mut self, a: NonZeroU32) {
+        self.xembed_info_atom.set(Some(a));
+    }
+    #[inline]
+    fn last_event_time(&self) -> ServerTime {
+        self.last_event_time.get()
+    }
+    #[inline]
+    fn set_last_event_time(&mut self, time: ServerTime) {
+        self.last_event_time.set(time);
+    }
+    #[inline]
+    fn keymap_generation(&self) -> u32 {
+        self.keymap_generation.get()
+    }
+    #[inline]
+    fn set_keymap_generation(&mut self, generation: u32) {
+        self.keymap_generation.set(generation);
+    }
+
+    #[inline]
+    fn ge_negotiated(&self) -> bool {
+        self.ge_negotiated.get()
+    }
+
+    #[inline]
+    fn set_ge_negotiated(&mut self) {
+        self.ge_negotiated.set(true);
+    }
+
+    #[inline]
+    fn max_pending(&self) -> usize {
+        self.max_pending.get()
+    }
+
+    #[inline]
+    fn set_max_pending(&mut self, max: usize) {
+        self.max_pending.set(max);
+    }
+
+    #[inline]
+    fn awaited_reply_count(&self) -> usize {
+        self.inner
+            .borrow()
+            .pending_requests
+            .values()
+            .filter(|pereq| !pereq.flags.checked)
+            .count()
+    }
+
+    #[inline]
+    fn unread_reply_count(&self) -> usize {
+        self.inner.borrow().pending_replies.len()
+    }
+
+    #[inline]
+    fn reserve_pending(&mut self, additional: usize) {
+        self.inner.borrow_mut().reserve_pending(additional);
+    }
+
+    #[inline]
+    fn root_size(&self, screen: usize) -> Option<(u16, u16)> {
+        self.inner.borrow().root_sizes.get(screen).copied()
+    }
+
+    #[inline]
+    fn set_root_size(&mut self, screen: usize, width: u16, height: u16) {
+        if let Some(size) = self.inner.borrow_mut().root_sizes.get_mut(screen) {
+            *size = (width, height);
+        }
+    }
+
+    #[inline]
+    fn tracking_resources(&self) -> bool {
+        self.inner.borrow().resources.enabled()
+    }
+
+    #[inline]
+    fn track_resources(&mut self, track: bool) {
+        self.inner.borrow_mut().resources.set_enabled(track);
+    }
+
+    #[inline]
+    fn track_resource(&mut self, xid: XID, kind: ResourceKind) {
+        self.inner.borrow_mut().resources.track(xid, kind);
+    }
+
+    #[inline]
+    fn untrack_resource(&mut self, xid: XID) {
+        self.inner.borrow_mut().resources.untrack(xid);
+    }
+
+    #[inline]
+    fn add_orphan_reply(&mut self, sequence: u16, reply: &[u8]) {
+        self.inner.borrow_mut().orphan_replies.add(sequence, reply);
+    }
+
+    #[inline]
+    fn orphan_reply_count(&self) -> usize {
+        self.inner.borrow().orphan_replies.count()
+    }
+
+    #[inline]
+    fn on_orphan_reply(&mut self, hook: Option<OrphanReplyHook>) {
+        self.inner.borrow_mut().orphan_replies.set_hook(hook);
+    }
+
+    #[inline]
+    fn register_event_decoder(
+        &mut self,
+        extension: &'static str,
+        event_count: u8,
+        decoder: EventDecoder,
+    ) {
+        self.inner
+            .borrow_mut()
+            .event_routes
+            .register(extension, event_count, decoder);
+    }
+
+    #[inline]
+    fn event_decoder(&self, code: u8) -> Option<(u8, EventDecoder)> {
+        let inner = self.inner.borrow();
+        inner
+            .event_routes
+            .find(code, |key| inner.extension_first_events.get(key).copied())
+    }
+    #[inline]
+    fn has_event_decoder(&self, extension: &str) -> bool {
+        self.inner.borrow().event_routes.contains(extension)
+    }
 }
 
 impl<'a, Connect> Display for &'a CellDisplay<Connect>
@@ -572,6 +1073,7 @@ where
             .poll_wait(
                 &mut self.connection.as_ref().unwrap(),
                 &data.workarounders,
+                |seq| data.pending_requests.get(&seq).copied(),
                 ctx,
             ) {
             Poll::Pending => return Poll::Pending,
@@ -616,3 +1118,52 @@ where
         }
     }
 }
+
+#[cfg(all(test, feature = "fixes"))]
+mod tests {
+    use super::CellDisplay;
+    use crate::display::{mock_display, mock_setup, DisplayBase, DisplayExt, MockConnection};
+
+    #[test]
+    fn held_back_requests_are_sent_before_closing() {
+        use crate::auto::{
+            xfixes::HideCursorRequest,
+            xproto::{QueryExtensionReply, Window},
+        };
+        use alloc::vec::Vec;
+
+        let mut expected = Vec::from(&[140, 29][..]);
+        expected.extend_from_slice(&2u16.to_ne_bytes());
+        expected.extend_from_slice(&1u32.to_ne_bytes());
+
+        for close in [false, true].iter().copied() {
+            let mut conn = MockConnection::with_setup(&mock_setup());
+            conn.feed_reply(&QueryExtensionReply {
+                reply_type: 1,
+                sequence: 2,
+                present: true,
+                major_opcode: 140,
+                ..Default::default()
+            });
+
+            let mut display = mock_display(&mut conn);
+            display.set_checked(false);
+            display
+                .send_request(HideCursorRequest {
+                    window: Window::const_from_xid(1),
+                    ..Default::default()
+                })
+                .unwrap();
+
+            // the held back request moves over with the rest of the display's state
+            let display = CellDisplay::from(display);
+            assert!(display.has_deferred_requests());
+            if close {
+                display.close().unwrap();
+            } else {
+                drop(display);
+            }
+            assert!(conn.outgoing().ends_with(&expected));
+        }
+    }
+}
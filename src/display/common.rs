@@ -3,14 +3,17 @@
 //! Common async implementation functionality between our connection types.
 
 use super::{
-    decode_reply, input, output, AsyncConnection, AsyncDisplay, PendingReply, PendingRequest,
-    RequestInfo, RequestWorkaround,
+    decode_reply, input, output, AsyncConnection, AsyncDisplay, ExtensionPresence, PendingReply,
+    PendingRequest, RequestInfo, RequestWorkaround,
 };
 use crate::{
     auto::xproto::{QueryExtensionReply, QueryExtensionRequest},
     log_debug, log_trace, Fd,
 };
 use alloc::{string::String, vec, vec::Vec};
+
+#[cfg(feature = "ge")]
+use alloc::boxed::Box;
 use core::{
     iter, mem,
     task::{Context, Poll},
@@ -27,6 +30,9 @@ pub(crate) struct WaitBuffer {
     /// Whether or not we are on the initial 32 bytes per object sent from the server, or if we are
     /// looking for additional bytes.
     first_read: bool,
+    /// The number of file descriptors we are still waiting on after all of the bytes have been read.
+    /// These can arrive separately from the data they belong to.
+    expected_fds: Option<usize>,
     /// Defines the portion of the buffer we need to pass to the connection.
     cursor: usize,
     /// Since this is essentially a pseudo-future, this allows us to panic if we're polled past
@@ -41,6 +47,7 @@ impl Default for WaitBuffer {
             buffer: iter::repeat(0).take(32).collect(),
             fds: vec![],
             first_read: true,
+            expected_fds: None,
             cursor: 0,
             complete: false,
         }
@@ -64,10 +71,14 @@ impl WaitBuffer {
 
     /// Poll a connection with this `WaitBuffer`, possibly returning a result.
     #[inline]
-    pub(crate) fn poll_wait<C: AsyncConnection + Unpin + ?Sized>(
+    pub(crate) fn poll_wait<
+        C: AsyncConnection + Unpin + ?Sized,
+        F: Fn(u16) -> Option<PendingRequest>,
+    >(
         &mut self,
         conn: &mut C,
         workarounders: &[u16],
+        pending_request: F,
         cx: &mut Context<'_>,
     ) -> Poll<crate::Result<WaitBufferReturn>> {
         log_trace!("Entering poll_wait for WaitBuffer");
@@ -78,6 +89,37 @@ impl WaitBuffer {
         }
 
         loop {
+            // if we've read all of the bytes, we may still be waiting for the file descriptors
+            if let Some(expected) = self.expected_fds {
+                let received = self.fds.len();
+                let mut bytes_read = 0;
+                match conn.poll_read_packet(&mut [], &mut self.fds, cx, &mut bytes_read) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Err(e)) => {
+                        self.complete();
+                        return Poll::Ready(Err(e));
+                    }
+                    Poll::Ready(Ok(())) => {}
+                }
+
+                if self.fds.len() < expected {
+                    if self.fds.len() > received {
+                        continue;
+                    }
+
+                    log::warn!(
+                        "Reply should carry {} file descriptors, but only {} were received",
+                        expected,
+                        received
+                    );
+                }
+
+                let buf = mem::take(&mut self.buffer);
+                let fds = mem::take(&mut self.fds);
+                self.complete();
+                return Poll::Ready(Ok(WaitBufferReturn { data: buf, fds }));
+            }
+
             // read into the buffer as much as we can
             log_debug!("Running poll_read_packet()...");
             let res = conn.poll_read_packet(
@@ -112,6 +154,10 @@ impl WaitBuffer {
                 // fix the GLX bug
                 let mut buf = mem::take(&mut self.buffer);
                 input::fix_glx_workaround(|seq| workarounders.contains(&seq), &mut buf);
+                if let Err(e) = input::check_packet_header(&buf) {
+                    self.complete();
+                    return Poll::Ready(Err(e));
+                }
 
                 // check if we need additional bytes
                 if let Some(ab) = input::additional_bytes(&buf[..8]) {
@@ -125,6 +171,14 @@ impl WaitBuffer {
                 mem::take(&mut self.buffer)
             };
 
+            // make sure we have every file descriptor that goes with the reply
+            let expected = input::expected_fds(&buf, &pending_request);
+            if self.fds.len() < expected {
+                self.buffer = buf;
+                self.expected_fds = Some(expected);
+                continue;
+            }
+
             // process the bytes/fds and return
             let fds = mem::take(&mut self.fds);
             self.complete();
@@ -142,6 +196,9 @@ pub(crate) enum SendBuffer {
     Init(InnerSendBuffer),
     PollingForExt(RequestInfo, InnerSendBuffer),
     WaitingForExt(RequestInfo, u16, Option<WaitBuffer>),
+    Draining(RequestInfo, Option<WaitBuffer>),
+    #[cfg(feature = "ge")]
+    Negotiating(RequestInfo, Box<SendBuffer>),
 }
 
 impl Default for SendBuffer {
@@ -160,9 +217,14 @@ impl SendBuffer {
             | SendBuffer::Uninit(..)
             | SendBuffer::PollingForExt(..)
             | SendBuffer::WaitingForExt(..)
+            | SendBuffer::Draining(..)
             | SendBuffer::OccupiedHole => {
                 panic!("Attempted to call begin_send_request_raw before the other request is finished sending")
             }
+            #[cfg(feature = "ge")]
+            SendBuffer::Negotiating(..) => {
+                panic!("Attempted to call begin_send_request_raw before the other request is finished sending")
+            }
             this => {
                 *this = SendBuffer::Uninit(request_info);
             }
@@ -228,19 +290,19 @@ impl SendBuffer {
                                     return Poll::Ready(Err(e));
                                 }
                             };
-                            // check to ensure our opcode is actually present
-                            if !qer.present {
+                            // insert the opcode and first event into the display, and check to
+                            // ensure our opcode is actually present
+                            let extension = req.extension.unwrap();
+                            if !output::cache_extension_query(
+                                display,
+                                output::str_to_key(extension),
+                                &qer,
+                            ) {
                                 self.dig_hole();
-                                return Poll::Ready(Err(crate::BreadError::ExtensionNotPresent(
-                                    req.extension.unwrap().into(),
+                                return Poll::Ready(Err(output::extension_not_present(
+                                    &req, extension,
                                 )));
                             }
-                            // insert the opcode into the display
-                            display.set_extension_opcode(
-                                output::str_to_key(req.extension.unwrap()),
-                                qer.major_opcode,
-                            );
-                            // TODO: first_event and first_error are probably important too
                             break (req, Some(qer.major_opcode));
                         }
 
@@ -248,6 +310,7 @@ impl SendBuffer {
                         let res = wait_buffer.get_or_insert_with(Default::default).poll_wait(
                             conn,
                             &[], // we don't have any GLX workarounds here we need to check
+                            |seq| display.get_pending_request(seq),
                             cx,
                         );
 
@@ -274,16 +337,118 @@ impl SendBuffer {
                         }
                     };
                 }
+                // we are sending the Generic Event Extension its version before sending the request
+                #[cfg(feature = "ge")]
+                SendBuffer::Negotiating(req, mut negotiation) => {
+                    match negotiation.poll_send_request(display, conn, cx) {
+                        Poll::Ready(Ok(qvr)) => {
+                            output::finish_request(display, qvr);
+                            display.set_ge_negotiated();
+                            *self = SendBuffer::Uninit(req);
+                        }
+                        // without the extension, there's nothing to negotiate
+                        Poll::Ready(Err(crate::BreadError::ExtensionNotPresent { .. })) => {
+                            display.set_ge_negotiated();
+                            *self = SendBuffer::Uninit(req);
+                        }
+                        Poll::Ready(Err(e)) => {
+                            self.dig_hole();
+                            return Poll::Ready(Err(e));
+                        }
+                        Poll::Pending => {
+                            *self = SendBuffer::Negotiating(req, negotiation);
+                            return Poll::Pending;
+                        }
+                    }
+                }
+                // there are too many outstanding replies, so we are reading from the connection until
+                // some of them arrive
+                SendBuffer::Draining(req, mut wait_buffer) => {
+                    if !output::over_pending_limit(display) {
+                        *self = SendBuffer::Uninit(req);
+                        continue;
+                    }
+
+                    // if none of the replies are still on their way, reading won't make room
+                    if display.awaited_reply_count() == 0 {
+                        self.dig_hole();
+                        return Poll::Ready(Err(crate::BreadError::WouldBlock));
+                    }
+
+                    let res = wait_buffer.get_or_insert_with(Default::default).poll_wait(
+                        conn,
+                        &[],
+                        |seq| display.get_pending_request(seq),
+                        cx,
+                    );
+
+                    match res {
+                        Poll::Pending => {
+                            *self = SendBuffer::Draining(req, wait_buffer);
+                            return Poll::Pending;
+                        }
+                        Poll::Ready(Err(e)) => {
+                            self.dig_hole();
+                            return Poll::Ready(Err(e));
+                        }
+                        Poll::Ready(Ok(WaitBufferReturn { data, fds })) => {
+                            if let Err(e) = input::process_bytes(display, data, fds) {
+                                self.dig_hole();
+                                return Poll::Ready(Err(e));
+                            }
+                            *self = SendBuffer::Draining(req, None);
+                        }
+                    }
+                }
                 // we are not initialized at all
-                SendBuffer::Uninit(req) => {
+                SendBuffer::Uninit(mut req) => {
+                    if let Err(e) = req.check_fd_count() {
+                        self.dig_hole();
+                        return Poll::Ready(Err(e));
+                    }
+
+                    // don't let outstanding replies pile up past the display's limit
+                    if output::over_pending_limit(display) {
+                        *self = SendBuffer::Draining(req, None);
+                        continue;
+                    }
+
+                    // some servers won't send generic events until the client has negotiated the Generic
+                    // Event Extension
+                    #[cfg(feature = "ge")]
+                    if let Some(extension) = req.extension {
+                        if output::ge_negotiation_needed(display, extension) {
+                            let mut negotiation = SendBuffer::Hole;
+                            negotiation.fill_hole(output::ge_negotiation_request(display));
+                            *self = SendBuffer::Negotiating(req, Box::new(negotiation));
+                            continue;
+                        }
+                    }
+
                     match req.extension {
                         None => break (req, None),
                         Some(extension) => {
                             // see if we have it cached
                             let key = output::str_to_key(extension);
-                            match display.get_extension_opcode(&key) {
-                                Some(opcode) => break (req, Some(opcode)),
-                                None => {
+                            // if we've already sent a query for this extension, wait for its reply
+                            let presence = display.get_extension_presence(&key);
+                            if presence == ExtensionPresence::Unknown {
+                                if let Some(req_id) = display.take_pending_extension_query(&key) {
+                                    *self = SendBuffer::WaitingForExt(req, req_id, None);
+                                    continue;
+                                }
+                            }
+
+                            match presence {
+                                ExtensionPresence::Present(opcode) => break (req, Some(opcode)),
+                                // we already know the server doesn't have it
+                                ExtensionPresence::Absent => {
+                                    self.dig_hole();
+                                    return Poll::Ready(Err(output::extension_not_present(
+                                        &req, extension,
+                                    )));
+                                }
+                                ExtensionPresence::Unknown => {
                                     // looks like we have to poll for it
                                     *self = SendBuffer::PollingForExt(
                                         req,
@@ -440,6 +605,7 @@ impl InnerSendBuffer {
             // bubble up pending and error, making sure to complete on error
             Poll::Pending => return Poll::Pending,
             Poll::Ready(Err(e)) => {
+                log::error!("Failed to send {} request: {}", self.request.describe(), e);
                 return Poll::Ready(Err(e));
             }
             Poll::Ready(Ok(())) => {}
@@ -1,6 +1,6 @@
 // MIT/Apache2 License
 
-use super::{create_setup, AsyncConnection};
+use super::{accept_setup, create_setup, AsyncConnection};
 use crate::{
     auth_info::AuthInfo,
     auto::{xproto::Setup, AsByteSequence},
@@ -180,11 +180,7 @@ impl<'a, C: AsyncConnection + Unpin + ?Sized> Future for EstablishConnectionFutu
                                         )))
                                     }
                                 };
-                                let xid = XidGenerator::new(
-                                    setup.resource_id_base,
-                                    setup.resource_id_mask,
-                                );
-                                return Poll::Ready(Ok((setup, xid)));
+                                return Poll::Ready(accept_setup(setup));
                             }
                         }
                     }
@@ -14,6 +14,9 @@ use alloc::vec::Vec;
 use core::iter;
 use tinyvec::TinyVec;
 
+/// The byte order declared to the server. This is always the machine's own: requests, replies and events
+/// are encoded in native order everywhere, in the generated code and in the packet handling alike, so
+/// declaring the other order would have the server misread every multi-byte value.
 #[inline]
 const fn endian_byte() -> u8 {
     // Excerpt from the X Window System Protocol
@@ -81,6 +84,61 @@ pub(crate) fn establish_connection<C: Connection + ?Sized>(
 
     let (setup, _) =
         Setup::from_bytes(&bytes).ok_or(crate::BreadError::BadObjectRead(Some("Setup")))?;
+    accept_setup(setup)
+}
+
+/// Make sure the server speaks the version of the protocol we do, and set up XID generation.
+#[inline]
+pub(crate) fn accept_setup(setup: Setup) -> crate::Result<(Setup, XidGenerator)> {
+    if setup.protocol_major_version != 11 {
+        return Err(crate::BreadError::UnsupportedProtocolVersion {
+            major: setup.protocol_major_version,
+            minor: setup.protocol_minor_version,
+        });
+    }
+
     let xid = XidGenerator::new(setup.resource_id_base, setup.resource_id_mask);
     Ok((setup, xid))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::create_setup;
+    use crate::{auth_info::AuthInfo, auto::AsByteSequence};
+    use alloc::{string::ToString, vec};
+
+    #[test]
+    fn setup_without_auth() {
+        let setup = create_setup(AuthInfo::default());
+        let mut bytes = vec![0; setup.size()];
+        let len = setup.as_bytes(&mut bytes);
+
+        // both lengths are zero, and there's no padding after the empty name and data
+        let major = 11u16.to_ne_bytes();
+        assert_eq!(len, 12);
+        assert_eq!(
+            &bytes[..len],
+            &[bytes[0], 0, major[0], major[1], 0, 0, 0, 0, 0, 0, 0, 0][..]
+        );
+        assert!(bytes[0] == b'l' || bytes[0] == b'B');
+    }
+
+    #[test]
+    fn setup_with_auth_is_padded() {
+        let setup = create_setup(AuthInfo {
+            name: "MIT-MAGIC-COOKIE-1".to_string(),
+            data: vec![0xAB; 16],
+            ..Default::default()
+        });
+        let mut bytes = vec![0; setup.size()];
+        let len = setup.as_bytes(&mut bytes);
+
+        // the 18 byte name is padded to 20 bytes
+        assert_eq!(len, 12 + 20 + 16);
+        assert_eq!(&bytes[6..8], &18u16.to_ne_bytes());
+        assert_eq!(&bytes[8..10], &16u16.to_ne_bytes());
+        assert_eq!(&bytes[12..30], b"MIT-MAGIC-COOKIE-1");
+        assert_eq!(&bytes[30..32], &[0, 0]);
+        assert_eq!(&bytes[32..48], &[0xAB; 16][..]);
+    }
+}
@@ -0,0 +1,294 @@
+// MIT/Apache2 License
+
+use super::Connection;
+#[cfg(test)]
+use crate::{auth_info::AuthInfo, display::BasicDisplay};
+use crate::{
+    auto::{
+        xproto::{QueryExtensionReply, Setup},
+        AsByteSequence,
+    },
+    Fd,
+};
+use alloc::{collections::VecDeque, vec::Vec};
+use core::iter;
+
+#[cfg(feature = "async")]
+use super::AsyncConnection;
+#[cfg(feature = "async")]
+use core::task::{Context, Poll};
+
+/// A connection that reads from and writes to in-memory byte queues, rather than a real X server. This
+/// is useful for testing code that builds requests or reacts to replies without needing an X server.
+///
+/// Bytes queued with `feed` and friends are handed out to the display as it reads, and every byte the
+/// display writes is captured and can be inspected with `outgoing` or `take_outgoing`. Reading more bytes
+/// than are queued results in a `ClosedConnection` error. File descriptors queued with `feed_fds` are handed
+/// out once every byte queued before them has been read, and file descriptors that are sent are captured
+/// and can be inspected with `take_outgoing_fds`.
+///
+/// Since `Connection` is implemented for `&mut C`, the easiest way to inspect the mock after a display has
+/// used it is to lend the display a mutable reference:
+///
+/// ```rust,no_run
+/// use breadx::{auto::xproto::Setup, AuthInfo, BasicDisplay, MockConnection};
+///
+/// let setup = Setup {
+///     protocol_major_version: 11,
+///     maximum_request_length: u16::MAX,
+///     ..Default::default()
+/// };
+/// let mut conn = MockConnection::with_setup(&setup);
+/// let display = BasicDisplay::from_connection(&mut conn, 0, Some(AuthInfo::default())).unwrap();
+/// // ... use the display ...
+/// drop(display);
+/// let written = conn.take_outgoing();
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct MockConnection {
+    incoming: VecDeque<u8>,
+    outgoing: Vec<u8>,
+    // file descriptors that were sent, along with where the packet carrying them starts in "outgoing"
+    outgoing_fds: Vec<(usize, Vec<Fd>)>,
+    // file descriptors, along with the number of bytes that need to be read before they are handed out
+    incoming_fds: VecDeque<(usize, Vec<Fd>)>,
+    // the number of bytes read so far
+    read_len: usize,
+}
+
+impl MockConnection {
+    /// Create a new mock connection with nothing queued.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new mock connection with the replies needed to establish a display already queued. This
+    /// is a successful setup reply built from `setup`, followed by a reply saying that the `BIG-REQUESTS`
+    /// extension is not present.
+    #[inline]
+    #[must_use]
+    pub fn with_setup(setup: &Setup) -> Self {
+        let mut this = Self::new();
+        this.feed_setup(setup);
+        this.feed_reply(&QueryExtensionReply {
+            reply_type: 1,
+            sequence: 1,
+            ..Default::default()
+        });
+        this
+    }
+
+    /// Queue bytes to be read from the connection.
+    #[inline]
+    pub fn feed(&mut self, bytes: &[u8]) {
+        self.incoming.extend(bytes);
+    }
+
+    /// Queue file descriptors to be read from the connection. They are handed out by the first read that
+    /// begins after all of the currently queued bytes, like an ancillary message sent on its own.
+    #[inline]
+    pub fn feed_fds(&mut self, fds: Vec<Fd>) {
+        self.incoming_fds
+            .push_back((self.read_len + self.incoming.len(), fds));
+    }
+
+    /// Queue a successful setup reply built from `setup`. The status and length fields are filled in
+    /// automatically.
+    #[inline]
+    pub fn feed_setup(&mut self, setup: &Setup) {
+        let mut setup = setup.clone();
+        setup.status = 1;
+        setup.length = ((setup.size() - 8) / 4) as u16;
+        self.feed_object(&setup, 0);
+    }
+
+    /// Queue a reply, event or error. It is padded to the 32 bytes the X server always sends at minimum.
+    #[inline]
+    pub fn feed_reply<T: AsByteSequence>(&mut self, reply: &T) {
+        self.feed_object(reply, 32);
+    }
+
+    #[inline]
+    fn feed_object<T: AsByteSequence>(&mut self, object: &T, min_len: usize) {
+        let mut bytes: Vec<u8> = iter::repeat(0).take(object.size().max(min_len)).collect();
+        object.as_bytes(&mut bytes);
+        self.feed(&bytes);
+    }
+
+    /// The number of queued bytes that have not been read yet.
+    #[inline]
+    #[must_use]
+    pub fn incoming_len(&self) -> usize {
+        self.incoming.len()
+    }
+
+    /// All of the bytes that have been written to the connection so far.
+    #[inline]
+    #[must_use]
+    pub fn outgoing(&self) -> &[u8] {
+        &self.outgoing
+    }
+
+    /// Take the bytes that have been written to the connection so far, clearing the buffer.
+    #[inline]
+    pub fn take_outgoing(&mut self) -> Vec<u8> {
+        core::mem::take(&mut self.outgoing)
+    }
+
+    /// Take the file descriptors that have been sent so far, clearing the buffer. Each batch is paired with
+    /// the position in `outgoing` where the packet that carried it starts.
+    #[inline]
+    pub fn take_outgoing_fds(&mut self) -> Vec<(usize, Vec<Fd>)> {
+        core::mem::take(&mut self.outgoing_fds)
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8], fds: &mut Vec<Fd>) {
+        if !fds.is_empty() {
+            self.outgoing_fds
+                .push((self.outgoing.len(), core::mem::take(fds)));
+        }
+
+        self.outgoing.extend_from_slice(bytes);
+    }
+
+    #[inline]
+    fn read(&mut self, bytes: &mut [u8], fds: &mut Vec<Fd>) -> crate::Result {
+        if self.incoming.len() < bytes.len() {
+            return Err(crate::BreadError::ClosedConnection);
+        }
+
+        while matches!(self.incoming_fds.front(), Some((at, _)) if *at <= self.read_len) {
+            fds.extend(self.incoming_fds.pop_front().unwrap().1);
+        }
+
+        let len = bytes.len();
+        bytes
+            .iter_mut()
+            .zip(self.incoming.drain(..len))
+            .for_each(|(dest, src)| *dest = src);
+        self.read_len += len;
+        Ok(())
+    }
+}
+
+impl Connection for MockConnection {
+    #[inline]
+    fn send_packet(&mut self, bytes: &[u8], fds: &mut Vec<Fd>) -> crate::Result {
+        self.write(bytes, fds);
+        Ok(())
+    }
+
+    #[inline]
+    fn read_packet(&mut self, bytes: &mut [u8], fds: &mut Vec<Fd>) -> crate::Result {
+        self.read(bytes, fds)
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncConnection for MockConnection {
+    #[inline]
+    fn poll_send_packet(
+        &mut self,
+        bytes: &[u8],
+        fds: &mut Vec<Fd>,
+        _cx: &mut Context<'_>,
+        bytes_written: &mut usize,
+    ) -> Poll<crate::Result> {
+        self.write(bytes, fds);
+        *bytes_written += bytes.len();
+        Poll::Ready(Ok(()))
+    }
+
+    #[inline]
+    fn poll_read_packet(
+        &mut self,
+        bytes: &mut [u8],
+        fds: &mut Vec<Fd>,
+        _cx: &mut Context<'_>,
+        bytes_read: &mut usize,
+    ) -> Poll<crate::Result> {
+        let res = self.read(bytes, fds);
+        if res.is_ok() {
+            *bytes_read += bytes.len();
+        }
+        Poll::Ready(res)
+    }
+}
+
+/// A setup with just enough filled in for a display to accept it, for tests to build on.
+#[cfg(test)]
+pub(crate) fn mock_setup() -> Setup {
+    Setup {
+        protocol_major_version: 11,
+        maximum_request_length: u16::MAX,
+        ..Default::default()
+    }
+}
+
+/// Establish a display over `conn`, which should already have a setup queued.
+#[cfg(test)]
+pub(crate) fn mock_display(conn: &mut MockConnection) -> BasicDisplay<&mut MockConnection> {
+    BasicDisplay::from_connection(conn, 0, Some(AuthInfo::default())).unwrap()
+}
+
+/// Open a pipe, returning its non-blocking read end and its write end. Once every copy of the write end
+/// is closed, reading from the read end reports end-of-file.
+#[cfg(all(test, unix, feature = "std"))]
+pub(crate) fn pipe() -> (Fd, Fd) {
+    use nix::fcntl::{fcntl, FcntlArg, OFlag};
+
+    let (read, write) = nix::unistd::pipe().unwrap();
+    fcntl(read, FcntlArg::F_SETFL(OFlag::O_NONBLOCK)).unwrap();
+    (read, write)
+}
+
+/// Tell whether the write end of the pipe that `read` belongs to has been closed, then close `read`.
+#[cfg(all(test, unix, feature = "std"))]
+pub(crate) fn write_end_closed(read: Fd) -> bool {
+    let closed = matches!(nix::unistd::read(read, &mut [0]), Ok(0));
+    nix::unistd::close(read).unwrap();
+    closed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{mock_display, mock_setup, MockConnection};
+    use crate::{
+        auto::xproto::BellRequest,
+        display::{DisplayBase, DisplayExt},
+    };
+
+    #[test]
+    fn establish_and_send() {
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        let mut display = mock_display(&mut conn);
+        display.set_checked(false);
+        display
+            .send_request(BellRequest {
+                percent: 50,
+                ..Default::default()
+            })
+            .unwrap();
+        drop(display);
+
+        let written = conn.take_outgoing();
+        // the setup request, then the query for BIG-REQUESTS, then our bell request
+        assert_eq!(
+            written[0],
+            if cfg!(target_endian = "little") {
+                b'l'
+            } else {
+                b'B'
+            }
+        );
+        let len = 1u16.to_ne_bytes();
+        assert_eq!(
+            &written[written.len() - 4..],
+            &[104, 50, len[0], len[1]][..]
+        );
+        assert_eq!(conn.incoming_len(), 0);
+    }
+}
@@ -5,7 +5,10 @@ pub(crate) mod unix;
 
 #[cfg(feature = "async")]
 mod async_connection;
+mod mock;
 mod sync;
+#[cfg(all(feature = "tokio-support", unix))]
+mod tokio_connection;
 
 #[cfg(feature = "async")]
 mod async_establish;
@@ -14,12 +17,17 @@ mod establish;
 #[cfg(feature = "async")]
 pub use async_establish::EstablishConnectionFuture;
 #[cfg(feature = "async")]
+pub(crate) use establish::accept_setup;
+#[cfg(feature = "async")]
 pub(crate) use establish::create_setup;
 pub(crate) use establish::establish_connection;
 
 #[cfg(feature = "async")]
 pub use async_connection::*;
+pub use mock::*;
 pub use sync::*;
+#[cfg(all(feature = "tokio-support", unix))]
+pub use tokio_connection::TokioConnection;
 
 #[cfg(not(unix))]
 use alloc::vec::Vec;
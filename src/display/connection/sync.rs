@@ -14,7 +14,7 @@ use super::standard_fd_warning;
 use std::io::{Read, Write};
 
 #[cfg(feature = "std")]
-use std::net::TcpStream;
+use std::net::{Shutdown, TcpStream};
 #[cfg(all(feature = "std", unix))]
 use std::os::unix::{io::AsRawFd, net::UnixStream};
 
@@ -24,6 +24,12 @@ pub trait Connection {
     fn send_packet(&mut self, bytes: &[u8], fds: &mut Vec<Fd>) -> crate::Result;
     /// Read a packet from the connection in a blocking manner.
     fn read_packet(&mut self, bytes: &mut [u8], fds: &mut Vec<Fd>) -> crate::Result;
+    /// Shut down the connection, in a blocking manner. The default implementation does nothing, and leaves
+    /// closing the connection up to its destructor.
+    #[inline]
+    fn shutdown(&mut self) -> crate::Result {
+        Ok(())
+    }
     /// Establish a setup using this connection.
     #[inline]
     fn establish(&mut self, auth_info: Option<AuthInfo>) -> crate::Result<(Setup, XidGenerator)> {
@@ -41,6 +47,11 @@ impl<C: Connection + ?Sized> Connection for &mut C {
     fn read_packet(&mut self, bytes: &mut [u8], fds: &mut Vec<Fd>) -> crate::Result {
         (**self).read_packet(bytes, fds)
     }
+
+    #[inline]
+    fn shutdown(&mut self) -> crate::Result {
+        (**self).shutdown()
+    }
 }
 
 // Implement Connection on TcpStream and UnixStream
@@ -77,6 +88,14 @@ macro_rules! unix_aware_connection_impl {
                     }
                 }
             }
+
+            #[inline]
+            fn shutdown(&mut self) -> crate::Result {
+                // reborrow immutably so we call the stream's own shutdown() instead of this one
+                let stream: &$name = self;
+                stream.shutdown(Shutdown::Both)?;
+                Ok(())
+            }
         }
     };
 }
@@ -0,0 +1,178 @@
+// MIT/Apache2 License
+
+//! An `AsyncConnection` that is driven by `tokio`'s reactor rather than `async-io`'s.
+
+#![cfg(all(feature = "tokio-support", unix))]
+
+use super::{unix, AsyncConnection};
+use crate::{auth_info::AuthTarget, display::name::NameConnection, util::convert_nix_error, Fd};
+use alloc::{borrow::Cow, vec::Vec};
+use core::task::{Context, Poll};
+use nix::fcntl::{fcntl, FcntlArg, OFlag};
+use std::{io, os::unix::io::AsRawFd};
+use tokio::io::unix::AsyncFd;
+
+/// A connection to the X server that registers its socket with `tokio`'s reactor. Packets are sent and
+/// received with `sendmsg` and `recvmsg`, so file descriptors can be passed over Unix sockets.
+///
+/// Using `AsyncNameConnection` inside of a `tokio` runtime works, but it spins up `async-io`'s reactor thread
+/// alongside `tokio`'s. This type avoids that.
+#[derive(Debug)]
+pub struct TokioConnection<S: AsRawFd = NameConnection> {
+    inner: AsyncFd<S>,
+}
+
+impl<S: AsRawFd> TokioConnection<S> {
+    /// Wrap around a connected socket, putting it into non-blocking mode.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if it is not called from within a `tokio` runtime.
+    #[inline]
+    pub fn new(stream: S) -> crate::Result<Self> {
+        let fd = stream.as_raw_fd();
+        let flags = fcntl(fd, FcntlArg::F_GETFL).map_err(convert_nix_error)?;
+        let flags = OFlag::from_bits_truncate(flags) | OFlag::O_NONBLOCK;
+        fcntl(fd, FcntlArg::F_SETFL(flags)).map_err(convert_nix_error)?;
+
+        Ok(Self {
+            inner: AsyncFd::new(stream)?,
+        })
+    }
+
+    /// Get a reference to the underlying socket.
+    #[inline]
+    pub fn get_ref(&self) -> &S {
+        self.inner.get_ref()
+    }
+
+    /// Deregister the socket from the reactor and return it. It is left in non-blocking mode.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.inner.into_inner()
+    }
+}
+
+impl TokioConnection {
+    /// Open a new connection, resolving the host name and connecting to the socket without blocking the
+    /// runtime.
+    #[inline]
+    pub(crate) async fn connect_internal(
+        name: Option<Cow<'_, str>>,
+    ) -> crate::Result<(TokioConnection, usize, AuthTarget)> {
+        let (connection, screen, target) = NameConnection::connect_internal_tokio(name).await?;
+        Ok((Self::new(connection)?, screen, target))
+    }
+}
+
+impl<S: AsRawFd> AsyncConnection for TokioConnection<S> {
+    #[inline]
+    fn poll_send_packet(
+        &mut self,
+        bytes: &[u8],
+        fds: &mut Vec<Fd>,
+        cx: &mut Context<'_>,
+        bytes_written: &mut usize,
+    ) -> Poll<crate::Result> {
+        let fd = self.inner.as_raw_fd();
+        let mut bytes = bytes;
+
+        loop {
+            let mut guard = match self.inner.poll_write_ready(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Ok(guard)) => guard,
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e.into())),
+            };
+
+            let (offset, res) = unix::send_msg_packet(fd, bytes, fds);
+            bytes = &bytes[offset..];
+            *bytes_written += offset;
+
+            match res {
+                Ok(()) => return Poll::Ready(Ok(())),
+                // tokio still thinks the socket is writable; tell it otherwise so we get woken up properly
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => guard.clear_ready(),
+                Err(e) => return Poll::Ready(Err(e.into())),
+            }
+        }
+    }
+
+    #[inline]
+    fn poll_read_packet(
+        &mut self,
+        bytes: &mut [u8],
+        fds: &mut Vec<Fd>,
+        cx: &mut Context<'_>,
+        bytes_read: &mut usize,
+    ) -> Poll<crate::Result> {
+        let fd = self.inner.as_raw_fd();
+        let start = *bytes_read;
+
+        loop {
+            let mut guard = match self.inner.poll_read_ready(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Ok(guard)) => guard,
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e.into())),
+            };
+
+            // skip over anything we read before the last WouldBlock
+            let offset = *bytes_read - start;
+            match unix::read_msg_packet(fd, &mut bytes[offset..], fds, bytes_read) {
+                Ok(()) => return Poll::Ready(Ok(())),
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => guard.clear_ready(),
+                Err(e) => return Poll::Ready(Err(e.into())),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TokioConnection;
+    use crate::display::connection::AsyncConnection;
+    use alloc::{vec, vec::Vec};
+    use futures_lite::future;
+    use std::{io::Write, os::unix::net::UnixStream, thread, time::Duration};
+
+    #[test]
+    fn tokio_connection_reads_across_wakeups() {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .build()
+            .unwrap();
+
+        rt.block_on(async {
+            let (ours, mut theirs) = UnixStream::pair().unwrap();
+            let mut conn = TokioConnection::new(ours).unwrap();
+
+            // the second half shows up after we've already hit WouldBlock
+            let writer = thread::spawn(move || {
+                theirs.write_all(&[1, 2, 3, 4]).unwrap();
+                thread::sleep(Duration::from_millis(50));
+                theirs.write_all(&[5, 6, 7, 8]).unwrap();
+                theirs
+            });
+
+            let mut buffer = [0u8; 8];
+            let mut fds: Vec<crate::Fd> = vec![];
+            let mut bytes_read = 0;
+            // like WaitBuffer, only hand over the part of the buffer that hasn't been filled yet
+            future::poll_fn(|cx| {
+                let cursor = bytes_read;
+                conn.poll_read_packet(&mut buffer[cursor..], &mut fds, cx, &mut bytes_read)
+            })
+            .await
+            .unwrap();
+            assert_eq!(bytes_read, 8);
+            assert_eq!(buffer, [1, 2, 3, 4, 5, 6, 7, 8]);
+
+            let mut bytes_written = 0;
+            future::poll_fn(|cx| conn.poll_send_packet(&[9, 10], &mut fds, cx, &mut bytes_written))
+                .await
+                .unwrap();
+            assert_eq!(bytes_written, 2);
+
+            writer.join().unwrap();
+        });
+    }
+}
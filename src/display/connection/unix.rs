@@ -6,7 +6,7 @@
 use crate::{log_debug, log_trace, util::convert_nix_error, Fd};
 use alloc::{vec, vec::Vec};
 use nix::sys::{
-    socket::{recvmsg, sendmsg, ControlMessage, ControlMessageOwned, MsgFlags},
+    socket::{recvmsg, sendmsg, ControlMessage, ControlMessageOwned, MsgFlags, RecvMsg},
     uio::IoVec,
 };
 use std::{io, os::unix::io::RawFd};
@@ -22,7 +22,11 @@ use std::{
 };
 
 #[inline]
-fn send_msg_packet(conn: RawFd, data: &[u8], fds: &mut Vec<Fd>) -> (usize, io::Result<()>) {
+pub(crate) fn send_msg_packet(
+    conn: RawFd,
+    data: &[u8],
+    fds: &mut Vec<Fd>,
+) -> (usize, io::Result<()>) {
     #[inline]
     fn sendmsg_loop(
         conn: RawFd,
@@ -122,7 +126,7 @@ pub fn poll_send_packet_unix<Conn: AsRawFd + Write + Unpin>(
 /// Read a packet, unix style. Includes fds.
 #[allow(clippy::similar_names)]
 #[inline]
-fn read_msg_packet(
+pub(crate) fn read_msg_packet(
     conn: RawFd,
     mut data: &mut [u8],
     fds: &mut Vec<Fd>,
@@ -132,20 +136,38 @@ fn read_msg_packet(
 
     log_trace!("Beginning read_msg_packet (*nix implementation of read_packet())");
 
+    let mut cmsg = nix::cmsg_space!([Fd; MAX_FDS]);
+
+    // with no data to read, this is only a check for file descriptors that arrived after the data they go
+    // with; they're attached to the next chunk in the socket, which a zero-length read collects without
+    // consuming any of its data. it doesn't wait, since there may not be a next chunk yet
     if data.is_empty() {
-        return Ok(());
+        let datavec = [IoVec::from_mut_slice(data)];
+        loop {
+            match recvmsg(conn, &datavec, Some(&mut cmsg), MsgFlags::MSG_DONTWAIT) {
+                Ok(m) => {
+                    fds.extend(scm_rights(&m));
+                    return Ok(());
+                }
+                Err(nix::Error::Sys(nix::errno::Errno::EINTR)) => {
+                    log::warn!("Interrupt occurred during read");
+                }
+                Err(nix::Error::Sys(nix::errno::Errno::EAGAIN)) => return Ok(()),
+                Err(e) => return Err(convert_nix_error(e)),
+            }
+        }
     }
 
-    let mut cmsg = nix::cmsg_space!([Fd; MAX_FDS]);
     let mut datalen = data.len();
     let mut datavec = [IoVec::from_mut_slice(data)];
 
-    let msg = loop {
+    loop {
         log::debug!("Calling recvmsg with a data buffer of length {}", datalen);
         match recvmsg(conn, &datavec, Some(&mut cmsg), MsgFlags::empty()) {
             Ok(m) if m.bytes == 0 => {
-                log_debug!("recvmsg read 0 bytes; we've likely read everything");
-                break m;
+                // we always ask for at least one byte, so this means the server hung up
+                log_debug!("recvmsg read 0 bytes; the connection has been closed");
+                return Err(io::ErrorKind::UnexpectedEof.into());
             }
             Ok(m) if m.bytes == datalen => {
                 log_debug!(
@@ -153,7 +175,8 @@ fn read_msg_packet(
                     m.bytes
                 );
                 *total_read += m.bytes;
-                break m;
+                fds.extend(scm_rights(&m));
+                break;
             }
             Ok(m) => {
                 log_debug!(
@@ -161,6 +184,8 @@ fn read_msg_packet(
                     m.bytes,
                     data.len() - m.bytes
                 );
+                // file descriptors can come attached to any of the chunks we read, so hold on to them
+                fds.extend(scm_rights(&m));
                 let bytes = m.bytes;
                 data = &mut data[bytes..];
                 *total_read += bytes;
@@ -173,14 +198,18 @@ fn read_msg_packet(
             }
             Err(e) => return Err(convert_nix_error(e)),
         }
-    };
+    }
+
+    Ok(())
+}
 
-    fds.extend(msg.cmsgs().flat_map(|cmsg| match cmsg {
+/// Get the file descriptors sent alongside a message.
+#[inline]
+fn scm_rights<'a>(msg: &'a RecvMsg<'_>) -> impl Iterator<Item = Fd> + 'a {
+    msg.cmsgs().flat_map(|cmsg| match cmsg {
         ControlMessageOwned::ScmRights(r) => r,
         _ => vec![],
-    }));
-
-    Ok(())
+    })
 }
 
 /// Read a packet, unix style.
@@ -217,3 +246,43 @@ pub fn poll_read_packet_unix<Conn: AsRawFd + Read + Unpin>(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{read_packet_unix, send_packet_unix};
+    use alloc::vec;
+    use std::os::unix::{io::AsRawFd, net::UnixStream};
+
+    #[test]
+    fn empty_read_collects_late_fds() {
+        let (ours, theirs) = UnixStream::pair().unwrap();
+        let (pipe_read, pipe_write) = nix::unistd::pipe().unwrap();
+
+        // the file descriptor arrives with the chunk after the one it goes with
+        send_packet_unix(theirs.as_raw_fd(), &[1, 2, 3, 4], &mut vec![]).unwrap();
+        send_packet_unix(theirs.as_raw_fd(), &[5, 6, 7, 8], &mut vec![pipe_write]).unwrap();
+        nix::unistd::close(pipe_write).unwrap();
+
+        let mut data = [0; 4];
+        let mut fds = vec![];
+        read_packet_unix(ours.as_raw_fd(), &mut data, &mut fds).unwrap();
+        assert_eq!(data, [1, 2, 3, 4]);
+        assert!(fds.is_empty());
+
+        read_packet_unix(ours.as_raw_fd(), &mut [], &mut fds).unwrap();
+        assert_eq!(fds.len(), 1);
+
+        // nothing was consumed by the empty read
+        read_packet_unix(ours.as_raw_fd(), &mut data, &mut fds).unwrap();
+        assert_eq!(data, [5, 6, 7, 8]);
+        assert_eq!(fds.len(), 1);
+
+        // with nothing left in the socket, an empty read finds nothing and doesn't wait
+        read_packet_unix(ours.as_raw_fd(), &mut [], &mut fds).unwrap();
+        assert_eq!(fds.len(), 1);
+
+        fds.into_iter()
+            .chain(Some(pipe_read))
+            .for_each(|fd| nix::unistd::close(fd).unwrap());
+    }
+}
@@ -2,7 +2,7 @@
 
 use super::ExchangeRequestFuture;
 use crate::{
-    display::{generate_xid, AsyncDisplay},
+    display::{generate_xid, AsyncDisplay, ResourceKind},
     util::take_mut,
     xid::XidType,
     Request,
@@ -82,6 +82,10 @@ where
                             return ExchangeXidFuture::Complete;
                         }
                     };
+                    // the display isn't ours again until the request is done, so track it now
+                    if let Some(kind) = ResourceKind::created_by::<R>() {
+                        display.track_resource(xid, kind);
+                    }
                     let request: R = to_request(U::from_xid(xid));
                     let exchange = ExchangeRequestFuture::run(display, request);
                     ExchangeXidFuture::Exchanging {
@@ -0,0 +1,130 @@
+// MIT/Apache2 License
+
+use super::{ResolveRequestFuture, SendRequestFuture};
+use crate::{
+    auto::xproto::QueryExtensionRequest,
+    display::{output, AsyncDisplay, ExtensionPresence, RequestCookie, EXT_KEY_SIZE},
+    util::take_mut,
+};
+use alloc::string::String;
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_lite::prelude::*;
+
+/// The future returned by `AsyncDisplayExt::has_extension_async`.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you poll or .await them"]
+pub enum HasExtensionFuture<'a, D: ?Sized> {
+    /// The answer was already cached.
+    #[doc(hidden)]
+    Cached(bool),
+    /// We're waiting on a query that was sent ahead of time.
+    #[doc(hidden)]
+    Resolving {
+        rrf: ResolveRequestFuture<'a, D, QueryExtensionRequest>,
+        key: [u8; EXT_KEY_SIZE],
+    },
+    /// We're asking the server.
+    #[doc(hidden)]
+    Sending {
+        srf: SendRequestFuture<'a, D, QueryExtensionRequest>,
+        key: [u8; EXT_KEY_SIZE],
+    },
+    /// We've completed.
+    #[doc(hidden)]
+    Complete,
+    #[doc(hidden)]
+    Hole,
+}
+
+impl<D: ?Sized> Default for HasExtensionFuture<'_, D> {
+    #[inline]
+    fn default() -> Self {
+        Self::Hole
+    }
+}
+impl<D: ?Sized> Unpin for HasExtensionFuture<'_, D> {}
+
+impl<'a, D: AsyncDisplay + ?Sized> HasExtensionFuture<'a, D> {
+    #[inline]
+    pub(crate) fn run(display: &'a mut D, name: &str) -> Self {
+        let key = output::str_to_key(name);
+        match display.get_extension_presence(&key) {
+            ExtensionPresence::Present(_) => Self::Cached(true),
+            ExtensionPresence::Absent => Self::Cached(false),
+            ExtensionPresence::Unknown => match display.take_pending_extension_query(&key) {
+                // if we've already sent a query for this extension, use its reply
+                Some(req_id) => Self::Resolving {
+                    rrf: ResolveRequestFuture::run(display, RequestCookie::from_sequence(req_id)),
+                    key,
+                },
+                None => Self::Sending {
+                    srf: SendRequestFuture::run(
+                        display,
+                        QueryExtensionRequest {
+                            name: String::from(name),
+                            ..Default::default()
+                        },
+                    ),
+                    key,
+                },
+            },
+        }
+    }
+}
+
+impl<D: AsyncDisplay + ?Sized> Future for HasExtensionFuture<'_, D> {
+    type Output = crate::Result<bool>;
+
+    #[inline]
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<crate::Result<bool>> {
+        let mut result = None;
+        loop {
+            take_mut(&mut *self, |this| match this {
+                HasExtensionFuture::Cached(present) => {
+                    result = Some(Poll::Ready(Ok(present)));
+                    HasExtensionFuture::Complete
+                }
+                HasExtensionFuture::Sending { mut srf, key } => match srf.poll(cx) {
+                    Poll::Pending => {
+                        result = Some(Poll::Pending);
+                        HasExtensionFuture::Sending { srf, key }
+                    }
+                    Poll::Ready(Err(e)) => {
+                        result = Some(Poll::Ready(Err(e)));
+                        HasExtensionFuture::Complete
+                    }
+                    Poll::Ready(Ok(tok)) => HasExtensionFuture::Resolving {
+                        rrf: ResolveRequestFuture::run(srf.cannibalize(), tok),
+                        key,
+                    },
+                },
+                HasExtensionFuture::Resolving { mut rrf, key } => match rrf.poll(cx) {
+                    Poll::Pending => {
+                        result = Some(Poll::Pending);
+                        HasExtensionFuture::Resolving { rrf, key }
+                    }
+                    Poll::Ready(repl) => {
+                        let display = rrf.cannibalize();
+                        result =
+                            Some(Poll::Ready(repl.map(|repl| {
+                                output::cache_extension_query(display, key, &repl)
+                            })));
+                        HasExtensionFuture::Complete
+                    }
+                },
+                HasExtensionFuture::Complete => {
+                    panic!("Attempted to poll future past completion")
+                }
+                HasExtensionFuture::Hole => panic!("Cannot poll an empty hole"),
+            });
+
+            if let Some(result) = result.take() {
+                return result;
+            }
+        }
+    }
+}
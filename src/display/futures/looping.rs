@@ -13,12 +13,16 @@ use core::{
 };
 use futures_lite::prelude::*;
 
-/// A way to handle a wait loop.
+/// A way to handle a wait loop. The loop ends once the handler returns `Some`, with either a result or an
+/// error.
 #[doc(hidden)]
 pub trait WaitLoopHandler {
     type Output;
 
-    fn handle<D: AsyncDisplay + ?Sized>(&self, display: &mut &mut D) -> Option<Self::Output>;
+    fn handle<D: AsyncDisplay + ?Sized>(
+        &self,
+        display: &mut &mut D,
+    ) -> Option<crate::Result<Self::Output>>;
 }
 
 /// A future where the end result is to loop until an object is present, by waiting.
@@ -82,7 +86,7 @@ impl<'a, D: AsyncDisplay + ?Sized, Handler: WaitLoopHandler + Clone + Unpin> Fut
                 macro_rules! check_for_handler {
                     ($display: expr, $handler: expr, $result: ident) => {{
                         if let Some(output) = ($handler).handle(&mut $display) {
-                            $result = Some(Poll::Ready(Ok(output)));
+                            $result = Some(Poll::Ready(output));
                             return Inner::Complete($display);
                         };
                     }};
@@ -3,6 +3,7 @@
 mod and_then;
 mod either;
 mod exchange_request;
+mod has_extension;
 mod looping;
 mod map;
 mod put_image;
@@ -20,6 +21,7 @@ mod wait_for_special_event;
 pub use and_then::ExchangeXidFuture;
 pub use either::EitherFuture;
 pub use exchange_request::ExchangeRequestFuture;
+pub use has_extension::HasExtensionFuture;
 pub use looping::{WaitLoopFuture, WaitLoopHandler};
 pub use map::MapFuture;
 pub use put_image::PutImageFuture;
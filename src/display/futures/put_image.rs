@@ -34,6 +34,9 @@ pub enum PutImageFuture<'a, D: ?Sized, I: IntoIterator> {
         tokens: VecIter<RequestCookie<PutImageRequest>>,
         inner: ResolveRequestFuture<'a, D, PutImageRequest>,
     },
+    /// The requests could not be built; return the error on the first poll.
+    #[doc(hidden)]
+    Failed(crate::BreadError),
     /// Edge case: no requests, but we are complete... or we've error'd out.
     #[doc(hidden)]
     Complete,
@@ -44,6 +47,11 @@ impl<'a, D: ?Sized, I: IntoIterator> PutImageFuture<'a, D, I> {
     pub(crate) fn run(display: &'a mut D, requests: I) -> Self {
         Self::AwaitingPoll { display, requests }
     }
+
+    #[inline]
+    pub(crate) fn failed(err: crate::BreadError) -> Self {
+        Self::Failed(err)
+    }
 }
 
 impl<'a, D: AsyncDisplay + ?Sized, I: IntoIterator<Item = PutImageRequest> + Unpin> Future
@@ -137,6 +145,7 @@ where
                         None => return Poll::Ready(Ok(())),
                     }
                 }
+                PutImageFuture::Failed(err) => return Poll::Ready(Err(err)),
                 PutImageFuture::Complete => panic!("Attempted to poll future after completion"),
             }
         }
@@ -56,14 +56,17 @@ impl<'a, D: AsyncDisplay + ?Sized, R: Request> ResolveRequestFuture<'a, D, R> {
             core::any::type_name::<R>()
         );
 
-        match (mem::size_of::<R::Reply>(), display.checked()) {
+        // requests without replies are only tracked if they were sent checked
+        let seq = tok.sequence();
+        let checked = display.checked() || display.get_pending_request(seq).is_some();
+        match (mem::size_of::<R::Reply>(), checked) {
             (0, false) => ResolveRequestFuture::FastPath { display },
             (0, true) => ResolveRequestFuture::Synchronizing {
                 sf: SynchronizeFuture::run(display),
-                tok,
+                tok: RequestCookie::from_sequence(seq),
             },
             _ => ResolveRequestFuture::Resolving {
-                rrrf: ResolveRequestRawFuture::run(display, tok.sequence()),
+                rrrf: ResolveRequestRawFuture::run(display, seq),
             },
         }
     }
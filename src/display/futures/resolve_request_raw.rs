@@ -23,7 +23,17 @@ impl WaitLoopHandler for ResolveRequestRawHandler {
     type Output = PendingReply;
 
     #[inline]
-    fn handle<D: AsyncDisplay + ?Sized>(&self, display: &mut &mut D) -> Option<PendingReply> {
-        (**display).take_pending_reply(self.req_id)
+    fn handle<D: AsyncDisplay + ?Sized>(
+        &self,
+        display: &mut &mut D,
+    ) -> Option<crate::Result<PendingReply>> {
+        match (**display).take_pending_reply(self.req_id) {
+            Some(reply) => Some(Ok(reply)),
+            // the server sends an error instead of a reply if the request failed
+            None => (**display)
+                .check_for_pending_error(self.req_id)
+                .err()
+                .map(Err),
+        }
     }
 }
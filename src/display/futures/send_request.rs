@@ -35,6 +35,23 @@ impl<'a, D: AsyncDisplay + ?Sized, R: Request> SendRequestFuture<'a, D, R> {
         }
     }
 
+    /// Send the request, keeping track of any error it causes even if the display isn't checked.
+    #[inline]
+    pub(crate) fn run_checked(display: &'a mut D, request: R) -> Self {
+        log::info!(
+            "Sending a checked {} to the server",
+            core::any::type_name::<R>()
+        );
+
+        let mut req =
+            RequestInfo::from_request(request, display.bigreq_enabled(), display.max_request_len());
+        req.checked = true;
+        Self {
+            inner: SendRequestRawFuture::run(display, req),
+            _phantom: PhantomData,
+        }
+    }
+
     #[inline]
     pub(crate) fn cannibalize(self) -> &'a mut D {
         self.inner.cannibalize()
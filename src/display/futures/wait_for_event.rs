@@ -24,7 +24,10 @@ impl WaitLoopHandler for WaitForEventHandler {
     type Output = Event;
 
     #[inline]
-    fn handle<D: AsyncDisplay + ?Sized>(&self, display: &mut &mut D) -> Option<Event> {
-        display.pop_event()
+    fn handle<D: AsyncDisplay + ?Sized>(
+        &self,
+        display: &mut &mut D,
+    ) -> Option<crate::Result<Event>> {
+        display.pop_event().map(Ok)
     }
 }
@@ -27,7 +27,10 @@ impl WaitLoopHandler for WaitForSpecialEventHandler {
     type Output = Event;
 
     #[inline]
-    fn handle<D: AsyncDisplay + ?Sized>(&self, display: &mut &mut D) -> Option<Event> {
-        display.pop_special_event(self.xid)
+    fn handle<D: AsyncDisplay + ?Sized>(
+        &self,
+        display: &mut &mut D,
+    ) -> Option<crate::Result<Event>> {
+        display.pop_special_event(self.xid).map(Ok)
     }
 }
@@ -1,10 +1,18 @@
 // MIT/Apache2 License
 
 use super::{
-    Connection, Display, DisplayBase, PendingReply, PendingRequest, PendingRequestFlags,
-    RequestWorkaround,
+    output, Connection, Display, DisplayBase, EventDecoder, OrphanReplyHook, PendingReply,
+    PendingRequest, PendingRequestFlags, RequestInfo, RequestWorkaround, EXT_KEY_SIZE,
 };
-use crate::{event::Event, log_debug, log_trace, Fd};
+use crate::{
+    auto::xproto::{ConfigureNotifyEvent, GetInputFocusRequest, Mapping},
+    event::{Event, OPCODE_MASK},
+    log_debug, log_trace,
+    util::close_fds,
+    BreadError, Fd, ServerTime,
+};
+
+use super::output::str_to_key;
 use alloc::{vec, vec::Vec};
 use core::iter;
 use tinyvec::TinyVec;
@@ -13,6 +21,13 @@ const TYPE_ERROR: u8 = 0;
 const TYPE_REPLY: u8 = 1;
 const GENERIC_EVENT: u8 = 35;
 const GE_MASK: u8 = 0x7f;
+/// Event codes below this and above `GENERIC_EVENT` are never assigned, since the core protocol reserves them
+/// for itself and extensions are given codes starting here.
+const FIRST_EXTENSION_EVENT: u8 = 64;
+/// The longest length, in 4-byte units, that we'll believe a reply or generic event has. This is a gigabyte,
+/// which is more than even the largest image a server will send; a longer length means that we're reading
+/// from the middle of a packet.
+const MAX_REPLY_LENGTH: u32 = 1 << 28;
 
 /// Given a set of bytes representing a reply, error, or event, convert those bytes and process them into
 /// the given `DisplayBase`.
@@ -30,15 +45,39 @@ pub(crate) fn process_bytes<D: DisplayBase + ?Sized>(
     if bytes[0] == TYPE_REPLY {
         log::debug!("Received bytes of type REPLY");
 
-        let pereq = display
-            .take_pending_request(sequence)
-            .ok_or(crate::BreadError::NoMatchingRequest(sequence))?;
+        // a reply nobody is waiting on is either a server bug or for a request whose reply was already
+        // given up on; since it was read in full, dropping it leaves the connection in sync
+        let pereq = match display.get_pending_request(sequence) {
+            // a request with several replies stays pending until the last one, which has a zero where the
+            // others have the length of a font name, arrives
+            Some(pereq) if pereq.flags.multiple_replies => {
+                if bytes[1] == 0 {
+                    display.take_pending_request(sequence);
+                }
+                Some(pereq)
+            }
+            _ => display.take_pending_request(sequence),
+        };
+        let pereq = match pereq {
+            Some(pereq) => pereq,
+            None => {
+                log_trace!(
+                    "Dropping reply for sequence {}, which has no pending request",
+                    sequence
+                );
+                display.add_orphan_reply(sequence, &bytes);
+                // nothing will ever take ownership of the reply's file descriptors
+                close_fds(fds);
+                return Ok(());
+            }
+        };
 
         // if we're discarding the reply, skip the conversion process
         if pereq.flags.discard_reply {
             log::debug!(
                 "Discarding reply as per the request's instructions (likely a synchronization)"
             );
+            close_fds(fds);
         } else {
             // convert to a PendingReply
             let reply = PendingReply {
@@ -54,10 +93,10 @@ pub(crate) fn process_bytes<D: DisplayBase + ?Sized>(
         // we're fine to error out here
         if !bytes.iter().any(|&x| x != 0) {
             log::error!("Request was all zeroes, assuming this means connection is closed");
-            return Err(crate::BreadError::ClosedConnection);
+            return Err(BreadError::ClosedConnection);
         }
 
-        let err = crate::BreadError::from_x_error(bytes);
+        let err = BreadError::from_x_error(bytes);
 
         // if we have a pending request with the given sequence, remove that pending
         // request and put that in the pending requests
@@ -73,7 +112,27 @@ pub(crate) fn process_bytes<D: DisplayBase + ?Sized>(
     } else {
         log::debug!("Received bytes of type EVENT");
         // this is an event
-        let event = Event::from_bytes(bytes)?;
+        let synthetic = bytes[0] & !OPCODE_MASK != 0;
+        let mut event = Event::from_bytes(bytes)?;
+        if let Some((first_event, decoder)) = display.event_decoder(event.opcode()) {
+            decoder(&mut event, first_event)?;
+        }
+        // keep track of the server time, so we have something better than CurrentTime to use for grabs; any
+        // client can make up the time in a SendEvent, and events aren't always reported in time order
+        match event.timestamp() {
+            Some(time) if !synthetic => update_last_event_time(display, time.into()),
+            _ => (),
+        }
+        // let keymaps know that they're out of date; pointer mappings aren't cached, so they don't count
+        if let Event::MappingNotify(ref mne) = event {
+            if mne.request != Mapping::Pointer {
+                display.set_keymap_generation(display.keymap_generation().wrapping_add(1));
+            }
+        }
+        // keep the cached root window sizes correct across screen resizes
+        if let Event::ConfigureNotify(ref cne) = event {
+            update_root_size(display, cne);
+        }
         // if it doesn't fit in any of the special event queues, put it in the main one
         if let Err(event) = filter_into_special_event(display, event) {
             display.push_event(event);
@@ -83,6 +142,119 @@ pub(crate) fn process_bytes<D: DisplayBase + ?Sized>(
     Ok(())
 }
 
+/// The replies that arrived without a request waiting on them, and the hook to call for each one.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct OrphanReplies {
+    count: usize,
+    hook: Option<OrphanReplyHook>,
+}
+
+impl OrphanReplies {
+    #[inline]
+    pub(crate) fn add(&mut self, sequence: u16, reply: &[u8]) {
+        self.count = self.count.saturating_add(1);
+        if let Some(hook) = self.hook {
+            hook(sequence, reply);
+        }
+    }
+
+    #[inline]
+    pub(crate) fn count(&self) -> usize {
+        self.count
+    }
+
+    #[inline]
+    pub(crate) fn set_hook(&mut self, hook: Option<OrphanReplyHook>) {
+        self.hook = hook;
+    }
+}
+
+/// Move the last event time forwards to `time`, if it is later.
+#[inline]
+fn update_last_event_time<D: DisplayBase + ?Sized>(display: &mut D, time: ServerTime) {
+    let last = display.last_event_time();
+    if last.is_current_time() || last.is_earlier_than(time) {
+        display.set_last_event_time(time);
+    }
+}
+
+/// If the window that was reconfigured is a root window, update its cached size.
+#[inline]
+fn update_root_size<D: DisplayBase + ?Sized>(display: &mut D, cne: &ConfigureNotifyEvent) {
+    if let Some(screen) = display
+        .screens()
+        .iter()
+        .position(|screen| screen.root == cne.window)
+    {
+        display.set_root_size(screen, cne.width, cne.height);
+    }
+}
+
+/// The decoders registered for extension events. Each one is kept with the extension it belongs to, since
+/// the range of event codes it covers isn't known until the extension has been queried.
+#[derive(Debug, Clone)]
+pub(crate) struct EventRoutes {
+    routes: Vec<([u8; EXT_KEY_SIZE], u8, EventDecoder)>,
+}
+
+impl Default for EventRoutes {
+    #[inline]
+    fn default() -> Self {
+        #[allow(unused_mut)]
+        let mut this = Self { routes: vec![] };
+        // XKB events all share the extension's first event code
+        #[cfg(feature = "xkb")]
+        this.register("XKEYBOARD", 1, Event::differentiate_xkb);
+        // these deliver their events as generic events, which aren't told apart by event code
+        #[cfg(feature = "ge")]
+        for extension in &["XInputExtension", "Present"] {
+            this.register(extension, 0, keep_event);
+        }
+        this
+    }
+}
+
+/// A decoder that leaves events as they are.
+#[cfg(feature = "ge")]
+#[allow(clippy::unnecessary_wraps)]
+#[inline]
+fn keep_event(_event: &mut Event, _first_event: u8) -> crate::Result {
+    Ok(())
+}
+
+impl EventRoutes {
+    #[inline]
+    pub(crate) fn register(&mut self, extension: &str, event_count: u8, decoder: EventDecoder) {
+        let key = str_to_key(extension);
+        self.routes.retain(|(k, _, _)| *k != key);
+        self.routes.push((key, event_count, decoder));
+    }
+
+    /// Whether or not a decoder is registered for an extension.
+    #[inline]
+    pub(crate) fn contains(&self, extension: &str) -> bool {
+        let key = str_to_key(extension);
+        self.routes.iter().any(|(k, _, _)| *k == key)
+    }
+
+    /// Find the decoder whose range contains `code`, given a way to look up each extension's first event.
+    #[inline]
+    pub(crate) fn find<F: Fn(&[u8; EXT_KEY_SIZE]) -> Option<u8>>(
+        &self,
+        code: u8,
+        first_event: F,
+    ) -> Option<(u8, EventDecoder)> {
+        self.routes.iter().find_map(|(key, event_count, decoder)| {
+            let first = first_event(key)?;
+            if code >= first && code - first < *event_count {
+                Some((first, *decoder))
+            } else {
+                None
+            }
+        })
+    }
+}
+
 /// See if the specified event fits in a special events queue.
 #[inline]
 fn filter_into_special_event<D: DisplayBase + ?Sized>(
@@ -107,7 +279,8 @@ fn filter_into_special_event<D: DisplayBase + ?Sized>(
     display.push_special_event(my_eid, event)
 }
 
-/// Tell whether or not we need any additional bytes.
+/// Tell whether or not we need any additional bytes. Replies and generic events both store the number of
+/// 4-byte units past the first 32 bytes in their second word.
 #[inline]
 pub(crate) fn additional_bytes(bytes: &[u8]) -> Option<usize> {
     if bytes[0] == TYPE_REPLY || bytes[0] & GE_MASK == GENERIC_EVENT {
@@ -119,26 +292,85 @@ pub(crate) fn additional_bytes(bytes: &[u8]) -> Option<usize> {
     }
 }
 
-/// Fix the GLX bug in certain requests.
+/// Check that the first 32 bytes of a packet could have been sent by the server. If a packet was misread
+/// earlier, we may be reading from the middle of one now, and its "length" could have us read gigabytes of
+/// garbage; it's better to stop and report `ProtocolDesync` than to keep going.
+#[inline]
+pub(crate) fn check_packet_header(bytes: &[u8]) -> crate::Result {
+    let code = bytes[0] & GE_MASK;
+    if code > GENERIC_EVENT && code < FIRST_EXTENSION_EVENT {
+        log::error!("Received event with unassigned code {}", code);
+        return Err(BreadError::ProtocolDesync);
+    }
+
+    if bytes[0] == TYPE_REPLY || code == GENERIC_EVENT {
+        let length = u32::from_ne_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+        if length > MAX_REPLY_LENGTH {
+            log::error!("Received packet with implausible length {}", length);
+            return Err(BreadError::ProtocolDesync);
+        }
+    }
+
+    Ok(())
+}
+
+/// Tell how many file descriptors a reply carries. Every reply that carries file descriptors stores their
+/// number in its second byte.
+#[inline]
+pub(crate) fn expected_fds<F: FnOnce(u16) -> Option<PendingRequest>>(
+    bytes: &[u8],
+    pending_request: F,
+) -> usize {
+    if bytes[0] != TYPE_REPLY {
+        return 0;
+    }
+
+    match pending_request(u16::from_ne_bytes([bytes[2], bytes[3]])) {
+        Some(pereq) if pereq.flags.expects_fds => bytes[1] as usize,
+        _ => 0,
+    }
+}
+
+/// The longest length, in 4-byte units, that the GLX workaround will give a reply. Even a server with
+/// thousands of configs and hundreds of properties each stays well under this, so anything longer means
+/// that the counts it's computed from are garbage.
+const GLX_WORKAROUND_MAX_LENGTH: u32 = 1 << 20;
+
+/// Fix the GLX bug in certain requests. Some servers send the wrong length for `GetFBConfigs` replies, so
+/// the length is computed from the number of configs and properties in the reply instead. If the declared
+/// length is already right, or the computed one is implausible, the reply is left alone; reading past the
+/// end of the reply would wait forever for bytes that are never coming.
 #[inline]
 pub(crate) fn fix_glx_workaround<F: FnOnce(u16) -> bool>(check: F, bytes: &mut [u8]) {
     // this will only ever apply to replies
-    if bytes[0] == TYPE_REPLY {
-        // grab the pending request
-        let sequence = u16::from_ne_bytes([bytes[2], bytes[3]]);
-        if check(sequence) {
-            log::debug!("Applying GLX FbConfig workaround to reply");
-
-            // length is the 1st u32, numVisuals is the 2nd u32, numProps in the 3rd u32
-            // numVisuals is 8..12, numProps is 12..16, length is 4..8
-            let (mut visuals, mut props): ([u8; 4], [u8; 4]) = ([0; 4], [0; 4]);
-            visuals.copy_from_slice(&bytes[8..12]);
-            props.copy_from_slice(&bytes[12..16]);
+    if bytes[0] != TYPE_REPLY || !check(u16::from_ne_bytes([bytes[2], bytes[3]])) {
+        return;
+    }
 
-            let (visuals, props) = (u32::from_ne_bytes(visuals), u32::from_ne_bytes(props));
+    // length is 4..8, numVisuals is 8..12, numProps is 12..16
+    let word = |i: usize| u32::from_ne_bytes([bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3]]);
+    let (declared, visuals, props) = (word(4), word(8), word(12));
 
-            let length = (visuals * props * 2).to_ne_bytes();
-            (&mut bytes[4..8]).copy_from_slice(&length);
+    // each property is a pair of words
+    match visuals.checked_mul(props).and_then(|n| n.checked_mul(2)) {
+        Some(length) if length == declared => {
+            log_trace!("GLX FbConfig reply already has the right length");
+        }
+        Some(length) if length <= GLX_WORKAROUND_MAX_LENGTH => {
+            log::debug!(
+                "Applying GLX FbConfig workaround to reply, changing length from {} to {}",
+                declared,
+                length
+            );
+            bytes[4..8].copy_from_slice(&length.to_ne_bytes());
+        }
+        _ => {
+            log::warn!(
+                "GLX FbConfig reply claims {} configs with {} properties each; keeping its length of {}",
+                visuals,
+                props,
+                declared
+            );
         }
     }
 }
@@ -159,13 +391,19 @@ pub(crate) fn expect_reply<D: DisplayBase + ?Sized>(
 
 /// Wait for bytes to appear on a synchronous connection.
 #[inline]
-pub(crate) fn wait<C: Connection + ?Sized, D: Display + ?Sized>(
+pub(crate) fn wait<C: Connection + ?Sized, D: DisplayBase + ?Sized>(
     display: &mut D,
     connection: &mut C,
 ) -> crate::Result {
     log_trace!("Ran wait()");
     log::debug!("Beginning wait cycle");
 
+    // a reply we're about to wait on might belong to a request we haven't sent yet; sending it, or giving
+    // up on it, counts as something happening
+    if output::send_deferred_requests(display, connection)? {
+        return Ok(());
+    }
+
     // replies, errors, and events are all in units of 32 bytes
     let mut bytes: TinyVec<[u8; 32]> = iter::repeat(0).take(32).collect();
     let mut fds: Vec<Fd> = vec![];
@@ -180,6 +418,7 @@ pub(crate) fn wait<C: Connection + ?Sized, D: Display + ?Sized>(
         },
         &mut bytes,
     );
+    check_packet_header(&bytes)?;
 
     // in certain cases, we may have to read more bytes
     if let Some(ab) = additional_bytes(&bytes[..8]) {
@@ -192,6 +431,462 @@ pub(crate) fn wait<C: Connection + ?Sized, D: Display + ?Sized>(
         }
     }
 
+    // the file descriptors for a reply may arrive separately from its data, so keep reading until we
+    // have all of them
+    let expected = expected_fds(&bytes, |seq| display.get_pending_request(seq));
+    while fds.len() < expected {
+        let received = fds.len();
+        connection.read_packet(&mut [], &mut fds)?;
+        if fds.len() == received {
+            log::warn!(
+                "Reply should carry {} file descriptors, but only {} were received",
+                expected,
+                received
+            );
+            break;
+        }
+    }
+
     log::debug!("Found {} bytes; now processing them...", bytes.len());
     process_bytes(display, bytes, fds)
 }
+
+/// Re-establish a known packet boundary after `ProtocolDesync`. A `GetInputFocus` request is sent, and the
+/// incoming bytes are skipped one at a time until its reply shows up. Everything the server sent before that
+/// reply is discarded, so the requests sent before it fail with `ProtocolDesync` rather than waiting forever
+/// for replies that were thrown away.
+#[inline]
+pub(crate) fn resync<D: Display + ?Sized, C: Connection + ?Sized>(
+    display: &mut D,
+    connection: &mut C,
+) -> crate::Result {
+    let mut gifr = RequestInfo::from_request(
+        GetInputFocusRequest::default(),
+        display.bigreq_enabled(),
+        display.max_request_len(),
+    );
+    gifr.discard_reply = true;
+
+    // the replies the held back requests are waiting on may be among what we skip, so give up on them
+    while let Some(mut req) = display.take_deferred_request() {
+        output::cancel_deferred_request(display, &mut req, BreadError::ProtocolDesync);
+        connection.send_packet(&req.data, &mut vec![])?;
+    }
+
+    let sequence = output::send_request(display, connection, gifr)?;
+    display.take_pending_request(sequence);
+
+    let mut window = [0; 32];
+    let mut fds = vec![];
+    connection.read_packet(&mut window, &mut fds)?;
+    let mut skipped = 0usize;
+    while !is_reply_header(&window, sequence) {
+        window.copy_within(1.., 0);
+        connection.read_packet(&mut window[31..], &mut fds)?;
+        skipped += 1;
+    }
+    close_fds(fds);
+    log::warn!(
+        "Skipped {} bytes to resynchronize with the server at sequence {}",
+        skipped,
+        sequence
+    );
+
+    for back in 1..=u16::MAX {
+        let seq = sequence.wrapping_sub(back);
+        if let Some(pereq) = display.take_pending_request(seq) {
+            if !pereq.flags.discard_reply {
+                display.add_pending_error(seq, BreadError::ProtocolDesync);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Tell whether these bytes begin the empty-length reply for the given sequence.
+#[inline]
+fn is_reply_header(bytes: &[u8], sequence: u16) -> bool {
+    bytes[0] == TYPE_REPLY && bytes[2..4] == sequence.to_ne_bytes() && bytes[4..8] == [0; 4]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_packet_header, fix_glx_workaround, TYPE_REPLY};
+    use crate::{
+        auto::xproto::{GetInputFocusRequest, Setup},
+        display::{mock_display, mock_setup, DisplayBase, DisplayExt, MockConnection},
+        BreadError,
+    };
+
+    fn fbconfigs_reply(length: u32, visuals: u32, props: u32) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        bytes[0] = TYPE_REPLY;
+        bytes[4..8].copy_from_slice(&length.to_ne_bytes());
+        bytes[8..12].copy_from_slice(&visuals.to_ne_bytes());
+        bytes[12..16].copy_from_slice(&props.to_ne_bytes());
+        bytes
+    }
+
+    fn length(bytes: &[u8]) -> u32 {
+        u32::from_ne_bytes([bytes[4], bytes[5], bytes[6], bytes[7]])
+    }
+
+    #[test]
+    fn glx_workaround_is_guarded() {
+        // a broken length is fixed
+        let mut bytes = fbconfigs_reply(30, 3, 10);
+        fix_glx_workaround(|_| true, &mut bytes);
+        assert_eq!(length(&bytes), 60);
+
+        // a correct length is already right
+        let mut bytes = fbconfigs_reply(60, 3, 10);
+        fix_glx_workaround(|_| true, &mut bytes);
+        assert_eq!(length(&bytes), 60);
+
+        // counts that overflow or are absurdly large aren't trusted
+        let mut bytes = fbconfigs_reply(30, u32::MAX, 2);
+        fix_glx_workaround(|_| true, &mut bytes);
+        assert_eq!(length(&bytes), 30);
+        let mut bytes = fbconfigs_reply(30, 1 << 12, 1 << 12);
+        fix_glx_workaround(|_| true, &mut bytes);
+        assert_eq!(length(&bytes), 30);
+
+        // other replies are never touched
+        let mut bytes = fbconfigs_reply(30, 3, 10);
+        fix_glx_workaround(|_| false, &mut bytes);
+        assert_eq!(length(&bytes), 30);
+    }
+
+    #[test]
+    fn implausible_headers_are_desyncs() {
+        // an ordinary reply, core event and extension event are all fine
+        assert!(check_packet_header(&fbconfigs_reply(60, 3, 10)).is_ok());
+        let mut event = [0; 32];
+        event[0] = 12;
+        assert!(check_packet_header(&event).is_ok());
+        event[0] = 70;
+        assert!(check_packet_header(&event).is_ok());
+
+        // unassigned event codes and enormous lengths aren't
+        event[0] = 40;
+        assert!(check_packet_header(&event).is_err());
+        assert!(check_packet_header(&fbconfigs_reply(u32::MAX, 0, 0)).is_err());
+    }
+
+    #[test]
+    fn eof_fails_pending_requests() {
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        // the server hangs up partway through sending the first reply
+        conn.feed(&[1, 0, 2, 0]);
+        let mut display = mock_display(&mut conn);
+        let first = display
+            .send_request(GetInputFocusRequest::default())
+            .unwrap();
+        let second = display
+            .send_request(GetInputFocusRequest::default())
+            .unwrap();
+
+        assert!(matches!(
+            display.resolve_request(first),
+            Err(BreadError::ClosedConnection)
+        ));
+        assert!(matches!(
+            display.resolve_request(second),
+            Err(BreadError::ClosedConnection)
+        ));
+    }
+
+    #[test]
+    fn orphan_replies_are_dropped() {
+        use crate::auto::xproto::{GetInputFocusReply, Window};
+        use core::sync::atomic::{AtomicU16, Ordering};
+
+        static ORPHAN: AtomicU16 = AtomicU16::new(0);
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        // a reply for a request that was never sent comes before the one we're waiting on
+        conn.feed_reply(&GetInputFocusReply {
+            reply_type: 1,
+            sequence: 7,
+            ..Default::default()
+        });
+        conn.feed_reply(&GetInputFocusReply {
+            reply_type: 1,
+            sequence: 2,
+            focus: Window::const_from_xid(0x0200_0001),
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        display.on_orphan_reply(Some(|sequence, _| ORPHAN.store(sequence, Ordering::SeqCst)));
+        let tok = display
+            .send_request(GetInputFocusRequest::default())
+            .unwrap();
+        let reply = display.resolve_request(tok).unwrap();
+        assert_eq!(reply.focus, Window::const_from_xid(0x0200_0001));
+        assert_eq!(display.orphan_reply_count(), 1);
+        assert_eq!(ORPHAN.load(Ordering::SeqCst), 7);
+    }
+
+    #[cfg(all(unix, feature = "std"))]
+    #[test]
+    fn orphan_reply_fds_are_closed() {
+        use crate::{
+            auto::xproto::GetInputFocusReply,
+            display::{pipe, write_end_closed},
+        };
+        use alloc::vec;
+
+        let (read, write) = pipe();
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_fds(vec![write]);
+        conn.feed_reply(&GetInputFocusReply {
+            reply_type: 1,
+            sequence: 7,
+            ..Default::default()
+        });
+        conn.feed_reply(&GetInputFocusReply {
+            reply_type: 1,
+            sequence: 2,
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        let tok = display
+            .send_request(GetInputFocusRequest::default())
+            .unwrap();
+        display.resolve_request(tok).unwrap();
+        assert_eq!(display.orphan_reply_count(), 1);
+        assert!(write_end_closed(read));
+    }
+
+    /// A generic event with 32 bytes past the usual 32, whose `length` field says as much.
+    fn generic_event() -> alloc::vec::Vec<u8> {
+        let mut bytes = alloc::vec![35, 131, 0, 0];
+        bytes.extend_from_slice(&8u32.to_ne_bytes());
+        bytes.extend((8u8..64).map(|i| i.wrapping_mul(3)));
+        bytes
+    }
+
+    #[test]
+    fn generic_event_is_read_whole() {
+        use crate::display::Display;
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        let event = generic_event();
+        conn.feed(&event);
+
+        let mut display = mock_display(&mut conn);
+        let received = display.wait_for_event().unwrap();
+        assert_eq!(received.as_byte_slice(), Some(&event[..]));
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn generic_event_is_read_whole_async() {
+        use crate::display::AsyncDisplayExt;
+        use futures_lite::future;
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        let event = generic_event();
+        conn.feed(&event);
+
+        let mut display = mock_display(&mut conn);
+        let received = future::block_on(display.wait_for_event_async()).unwrap();
+        assert_eq!(received.as_byte_slice(), Some(&event[..]));
+    }
+
+    #[cfg(all(feature = "async", feature = "shm"))]
+    #[test]
+    fn fds_sent_after_reply() {
+        use crate::{
+            auto::{
+                shm::{CreateSegmentReply, CreateSegmentRequest},
+                xproto::QueryExtensionReply,
+            },
+            display::AsyncDisplayExt,
+        };
+        use alloc::vec;
+        use futures_lite::future;
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_reply(&QueryExtensionReply {
+            reply_type: 1,
+            sequence: 2,
+            present: true,
+            major_opcode: 130,
+            ..Default::default()
+        });
+        // the file descriptor arrives on its own, after the reply's data
+        conn.feed_reply(&CreateSegmentReply {
+            reply_type: 1,
+            nfd: 1,
+            sequence: 3,
+            ..Default::default()
+        });
+        conn.feed_fds(vec![7]);
+
+        let mut display = mock_display(&mut conn);
+        let reply = future::block_on(async {
+            let tok = display
+                .send_request_async(CreateSegmentRequest::default())
+                .await?;
+            display.resolve_request_async(tok).await
+        })
+        .unwrap();
+
+        assert_eq!(reply.shm_fd, vec![7]);
+    }
+
+    #[test]
+    fn root_size_follows_configure_notify() {
+        use crate::{
+            auto::xproto::{ConfigureNotifyEvent, Screen, Window},
+            display::Display,
+        };
+        use alloc::vec;
+
+        let root = Window::const_from_xid(0x100);
+        let setup = Setup {
+            roots: vec![Screen {
+                root,
+                width_in_pixels: 1024,
+                height_in_pixels: 768,
+                ..Default::default()
+            }],
+            ..mock_setup()
+        };
+        let mut conn = MockConnection::with_setup(&setup);
+        conn.feed_reply(&ConfigureNotifyEvent {
+            event_type: 22,
+            event: root,
+            window: root,
+            width: 1920,
+            height: 1080,
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        assert_eq!(display.root_size(0), Some((1024, 768)));
+        assert_eq!(display.root_size(1), None);
+
+        display.wait_for_event().unwrap();
+        assert_eq!(display.root_size(0), Some((1920, 1080)));
+    }
+
+    #[test]
+    fn last_event_time_only_moves_forwards() {
+        use crate::{
+            auto::xproto::{KeyPressEvent, PropertyNotifyEvent},
+            display::Display,
+            ServerTime,
+        };
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_reply(&PropertyNotifyEvent {
+            event_type: 28,
+            time: 1000,
+            ..Default::default()
+        });
+        // an event that arrives out of order
+        conn.feed_reply(&KeyPressEvent {
+            event_type: 2,
+            time: 500,
+            ..Default::default()
+        });
+        // an event sent by another client, with whatever time it likes
+        conn.feed_reply(&PropertyNotifyEvent {
+            event_type: 0x1c | 0x80,
+            time: 2000,
+            ..Default::default()
+        });
+        conn.feed_reply(&KeyPressEvent {
+            event_type: 2,
+            time: 1500,
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        assert_eq!(display.last_event_time(), ServerTime::CURRENT_TIME);
+        for expected in &[1000, 1000, 1000, 1500] {
+            display.wait_for_event().unwrap();
+            assert_eq!(display.last_event_time(), ServerTime::new(*expected));
+        }
+    }
+
+    #[test]
+    fn resync_skips_to_a_packet_boundary() {
+        use crate::auto::xproto::{Atom, GetInputFocusReply, InternAtomReply, InternAtomRequest};
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        // an event code that is never assigned, then junk that starts out like a reply
+        let mut garbage = [0; 41];
+        garbage[0] = 40;
+        garbage[32..37].copy_from_slice(&[1, 0, 4, 0, 5]);
+        conn.feed(&garbage);
+        conn.feed_reply(&GetInputFocusReply {
+            reply_type: 1,
+            sequence: 4,
+            ..Default::default()
+        });
+        conn.feed_reply(&InternAtomReply {
+            reply_type: 1,
+            sequence: 5,
+            atom: Atom::const_from_xid(7),
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        let first = display.send_request(InternAtomRequest::default()).unwrap();
+        let second = display.send_request(InternAtomRequest::default()).unwrap();
+        assert!(matches!(
+            display.resolve_request(first),
+            Err(BreadError::ProtocolDesync)
+        ));
+
+        display.resync().unwrap();
+        assert!(matches!(
+            display.resolve_request(second),
+            Err(BreadError::ProtocolDesync)
+        ));
+        let reply = display
+            .exchange_request(InternAtomRequest::default())
+            .unwrap();
+        assert_eq!(reply.atom, Atom::const_from_xid(7));
+    }
+
+    #[test]
+    fn extension_events_are_routed_to_decoders() {
+        use crate::{
+            display::{output::str_to_key, Display, EventDecoder},
+            Event,
+        };
+        use alloc::{vec, vec::Vec};
+
+        // turns each event into one whose opcode is its offset from the extension's first event
+        #[allow(clippy::unnecessary_wraps)]
+        fn decode(event: &mut Event, first_event: u8) -> crate::Result {
+            if let Event::NoneOfTheAbove { opcode, .. } = event {
+                *opcode -= first_event;
+            }
+            Ok(())
+        }
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        for code in &[90u8, 91, 92] {
+            let mut event = [0; 32];
+            event[0] = *code;
+            conn.feed(&event);
+        }
+
+        let mut display = mock_display(&mut conn);
+        display.register_event_decoder("FAKE-EXTENSION", 2, decode as EventDecoder);
+        display.set_extension_first_event(str_to_key("FAKE-EXTENSION"), 90);
+
+        let opcodes: Vec<u8> = (0..3)
+            .map(|_| display.wait_for_event().unwrap().opcode())
+            .collect();
+        assert_eq!(opcodes, vec![0, 1, 92]);
+    }
+}
@@ -6,40 +6,68 @@
 
 use crate::{
     auto::{
-        xproto::{Colormap, GetInputFocusRequest, Screen, Setup, Visualid, Visualtype, Window},
+        xproto::{
+            Colormap, Format, GetInputFocusRequest, ImageOrder, QueryExtensionRequest, Screen,
+            Setup, Visualid, Visualtype, Window,
+        },
         AsByteSequence,
     },
     error::BreadError,
     event::Event,
-    util::expand_or_truncate_to_length,
-    Fd, Request, XID,
+    util::{close_fds, expand_or_truncate_to_length},
+    Fd, Request, ServerTime, XID,
 };
-use alloc::{boxed::Box, vec::Vec};
-use core::{fmt, iter, iter::FusedIterator, marker::PhantomData, mem, num::NonZeroU32};
+use alloc::{boxed::Box, collections::VecDeque, string::String, vec::Vec};
+use core::{
+    fmt, iter,
+    iter::FusedIterator,
+    marker::PhantomData,
+    mem::{self, size_of},
+    num::NonZeroU32,
+};
+use hashbrown::HashMap;
 use tinyvec::TinyVec;
 
 #[cfg(feature = "async")]
 use crate::xid::XidType;
 #[cfg(feature = "async")]
 use core::task::{Context, Poll};
+#[cfg(feature = "async")]
+use futures_lite::future::{self, Ready};
+#[cfg(feature = "std")]
+use std::time::{Duration, Instant};
 
 mod basic;
 pub(crate) mod bigreq;
 mod cell;
 mod connection;
+mod reconnect;
+mod resources;
+mod setup;
+mod shared;
 pub mod traits;
+mod visual;
 
 // "traits" contains some important types.
-pub use traits::{rgb, GcParameters, KeyboardMapping, WindowParameters};
+pub use traits::{
+    rgb, FocusTarget, GcParameters, HostEntry, HostFamily, HostList, IconImage, KeyboardGrab,
+    KeyboardMapping, PointerGrab, PressedKeys, PropertyStream, RevertTo, SelectionData,
+    SelectionEvent, SelectionSource, ServerGrab, WindowParameters, PROPERTY_CHUNK_LENGTH,
+};
 
 pub use basic::*;
 pub use cell::*;
 pub use connection::*;
+pub use reconnect::*;
+pub use resources::*;
+pub use visual::*;
 
 #[cfg(feature = "async")]
 pub(crate) mod futures;
 #[cfg(feature = "async")]
 pub use futures::*;
+#[cfg(feature = "async")]
+pub use shared::*;
 
 pub(crate) mod input;
 pub(crate) mod output;
@@ -59,6 +87,41 @@ pub mod prelude {
 
 pub(crate) const EXT_KEY_SIZE: usize = 24;
 
+/// What a display knows about whether the server supports an extension.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ExtensionPresence {
+    /// The server hasn't been asked about the extension yet.
+    Unknown,
+    /// The server does not support the extension.
+    Absent,
+    /// The server supports the extension, which has the given major opcode.
+    Present(u8),
+}
+
+impl From<Option<Option<u8>>> for ExtensionPresence {
+    #[inline]
+    fn from(cached: Option<Option<u8>>) -> Self {
+        match cached {
+            None => Self::Unknown,
+            Some(None) => Self::Absent,
+            Some(Some(opcode)) => Self::Present(opcode),
+        }
+    }
+}
+
+/// Information about the X11 server, as reported when the connection was set up.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ServerInfo<'a> {
+    /// The major version of the protocol the server speaks. This is always 11.
+    pub protocol_major_version: u16,
+    /// The minor version of the protocol the server speaks.
+    pub protocol_minor_version: u16,
+    /// The vendor of the server, such as "The X.Org Foundation".
+    pub vendor: &'a str,
+    /// The vendor's release number for the server.
+    pub release_number: u32,
+}
+
 /// This trait represents a connection to the X11 server. Most operations in `breadx` revolve around an object
 /// implementing this trait in some way, shape, or form.
 ///
@@ -135,7 +198,9 @@ pub trait DisplayBase {
     /// 262140 bytes are allowed to be sent over the connection.
     fn bigreq_enabled(&self) -> bool;
 
-    /// The current maximum request length.
+    /// The current maximum request length, in bytes. This is the limit requests are checked against when
+    /// they are sent, and helpers that split up large requests, such as `put_image`, read it again on every
+    /// call rather than caching it.
     fn max_request_len(&self) -> usize;
 
     /// Get the opcode for an extension.
@@ -144,14 +209,184 @@ pub trait DisplayBase {
     /// Set the opcode for an extension.
     fn set_extension_opcode(&mut self, key: [u8; EXT_KEY_SIZE], opcode: u8);
 
+    /// Get what the display knows about whether the server supports an extension.
+    fn get_extension_presence(&mut self, key: &[u8; EXT_KEY_SIZE]) -> ExtensionPresence;
+
+    /// Remember that the server does not support an extension.
+    fn set_extension_absent(&mut self, key: [u8; EXT_KEY_SIZE]);
+
+    /// Get the first event code for an extension.
+    fn get_extension_first_event(&self, key: &[u8; EXT_KEY_SIZE]) -> Option<u8>;
+
+    /// Set the first event code for an extension.
+    fn set_extension_first_event(&mut self, key: [u8; EXT_KEY_SIZE], first_event: u8);
+
+    /// Note that a `QueryExtension` request for an extension has been sent and not yet used.
+    fn add_pending_extension_query(&mut self, key: [u8; EXT_KEY_SIZE], req_id: u16);
+
+    /// Remove the sequence number of an unused `QueryExtension` request for an extension, if there is one.
+    fn take_pending_extension_query(&mut self, key: &[u8; EXT_KEY_SIZE]) -> Option<u16>;
+
+    /// Get the sequence number of an unused `QueryExtension` request for an extension, if there is one.
+    fn pending_extension_query(&self, key: &[u8; EXT_KEY_SIZE]) -> Option<u16>;
+
+    /// Hold back a request that already has a sequence number, to be sent once the opcode of its extension
+    /// is known. Requests are held back in the order they were given sequence numbers.
+    fn defer_request(&mut self, request: RequestInfo);
+
+    /// Take the oldest request that is being held back, if there is one.
+    fn take_deferred_request(&mut self) -> Option<RequestInfo>;
+
+    /// Put a request taken by `take_deferred_request` back in front of the others, since it still can't be
+    /// sent.
+    fn restore_deferred_request(&mut self, request: RequestInfo);
+
+    /// Whether or not any requests are being held back.
+    fn has_deferred_requests(&self) -> bool;
+
     /// Get the `WM_PROTOCOLS` atom, which we cache in the display.
     fn wm_protocols_atom(&self) -> Option<NonZeroU32>;
 
     /// Set the `WM_PROTOCOLS` atom.
     fn set_wm_protocols_atom(&mut self, a: NonZeroU32);
 
+    /// Get the `_XEMBED` atom, which we cache in the display.
+    fn xembed_atom(&self) -> Option<NonZeroU32>;
+
+    /// Set the `_XEMBED` atom.
+    fn set_xembed_atom(&mut self, a: NonZeroU32);
+
+    /// Get the `_XEMBED_INFO` atom, which we cache in the display.
+    fn xembed_info_atom(&self) -> Option<NonZeroU32>;
+
+    /// Set the `_XEMBED_INFO` atom.
+    fn set_xembed_info_atom(&mut self, a: NonZeroU32);
+
+    /// Get the latest server timestamp seen in an event. Events sent by other clients through `SendEvent`
+    /// are not counted, since their timestamps can be anything. This is `ServerTime::CURRENT_TIME` if no
+    /// event carrying a timestamp has been received yet.
+    ///
+    /// Grabs and selection ownership should use a real timestamp rather than `CurrentTime`, as the ICCCM
+    /// requires; otherwise, requests from multiple clients can race each other.
+    fn last_event_time(&self) -> ServerTime;
+
+    /// Set the most recent server timestamp.
+    fn set_last_event_time(&mut self, time: ServerTime);
+
+    /// Get the number of times the keyboard or modifier mapping has changed since the display was opened,
+    /// going by the `MappingNotify` events it has received. A keymap fetched before this last changed is out
+    /// of date.
+    fn keymap_generation(&self) -> u32;
+
+    /// Set the keymap generation.
+    fn set_keymap_generation(&mut self, generation: u32);
+
+    /// Whether or not the version of the Generic Event Extension has been negotiated with the server yet.
+    /// Some servers don't deliver generic events, such as those from XI2 and Present, until a client
+    /// has done so.
+    fn ge_negotiated(&self) -> bool;
+
+    /// Remember that the Generic Event Extension has been negotiated.
+    fn set_ge_negotiated(&mut self);
+
+    /// Get the maximum number of replies that may be outstanding at once, counting both replies that haven't
+    /// arrived yet and replies that have arrived but haven't been read. This is `usize::MAX`, meaning
+    /// unbounded, by default.
+    fn max_pending(&self) -> usize;
+
+    /// Set the maximum number of outstanding replies. Once this many are outstanding, sending another request
+    /// first reads from the connection until enough replies have arrived to make room. If every outstanding
+    /// reply has already arrived and simply hasn't been read, sending fails with `BreadError::WouldBlock`
+    /// instead.
+    fn set_max_pending(&mut self, max: usize);
+
+    /// Get the number of requests that have been sent and are still waiting on a reply from the server.
+    fn awaited_reply_count(&self) -> usize;
+
+    /// Get the number of replies that have arrived from the server but have not been read yet.
+    fn unread_reply_count(&self) -> usize;
+
+    /// Reserve room for at least `additional` more outstanding requests and their replies, so that a large
+    /// batch of pipelined requests doesn't make the display grow its internal maps over and over. Each slot
+    /// takes up a few dozen bytes. Sequence numbers are 16 bits wide, so no more than 65536 requests are
+    /// ever outstanding at once, and larger values are clamped to that.
+    fn reserve_pending(&mut self, additional: usize);
+
+    /// Get the width and height of a screen's root window, in pixels, or `None` if there is no such screen.
+    /// This starts out as the size given in the setup, and is updated whenever a `ConfigureNotify` event for
+    /// that root window is received, so it stays correct across resolution changes without a round trip.
+    /// Note that the server only sends these events if `StructureNotify` is selected on the root window.
+    fn root_size(&self, screen: usize) -> Option<(u16, u16)>;
+
+    /// Set the cached size of a screen's root window.
+    fn set_root_size(&mut self, screen: usize, width: u16, height: u16);
+
+    /// Whether or not this display keeps track of the windows, pixmaps and graphics contexts created through
+    /// it, in order to free them when it is dropped.
+    fn tracking_resources(&self) -> bool;
+
+    /// Set whether or not this display tracks the resources created through it. This is off by default, since
+    /// not every resource a client creates is one it owns; a window may be handed off to another client, for
+    /// instance. Turning tracking off forgets every resource tracked so far.
+    ///
+    /// Tracked resources are freed in one batched write when the display is dropped, newest first. Freeing a
+    /// resource through its `free` method stops tracking it. Sending `SetCloseDownMode` with one of the retain
+    /// modes turns tracking off, since those resources are meant to outlive the connection.
+    fn track_resources(&mut self, track: bool);
+
+    /// Track a resource, so that it is freed when the display is dropped. This does nothing unless tracking
+    /// is on.
+    fn track_resource(&mut self, xid: XID, kind: ResourceKind);
+
+    /// Stop tracking a resource, usually because it has been freed.
+    fn untrack_resource(&mut self, xid: XID);
+
+    /// Record a reply that arrived with a sequence number no request is waiting on, calling the orphan reply
+    /// hook if one is set. The reply itself is dropped.
+    fn add_orphan_reply(&mut self, sequence: u16, reply: &[u8]);
+
+    /// Get the number of replies that have arrived without a request waiting on them. This should stay at
+    /// zero; if it doesn't, the server is misbehaving or replies are being read by something else.
+    fn orphan_reply_count(&self) -> usize;
+
+    /// Set a function to be called with the sequence number and bytes of every reply that arrives without a
+    /// request waiting on it, or `None` to stop calling it. This is only for diagnostics, since such replies
+    /// are dropped either way.
+    fn on_orphan_reply(&mut self, hook: Option<OrphanReplyHook>);
+
+    /// Register a function that decodes the events of an extension, which has `event_count` event codes
+    /// starting at its first event. Once the extension has been queried, every event in that range is given
+    /// to `decoder` before it is queued. Registering a decoder for an extension replaces its old one.
+    fn register_event_decoder(
+        &mut self,
+        extension: &'static str,
+        event_count: u8,
+        decoder: EventDecoder,
+    );
+
+    /// Get the decoder registered for an event code, along with the first event of the extension that owns it.
+    fn event_decoder(&self, code: u8) -> Option<(u8, EventDecoder)>;
+
+    /// Whether or not the events of an extension are routed through a decoder. With the `ge` feature, the
+    /// Generic Event Extension is negotiated before the first request to any routed extension, and the X
+    /// Input and Present extensions are always routed, since they deliver generic events.
+    fn has_event_decoder(&self, extension: &str) -> bool;
+
     // -- Setup-based functions.
 
+    /// Get the protocol version, vendor and release number of the server. The vendor can be used to work
+    /// around quirks of specific servers.
+    #[inline]
+    fn server_info(&self) -> ServerInfo<'_> {
+        let setup = self.setup();
+        ServerInfo {
+            protocol_major_version: setup.protocol_major_version,
+            protocol_minor_version: setup.protocol_minor_version,
+            vendor: &setup.vendor,
+            release_number: setup.release_number,
+        }
+    }
+
     /// Get the list of screens in this display.
     #[inline]
     fn screens(&self) -> &[Screen] {
@@ -198,6 +433,43 @@ pub trait DisplayBase {
         self.default_screen().default_colormap
     }
 
+    /// Get the byte order the server uses for images, and for each scanline unit of a bitmap.
+    #[inline]
+    fn image_byte_order(&self) -> ImageOrder {
+        self.setup().image_byte_order
+    }
+
+    /// Get the order of the bits within each scanline unit of a bitmap.
+    #[inline]
+    fn bitmap_bit_order(&self) -> ImageOrder {
+        self.setup().bitmap_format_bit_order
+    }
+
+    /// Get the size of a bitmap's scanline unit, in bits. This is 8, 16 or 32.
+    #[inline]
+    fn bitmap_scanline_unit(&self) -> u8 {
+        self.setup().bitmap_format_scanline_unit
+    }
+
+    /// Get the number of bits each scanline of a bitmap is padded to. This is 8, 16 or 32.
+    #[inline]
+    fn bitmap_scanline_pad(&self) -> u8 {
+        self.setup().bitmap_format_scanline_pad
+    }
+
+    /// Get the `ZPixmap` formats the server supports, one for each depth.
+    #[inline]
+    fn pixmap_formats(&self) -> &[Format] {
+        &self.setup().pixmap_formats
+    }
+
+    /// Get the `ZPixmap` format for the specified depth, which gives the bits per pixel and the scanline pad
+    /// the server expects for images of that depth.
+    #[inline]
+    fn pixmap_format(&self, depth: u8) -> Option<&Format> {
+        self.pixmap_formats().iter().find(|f| f.depth == depth)
+    }
+
     /// Get a visual type from a visual ID.
     #[inline]
     fn visual_id_to_visual(&self, id: Visualid) -> Option<&Visualtype> {
@@ -209,6 +481,18 @@ pub trait DisplayBase {
             .find(|v| v.visual_id == id)
     }
 
+    /// Get a visual from a visual ID, with its class and color masks interpreted.
+    #[inline]
+    fn visual_type(&self, id: Visualid) -> Option<VisualType<'_>> {
+        self.visual_id_to_visual(id).map(VisualType::new)
+    }
+
+    /// Get the default screen's root visual, with its class and color masks interpreted.
+    #[inline]
+    fn default_visual_type(&self) -> VisualType<'_> {
+        VisualType::new(self.default_visual())
+    }
+
     /// Get the depth of the specified visual ID.
     #[inline]
     fn depth_of_visual(&self, id: Visualid) -> Option<u8> {
@@ -343,6 +627,61 @@ impl<D: DisplayBase + ?Sized> DisplayBase for &mut D {
         (**self).set_extension_opcode(key, opcode)
     }
 
+    #[inline]
+    fn get_extension_presence(&mut self, key: &[u8; EXT_KEY_SIZE]) -> ExtensionPresence {
+        (**self).get_extension_presence(key)
+    }
+
+    #[inline]
+    fn set_extension_absent(&mut self, key: [u8; EXT_KEY_SIZE]) {
+        (**self).set_extension_absent(key);
+    }
+
+    #[inline]
+    fn get_extension_first_event(&self, key: &[u8; EXT_KEY_SIZE]) -> Option<u8> {
+        (**self).get_extension_first_event(key)
+    }
+
+    #[inline]
+    fn set_extension_first_event(&mut self, key: [u8; EXT_KEY_SIZE], first_event: u8) {
+        (**self).set_extension_first_event(key, first_event);
+    }
+
+    #[inline]
+    fn add_pending_extension_query(&mut self, key: [u8; EXT_KEY_SIZE], req_id: u16) {
+        (**self).add_pending_extension_query(key, req_id);
+    }
+
+    #[inline]
+    fn take_pending_extension_query(&mut self, key: &[u8; EXT_KEY_SIZE]) -> Option<u16> {
+        (**self).take_pending_extension_query(key)
+    }
+
+    #[inline]
+    fn pending_extension_query(&self, key: &[u8; EXT_KEY_SIZE]) -> Option<u16> {
+        (**self).pending_extension_query(key)
+    }
+
+    #[inline]
+    fn defer_request(&mut self, request: RequestInfo) {
+        (**self).defer_request(request);
+    }
+
+    #[inline]
+    fn take_deferred_request(&mut self) -> Option<RequestInfo> {
+        (**self).take_deferred_request()
+    }
+
+    #[inline]
+    fn restore_deferred_request(&mut self, request: RequestInfo) {
+        (**self).restore_deferred_request(request);
+    }
+
+    #[inline]
+    fn has_deferred_requests(&self) -> bool {
+        (**self).has_deferred_requests()
+    }
+
     #[inline]
     fn wm_protocols_atom(&self) -> Option<NonZeroU32> {
         (**self).wm_protocols_atom()
@@ -352,6 +691,146 @@ impl<D: DisplayBase + ?Sized> DisplayBase for &mut D {
     fn set_wm_protocols_atom(&mut self, a: NonZeroU32) {
         (**self).set_wm_protocols_atom(a)
     }
+
+    #[inline]
+    fn xembed_atom(&self) -> Option<NonZeroU32> {
+        (**self).xembed_atom()
+    }
+
+    #[inline]
+    fn set_xembed_atom(&mut self, a: NonZeroU32) {
+        (**self).set_xembed_atom(a);
+    }
+
+    #[inline]
+    fn xembed_info_atom(&self) -> Option<NonZeroU32> {
+        (**self).xembed_info_atom()
+    }
+
+    #[inline]
+    fn set_xembed_info_atom(&mut self, a: NonZeroU32) {
+        (**self).set_xembed_info_atom(a);
+    }
+
+    #[inline]
+    fn last_event_time(&self) -> ServerTime {
+        (**self).last_event_time()
+    }
+
+    #[inline]
+    fn set_last_event_time(&mut self, time: ServerTime) {
+        (**self).set_last_event_time(time);
+    }
+
+    #[inline]
+    fn keymap_generation(&self) -> u32 {
+        (**self).keymap_generation()
+    }
+
+    #[inline]
+    fn set_keymap_generation(&mut self, generation: u32) {
+        (**self).set_keymap_generation(generation);
+    }
+
+    #[inline]
+    fn ge_negotiated(&self) -> bool {
+        (**self).ge_negotiated()
+    }
+
+    #[inline]
+    fn set_ge_negotiated(&mut self) {
+        (**self).set_ge_negotiated();
+    }
+
+    #[inline]
+    fn max_pending(&self) -> usize {
+        (**self).max_pending()
+    }
+
+    #[inline]
+    fn set_max_pending(&mut self, max: usize) {
+        (**self).set_max_pending(max);
+    }
+
+    #[inline]
+    fn awaited_reply_count(&self) -> usize {
+        (**self).awaited_reply_count()
+    }
+
+    #[inline]
+    fn reserve_pending(&mut self, additional: usize) {
+        (**self).reserve_pending(additional);
+    }
+
+    #[inline]
+    fn unread_reply_count(&self) -> usize {
+        (**self).unread_reply_count()
+    }
+
+    #[inline]
+    fn root_size(&self, screen: usize) -> Option<(u16, u16)> {
+        (**self).root_size(screen)
+    }
+
+    #[inline]
+    fn set_root_size(&mut self, screen: usize, width: u16, height: u16) {
+        (**self).set_root_size(screen, width, height);
+    }
+
+    #[inline]
+    fn tracking_resources(&self) -> bool {
+        (**self).tracking_resources()
+    }
+
+    #[inline]
+    fn track_resources(&mut self, track: bool) {
+        (**self).track_resources(track);
+    }
+
+    #[inline]
+    fn track_resource(&mut self, xid: XID, kind: ResourceKind) {
+        (**self).track_resource(xid, kind);
+    }
+
+    #[inline]
+    fn untrack_resource(&mut self, xid: XID) {
+        (**self).untrack_resource(xid);
+    }
+
+    #[inline]
+    fn add_orphan_reply(&mut self, sequence: u16, reply: &[u8]) {
+        (**self).add_orphan_reply(sequence, reply);
+    }
+
+    #[inline]
+    fn orphan_reply_count(&self) -> usize {
+        (**self).orphan_reply_count()
+    }
+
+    #[inline]
+    fn on_orphan_reply(&mut self, hook: Option<OrphanReplyHook>) {
+        (**self).on_orphan_reply(hook);
+    }
+
+    #[inline]
+    fn register_event_decoder(
+        &mut self,
+        extension: &'static str,
+        event_count: u8,
+        decoder: EventDecoder,
+    ) {
+        (**self).register_event_decoder(extension, event_count, decoder);
+    }
+
+    #[inline]
+    fn event_decoder(&self, code: u8) -> Option<(u8, EventDecoder)> {
+        (**self).event_decoder(code)
+    }
+
+    #[inline]
+    fn has_event_decoder(&self, extension: &str) -> bool {
+        (**self).has_event_decoder(extension)
+    }
 }
 
 /// A wrapper around a synchronous connection to the X11 server.
@@ -383,15 +862,92 @@ pub trait Display: DisplayBase {
         Ok(())
     }
 
+    /// Wait for the server to process every request sent so far, returning the first error that any of them
+    /// caused. This is the safe way to find out whether a batch of requests sent on an unchecked display
+    /// succeeded; otherwise, their errors only turn up whenever the display next reads from the connection.
+    ///
+    /// Requests are written to the connection as soon as they are sent, so there is nothing to flush before
+    /// the round trip. Unlike `synchronize`, which stops at the first error, this keeps reading until the
+    /// round trip is over, so the display is in sync afterwards even if the batch failed. Errors caused by
+    /// requests that are still tracked, such as requests with replies, are returned when those requests are
+    /// resolved instead.
+    #[inline]
+    fn flush_and_sync(&mut self) -> crate::Result {
+        let mut gifr = RequestInfo::from_request(
+            GetInputFocusRequest::default(),
+            self.bigreq_enabled(),
+            self.max_request_len(),
+        );
+        gifr.discard_reply = true;
+        let sequence = self.send_request_raw(gifr)?;
+
+        let mut first_error = None;
+        while self.get_pending_request(sequence).is_some() {
+            match self.wait() {
+                Ok(()) => {}
+                Err(err @ BreadError::XProtocol { .. }) => {
+                    first_error.get_or_insert(err);
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        first_error.map_or(Ok(()), Err)
+    }
+
+    /// Measure how long it takes for a request to reach the server and its reply to come back. This is
+    /// done by synchronizing the display, so any replies that were already on their way have to be read
+    /// first, and the measurement is only accurate when nothing else is outstanding.
+    ///
+    /// Clients on slow connections can use this to decide how much to pipeline requests rather than
+    /// waiting on each reply.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn round_trip_time(&mut self) -> crate::Result<Duration> {
+        let start = Instant::now();
+        self.synchronize()?;
+        Ok(start.elapsed())
+    }
+
+    /// Measure the average time a round trip to the server takes over `probes` round trips, which smooths
+    /// out jitter in the connection. Each probe is one request.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn average_round_trip_time(&mut self, probes: u32) -> crate::Result<Duration> {
+        let probes = probes.max(1);
+        let mut total = Duration::default();
+        for _ in 0..probes {
+            total += self.round_trip_time()?;
+        }
+        Ok(total / probes)
+    }
+
+    /// Send a `QueryExtension` request for an extension without waiting for the reply. Sending a request to an
+    /// extension that hasn't been queried does this on its own, holding the request back until the reply
+    /// arrives; querying ahead of time lets the reply arrive sooner, and is also used by `has_extension`. Does
+    /// nothing if the extension has already been queried.
+    #[inline]
+    fn prefetch_extension(&mut self, name: &'static str) -> crate::Result {
+        if output::extension_query_needed(self, name) {
+            let qer = output::extension_query_request(self, name);
+            self.send_request_raw(qer)?;
+        }
+
+        Ok(())
+    }
+
     /// Resolve for a request, returning only the raw data of the reply. The default implementation assumes that
     /// the reply is not zero-sized.
     #[inline]
     fn resolve_request_raw(&mut self, req_id: u16) -> crate::Result<PendingReply> {
         loop {
-            match self.take_pending_reply(req_id) {
-                Some(p) => return Ok(p),
-                None => self.wait()?,
+            if let Some(p) = self.take_pending_reply(req_id) {
+                return Ok(p);
             }
+
+            // the server sends an error instead of a reply if the request failed
+            self.check_for_pending_error(req_id)?;
+            self.wait()?;
         }
     }
 
@@ -469,6 +1025,12 @@ pub trait DisplayExt {
     /// Send a request to the server.
     fn send_request<R: Request>(&mut self, request: R) -> crate::Result<RequestCookie<R>>;
 
+    /// Send a request to the server, keeping track of any error it causes even if the display isn't
+    /// checked. Resolving the cookie waits for the server to process the request and returns that error.
+    ///
+    /// This is only useful for requests without replies; requests with replies are always tracked.
+    fn send_request_checked<R: Request>(&mut self, request: R) -> crate::Result<RequestCookie<R>>;
+
     /// Resolve a request that we sent to the server.
     fn resolve_request<R: Request>(&mut self, token: RequestCookie<R>) -> crate::Result<R::Reply>
     where
@@ -485,6 +1047,28 @@ pub trait DisplayExt {
         log::info!("Resolving request...");
         self.resolve_request(tok)
     }
+
+    /// Send a request that has already been serialized to the server, bypassing the `Request` trait. This is
+    /// intended for tools like proxies that forward requests they don't model.
+    ///
+    /// `bytes` is sent as-is, so it must be a complete request: the opcode (and, for extension requests, the
+    /// major opcode the server assigned to the extension) and the length field must already be filled in, and
+    /// it must be padded to a multiple of four bytes. `has_reply` tells the display whether the server will
+    /// send a reply to this request.
+    fn send_raw_request(
+        &mut self,
+        bytes: &[u8],
+        fds: Vec<Fd>,
+        has_reply: bool,
+    ) -> crate::Result<RawRequestCookie>;
+
+    /// Wait for the reply to a request sent with `send_raw_request`, returning its raw bytes and file
+    /// descriptors. Returns `None` if the request has no reply.
+    fn read_raw_reply(&mut self, cookie: RawRequestCookie) -> crate::Result<Option<PendingReply>>;
+
+    /// Tell whether or not the server supports an extension. The server is only asked the first time;
+    /// after that, the answer is cached, whether or not the extension is present.
+    fn has_extension(&mut self, name: &str) -> crate::Result<bool>;
 }
 
 impl<D: Display + ?Sized> DisplayExt for D {
@@ -495,15 +1079,25 @@ impl<D: Display + ?Sized> DisplayExt for D {
         Ok(RequestCookie::from_sequence(req_id))
     }
 
+    #[inline]
+    fn send_request_checked<R: Request>(&mut self, request: R) -> crate::Result<RequestCookie<R>> {
+        let mut r =
+            RequestInfo::from_request(request, self.bigreq_enabled(), self.max_request_len());
+        r.checked = true;
+        let req_id = self.send_request_raw(r)?;
+        Ok(RequestCookie::from_sequence(req_id))
+    }
+
     #[inline]
     fn resolve_request<R: Request>(&mut self, token: RequestCookie<R>) -> crate::Result<R::Reply>
     where
         R::Reply: Default,
     {
         if mem::size_of::<R::Reply>() == 0 {
-            if self.checked() {
+            // requests without replies are only tracked if they were sent checked
+            let seq = token.sequence();
+            if self.checked() || self.get_pending_request(seq).is_some() {
                 self.synchronize()?;
-                let seq = token.sequence();
                 self.take_pending_request(seq);
                 self.check_for_pending_error(seq)?;
             }
@@ -514,6 +1108,71 @@ impl<D: Display + ?Sized> DisplayExt for D {
         let PendingReply { data, fds } = self.resolve_request_raw(token.sequence())?;
         decode_reply::<R>(&data, fds)
     }
+
+    #[inline]
+    fn send_raw_request(
+        &mut self,
+        bytes: &[u8],
+        fds: Vec<Fd>,
+        has_reply: bool,
+    ) -> crate::Result<RawRequestCookie> {
+        if bytes.len() < 4 || bytes.len() & 0x03 != 0 {
+            return Err(BreadError::StaticMsg(
+                "Raw request must be a non-empty multiple of four bytes",
+            ));
+        }
+        if bytes.len() > self.max_request_len() {
+            return Err(BreadError::StaticMsg(
+                "Raw request was larger than the maximum request length",
+            ));
+        }
+
+        let r = RequestInfo::from_raw(bytes, fds, has_reply)
+            .ok_or(BreadError::StaticMsg("Raw request must not be empty"))?;
+        let req_id = self.send_request_raw(r)?;
+        Ok(RawRequestCookie {
+            sequence: req_id,
+            has_reply,
+        })
+    }
+
+    #[inline]
+    fn read_raw_reply(&mut self, cookie: RawRequestCookie) -> crate::Result<Option<PendingReply>> {
+        if !cookie.has_reply {
+            let seq = cookie.sequence;
+            if self.checked() || self.get_pending_request(seq).is_some() {
+                self.synchronize()?;
+                self.take_pending_request(seq);
+                self.check_for_pending_error(seq)?;
+            }
+
+            return Ok(None);
+        }
+
+        self.resolve_request_raw(cookie.sequence).map(Some)
+    }
+
+    #[inline]
+    fn has_extension(&mut self, name: &str) -> crate::Result<bool> {
+        let key = output::str_to_key(name);
+        match self.get_extension_presence(&key) {
+            ExtensionPresence::Present(_) => Ok(true),
+            ExtensionPresence::Absent => Ok(false),
+            ExtensionPresence::Unknown => {
+                // if we've already sent a query for this extension, use its reply
+                let repl = match self.take_pending_extension_query(&key) {
+                    Some(req_id) => self.resolve_request(
+                        RequestCookie::<QueryExtensionRequest>::from_sequence(req_id),
+                    )?,
+                    None => self.exchange_request(QueryExtensionRequest {
+                        name: String::from(name),
+                        ..Default::default()
+                    })?,
+                };
+                Ok(output::cache_extension_query(self, key, &repl))
+            }
+        }
+    }
 }
 
 /// Monomorphized methods we can't put into the `AsyncDisplay` trait proper.
@@ -528,6 +1187,13 @@ pub trait AsyncDisplayExt: AsyncDisplay {
     /// Send a request to the server.
     fn send_request_async<R: Request>(&mut self, request: R) -> SendRequestFuture<'_, Self, R>;
 
+    /// Send a request to the server, keeping track of any error it causes even if the display isn't
+    /// checked, async redox.
+    fn send_request_checked_async<R: Request>(
+        &mut self,
+        request: R,
+    ) -> SendRequestFuture<'_, Self, R>;
+
     /// Resolve a request that we sent to the server.
     fn resolve_request_async<R: Request>(
         &mut self,
@@ -539,6 +1205,15 @@ pub trait AsyncDisplayExt: AsyncDisplay {
     /// Synchronize this display so that every request that has been sent is resolved.
     fn synchronize_async(&mut self) -> SynchronizeFuture<'_, Self>;
 
+    /// Send a `QueryExtension` request for an extension without waiting for the reply, async redox.
+    fn prefetch_extension_async(
+        &mut self,
+        name: &'static str,
+    ) -> EitherFuture<
+        Ready<crate::Result>,
+        MapFuture<SendRequestRawFuture<'_, Self>, fn(crate::Result<u16>) -> crate::Result>,
+    >;
+
     /// Resolve for a raw request.
     fn resolve_request_raw_async(&mut self, req_id: u16) -> ResolveRequestRawFuture<'_, Self>;
 
@@ -559,6 +1234,9 @@ pub trait AsyncDisplayExt: AsyncDisplay {
         &mut self,
         to_request: F,
     ) -> ExchangeXidFuture<'_, Self, R, U, F>;
+
+    /// Tell whether or not the server supports an extension, async redox.
+    fn has_extension_async(&mut self, name: &str) -> HasExtensionFuture<'_, Self>;
 }
 
 #[cfg(feature = "async")]
@@ -578,6 +1256,14 @@ impl<D: AsyncDisplay + ?Sized> AsyncDisplayExt for D {
         SendRequestFuture::run(self, request)
     }
 
+    #[inline]
+    fn send_request_checked_async<R: Request>(
+        &mut self,
+        request: R,
+    ) -> SendRequestFuture<'_, Self, R> {
+        SendRequestFuture::run_checked(self, request)
+    }
+
     #[inline]
     fn resolve_request_async<R: Request>(
         &mut self,
@@ -594,6 +1280,26 @@ impl<D: AsyncDisplay + ?Sized> AsyncDisplayExt for D {
         SynchronizeFuture::run(self)
     }
 
+    #[inline]
+    fn prefetch_extension_async(
+        &mut self,
+        name: &'static str,
+    ) -> EitherFuture<
+        Ready<crate::Result>,
+        MapFuture<SendRequestRawFuture<'_, Self>, fn(crate::Result<u16>) -> crate::Result>,
+    > {
+        if output::extension_query_needed(self, name) {
+            let qer = output::extension_query_request(self, name);
+            EitherFuture::Right {
+                future: MapFuture::run(self.send_request_raw_async(qer), |res| res.map(|_| ())),
+            }
+        } else {
+            EitherFuture::Left {
+                future: future::ready(Ok(())),
+            }
+        }
+    }
+
     #[inline]
     fn resolve_request_raw_async(&mut self, req_id: u16) -> ResolveRequestRawFuture<'_, Self> {
         ResolveRequestRawFuture::run(self, req_id)
@@ -624,9 +1330,15 @@ impl<D: AsyncDisplay + ?Sized> AsyncDisplayExt for D {
     ) -> ExchangeXidFuture<'_, Self, R, U, F> {
         ExchangeXidFuture::run(self, to_request)
     }
+
+    #[inline]
+    fn has_extension_async(&mut self, name: &str) -> HasExtensionFuture<'_, Self> {
+        HasExtensionFuture::run(self, name)
+    }
 }
 
 /// Request information, monomorphized from the Request trait.
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct RequestInfo {
     pub(crate) data: TinyVec<[u8; 32]>,
@@ -636,7 +1348,11 @@ pub struct RequestInfo {
     pub(crate) extension: Option<&'static str>,
     pub(crate) expects_fds: bool,
     pub(crate) discard_reply: bool,
+    pub(crate) checked: bool,
     pub(crate) sequence: Option<u16>,
+    pub(crate) queried_extension: Option<&'static str>,
+    pub(crate) request_name: Option<&'static str>,
+    pub(crate) declared_fds: Option<usize>,
 }
 
 impl RequestInfo {
@@ -645,6 +1361,7 @@ impl RequestInfo {
     pub fn from_request<R: Request>(mut req: R, use_bigreq: bool, max_request_len: usize) -> Self {
         const SHORT_REQUEST_LIMIT: usize = (u16::MAX as usize) * 4;
         debug_assert!(use_bigreq || max_request_len <= SHORT_REQUEST_LIMIT);
+        let declared_fds = req.fd_count();
 
         // TODO: somehow write using uninitialzied data
         let mut data = iter::repeat(0)
@@ -659,7 +1376,8 @@ impl RequestInfo {
         // note: we assume max_request_len is already normalized
         assert!(
             max_request_len >= len,
-            "Request's size was larger than the maximum request length"
+            "{} request's size was larger than the maximum request length",
+            R::NAME
         );
 
         // If we fit in the short request limit, third and fourth bytes need to be length
@@ -698,7 +1416,73 @@ impl RequestInfo {
             extension: R::EXTENSION,
             expects_fds: R::REPLY_EXPECTS_FDS,
             discard_reply: false,
+            checked: false,
+            sequence: None,
+            queried_extension: None,
+            request_name: Some(R::NAME),
+            declared_fds: Some(declared_fds),
+        }
+    }
+
+    /// Generate a `RequestInfo` from a request that has already been serialized. `data` is sent as-is, so its
+    /// opcodes and length field must already be filled in. This can be used alongside `send_request_raw` and
+    /// `resolve_request_raw`, or their async counterparts, to forward requests that `breadx` doesn't model.
+    /// Returns `None` if `data` is empty, since then it doesn't even have an opcode.
+    #[inline]
+    #[must_use]
+    pub fn from_raw(data: &[u8], fds: Vec<Fd>, has_reply: bool) -> Option<Self> {
+        let opcode = *data.first()?;
+        Some(RequestInfo {
+            data: data.iter().copied().collect(),
+            fds,
+            zero_sized_reply: !has_reply,
+            opcode,
+            extension: None,
+            expects_fds: false,
+            discard_reply: false,
+            checked: false,
             sequence: None,
+            queried_extension: None,
+            request_name: None,
+            declared_fds: None,
+        })
+    }
+
+    /// The name of the request this was generated from, or `None` if it was built from raw bytes.
+    #[inline]
+    #[must_use]
+    pub fn request_name(&self) -> Option<&'static str> {
+        self.request_name
+    }
+
+    /// The name of the request, for use in log and error messages.
+    #[inline]
+    pub(crate) fn describe(&self) -> &'static str {
+        self.request_name.unwrap_or("raw")
+    }
+
+    /// Make sure the request carries as many file descriptors as it declares. Requests built from raw bytes
+    /// don't declare anything, so they're always let through. The request won't be sent if the count is
+    /// wrong, so the file descriptors it carries are closed.
+    #[inline]
+    pub(crate) fn check_fd_count(&mut self) -> crate::Result {
+        match self.declared_fds {
+            Some(expected) if expected != self.fds.len() => {
+                let got = self.fds.len();
+                log::error!(
+                    "{} request declares {} file descriptors, but carries {}",
+                    self.describe(),
+                    expected,
+                    got
+                );
+                close_fds(mem::take(&mut self.fds));
+                Err(BreadError::FdCountMismatch {
+                    request: self.describe(),
+                    expected,
+                    got,
+                })
+            }
+            _ => Ok(()),
         }
     }
 
@@ -753,6 +1537,60 @@ pub struct PendingReply {
     pub fds: Box<[Fd]>,
 }
 
+/// The replies that have been read but not yet taken, by sequence number. Some requests, like
+/// `ListFontsWithInfo`, get several replies with the same sequence number, so the replies for each sequence
+/// number are kept in the order they arrived in.
+#[derive(Debug, Default)]
+pub(crate) struct PendingReplies {
+    replies: HashMap<u16, VecDeque<PendingReply>>,
+}
+
+impl PendingReplies {
+    #[inline]
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Self {
+            replies: HashMap::with_capacity(capacity),
+        }
+    }
+
+    /// Queue a reply behind any others with the same sequence number.
+    #[inline]
+    pub(crate) fn insert(&mut self, sequence: u16, reply: PendingReply) {
+        self.replies.entry(sequence).or_default().push_back(reply);
+    }
+
+    /// Take the oldest reply with the given sequence number.
+    #[inline]
+    pub(crate) fn remove(&mut self, sequence: u16) -> Option<PendingReply> {
+        let queue = self.replies.get_mut(&sequence)?;
+        let reply = queue.pop_front();
+        if queue.is_empty() {
+            self.replies.remove(&sequence);
+        }
+        reply
+    }
+
+    /// The number of replies waiting to be taken.
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.replies.values().map(VecDeque::len).sum()
+    }
+
+    #[inline]
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        self.replies.reserve(additional);
+    }
+
+    /// Drop every reply, closing the file descriptors that came with them.
+    #[inline]
+    pub(crate) fn close(&mut self) {
+        self.replies
+            .drain()
+            .flat_map(|(_, queue)| queue)
+            .for_each(|reply| close_fds(reply.fds.into_vec()));
+    }
+}
+
 /// A cookie for a request.
 ///
 /// Requests usually take time to resolve into replies. Therefore, the `Display::send_request` method returns
@@ -787,19 +1625,138 @@ impl<R: Request> RequestCookie<R> {
     pub fn sequence(self) -> u16 {
         self.sequence
     }
+
+    /// Get the reply to this request if it has already been read from the connection, without waiting for
+    /// it. This returns `Ok(None)` if the reply hasn't arrived yet, in which case the cookie can still be
+    /// resolved later, and returns the error the server sent instead of a reply, if it sent one.
+    ///
+    /// Requests without replies never have one to take, so this only ever reports their errors.
+    #[inline]
+    pub fn try_reply<D: DisplayBase + ?Sized>(
+        &self,
+        display: &mut D,
+    ) -> crate::Result<Option<R::Reply>> {
+        if let Some(PendingReply { data, fds }) = display.take_pending_reply(self.sequence) {
+            decode_reply::<R>(&data, fds).map(Some)
+        } else {
+            display.check_for_pending_error(self.sequence)?;
+            Ok(None)
+        }
+    }
+
+    /// Wait for the reply to this request, but return its bytes and file descriptors as they came off of the
+    /// wire instead of decoding them. This is meant for debugging replies that the generated code can't
+    /// make sense of; `DisplayExt::resolve_request` is what should normally be used.
+    ///
+    /// Requests without replies have no bytes to return, so this fails for them.
+    #[inline]
+    pub fn reply_raw<D: Display + ?Sized>(self, display: &mut D) -> crate::Result<PendingReply> {
+        if size_of::<R::Reply>() == 0 {
+            return Err(BreadError::StaticMsg("Request does not have a reply"));
+        }
+
+        display.resolve_request_raw(self.sequence)
+    }
+
+    /// Wait for the undecoded reply to this request, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn reply_raw_async<D: AsyncDisplay + ?Sized>(
+        self,
+        display: &mut D,
+    ) -> crate::Result<PendingReply> {
+        if size_of::<R::Reply>() == 0 {
+            return Err(BreadError::StaticMsg("Request does not have a reply"));
+        }
+
+        display.resolve_request_raw_async(self.sequence).await
+    }
+
+    /// Poll for the reply to this request, reading from the connection while it hasn't arrived. Unlike
+    /// `AsyncDisplayExt::resolve_request_async`, this doesn't hold onto the display between polls, so it can
+    /// be used in a hand-written future alongside other work.
+    ///
+    /// Requests without replies resolve immediately, unless an error for them has already arrived.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub fn poll_reply<D: AsyncDisplay + ?Sized>(
+        &self,
+        display: &mut D,
+        cx: &mut Context<'_>,
+    ) -> Poll<crate::Result<R::Reply>>
+    where
+        R::Reply: Default,
+    {
+        if mem::size_of::<R::Reply>() == 0 {
+            return Poll::Ready(
+                display
+                    .check_for_pending_error(self.sequence)
+                    .map(|()| Default::default()),
+            );
+        }
+
+        loop {
+            match self.try_reply(display) {
+                Ok(Some(reply)) => return Poll::Ready(Ok(reply)),
+                Ok(None) => (),
+                Err(e) => return Poll::Ready(Err(e)),
+            }
+
+            match display.poll_wait(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Ok(())) => (),
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+            }
+        }
+    }
+}
+
+/// A cookie for a request sent with `DisplayExt::send_raw_request`.
+#[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Default, Eq, Hash)]
+pub struct RawRequestCookie {
+    sequence: u16,
+    has_reply: bool,
+}
+
+impl RawRequestCookie {
+    /// The sequence number the request was sent with, which the server uses to tag its reply and any error
+    /// that the request causes.
+    #[inline]
+    #[must_use]
+    pub fn sequence(self) -> u16 {
+        self.sequence
+    }
+
+    /// Whether or not the server will send a reply to this request.
+    #[inline]
+    #[must_use]
+    pub fn has_reply(self) -> bool {
+        self.has_reply
+    }
 }
 
+/// A function called with the sequence number and bytes of a reply that no request was waiting on.
+pub type OrphanReplyHook = fn(u16, &[u8]);
+
+/// A function that decodes an extension's events. It is given an event that hasn't been decoded yet and the
+/// extension's first event code, and replaces the event with its decoded form if it recognizes it.
+pub type EventDecoder = fn(&mut Event, u8) -> crate::Result;
+
 #[derive(Debug, Default, Clone, Copy)]
 pub struct PendingRequest {
     pub request: u16,
     pub flags: PendingRequestFlags,
 }
 
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Default, Debug, Copy, Clone)]
 pub struct PendingRequestFlags {
     pub discard_reply: bool,
     pub checked: bool,
     pub expects_fds: bool,
+    /// The server sends a series of replies to this request, as it does for `ListFontsWithInfo`, so it stays
+    /// pending until the last one arrives.
+    pub multiple_replies: bool,
     pub workaround: RequestWorkaround,
 }
 
@@ -829,9 +1786,307 @@ pub(crate) fn decode_reply<R: Request>(reply: &[u8], fds: Box<[Fd]>) -> crate::R
     Ok(r)
 }
 
+/// Clamp a number of pending requests to reserve room for to the number of sequence numbers there are.
+#[inline]
+pub(crate) fn clamp_pending(additional: usize) -> usize {
+    additional.min(usize::from(u16::MAX) + 1)
+}
+
 #[inline]
 pub(crate) fn generate_xid<D: DisplayBase + ?Sized>(display: &mut D) -> crate::Result<XID> {
     display
         .generate_xid()
         .ok_or(crate::BreadError::StaticMsg("Ran out of XIDs"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{mock_display, mock_setup, DisplayBase, DisplayExt, MockConnection};
+    use crate::{
+        auto::xproto::{BellRequest, GetInputFocusRequest},
+        BreadError,
+    };
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reply_outlives_display() {
+        use crate::auto::xproto::{GetInputFocusReply, Window};
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_reply(&GetInputFocusReply {
+            reply_type: 1,
+            sequence: 2,
+            focus: Window::const_from_xid(0x0200_0001),
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        let tok = display
+            .send_request(GetInputFocusRequest::default())
+            .unwrap();
+        let reply = display.resolve_request(tok).unwrap();
+        drop(display);
+
+        // the reply owns all of its data, so it can be handed to another thread
+        let focus = std::thread::spawn(move || reply.focus).join().unwrap();
+        assert_eq!(focus, Window::const_from_xid(0x0200_0001));
+    }
+
+    #[test]
+    fn try_reply_does_not_wait() {
+        use crate::{
+            auto::xproto::{Atom, InternAtomReply, InternAtomRequest},
+            display::Display,
+        };
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_reply(&InternAtomReply {
+            reply_type: 1,
+            sequence: 2,
+            atom: Atom::const_from_xid(300),
+            ..Default::default()
+        });
+        let mut error = [0u8; 32];
+        error[1] = 2;
+        error[2..4].copy_from_slice(&3u16.to_ne_bytes());
+        conn.feed(&error);
+
+        let mut display = mock_display(&mut conn);
+        let first = display.send_request(InternAtomRequest::default()).unwrap();
+        let second = display.send_request(InternAtomRequest::default()).unwrap();
+
+        // nothing has been read yet
+        assert!(first.try_reply(&mut display).unwrap().is_none());
+
+        display.wait().unwrap();
+        let reply = first.try_reply(&mut display).unwrap().unwrap();
+        assert_eq!(reply.atom, Atom::const_from_xid(300));
+        assert!(second.try_reply(&mut display).unwrap().is_none());
+
+        display.wait().unwrap();
+        assert!(second.try_reply(&mut display).is_err());
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn poll_reply_reads_until_ready() {
+        use crate::auto::xproto::{Atom, InternAtomReply, InternAtomRequest};
+        use futures_lite::future;
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_reply(&InternAtomReply {
+            reply_type: 1,
+            sequence: 2,
+            atom: Atom::const_from_xid(300),
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        let cookie = display.send_request(InternAtomRequest::default()).unwrap();
+        let reply =
+            future::block_on(future::poll_fn(|cx| cookie.poll_reply(&mut display, cx))).unwrap();
+        assert_eq!(reply.atom, Atom::const_from_xid(300));
+    }
+
+    #[test]
+    fn void_requests_are_only_tracked_when_checked() {
+        use crate::auto::xproto::GetInputFocusReply;
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        // the checked bell fails, and then the synchronization goes through
+        let mut error = [0u8; 32];
+        error[1] = 2;
+        error[2..4].copy_from_slice(&3u16.to_ne_bytes());
+        conn.feed(&error);
+        conn.feed_reply(&GetInputFocusReply {
+            reply_type: 1,
+            sequence: 4,
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        display.set_checked(false);
+        display.send_request(BellRequest::default()).unwrap();
+        let checked = display
+            .send_request_checked(BellRequest::default())
+            .unwrap();
+        assert!(display.get_pending_request(2).is_none());
+        assert!(display.get_pending_request(3).is_some());
+
+        assert!(matches!(
+            display.resolve_request(checked),
+            Err(BreadError::XProtocol { .. })
+        ));
+    }
+
+    #[test]
+    fn missing_extension_is_cached() {
+        use crate::{
+            auto::xproto::QueryExtensionReply,
+            display::{output::str_to_key, ExtensionPresence},
+        };
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_reply(&QueryExtensionReply {
+            reply_type: 1,
+            sequence: 2,
+            present: false,
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        let key = str_to_key("XKEYBOARD");
+        assert_eq!(
+            display.get_extension_presence(&key),
+            ExtensionPresence::Unknown
+        );
+        assert!(!display.has_extension("XKEYBOARD").unwrap());
+        assert_eq!(
+            display.get_extension_presence(&key),
+            ExtensionPresence::Absent
+        );
+
+        // the second check doesn't go to the server; if it did, there would be no reply for it to read
+        assert!(!display.has_extension("XKEYBOARD").unwrap());
+    }
+
+    #[test]
+    fn requests_are_named() {
+        use crate::{
+            auto::bigreq::EnableRequest,
+            display::{output::str_to_key, RequestInfo},
+        };
+        use alloc::vec::Vec;
+
+        let info = RequestInfo::from_request(GetInputFocusRequest::default(), false, 1024);
+        assert_eq!(info.request_name(), Some("GetInputFocus"));
+        let raw = RequestInfo::from_raw(&[127, 0, 1, 0], Vec::new(), false).unwrap();
+        assert_eq!(raw.request_name(), None);
+        assert!(RequestInfo::from_raw(&[], Vec::new(), false).is_none());
+
+        // a request for a missing extension names itself in the error
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        let mut display = mock_display(&mut conn);
+        display.set_extension_absent(str_to_key("BIG-REQUESTS"));
+        assert!(matches!(
+            display.send_request(EnableRequest::default()),
+            Err(BreadError::ExtensionNotPresent { ref extension, request: "Enable" })
+                if extension == "BIG-REQUESTS"
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn round_trip_time_probes_once_each() {
+        use crate::{auto::xproto::GetInputFocusReply, display::Display};
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        for sequence in 2..=4 {
+            conn.feed_reply(&GetInputFocusReply {
+                reply_type: 1,
+                sequence,
+                ..Default::default()
+            });
+        }
+
+        let mut display = mock_display(&mut conn);
+        display.round_trip_time().unwrap();
+        display.average_round_trip_time(2).unwrap();
+        // the probes' replies are discarded rather than kept around
+        assert_eq!(display.unread_reply_count(), 0);
+        assert_eq!(display.awaited_reply_count(), 0);
+        drop(display);
+        assert_eq!(conn.incoming_len(), 0);
+    }
+
+    #[test]
+    fn raw_reply_is_undecoded() {
+        use crate::auto::xproto::{Atom, BellRequest, InternAtomReply, InternAtomRequest};
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_reply(&InternAtomReply {
+            reply_type: 1,
+            sequence: 2,
+            atom: Atom::const_from_xid(0x0102_0304),
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        let tok = display.send_request(InternAtomRequest::default()).unwrap();
+        let raw = tok.reply_raw(&mut display).unwrap();
+        assert_eq!(raw.data.len(), 32);
+        assert_eq!(raw.data[0], 1);
+        assert_eq!(&raw.data[8..12], &0x0102_0304u32.to_ne_bytes());
+        assert!(raw.fds.is_empty());
+
+        // there's nothing to wait for if the request has no reply
+        display.set_checked(false);
+        let tok = display.send_request(BellRequest::default()).unwrap();
+        assert!(tok.reply_raw(&mut display).is_err());
+    }
+
+    #[test]
+    fn flush_and_sync_waits_out_errors() {
+        use crate::{auto::xproto::GetInputFocusReply, display::Display};
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        // both bells fail with a Value error
+        for sequence in 2u16..4 {
+            let mut error = [0; 32];
+            error[1] = 2;
+            error[2..4].copy_from_slice(&sequence.to_ne_bytes());
+            conn.feed(&error);
+        }
+        conn.feed_reply(&GetInputFocusReply {
+            reply_type: 1,
+            sequence: 4,
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        display.set_checked(false);
+        display.send_request(BellRequest::default()).unwrap();
+        display.send_request(BellRequest::default()).unwrap();
+        match display.flush_and_sync() {
+            Err(BreadError::XProtocol { sequence: 2, .. }) => (),
+            res => panic!("expected the first bell's error, got {:?}", res),
+        }
+        drop(display);
+
+        // the whole round trip was read
+        assert_eq!(conn.incoming_len(), 0);
+    }
+
+    #[test]
+    fn replies_sharing_a_sequence_are_kept_in_order() {
+        use crate::{
+            auto::xproto::{ListFontsWithInfoReply, ListFontsWithInfoRequest},
+            display::{Display, RequestCookie},
+        };
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        for name in &["misc", "6x13"] {
+            conn.feed_reply(&ListFontsWithInfoReply {
+                reply_type: 1,
+                sequence: 2,
+                length: 8,
+                name: (*name).into(),
+                ..Default::default()
+            });
+        }
+
+        let mut display = mock_display(&mut conn);
+        let tok = display
+            .send_request(ListFontsWithInfoRequest::default())
+            .unwrap();
+        // both replies are read before either is taken
+        display.wait().unwrap();
+        display.wait().unwrap();
+        assert_eq!(display.unread_reply_count(), 2);
+        let sequence = tok.sequence();
+        let cookie = || RequestCookie::<ListFontsWithInfoRequest>::from_sequence(sequence);
+        assert_eq!(display.resolve_request(cookie()).unwrap().name, "misc");
+        assert_eq!(display.resolve_request(cookie()).unwrap().name, "6x13");
+        assert_eq!(display.unread_reply_count(), 0);
+    }
+}
@@ -6,7 +6,7 @@
 #![cfg(feature = "std")]
 
 use super::Connection;
-use crate::Fd;
+use crate::{auth_info::AuthTarget, Fd};
 use alloc::{borrow::Cow, format, string::String, vec::Vec};
 use core::mem;
 use memchr::memrchr;
@@ -25,7 +25,10 @@ use std::net::ToSocketAddrs;
 use std::borrow::ToOwned;
 
 #[cfg(unix)]
-use std::os::unix::net as unet;
+use std::os::unix::{
+    io::{AsRawFd, RawFd},
+    net as unet,
+};
 
 #[cfg(all(feature = "async", unix))]
 use async_net::unix as async_unet;
@@ -58,6 +61,26 @@ impl Connection for NameConnection {
             NameConnection::Socket(s) => s.read_packet(bytes, fds),
         }
     }
+
+    #[inline]
+    fn shutdown(&mut self) -> crate::Result {
+        match self {
+            NameConnection::Tcp(t) => Connection::shutdown(t),
+            #[cfg(unix)]
+            NameConnection::Socket(s) => Connection::shutdown(s),
+        }
+    }
+}
+
+#[cfg(unix)]
+impl AsRawFd for NameConnection {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        match self {
+            NameConnection::Tcp(t) => t.as_raw_fd(),
+            NameConnection::Socket(s) => s.as_raw_fd(),
+        }
+    }
 }
 
 impl<'a> Connection for &'a NameConnection {
@@ -90,6 +113,21 @@ impl<'a> Connection for &'a NameConnection {
             }
         }
     }
+
+    #[inline]
+    fn shutdown(&mut self) -> crate::Result {
+        match self {
+            NameConnection::Tcp(ref t) => {
+                let mut t = t;
+                Connection::shutdown(&mut t)
+            }
+            #[cfg(unix)]
+            NameConnection::Socket(ref s) => {
+                let mut s = s;
+                Connection::shutdown(&mut s)
+            }
+        }
+    }
 }
 
 #[cfg(feature = "async")]
@@ -426,7 +464,8 @@ impl<'a> XConnection<'a> {
         Err(crate::BreadError::UnableToOpenConnection)
     }
 
-    /// Open the connection via TCP, async redox.
+    /// Open the connection via TCP, async redox. `async-io` has no resolver of its own, so the host name is
+    /// resolved on `blocking`'s thread pool.
     #[cfg(feature = "async")]
     #[inline]
     async fn open_tcp_async(self) -> crate::Result<AsyncNameConnection> {
@@ -464,6 +503,46 @@ impl<'a> XConnection<'a> {
         Ok(AsyncNameConnection::Socket(conn))
     }
 
+    /// Open the connection via TCP using `tokio`. The host name is resolved on `tokio`'s blocking pool.
+    #[cfg(all(feature = "tokio-support", unix))]
+    #[inline]
+    async fn open_tcp_tokio(self) -> crate::Result<NameConnection> {
+        let (host, port) = self.host_and_port();
+        let connection = tokio::net::TcpStream::connect((&*host, port)).await?;
+        Ok(NameConnection::Tcp(connection.into_std()?))
+    }
+
+    /// Open a socket file on Unix using `tokio`.
+    #[cfg(all(feature = "tokio-support", unix))]
+    #[inline]
+    async fn open_unix_tokio(self) -> crate::Result<NameConnection> {
+        let fname = self.socket_filename()?;
+        let connection = tokio::net::UnixStream::connect(&*fname).await?;
+        Ok(NameConnection::Socket(connection.into_std()?))
+    }
+
+    /// Open a connection using `tokio`. The socket is connected on `tokio`'s reactor, but it is handed back
+    /// deregistered and in non-blocking mode.
+    #[cfg(all(feature = "tokio-support", unix))]
+    pub(crate) async fn open_tokio(mut self) -> crate::Result<NameConnection> {
+        // if the protocol or hostname isn't "unix", just run the tcp code
+        if self.protocol != Some(Protocol::Unix)
+            || (self.host.is_none() || self.host.as_deref().unwrap() != "unix")
+        {
+            if let Ok(c) = self.clone().open_tcp_tokio().await {
+                return Ok(c);
+            }
+        }
+
+        if let Ok(u) = self.clone().open_unix_tokio().await {
+            return Ok(u);
+        }
+
+        // add the display name to the host
+        self.host = Some(Cow::Owned(format!("{}{}", PART1, self.display)));
+        self.open_unix_tokio().await
+    }
+
     /// Open an asynchronous connection.
     #[allow(unused_mut)]
     #[cfg(feature = "async")]
@@ -495,15 +574,53 @@ impl<'a> XConnection<'a> {
     }
 }
 
+/// The server to pick an `XAUTHORITY` entry for, given the address of the TCP peer, if there is one.
+#[inline]
+fn auth_target(peer: Option<std::io::Result<net::SocketAddr>>, display: u16) -> AuthTarget {
+    match peer {
+        Some(Ok(addr)) => AuthTarget::ip(addr.ip(), display),
+        Some(Err(e)) => {
+            log::warn!("Unable to get the address of the server: {}", e);
+            AuthTarget::local(display)
+        }
+        None => AuthTarget::local(display),
+    }
+}
+
 impl NameConnection {
-    /// Open a new connection.
+    /// Open a new connection. Along with the screen, this returns the server that authorization
+    /// information should be looked up for.
     #[inline]
     pub(crate) fn connect_internal(
         name: Option<Cow<'_, str>>,
-    ) -> crate::Result<(NameConnection, usize)> {
+    ) -> crate::Result<(NameConnection, usize, AuthTarget)> {
         let connection = XConnection::parse(name)?;
-        let screen = connection.screen;
-        Ok((connection.open()?, screen))
+        let (screen, display) = (connection.screen, connection.display);
+        let connection = connection.open()?;
+        let target = connection.auth_target(display);
+        Ok((connection, screen, target))
+    }
+
+    /// Open a new connection on `tokio`'s reactor.
+    #[cfg(all(feature = "tokio-support", unix))]
+    #[inline]
+    pub(crate) async fn connect_internal_tokio(
+        name: Option<Cow<'_, str>>,
+    ) -> crate::Result<(NameConnection, usize, AuthTarget)> {
+        let connection = XConnection::parse(name)?;
+        let (screen, display) = (connection.screen, connection.display);
+        let connection = connection.open_tokio().await?;
+        let target = connection.auth_target(display);
+        Ok((connection, screen, target))
+    }
+
+    #[inline]
+    fn auth_target(&self, display: u16) -> AuthTarget {
+        match self {
+            NameConnection::Tcp(t) => auth_target(Some(t.peer_addr()), display),
+            #[cfg(unix)]
+            NameConnection::Socket(_) => auth_target(None, display),
+        }
     }
 }
 
@@ -514,10 +631,21 @@ impl AsyncNameConnection {
     #[cfg(feature = "async")]
     pub(crate) async fn connect_internal_async(
         name: Option<Cow<'_, str>>,
-    ) -> crate::Result<(AsyncNameConnection, usize)> {
+    ) -> crate::Result<(AsyncNameConnection, usize, AuthTarget)> {
         let connection = XConnection::parse(name)?;
-        let screen = connection.screen;
-        Ok((connection.open_async().await?, screen))
+        let (screen, display) = (connection.screen, connection.display);
+        let connection = connection.open_async().await?;
+        let target = connection.auth_target(display);
+        Ok((connection, screen, target))
+    }
+
+    #[inline]
+    fn auth_target(&self, display: u16) -> AuthTarget {
+        match self {
+            AsyncNameConnection::Tcp(t) => auth_target(Some(t.get_ref().peer_addr()), display),
+            #[cfg(unix)]
+            AsyncNameConnection::Socket(_) => auth_target(None, display),
+        }
     }
 }
 
@@ -1,13 +1,24 @@
 // MIT/Apache2 License
 
 use super::{
-    decode_reply, input, Connection, Display, DisplayBase, PendingReply, PendingRequestFlags,
-    RequestInfo, RequestWorkaround, EXT_KEY_SIZE,
+    decode_reply, input, Connection, Display, DisplayBase, ExtensionPresence, PendingReply,
+    PendingRequestFlags, RequestInfo, RequestWorkaround, EXT_KEY_SIZE,
+};
+use crate::{
+    auto::xproto::{
+        CloseDown, ListFontsWithInfoRequest, NoOperationRequest, QueryExtensionReply,
+        QueryExtensionRequest, SetCloseDownModeRequest,
+    },
+    log_debug, log_trace,
+    util::close_fds,
+    Request,
 };
-use crate::{auto::xproto::QueryExtensionRequest, log_debug, log_trace};
 use alloc::string::ToString;
 use core::mem;
 
+#[cfg(feature = "ge")]
+use crate::auto::ge::QueryVersionRequest as GeQueryVersionRequest;
+
 #[cfg(feature = "async")]
 use super::AsyncConnection;
 
@@ -22,6 +33,17 @@ pub(crate) fn preprocess_request<D: DisplayBase + ?Sized>(
     let sequence = sequence as u16;
 
     pr.set_sequence(sequence);
+
+    // resources the client asked the server to keep around after it disconnects shouldn't be freed when the
+    // display is dropped
+    if pr.extension.is_none()
+        && pr.opcode == SetCloseDownModeRequest::OPCODE
+        && matches!(pr.data.get(1), Some(&mode) if mode != CloseDown::DestroyAll as u8)
+    {
+        log_debug!("Resources are retained after the connection closes; no longer tracking them");
+        display.track_resources(false);
+    }
+
     pr
 }
 
@@ -30,10 +52,20 @@ pub(crate) fn finish_request<D: DisplayBase + ?Sized>(display: &mut D, mut pr: R
     log_trace!("Entering finish_request() with request info: {:?}", &pr);
 
     // data has already been sent over the bandwaves, make sure we acknowledge it
+    let seq = pr.sequence.take().expect("Failed to set sequence number");
+    log_debug!("Got sequence number {}", seq);
+
+    // if this is an extension query sent ahead of time, remember it so the extension's first request
+    // can use its reply
+    if let Some(extension) = pr.queried_extension {
+        display.add_pending_extension_query(str_to_key(extension), seq);
+    }
+
+    let checked = pr.checked || display.checked();
     let mut flags = PendingRequestFlags {
         expects_fds: pr.expects_fds,
         discard_reply: pr.discard_reply,
-        checked: pr.zero_sized_reply && display.checked(),
+        checked: pr.zero_sized_reply && checked,
         ..Default::default()
     };
 
@@ -50,16 +82,13 @@ pub(crate) fn finish_request<D: DisplayBase + ?Sized>(display: &mut D, mut pr: R
             log_debug!("Applying GLX FbConfig workaround to request");
             flags.workaround = RequestWorkaround::GlxFbconfigBug;
         }
+        // ListFontsWithInfo gets a reply for every font, and then an empty one
+        (None, ListFontsWithInfoRequest::OPCODE, _) => flags.multiple_replies = true,
         _ => (),
     }
 
-    let seq = pr.sequence.take().expect("Failed to set sequence number");
-    log_debug!("Got sequence number {}", seq);
-
-    if !pr.zero_sized_reply || display.checked() {
-        log::trace!(
-            "Request is neither zero-sized nor is the display not checked, so we expect a reply"
-        );
+    if !pr.zero_sized_reply || checked {
+        log::trace!("Request either has a reply or is checked, so we expect something back");
         input::expect_reply(display, seq, flags);
     }
 
@@ -83,29 +112,97 @@ pub(crate) fn modify_for_opcode(bytes: &mut [u8], request_opcode: u8, ext_opcode
     }
 }
 
+/// Whether or not the display has as many outstanding replies as it allows, meaning that we should read from
+/// the connection before sending anything else.
+#[inline]
+pub(crate) fn over_pending_limit<D: DisplayBase + ?Sized>(display: &D) -> bool {
+    let max = display.max_pending();
+    max != usize::MAX && display.awaited_reply_count() + display.unread_reply_count() >= max
+}
+
+/// Read from the connection until there is room for another outstanding reply. If the only outstanding
+/// replies are ones that haven't been read by the user, reading won't help, so we return `WouldBlock`.
+#[inline]
+fn apply_backpressure<D: Display + ?Sized, C: Connection + ?Sized>(
+    display: &mut D,
+    connection: &mut C,
+) -> crate::Result {
+    while over_pending_limit(display) {
+        if display.awaited_reply_count() == 0 {
+            log::warn!("Too many replies have been left unread; refusing to send another request");
+            return Err(crate::BreadError::WouldBlock);
+        }
+
+        log_debug!("Too many replies are outstanding, reading from the connection");
+        input::wait(display, connection)?;
+    }
+
+    Ok(())
+}
+
 #[inline]
 pub(crate) fn send_request<D: Display + ?Sized, C: Connection + ?Sized>(
     display: &mut D,
     connection: &mut C,
-    request_info: RequestInfo,
+    mut request_info: RequestInfo,
 ) -> crate::Result<u16> {
     log_trace!("Entering output::send_request()");
 
+    // a request with the wrong number of fds would hand them to the wrong request on the server's end
+    request_info.check_fd_count()?;
+
+    // send whatever we were holding back that can be sent now, so this request doesn't have to wait behind it
+    send_deferred_requests(display, connection)?;
+
+    // don't let outstanding replies pile up past the display's limit
+    apply_backpressure(display, connection)?;
+
+    // some servers won't send generic events until the client has negotiated the Generic Event Extension
+    #[cfg(feature = "ge")]
+    if let Some(extension) = request_info.extension {
+        if ge_negotiation_needed(display, extension) {
+            match send_request(display, connection, ge_negotiation_request(display)) {
+                // without the extension, there's nothing to negotiate
+                Ok(_) | Err(crate::BreadError::ExtensionNotPresent { .. }) => {
+                    display.set_ge_negotiated();
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
     // figure out the extension opcode
     let ext_opcode = match request_info.extension {
         None => None,
         Some(extension) => {
             let key = str_to_key(extension);
-            if let Some(opcode) = display.get_extension_opcode(&key) {
-                Some(opcode)
-            } else {
-                let opcode = get_ext_opcode(display, connection, extension)?;
-                display.set_extension_opcode(key, opcode);
-                Some(opcode)
+            match display.get_extension_presence(&key) {
+                ExtensionPresence::Present(opcode) => Some(opcode),
+                ExtensionPresence::Absent => {
+                    return Err(extension_not_present(&request_info, extension))
+                }
+                ExtensionPresence::Unknown => {
+                    // ask the server for the opcode, but don't wait for it; the request is held back
+                    // until the reply comes in
+                    if display.pending_extension_query(&key).is_none() {
+                        log_trace!("Sending QER..");
+                        send_request(
+                            display,
+                            connection,
+                            extension_query_request(display, extension),
+                        )?;
+                    }
+                    return Ok(defer_request(display, request_info));
+                }
             }
         }
     };
 
+    // a request can't overtake the ones being held back, or the server would number them differently
+    if display.has_deferred_requests() {
+        return Ok(defer_request(display, request_info));
+    }
+
     // figure out sequence, et al
     let mut req = preprocess_request(display, request_info);
 
@@ -116,53 +213,272 @@ pub(crate) fn send_request<D: Display + ?Sized, C: Connection + ?Sized>(
     // send the packet
     log_debug!("Request is ready to send, beginning send_packet()");
     let mut fds = mem::take(&mut req.fds);
-    connection.send_packet(&req.data, &mut fds)?;
+    if let Err(e) = connection.send_packet(&req.data, &mut fds) {
+        log::error!("Failed to send {} request: {}", req.describe(), e);
+        return Err(e);
+    }
     log_debug!("Finished send_packet()");
 
     Ok(finish_request(display, req))
 }
 
+/// Give a request its sequence number, but hold it back rather than sending it. The display expects its
+/// reply as usual.
 #[inline]
-pub(crate) fn get_ext_opcode<D: Display + ?Sized, C: Connection + ?Sized>(
-    display: &mut D,
-    conn: &mut C,
-    extension: &'static str,
-) -> crate::Result<u8> {
-    log_trace!("Entering get_ext_opcode with extension: {}", extension);
+fn defer_request<D: DisplayBase + ?Sized>(display: &mut D, request_info: RequestInfo) -> u16 {
+    let mut req = preprocess_request(display, request_info);
     log_debug!(
-        "Could not find extension opcode in display's database; sending request to server..."
+        "Holding back {} request until its extension's opcode is known",
+        req.describe()
     );
 
-    let qer = QueryExtensionRequest {
-        name: extension.to_string(),
-        ..Default::default()
+    let held = RequestInfo {
+        data: mem::take(&mut req.data),
+        fds: mem::take(&mut req.fds),
+        queried_extension: None,
+        ..req.clone()
     };
+    display.defer_request(held);
+    finish_request(display, req)
+}
 
-    log_trace!("Sending QER..");
-    let tok = send_request(
-        display,
-        conn,
-        RequestInfo::from_request(qer, display.bigreq_enabled(), display.max_request_len()),
-    )?;
-    log_trace!("Resolving QER...");
-    let repl = loop {
-        match display.take_pending_reply(tok) {
-            Some(PendingReply { data, fds }) => {
-                break decode_reply::<QueryExtensionRequest>(&data, fds)?;
-            }
-            None => input::wait(display, conn)?,
+/// Send the requests that are being held back, oldest first, stopping at one whose extension's opcode
+/// hasn't arrived yet. This doesn't read from the connection. Returns whether anything was sent.
+#[inline]
+pub(crate) fn send_deferred_requests<D: DisplayBase + ?Sized, C: Connection + ?Sized>(
+    display: &mut D,
+    connection: &mut C,
+) -> crate::Result<bool> {
+    let mut sent = false;
+    while let Some(mut req) = display.take_deferred_request() {
+        let ext_opcode = match req.extension {
+            None => None,
+            Some(extension) => match deferred_extension_opcode(display, extension) {
+                None => {
+                    display.restore_deferred_request(req);
+                    break;
+                }
+                Some(Ok(Some(opcode))) => Some(opcode),
+                Some(Ok(None)) => {
+                    let err = extension_not_present(&req, extension);
+                    cancel_deferred_request(display, &mut req, err);
+                    None
+                }
+                Some(Err(e)) => {
+                    log::error!(
+                        "Cannot send {} request: querying the {} extension failed: {}",
+                        req.describe(),
+                        extension,
+                        &e
+                    );
+                    cancel_deferred_request(display, &mut req, e);
+                    None
+                }
+            },
+        };
+
+        let request_opcode = req.opcode;
+        modify_for_opcode(&mut req.data, request_opcode, ext_opcode);
+        log_trace!("Sending held back request: {:?}", &req);
+
+        let mut fds = mem::take(&mut req.fds);
+        if let Err(e) = connection.send_packet(&req.data, &mut fds) {
+            log::error!("Failed to send {} request: {}", req.describe(), e);
+            return Err(e);
+        }
+        sent = true;
+    }
+
+    Ok(sent)
+}
+
+/// Send every request that is being held back, reading from the connection until the replies they're waiting
+/// on arrive.
+#[inline]
+pub(crate) fn flush_deferred_requests<D: DisplayBase + ?Sized, C: Connection + ?Sized>(
+    display: &mut D,
+    connection: &mut C,
+) -> crate::Result {
+    loop {
+        send_deferred_requests(display, connection)?;
+        if !display.has_deferred_requests() {
+            return Ok(());
         }
+
+        input::wait(display, connection)?;
+    }
+}
+
+/// Get the opcode of a held back request's extension out of the reply to the `QueryExtension` request sent
+/// for it. This is `None` if the reply hasn't arrived yet, and `Some(Ok(None))` if the server doesn't support
+/// the extension.
+#[inline]
+fn deferred_extension_opcode<D: DisplayBase + ?Sized>(
+    display: &mut D,
+    extension: &'static str,
+) -> Option<crate::Result<Option<u8>>> {
+    let key = str_to_key(extension);
+    let req_id = match display.get_extension_presence(&key) {
+        ExtensionPresence::Present(opcode) => return Some(Ok(Some(opcode))),
+        ExtensionPresence::Absent => return Some(Ok(None)),
+        ExtensionPresence::Unknown => match display.pending_extension_query(&key) {
+            Some(req_id) => req_id,
+            // the query was used up elsewhere without finding anything out
+            None => return Some(Ok(None)),
+        },
+    };
+
+    let repl = match display.take_pending_reply(req_id) {
+        Some(PendingReply { data, fds }) => decode_reply::<QueryExtensionRequest>(&data, fds),
+        None => match display.check_for_pending_error(req_id) {
+            Ok(()) => return None,
+            Err(e) => Err(e),
+        },
     };
+    display.take_pending_extension_query(&key);
 
-    if !repl.present {
-        return Err(crate::BreadError::ExtensionNotPresent(extension.into()));
+    Some(repl.map(|repl| {
+        if cache_extension_query(display, key, &repl) {
+            log_debug!("Found opcode for extension: {}", &repl.major_opcode);
+            Some(repl.major_opcode)
+        } else {
+            None
+        }
+    }))
+}
+
+/// Replace a held back request that can't be sent with a `NoOperation` request, so that the server's
+/// sequence numbers stay in step with ours, and make resolving it fail with `error`.
+#[inline]
+pub(crate) fn cancel_deferred_request<D: DisplayBase + ?Sized>(
+    display: &mut D,
+    req: &mut RequestInfo,
+    error: crate::BreadError,
+) {
+    close_fds(mem::take(&mut req.fds));
+    let mut noop = RequestInfo::from_request(
+        NoOperationRequest::default(),
+        display.bigreq_enabled(),
+        display.max_request_len(),
+    );
+    modify_for_opcode(&mut noop.data, noop.opcode, None);
+    req.data = noop.data;
+    req.opcode = noop.opcode;
+    req.extension = None;
+
+    if let Some(seq) = req.sequence {
+        if let Some(pereq) = display.take_pending_request(seq) {
+            if !pereq.flags.discard_reply {
+                display.add_pending_error(seq, error);
+            }
+        }
     }
+}
 
-    log_debug!("Found opcode for extension: {}", &repl.major_opcode);
+/// Log that a request can't be sent because the server doesn't have its extension, and create the error
+/// for it.
+#[inline]
+pub(crate) fn extension_not_present(
+    request: &RequestInfo,
+    extension: &'static str,
+) -> crate::BreadError {
+    log::error!(
+        "Cannot send {} request: the server does not support the {} extension",
+        request.describe(),
+        extension
+    );
+    crate::BreadError::ExtensionNotPresent {
+        extension: extension.into(),
+        request: request.describe(),
+    }
+}
+
+/// Remember the result of a `QueryExtension` request, including whether the extension is missing, so the
+/// server doesn't need to be asked again. Returns whether the extension is present.
+#[inline]
+pub(crate) fn cache_extension_query<D: DisplayBase + ?Sized>(
+    display: &mut D,
+    key: [u8; EXT_KEY_SIZE],
+    repl: &QueryExtensionReply,
+) -> bool {
+    if repl.present {
+        display.set_extension_opcode(key, repl.major_opcode);
+        display.set_extension_first_event(key, repl.first_event);
+        // TODO: first_error
+    } else {
+        display.set_extension_absent(key);
+    }
+
+    repl.present
+}
+
+/// Whether or not a `QueryExtension` request needs to be sent for the given extension; that is, whether we
+/// don't know if the server supports it and haven't already sent one.
+#[inline]
+pub(crate) fn extension_query_needed<D: DisplayBase + ?Sized>(
+    display: &mut D,
+    extension: &'static str,
+) -> bool {
     let key = str_to_key(extension);
-    display.set_extension_opcode(key, repl.major_opcode);
-    // TODO: first_event, first_error
-    Ok(repl.major_opcode)
+    if display.get_extension_presence(&key) != ExtensionPresence::Unknown {
+        return false;
+    }
+
+    display.pending_extension_query(&key).is_none()
+}
+
+/// Create a `QueryExtension` request for the given extension, to be sent ahead of the extension's first
+/// request.
+#[inline]
+pub(crate) fn extension_query_request<D: DisplayBase + ?Sized>(
+    display: &D,
+    extension: &'static str,
+) -> RequestInfo {
+    let mut qer = RequestInfo::from_request(
+        QueryExtensionRequest {
+            name: extension.to_string(),
+            ..Default::default()
+        },
+        display.bigreq_enabled(),
+        display.max_request_len(),
+    );
+    qer.queried_extension = Some(extension);
+    qer
+}
+
+/// Whether or not the Generic Event Extension needs to be negotiated before a request to the given extension
+/// is sent; that is, whether the extension's events are routed through the display and the negotiation
+/// hasn't been sent yet. The caller should call `set_ge_negotiated` once it has been sent.
+#[cfg(feature = "ge")]
+#[inline]
+pub(crate) fn ge_negotiation_needed<D: DisplayBase + ?Sized>(display: &D, extension: &str) -> bool {
+    if display.ge_negotiated() || !display.has_event_decoder(extension) {
+        return false;
+    }
+
+    log_debug!(
+        "Negotiating the Generic Event Extension ahead of the {} extension",
+        extension
+    );
+    true
+}
+
+/// Create the `QueryVersion` request that negotiates version 1.0 of the Generic Event Extension. Nothing in
+/// its reply is needed, so the reply is thrown away.
+#[cfg(feature = "ge")]
+#[inline]
+pub(crate) fn ge_negotiation_request<D: DisplayBase + ?Sized>(display: &D) -> RequestInfo {
+    let mut qvr = RequestInfo::from_request(
+        GeQueryVersionRequest {
+            client_major_version: 1,
+            client_minor_version: 0,
+            ..Default::default()
+        },
+        display.bigreq_enabled(),
+        display.max_request_len(),
+    );
+    qvr.discard_reply = true;
+    qvr
 }
 
 #[inline]
@@ -178,3 +494,331 @@ pub(crate) fn str_to_key(s: &str) -> [u8; EXT_KEY_SIZE] {
 
     key
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        auto::xproto::GetInputFocusRequest,
+        display::{mock_display, mock_setup, DisplayBase, DisplayExt, MockConnection},
+        BreadError,
+    };
+
+    #[test]
+    fn max_pending_applies_backpressure() {
+        use crate::auto::xproto::GetInputFocusReply;
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_reply(&GetInputFocusReply {
+            reply_type: 1,
+            sequence: 2,
+            ..Default::default()
+        });
+        conn.feed_reply(&GetInputFocusReply {
+            reply_type: 1,
+            sequence: 3,
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        display.set_max_pending(2);
+        let first = display
+            .send_request(GetInputFocusRequest::default())
+            .unwrap();
+        display
+            .send_request(GetInputFocusRequest::default())
+            .unwrap();
+        assert_eq!(display.awaited_reply_count(), 2);
+
+        // the third request reads both replies, but they're still unread, so it can't be sent
+        assert!(matches!(
+            display.send_request(GetInputFocusRequest::default()),
+            Err(BreadError::WouldBlock)
+        ));
+        assert_eq!(display.awaited_reply_count(), 0);
+        assert_eq!(display.unread_reply_count(), 2);
+
+        display.resolve_request(first).unwrap();
+        display
+            .send_request(GetInputFocusRequest::default())
+            .unwrap();
+        assert_eq!(display.awaited_reply_count(), 1);
+    }
+
+    #[cfg(feature = "fixes")]
+    #[test]
+    fn extension_requests_are_held_until_the_opcode_arrives() {
+        use crate::{
+            auto::{
+                xfixes::HideCursorRequest,
+                xproto::{BellRequest, GetInputFocusReply, QueryExtensionReply, Window},
+            },
+            display::{output::str_to_key, Display},
+        };
+        use alloc::vec;
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_reply(&QueryExtensionReply {
+            reply_type: 1,
+            sequence: 2,
+            present: true,
+            major_opcode: 140,
+            ..Default::default()
+        });
+        conn.feed_reply(&GetInputFocusReply {
+            reply_type: 1,
+            sequence: 5,
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        display.set_checked(false);
+        display
+            .send_request(HideCursorRequest {
+                window: Window::const_from_xid(1),
+                ..Default::default()
+            })
+            .unwrap();
+        // the query went out, but nothing waited on its reply
+        assert_eq!(
+            display.pending_extension_query(&str_to_key("XFIXES")),
+            Some(2)
+        );
+        assert!(display.has_deferred_requests());
+
+        // the bell can't overtake the held back request
+        display.send_request(BellRequest::default()).unwrap();
+        display.synchronize().unwrap();
+        assert!(!display.has_deferred_requests());
+        drop(display);
+
+        let written = conn.take_outgoing();
+        let window = 1u32.to_ne_bytes();
+        let mut expected = vec![140, 29];
+        expected.extend_from_slice(&2u16.to_ne_bytes());
+        expected.extend_from_slice(&window);
+        expected.extend_from_slice(&[104, 0]);
+        expected.extend_from_slice(&1u16.to_ne_bytes());
+        expected.extend_from_slice(&[43, 0]);
+        expected.extend_from_slice(&1u16.to_ne_bytes());
+        assert_eq!(&written[written.len() - expected.len()..], &expected[..]);
+    }
+
+    #[cfg(feature = "fixes")]
+    #[test]
+    fn held_requests_to_a_missing_extension_fail() {
+        use crate::auto::{xfixes::QueryVersionRequest, xproto::QueryExtensionReply};
+        use alloc::vec;
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_reply(&QueryExtensionReply {
+            reply_type: 1,
+            sequence: 2,
+            present: false,
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        let cookie = display
+            .send_request(QueryVersionRequest::default())
+            .unwrap();
+        assert!(matches!(
+            display.resolve_request(cookie),
+            Err(BreadError::ExtensionNotPresent {
+                request: "QueryVersion",
+                ..
+            })
+        ));
+        drop(display);
+
+        // a NoOperation takes its place, so the server numbers later requests the same way we do
+        let written = conn.take_outgoing();
+        let mut expected = vec![127, 0];
+        expected.extend_from_slice(&1u16.to_ne_bytes());
+        assert_eq!(&written[written.len() - 4..], &expected[..]);
+    }
+
+    #[cfg(feature = "dri3")]
+    #[test]
+    fn mismatched_fd_count_is_refused() {
+        use crate::auto::dri3::PixmapFromBuffersRequest;
+        #[cfg(all(unix, feature = "std"))]
+        use crate::display::{pipe, write_end_closed};
+        use alloc::vec;
+
+        let mut idle = MockConnection::with_setup(&mock_setup());
+        drop(mock_display(&mut idle));
+        let mut conn = MockConnection::with_setup(&mock_setup());
+
+        #[cfg(all(unix, feature = "std"))]
+        let (read, write) = pipe();
+        #[cfg(not(all(unix, feature = "std")))]
+        let write = 10;
+
+        let mut display = mock_display(&mut conn);
+        match display.send_request(PixmapFromBuffersRequest {
+            num_buffers: 2,
+            buffers: vec![write],
+            ..Default::default()
+        }) {
+            Err(BreadError::FdCountMismatch {
+                request: "PixmapFromBuffers",
+                expected: 2,
+                got: 1,
+            }) => (),
+            res => panic!("expected an fd count mismatch, got {:?}", res.map(|_| ())),
+        }
+        drop(display);
+
+        // nothing was written, not even a query for the extension
+        assert_eq!(conn.outgoing(), idle.outgoing());
+        assert!(conn.take_outgoing_fds().is_empty());
+        // the request's fd was closed, since it'll never be sent
+        #[cfg(all(unix, feature = "std"))]
+        assert!(write_end_closed(read));
+    }
+
+    #[cfg(feature = "dri3")]
+    #[test]
+    fn several_fds_travel_with_one_request() {
+        use crate::{
+            auto::{
+                dri3::{
+                    BuffersFromPixmapReply, BuffersFromPixmapRequest, PixmapFromBuffersRequest,
+                },
+                xproto::QueryExtensionReply,
+            },
+            display::DisplayBase,
+        };
+        use alloc::vec;
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_reply(&QueryExtensionReply {
+            reply_type: 1,
+            sequence: 2,
+            present: true,
+            major_opcode: 140,
+            ..Default::default()
+        });
+        conn.feed_fds(vec![20, 21, 22]);
+        conn.feed_reply(&BuffersFromPixmapReply {
+            reply_type: 1,
+            nfd: 3,
+            sequence: 4,
+            length: 6,
+            strides: vec![1, 2, 3],
+            offsets: vec![4, 5, 6],
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        display.set_checked(false);
+        display
+            .send_request(PixmapFromBuffersRequest {
+                num_buffers: 3,
+                buffers: vec![10, 11, 12],
+                ..Default::default()
+            })
+            .unwrap();
+        let tok = display
+            .send_request(BuffersFromPixmapRequest::default())
+            .unwrap();
+        let reply = display.resolve_request(tok).unwrap();
+        drop(display);
+
+        // the request's file descriptors are sent alongside its data, in order
+        let sent = conn.take_outgoing_fds();
+        assert_eq!(sent.len(), 1);
+        let (at, ref fds) = sent[0];
+        assert_eq!(fds, &[10, 11, 12]);
+        assert_eq!(&conn.outgoing()[at..at + 2], &[140, 7]);
+
+        // and a reply's file descriptors all stay with that reply
+        assert_eq!(reply.buffers, vec![20, 21, 22]);
+        assert_eq!(reply.strides, vec![1, 2, 3]);
+    }
+
+    #[cfg(feature = "input")]
+    #[test]
+    fn generic_events_are_negotiated_once() {
+        use crate::auto::{
+            ge::QueryVersionReply, xinput::GetExtensionVersionRequest, xproto::QueryExtensionReply,
+        };
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_reply(&QueryExtensionReply {
+            reply_type: 1,
+            sequence: 2,
+            present: true,
+            major_opcode: 128,
+            ..Default::default()
+        });
+        conn.feed_reply(&QueryVersionReply {
+            reply_type: 1,
+            sequence: 3,
+            major_version: 1,
+            ..Default::default()
+        });
+        conn.feed_reply(&QueryExtensionReply {
+            reply_type: 1,
+            sequence: 4,
+            present: true,
+            major_opcode: 131,
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        assert!(!display.ge_negotiated());
+        let first = display
+            .send_request(GetExtensionVersionRequest::default())
+            .unwrap();
+        assert_eq!(first.sequence(), 5);
+        assert!(display.ge_negotiated());
+
+        // the second request doesn't negotiate again
+        let second = display
+            .send_request(GetExtensionVersionRequest::default())
+            .unwrap();
+        assert_eq!(second.sequence(), 6);
+        drop(display);
+
+        // GE's QueryVersion 1.0 goes out between the two QueryExtension requests
+        let mut qvr = alloc::vec![128, 0];
+        qvr.extend_from_slice(&2u16.to_ne_bytes());
+        qvr.extend_from_slice(&1u16.to_ne_bytes());
+        qvr.extend_from_slice(&0u16.to_ne_bytes());
+        assert!(conn.outgoing().windows(8).any(|w| w == &qvr[..]));
+    }
+
+    #[cfg(feature = "fixes")]
+    #[test]
+    fn registered_decoders_negotiate_generic_events() {
+        use crate::{
+            auto::{xfixes::HideCursorRequest, xproto::Window},
+            display::{output::str_to_key, EventDecoder},
+            Event,
+        };
+
+        #[allow(clippy::unnecessary_wraps)]
+        fn decode(_event: &mut Event, _first_event: u8) -> crate::Result {
+            Ok(())
+        }
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        let mut display = mock_display(&mut conn);
+        let hide_cursor = HideCursorRequest {
+            window: Window::const_from_xid(1),
+            ..Default::default()
+        };
+
+        // nothing routes XFIXES events yet
+        display.send_request(hide_cursor.clone()).unwrap();
+        assert!(!display.ge_negotiated());
+
+        display.register_event_decoder("XFIXES", 2, decode as EventDecoder);
+        display.send_request(hide_cursor).unwrap();
+        assert!(display.ge_negotiated());
+        assert!(display
+            .pending_extension_query(&str_to_key("Generic Event Extension"))
+            .is_some());
+    }
+}
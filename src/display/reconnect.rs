@@ -0,0 +1,511 @@
+// MIT/Apache2 License
+
+use super::{
+    output, BasicDisplay, Connection, Display, DisplayBase, DisplayExt, EventDecoder,
+    ExtensionPresence, OrphanReplyHook, PendingReply, PendingRequest, RequestInfo, ResourceKind,
+    EXT_KEY_SIZE,
+};
+use crate::{
+    auto::xproto::{QueryExtensionRequest, Setup},
+    event::Event,
+    BreadError, ServerTime, XID,
+};
+use alloc::{string::String, vec::Vec};
+use core::num::NonZeroU32;
+
+/// A wrapper around a `BasicDisplay` that connects to the X server again if the connection is lost. This
+/// is useful for clients that talk to a remote server over a network that may drop out.
+///
+/// When a request or a wait fails because the connection has closed, the display is rebuilt using the
+/// closure given on construction, which should open a new connection and run the setup handshake (as
+/// `DisplayConnection::create` does). The extensions that had been queried on the old connection are then
+/// queried again, so that their opcodes are known ahead of time. Finally, the operation that failed returns
+/// `BreadError::Reconnected`.
+///
+/// Nothing on the server side survives the reconnection. Windows, pixmaps and other resources, as well as
+/// grabs and selections, are gone; request cookies and events from the old connection are meaningless. The
+/// `Reconnected` error is the signal to recreate whatever the client needs and carry on.
+#[derive(Debug)]
+pub struct ReconnectingDisplay<Conn, F> {
+    inner: BasicDisplay<Conn>,
+    connect: F,
+}
+
+impl<Conn, F> ReconnectingDisplay<Conn, F> {
+    /// Get a reference to the current display.
+    #[inline]
+    pub fn inner(&self) -> &BasicDisplay<Conn> {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the current display.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut BasicDisplay<Conn> {
+        &mut self.inner
+    }
+
+    /// Destroy this object and return the current display.
+    #[inline]
+    pub fn into_inner(self) -> BasicDisplay<Conn> {
+        self.inner
+    }
+}
+
+impl<Conn: Connection, F: FnMut() -> crate::Result<BasicDisplay<Conn>>>
+    ReconnectingDisplay<Conn, F>
+{
+    /// Connect to the X server using `connect`, which will also be used to connect again whenever the
+    /// connection is lost.
+    #[inline]
+    pub fn new(mut connect: F) -> crate::Result<Self> {
+        let inner = connect()?;
+        Ok(Self::from_display(inner, connect))
+    }
+
+    /// Wrap around a display that is already connected. `connect` is used to connect again whenever the
+    /// connection is lost.
+    #[inline]
+    pub fn from_display(inner: BasicDisplay<Conn>, connect: F) -> Self {
+        Self { inner, connect }
+    }
+
+    /// Throw away the current connection and connect to the X server again, querying any extensions that
+    /// were in use on the old connection.
+    #[inline]
+    pub fn reconnect(&mut self) -> crate::Result {
+        // extensions the old server didn't have are forgotten, rather than asked about again
+        let extensions: Vec<[u8; EXT_KEY_SIZE]> = self
+            .inner
+            .extensions
+            .iter()
+            .filter(|(_, opcode)| opcode.is_some())
+            .map(|(key, _)| *key)
+            .collect();
+        let mut inner = (self.connect)()?;
+
+        // send all of the queries before waiting on any of them
+        let toks = extensions
+            .iter()
+            .map(|key| {
+                let len = key.iter().position(|&b| b == 0).unwrap_or(EXT_KEY_SIZE);
+                inner.send_request(QueryExtensionRequest {
+                    name: String::from_utf8_lossy(&key[..len]).into_owned(),
+                    ..Default::default()
+                })
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        for (key, tok) in extensions.into_iter().zip(toks) {
+            // the server we reconnected to may not be the one we lost
+            let qer = inner.resolve_request(tok)?;
+            output::cache_extension_query(&mut inner, key, &qer);
+        }
+
+        // carry over the settings made on the old connection; the old connection's resources are gone, so
+        // there's nothing left to free
+        inner.set_max_pending(self.inner.max_pending());
+        inner.track_resources(self.inner.tracking_resources());
+        self.inner.resources.take();
+
+        log::info!("Re-established the connection to the X server");
+        self.inner = inner;
+        Ok(())
+    }
+
+    #[inline]
+    fn check_connection<T>(&mut self, res: crate::Result<T>) -> crate::Result<T> {
+        match res {
+            Err(e) if connection_lost(&e) => {
+                log::warn!("Lost the connection to the X server: {}", e);
+                self.reconnect()?;
+                Err(BreadError::Reconnected)
+            }
+            res => res,
+        }
+    }
+}
+
+/// Tell whether or not an error means that the connection to the server is gone.
+#[inline]
+fn connection_lost(err: &BreadError) -> bool {
+    match err {
+        BreadError::ClosedConnection => true,
+        #[cfg(feature = "std")]
+        BreadError::Io(io) => matches!(
+            io.kind(),
+            std::io::ErrorKind::BrokenPipe
+                | std::io::ErrorKind::ConnectionReset
+                | std::io::ErrorKind::ConnectionAborted
+        ),
+        _ => false,
+    }
+}
+
+impl<Conn, F> DisplayBase for ReconnectingDisplay<Conn, F> {
+    #[inline]
+    fn setup(&self) -> &Setup {
+        self.inner.setup()
+    }
+
+    #[inline]
+    fn default_screen_index(&self) -> usize {
+        self.inner.default_screen_index()
+    }
+
+    #[inline]
+    fn next_request_number(&mut self) -> u64 {
+        self.inner.next_request_number()
+    }
+
+    #[inline]
+    fn push_event(&mut self, event: Event) {
+        self.inner.push_event(event);
+    }
+
+    #[inline]
+    fn pop_event(&mut self) -> Option<Event> {
+        self.inner.pop_event()
+    }
+
+    #[inline]
+    fn generate_xid(&mut self) -> Option<XID> {
+        self.inner.generate_xid()
+    }
+
+    #[inline]
+    fn add_pending_request(&mut self, req_id: u16, pereq: PendingRequest) {
+        self.inner.add_pending_request(req_id, pereq);
+    }
+
+    #[inline]
+    fn get_pending_request(&self, req_id: u16) -> Option<PendingRequest> {
+        self.inner.get_pending_request(req_id)
+    }
+
+    #[inline]
+    fn take_pending_request(&mut self, req_id: u16) -> Option<PendingRequest> {
+        self.inner.take_pending_request(req_id)
+    }
+
+    #[inline]
+    fn add_pending_error(&mut self, req_id: u16, error: BreadError) {
+        self.inner.add_pending_error(req_id, error);
+    }
+
+    #[inline]
+    fn check_for_pending_error(&mut self, req_id: u16) -> crate::Result<()> {
+        self.inner.check_for_pending_error(req_id)
+    }
+
+    #[inline]
+    fn add_pending_reply(&mut self, req_id: u16, reply: PendingReply) {
+        self.inner.add_pending_reply(req_id, reply);
+    }
+
+    #[inline]
+    fn take_pending_reply(&mut self, req_id: u16) -> Option<PendingReply> {
+        self.inner.take_pending_reply(req_id)
+    }
+
+    #[inline]
+    fn create_special_event_queue(&mut self, xid: XID) {
+        self.inner.create_special_event_queue(xid);
+    }
+
+    #[inline]
+    fn push_special_event(&mut self, xid: XID, event: Event) -> Result<(), Event> {
+        self.inner.push_special_event(xid, event)
+    }
+
+    #[inline]
+    fn pop_special_event(&mut self, xid: XID) -> Option<Event> {
+        self.inner.pop_special_event(xid)
+    }
+
+    #[inline]
+    fn delete_special_event_queue(&mut self, xid: XID) {
+        self.inner.delete_special_event_queue(xid);
+    }
+
+    #[inline]
+    fn checked(&self) -> bool {
+        self.inner.checked()
+    }
+
+    #[inline]
+    fn set_checked(&mut self, checked: bool) {
+        self.inner.set_checked(checked);
+    }
+
+    #[inline]
+    fn bigreq_enabled(&self) -> bool {
+        self.inner.bigreq_enabled()
+    }
+    #[inline]
+    fn max_request_len(&self) -> usize {
+        self.inner.max_request_len()
+    }
+
+    #[inline]
+    fn get_extension_opcode(&mut self, key: &[u8; EXT_KEY_SIZE]) -> Option<u8> {
+        self.inner.get_extension_opcode(key)
+    }
+
+    #[inline]
+    fn set_extension_opcode(&mut self, key: [u8; EXT_KEY_SIZE], opcode: u8) {
+        self.inner.set_extension_opcode(key, opcode);
+    }
+
+    #[inline]
+    fn get_extension_presence(&mut self, key: &[u8; EXT_KEY_SIZE]) -> ExtensionPresence {
+        self.inner.get_extension_presence(key)
+    }
+
+    #[inline]
+    fn set_extension_absent(&mut self, key: [u8; EXT_KEY_SIZE]) {
+        self.inner.set_extension_absent(key);
+    }
+
+    #[inline]
+    fn get_extension_first_event(&self, key: &[u8; EXT_KEY_SIZE]) -> Option<u8> {
+        self.inner.get_extension_first_event(key)
+    }
+
+    #[inline]
+    fn set_extension_first_event(&mut self, key: [u8; EXT_KEY_SIZE], first_event: u8) {
+        self.inner.set_extension_first_event(key, first_event);
+    }
+
+    #[inline]
+    fn add_pending_extension_query(&mut self, key: [u8; EXT_KEY_SIZE], req_id: u16) {
+        self.inner.add_pending_extension_query(key, req_id);
+    }
+
+    #[inline]
+    fn take_pending_extension_query(&mut self, key: &[u8; EXT_KEY_SIZE]) -> Option<u16> {
+        self.inner.take_pending_extension_query(key)
+    }
+
+    #[inline]
+    fn pending_extension_query(&self, key: &[u8; EXT_KEY_SIZE]) -> Option<u16> {
+        self.inner.pending_extension_query(key)
+    }
+
+    #[inline]
+    fn defer_request(&mut self, request: RequestInfo) {
+        self.inner.defer_request(request);
+    }
+
+    #[inline]
+    fn take_deferred_request(&mut self) -> Option<RequestInfo> {
+        self.inner.take_deferred_request()
+    }
+
+    #[inline]
+    fn restore_deferred_request(&mut self, request: RequestInfo) {
+        self.inner.restore_deferred_request(request);
+    }
+
+    #[inline]
+    fn has_deferred_requests(&self) -> bool {
+        self.inner.has_deferred_requests()
+    }
+
+    #[inline]
+    fn wm_protocols_atom(&self) -> Option<NonZeroU32> {
+        self.inner.wm_protocols_atom()
+    }
+
+    #[inline]
+    fn set_wm_protocols_atom(&mut self, a: NonZeroU32) {
+        self.inner.set_wm_protocols_atom(a);
+    }
+
+    #[inline]
+    fn xembed_atom(&self) -> Option<NonZeroU32> {
+        self.inner.xembed_atom()
+    }
+
+    #[inline]
+    fn set_xembed_atom(&mut self, a: NonZeroU32) {
+        self.inner.set_xembed_atom(a);
+    }
+
+    #[inline]
+    fn xembed_info_atom(&self) -> Option<NonZeroU32> {
+        self.inner.xembed_info_atom()
+    }
+
+    #[inline]
+    fn set_xembed_info_atom(&mut self, a: NonZeroU32) {
+        self.inner.set_xembed_info_atom(a);
+    }
+
+    #[inline]
+    fn last_event_time(&self) -> ServerTime {
+        self.inner.last_event_time()
+    }
+
+    #[inline]
+    fn set_last_event_time(&mut self, time: ServerTime) {
+        self.inner.set_last_event_time(time);
+    }
+
+    #[inline]
+    fn keymap_generation(&self) -> u32 {
+        self.inner.keymap_generation()
+    }
+
+    #[inline]
+    fn set_keymap_generation(&mut self, generation: u32) {
+        self.inner.set_keymap_generation(generation);
+    }
+
+    #[inline]
+    fn ge_negotiated(&self) -> bool {
+        self.inner.ge_negotiated()
+    }
+
+    #[inline]
+    fn set_ge_negotiated(&mut self) {
+        self.inner.set_ge_negotiated();
+    }
+
+    #[inline]
+    fn max_pending(&self) -> usize {
+        self.inner.max_pending()
+    }
+
+    #[inline]
+    fn set_max_pending(&mut self, max: usize) {
+        self.inner.set_max_pending(max);
+    }
+
+    #[inline]
+    fn awaited_reply_count(&self) -> usize {
+        self.inner.awaited_reply_count()
+    }
+
+    #[inline]
+    fn reserve_pending(&mut self, additional: usize) {
+        self.inner.reserve_pending(additional);
+    }
+
+    #[inline]
+    fn unread_reply_count(&self) -> usize {
+        self.inner.unread_reply_count()
+    }
+
+    #[inline]
+    fn root_size(&self, screen: usize) -> Option<(u16, u16)> {
+        self.inner.root_size(screen)
+    }
+
+    #[inline]
+    fn set_root_size(&mut self, screen: usize, width: u16, height: u16) {
+        self.inner.set_root_size(screen, width, height);
+    }
+
+    #[inline]
+    fn tracking_resources(&self) -> bool {
+        self.inner.tracking_resources()
+    }
+
+    #[inline]
+    fn track_resources(&mut self, track: bool) {
+        self.inner.track_resources(track);
+    }
+
+    #[inline]
+    fn track_resource(&mut self, xid: XID, kind: ResourceKind) {
+        self.inner.track_resource(xid, kind);
+    }
+
+    #[inline]
+    fn untrack_resource(&mut self, xid: XID) {
+        self.inner.untrack_resource(xid);
+    }
+
+    #[inline]
+    fn add_orphan_reply(&mut self, sequence: u16, reply: &[u8]) {
+        self.inner.add_orphan_reply(sequence, reply);
+    }
+
+    #[inline]
+    fn orphan_reply_count(&self) -> usize {
+        self.inner.orphan_reply_count()
+    }
+
+    #[inline]
+    fn on_orphan_reply(&mut self, hook: Option<OrphanReplyHook>) {
+        self.inner.on_orphan_reply(hook);
+    }
+
+    #[inline]
+    fn register_event_decoder(
+        &mut self,
+        extension: &'static str,
+        event_count: u8,
+        decoder: EventDecoder,
+    ) {
+        self.inner
+            .register_event_decoder(extension, event_count, decoder);
+    }
+
+    #[inline]
+    fn event_decoder(&self, code: u8) -> Option<(u8, EventDecoder)> {
+        self.inner.event_decoder(code)
+    }
+
+    #[inline]
+    fn has_event_decoder(&self, extension: &str) -> bool {
+        self.inner.has_event_decoder(extension)
+    }
+}
+
+impl<Conn: Connection, F: FnMut() -> crate::Result<BasicDisplay<Conn>>> Display
+    for ReconnectingDisplay<Conn, F>
+{
+    #[inline]
+    fn wait(&mut self) -> crate::Result {
+        let res = self.inner.wait();
+        self.check_connection(res)
+    }
+
+    #[inline]
+    fn send_request_raw(&mut self, request_info: RequestInfo) -> crate::Result<u16> {
+        let res = self.inner.send_request_raw(request_info);
+        self.check_connection(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReconnectingDisplay;
+    use crate::{
+        auth_info::AuthInfo,
+        display::{mock_setup, BasicDisplay, Display, MockConnection},
+        BreadError,
+    };
+    use core::cell::Cell;
+
+    #[test]
+    fn reconnect_on_close() {
+        let setup = mock_setup();
+        let connections = Cell::new(0);
+        let mut display = ReconnectingDisplay::new(|| {
+            connections.set(connections.get() + 1);
+            BasicDisplay::from_connection(
+                MockConnection::with_setup(&setup),
+                0,
+                Some(AuthInfo::default()),
+            )
+        })
+        .unwrap();
+        assert_eq!(connections.get(), 1);
+
+        // nothing else is queued on the mock, so it looks like the server hung up
+        assert!(matches!(display.wait(), Err(BreadError::Reconnected)));
+        assert_eq!(connections.get(), 2);
+    }
+}
@@ -0,0 +1,279 @@
+// MIT/Apache2 License
+
+use crate::{
+    auto::xproto::{DestroyWindowRequest, FreeGcRequest, FreePixmapRequest},
+    Request, XID,
+};
+use alloc::vec::Vec;
+use core::mem;
+
+#[cfg(feature = "async")]
+use super::AsyncConnection;
+#[cfg(feature = "async")]
+use crate::auto::xproto::{CreateGcRequest, CreatePixmapRequest, CreateWindowRequest};
+#[cfg(feature = "async")]
+use alloc::{sync::Arc, task::Wake};
+#[cfg(feature = "async")]
+use core::task::{Context, Poll, Waker};
+
+/// The kind of a resource that a display can free on its own, which determines the request used to free
+/// it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ResourceKind {
+    /// Freed with `DestroyWindow`.
+    Window,
+    /// Freed with `FreePixmap`.
+    Pixmap,
+    /// Freed with `FreeGC`.
+    Gcontext,
+}
+
+impl ResourceKind {
+    /// The kind of resource a request creates, if it's one that can be tracked.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub(crate) fn created_by<R: Request>() -> Option<Self> {
+        match (R::EXTENSION, R::OPCODE) {
+            (None, CreateWindowRequest::OPCODE) => Some(Self::Window),
+            (None, CreatePixmapRequest::OPCODE) => Some(Self::Pixmap),
+            (None, CreateGcRequest::OPCODE) => Some(Self::Gcontext),
+            _ => None,
+        }
+    }
+
+    /// The opcode of the request that frees this kind of resource.
+    #[inline]
+    fn free_opcode(self) -> u8 {
+        match self {
+            Self::Window => DestroyWindowRequest::OPCODE,
+            Self::Pixmap => FreePixmapRequest::OPCODE,
+            Self::Gcontext => FreeGcRequest::OPCODE,
+        }
+    }
+}
+
+/// The resources a display has created and should free when it is dropped, in the order they were
+/// created.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ResourceTracker {
+    enabled: bool,
+    resources: Vec<(XID, ResourceKind)>,
+}
+
+impl ResourceTracker {
+    #[inline]
+    pub(crate) fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Turn tracking on or off. Turning it off forgets every resource tracked so far.
+    #[inline]
+    pub(crate) fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.resources.clear();
+        }
+    }
+
+    #[inline]
+    pub(crate) fn track(&mut self, xid: XID, kind: ResourceKind) {
+        if self.enabled {
+            self.resources.push((xid, kind));
+        }
+    }
+
+    #[inline]
+    pub(crate) fn untrack(&mut self, xid: XID) {
+        // resources are usually freed not long after they're created, so search from the back
+        if let Some(i) = self.resources.iter().rposition(|&(r, _)| r == xid) {
+            self.resources.remove(i);
+        }
+    }
+
+    #[inline]
+    pub(crate) fn take(&mut self) -> Vec<(XID, ResourceKind)> {
+        mem::take(&mut self.resources)
+    }
+}
+
+/// Serialize the requests that free the given resources into one buffer, so that they can be sent in one
+/// packet. Resources are freed newest first, so that child windows are destroyed before their parents.
+#[inline]
+pub(crate) fn free_requests(resources: &[(XID, ResourceKind)]) -> Vec<u8> {
+    // every one of these requests is two units long: the opcode, padding, length and then the XID
+    let length = 2u16.to_ne_bytes();
+    let mut bytes = Vec::with_capacity(resources.len() * 8);
+    for &(xid, kind) in resources.iter().rev() {
+        bytes.extend_from_slice(&[kind.free_opcode(), 0, length[0], length[1]]);
+        bytes.extend_from_slice(&xid.to_ne_bytes());
+    }
+    bytes
+}
+
+/// Write to an async connection if it's ready to be written to right away. Dropping can't be async, and
+/// blocking in `Drop` could stall the executor it's running on, so if the write can't finish immediately it
+/// fails with `WouldBlock` instead.
+#[cfg(feature = "async")]
+#[inline]
+pub(crate) fn send_without_waiting<C: AsyncConnection + ?Sized>(
+    conn: &mut C,
+    bytes: &[u8],
+) -> crate::Result {
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        #[inline]
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut cx = Context::from_waker(&waker);
+    match conn.poll_send_packet(bytes, &mut Vec::new(), &mut cx, &mut 0) {
+        Poll::Ready(res) => res,
+        Poll::Pending => Err(crate::BreadError::WouldBlock),
+    }
+}
+
+/// Free the given resources in one write, logging any error that occurs.
+#[inline]
+pub(crate) fn free_tracked_resources<Conn>(
+    send: fn(&mut Conn, &[u8]) -> crate::Result,
+    conn: &mut Conn,
+    resources: Vec<(XID, ResourceKind)>,
+) {
+    if !resources.is_empty() {
+        log::debug!("Freeing {} tracked resources", resources.len());
+        if let Err(e) = send(conn, &free_requests(&resources)) {
+            log::error!("Failed to free tracked resources: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{free_requests, ResourceKind, ResourceTracker};
+    use crate::{
+        auto::xproto::Setup,
+        display::{mock_display, mock_setup, DisplayBase, MockConnection},
+    };
+    use alloc::vec;
+
+    #[test]
+    fn tracker_frees_newest_first() {
+        let mut tracker = ResourceTracker::default();
+        // nothing is tracked until tracking is turned on
+        tracker.track(1, ResourceKind::Window);
+        assert!(tracker.take().is_empty());
+
+        tracker.set_enabled(true);
+        tracker.track(2, ResourceKind::Window);
+        tracker.track(3, ResourceKind::Pixmap);
+        tracker.track(4, ResourceKind::Gcontext);
+        tracker.untrack(3);
+
+        let resources = tracker.take();
+        assert_eq!(
+            resources,
+            vec![(2, ResourceKind::Window), (4, ResourceKind::Gcontext)]
+        );
+
+        let bytes = free_requests(&resources);
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(bytes[0], 60);
+        assert_eq!(&bytes[4..8], &4u32.to_ne_bytes());
+        assert_eq!(bytes[8], 4);
+        assert_eq!(&bytes[12..16], &2u32.to_ne_bytes());
+    }
+
+    #[test]
+    fn retained_resources_are_not_freed_on_drop() {
+        use crate::{
+            auto::xproto::{CloseDown, Window, WindowClass},
+            display::prelude::DisplayXprotoExt,
+        };
+        use alloc::vec;
+
+        let setup = Setup {
+            resource_id_base: 0x0400_0000,
+            resource_id_mask: 0x001F_FFFF,
+            ..mock_setup()
+        };
+        let mut conn = MockConnection::with_setup(&setup);
+        let mut display = mock_display(&mut conn);
+        display.set_checked(false);
+        display.track_resources(true);
+
+        display
+            .create_window(
+                Window::const_from_xid(1),
+                WindowClass::InputOutput,
+                None,
+                None,
+                0,
+                0,
+                100,
+                100,
+                0,
+                Default::default(),
+            )
+            .unwrap();
+        display
+            .set_close_down_mode(CloseDown::RetainTemporary)
+            .unwrap();
+        assert!(!display.tracking_resources());
+        drop(display);
+
+        // nothing is written after SetCloseDownMode
+        let written = conn.take_outgoing();
+        let mut expected = vec![112, 2];
+        expected.extend_from_slice(&1u16.to_ne_bytes());
+        assert_eq!(&written[written.len() - 4..], &expected[..]);
+    }
+
+    #[test]
+    fn tracked_resources_freed_on_drop() {
+        use crate::{
+            auto::xproto::{Window, WindowClass},
+            display::{prelude::DisplayXprotoExt, traits::DisplayDrawableExt},
+        };
+
+        let setup = Setup {
+            resource_id_base: 0x0400_0000,
+            resource_id_mask: 0x001F_FFFF,
+            ..mock_setup()
+        };
+        let mut conn = MockConnection::with_setup(&setup);
+        let mut display = mock_display(&mut conn);
+        display.set_checked(false);
+        display.track_resources(true);
+
+        let window = display
+            .create_window(
+                Window::const_from_xid(1),
+                WindowClass::InputOutput,
+                None,
+                None,
+                0,
+                0,
+                100,
+                100,
+                0,
+                Default::default(),
+            )
+            .unwrap();
+        let pixmap = display.create_pixmap(window, 10, 10, 24).unwrap();
+        let gc = display.create_gc(window, Default::default()).unwrap();
+        pixmap.free(&mut display).unwrap();
+        drop(display);
+
+        // FreePixmap was sent on its own; then FreeGC and DestroyWindow go out together
+        let written = conn.take_outgoing();
+        let requests = &written[written.len() - 24..];
+        assert_eq!(requests[0], 54);
+        assert_eq!(&requests[4..8], &pixmap.xid.to_ne_bytes());
+        assert_eq!(&requests[8..12], &[60, 0, 2, 0]);
+        assert_eq!(&requests[12..16], &gc.xid.to_ne_bytes());
+        assert_eq!(&requests[16..20], &[4, 0, 2, 0]);
+        assert_eq!(&requests[20..24], &window.xid.to_ne_bytes());
+    }
+}
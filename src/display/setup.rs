@@ -0,0 +1,108 @@
+// MIT/Apache2 License
+
+use crate::auto::xproto::{BackingStore, Keycode, Screen, Setup};
+
+impl Setup {
+    /// The largest request the server accepts without the BIG-REQUESTS extension, in bytes. Once a display
+    /// has enabled BIG-REQUESTS, `DisplayBase::max_request_len` is the limit that actually applies.
+    #[inline]
+    #[must_use]
+    pub fn core_max_request_len(&self) -> usize {
+        usize::from(self.maximum_request_length) * 4
+    }
+
+    /// The first keycode the server uses, and the number of keycodes from there to the last one, as taken by
+    /// `DisplayXprotoExt::get_keyboard_mapping` to get the whole keyboard mapping.
+    #[inline]
+    #[must_use]
+    pub fn keycode_range(&self) -> (Keycode, u8) {
+        let count = self
+            .max_keycode
+            .saturating_sub(self.min_keycode)
+            .saturating_add(1);
+        (self.min_keycode, count)
+    }
+
+    /// The number of events the server keeps in its motion history buffer, or `None` if the server
+    /// doesn't keep one. Without a buffer, `GetMotionEvents` never returns anything and pointer motion
+    /// has to be tracked through events.
+    #[inline]
+    #[must_use]
+    pub fn motion_buffer_len(&self) -> Option<usize> {
+        match self.motion_buffer_size {
+            0 => None,
+            len => Some(len as usize),
+        }
+    }
+}
+
+impl Screen {
+    /// The smallest number of colormaps the server guarantees can be installed at the same time on this
+    /// screen.
+    #[inline]
+    #[must_use]
+    pub fn min_installed_colormaps(&self) -> u16 {
+        self.min_installed_maps
+    }
+
+    /// The largest number of colormaps that can be installed at the same time on this screen. Installing
+    /// another one may uninstall one of the others.
+    #[inline]
+    #[must_use]
+    pub fn max_installed_colormaps(&self) -> u16 {
+        self.max_installed_maps
+    }
+
+    /// When the server is willing to keep the contents of windows on this screen in backing store.
+    /// `NotUseful` means it never does, `WhenMapped` means only while the window is mapped, and `Always`
+    /// means even while it's unmapped.
+    #[inline]
+    #[must_use]
+    pub fn backing_store_support(&self) -> BackingStore {
+        self.backing_stores
+    }
+
+    /// Whether or not windows on this screen can have backing store at all. If not, clients have to
+    /// repaint obscured parts of their windows on `Expose`.
+    #[inline]
+    #[must_use]
+    pub fn has_backing_store(&self) -> bool {
+        self.backing_stores != BackingStore::NotUseful
+    }
+
+    /// Whether or not the server can save what's under a window with the `save_under` attribute, so
+    /// that windows beneath it don't need to be repainted when it goes away.
+    #[inline]
+    #[must_use]
+    pub fn has_save_unders(&self) -> bool {
+        self.save_unders
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::auto::xproto::{BackingStore, Screen, Setup};
+
+    #[test]
+    fn capabilities_are_interpreted() {
+        let setup = Setup {
+            maximum_request_length: u16::MAX,
+            ..Default::default()
+        };
+        assert_eq!(setup.core_max_request_len(), 262_140);
+        let keycodes = Setup {
+            min_keycode: 8,
+            max_keycode: 255,
+            ..setup.clone()
+        };
+        assert_eq!(keycodes.keycode_range(), (8, 248));
+        assert_eq!(setup.motion_buffer_len(), None);
+
+        let screen = Screen {
+            backing_stores: BackingStore::WhenMapped,
+            ..Default::default()
+        };
+        assert!(screen.has_backing_store());
+        assert!(!screen.has_save_unders());
+    }
+}
@@ -0,0 +1,525 @@
+// MIT/Apache2 License
+
+#![cfg(feature = "async")]
+
+use super::{
+    decode_reply, output, AsyncDisplay, DisplayBase, ExtensionPresence, PendingReply,
+    RequestCookie, RequestInfo,
+};
+use crate::{
+    auto::xproto::{GetInputFocusRequest, QueryExtensionRequest},
+    BreadError, Event, Request,
+};
+use alloc::{string::ToString, sync::Arc, vec::Vec};
+use core::{
+    mem,
+    task::{Context, Poll, Waker},
+};
+use futures_lite::future;
+use hashbrown::HashMap;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// A handle to an [`AsyncDisplay`] that can be cloned and given to several tasks, so that they can send
+/// requests and wait on replies at the same time.
+///
+/// The display is kept behind a lock, but the lock is only held while the display is being polled, never
+/// across an `.await`. Requests are sent one at a time, since a request that is part of the way through being
+/// sent can't be interrupted. Waiting for a reply doesn't keep anyone else from sending: each waiting task
+/// registers itself under the sequence number of its request, and whichever task is reading from the
+/// connection wakes it once its reply or error arrives.
+///
+/// ## Construction
+///
+/// `SharedDisplay` wraps any display that implements [`AsyncDisplay`]:
+///
+/// ```rust,no_run
+/// use breadx::display::{AsyncDisplayConnection, SharedDisplay};
+///
+/// # futures_lite::future::block_on(async {
+/// let display = AsyncDisplayConnection::create_async(None, None).await.unwrap();
+/// let display = SharedDisplay::new(display);
+/// let handle = display.clone();
+/// # });
+/// ```
+///
+/// Things that don't involve the connection, like generating XIDs or reading the setup, can be done with
+/// [`SharedDisplay::with_display`].
+#[derive(Debug)]
+pub struct SharedDisplay<Dpy> {
+    state: Arc<Mutex<State<Dpy>>>,
+}
+
+impl<Dpy> Clone for SharedDisplay<Dpy> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct State<Dpy> {
+    display: Dpy,
+    sending: Sending,
+    // tasks waiting for their turn to send a request
+    send_queue: Vec<Waker>,
+    // tasks waiting on the reply to, or error from, the request with the given sequence number
+    reply_waiters: HashMap<u16, Waker>,
+    // tasks waiting on an event, or on room for another outstanding reply
+    event_waiters: Vec<Waker>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Sending {
+    Idle,
+    Busy,
+    // the task sending a request went away before it was done; the next task to send finishes it
+    Abandoned,
+}
+
+/// What a waiting task is waiting on.
+#[derive(Debug, Copy, Clone)]
+enum Waiter {
+    Reply(u16),
+    Event,
+}
+
+impl<Dpy: AsyncDisplay> SharedDisplay<Dpy> {
+    /// Wrap a display so it can be shared between tasks.
+    #[inline]
+    pub fn new(display: Dpy) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(State {
+                display,
+                sending: Sending::Idle,
+                send_queue: Vec::new(),
+                reply_waiters: HashMap::new(),
+                event_waiters: Vec::new(),
+            })),
+        }
+    }
+
+    /// Run `f` with the display. The display is locked while `f` runs, so it should be brief and shouldn't
+    /// use the connection; every other task that uses the display waits for it.
+    #[inline]
+    pub fn with_display<T, F: FnOnce(&mut Dpy) -> T>(&self, f: F) -> T {
+        f(&mut lock(&self.state).display)
+    }
+
+    /// Get the display back, if this is the last handle to it. Otherwise, the handle is returned.
+    #[inline]
+    pub fn try_into_inner(self) -> Result<Dpy, Self> {
+        match Arc::try_unwrap(self.state) {
+            Ok(state) => Ok(state
+                .into_inner()
+                .unwrap_or_else(PoisonError::into_inner)
+                .display),
+            Err(state) => Err(Self { state }),
+        }
+    }
+
+    /// Send a request to the server, waiting for any request that's being sent by another task first.
+    #[inline]
+    pub async fn send_request_async<R: Request>(
+        &self,
+        request: R,
+    ) -> crate::Result<RequestCookie<R>> {
+        let req = self.with_display(|display| {
+            RequestInfo::from_request(request, display.bigreq_enabled(), display.max_request_len())
+        });
+        self.send_request_raw(req)
+            .await
+            .map(RequestCookie::from_sequence)
+    }
+
+    /// Wait for the reply to a request. Other tasks can send requests and wait on their own replies in the
+    /// meantime.
+    #[inline]
+    pub async fn resolve_request_async<R: Request>(
+        &self,
+        token: RequestCookie<R>,
+    ) -> crate::Result<R::Reply>
+    where
+        R::Reply: Default,
+    {
+        let seq = token.sequence();
+        if mem::size_of::<R::Reply>() != 0 {
+            let PendingReply { data, fds } = self.wait_for_reply(seq).await?;
+            return decode_reply::<R>(&data, fds);
+        }
+
+        // requests without replies are only tracked if they were sent checked; if this one was, the server
+        // has processed it once it answers a request sent after it
+        let checked = self.with_display(|display| {
+            display.checked() || display.get_pending_request(seq).is_some()
+        });
+        if checked {
+            let mut gifr = self.with_display(|display| {
+                RequestInfo::from_request(
+                    GetInputFocusRequest::default(),
+                    display.bigreq_enabled(),
+                    display.max_request_len(),
+                )
+            });
+            gifr.discard_reply = true;
+            let sync = self.send_request_raw(gifr).await?;
+            self.wait(Waiter::Reply(sync), |display| {
+                display
+                    .get_pending_request(sync)
+                    .map_or(Some(Ok(())), |_| None)
+            })
+            .await?;
+        }
+
+        self.with_display(|display| {
+            display.take_pending_request(seq);
+            display
+                .check_for_pending_error(seq)
+                .map(|()| R::Reply::default())
+        })
+    }
+
+    /// Send a request to the server and wait for its reply.
+    #[inline]
+    pub async fn exchange_request_async<R: Request>(&self, request: R) -> crate::Result<R::Reply>
+    where
+        R::Reply: Default,
+    {
+        let tok = self.send_request_async(request).await?;
+        self.resolve_request_async(tok).await
+    }
+
+    /// Wait for an event from the server. If several tasks are waiting on events, each event goes to one of
+    /// them.
+    #[inline]
+    pub async fn wait_for_event_async(&self) -> crate::Result<Event> {
+        self.wait(Waiter::Event, |display| display.pop_event().map(Ok))
+            .await
+    }
+
+    #[inline]
+    async fn send_request_raw(&self, mut req: RequestInfo) -> crate::Result<u16> {
+        req.check_fd_count()?;
+
+        // the display's own send path reads from the connection if it needs to query the extension, which
+        // would race with whichever task is reading, so the extension is queried here instead
+        if let Some(extension) = req.extension {
+            self.query_extension(&req, extension).await?;
+        }
+        self.send_in_turn(req).await
+    }
+
+    /// Send a request once no other task is sending one.
+    #[inline]
+    async fn send_in_turn(&self, req: RequestInfo) -> crate::Result<u16> {
+        let mut turn = SendTurn {
+            state: &self.state,
+            request: Some(req),
+            sending: false,
+        };
+        future::poll_fn(|cx| turn.poll(cx)).await
+    }
+
+    /// Make sure the display knows the opcode of the request's extension.
+    #[inline]
+    async fn query_extension(&self, req: &RequestInfo, extension: &'static str) -> crate::Result {
+        let key = output::str_to_key(extension);
+
+        loop {
+            let (presence, prefetched) = self.with_display(|display| {
+                let presence = display.get_extension_presence(&key);
+                let prefetched = match presence {
+                    ExtensionPresence::Unknown => display.take_pending_extension_query(&key),
+                    _ => None,
+                };
+                (presence, prefetched)
+            });
+
+            let seq = match (presence, prefetched) {
+                (ExtensionPresence::Present(_), _) => return Ok(()),
+                (ExtensionPresence::Absent, _) => {
+                    return Err(output::extension_not_present(req, extension))
+                }
+                (ExtensionPresence::Unknown, Some(seq)) => seq,
+                (ExtensionPresence::Unknown, None) => {
+                    let qer = self.with_display(|display| {
+                        RequestInfo::from_request(
+                            QueryExtensionRequest {
+                                name: extension.to_string(),
+                                ..Default::default()
+                            },
+                            display.bigreq_enabled(),
+                            display.max_request_len(),
+                        )
+                    });
+                    self.send_in_turn(qer).await?
+                }
+            };
+
+            let PendingReply { data, fds } = self.wait_for_reply(seq).await?;
+            let qer = decode_reply::<QueryExtensionRequest>(&data, fds)?;
+            self.with_display(|display| output::cache_extension_query(display, key, &qer));
+        }
+    }
+
+    #[inline]
+    async fn wait_for_reply(&self, seq: u16) -> crate::Result<PendingReply> {
+        self.wait(Waiter::Reply(seq), |display| {
+            match display.take_pending_reply(seq) {
+                Some(reply) => Some(Ok(reply)),
+                // the server sends an error instead of a reply if the request failed
+                None => display.check_for_pending_error(seq).err().map(Err),
+            }
+        })
+        .await
+    }
+
+    /// Read from the connection until `check` returns something.
+    #[inline]
+    async fn wait<T, F: FnMut(&mut Dpy) -> Option<crate::Result<T>>>(
+        &self,
+        waiter: Waiter,
+        mut check: F,
+    ) -> crate::Result<T> {
+        let _guard = WaitGuard {
+            state: &self.state,
+            waiter,
+        };
+        future::poll_fn(|cx| lock(&self.state).poll_until(waiter, cx, &mut check)).await
+    }
+}
+
+impl<Dpy: AsyncDisplay> State<Dpy> {
+    #[inline]
+    fn poll_until<T, F: FnMut(&mut Dpy) -> Option<crate::Result<T>>>(
+        &mut self,
+        waiter: Waiter,
+        cx: &mut Context<'_>,
+        check: &mut F,
+    ) -> Poll<crate::Result<T>> {
+        loop {
+            if let Some(res) = check(&mut self.display) {
+                return Poll::Ready(res);
+            }
+
+            match self.poll_read(cx) {
+                Poll::Ready(Ok(())) => {}
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => {
+                    self.register(waiter, cx.waker());
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+
+    /// Read a packet from the connection and wake whoever it was for.
+    #[inline]
+    fn poll_read(&mut self, cx: &mut Context<'_>) -> Poll<crate::Result> {
+        let res = match self.display.poll_wait(cx) {
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(res) => res,
+        };
+
+        if res.is_err() {
+            // let everyone see the error for themselves
+            self.reply_waiters.values().for_each(Waker::wake_by_ref);
+        } else {
+            // a request is no longer pending once its reply or error has arrived
+            let display = &self.display;
+            self.reply_waiters.retain(|&seq, waker| {
+                let resolved = display.get_pending_request(seq).is_none();
+                if resolved {
+                    waker.wake_by_ref();
+                }
+                !resolved
+            });
+        }
+        self.event_waiters.drain(..).for_each(Waker::wake);
+
+        Poll::Ready(res)
+    }
+}
+
+impl<Dpy> State<Dpy> {
+    #[inline]
+    fn register(&mut self, waiter: Waiter, waker: &Waker) {
+        match waiter {
+            Waiter::Reply(seq) => {
+                self.reply_waiters.insert(seq, waker.clone());
+            }
+            Waiter::Event => {
+                if !self.event_waiters.iter().any(|w| w.will_wake(waker)) {
+                    self.event_waiters.push(waker.clone());
+                }
+            }
+        }
+    }
+
+    /// Wake one of the tasks waiting on the connection, so that it takes over reading from it. The connection
+    /// only wakes the task that polled it last, which may be the task that's leaving.
+    #[inline]
+    fn hand_off(&mut self) {
+        if let Some(waker) = self.event_waiters.pop() {
+            waker.wake();
+        } else if let Some(waker) = self.reply_waiters.values().next() {
+            waker.wake_by_ref();
+        }
+    }
+
+    #[inline]
+    fn finish_sending(&mut self) {
+        self.sending = Sending::Idle;
+        self.send_queue.drain(..).for_each(Waker::wake);
+    }
+}
+
+#[inline]
+fn lock<Dpy>(state: &Mutex<State<Dpy>>) -> MutexGuard<'_, State<Dpy>> {
+    // the display is only poisoned if a closure passed to `with_display` panicked, which doesn't leave it in
+    // an inconsistent state
+    state.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Removes a waiting task once it stops waiting, whether it got what it was waiting for or was dropped.
+struct WaitGuard<'a, Dpy> {
+    state: &'a Mutex<State<Dpy>>,
+    waiter: Waiter,
+}
+
+impl<Dpy> Drop for WaitGuard<'_, Dpy> {
+    #[inline]
+    fn drop(&mut self) {
+        let mut state = lock(self.state);
+        if let Waiter::Reply(seq) = self.waiter {
+            state.reply_waiters.remove(&seq);
+        }
+        state.hand_off();
+    }
+}
+
+/// A task's attempt to send a request.
+struct SendTurn<'a, Dpy> {
+    state: &'a Mutex<State<Dpy>>,
+    request: Option<RequestInfo>,
+    // whether or not this task has begun sending its request and isn't done yet
+    sending: bool,
+}
+
+impl<Dpy: AsyncDisplay> SendTurn<'_, Dpy> {
+    #[inline]
+    fn poll(&mut self, cx: &mut Context<'_>) -> Poll<crate::Result<u16>> {
+        let mut state = lock(self.state);
+
+        while !self.sending {
+            match state.sending {
+                Sending::Busy => {
+                    state.send_queue.push(cx.waker().clone());
+                    return Poll::Pending;
+                }
+                Sending::Abandoned => match state.display.poll_send_request_raw(cx) {
+                    Poll::Pending => {
+                        state.send_queue.push(cx.waker().clone());
+                        return Poll::Pending;
+                    }
+                    Poll::Ready(res) => {
+                        if let Err(e) = res {
+                            log::error!("Failed to send abandoned request: {}", e);
+                        }
+                        state.finish_sending();
+                    }
+                },
+                Sending::Idle => {
+                    // the display's own send path reads from the connection to make room for another reply,
+                    // so that's done here as well
+                    if output::over_pending_limit(&state.display) {
+                        if state.display.awaited_reply_count() == 0 {
+                            return Poll::Ready(Err(BreadError::WouldBlock));
+                        }
+
+                        match state.poll_read(cx) {
+                            Poll::Ready(Ok(())) => continue,
+                            Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                            Poll::Pending => {
+                                state.register(Waiter::Event, cx.waker());
+                                return Poll::Pending;
+                            }
+                        }
+                    }
+
+                    let req = self.request.take().expect("Polled send after completion");
+                    state.display.begin_send_request_raw(req);
+                    state.sending = Sending::Busy;
+                    self.sending = true;
+                }
+            }
+        }
+
+        let res = state.display.poll_send_request_raw(cx);
+        if res.is_ready() {
+            self.sending = false;
+            state.finish_sending();
+        }
+        res
+    }
+}
+
+impl<Dpy> Drop for SendTurn<'_, Dpy> {
+    #[inline]
+    fn drop(&mut self) {
+        if self.sending {
+            let mut state = lock(self.state);
+            state.sending = Sending::Abandoned;
+            state.send_queue.drain(..).for_each(Waker::wake);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use crate::display::{mock_display, mock_setup, MockConnection};
+
+    #[test]
+    fn shared_display_routes_replies_to_their_tasks() {
+        use crate::{
+            auto::xproto::{Atom, InternAtomReply, InternAtomRequest},
+            display::SharedDisplay,
+        };
+        use futures_lite::future;
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        // the replies come back in the opposite order from the one the tasks wait on them in
+        for (sequence, atom) in &[(3, 30), (2, 20)] {
+            conn.feed_reply(&InternAtomReply {
+                reply_type: 1,
+                sequence: *sequence,
+                atom: Atom::const_from_xid(*atom),
+                ..Default::default()
+            });
+        }
+
+        let display = mock_display(&mut conn);
+        let shared = SharedDisplay::new(display);
+        let other = shared.clone();
+        let intern = |name: &str| InternAtomRequest {
+            name: name.into(),
+            ..Default::default()
+        };
+
+        let (first, second) = future::block_on(async {
+            let first = shared.send_request_async(intern("FIRST")).await.unwrap();
+            let second = other.send_request_async(intern("SECOND")).await.unwrap();
+            future::zip(
+                shared.resolve_request_async(first),
+                other.resolve_request_async(second),
+            )
+            .await
+        });
+        assert_eq!(first.unwrap().atom, Atom::const_from_xid(20));
+        assert_eq!(second.unwrap().atom, Atom::const_from_xid(30));
+
+        drop(other);
+        assert!(shared.try_into_inner().is_ok());
+    }
+}
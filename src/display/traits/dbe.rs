@@ -0,0 +1,227 @@
+// MIT/Apache2 License
+
+use crate::{
+    auto::{
+        dbe::{
+            AllocateBackBufferRequest, BackBuffer, DeallocateBackBufferRequest,
+            QueryVersionRequest, SwapAction, SwapBuffersRequest, SwapInfo,
+        },
+        xproto::{Drawable, Window},
+    },
+    display::{generate_xid, prelude::*, Display, RequestCookie},
+};
+
+#[cfg(feature = "async")]
+use crate::{
+    auto::dbe::QueryVersionReply,
+    display::{
+        futures::{ExchangeRequestFuture, ExchangeXidFuture, MapFuture, SendRequestFuture},
+        AsyncDisplay,
+    },
+    util::BoxedFnOnce,
+};
+#[cfg(feature = "async")]
+use alloc::boxed::Box;
+
+/// Back buffers can be drawn to like any other drawable.
+impl From<BackBuffer> for Drawable {
+    #[inline]
+    fn from(bb: BackBuffer) -> Drawable {
+        Drawable::const_from_xid(bb.xid)
+    }
+}
+
+#[inline]
+fn swap_buffers_request(swaps: &[(Window, SwapAction)]) -> SwapBuffersRequest {
+    SwapBuffersRequest {
+        actions: swaps
+            .iter()
+            .map(|&(window, swap_action)| SwapInfo {
+                window,
+                swap_action,
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+pub trait DisplayDbeExt: Display {
+    /// Query the version of the DBE extension that the server supports.
+    #[inline]
+    fn dbe_query_version(
+        &mut self,
+        required_major: u8,
+        required_minor: u8,
+    ) -> crate::Result<RequestCookie<QueryVersionRequest>> {
+        self.send_request(QueryVersionRequest {
+            major_version: required_major,
+            minor_version: required_minor,
+            ..Default::default()
+        })
+    }
+
+    /// Immediately query the version of the DBE extension that the server supports.
+    #[inline]
+    fn dbe_query_version_immediate(
+        &mut self,
+        required_major: u8,
+        required_minor: u8,
+    ) -> crate::Result<(u8, u8)> {
+        let tok = self.dbe_query_version(required_major, required_minor)?;
+        let repl = self.resolve_request(tok)?;
+        Ok((repl.major_version, repl.minor_version))
+    }
+
+    /// Allocate a back buffer for a window. Drawing to the back buffer does not affect the window until the
+    /// buffers are swapped; `swap_action` is a hint as to what the back buffer should contain afterwards.
+    #[inline]
+    fn dbe_allocate_back_buffer(
+        &mut self,
+        window: Window,
+        swap_action: SwapAction,
+    ) -> crate::Result<BackBuffer> {
+        let buffer = BackBuffer::const_from_xid(generate_xid(self)?);
+        self.exchange_request(AllocateBackBufferRequest {
+            window,
+            buffer,
+            swap_action,
+            ..Default::default()
+        })?;
+        Ok(buffer)
+    }
+
+    /// Swap the front and back buffers of each of the given windows. Each window is paired with what its
+    /// new back buffer should contain.
+    #[inline]
+    fn dbe_swap_buffers(&mut self, swaps: &[(Window, SwapAction)]) -> crate::Result {
+        self.exchange_request(swap_buffers_request(swaps))
+    }
+
+    /// Free a back buffer. The window's front buffer is not affected.
+    #[inline]
+    fn dbe_deallocate_back_buffer(&mut self, buffer: BackBuffer) -> crate::Result {
+        self.exchange_request(DeallocateBackBufferRequest {
+            buffer,
+            ..Default::default()
+        })
+    }
+}
+
+impl<D: Display + ?Sized> DisplayDbeExt for D {}
+
+#[cfg(feature = "async")]
+pub trait AsyncDisplayDbeExt: AsyncDisplay {
+    /// Query the version of the DBE extension that the server supports, async redox.
+    #[inline]
+    fn dbe_query_version_async(
+        &mut self,
+        required_major: u8,
+        required_minor: u8,
+    ) -> SendRequestFuture<'_, Self, QueryVersionRequest> {
+        self.send_request_async(QueryVersionRequest {
+            major_version: required_major,
+            minor_version: required_minor,
+            ..Default::default()
+        })
+    }
+
+    /// Immediately query the version of the DBE extension that the server supports, async redox.
+    #[inline]
+    fn dbe_query_version_immediate_async(
+        &mut self,
+        required_major: u8,
+        required_minor: u8,
+    ) -> MapFuture<
+        ExchangeRequestFuture<'_, Self, QueryVersionRequest>,
+        fn(crate::Result<QueryVersionReply>) -> crate::Result<(u8, u8)>,
+    > {
+        MapFuture::run(
+            self.exchange_request_async(QueryVersionRequest {
+                major_version: required_major,
+                minor_version: required_minor,
+                ..Default::default()
+            }),
+            |repl| repl.map(|repl| (repl.major_version, repl.minor_version)),
+        )
+    }
+
+    /// Allocate a back buffer for a window, async redox.
+    #[inline]
+    fn dbe_allocate_back_buffer_async(
+        &mut self,
+        window: Window,
+        swap_action: SwapAction,
+    ) -> ExchangeXidFuture<
+        '_,
+        Self,
+        AllocateBackBufferRequest,
+        BackBuffer,
+        BoxedFnOnce<BackBuffer, AllocateBackBufferRequest>,
+    > {
+        let mut abbr = AllocateBackBufferRequest {
+            window,
+            swap_action,
+            ..Default::default()
+        };
+        self.exchange_xid_async(Box::new(move |buffer| {
+            abbr.buffer = buffer;
+            abbr
+        }))
+    }
+
+    /// Swap the front and back buffers of each of the given windows, async redox.
+    #[inline]
+    fn dbe_swap_buffers_async(
+        &mut self,
+        swaps: &[(Window, SwapAction)],
+    ) -> ExchangeRequestFuture<'_, Self, SwapBuffersRequest> {
+        self.exchange_request_async(swap_buffers_request(swaps))
+    }
+
+    /// Free a back buffer, async redox.
+    #[inline]
+    fn dbe_deallocate_back_buffer_async(
+        &mut self,
+        buffer: BackBuffer,
+    ) -> ExchangeRequestFuture<'_, Self, DeallocateBackBufferRequest> {
+        self.exchange_request_async(DeallocateBackBufferRequest {
+            buffer,
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D: AsyncDisplay + ?Sized> AsyncDisplayDbeExt for D {}
+
+#[cfg(test)]
+mod tests {
+    use super::swap_buffers_request;
+    use crate::{
+        auto::{dbe::SwapAction, xproto::Window},
+        display::RequestInfo,
+    };
+    use alloc::vec;
+
+    #[test]
+    fn swap_buffers_layout() {
+        let req = swap_buffers_request(&[
+            (Window::const_from_xid(0x0200_0001), SwapAction::Background),
+            (Window::const_from_xid(0x0200_0002), SwapAction::Copied),
+        ]);
+        let data = RequestInfo::from_request(req, false, usize::from(u16::MAX) * 4).data;
+
+        // the header, the number of swaps, then each window followed by its action and three bytes of padding
+        let len = 6u16.to_ne_bytes();
+        let count = 2u32.to_ne_bytes();
+        let first = 0x0200_0001u32.to_ne_bytes();
+        let second = 0x0200_0002u32.to_ne_bytes();
+        let mut expected = vec![0, 0, len[0], len[1]];
+        expected.extend_from_slice(&count);
+        expected.extend_from_slice(&first);
+        expected.extend_from_slice(&[1, 0, 0, 0]);
+        expected.extend_from_slice(&second);
+        expected.extend_from_slice(&[3, 0, 0, 0]);
+        assert_eq!(&data[..], &expected[..]);
+    }
+}
@@ -161,6 +161,7 @@ pub trait DisplayDri3Ext: Display {
             offset1: offsets[1],
             offset2: offsets[2],
             offset3: offsets[3],
+            num_buffers: fds.len() as u8,
             buffers: fds,
             ..Default::default()
         })?;
@@ -402,6 +403,7 @@ pub trait AsyncDisplayDri3Ext: AsyncDisplay {
             offset1: offsets[1],
             offset2: offsets[2],
             offset3: offsets[3],
+            num_buffers: fds.len() as u8,
             buffers: fds,
             ..Default::default()
         };
@@ -518,4 +520,5 @@ pub trait AsyncDisplayDri3Ext: AsyncDisplay {
     }
 }
 
+#[cfg(feature = "async")]
 impl<D: AsyncDisplay + ?Sized> AsyncDisplayDri3Ext for D {}
@@ -0,0 +1,70 @@
+// MIT/Apache2 License
+
+//! The Generic Event Extension, which carries the events of extensions that have run out of room in the core
+//! event space, such as XI2 and Present. A client has to tell the server which version of it the client
+//! speaks before some servers will deliver any generic events. Displays do this on their own before the first
+//! request to an extension that uses generic events, so `ge_query_version` is only needed to find out which
+//! version the server speaks.
+
+use crate::{
+    auto::ge::QueryVersionRequest,
+    display::{prelude::*, Display},
+};
+
+#[cfg(feature = "async")]
+use crate::{
+    auto::ge::QueryVersionReply,
+    display::{
+        futures::{ExchangeRequestFuture, MapFuture},
+        AsyncDisplay,
+    },
+};
+
+#[inline]
+fn query_version_request(client_major: u16, client_minor: u16) -> QueryVersionRequest {
+    QueryVersionRequest {
+        client_major_version: client_major,
+        client_minor_version: client_minor,
+        ..Default::default()
+    }
+}
+
+pub trait DisplayGeExt: Display {
+    /// Tell the server which version of the Generic Event Extension the client speaks, and get back the
+    /// version the server speaks.
+    #[inline]
+    fn ge_query_version(
+        &mut self,
+        client_major: u16,
+        client_minor: u16,
+    ) -> crate::Result<(u16, u16)> {
+        self.set_ge_negotiated();
+        let repl = self.exchange_request(query_version_request(client_major, client_minor))?;
+        Ok((repl.major_version, repl.minor_version))
+    }
+}
+
+impl<D: Display + ?Sized> DisplayGeExt for D {}
+
+#[cfg(feature = "async")]
+pub trait AsyncDisplayGeExt: AsyncDisplay {
+    /// Tell the server which version of the Generic Event Extension the client speaks, async redox.
+    #[inline]
+    fn ge_query_version_async(
+        &mut self,
+        client_major: u16,
+        client_minor: u16,
+    ) -> MapFuture<
+        ExchangeRequestFuture<'_, Self, QueryVersionRequest>,
+        fn(crate::Result<QueryVersionReply>) -> crate::Result<(u16, u16)>,
+    > {
+        self.set_ge_negotiated();
+        MapFuture::run(
+            self.exchange_request_async(query_version_request(client_major, client_minor)),
+            |repl| repl.map(|repl| (repl.major_version, repl.minor_version)),
+        )
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D: AsyncDisplay + ?Sized> AsyncDisplayGeExt for D {}
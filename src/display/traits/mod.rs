@@ -4,25 +4,49 @@
 
 mod xproto;
 
+#[cfg(feature = "dbe")]
+mod dbe;
 #[cfg(feature = "dri3")]
 mod dri3;
+#[cfg(feature = "ge")]
+mod ge;
 #[cfg(feature = "glx")]
 mod glx;
 #[cfg(feature = "present")]
 mod present;
+#[cfg(feature = "randr")]
+mod randr;
 #[cfg(feature = "sync")]
 mod sync;
+#[cfg(feature = "xf86vidmode")]
+mod xf86vidmode;
 #[cfg(feature = "fixes")]
 mod xfixes;
+#[cfg(feature = "xkb")]
+mod xkb;
+#[cfg(feature = "xtest")]
+mod xtest;
 
+#[cfg(feature = "dbe")]
+pub use dbe::*;
 #[cfg(feature = "dri3")]
 pub use dri3::*;
+#[cfg(feature = "ge")]
+pub use ge::*;
 #[cfg(feature = "glx")]
 pub use glx::*;
 #[cfg(feature = "present")]
 pub use present::*;
+#[cfg(feature = "randr")]
+pub use randr::*;
 #[cfg(feature = "sync")]
 pub use sync::*;
+#[cfg(feature = "xf86vidmode")]
+pub use xf86vidmode::*;
 #[cfg(feature = "fixes")]
 pub use xfixes::*;
+#[cfg(feature = "xkb")]
+pub use xkb::*;
 pub use xproto::*;
+#[cfg(feature = "xtest")]
+pub use xtest::*;
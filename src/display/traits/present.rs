@@ -5,8 +5,8 @@
 use crate::{
     auto::{
         present::{
-            Event, EventMask, Notify, PixmapRequest, QueryCapabilitiesRequest, QueryVersionRequest,
-            SelectInputRequest,
+            Event, EventMask, Notify, Option_, PixmapRequest, QueryCapabilitiesRequest,
+            QueryVersionRequest, SelectInputRequest,
         },
         randr::Crtc,
         sync::Fence,
@@ -28,6 +28,187 @@ use crate::{
     },
 };
 
+/// How and when a pixmap is presented by `present_pixmap`. The defaults present the whole pixmap at the
+/// window's origin, at the next vertical blank, without waiting on or signalling any fences, so presenting
+/// a pixmap that holds a complete frame is just `PresentParameters::new()`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PresentParameters {
+    /// An identifier that is echoed back in the `CompleteNotify` and `IdleNotify` events for this
+    /// presentation.
+    pub serial: u32,
+    /// The part of the pixmap that holds valid contents. Outside of it, the pixmap's contents are
+    /// undefined. `None` means the whole pixmap is valid.
+    pub valid: Option<Region>,
+    /// The part of the pixmap that changed since the last presentation. Only this part is copied to the
+    /// window. `None` means the whole pixmap is updated.
+    pub update: Option<Region>,
+    /// Where the pixmap's origin is placed, relative to the window's origin.
+    pub offset: (i16, i16),
+    /// The CRTC whose vertical blanks are used for timing. `None` lets the server pick one.
+    pub target_crtc: Option<Crtc>,
+    /// A fence the server waits on before it presents the pixmap.
+    pub wait_fence: Option<Fence>,
+    /// A fence the server triggers once the pixmap is no longer in use and can be drawn on again.
+    pub idle_fence: Option<Fence>,
+    pub options: Option_,
+    /// The media stream counter to present at, or the UST time in microseconds if `Option_::UST` is
+    /// set. If it has already passed, the pixmap is presented at the next frame where
+    /// `msc % divisor == remainder`, or the next frame at all if `divisor` is zero.
+    pub target_msc: u64,
+    pub divisor: u64,
+    pub remainder: u64,
+    /// Other windows that should get a `CompleteNotify` event once the pixmap has been presented, along
+    /// with the serial each should get.
+    pub notifies: Vec<Notify>,
+}
+
+impl PresentParameters {
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn serial(self, serial: u32) -> Self {
+        Self { serial, ..self }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn valid(self, valid: Region) -> Self {
+        Self {
+            valid: Some(valid),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn update(self, update: Region) -> Self {
+        Self {
+            update: Some(update),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn offset(self, x: i16, y: i16) -> Self {
+        Self {
+            offset: (x, y),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn target_crtc(self, target_crtc: Crtc) -> Self {
+        Self {
+            target_crtc: Some(target_crtc),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wait_fence(self, wait_fence: Fence) -> Self {
+        Self {
+            wait_fence: Some(wait_fence),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn idle_fence(self, idle_fence: Fence) -> Self {
+        Self {
+            idle_fence: Some(idle_fence),
+            ..self
+        }
+    }
+
+    /// Present the pixmap as soon as possible if the target has already passed, rather than waiting for
+    /// the next vertical blank. This avoids a missed frame at the cost of possible tearing.
+    #[inline]
+    #[must_use]
+    pub fn asynchronous(self) -> Self {
+        Self {
+            options: self.options | Option_::ASYNC,
+            ..self
+        }
+    }
+
+    /// Copy the pixmap's contents into the window, rather than letting the server flip to the pixmap. The
+    /// pixmap can then be drawn on again as soon as it's been copied.
+    #[inline]
+    #[must_use]
+    pub fn copy(self) -> Self {
+        Self {
+            options: self.options | Option_::COPY,
+            ..self
+        }
+    }
+
+    /// Present at the time given in microseconds by `target`, rather than at a media stream counter.
+    #[inline]
+    #[must_use]
+    pub fn at_time(self, target: u64) -> Self {
+        Self {
+            options: self.options | Option_::UST,
+            target_msc: target,
+            ..self
+        }
+    }
+
+    /// Present at the media stream counter `target_msc`, or at the next frame where
+    /// `msc % divisor == remainder` if it's already passed.
+    #[inline]
+    #[must_use]
+    pub fn at_msc(self, target_msc: u64, divisor: u64, remainder: u64) -> Self {
+        Self {
+            target_msc,
+            divisor,
+            remainder,
+            ..self
+        }
+    }
+
+    /// Send a `CompleteNotify` event with `serial` to `window` as well, once the pixmap has been
+    /// presented.
+    #[inline]
+    #[must_use]
+    pub fn notify(mut self, window: Window, serial: u32) -> Self {
+        self.notifies.push(Notify { window, serial });
+        self
+    }
+
+    #[allow(clippy::cast_sign_loss)]
+    #[inline]
+    fn into_request(self, window: Window, pixmap: Pixmap) -> PixmapRequest {
+        let (x_off, y_off) = self.offset;
+        PixmapRequest {
+            window,
+            pixmap,
+            serial: self.serial,
+            valid: self.valid.unwrap_or_default(),
+            update: self.update.unwrap_or_default(),
+            x_off,
+            y_off,
+            target_crtc: self.target_crtc.unwrap_or_default(),
+            wait_fence: self.wait_fence.unwrap_or_default(),
+            idle_fence: self.idle_fence.unwrap_or_default(),
+            options: self.options.inner as u32,
+            target_msc: self.target_msc,
+            divisor: self.divisor,
+            remainder: self.remainder,
+            notifies: self.notifies,
+            ..Default::default()
+        }
+    }
+}
+
 pub trait DisplayPresentExt: Display {
     #[inline]
     fn query_present_version(
@@ -92,43 +273,15 @@ pub trait DisplayPresentExt: Display {
         })
     }
 
+    /// Present the contents of `pixmap` in `window`. See `PresentParameters` for what can be controlled.
     #[inline]
     fn present_pixmap(
         &mut self,
         window: Window,
         pixmap: Pixmap,
-        serial: u32,
-        valid: Region,
-        update: Region,
-        xoff: i16,
-        yoff: i16,
-        target_crtc: Crtc,
-        wait_fence: Fence,
-        idle_fence: Fence,
-        options: u32,
-        target_msc: u64,
-        divisor: u64,
-        remainder: u64,
-        notifies: Vec<Notify>,
+        params: PresentParameters,
     ) -> crate::Result {
-        self.exchange_request(PixmapRequest {
-            window,
-            pixmap,
-            serial,
-            valid,
-            update,
-            x_off: xoff,
-            y_off: yoff,
-            target_crtc,
-            wait_fence,
-            idle_fence,
-            options,
-            target_msc,
-            divisor,
-            remainder,
-            notifies,
-            ..Default::default()
-        })
+        self.exchange_request(params.into_request(window, pixmap))
     }
 }
 
@@ -222,42 +375,56 @@ pub trait AsyncDisplayPresentExt: AsyncDisplay {
         })
     }
 
+    /// Present the contents of `pixmap` in `window`, async redox.
     #[inline]
     fn present_pixmap_async(
         &mut self,
         window: Window,
         pixmap: Pixmap,
-        serial: u32,
-        valid: Region,
-        update: Region,
-        xoff: i16,
-        yoff: i16,
-        target_crtc: Crtc,
-        wait_fence: Fence,
-        idle_fence: Fence,
-        options: u32,
-        target_msc: u64,
-        divisor: u64,
-        remainder: u64,
-        notifies: Vec<Notify>,
+        params: PresentParameters,
     ) -> ExchangeRequestFuture<'_, Self, PixmapRequest> {
-        self.exchange_request_async(PixmapRequest {
-            window,
-            pixmap,
-            serial,
-            valid,
-            update,
-            x_off: xoff,
-            y_off: yoff,
-            target_crtc,
-            wait_fence,
-            idle_fence,
-            options,
-            target_msc,
-            divisor,
-            remainder,
-            notifies,
-            ..Default::default()
-        })
+        self.exchange_request_async(params.into_request(window, pixmap))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D: AsyncDisplay + ?Sized> AsyncDisplayPresentExt for D {}
+
+#[cfg(test)]
+mod tests {
+    use super::PresentParameters;
+    use crate::{
+        auto::{present::Notify, xfixes::Region},
+        Pixmap, Window,
+    };
+
+    #[test]
+    fn parameters_fill_in_the_request() {
+        let window = Window::const_from_xid(1);
+        let pixmap = Pixmap::const_from_xid(2);
+
+        // the whole pixmap, at the next vertical blank
+        let req = PresentParameters::new().into_request(window, pixmap);
+        assert_eq!(req.valid.xid, 0);
+        assert_eq!(req.update.xid, 0);
+        assert_eq!(req.options, 0);
+
+        let req = PresentParameters::new()
+            .update(Region::const_from_xid(3))
+            .asynchronous()
+            .copy()
+            .at_time(1000)
+            .notify(Window::const_from_xid(4), 5)
+            .into_request(window, pixmap);
+        assert_eq!(req.update.xid, 3);
+        assert_eq!(req.options, 0b111);
+        assert_eq!(req.target_msc, 1000);
+        assert_eq!(
+            req.notifies,
+            [Notify {
+                window: Window::const_from_xid(4),
+                serial: 5,
+            }]
+        );
     }
 }
@@ -0,0 +1,338 @@
+// MIT/Apache2 License
+
+use crate::{
+    auto::{
+        randr::{
+            ChangeOutputPropertyRequest, GetOutputPropertyReply, GetOutputPropertyRequest,
+            ListOutputPropertiesRequest, Output,
+        },
+        xproto::{Atom, PropMode, ATOM_INTEGER},
+        AsByteSequence,
+    },
+    display::{prelude::*, Display},
+    BreadError,
+};
+use alloc::{string::String, vec, vec::Vec};
+
+#[cfg(feature = "async")]
+use crate::{
+    auto::randr::ListOutputPropertiesReply,
+    display::{
+        futures::{ExchangeRequestFuture, MapFuture},
+        AsyncDisplay,
+    },
+};
+#[cfg(feature = "async")]
+use alloc::boxed::Box;
+#[cfg(feature = "async")]
+use core::{future::Future, pin::Pin};
+
+/// The name of the output property that holds a monitor's EDID.
+const EDID_NAME: &str = "EDID";
+/// The length of the longest possible EDID, in 32-bit units: a base block and 255 extension blocks, each 128
+/// bytes long.
+const EDID_MAX_LENGTH: u32 = 256 * 128 / 4;
+
+#[inline]
+fn get_output_property_request(
+    output: Output,
+    property: Atom,
+    ty: Atom,
+    long_offset: u32,
+    long_length: u32,
+    delete: bool,
+    pending: bool,
+) -> GetOutputPropertyRequest {
+    GetOutputPropertyRequest {
+        output,
+        property,
+        ty,
+        long_offset,
+        long_length,
+        delete,
+        pending,
+        ..Default::default()
+    }
+}
+
+#[inline]
+fn change_output_property_request<T: AsByteSequence>(
+    output: Output,
+    property: Atom,
+    ty: Atom,
+    format: PropertyFormat,
+    mode: PropMode,
+    data: &[T],
+) -> ChangeOutputPropertyRequest {
+    let mut bytes = vec![0; data.iter().map(AsByteSequence::size).sum()];
+    let len = data
+        .iter()
+        .fold(0, |index, item| index + item.as_bytes(&mut bytes[index..]));
+    bytes.truncate(len);
+
+    ChangeOutputPropertyRequest {
+        output,
+        property,
+        ty,
+        format: format as u8,
+        mode,
+        num_units: data.len() as u32,
+        data: bytes,
+        ..Default::default()
+    }
+}
+
+/// Request for the whole EDID of an output, given the atom naming it.
+#[inline]
+fn edid_request(output: Output, edid: Atom) -> GetOutputPropertyRequest {
+    get_output_property_request(output, edid, ATOM_INTEGER, 0, EDID_MAX_LENGTH, false, false)
+}
+
+/// Take the EDID out of its property. An unset property has a type of zero.
+#[inline]
+fn edid_from_reply(repl: GetOutputPropertyReply) -> crate::Result<Option<Vec<u8>>> {
+    if repl.ty.xid == 0 {
+        Ok(None)
+    } else if repl.format == 8 {
+        Ok(Some(repl.data))
+    } else {
+        Err(BreadError::StaticMsg(
+            "EDID properties must have a format of 8",
+        ))
+    }
+}
+
+pub trait DisplayRandrExt: Display {
+    /// List the atoms of every property currently set on an output.
+    #[inline]
+    fn randr_list_output_properties(&mut self, output: Output) -> crate::Result<Vec<Atom>> {
+        Ok(self
+            .exchange_request(ListOutputPropertiesRequest {
+                output,
+                ..Default::default()
+            })?
+            .atoms)
+    }
+
+    /// Get part of a property of an output. This works like `GetProperty` does for windows: `long_offset`
+    /// and `long_length` are in 32-bit units, and `ty` can be zero to accept any type. If `pending` is set,
+    /// the value that will take effect the next time the output is configured is returned instead of the
+    /// current one.
+    #[inline]
+    fn randr_get_output_property(
+        &mut self,
+        output: Output,
+        property: Atom,
+        ty: Atom,
+        long_offset: u32,
+        long_length: u32,
+        delete: bool,
+        pending: bool,
+    ) -> crate::Result<GetOutputPropertyReply> {
+        self.exchange_request(get_output_property_request(
+            output,
+            property,
+            ty,
+            long_offset,
+            long_length,
+            delete,
+            pending,
+        ))
+    }
+
+    /// Change a property of an output. Many output properties only take effect once the output is
+    /// configured again.
+    #[inline]
+    fn randr_change_output_property<T: AsByteSequence>(
+        &mut self,
+        output: Output,
+        property: Atom,
+        ty: Atom,
+        format: PropertyFormat,
+        mode: PropMode,
+        data: &[T],
+    ) -> crate::Result {
+        self.exchange_request(change_output_property_request(
+            output, property, ty, format, mode, data,
+        ))
+    }
+
+    /// Get the raw EDID of the monitor connected to an output. Returns `None` if the output has no EDID,
+    /// which is usually because nothing is connected to it.
+    #[inline]
+    fn randr_get_edid(&mut self, output: Output) -> crate::Result<Option<Vec<u8>>> {
+        let edid = self.intern_atom_immediate(String::from(EDID_NAME), true)?;
+        // if the atom doesn't exist, no output could have an EDID
+        if edid.xid == 0 {
+            return Ok(None);
+        }
+
+        edid_from_reply(self.exchange_request(edid_request(output, edid))?)
+    }
+}
+
+impl<D: Display + ?Sized> DisplayRandrExt for D {}
+
+/// The future returned by `randr_get_edid_async`. It takes two round trips, so it's boxed.
+#[cfg(feature = "async")]
+pub type EdidFuture<'a> = Pin<Box<dyn Future<Output = crate::Result<Option<Vec<u8>>>> + 'a>>;
+
+#[cfg(feature = "async")]
+pub trait AsyncDisplayRandrExt: AsyncDisplay {
+    /// List the atoms of every property currently set on an output, async redox.
+    #[inline]
+    fn randr_list_output_properties_async(
+        &mut self,
+        output: Output,
+    ) -> MapFuture<
+        ExchangeRequestFuture<'_, Self, ListOutputPropertiesRequest>,
+        fn(crate::Result<ListOutputPropertiesReply>) -> crate::Result<Vec<Atom>>,
+    > {
+        MapFuture::run(
+            self.exchange_request_async(ListOutputPropertiesRequest {
+                output,
+                ..Default::default()
+            }),
+            |repl| repl.map(|repl| repl.atoms),
+        )
+    }
+
+    /// Get part of a property of an output, async redox.
+    #[inline]
+    fn randr_get_output_property_async(
+        &mut self,
+        output: Output,
+        property: Atom,
+        ty: Atom,
+        long_offset: u32,
+        long_length: u32,
+        delete: bool,
+        pending: bool,
+    ) -> ExchangeRequestFuture<'_, Self, GetOutputPropertyRequest> {
+        self.exchange_request_async(get_output_property_request(
+            output,
+            property,
+            ty,
+            long_offset,
+            long_length,
+            delete,
+            pending,
+        ))
+    }
+
+    /// Change a property of an output, async redox.
+    #[inline]
+    fn randr_change_output_property_async<T: AsByteSequence>(
+        &mut self,
+        output: Output,
+        property: Atom,
+        ty: Atom,
+        format: PropertyFormat,
+        mode: PropMode,
+        data: &[T],
+    ) -> ExchangeRequestFuture<'_, Self, ChangeOutputPropertyRequest> {
+        self.exchange_request_async(change_output_property_request(
+            output, property, ty, format, mode, data,
+        ))
+    }
+
+    /// Get the raw EDID of the monitor connected to an output, async redox.
+    #[inline]
+    fn randr_get_edid_async(&mut self, output: Output) -> EdidFuture<'_> {
+        Box::pin(async move {
+            let edid = self
+                .intern_atom_immediate_async(String::from(EDID_NAME), true)
+                .await?;
+            if edid.xid == 0 {
+                return Ok(None);
+            }
+
+            edid_from_reply(
+                self.exchange_request_async(edid_request(output, edid))
+                    .await?,
+            )
+        })
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D: AsyncDisplay + ?Sized> AsyncDisplayRandrExt for D {}
+
+#[cfg(test)]
+mod tests {
+    use crate::display::{mock_display, mock_setup, MockConnection};
+
+    #[test]
+    fn randr_edid_is_read() {
+        use crate::{
+            auto::{
+                randr::{GetOutputPropertyReply, Output},
+                xproto::{Atom, InternAtomReply, QueryExtensionReply, ATOM_INTEGER},
+            },
+            display::traits::DisplayRandrExt,
+        };
+        use alloc::vec;
+
+        let edid = Atom::const_from_xid(400);
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_reply(&InternAtomReply {
+            reply_type: 1,
+            sequence: 2,
+            atom: edid,
+            ..Default::default()
+        });
+        conn.feed_reply(&QueryExtensionReply {
+            reply_type: 1,
+            sequence: 3,
+            present: true,
+            major_opcode: 140,
+            ..Default::default()
+        });
+        conn.feed_reply(&GetOutputPropertyReply {
+            reply_type: 1,
+            format: 8,
+            sequence: 4,
+            length: 32,
+            ty: ATOM_INTEGER,
+            num_items: 128,
+            data: vec![0xEE; 128],
+            ..Default::default()
+        });
+        // an output with nothing connected to it has no EDID
+        conn.feed_reply(&InternAtomReply {
+            reply_type: 1,
+            sequence: 5,
+            atom: edid,
+            ..Default::default()
+        });
+        conn.feed_reply(&GetOutputPropertyReply {
+            reply_type: 1,
+            sequence: 6,
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        assert_eq!(
+            display
+                .randr_get_edid(Output::const_from_xid(0x42))
+                .unwrap(),
+            Some(vec![0xEE; 128])
+        );
+        assert_eq!(
+            display
+                .randr_get_edid(Output::const_from_xid(0x43))
+                .unwrap(),
+            None
+        );
+        drop(display);
+
+        // GetOutputProperty is 28 bytes long, and asks for the EDID atom as an INTEGER
+        let written = conn.take_outgoing();
+        let request = &written[written.len() - 28..];
+        assert_eq!(request[0], 140);
+        assert_eq!(request[1], 15);
+        assert_eq!(&request[4..8], &0x43u32.to_ne_bytes());
+        assert_eq!(&request[8..12], &400u32.to_ne_bytes());
+        assert_eq!(&request[12..16], &ATOM_INTEGER.xid.to_ne_bytes());
+    }
+}
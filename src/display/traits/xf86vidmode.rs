@@ -0,0 +1,406 @@
+// MIT/Apache2 License
+
+use crate::{
+    auto::xf86vidmode::{
+        GetGammaRampReply, GetGammaRampRequest, GetGammaRampSizeRequest, GetGammaReply,
+        GetGammaRequest, GetModeLineReply, GetModeLineRequest, SetGammaRampRequest,
+        SetGammaRequest,
+    },
+    display::{prelude::*, Display},
+    BreadError,
+};
+use alloc::vec::Vec;
+
+#[cfg(feature = "async")]
+use crate::{
+    display::{
+        futures::{ExchangeRequestFuture, MapFuture},
+        AsyncDisplay, EitherFuture,
+    },
+    Request,
+};
+#[cfg(feature = "async")]
+use futures_lite::future::{self, Ready};
+
+/// The server stores gamma values as fixed point numbers, scaled by this much.
+const GAMMA_SCALE: f32 = 10_000.0;
+/// The smallest gamma value the server accepts.
+const GAMMA_MIN: f32 = 0.1;
+/// The largest gamma value the server accepts.
+const GAMMA_MAX: f32 = 10.0;
+
+/// The gamma correction applied to each channel of a screen. `1.0` leaves a channel unchanged.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Gamma {
+    pub red: f32,
+    pub green: f32,
+    pub blue: f32,
+}
+
+impl Default for Gamma {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            red: 1.0,
+            green: 1.0,
+            blue: 1.0,
+        }
+    }
+}
+
+/// A screen's gamma ramp: the lookup table that maps each color value to the intensity that is actually
+/// displayed, for each channel. Every channel must have exactly as many entries as the screen's ramp size.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct GammaRamp {
+    pub red: Vec<u16>,
+    pub green: Vec<u16>,
+    pub blue: Vec<u16>,
+}
+
+impl GammaRamp {
+    /// Get the gamma ramp of the given screen.
+    #[inline]
+    pub fn get<Dpy: Display + ?Sized>(dpy: &mut Dpy, screen: u16) -> crate::Result<Self> {
+        let size = dpy.exchange_request(gamma_ramp_size_request(screen))?.size;
+        let repl = dpy.exchange_request(GetGammaRampRequest {
+            screen,
+            size,
+            ..Default::default()
+        })?;
+        Ok(Self::from_reply(repl, size))
+    }
+
+    /// Get the gamma ramp of the given screen, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn get_async<Dpy: AsyncDisplay + ?Sized>(
+        dpy: &mut Dpy,
+        screen: u16,
+    ) -> crate::Result<Self> {
+        let size = dpy
+            .exchange_request_async(gamma_ramp_size_request(screen))
+            .await?
+            .size;
+        let repl = dpy
+            .exchange_request_async(GetGammaRampRequest {
+                screen,
+                size,
+                ..Default::default()
+            })
+            .await?;
+        Ok(Self::from_reply(repl, size))
+    }
+
+    /// Set this as the gamma ramp of the given screen. This fails without contacting the server any further
+    /// if the ramp does not match the screen's ramp size.
+    #[inline]
+    pub fn set<Dpy: Display + ?Sized>(&self, dpy: &mut Dpy, screen: u16) -> crate::Result {
+        let size = dpy.exchange_request(gamma_ramp_size_request(screen))?.size;
+        dpy.exchange_request(self.set_request(screen, size)?)
+    }
+
+    /// Set this as the gamma ramp of the given screen, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn set_async<Dpy: AsyncDisplay + ?Sized>(
+        &self,
+        dpy: &mut Dpy,
+        screen: u16,
+    ) -> crate::Result {
+        let size = dpy
+            .exchange_request_async(gamma_ramp_size_request(screen))
+            .await?
+            .size;
+        dpy.exchange_request_async(self.set_request(screen, size)?)
+            .await
+    }
+
+    /// The channels in the reply are padded to an even length, so cut them back down to the ramp size.
+    #[inline]
+    fn from_reply(repl: GetGammaRampReply, size: u16) -> Self {
+        let GetGammaRampReply {
+            mut red,
+            mut green,
+            mut blue,
+            ..
+        } = repl;
+        let size = usize::from(size);
+        red.truncate(size);
+        green.truncate(size);
+        blue.truncate(size);
+        Self { red, green, blue }
+    }
+
+    /// Build the `SetGammaRamp` request for a screen with the given ramp size, padding each channel to the
+    /// even length the protocol expects.
+    #[inline]
+    fn set_request(&self, screen: u16, size: u16) -> crate::Result<SetGammaRampRequest> {
+        let expected = usize::from(size);
+        if self.red.len() != expected || self.green.len() != expected || self.blue.len() != expected
+        {
+            return Err(BreadError::StaticMsg(
+                "Gamma ramp channels must have exactly as many entries as the screen's ramp size",
+            ));
+        }
+
+        let padded = (expected + 1) & !1;
+        let pad = |channel: &[u16]| {
+            let mut channel = channel.to_vec();
+            channel.resize(padded, 0);
+            channel
+        };
+
+        Ok(SetGammaRampRequest {
+            screen,
+            size,
+            red: pad(&self.red),
+            green: pad(&self.green),
+            blue: pad(&self.blue),
+            ..Default::default()
+        })
+    }
+}
+
+#[inline]
+fn gamma_ramp_size_request(screen: u16) -> GetGammaRampSizeRequest {
+    GetGammaRampSizeRequest {
+        screen,
+        ..Default::default()
+    }
+}
+
+#[inline]
+fn get_gamma_request(screen: u16) -> GetGammaRequest {
+    GetGammaRequest {
+        screen,
+        ..Default::default()
+    }
+}
+
+#[inline]
+fn get_mode_line_request(screen: u16) -> GetModeLineRequest {
+    GetModeLineRequest {
+        screen,
+        ..Default::default()
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+#[inline]
+fn gamma_from_reply(repl: GetGammaReply) -> Gamma {
+    Gamma {
+        red: repl.red as f32 / GAMMA_SCALE,
+        green: repl.green as f32 / GAMMA_SCALE,
+        blue: repl.blue as f32 / GAMMA_SCALE,
+    }
+}
+
+#[inline]
+fn set_gamma_request(screen: u16, gamma: Gamma) -> crate::Result<SetGammaRequest> {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn scale(value: f32) -> crate::Result<u32> {
+        // this also rules out NaN
+        if (GAMMA_MIN..=GAMMA_MAX).contains(&value) {
+            Ok((value * GAMMA_SCALE).round() as u32)
+        } else {
+            Err(BreadError::StaticMsg(
+                "Gamma values must be between 0.1 and 10.0",
+            ))
+        }
+    }
+
+    Ok(SetGammaRequest {
+        screen,
+        red: scale(gamma.red)?,
+        green: scale(gamma.green)?,
+        blue: scale(gamma.blue)?,
+        ..Default::default()
+    })
+}
+
+pub trait DisplayXf86vidmodeExt: Display {
+    /// Get the gamma correction of the given screen.
+    #[inline]
+    fn vidmode_get_gamma(&mut self, screen: u16) -> crate::Result<Gamma> {
+        self.exchange_request(get_gamma_request(screen))
+            .map(gamma_from_reply)
+    }
+
+    /// Set the gamma correction of the given screen. Each value must be between 0.1 and 10.0.
+    #[inline]
+    fn vidmode_set_gamma(&mut self, screen: u16, gamma: Gamma) -> crate::Result {
+        self.exchange_request(set_gamma_request(screen, gamma)?)
+    }
+
+    /// Get the timings of the video mode the given screen is currently using.
+    #[inline]
+    fn vidmode_get_mode_line(&mut self, screen: u16) -> crate::Result<GetModeLineReply> {
+        self.exchange_request(get_mode_line_request(screen))
+    }
+
+    /// Get the number of entries in each channel of the given screen's gamma ramp.
+    #[inline]
+    fn vidmode_get_gamma_ramp_size(&mut self, screen: u16) -> crate::Result<u16> {
+        self.exchange_request(gamma_ramp_size_request(screen))
+            .map(|repl| repl.size)
+    }
+
+    /// Get the gamma ramp of the given screen.
+    #[inline]
+    fn vidmode_get_gamma_ramp(&mut self, screen: u16) -> crate::Result<GammaRamp> {
+        GammaRamp::get(self, screen)
+    }
+
+    /// Set the gamma ramp of the given screen. Every channel of the ramp must have exactly as many entries
+    /// as the screen's ramp size.
+    #[inline]
+    fn vidmode_set_gamma_ramp(&mut self, screen: u16, ramp: &GammaRamp) -> crate::Result {
+        ramp.set(self, screen)
+    }
+}
+
+impl<D: Display + ?Sized> DisplayXf86vidmodeExt for D {}
+
+/// A request whose reply is converted into something more convenient before it is returned.
+#[cfg(feature = "async")]
+pub type VidmodeMapFuture<'a, D, R, T> = MapFuture<
+    ExchangeRequestFuture<'a, D, R>,
+    fn(crate::Result<<R as Request>::Reply>) -> crate::Result<T>,
+>;
+
+#[cfg(feature = "async")]
+pub trait AsyncDisplayXf86vidmodeExt: AsyncDisplay {
+    /// Get the gamma correction of the given screen, async redox.
+    #[inline]
+    fn vidmode_get_gamma_async(
+        &mut self,
+        screen: u16,
+    ) -> VidmodeMapFuture<'_, Self, GetGammaRequest, Gamma> {
+        MapFuture::run(
+            self.exchange_request_async(get_gamma_request(screen)),
+            |repl| repl.map(gamma_from_reply),
+        )
+    }
+
+    /// Set the gamma correction of the given screen, async redox.
+    #[inline]
+    fn vidmode_set_gamma_async(
+        &mut self,
+        screen: u16,
+        gamma: Gamma,
+    ) -> EitherFuture<Ready<crate::Result>, ExchangeRequestFuture<'_, Self, SetGammaRequest>> {
+        match set_gamma_request(screen, gamma) {
+            Ok(sgr) => EitherFuture::Right {
+                future: self.exchange_request_async(sgr),
+            },
+            Err(e) => EitherFuture::Left {
+                future: future::ready(Err(e)),
+            },
+        }
+    }
+
+    /// Get the timings of the video mode the given screen is currently using, async redox.
+    #[inline]
+    fn vidmode_get_mode_line_async(
+        &mut self,
+        screen: u16,
+    ) -> ExchangeRequestFuture<'_, Self, GetModeLineRequest> {
+        self.exchange_request_async(get_mode_line_request(screen))
+    }
+
+    /// Get the number of entries in each channel of the given screen's gamma ramp, async redox.
+    #[inline]
+    fn vidmode_get_gamma_ramp_size_async(
+        &mut self,
+        screen: u16,
+    ) -> VidmodeMapFuture<'_, Self, GetGammaRampSizeRequest, u16> {
+        MapFuture::run(
+            self.exchange_request_async(gamma_ramp_size_request(screen)),
+            |repl| repl.map(|repl| repl.size),
+        )
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D: AsyncDisplay + ?Sized> AsyncDisplayXf86vidmodeExt for D {}
+
+#[cfg(test)]
+mod tests {
+    use super::{gamma_from_reply, set_gamma_request, Gamma, GammaRamp};
+    use crate::auto::xf86vidmode::{GetGammaRampReply, GetGammaReply};
+    use alloc::vec;
+
+    #[test]
+    fn gamma_is_fixed_point() {
+        let req = set_gamma_request(
+            0,
+            Gamma {
+                red: 1.0,
+                green: 0.8,
+                blue: 2.5,
+            },
+        )
+        .unwrap();
+        assert_eq!((req.red, req.green, req.blue), (10_000, 8_000, 25_000));
+
+        let gamma = gamma_from_reply(GetGammaReply {
+            red: 10_000,
+            green: 5_000,
+            blue: 20_000,
+            ..Default::default()
+        });
+        assert_eq!(
+            gamma,
+            Gamma {
+                red: 1.0,
+                green: 0.5,
+                blue: 2.0,
+            }
+        );
+
+        let bad = Gamma {
+            red: 0.0,
+            ..Default::default()
+        };
+        assert!(set_gamma_request(0, bad).is_err());
+        let bad = Gamma {
+            blue: f32::NAN,
+            ..Default::default()
+        };
+        assert!(set_gamma_request(0, bad).is_err());
+    }
+
+    #[test]
+    fn gamma_ramp_is_padded_and_validated() {
+        let ramp = GammaRamp {
+            red: vec![1, 2, 3],
+            green: vec![4, 5, 6],
+            blue: vec![7, 8, 9],
+        };
+
+        // odd ramp sizes are padded to an even length on the wire
+        let req = ramp.set_request(0, 3).unwrap();
+        assert_eq!(req.size, 3);
+        assert_eq!(req.red, vec![1, 2, 3, 0]);
+        assert_eq!(req.green, vec![4, 5, 6, 0]);
+        assert_eq!(req.blue, vec![7, 8, 9, 0]);
+
+        // every channel has to match the ramp size
+        assert!(ramp.set_request(0, 4).is_err());
+        let short = GammaRamp {
+            blue: vec![7, 8],
+            ..ramp.clone()
+        };
+        assert!(short.set_request(0, 3).is_err());
+
+        // and the padding is stripped back off of replies
+        let repl = GetGammaRampReply {
+            size: 3,
+            red: vec![1, 2, 3, 0],
+            green: vec![4, 5, 6, 0],
+            blue: vec![7, 8, 9, 0],
+            ..Default::default()
+        };
+        assert_eq!(GammaRamp::from_reply(repl, 3), ramp);
+    }
+}
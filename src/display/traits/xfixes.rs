@@ -5,7 +5,7 @@ use crate::{
         xfixes::{CreateRegionRequest, DestroyRegionRequest, Region},
         xproto::Rectangle,
     },
-    display::{prelude::*, Display},
+    display::{generate_xid, prelude::*, Display},
 };
 use alloc::vec::Vec;
 
@@ -13,7 +13,7 @@ use alloc::vec::Vec;
 use crate::{
     display::{
         futures::{ExchangeRequestFuture, ExchangeXidFuture},
-        generate_xid, AsyncDisplay,
+        AsyncDisplay,
     },
     util::BoxedFnOnce,
 };
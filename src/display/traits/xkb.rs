@@ -0,0 +1,762 @@
+// MIT/Apache2 License
+
+use super::{grab_key_all_locks_requests, grab_keys};
+use crate::{
+    auto::{
+        xkb::{
+            DeviceSpec, EventType, GetMapRequest, GetNamesRequest, MapPart, NameDetail,
+            SelectEventsRequest,
+        },
+        xproto::{
+            Atom, GetAtomNameRequest, GrabKeyRequest, KeyButMask, Keycode, Keysym, ModMask, Window,
+        },
+    },
+    display::{prelude::*, Display},
+    BreadError,
+};
+use alloc::{collections::BTreeMap, format, string::String, vec::Vec};
+
+#[cfg(feature = "async")]
+use super::grab_keys_async;
+#[cfg(feature = "async")]
+use crate::display::{futures::ExchangeRequestFuture, AsyncDisplay};
+#[cfg(feature = "async")]
+use alloc::boxed::Box;
+#[cfg(feature = "async")]
+use core::{future::Future, pin::Pin};
+
+pub trait DisplayXkbExt: Display {
+    /// Select which XKB events the server should send for the given keyboard. Of the events named in
+    /// `affect_which`, those also set in `details` are selected and the rest are deselected; events not
+    /// named in `affect_which` are left alone. Use `Id::UseCoreKbd` as the device to refer to the core
+    /// keyboard.
+    ///
+    /// Note that the server ignores XKB requests until the client has sent a `UseExtensionRequest`.
+    #[inline]
+    fn xkb_select_events(
+        &mut self,
+        device: DeviceSpec,
+        affect_which: EventType,
+        details: EventType,
+    ) -> crate::Result {
+        self.exchange_request(select_events_request(device, affect_which, details))
+    }
+
+    /// Get the symbolic names of a keyboard's keys, groups, modifiers and indicators, along with the names of
+    /// the keymap components it was built from. Only the names selected by `which` are fetched; the rest are
+    /// left empty. The names are all requested at once, so this takes two round trips.
+    ///
+    /// Note that the server ignores XKB requests until the client has sent a `UseExtensionRequest`.
+    #[inline]
+    fn xkb_get_names(
+        &mut self,
+        device: DeviceSpec,
+        which: NameDetail,
+    ) -> crate::Result<KeyboardNames> {
+        let tok = self.send_request(get_names_request(device, which))?;
+        let raw = RawNames::from_reply(&tok.reply_raw(self)?.data)?;
+
+        let atoms = raw.atoms();
+        let toks = atoms
+            .iter()
+            .map(|&atom| {
+                self.send_request(GetAtomNameRequest {
+                    atom,
+                    ..Default::default()
+                })
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+        let mut names = BTreeMap::new();
+        for (atom, tok) in atoms.into_iter().zip(toks) {
+            names.insert(atom, self.resolve_request(tok)?.name);
+        }
+
+        Ok(raw.into_names(&names))
+    }
+
+    /// Register passive grabs on `grab_window` for `keysym` with the named modifiers held, whatever the
+    /// state of Caps Lock and Num Lock. This is for binding hotkeys like `Super+L` symbolically, since which
+    /// key produces a keysym and which real modifier a virtual one like `Super` or `Alt` is bound to depend
+    /// on the keyboard layout. Every key that produces `keysym` in any group or shift level is grabbed.
+    ///
+    /// The modifiers may be real ones (`Shift`, `Lock`, `Control` and `Mod1` through `Mod5`) or the names
+    /// of the keyboard's virtual modifiers, ignoring case. It's an error if a modifier is unknown or not
+    /// bound to any real modifier, or if no key produces `keysym`. The returned grab says which keys and
+    /// modifiers were grabbed, so the key events it produces can be recognized.
+    ///
+    /// The grabs are made from the layout at the time of the call, so they need to be made again after a
+    /// `MapNotify` event. Note that the server ignores XKB requests until the client has sent a
+    /// `UseExtensionRequest`.
+    #[inline]
+    fn xkb_grab_key(
+        &mut self,
+        device: DeviceSpec,
+        grab_window: Window,
+        keysym: Keysym,
+        modifiers: &[&str],
+    ) -> crate::Result<HotkeyGrab> {
+        let names = self.xkb_get_names(device, NameDetail::VIRTUAL_MOD_NAMES)?;
+        let tok = self.send_request(get_map_request(device))?;
+        let map = RawMap::from_reply(&tok.reply_raw(self)?.data)?;
+
+        let grab = map.resolve(keysym, modifiers, &names.virtual_mod_names)?;
+        grab_keys(self, grab.requests(grab_window))?;
+        Ok(grab)
+    }
+}
+
+impl<D: Display + ?Sized> DisplayXkbExt for D {}
+
+/// The future returned by `AsyncDisplayXkbExt::xkb_get_names_async`.
+#[cfg(feature = "async")]
+pub type KeyboardNamesFuture<'a> = Pin<Box<dyn Future<Output = crate::Result<KeyboardNames>> + 'a>>;
+
+/// The future returned by `AsyncDisplayXkbExt::xkb_grab_key_async`.
+#[cfg(feature = "async")]
+pub type HotkeyGrabFuture<'a> = Pin<Box<dyn Future<Output = crate::Result<HotkeyGrab>> + 'a>>;
+
+#[cfg(feature = "async")]
+pub trait AsyncDisplayXkbExt: AsyncDisplay {
+    /// Select which XKB events the server should send for the given keyboard, async redox.
+    #[inline]
+    fn xkb_select_events_async(
+        &mut self,
+        device: DeviceSpec,
+        affect_which: EventType,
+        details: EventType,
+    ) -> ExchangeRequestFuture<'_, Self, SelectEventsRequest> {
+        self.exchange_request_async(select_events_request(device, affect_which, details))
+    }
+
+    /// Get the symbolic names of a keyboard's keys, groups, modifiers and indicators, async redox.
+    #[inline]
+    fn xkb_get_names_async(
+        &mut self,
+        device: DeviceSpec,
+        which: NameDetail,
+    ) -> KeyboardNamesFuture<'_> {
+        Box::pin(async move {
+            let tok = self
+                .send_request_async(get_names_request(device, which))
+                .await?;
+            let raw = RawNames::from_reply(&tok.reply_raw_async(self).await?.data)?;
+
+            let atoms = raw.atoms();
+            let mut toks = Vec::with_capacity(atoms.len());
+            for &atom in &atoms {
+                toks.push(
+                    self.send_request_async(GetAtomNameRequest {
+                        atom,
+                        ..Default::default()
+                    })
+                    .await?,
+                );
+            }
+            let mut names = BTreeMap::new();
+            for (atom, tok) in atoms.into_iter().zip(toks) {
+                names.insert(atom, self.resolve_request_async(tok).await?.name);
+            }
+
+            Ok(raw.into_names(&names))
+        })
+    }
+
+    /// Register passive grabs for `keysym` with the named modifiers held, async redox.
+    #[inline]
+    fn xkb_grab_key_async<'a>(
+        &'a mut self,
+        device: DeviceSpec,
+        grab_window: Window,
+        keysym: Keysym,
+        modifiers: &'a [&'a str],
+    ) -> HotkeyGrabFuture<'a> {
+        Box::pin(async move {
+            let names = self
+                .xkb_get_names_async(device, NameDetail::VIRTUAL_MOD_NAMES)
+                .await?;
+            let tok = self.send_request_async(get_map_request(device)).await?;
+            let map = RawMap::from_reply(&tok.reply_raw_async(self).await?.data)?;
+
+            let grab = map.resolve(keysym, modifiers, &names.virtual_mod_names)?;
+            grab_keys_async(self, grab.requests(grab_window)).await?;
+            Ok(grab)
+        })
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D: AsyncDisplay + ?Sized> AsyncDisplayXkbExt for D {}
+
+#[inline]
+fn select_events_request(
+    device: DeviceSpec,
+    affect_which: EventType,
+    details: EventType,
+) -> SelectEventsRequest {
+    // every affected event is either cleared or fully selected, so none of the per-event details
+    // need to be sent
+    let all_parts = MapPart { inner: 0xff };
+    let (affect_map, map) = if affect_which.map_notify() {
+        (
+            all_parts,
+            if details.map_notify() {
+                all_parts
+            } else {
+                MapPart::default()
+            },
+        )
+    } else {
+        (MapPart::default(), MapPart::default())
+    };
+
+    SelectEventsRequest {
+        device_spec: device,
+        affect_which,
+        clear: affect_which & !details,
+        select_all: affect_which & details,
+        affect_map,
+        map,
+        ..Default::default()
+    }
+}
+
+/// The symbolic names of a keyboard, as returned by `xkb_get_names`. Lists whose names weren't requested are
+/// empty, and names the server doesn't have are empty strings. Indicators, virtual modifiers and groups are
+/// paired with their index, since only the ones with names are listed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeyboardNames {
+    /// The name of the keycodes component of the keymap, such as `evdev+aliases(qwerty)`.
+    pub keycodes: Option<String>,
+    pub geometry: Option<String>,
+    /// The name of the symbols component of the keymap, such as `pc+us+inet(evdev)`.
+    pub symbols: Option<String>,
+    pub phys_symbols: Option<String>,
+    pub types: Option<String>,
+    pub compat: Option<String>,
+    /// The name of each key type.
+    pub type_names: Vec<String>,
+    /// The names of the shift levels of each key type.
+    pub level_names: Vec<Vec<String>>,
+    pub indicator_names: Vec<(u8, String)>,
+    pub virtual_mod_names: Vec<(u8, String)>,
+    /// The name of each group, which is the name of its layout, such as `English (US)`.
+    pub group_names: Vec<(u8, String)>,
+    /// The four-character name of each key, such as `AE01` or `LFSH`.
+    pub key_names: Vec<(Keycode, String)>,
+    /// Other names for keys, as pairs of the alias and the real name.
+    pub key_aliases: Vec<(String, String)>,
+    pub radio_group_names: Vec<String>,
+}
+
+/// A `GetNames` reply, with its names still as atoms. The generated reply type reads every list regardless
+/// of whether `which` says that it's present, so the reply is decoded here instead.
+#[derive(Debug, Default)]
+struct RawNames {
+    // keycodes, geometry, symbols, phys_symbols, types and compat; zero if they weren't requested
+    components: [Atom; 6],
+    type_names: Vec<Atom>,
+    levels_per_type: Vec<u8>,
+    level_names: Vec<Atom>,
+    indicators: u32,
+    indicator_names: Vec<Atom>,
+    virtual_mods: u16,
+    virtual_mod_names: Vec<Atom>,
+    groups: u8,
+    group_names: Vec<Atom>,
+    first_key: Keycode,
+    key_names: Vec<[u8; 4]>,
+    key_aliases: Vec<([u8; 4], [u8; 4])>,
+    radio_group_names: Vec<Atom>,
+}
+
+impl RawNames {
+    #[inline]
+    fn from_reply(bytes: &[u8]) -> crate::Result<Self> {
+        Self::parse(bytes).ok_or(BreadError::BadObjectRead(Some("GetNamesReply")))
+    }
+
+    #[inline]
+    fn parse(bytes: &[u8]) -> Option<Self> {
+        let header = bytes.get(..32)?;
+        let word =
+            |i: usize| u32::from_ne_bytes([header[i], header[i + 1], header[i + 2], header[i + 3]]);
+        let which = NameDetail { inner: word(8) };
+        let n_types = usize::from(header[14]);
+        let n_keys = usize::from(header[19]);
+        let n_radio_groups = usize::from(header[24]);
+        let n_key_aliases = usize::from(header[25]);
+        let mut names = Self {
+            groups: header[15],
+            virtual_mods: u16::from_ne_bytes([header[16], header[17]]),
+            first_key: header[18],
+            indicators: word(20),
+            ..Default::default()
+        };
+
+        let mut rest = &bytes[32..];
+        let present = [
+            which.keycodes(),
+            which.geometry(),
+            which.symbols(),
+            which.phys_symbols(),
+            which.types(),
+            which.compat(),
+        ];
+        for (component, present) in names.components.iter_mut().zip(&present) {
+            if *present {
+                *component = take_atoms(&mut rest, 1)?[0];
+            }
+        }
+
+        if which.key_type_names() {
+            names.type_names = take_atoms(&mut rest, n_types)?;
+        }
+        if which.kt_level_names() {
+            names.levels_per_type = take(&mut rest, n_types)?.to_vec();
+            take(&mut rest, (4 - n_types % 4) % 4)?;
+            let n_levels = names.levels_per_type.iter().map(|&n| usize::from(n)).sum();
+            names.level_names = take_atoms(&mut rest, n_levels)?;
+        }
+        if which.indicator_names() {
+            names.indicator_names = take_atoms(&mut rest, names.indicators.count_ones() as usize)?;
+        }
+        if which.virtual_mod_names() {
+            names.virtual_mod_names =
+                take_atoms(&mut rest, names.virtual_mods.count_ones() as usize)?;
+        }
+        if which.group_names() {
+            names.group_names = take_atoms(&mut rest, names.groups.count_ones() as usize)?;
+        }
+        if which.key_names() {
+            names.key_names = take(&mut rest, n_keys * 4)?
+                .chunks(4)
+                .map(|name| [name[0], name[1], name[2], name[3]])
+                .collect();
+        }
+        if which.key_aliases() {
+            names.key_aliases = take(&mut rest, n_key_aliases * 8)?
+                .chunks(8)
+                .map(|alias| {
+                    (
+                        [alias[0], alias[1], alias[2], alias[3]],
+                        [alias[4], alias[5], alias[6], alias[7]],
+                    )
+                })
+                .collect();
+        }
+        if which.rg_names() {
+            names.radio_group_names = take_atoms(&mut rest, n_radio_groups)?;
+        }
+
+        Some(names)
+    }
+
+    /// Every distinct atom that needs to be named.
+    #[inline]
+    fn atoms(&self) -> Vec<Atom> {
+        let mut atoms: Vec<Atom> = self
+            .components
+            .iter()
+            .chain(&self.type_names)
+            .chain(&self.level_names)
+            .chain(&self.indicator_names)
+            .chain(&self.virtual_mod_names)
+            .chain(&self.group_names)
+            .chain(&self.radio_group_names)
+            .copied()
+            .filter(|atom| atom.xid != 0)
+            .collect();
+        atoms.sort_unstable();
+        atoms.dedup();
+        atoms
+    }
+
+    #[inline]
+    fn into_names(self, names: &BTreeMap<Atom, String>) -> KeyboardNames {
+        let name = |atom: &Atom| names.get(atom).cloned().unwrap_or_default();
+        let component = |i: usize| {
+            if self.components[i].xid == 0 {
+                None
+            } else {
+                Some(name(&self.components[i]))
+            }
+        };
+        let indexed = |mask: u32, atoms: &[Atom]| {
+            (0..32)
+                .filter(|i| mask & (1 << i) != 0)
+                .zip(atoms)
+                .map(|(i, atom)| (i, name(atom)))
+                .collect()
+        };
+
+        let mut level_names = self.level_names.iter();
+        KeyboardNames {
+            keycodes: component(0),
+            geometry: component(1),
+            symbols: component(2),
+            phys_symbols: component(3),
+            types: component(4),
+            compat: component(5),
+            type_names: self.type_names.iter().map(name).collect(),
+            level_names: self
+                .levels_per_type
+                .iter()
+                .map(|&n| {
+                    level_names
+                        .by_ref()
+                        .take(usize::from(n))
+                        .map(name)
+                        .collect()
+                })
+                .collect(),
+            indicator_names: indexed(self.indicators, &self.indicator_names),
+            virtual_mod_names: indexed(self.virtual_mods.into(), &self.virtual_mod_names),
+            group_names: indexed(self.groups.into(), &self.group_names),
+            key_names: (self.first_key..=Keycode::MAX)
+                .zip(&self.key_names)
+                .map(|(keycode, key_name)| (keycode, key_name_string(*key_name)))
+                .collect(),
+            key_aliases: self
+                .key_aliases
+                .iter()
+                .map(|(real, alias)| (key_name_string(*alias), key_name_string(*real)))
+                .collect(),
+            radio_group_names: self.radio_group_names.iter().map(name).collect(),
+        }
+    }
+}
+
+/// The passive grabs registered by `xkb_grab_key`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HotkeyGrab {
+    /// Every key that produces the keysym.
+    pub keycodes: Vec<Keycode>,
+    /// The real modifiers that the named modifiers are bound to.
+    pub modifiers: ModMask,
+    // Caps Lock and the real modifier that Num Lock is bound to
+    locks: ModMask,
+}
+
+impl HotkeyGrab {
+    /// Whether or not a key event with this keycode and state is one this grab activates on. The state of
+    /// the locks and the pointer buttons is ignored.
+    #[inline]
+    #[must_use]
+    pub fn matches(&self, keycode: Keycode, state: KeyButMask) -> bool {
+        let held = state.inner & 0xFF & !self.locks.inner;
+        self.keycodes.contains(&keycode) && held == self.modifiers.inner
+    }
+
+    #[inline]
+    fn requests(&self, grab_window: Window) -> Vec<GrabKeyRequest> {
+        let num_lock = self.locks & !ModMask::LOCK;
+        self.keycodes
+            .iter()
+            .flat_map(|&key| {
+                grab_key_all_locks_requests(grab_window, key, self.modifiers, num_lock)
+            })
+            .collect()
+    }
+}
+
+/// The names of the real modifiers, in the order of their bits.
+const REAL_MODIFIERS: [&str; 8] = [
+    "Shift", "Lock", "Control", "Mod1", "Mod2", "Mod3", "Mod4", "Mod5",
+];
+
+/// The parts of a `GetMap` reply that are needed to resolve a hotkey: the keysyms of every key, and the
+/// real modifiers that each virtual modifier is bound to. Like `GetNames`, the generated reply type reads
+/// every part regardless of whether it's present, so only these two are requested and decoded here.
+#[derive(Debug, Default)]
+struct RawMap {
+    first_key: Keycode,
+    // the keysyms of each key, across every group and shift level
+    syms: Vec<Vec<Keysym>>,
+    virtual_mods: u16,
+    // the real modifiers of each virtual modifier set in `virtual_mods`
+    virtual_mod_masks: Vec<u8>,
+}
+
+impl RawMap {
+    #[inline]
+    fn from_reply(bytes: &[u8]) -> crate::Result<Self> {
+        Self::parse(bytes).ok_or(BreadError::BadObjectRead(Some("GetMapReply")))
+    }
+
+    #[inline]
+    fn parse(bytes: &[u8]) -> Option<Self> {
+        let header = bytes.get(..40)?;
+        let present = MapPart {
+            inner: u16::from_ne_bytes([header[12], header[13]]),
+        };
+        let n_keys = usize::from(header[20]);
+        let mut map = Self {
+            first_key: header[17],
+            virtual_mods: u16::from_ne_bytes([header[38], header[39]]),
+            ..Default::default()
+        };
+
+        // key types come before the keysyms, and they weren't requested
+        if present.key_types() {
+            return None;
+        }
+
+        let mut rest = &bytes[40..];
+        if present.key_syms() {
+            for _ in 0..n_keys {
+                // the key type of each group, the group info and the width come before the keysyms
+                let sym_map = take(&mut rest, 8)?;
+                let n_syms = usize::from(u16::from_ne_bytes([sym_map[6], sym_map[7]]));
+                map.syms.push(
+                    take(&mut rest, n_syms * 4)?
+                        .chunks(4)
+                        .map(|sym| u32::from_ne_bytes([sym[0], sym[1], sym[2], sym[3]]))
+                        .collect(),
+                );
+            }
+        }
+
+        // actions and behaviors come between the keysyms and the virtual modifiers
+        if present.key_actions() || present.key_behaviors() {
+            return None;
+        }
+
+        if present.virtual_mods() {
+            map.virtual_mod_masks =
+                take(&mut rest, map.virtual_mods.count_ones() as usize)?.to_vec();
+        }
+
+        Some(map)
+    }
+
+    /// The real modifiers that the named modifier stands for, or `None` if there isn't one by that name or
+    /// it isn't bound to anything.
+    #[inline]
+    fn modifier_mask(&self, name: &str, virtual_mod_names: &[(u8, String)]) -> Option<u16> {
+        if let Some(bit) = REAL_MODIFIERS
+            .iter()
+            .position(|real| real.eq_ignore_ascii_case(name))
+        {
+            return Some(1 << bit);
+        }
+
+        let &(index, _) = virtual_mod_names
+            .iter()
+            .find(|(_, vmod)| vmod.eq_ignore_ascii_case(name))?;
+        if self.virtual_mods & (1 << index) == 0 {
+            return None;
+        }
+        // the masks are only listed for the virtual modifiers in `virtual_mods`
+        let position = (self.virtual_mods & ((1 << index) - 1)).count_ones() as usize;
+        match self.virtual_mod_masks.get(position) {
+            Some(&mask) if mask != 0 => Some(mask.into()),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn resolve(
+        &self,
+        keysym: Keysym,
+        modifiers: &[&str],
+        virtual_mod_names: &[(u8, String)],
+    ) -> crate::Result<HotkeyGrab> {
+        let mut mask = 0;
+        for name in modifiers {
+            mask |= self
+                .modifier_mask(name, virtual_mod_names)
+                .ok_or_else(|| BreadError::Msg(format!("Unknown or unbound modifier: {}", name)))?;
+        }
+
+        let keycodes: Vec<Keycode> = (self.first_key..=Keycode::MAX)
+            .zip(&self.syms)
+            .filter(|(_, syms)| syms.contains(&keysym))
+            .map(|(keycode, _)| keycode)
+            .collect();
+        if keycodes.is_empty() {
+            return Err(BreadError::Msg(format!(
+                "No key produces keysym {:#x}",
+                keysym
+            )));
+        }
+
+        let num_lock = self
+            .modifier_mask("NumLock", virtual_mod_names)
+            .unwrap_or(0);
+        Ok(HotkeyGrab {
+            keycodes,
+            modifiers: ModMask { inner: mask },
+            locks: ModMask {
+                inner: ModMask::LOCK.inner | num_lock,
+            },
+        })
+    }
+}
+
+/// Split the first `len` bytes off of `bytes`.
+#[inline]
+fn take<'a>(bytes: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+    if bytes.len() < len {
+        return None;
+    }
+
+    let (head, tail) = bytes.split_at(len);
+    *bytes = tail;
+    Some(head)
+}
+
+#[inline]
+fn take_atoms(bytes: &mut &[u8], count: usize) -> Option<Vec<Atom>> {
+    Some(
+        take(bytes, count * 4)?
+            .chunks(4)
+            .map(|atom| {
+                Atom::const_from_xid(u32::from_ne_bytes([atom[0], atom[1], atom[2], atom[3]]))
+            })
+            .collect(),
+    )
+}
+
+/// Key names are up to four Latin-1 characters, padded with zeroes.
+#[inline]
+fn key_name_string(name: [u8; 4]) -> String {
+    name.iter()
+        .take_while(|&&c| c != 0)
+        .map(|&c| char::from(c))
+        .collect()
+}
+
+/// Every key's keysyms, and the bindings of every virtual modifier.
+#[inline]
+fn get_map_request(device: DeviceSpec) -> GetMapRequest {
+    GetMapRequest {
+        device_spec: device,
+        full: MapPart::KEY_SYMS | MapPart::VIRTUAL_MODS,
+        ..Default::default()
+    }
+}
+
+#[inline]
+fn get_names_request(device: DeviceSpec, which: NameDetail) -> GetNamesRequest {
+    GetNamesRequest {
+        device_spec: device,
+        which,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KeyboardNames, RawMap, RawNames};
+    use crate::auto::xproto::{Atom, KeyButMask, Window};
+    use alloc::{
+        collections::BTreeMap,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+
+    #[test]
+    fn names_reply_is_decoded() {
+        let mut bytes = vec![0; 32];
+        bytes[0] = 1;
+        // symbols, key type names, level names, group names and key names
+        let which: u32 = 1 << 2 | 1 << 6 | 1 << 7 | 1 << 9 | 1 << 12;
+        bytes[8..12].copy_from_slice(&which.to_ne_bytes());
+        bytes[14] = 2; // types
+        bytes[15] = 0b0101; // groups 1 and 3 are named
+        bytes[18] = 9; // first key
+        bytes[19] = 2; // keys
+        bytes[24] = 3; // radio groups, which weren't requested
+
+        let atoms = |atoms: &[u32]| -> Vec<u8> {
+            atoms
+                .iter()
+                .flat_map(|a| a.to_ne_bytes().to_vec())
+                .collect()
+        };
+        bytes.extend(atoms(&[10]));
+        bytes.extend(atoms(&[11, 12]));
+        bytes.extend(&[1, 2, 0, 0]);
+        bytes.extend(atoms(&[13, 14, 15]));
+        bytes.extend(atoms(&[16, 0]));
+        bytes.extend(b"ESC\0AE01");
+
+        let raw = RawNames::parse(&bytes).unwrap();
+        assert_eq!(
+            raw.atoms(),
+            (10..=16).map(Atom::const_from_xid).collect::<Vec<_>>()
+        );
+
+        let names: BTreeMap<Atom, String> = (10..=16)
+            .map(|xid| (Atom::const_from_xid(xid), xid.to_string()))
+            .collect();
+        let s = |s: &str| String::from(s);
+        assert_eq!(
+            raw.into_names(&names),
+            KeyboardNames {
+                symbols: Some(s("10")),
+                type_names: vec![s("11"), s("12")],
+                level_names: vec![vec![s("13")], vec![s("14"), s("15")]],
+                group_names: vec![(0, s("16")), (2, String::new())],
+                key_names: vec![(9, s("ESC")), (10, s("AE01"))],
+                ..Default::default()
+            }
+        );
+
+        // a reply that's shorter than its counts say is rejected
+        assert!(RawNames::parse(&bytes[..bytes.len() - 4]).is_none());
+    }
+
+    #[test]
+    fn hotkeys_are_resolved_from_the_map() {
+        let mut bytes = vec![0; 40];
+        bytes[1] = 3;
+        // key syms and virtual modifiers
+        bytes[12..14].copy_from_slice(&(2u16 | 64).to_ne_bytes());
+        bytes[17] = 9; // first key
+        bytes[20] = 2; // keys
+        bytes[38..40].copy_from_slice(&(1u16 | 1 << 4 | 1 << 7).to_ne_bytes());
+
+        let sym_map = |syms: &[u32]| -> Vec<u8> {
+            let mut bytes = vec![0, 0, 0, 0, 1, syms.len() as u8];
+            bytes.extend(&(syms.len() as u16).to_ne_bytes());
+            bytes.extend(syms.iter().flat_map(|s| s.to_ne_bytes().to_vec()));
+            bytes
+        };
+        bytes.extend(sym_map(&[0xff1b]));
+        bytes.extend(sym_map(&[0x6c, 0x4c]));
+        // Alt isn't bound, NumLock is Mod2 and Super is Mod4
+        bytes.extend(&[0x00, 0x10, 0x40, 0]);
+
+        let map = RawMap::parse(&bytes).unwrap();
+        let names = vec![
+            (0, String::from("Alt")),
+            (4, String::from("NumLock")),
+            (7, String::from("Super")),
+        ];
+
+        let grab = map.resolve(0x4c, &["super", "Shift"], &names).unwrap();
+        assert_eq!(grab.keycodes, [10]);
+        assert_eq!(grab.modifiers.inner, 0x41);
+        assert!(grab.matches(
+            10,
+            KeyButMask::MOD4 | KeyButMask::SHIFT | KeyButMask::MOD2 | KeyButMask::BUTTON1
+        ));
+        assert!(!grab.matches(10, KeyButMask::MOD4));
+        assert!(!grab.matches(9, KeyButMask::MOD4 | KeyButMask::SHIFT));
+
+        let grabbed: Vec<u16> = grab
+            .requests(Window::const_from_xid(1))
+            .iter()
+            .map(|req| req.modifiers.inner)
+            .collect();
+        assert_eq!(grabbed, [0x41, 0x43, 0x51, 0x53]);
+
+        assert!(map.resolve(0x4c, &["Alt"], &names).is_err());
+        assert!(map.resolve(0x4c, &["Hyper"], &names).is_err());
+        assert!(map.resolve(0x61, &[], &names).is_err());
+
+        assert!(RawMap::parse(&bytes[..bytes.len() - 8]).is_none());
+    }
+}
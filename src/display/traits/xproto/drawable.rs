@@ -6,11 +6,12 @@
 use crate::{
     auto::xproto::{
         CopyAreaRequest, CopyPlaneRequest, CreatePixmapRequest, Drawable, GetGeometryReply,
-        GetGeometryRequest, Pixmap, Window,
+        GetGeometryRequest, GetWindowAttributesReply, GetWindowAttributesRequest, ImageFormat,
+        Pixmap, Visualid, Window,
     },
-    display::{generate_xid, prelude::*},
-    image::{put::put_image_req, Image},
-    Display, Gcontext, RequestCookie,
+    display::{generate_xid, prelude::*, ResourceKind},
+    image::{get::ImageBands, put::put_image_req, Image},
+    BreadError, Display, ErrorCode, Gcontext, RequestCookie,
 };
 use alloc::vec::Vec;
 use core::{convert::TryInto, ops::Deref};
@@ -56,6 +57,206 @@ impl From<GetGeometryReply> for Geometry {
     }
 }
 
+impl Drawable {
+    /// Get the depth of this drawable and, if it is a window, its visual. These are needed in order to pick
+    /// a compatible image format or picture format. Both requests are sent before waiting on either, so this
+    /// takes one round trip.
+    #[inline]
+    pub fn format<Dpy: Display + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+    ) -> crate::Result<(u8, Option<Visualid>)> {
+        let geometry = dpy.send_request(get_geometry_request(self))?;
+        let attributes = dpy.send_request(get_window_attributes_request(self))?;
+        // resolve both, so that neither reply is left behind if the first fails
+        let geometry = dpy.resolve_request(geometry);
+        let attributes = dpy.resolve_request(attributes);
+        drawable_format(geometry?, attributes)
+    }
+
+    /// Get the depth and visual of this drawable, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn format_async<Dpy: AsyncDisplay + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+    ) -> crate::Result<(u8, Option<Visualid>)> {
+        let geometry = dpy.send_request_async(get_geometry_request(self)).await?;
+        let attributes = dpy
+            .send_request_async(get_window_attributes_request(self))
+            .await?;
+        let geometry = dpy.resolve_request_async(geometry).await;
+        let attributes = dpy.resolve_request_async(attributes).await;
+        drawable_format(geometry?, attributes)
+    }
+
+    /// Read a rectangle of this drawable into an image. Only `ZPixmap` and `XyPixmap` are valid formats; in
+    /// the latter, the image has one plane for each plane in `plane_mask`.
+    ///
+    /// Large images are read in bands of scanlines, as many as fit in the longest reply the server can
+    /// send, and every band is requested before any of them are waited on. If even one scanline doesn't
+    /// fit, a `ScanlineTooLong` error is returned.
+    #[inline]
+    pub fn get_image<Dpy: Display + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+        plane_mask: u32,
+        format: ImageFormat,
+    ) -> crate::Result<Image<Vec<u8>>> {
+        let depth = dpy.exchange_request(get_geometry_request(self))?.depth;
+        self.get_image_of_depth(dpy, depth, x, y, width, height, plane_mask, format)
+    }
+
+    /// Read a rectangle of this drawable into an image with every plane in it, picking the format from the
+    /// drawable's depth: deeper drawables are read as `ZPixmap` images in the display's pixmap format for that
+    /// depth, and bitmaps are read as single-plane `XyPixmap` images, padded like any other bitmap.
+    ///
+    /// Use `get_image` instead to read only some of the planes, such as a single plane of a deep drawable,
+    /// or to read a deep drawable as planes rather than pixels.
+    #[inline]
+    pub fn read_image<Dpy: Display + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+    ) -> crate::Result<Image<Vec<u8>>> {
+        let depth = dpy.exchange_request(get_geometry_request(self))?.depth;
+        let format = image_format_for_depth(depth);
+        self.get_image_of_depth(dpy, depth, x, y, width, height, !0, format)
+    }
+
+    /// Read a rectangle of this drawable, once its depth is known.
+    #[inline]
+    fn get_image_of_depth<Dpy: Display + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+        depth: u8,
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+        plane_mask: u32,
+        format: ImageFormat,
+    ) -> crate::Result<Image<Vec<u8>>> {
+        let bands = ImageBands::new(dpy, depth, format, plane_mask, width)?;
+
+        let reqs = bands.requests(self, x, y, width, height, plane_mask);
+        let mut toks = Vec::with_capacity(reqs.len());
+        for req in reqs {
+            toks.push(dpy.send_request(req)?);
+        }
+        let mut replies = Vec::with_capacity(toks.len());
+        for tok in toks {
+            replies.push(dpy.resolve_request(tok)?);
+        }
+
+        bands.into_image(dpy, width, height, replies)
+    }
+
+    /// Read a rectangle of this drawable into an image, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn get_image_async<Dpy: AsyncDisplay + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+        plane_mask: u32,
+        format: ImageFormat,
+    ) -> crate::Result<Image<Vec<u8>>> {
+        let depth = dpy
+            .exchange_request_async(get_geometry_request(self))
+            .await?
+            .depth;
+        self.get_image_of_depth_async(dpy, depth, x, y, width, height, plane_mask, format)
+            .await
+    }
+
+    /// Read a rectangle of this drawable into an image with every plane in it, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn read_image_async<Dpy: AsyncDisplay + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+    ) -> crate::Result<Image<Vec<u8>>> {
+        let depth = dpy
+            .exchange_request_async(get_geometry_request(self))
+            .await?
+            .depth;
+        let format = image_format_for_depth(depth);
+        self.get_image_of_depth_async(dpy, depth, x, y, width, height, !0, format)
+            .await
+    }
+
+    /// Read a rectangle of this drawable once its depth is known, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    async fn get_image_of_depth_async<Dpy: AsyncDisplay + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+        depth: u8,
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+        plane_mask: u32,
+        format: ImageFormat,
+    ) -> crate::Result<Image<Vec<u8>>> {
+        let bands = ImageBands::new(dpy, depth, format, plane_mask, width)?;
+
+        let reqs = bands.requests(self, x, y, width, height, plane_mask);
+        let mut toks = Vec::with_capacity(reqs.len());
+        for req in reqs {
+            toks.push(dpy.send_request_async(req).await?);
+        }
+        let mut replies = Vec::with_capacity(toks.len());
+        for tok in toks {
+            replies.push(dpy.resolve_request_async(tok).await?);
+        }
+
+        bands.into_image(dpy, width, height, replies)
+    }
+}
+
+/// The format `read_image` reads a drawable of the given depth in.
+#[inline]
+fn image_format_for_depth(depth: u8) -> ImageFormat {
+    if depth == 1 {
+        ImageFormat::XyPixmap
+    } else {
+        ImageFormat::ZPixmap
+    }
+}
+
+/// Combine the replies to `GetGeometry` and `GetWindowAttributes` into a depth and a visual.
+#[inline]
+fn drawable_format(
+    geometry: GetGeometryReply,
+    attributes: crate::Result<GetWindowAttributesReply>,
+) -> crate::Result<(u8, Option<Visualid>)> {
+    match attributes {
+        Ok(attributes) => Ok((geometry.depth, Some(attributes.visual))),
+        // a BadWindow error means that the drawable is a pixmap, which has no visual
+        Err(BreadError::XProtocol {
+            error_code: ErrorCode(3),
+            ..
+        }) => Ok((geometry.depth, None)),
+        Err(e) => Err(e),
+    }
+}
+
 /// Get geometry request.
 #[inline]
 fn get_geometry_request(target: Drawable) -> GetGeometryRequest {
@@ -65,6 +266,15 @@ fn get_geometry_request(target: Drawable) -> GetGeometryRequest {
     }
 }
 
+/// Get window attributes request, for a drawable that may or may not be a window.
+#[inline]
+fn get_window_attributes_request(target: Drawable) -> GetWindowAttributesRequest {
+    GetWindowAttributesRequest {
+        window: Window::const_from_xid(target.xid),
+        ..Default::default()
+    }
+}
+
 /// Copy area request.
 #[inline]
 fn copy_area_request(
@@ -150,6 +360,16 @@ pub trait DisplayDrawableExt: Display {
         self.send_request(get_geometry_request(target.into()))
     }
 
+    /// Get the depth of a drawable and, if it is a window, its visual, in one round trip. See
+    /// `Drawable::format`.
+    #[inline]
+    fn drawable_format<Target: Into<Drawable>>(
+        &mut self,
+        target: Target,
+    ) -> crate::Result<(u8, Option<Visualid>)> {
+        target.into().format(self)
+    }
+
     /// Immediately resolve the geometry of a drawable object.
     #[inline]
     fn get_drawable_geometry_immediate<Target: Into<Drawable>>(
@@ -233,10 +453,13 @@ pub trait DisplayDrawableExt: Display {
             height,
             depth,
         ))?;
+        self.track_resource(pixmap.xid, ResourceKind::Pixmap);
         Ok(pixmap)
     }
 
-    /// Write an image to a drawable.
+    /// Write an image to a drawable. Each scanline is padded to the boundary the server expects for the
+    /// image's depth. If the image's data does not hold exactly `bytes_per_line` bytes for each of its
+    /// scanlines, an `ImageStrideMismatch` error is returned.
     #[inline]
     fn put_image<Target: Into<Drawable>, Data: Deref<Target = [u8]>>(
         &mut self,
@@ -261,7 +484,7 @@ pub trait DisplayDrawableExt: Display {
             dest_y,
             width,
             height,
-        );
+        )?;
 
         let mut toks = Vec::with_capacity(reqs.len());
         for req in reqs {
@@ -439,7 +662,10 @@ pub trait AsyncDisplayDrawableExt: AsyncDisplay {
             height,
         );
 
-        PutImageFuture::run(self, reqs)
+        match reqs {
+            Ok(reqs) => PutImageFuture::run(self, reqs),
+            Err(e) => PutImageFuture::failed(e),
+        }
     }
 
     // TODO: too lazy to fix this
@@ -483,3 +709,99 @@ pub trait AsyncDisplayDrawableExt: AsyncDisplay {
 
 #[cfg(feature = "async")]
 impl<D: AsyncDisplay + ?Sized> AsyncDisplayDrawableExt for D {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        auto::xproto::Setup,
+        display::{mock_display, mock_setup, MockConnection},
+    };
+
+    #[test]
+    fn drawable_format_tolerates_pixmaps() {
+        use crate::{
+            auto::xproto::{GetGeometryReply, GetWindowAttributesReply, Pixmap, Window},
+            display::traits::DisplayDrawableExt,
+        };
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_reply(&GetGeometryReply {
+            reply_type: 1,
+            depth: 24,
+            sequence: 2,
+            ..Default::default()
+        });
+        conn.feed_reply(&GetWindowAttributesReply {
+            reply_type: 1,
+            sequence: 3,
+            length: 3,
+            visual: 0x21,
+            ..Default::default()
+        });
+        conn.feed_reply(&GetGeometryReply {
+            reply_type: 1,
+            depth: 32,
+            sequence: 4,
+            ..Default::default()
+        });
+        // GetWindowAttributes fails with BadWindow for the pixmap
+        let mut error = [0u8; 32];
+        error[1] = 3;
+        error[2..4].copy_from_slice(&5u16.to_ne_bytes());
+        conn.feed(&error);
+
+        let mut display = mock_display(&mut conn);
+        assert_eq!(
+            display.drawable_format(Window::const_from_xid(1)).unwrap(),
+            (24, Some(0x21))
+        );
+        assert_eq!(
+            display.drawable_format(Pixmap::const_from_xid(2)).unwrap(),
+            (32, None)
+        );
+    }
+
+    #[test]
+    fn bitmaps_are_read_as_single_planes() {
+        use crate::auto::xproto::{Drawable, GetGeometryReply, GetImageReply, ImageFormat};
+        use alloc::vec;
+
+        let setup = Setup {
+            bitmap_format_scanline_unit: 32,
+            bitmap_format_scanline_pad: 32,
+            ..mock_setup()
+        };
+        let mut conn = MockConnection::with_setup(&setup);
+        conn.feed_reply(&GetGeometryReply {
+            reply_type: 1,
+            depth: 1,
+            sequence: 2,
+            ..Default::default()
+        });
+        // two scanlines of 10 pixels, each padded out to 32 bits
+        conn.feed_reply(&GetImageReply {
+            reply_type: 1,
+            depth: 1,
+            sequence: 3,
+            length: 2,
+            data: vec![0xFF, 0x03, 0, 0, 0x01, 0x02, 0, 0],
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        let image = Drawable::const_from_xid(1)
+            .read_image(&mut display, 0, 0, 10, 2)
+            .unwrap();
+        assert_eq!(image.format, ImageFormat::XyPixmap);
+        assert_eq!(image.depth, 1);
+        assert_eq!(image.bytes_per_line, 4);
+        assert_eq!(image.data, vec![0xFF, 0x03, 0, 0, 0x01, 0x02, 0, 0]);
+        drop(display);
+
+        // the GetImage request is the last thing sent, and asks for every plane
+        let requests = conn.outgoing();
+        let get_image = &requests[requests.len() - 20..];
+        assert_eq!(get_image[1], ImageFormat::XyPixmap as u8);
+        assert_eq!(&get_image[16..20], &u32::MAX.to_ne_bytes());
+    }
+}
@@ -0,0 +1,630 @@
+// MIT/Apache2 License
+
+//! This module contains functions for measuring text using fonts stored on the server, for listing fonts
+//! along with their metrics, and for managing the paths the server searches for fonts.
+
+use super::str_list;
+use crate::{
+    auto::{
+        xproto::{
+            Char2b, FontDraw, Fontable, GetFontPathRequest, ListFontsWithInfoReply,
+            ListFontsWithInfoRequest, QueryTextExtentsReply, SetFontPathRequest,
+        },
+        AsByteSequence,
+    },
+    display::{prelude::*, DisplayBase},
+    util::close_fds,
+    BreadError, Display, Request, RequestCookie,
+};
+use alloc::{string::String, vec::Vec};
+use core::{convert::TryFrom, iter::FusedIterator};
+
+#[cfg(feature = "async")]
+use crate::{
+    auto::xproto::GetFontPathReply,
+    display::{
+        futures::{ExchangeRequestFuture, MapFuture, SendRequestFuture},
+        AsyncDisplay, EitherFuture,
+    },
+};
+#[cfg(feature = "async")]
+use futures_lite::future::{self, Ready};
+
+/// The return type of `query_text_extents_immediate`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TextExtents {
+    pub draw_direction: FontDraw,
+    pub font_ascent: i16,
+    pub font_descent: i16,
+    pub overall_ascent: i16,
+    pub overall_descent: i16,
+    pub overall_width: i32,
+    pub overall_left: i32,
+    pub overall_right: i32,
+}
+
+impl From<QueryTextExtentsReply> for TextExtents {
+    #[inline]
+    fn from(qter: QueryTextExtentsReply) -> Self {
+        Self {
+            draw_direction: qter.draw_direction,
+            font_ascent: qter.font_ascent,
+            font_descent: qter.font_descent,
+            overall_ascent: qter.overall_ascent,
+            overall_descent: qter.overall_descent,
+            overall_width: qter.overall_width,
+            overall_left: qter.overall_left,
+            overall_right: qter.overall_right,
+        }
+    }
+}
+
+/// The `QueryTextExtents` request. The generated version of this request leaves out the `odd-length` flag,
+/// which the server needs in order to tell whether the last `CHAR2B` in the request is part of the string
+/// or just padding; without it, the font ID is shifted into the wrong place and the server replies with a
+/// `BadLength` or `BadFont` error.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextExtentsRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub font: Fontable,
+    pub string: Vec<Char2b>,
+}
+
+impl AsByteSequence for TextExtentsRequest {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.req_type.as_bytes(&mut bytes[index..]);
+        // if there are an odd number of two-byte characters, the request ends with two bytes of padding
+        index += (self.string.len() & 1 != 0).as_bytes(&mut bytes[index..]);
+        index += self.length.as_bytes(&mut bytes[index..]);
+        index += self.font.as_bytes(&mut bytes[index..]);
+        for c in &self.string {
+            index += c.as_bytes(&mut bytes[index..]);
+        }
+        index
+    }
+
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let mut index: usize = 0;
+        let (req_type, sz) = u8::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (odd_length, sz) = bool::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (length, sz) = u16::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (font, sz) = Fontable::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+
+        let mut len = (length as usize * 4).checked_sub(index)? / 2;
+        if odd_length {
+            len = len.checked_sub(1)?;
+        }
+        let mut string = Vec::with_capacity(len);
+        for _ in 0..len {
+            let (c, sz) = Char2b::from_bytes(bytes.get(index..)?)?;
+            index += sz;
+            string.push(c);
+        }
+
+        Some((
+            Self {
+                req_type,
+                length,
+                font,
+                string,
+            },
+            index,
+        ))
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        8 + self.string.len() * 2
+    }
+}
+
+impl Request for TextExtentsRequest {
+    const OPCODE: u8 = 48;
+    const EXTENSION: Option<&'static str> = None;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = "QueryTextExtents";
+    type Reply = QueryTextExtentsReply;
+}
+
+/// Query text extents request. Characters are sent as their Unicode code points, which is the encoding used
+/// by both ISO 8859-1 and ISO 10646 fonts; characters that don't fit into two bytes are replaced by U+FFFD.
+#[inline]
+fn query_text_extents_request(font: Fontable, text: &str) -> TextExtentsRequest {
+    TextExtentsRequest {
+        font,
+        string: text
+            .chars()
+            .map(|c| Char2b::try_from(c).unwrap_or_else(|_| Char2b::from(0xFFFD)))
+            .collect(),
+        ..Default::default()
+    }
+}
+
+/// The `SetFontPath` request. The generated version of this request pads the list of paths to a multiple
+/// of eight bytes rather than four, so the server sometimes finds four bytes left over after the last path
+/// and replies with a `BadLength` error.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FontPathRequest {
+    pub req_type: u8,
+    pub length: u16,
+    pub path: Vec<String>,
+}
+
+impl AsByteSequence for FontPathRequest {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.req_type.as_bytes(&mut bytes[index..]);
+        index += 1;
+        index += self.length.as_bytes(&mut bytes[index..]);
+        index += (self.path.len() as u16).as_bytes(&mut bytes[index..]);
+        index += 2;
+        // each path is a STRING8, prefixed with its length; padding is added once the request is sent
+        for path in &self.path {
+            index += (path.len() as u8).as_bytes(&mut bytes[index..]);
+            bytes[index..index + path.len()].copy_from_slice(path.as_bytes());
+            index += path.len();
+        }
+        index
+    }
+
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let (req_type, _) = u8::from_bytes(bytes)?;
+        let (length, _) = u16::from_bytes(bytes.get(2..)?)?;
+        let (count, _) = u16::from_bytes(bytes.get(4..)?)?;
+
+        let mut index = 8;
+        let mut path = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let len = *bytes.get(index)? as usize;
+            let name = bytes.get(index + 1..index + 1 + len)?;
+            path.push(String::from_utf8_lossy(name).into_owned());
+            index += 1 + len;
+        }
+
+        Some((
+            Self {
+                req_type,
+                length,
+                path,
+            },
+            index,
+        ))
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        8 + self.path.iter().map(|path| 1 + path.len()).sum::<usize>()
+    }
+}
+
+impl Request for FontPathRequest {
+    const OPCODE: u8 = SetFontPathRequest::OPCODE;
+    const EXTENSION: Option<&'static str> = None;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = SetFontPathRequest::NAME;
+    type Reply = ();
+}
+
+/// Set font path request. Each path has its length stored in a single byte, so none of them may be longer
+/// than 255 bytes.
+#[inline]
+fn set_font_path_request(path: &[&str]) -> crate::Result<FontPathRequest> {
+    if path.iter().any(|path| path.len() > u8::MAX as usize) {
+        return Err(BreadError::StaticMsg(
+            "Font paths cannot be longer than 255 bytes",
+        ));
+    }
+    if u16::try_from(path.len()).is_err() {
+        return Err(BreadError::StaticMsg("Too many font paths"));
+    }
+
+    Ok(FontPathRequest {
+        path: path.iter().map(|&path| String::from(path)).collect(),
+        ..Default::default()
+    })
+}
+
+/// An iterator over the fonts matching a pattern, along with their metrics. The server answers a
+/// `ListFontsWithInfo` request with one reply per font, followed by a reply with an empty name, so each
+/// item is read as it arrives rather than all at once. The request is sent when the first item is asked
+/// for.
+///
+/// Dropping the stream before it ends is fine; the replies that are still on their way are thrown away as
+/// they arrive. Once an error occurs, the stream ends.
+#[derive(Debug)]
+pub struct FontInfoStream<'a, Dpy: DisplayBase + ?Sized> {
+    display: &'a mut Dpy,
+    pattern: String,
+    max_names: u16,
+    sequence: Option<u16>,
+    done: bool,
+}
+
+impl<'a, Dpy: DisplayBase + ?Sized> FontInfoStream<'a, Dpy> {
+    #[inline]
+    fn new(display: &'a mut Dpy, pattern: &str, max_names: u16) -> Self {
+        Self {
+            display,
+            pattern: pattern.into(),
+            max_names,
+            sequence: None,
+            done: false,
+        }
+    }
+
+    #[inline]
+    fn request(&self) -> ListFontsWithInfoRequest {
+        ListFontsWithInfoRequest {
+            max_names: self.max_names,
+            pattern: self.pattern.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Figure out whether the reply we just received is a font or the end of the list.
+    #[inline]
+    fn process_reply(
+        &mut self,
+        repl: crate::Result<ListFontsWithInfoReply>,
+    ) -> Option<crate::Result<ListFontsWithInfoReply>> {
+        match repl {
+            Ok(repl) if repl.name.is_empty() => {
+                self.done = true;
+                None
+            }
+            Ok(repl) => Some(Ok(repl)),
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl<Dpy: Display + ?Sized> Iterator for FontInfoStream<'_, Dpy> {
+    type Item = crate::Result<ListFontsWithInfoReply>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let sequence = match self.sequence {
+            Some(sequence) => sequence,
+            None => match self.display.send_request(self.request()) {
+                Ok(cookie) => {
+                    let sequence = cookie.sequence();
+                    self.sequence = Some(sequence);
+                    sequence
+                }
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            },
+        };
+        let repl =
+            self.display
+                .resolve_request(RequestCookie::<ListFontsWithInfoRequest>::from_sequence(
+                    sequence,
+                ));
+        self.process_reply(repl)
+    }
+}
+
+impl<Dpy: Display + ?Sized> FusedIterator for FontInfoStream<'_, Dpy> {}
+
+#[cfg(feature = "async")]
+impl<Dpy: AsyncDisplay + ?Sized> FontInfoStream<'_, Dpy> {
+    /// Get the next font, async redox.
+    #[inline]
+    pub async fn next_async(&mut self) -> Option<crate::Result<ListFontsWithInfoReply>> {
+        if self.done {
+            return None;
+        }
+
+        let sequence = if let Some(sequence) = self.sequence {
+            sequence
+        } else {
+            let req = self.request();
+            match self.display.send_request_async(req).await {
+                Ok(cookie) => {
+                    let sequence = cookie.sequence();
+                    self.sequence = Some(sequence);
+                    sequence
+                }
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        };
+        let cookie = RequestCookie::<ListFontsWithInfoRequest>::from_sequence(sequence);
+        let repl = self.display.resolve_request_async(cookie).await;
+        self.process_reply(repl)
+    }
+}
+
+impl<Dpy: DisplayBase + ?Sized> Drop for FontInfoStream<'_, Dpy> {
+    #[inline]
+    fn drop(&mut self) {
+        // stop expecting replies, so that any that are still to come are dropped when they arrive
+        if let Some(sequence) = self.sequence {
+            self.display.take_pending_request(sequence);
+            while let Some(reply) = self.display.take_pending_reply(sequence) {
+                close_fds(reply.fds.into_vec());
+            }
+        }
+    }
+}
+
+pub trait DisplayFontExt: Display {
+    /// Ask the server to measure a string as it would be drawn in the given font. Either a font or a
+    /// graphics context, in which case its font is used, may be provided.
+    #[inline]
+    fn query_text_extents<Target: Into<Fontable>>(
+        &mut self,
+        font: Target,
+        text: &str,
+    ) -> crate::Result<RequestCookie<TextExtentsRequest>> {
+        self.send_request(query_text_extents_request(font.into(), text))
+    }
+
+    /// Immediately measure a string as it would be drawn in the given font.
+    #[inline]
+    fn query_text_extents_immediate<Target: Into<Fontable>>(
+        &mut self,
+        font: Target,
+        text: &str,
+    ) -> crate::Result<TextExtents> {
+        let tok = self.query_text_extents(font, text)?;
+        Ok(self.resolve_request(tok)?.into())
+    }
+
+    /// List the fonts whose names match `pattern`, up to `max_names` of them, along with their metrics and
+    /// properties. The pattern may contain the wildcards `*` and `?`.
+    #[inline]
+    fn list_fonts_with_info(&mut self, pattern: &str, max_names: u16) -> FontInfoStream<'_, Self> {
+        FontInfoStream::new(self, pattern, max_names)
+    }
+
+    /// Get the list of directories and font servers that the server searches for fonts, in the order they
+    /// are searched.
+    #[inline]
+    fn get_font_path(&mut self) -> crate::Result<Vec<String>> {
+        let repl = self.exchange_request(GetFontPathRequest::default())?;
+        Ok(str_list(repl.path))
+    }
+
+    /// Set the list of directories and font servers that the server searches for fonts. An empty list
+    /// restores the server's default path.
+    #[inline]
+    fn set_font_path(&mut self, path: &[&str]) -> crate::Result {
+        let req = set_font_path_request(path)?;
+        self.exchange_request(req)
+    }
+}
+
+impl<D: Display + ?Sized> DisplayFontExt for D {}
+
+#[cfg(feature = "async")]
+pub trait AsyncDisplayFontExt: AsyncDisplay {
+    /// Ask the server to measure a string as it would be drawn in the given font, async redox.
+    #[inline]
+    fn query_text_extents_async<Target: Into<Fontable>>(
+        &mut self,
+        font: Target,
+        text: &str,
+    ) -> SendRequestFuture<'_, Self, TextExtentsRequest> {
+        self.send_request_async(query_text_extents_request(font.into(), text))
+    }
+
+    /// Immediately measure a string as it would be drawn in the given font, async redox.
+    #[inline]
+    fn query_text_extents_immediate_async<Target: Into<Fontable>>(
+        &mut self,
+        font: Target,
+        text: &str,
+    ) -> MapFuture<
+        ExchangeRequestFuture<'_, Self, TextExtentsRequest>,
+        fn(crate::Result<QueryTextExtentsReply>) -> crate::Result<TextExtents>,
+    > {
+        MapFuture::run(
+            self.exchange_request_async(query_text_extents_request(font.into(), text)),
+            |repl| repl.map(TextExtents::from),
+        )
+    }
+
+    /// List the fonts whose names match a pattern, along with their metrics, async redox. Use
+    /// `FontInfoStream::next_async` to get each font.
+    #[inline]
+    fn list_fonts_with_info_async(
+        &mut self,
+        pattern: &str,
+        max_names: u16,
+    ) -> FontInfoStream<'_, Self> {
+        FontInfoStream::new(self, pattern, max_names)
+    }
+
+    /// Get the list of paths that the server searches for fonts, async redox.
+    #[inline]
+    fn get_font_path_async(
+        &mut self,
+    ) -> MapFuture<
+        ExchangeRequestFuture<'_, Self, GetFontPathRequest>,
+        fn(crate::Result<GetFontPathReply>) -> crate::Result<Vec<String>>,
+    > {
+        MapFuture::run(
+            self.exchange_request_async(GetFontPathRequest::default()),
+            |repl| repl.map(|repl| str_list(repl.path)),
+        )
+    }
+
+    /// Set the list of paths that the server searches for fonts, async redox.
+    #[inline]
+    fn set_font_path_async(
+        &mut self,
+        path: &[&str],
+    ) -> EitherFuture<Ready<crate::Result>, ExchangeRequestFuture<'_, Self, FontPathRequest>> {
+        match set_font_path_request(path) {
+            Ok(sfpr) => EitherFuture::Right {
+                future: self.exchange_request_async(sfpr),
+            },
+            Err(e) => EitherFuture::Left {
+                future: future::ready(Err(e)),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D: AsyncDisplay + ?Sized> AsyncDisplayFontExt for D {}
+
+#[cfg(test)]
+mod tests {
+    use super::{query_text_extents_request, set_font_path_request, FontPathRequest};
+    use crate::{
+        auto::{
+            xproto::{Fontable, GetInputFocusReply, GetInputFocusRequest, ListFontsWithInfoReply},
+            AsByteSequence,
+        },
+        display::{mock_display, mock_setup, DisplayBase, DisplayExt, MockConnection, RequestInfo},
+    };
+    use alloc::{string::String, vec};
+
+    #[test]
+    fn odd_length_text_extents() {
+        let req = query_text_extents_request(Fontable::const_from_xid(0x0100_0002), "abc");
+        let data = RequestInfo::from_request(req, false, usize::from(u16::MAX) * 4).data;
+
+        // three characters take up six bytes, so the request is padded from 14 to 16 bytes; the opcode is
+        // filled in once the request is sent
+        let len = 4u16.to_ne_bytes();
+        let font = 0x0100_0002u32.to_ne_bytes();
+        assert_eq!(
+            &data[..],
+            &[
+                0, 1, len[0], len[1], font[0], font[1], font[2], font[3], 0, b'a', 0, b'b', 0,
+                b'c', 0, 0
+            ][..]
+        );
+    }
+
+    #[test]
+    fn font_path_is_padded_to_four_bytes() {
+        // the generated request would pad these 9 bytes of paths to 16 bytes rather than 12
+        let req = set_font_path_request(&["abc", "defg"]).unwrap();
+        let data = RequestInfo::from_request(req.clone(), false, usize::from(u16::MAX) * 4).data;
+
+        let len = 5u16.to_ne_bytes();
+        let count = 2u16.to_ne_bytes();
+        assert_eq!(
+            &data[..],
+            &[
+                0, 0, len[0], len[1], count[0], count[1], 0, 0, 3, b'a', b'b', b'c', 4, b'd', b'e',
+                b'f', b'g', 0, 0, 0
+            ][..]
+        );
+        assert_eq!(FontPathRequest::from_bytes(&data).unwrap().0.path, req.path);
+
+        let long: String = vec!['a'; 256].into_iter().collect();
+        assert!(set_font_path_request(&[&long]).is_err());
+    }
+
+    #[test]
+    fn font_info_is_streamed_until_the_empty_reply() {
+        use crate::display::traits::DisplayFontExt;
+        use alloc::{string::String, vec::Vec};
+
+        fn font(conn: &mut MockConnection, sequence: u16, name: &str) {
+            conn.feed_reply(&ListFontsWithInfoReply {
+                reply_type: 1,
+                sequence,
+                // the names are all four bytes long, so they need no padding
+                length: if name.is_empty() { 7 } else { 8 },
+                name: name.into(),
+                ..Default::default()
+            });
+        }
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        for &sequence in &[2, 3] {
+            font(&mut conn, sequence, "misc");
+            font(&mut conn, sequence, "6x13");
+            font(&mut conn, sequence, "");
+        }
+        conn.feed_reply(&GetInputFocusReply {
+            reply_type: 1,
+            sequence: 4,
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        let names = display
+            .list_fonts_with_info("*", 10)
+            .map(|font| font.map(|font| font.name))
+            .collect::<Result<Vec<String>, _>>()
+            .unwrap();
+        assert_eq!(names, ["misc", "6x13"]);
+        assert!(display.get_pending_request(2).is_none());
+
+        // stopping early leaves the rest of the replies to be dropped as they come in
+        let mut fonts = display.list_fonts_with_info("*", 10);
+        assert_eq!(fonts.next().unwrap().unwrap().name, "misc");
+        drop(fonts);
+        display
+            .exchange_request(GetInputFocusRequest::default())
+            .unwrap();
+        assert_eq!(display.orphan_reply_count(), 2);
+    }
+
+    #[test]
+    fn font_info_stops_pending_at_the_empty_reply() {
+        use crate::auto::xproto::ListFontsWithInfoRequest;
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        for name in &["misc", "6x13", ""] {
+            conn.feed_reply(&ListFontsWithInfoReply {
+                reply_type: 1,
+                sequence: 2,
+                length: if name.is_empty() { 7 } else { 8 },
+                name: (*name).into(),
+                ..Default::default()
+            });
+        }
+        conn.feed_reply(&GetInputFocusReply {
+            reply_type: 1,
+            sequence: 3,
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        display.set_checked(false);
+        // without the stream, nothing takes the request once its replies are all in
+        let cookie = display
+            .send_request(ListFontsWithInfoRequest {
+                max_names: 10,
+                pattern: "*".into(),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(display.resolve_request(cookie).unwrap().name, "misc");
+        assert!(display.get_pending_request(2).is_some());
+
+        display
+            .exchange_request(GetInputFocusRequest::default())
+            .unwrap();
+        assert!(display.get_pending_request(2).is_none());
+    }
+}
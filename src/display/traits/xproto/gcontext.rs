@@ -2,9 +2,10 @@
 
 use crate::{
     auto::xproto::{
-        Arc, ChangeGcRequest, CoordMode, Drawable, FillPolyRequest, FreeGcRequest, Gcontext, Point,
-        PolyArcRequest, PolyFillArcRequest, PolyFillRectangleRequest, PolyRectangleRequest,
-        PolySegmentRequest, PolyShape, Rectangle, Segment,
+        Arc, ChangeGcRequest, ClipOrdering, CoordMode, Drawable, FillPolyRequest, FreeGcRequest,
+        Gcontext, Point, PolyArcRequest, PolyFillArcRequest, PolyFillRectangleRequest,
+        PolyRectangleRequest, PolySegmentRequest, PolyShape, Rectangle, Segment,
+        SetClipRectanglesRequest, SetDashesRequest,
     },
     display::prelude::*,
     Display, GcParameters,
@@ -49,6 +50,102 @@ impl Gcontext {
         dpy.exchange_request_async(self.change_request(params))
     }
 
+    #[inline]
+    fn set_clip_rectangles_request(
+        self,
+        ordering: ClipOrdering,
+        clip_x_origin: i16,
+        clip_y_origin: i16,
+        rectangles: &[Rectangle],
+    ) -> SetClipRectanglesRequest {
+        SetClipRectanglesRequest {
+            ordering,
+            gc: self,
+            clip_x_origin,
+            clip_y_origin,
+            rectangles: rectangles.to_vec(),
+            ..Default::default()
+        }
+    }
+
+    /// Clip everything drawn with this GC to the given rectangles, which are relative to the clip origin.
+    /// This replaces any clip mask the GC had. An empty list of rectangles means that nothing is drawn.
+    ///
+    /// If the rectangles are already sorted, saying so with `ordering` saves the server from sorting them
+    /// itself. If they aren't actually sorted the way `ordering` claims, the server may reply with a `Match`
+    /// error or clip incorrectly.
+    #[inline]
+    pub fn set_clip_rectangles<Dpy: Display + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+        ordering: ClipOrdering,
+        clip_x_origin: i16,
+        clip_y_origin: i16,
+        rectangles: &[Rectangle],
+    ) -> crate::Result {
+        dpy.exchange_request(self.set_clip_rectangles_request(
+            ordering,
+            clip_x_origin,
+            clip_y_origin,
+            rectangles,
+        ))
+    }
+
+    /// Clip everything drawn with this GC to the given rectangles, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub fn set_clip_rectangles_async<'a, Dpy: AsyncDisplay + ?Sized>(
+        self,
+        dpy: &'a mut Dpy,
+        ordering: ClipOrdering,
+        clip_x_origin: i16,
+        clip_y_origin: i16,
+        rectangles: &[Rectangle],
+    ) -> ExchangeRequestFuture<'a, Dpy, SetClipRectanglesRequest> {
+        dpy.exchange_request_async(self.set_clip_rectangles_request(
+            ordering,
+            clip_x_origin,
+            clip_y_origin,
+            rectangles,
+        ))
+    }
+
+    #[inline]
+    fn set_dashes_request(self, dash_offset: u16, dashes: &[u8]) -> SetDashesRequest {
+        SetDashesRequest {
+            gc: self,
+            dash_offset,
+            dashes: dashes.to_vec(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the dash pattern used for dashed lines drawn with this GC. The pattern alternates between the
+    /// lengths of the dashes and the gaps between them, starting with a dash, and `dash_offset` is how far
+    /// into the pattern each line starts. The pattern must not be empty, and none of its lengths may be
+    /// zero.
+    #[inline]
+    pub fn set_dashes<Dpy: Display + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+        dash_offset: u16,
+        dashes: &[u8],
+    ) -> crate::Result {
+        dpy.exchange_request(self.set_dashes_request(dash_offset, dashes))
+    }
+
+    /// Set the dash pattern used for dashed lines drawn with this GC, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub fn set_dashes_async<'a, Dpy: AsyncDisplay + ?Sized>(
+        self,
+        dpy: &'a mut Dpy,
+        dash_offset: u16,
+        dashes: &[u8],
+    ) -> ExchangeRequestFuture<'a, Dpy, SetDashesRequest> {
+        dpy.exchange_request_async(self.set_dashes_request(dash_offset, dashes))
+    }
+
     /// Request to draw a line.
     #[inline]
     fn poly_segment_request(self, drawable: Drawable, line: &[Segment]) -> PolySegmentRequest {
@@ -485,6 +582,7 @@ impl Gcontext {
     /// to fail.
     #[inline]
     pub fn free<Dpy: Display + ?Sized>(self, dpy: &mut Dpy) -> crate::Result {
+        dpy.untrack_resource(self.xid);
         dpy.exchange_request(FreeGcRequest {
             gc: self,
             ..Default::default()
@@ -498,6 +596,7 @@ impl Gcontext {
         self,
         dpy: &mut Dpy,
     ) -> ExchangeRequestFuture<'_, Dpy, FreeGcRequest> {
+        dpy.untrack_resource(self.xid);
         dpy.exchange_request_async(FreeGcRequest {
             gc: self,
             ..Default::default()
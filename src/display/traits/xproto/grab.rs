@@ -0,0 +1,465 @@
+// MIT/Apache2 License
+
+use crate::{
+    auto::xproto::{
+        Cursor, EventMask, GrabKeyRequest, GrabKeyboardRequest, GrabMode, GrabPointerRequest,
+        GrabServerRequest, GrabStatus, Keycode, ModMask, Timestamp, UngrabServerRequest, Window,
+    },
+    display::{prelude::*, Display},
+    BreadError,
+};
+use alloc::vec::Vec;
+use core::ops::{Deref, DerefMut};
+
+#[cfg(feature = "async")]
+use crate::display::AsyncDisplay;
+#[cfg(feature = "async")]
+use alloc::boxed::Box;
+#[cfg(feature = "async")]
+use core::{future::Future, pin::Pin};
+
+/// The future returned by `AsyncDisplayXprotoExt::grab_key_all_locks_async`.
+#[cfg(feature = "async")]
+pub type GrabKeysFuture<'a> = Pin<Box<dyn Future<Output = crate::Result> + 'a>>;
+
+/// An active pointer grab, which is released when this guard is dropped. The guard dereferences to the
+/// display, so requests can still be sent and events received while the grab is held.
+///
+/// Errors that occur while releasing the grab on drop are logged and then ignored. Use `ungrab` to handle
+/// them instead.
+#[derive(Debug)]
+#[must_use = "the pointer is ungrabbed as soon as the guard is dropped"]
+pub struct PointerGrab<'a, Dpy: Display + ?Sized> {
+    display: &'a mut Dpy,
+    // the time the grab was made with, which it is released with as well
+    time: Timestamp,
+    released: bool,
+}
+
+impl<'a, Dpy: Display + ?Sized> PointerGrab<'a, Dpy> {
+    /// Grab the pointer, returning a guard if the server accepted the grab.
+    #[inline]
+    pub(crate) fn new(display: &'a mut Dpy, req: GrabPointerRequest) -> crate::Result<Self> {
+        let time = req.time;
+        match display.exchange_request(req)?.status {
+            GrabStatus::Success => Ok(Self {
+                display,
+                time,
+                released: false,
+            }),
+            status => Err(BreadError::GrabFailed(status)),
+        }
+    }
+
+    /// Release the grab, returning any error that occurs while doing so.
+    #[inline]
+    pub fn ungrab(mut self) -> crate::Result {
+        self.released = true;
+        self.display.ungrab_pointer(Some(self.time))
+    }
+}
+
+impl<Dpy: Display + ?Sized> Deref for PointerGrab<'_, Dpy> {
+    type Target = Dpy;
+
+    #[inline]
+    fn deref(&self) -> &Dpy {
+        self.display
+    }
+}
+
+impl<Dpy: Display + ?Sized> DerefMut for PointerGrab<'_, Dpy> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Dpy {
+        self.display
+    }
+}
+
+impl<Dpy: Display + ?Sized> Drop for PointerGrab<'_, Dpy> {
+    #[inline]
+    fn drop(&mut self) {
+        // an ungrab with a time earlier than the grab's is ignored, and the last event we've read may be
+        // older than a grab made at CurrentTime, so release it with the time it was made with
+        if !self.released {
+            if let Err(e) = self.display.ungrab_pointer(Some(self.time)) {
+                log::error!("Failed to release pointer grab: {}", e);
+            }
+        }
+    }
+}
+
+/// An active keyboard grab, which is released when this guard is dropped. While it is held, every key event
+/// is reported to the grab window, which is what a modal dialog or password prompt needs. The guard
+/// dereferences to the display.
+///
+/// Errors that occur while releasing the grab on drop are logged and then ignored. Use `ungrab` to handle
+/// them instead.
+#[derive(Debug)]
+#[must_use = "the keyboard is ungrabbed as soon as the guard is dropped"]
+pub struct KeyboardGrab<'a, Dpy: Display + ?Sized> {
+    display: &'a mut Dpy,
+    // the time the grab was made with, which it is released with as well
+    time: Timestamp,
+    released: bool,
+}
+
+impl<'a, Dpy: Display + ?Sized> KeyboardGrab<'a, Dpy> {
+    /// Grab the keyboard, returning a guard if the server accepted the grab. While another client holds the
+    /// keyboard, `retry` is called with the number of attempts so far, and the grab is tried again if it
+    /// returns `true`.
+    #[inline]
+    pub(crate) fn new<F: FnMut(u32) -> bool>(
+        display: &'a mut Dpy,
+        req: GrabKeyboardRequest,
+        mut retry: F,
+    ) -> crate::Result<Self> {
+        let mut attempts = 0;
+        loop {
+            match display.exchange_request(req.clone())?.status {
+                GrabStatus::Success => {
+                    return Ok(Self {
+                        display,
+                        time: req.time,
+                        released: false,
+                    })
+                }
+                GrabStatus::AlreadyGrabbed if retry(attempts) => attempts += 1,
+                status => return Err(BreadError::GrabFailed(status)),
+            }
+        }
+    }
+
+    /// Release the grab, returning any error that occurs while doing so.
+    #[inline]
+    pub fn ungrab(mut self) -> crate::Result {
+        self.released = true;
+        self.display.ungrab_keyboard(Some(self.time))
+    }
+}
+
+impl<Dpy: Display + ?Sized> Deref for KeyboardGrab<'_, Dpy> {
+    type Target = Dpy;
+
+    #[inline]
+    fn deref(&self) -> &Dpy {
+        self.display
+    }
+}
+
+impl<Dpy: Display + ?Sized> DerefMut for KeyboardGrab<'_, Dpy> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Dpy {
+        self.display
+    }
+}
+
+impl<Dpy: Display + ?Sized> Drop for KeyboardGrab<'_, Dpy> {
+    #[inline]
+    fn drop(&mut self) {
+        if !self.released {
+            if let Err(e) = self.display.ungrab_keyboard(Some(self.time)) {
+                log::error!("Failed to release keyboard grab: {}", e);
+            }
+        }
+    }
+}
+
+/// A grab of the entire server, which is released when this guard is dropped. While the server is grabbed,
+/// it does not process requests from any other client. The guard dereferences to the display.
+///
+/// Errors that occur while releasing the grab on drop are logged and then ignored. Use `ungrab` to handle
+/// them instead.
+#[derive(Debug)]
+#[must_use = "the server is ungrabbed as soon as the guard is dropped"]
+pub struct ServerGrab<'a, Dpy: Display + ?Sized> {
+    display: &'a mut Dpy,
+    released: bool,
+}
+
+impl<'a, Dpy: Display + ?Sized> ServerGrab<'a, Dpy> {
+    #[inline]
+    pub(crate) fn new(display: &'a mut Dpy) -> crate::Result<Self> {
+        display.exchange_request(GrabServerRequest::default())?;
+        Ok(Self {
+            display,
+            released: false,
+        })
+    }
+
+    /// Release the grab, returning any error that occurs while doing so.
+    #[inline]
+    pub fn ungrab(mut self) -> crate::Result {
+        self.released = true;
+        self.display
+            .exchange_request(UngrabServerRequest::default())
+    }
+}
+
+impl<Dpy: Display + ?Sized> Deref for ServerGrab<'_, Dpy> {
+    type Target = Dpy;
+
+    #[inline]
+    fn deref(&self) -> &Dpy {
+        self.display
+    }
+}
+
+impl<Dpy: Display + ?Sized> DerefMut for ServerGrab<'_, Dpy> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Dpy {
+        self.display
+    }
+}
+
+impl<Dpy: Display + ?Sized> Drop for ServerGrab<'_, Dpy> {
+    #[inline]
+    fn drop(&mut self) {
+        if !self.released {
+            if let Err(e) = self
+                .display
+                .exchange_request(UngrabServerRequest::default())
+            {
+                log::error!("Failed to release server grab: {}", e);
+            }
+        }
+    }
+}
+
+#[inline]
+pub(crate) fn grab_pointer_request(
+    grab_window: Window,
+    event_mask: EventMask,
+    confine_to: Option<Window>,
+    cursor: Option<Cursor>,
+    time: Timestamp,
+) -> GrabPointerRequest {
+    GrabPointerRequest {
+        owner_events: false,
+        grab_window,
+        event_mask,
+        // neither the pointer nor the keyboard are frozen
+        pointer_mode: GrabMode::Async,
+        keyboard_mode: GrabMode::Async,
+        confine_to: confine_to.unwrap_or_default(),
+        cursor: cursor.unwrap_or_default(),
+        time,
+        ..Default::default()
+    }
+}
+
+/// A keyboard grab for a modal window: key events are only reported to `grab_window`, and neither the
+/// pointer nor the keyboard are frozen.
+#[inline]
+pub(crate) fn grab_keyboard_modal_request(
+    grab_window: Window,
+    time: Timestamp,
+) -> GrabKeyboardRequest {
+    GrabKeyboardRequest {
+        owner_events: false,
+        grab_window,
+        time,
+        pointer_mode: GrabMode::Async,
+        keyboard_mode: GrabMode::Async,
+        ..Default::default()
+    }
+}
+
+/// The passive grabs for `key` with exactly `modifiers` held, one for each combination of Caps Lock and
+/// `num_lock`, so that the grab activates whether or not either lock is on. Neither the pointer nor the
+/// keyboard are frozen once it does.
+#[inline]
+pub(crate) fn grab_key_all_locks_requests(
+    grab_window: Window,
+    key: Keycode,
+    modifiers: ModMask,
+    num_lock: ModMask,
+) -> Vec<GrabKeyRequest> {
+    let mut locks = [
+        ModMask::default(),
+        ModMask::LOCK,
+        num_lock,
+        ModMask::LOCK | num_lock,
+    ]
+    .to_vec();
+    // if Num Lock isn't bound to anything, the last two are the same as the first two
+    locks.sort_unstable();
+    locks.dedup();
+
+    locks
+        .into_iter()
+        .map(|locks| GrabKeyRequest {
+            owner_events: false,
+            grab_window,
+            modifiers: modifiers | locks,
+            key,
+            pointer_mode: GrabMode::Async,
+            keyboard_mode: GrabMode::Async,
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Send every grab before waiting on any of them, so that registering them takes one round trip.
+#[inline]
+pub(crate) fn grab_keys<Dpy: Display + ?Sized>(
+    display: &mut Dpy,
+    reqs: Vec<GrabKeyRequest>,
+) -> crate::Result {
+    let toks = reqs
+        .into_iter()
+        .map(|req| display.send_request(req))
+        .collect::<crate::Result<Vec<_>>>()?;
+    toks.into_iter()
+        .try_for_each(|tok| display.resolve_request(tok))
+}
+
+/// Send every grab before waiting on any of them, async redox.
+#[cfg(feature = "async")]
+#[inline]
+pub(crate) async fn grab_keys_async<Dpy: AsyncDisplay + ?Sized>(
+    display: &mut Dpy,
+    reqs: Vec<GrabKeyRequest>,
+) -> crate::Result {
+    let mut toks = Vec::with_capacity(reqs.len());
+    for req in reqs {
+        toks.push(display.send_request_async(req).await?);
+    }
+    for tok in toks {
+        display.resolve_request_async(tok).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::grab_key_all_locks_requests;
+    use crate::auto::xproto::{ModMask, Window};
+    use crate::{
+        display::{mock_display, mock_setup, DisplayBase, MockConnection},
+        BreadError, ServerTime,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn key_grabs_cover_every_lock() {
+        let modifiers = |num_lock| -> Vec<u16> {
+            grab_key_all_locks_requests(Window::const_from_xid(1), 46, ModMask::FOUR, num_lock)
+                .iter()
+                .map(|req| req.modifiers.inner)
+                .collect()
+        };
+        assert_eq!(modifiers(ModMask::TWO), [64, 66, 80, 82]);
+        assert_eq!(modifiers(ModMask::default()), [64, 66]);
+    }
+
+    #[test]
+    fn pointer_grab_released_on_drop() {
+        use crate::{
+            auto::xproto::{EventMask, GrabPointerReply, GrabStatus, Window},
+            display::prelude::DisplayXprotoExt,
+        };
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_reply(&GrabPointerReply {
+            reply_type: 1,
+            status: GrabStatus::Success,
+            sequence: 2,
+            ..Default::default()
+        });
+        conn.feed_reply(&GrabPointerReply {
+            reply_type: 1,
+            status: GrabStatus::AlreadyGrabbed,
+            sequence: 4,
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        display.set_checked(false);
+        let window = Window::const_from_xid(1);
+
+        let mut grab = display
+            .grab_pointer(
+                window,
+                EventMask::BUTTON_RELEASE,
+                Some(window),
+                None,
+                Some(1000),
+            )
+            .unwrap();
+        // an event from before the grab must not decide when it's released
+        grab.set_last_event_time(ServerTime::new(500));
+        drop(grab);
+
+        // a refused grab never needs to be released
+        match display.grab_pointer(window, EventMask::BUTTON_RELEASE, None, None, None) {
+            Err(BreadError::GrabFailed(GrabStatus::AlreadyGrabbed)) => (),
+            res => panic!("expected a failed grab, got {:?}", res.map(|_| ())),
+        }
+        drop(display);
+
+        // GrabPointer is 24 bytes, and UngrabPointer is 8
+        let written = conn.take_outgoing();
+        let requests = &written[written.len() - 56..];
+        assert_eq!(&requests[..4], &[26, 0, 6, 0]);
+        assert_eq!(
+            &requests[8..10],
+            &EventMask::BUTTON_RELEASE.inner.to_ne_bytes()[..2]
+        );
+        assert_eq!(&requests[12..16], &1u32.to_ne_bytes());
+        assert_eq!(&requests[20..24], &1000u32.to_ne_bytes());
+        assert_eq!(&requests[24..28], &[27, 0, 2, 0]);
+        assert_eq!(&requests[28..32], &1000u32.to_ne_bytes());
+        assert_eq!(&requests[32..36], &[26, 0, 6, 0]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn modal_keyboard_grab_retries_and_releases() {
+        use crate::{
+            auto::xproto::{GrabKeyboardReply, GrabStatus, Window},
+            display::prelude::DisplayXprotoExt,
+        };
+        use core::time::Duration;
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        for (sequence, status) in &[
+            (2, GrabStatus::NotViewable),
+            (3, GrabStatus::AlreadyGrabbed),
+            (4, GrabStatus::Success),
+        ] {
+            conn.feed_reply(&GrabKeyboardReply {
+                reply_type: 1,
+                status: *status,
+                sequence: *sequence,
+                ..Default::default()
+            });
+        }
+
+        let mut display = mock_display(&mut conn);
+        display.set_checked(false);
+        let window = Window::const_from_xid(1);
+
+        display.set_last_event_time(ServerTime::new(700));
+        match display.grab_keyboard_modal(window) {
+            Err(BreadError::GrabFailed(GrabStatus::NotViewable)) => (),
+            res => panic!("expected a failed grab, got {:?}", res.map(|_| ())),
+        }
+        let mut grab = display
+            .grab_keyboard_modal_retrying(window, 2, Duration::from_millis(1))
+            .unwrap();
+        grab.set_last_event_time(ServerTime::new(900));
+        drop(grab);
+        drop(display);
+
+        // three GrabKeyboards of 16 bytes each, then an UngrabKeyboard of 8
+        let written = conn.take_outgoing();
+        let requests = &written[written.len() - 56..];
+        for grab in requests[..48].chunks(16) {
+            assert_eq!(&grab[..4], &[31, 0, 4, 0]);
+            assert_eq!(&grab[4..8], &1u32.to_ne_bytes());
+            assert_eq!(&grab[8..12], &700u32.to_ne_bytes());
+        }
+        assert_eq!(&requests[48..52], &[32, 0, 2, 0]);
+        assert_eq!(&requests[52..], &700u32.to_ne_bytes());
+    }
+}
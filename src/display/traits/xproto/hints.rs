@@ -0,0 +1,225 @@
+// MIT/Apache2 License
+
+use super::{PropertyFormat, PropertyType};
+use crate::{
+    auto::xproto::{Pixmap, PropMode, Window, ATOM_WM_HINTS},
+    display::Display,
+    XidType,
+};
+
+#[cfg(feature = "async")]
+use crate::display::AsyncDisplay;
+
+const INPUT_HINT: u32 = 1 << 0;
+const STATE_HINT: u32 = 1 << 1;
+const ICON_PIXMAP_HINT: u32 = 1 << 2;
+const ICON_WINDOW_HINT: u32 = 1 << 3;
+const ICON_POSITION_HINT: u32 = 1 << 4;
+const ICON_MASK_HINT: u32 = 1 << 5;
+const WINDOW_GROUP_HINT: u32 = 1 << 6;
+const URGENCY_HINT: u32 = 1 << 8;
+
+/// The state a window should be in when it is first mapped.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum InitialState {
+    Withdrawn = 0,
+    Normal = 1,
+    Iconic = 3,
+}
+
+/// The contents of the ICCCM `WM_HINTS` property, which tells the window manager how a window takes input
+/// focus, how it should be iconified, and which group of windows it belongs to. Only the hints that are
+/// set are sent; the window manager picks its own defaults for the rest.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct WmHints {
+    /// Whether or not the window relies on the window manager to give it input focus.
+    pub input: Option<bool>,
+    pub initial_state: Option<InitialState>,
+    pub icon_pixmap: Option<Pixmap>,
+    pub icon_window: Option<Window>,
+    pub icon_position: Option<(i32, i32)>,
+    /// A bitmap that gives `icon_pixmap` its shape.
+    pub icon_mask: Option<Pixmap>,
+    /// The leader of the group of windows this window belongs to.
+    pub window_group: Option<Window>,
+    /// Whether or not the window needs the user's attention.
+    pub urgent: bool,
+}
+
+impl WmHints {
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn input(self, input: bool) -> Self {
+        Self {
+            input: Some(input),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn initial_state(self, initial_state: InitialState) -> Self {
+        Self {
+            initial_state: Some(initial_state),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn icon_pixmap(self, icon_pixmap: Pixmap) -> Self {
+        Self {
+            icon_pixmap: Some(icon_pixmap),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn icon_window(self, icon_window: Window) -> Self {
+        Self {
+            icon_window: Some(icon_window),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn icon_position(self, x: i32, y: i32) -> Self {
+        Self {
+            icon_position: Some((x, y)),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn icon_mask(self, icon_mask: Pixmap) -> Self {
+        Self {
+            icon_mask: Some(icon_mask),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn window_group(self, window_group: Window) -> Self {
+        Self {
+            window_group: Some(window_group),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn urgent(self, urgent: bool) -> Self {
+        Self { urgent, ..self }
+    }
+
+    /// The nine words of the property: the flags saying which hints are set, then a word for each hint,
+    /// which is left zero if the hint isn't set.
+    #[allow(clippy::cast_sign_loss)]
+    #[inline]
+    fn to_words(self) -> [u32; 9] {
+        let mut words = [0; 9];
+        let mut set = |flag: u32, index: usize, value: u32| {
+            words[0] |= flag;
+            words[index] = value;
+        };
+
+        if let Some(input) = self.input {
+            set(INPUT_HINT, 1, u32::from(input));
+        }
+        if let Some(initial_state) = self.initial_state {
+            set(STATE_HINT, 2, initial_state as u32);
+        }
+        if let Some(icon_pixmap) = self.icon_pixmap {
+            set(ICON_PIXMAP_HINT, 3, icon_pixmap.xid());
+        }
+        if let Some(icon_window) = self.icon_window {
+            set(ICON_WINDOW_HINT, 4, icon_window.xid());
+        }
+        if let Some((x, y)) = self.icon_position {
+            set(ICON_POSITION_HINT, 5, x as u32);
+            set(ICON_POSITION_HINT, 6, y as u32);
+        }
+        if let Some(icon_mask) = self.icon_mask {
+            set(ICON_MASK_HINT, 7, icon_mask.xid());
+        }
+        if let Some(window_group) = self.window_group {
+            set(WINDOW_GROUP_HINT, 8, window_group.xid());
+        }
+        if self.urgent {
+            words[0] |= URGENCY_HINT;
+        }
+
+        words
+    }
+}
+
+impl Window {
+    /// Set the `WM_HINTS` property on this window.
+    #[inline]
+    pub fn set_wm_hints<Dpy: Display + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+        hints: WmHints,
+    ) -> crate::Result {
+        self.change_property(
+            dpy,
+            ATOM_WM_HINTS,
+            PropertyType::WMHints,
+            PropertyFormat::ThirtyTwo,
+            PropMode::Replace,
+            &hints.to_words(),
+        )
+    }
+
+    /// Set the `WM_HINTS` property on this window, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn set_wm_hints_async<Dpy: AsyncDisplay + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+        hints: WmHints,
+    ) -> crate::Result {
+        self.change_property_async(
+            dpy,
+            ATOM_WM_HINTS,
+            PropertyType::WMHints,
+            PropertyFormat::ThirtyTwo,
+            PropMode::Replace,
+            &hints.to_words(),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InitialState, WmHints};
+
+    #[test]
+    fn only_set_hints_are_flagged() {
+        let words = WmHints::new()
+            .input(true)
+            .initial_state(InitialState::Iconic)
+            .to_words();
+        assert_eq!(words, [0b11, 1, 3, 0, 0, 0, 0, 0, 0]);
+
+        let words = WmHints::new()
+            .input(false)
+            .icon_position(-5, 10)
+            .urgent(true)
+            .to_words();
+        assert_eq!(words[0], 1 | 1 << 4 | 1 << 8);
+        assert_eq!(&words[1..7], &[0, 0, 0, 0, 0xFFFF_FFFB, 10]);
+    }
+}
@@ -0,0 +1,270 @@
+// MIT/Apache2 License
+
+use crate::{
+    auto::{
+        buffer_pad, vector_as_bytes, vector_from_bytes,
+        xproto::{AccessControl, Family, ListHostsRequest},
+        AsByteSequence,
+    },
+    Request,
+};
+use alloc::vec::Vec;
+
+/// The address family of an entry in the host access list. Unlike `Family`, this can represent the families
+/// that the server reports but does not accept in `ChangeHosts`, as well as any family it doesn't know about.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum HostFamily {
+    Internet,
+    DecNet,
+    Chaos,
+    ServerInterpreted,
+    Internet6,
+    LocalHost,
+    Krb5Principal,
+    Netname,
+    Other(u8),
+}
+
+impl Default for HostFamily {
+    #[inline]
+    fn default() -> Self {
+        Self::Internet
+    }
+}
+
+impl From<u8> for HostFamily {
+    #[inline]
+    fn from(family: u8) -> Self {
+        match family {
+            0 => Self::Internet,
+            1 => Self::DecNet,
+            2 => Self::Chaos,
+            5 => Self::ServerInterpreted,
+            6 => Self::Internet6,
+            252 => Self::LocalHost,
+            253 => Self::Krb5Principal,
+            254 => Self::Netname,
+            family => Self::Other(family),
+        }
+    }
+}
+
+impl From<HostFamily> for u8 {
+    #[inline]
+    fn from(family: HostFamily) -> u8 {
+        match family {
+            HostFamily::Internet => 0,
+            HostFamily::DecNet => 1,
+            HostFamily::Chaos => 2,
+            HostFamily::ServerInterpreted => 5,
+            HostFamily::Internet6 => 6,
+            HostFamily::LocalHost => 252,
+            HostFamily::Krb5Principal => 253,
+            HostFamily::Netname => 254,
+            HostFamily::Other(family) => family,
+        }
+    }
+}
+
+impl From<Family> for HostFamily {
+    #[inline]
+    fn from(family: Family) -> Self {
+        Self::from(family as u8)
+    }
+}
+
+impl AsByteSequence for HostFamily {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        u8::from(*self).as_bytes(bytes)
+    }
+
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let (family, sz) = u8::from_bytes(bytes)?;
+        Some((family.into(), sz))
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        1
+    }
+}
+
+/// A host that is allowed to connect to the server. For the `Internet` family, the address is the four
+/// bytes of an IPv4 address; for `ServerInterpreted`, it is a type name and a value separated by a zero byte.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct HostEntry {
+    pub family: HostFamily,
+    pub address: Vec<u8>,
+}
+
+impl AsByteSequence for HostEntry {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.family.as_bytes(&mut bytes[index..]);
+        index += 1;
+        #[allow(clippy::cast_possible_truncation)]
+        let len = self.address.len() as u16;
+        index += len.as_bytes(&mut bytes[index..]);
+        let block_len = vector_as_bytes(&self.address, &mut bytes[index..]);
+        index += block_len;
+        index + buffer_pad(block_len, 4)
+    }
+
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let mut index: usize = 0;
+        let (family, sz) = HostFamily::from_bytes(&bytes[index..])?;
+        index += sz;
+        index += 1;
+        let (len, sz) = u16::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        let (address, block_len) = vector_from_bytes(bytes.get(index..)?, len as usize)?;
+        index += block_len;
+        index += buffer_pad(block_len, 4);
+        Some((Self { family, address }, index))
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        4 + self.address.len() + buffer_pad(self.address.len(), 4)
+    }
+}
+
+/// The host access list, as well as whether or not the server is checking connecting hosts against it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HostList {
+    pub mode: AccessControl,
+    pub hosts: Vec<HostEntry>,
+}
+
+impl AsByteSequence for HostList {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += 1u8.as_bytes(&mut bytes[index..]);
+        index += self.mode.as_bytes(&mut bytes[index..]);
+        index += 0u16.as_bytes(&mut bytes[index..]);
+        #[allow(clippy::cast_possible_truncation)]
+        let length = ((self.size() - 32) / 4) as u32;
+        index += length.as_bytes(&mut bytes[index..]);
+        #[allow(clippy::cast_possible_truncation)]
+        let hosts_len = self.hosts.len() as u16;
+        index += hosts_len.as_bytes(&mut bytes[index..]);
+        index += 22;
+        index + vector_as_bytes(&self.hosts, &mut bytes[index..])
+    }
+
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let mut index: usize = 0;
+        log::trace!("Deserializing HostList from byte buffer");
+        // reply type
+        index += 1;
+        let (mode, sz) = AccessControl::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        // sequence and length
+        index += 6;
+        let (hosts_len, sz) = u16::from_bytes(bytes.get(index..)?)?;
+        index += sz;
+        index += 22;
+        let (hosts, block_len) = vector_from_bytes(bytes.get(index..)?, hosts_len as usize)?;
+        index += block_len;
+        Some((Self { mode, hosts }, index))
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        32 + self.hosts.iter().map(AsByteSequence::size).sum::<usize>()
+    }
+}
+
+/// The `ListHosts` request, with a reply that can hold host families the generated `Family` type can't.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct HostListRequest {
+    pub req_type: u8,
+    pub length: u16,
+}
+
+impl AsByteSequence for HostListRequest {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.req_type.as_bytes(&mut bytes[index..]);
+        index += 1;
+        index += self.length.as_bytes(&mut bytes[index..]);
+        index
+    }
+
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let (req_type, _) = u8::from_bytes(bytes)?;
+        let (length, _) = u16::from_bytes(bytes.get(2..)?)?;
+        Some((Self { req_type, length }, 4))
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        4
+    }
+}
+
+impl Request for HostListRequest {
+    const OPCODE: u8 = ListHostsRequest::OPCODE;
+    const EXTENSION: Option<&'static str> = None;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = ListHostsRequest::NAME;
+    type Reply = HostList;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HostEntry, HostFamily, HostList};
+    use crate::auto::{xproto::AccessControl, AsByteSequence};
+    use alloc::vec;
+
+    #[test]
+    fn host_list_keeps_unknown_families() {
+        // an IPv4 host, then the server-interpreted "localuser" entry, then a LocalHost entry
+        let mut bytes = vec![1, 1, 0, 0];
+        bytes.extend_from_slice(&8u32.to_ne_bytes());
+        bytes.extend_from_slice(&3u16.to_ne_bytes());
+        bytes.extend_from_slice(&[0; 22]);
+        bytes.extend_from_slice(&[0, 0]);
+        bytes.extend_from_slice(&4u16.to_ne_bytes());
+        bytes.extend_from_slice(&[127, 0, 0, 1]);
+        bytes.extend_from_slice(&[5, 0]);
+        bytes.extend_from_slice(&14u16.to_ne_bytes());
+        bytes.extend_from_slice(b"localuser\0root\0\0");
+        bytes.extend_from_slice(&[252, 0]);
+        bytes.extend_from_slice(&0u16.to_ne_bytes());
+
+        let (list, len) = HostList::from_bytes(&bytes).unwrap();
+        assert_eq!(len, bytes.len());
+        assert_eq!(list.mode, AccessControl::Enable);
+        assert_eq!(
+            list.hosts,
+            vec![
+                HostEntry {
+                    family: HostFamily::Internet,
+                    address: vec![127, 0, 0, 1],
+                },
+                HostEntry {
+                    family: HostFamily::ServerInterpreted,
+                    address: b"localuser\0root".to_vec(),
+                },
+                HostEntry {
+                    family: HostFamily::LocalHost,
+                    address: vec![],
+                },
+            ]
+        );
+
+        // and it round-trips
+        let mut out = vec![0; list.size()];
+        list.as_bytes(&mut out);
+        assert_eq!(out, bytes);
+    }
+}
@@ -0,0 +1,171 @@
+// MIT/Apache2 License
+
+use super::{PropertyFormat, PropertyType, PROPERTY_CHUNK_LENGTH};
+use crate::{
+    auto::xproto::{Atom, ChangePropertyRequest, PropMode, Window},
+    display::{prelude::*, Display},
+    BreadError,
+};
+use alloc::{string::ToString, vec::Vec};
+
+#[cfg(feature = "async")]
+use crate::display::AsyncDisplay;
+
+/// One size of a window's icon, as stored in `_NET_WM_ICON`. Each pixel is a premultiplied ARGB value,
+/// and the pixels are laid out row by row, so there must be exactly `width * height` of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IconImage {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<u32>,
+}
+
+impl Window {
+    /// Set the `_NET_WM_ICON` property on this window to the given icons. The window manager picks
+    /// whichever size suits it best. Large icons are sent in chunks of `PROPERTY_CHUNK_LENGTH` 32-bit units,
+    /// so the property can be bigger than the maximum request length.
+    #[inline]
+    pub fn set_wm_icon<Dpy: Display + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+        icons: &[IconImage],
+    ) -> crate::Result<()> {
+        let data = wm_icon_data(icons)?;
+        let net_wm_icon = dpy.intern_atom_immediate("_NET_WM_ICON".to_string(), false)?;
+        for cpr in wm_icon_requests(self, net_wm_icon, &data) {
+            dpy.exchange_request(cpr)?;
+        }
+        Ok(())
+    }
+
+    /// Set the `_NET_WM_ICON` property on this window, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn set_wm_icon_async<Dpy: AsyncDisplay + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+        icons: &[IconImage],
+    ) -> crate::Result<()> {
+        let data = wm_icon_data(icons)?;
+        let net_wm_icon = dpy
+            .intern_atom_immediate_async("_NET_WM_ICON".to_string(), false)
+            .await?;
+        for cpr in wm_icon_requests(self, net_wm_icon, &data) {
+            dpy.exchange_request_async(cpr).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Pack the icons into the layout `_NET_WM_ICON` expects: the width, then the height, then the pixels, for
+/// every icon in turn.
+#[inline]
+fn wm_icon_data(icons: &[IconImage]) -> crate::Result<Vec<u32>> {
+    let mut data = Vec::with_capacity(
+        icons
+            .iter()
+            .map(|icon| icon.pixels.len() + 2)
+            .sum::<usize>(),
+    );
+
+    for icon in icons {
+        let expected = (icon.width as usize).checked_mul(icon.height as usize);
+        if expected != Some(icon.pixels.len()) {
+            return Err(BreadError::StaticMsg(
+                "Icon must have exactly width * height pixels",
+            ));
+        }
+
+        data.push(icon.width);
+        data.push(icon.height);
+        data.extend_from_slice(&icon.pixels);
+    }
+
+    Ok(data)
+}
+
+/// Split the icon data into `ChangeProperty` requests; the first replaces the property, and the rest append
+/// to it. An empty icon list still produces one request, which clears the property.
+#[inline]
+fn wm_icon_requests<'a>(
+    window: Window,
+    net_wm_icon: Atom,
+    data: &'a [u32],
+) -> impl Iterator<Item = ChangePropertyRequest> + 'a {
+    let mut chunks: Vec<&'a [u32]> = data.chunks(PROPERTY_CHUNK_LENGTH as usize).collect();
+    if chunks.is_empty() {
+        chunks.push(&[]);
+    }
+
+    chunks.into_iter().enumerate().map(move |(i, chunk)| {
+        let mode = if i == 0 {
+            PropMode::Replace
+        } else {
+            PropMode::Append
+        };
+        window.change_property_request(
+            net_wm_icon,
+            PropertyType::Cardinal,
+            PropertyFormat::ThirtyTwo,
+            mode,
+            chunk,
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{wm_icon_data, wm_icon_requests, IconImage};
+    use crate::{
+        auto::xproto::{Atom, PropMode, Window},
+        display::PROPERTY_CHUNK_LENGTH,
+    };
+    use alloc::{vec, vec::Vec};
+
+    #[test]
+    fn wm_icon_layout() {
+        let icons = [
+            IconImage {
+                width: 1,
+                height: 2,
+                pixels: vec![0xFF00_0000, 0xFFFF_FFFF],
+            },
+            IconImage {
+                width: 1,
+                height: 1,
+                pixels: vec![0x8000_0080],
+            },
+        ];
+        assert_eq!(
+            wm_icon_data(&icons).unwrap(),
+            vec![1, 2, 0xFF00_0000, 0xFFFF_FFFF, 1, 1, 0x8000_0080]
+        );
+
+        let bad = IconImage {
+            width: 2,
+            height: 2,
+            pixels: vec![0; 3],
+        };
+        assert!(wm_icon_data(&[bad]).is_err());
+    }
+
+    #[test]
+    fn wm_icon_is_chunked() {
+        let window = Window::const_from_xid(1);
+        let atom = Atom::const_from_xid(2);
+        let data = vec![0u32; PROPERTY_CHUNK_LENGTH as usize + 1];
+
+        let requests: Vec<_> = wm_icon_requests(window, atom, &data).collect();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].mode, PropMode::Replace);
+        assert_eq!(requests[0].data_len, PROPERTY_CHUNK_LENGTH);
+        assert_eq!(requests[1].mode, PropMode::Append);
+        assert_eq!(requests[1].data_len, 1);
+
+        // no icons still clears the property
+        let requests: Vec<_> = wm_icon_requests(window, atom, &[]).collect();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].mode, PropMode::Replace);
+        assert_eq!(requests[0].data_len, 0);
+    }
+}
@@ -4,46 +4,125 @@
 
 use crate::{
     auto::xproto::{
-        AccessControl, ArcMode, Atom, AutoRepeatMode, BackingStore, BellRequest, CapStyle,
-        ChangeActivePointerGrabRequest, ChangeGcRequest, ChangeKeyboardControlRequest,
-        ChangePointerControlRequest, ChangeWindowAttributesRequest, CloseDown, Colormap,
-        ColormapAlloc, CreateColormapRequest, CreateCursorRequest, CreateGcRequest,
-        CreateWindowRequest, Cursor, Cw, Drawable, EventMask, FillRule, FillStyle, Font,
-        ForceScreenSaverRequest, Gc, Gcontext, GetKeyboardMappingReply, GetKeyboardMappingRequest,
-        GetModifierMappingReply, GetModifierMappingRequest, Gravity, Gx, InternAtomRequest,
-        JoinStyle, Kb, Keycode, Keysym, LedMode, LineStyle, Pixmap, QueryExtensionRequest,
-        ScreenSaver, SendEventRequest, SetAccessControlRequest, SetCloseDownModeRequest,
-        SubwindowMode, Timestamp, Visualid, Window, WindowClass,
+        AccessControl, Allow, AllowEventsRequest, ArcMode, Atom, AutoRepeatMode, BackingStore,
+        BellRequest, CapStyle, ChangeActivePointerGrabRequest, ChangeGcRequest, ChangeHostsRequest,
+        ChangeKeyboardControlRequest, ChangeKeyboardMappingRequest, ChangePointerControlRequest,
+        ChangeWindowAttributesRequest, CloseDown, Colormap, ColormapAlloc, CreateColormapRequest,
+        CreateCursorRequest, CreateGcRequest, CreateWindowRequest, Cursor, Cw, Drawable, EventMask,
+        Family, FillRule, FillStyle, Font, ForceScreenSaverRequest, Gc, Gcontext,
+        GetInputFocusReply, GetInputFocusRequest, GetKeyboardMappingReply,
+        GetKeyboardMappingRequest, GetModifierMappingReply, GetModifierMappingRequest,
+        GetMotionEventsRequest, GetPointerMappingRequest, Gravity, Gx, HostMode, InputFocus,
+        InternAtomRequest, JoinStyle, Kb, Keycode, Keysym, Kill, KillClientRequest, LedMode,
+        LineStyle, ListExtensionsRequest, MappingStatus, ModMask, NoOperationRequest, Pixmap,
+        QueryColorsReply, QueryColorsRequest, QueryExtensionRequest, QueryKeymapReply,
+        QueryKeymapRequest, ReparentWindowRequest, Rgb, RotatePropertiesRequest, ScreenSaver,
+        SendEventRequest, SetAccessControlRequest, SetCloseDownModeRequest, SetInputFocusRequest,
+        SetPointerMappingRequest, Str, SubwindowMode, Timecoord, Timestamp, UngrabKeyboardRequest,
+        UngrabPointerRequest, Visualid, Window, WindowClass,
     },
-    display::{generate_xid, Display, RequestCookie},
-    Event, Extension,
+    display::{generate_xid, Display, RequestCookie, ResourceKind},
+    event::OPCODE_MASK,
+    util::expand_or_truncate_to_length,
+    BreadError, Event, Extension, Request, XID,
 };
-use alloc::{boxed::Box, string::String};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::iter;
 use cty::c_char;
+use tinyvec::TinyVec;
 
 #[cfg(feature = "async")]
 use crate::{
-    auto::xproto::{InternAtomReply, QueryExtensionReply},
+    auto::xproto::{
+        GetMotionEventsReply, GetPointerMappingReply, InternAtomReply, ListExtensionsReply,
+        QueryExtensionReply, SetPointerMappingReply,
+    },
     display::{
         futures::{ExchangeRequestFuture, ExchangeXidFuture, MapFuture, SendRequestFuture},
-        AsyncDisplay,
+        AsyncDisplay, EitherFuture,
     },
     util::BoxedFnOnce,
 };
+#[cfg(feature = "async")]
+use futures_lite::future::{self, Ready};
+#[cfg(feature = "std")]
+use std::{thread, time::Duration};
+
+// macro for retrieving an atom that might be cached in the display
+macro_rules! retrieve_atom {
+    ($dpy: expr, $dgetter: ident, $dsetter: ident, $name: expr) => {{
+        match $dpy.$dgetter() {
+            Some(wpa) => Atom::const_from_xid(wpa.get()),
+            None => {
+                let wpa = $dpy.intern_atom_immediate(($name).to_string(), false)?;
+                if wpa.xid() == 0 {
+                    log::error!("Unable to intern {} atom", $name);
+                    return Ok(());
+                }
+
+                $dpy.$dsetter(core::num::NonZeroU32::new(wpa.xid()).unwrap());
+                wpa
+            }
+        }
+    }};
+}
+
+#[cfg(feature = "async")]
+macro_rules! retrieve_atom_async {
+    ($dpy: expr, $dgetter: ident, $dsetter: ident, $name: expr) => {{
+        match $dpy.$dgetter() {
+            Some(wpa) => Atom::const_from_xid(wpa.get()),
+            None => {
+                let wpa = $dpy
+                    .intern_atom_immediate_async(($name).to_string(), false)
+                    .await?;
+                if wpa.xid() == 0 {
+                    log::error!("Unable to intern {} atom", $name);
+                    return Ok(());
+                } else {
+                    $dpy.$dsetter(core::num::NonZeroU32::new(wpa.xid()).unwrap());
+                    wpa
+                }
+            }
+        }
+    }};
+}
 
 mod colormap;
 mod cursor;
 mod drawable;
+mod font;
 mod gcontext;
+mod grab;
+mod hints;
+mod host;
+mod icon;
 mod pixmap;
+mod polytext;
+mod property;
+mod selection;
+mod text;
 mod window;
+mod window_builder;
+mod xembed;
 
 pub use colormap::*;
 pub use cursor::*;
 pub use drawable::*;
+pub use font::*;
 pub use gcontext::*;
+pub use grab::*;
+pub use hints::*;
+pub use host::*;
+pub use icon::*;
 pub use pixmap::*;
+pub use polytext::*;
+pub use property::*;
+pub use selection::*;
+pub use text::*;
 pub use window::*;
+pub use window_builder::*;
+pub use xembed::*;
 
 crate::create_paramaterizer! {
     pub struct WindowParameters : (Cw, CreateWindowRequest) {
@@ -166,20 +245,50 @@ impl GcParameters {
     }
 }
 
+/// The keysyms bound to a range of keycodes. `keysyms` holds `keysyms_per_keycode` keysyms for each keycode,
+/// starting with `first_keycode`; use `keysyms` to get the ones for a single keycode instead of indexing into
+/// it.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct KeyboardMapping {
+    pub first_keycode: Keycode,
     pub keysyms_per_keycode: u8,
     pub keysyms: Box<[Keysym]>,
 }
 
-impl From<GetKeyboardMappingReply> for KeyboardMapping {
+impl KeyboardMapping {
+    /// Create a keyboard mapping from the reply to a `GetKeyboardMapping` request that started at
+    /// `first_keycode`.
     #[inline]
-    fn from(gkmr: GetKeyboardMappingReply) -> Self {
+    #[must_use]
+    pub fn from_reply(first_keycode: Keycode, reply: GetKeyboardMappingReply) -> Self {
         Self {
-            keysyms_per_keycode: gkmr.keysyms_per_keycode,
-            keysyms: gkmr.keysyms.into_boxed_slice(),
+            first_keycode,
+            keysyms_per_keycode: reply.keysyms_per_keycode,
+            keysyms: reply.keysyms.into_boxed_slice(),
         }
     }
+
+    /// The keysyms bound to `keycode`, one for each combination of modifiers the server keeps track of.
+    /// Unused entries at the end are `NoSymbol`, which is zero. If `keycode` isn't in this mapping, the slice
+    /// is empty.
+    #[inline]
+    #[must_use]
+    pub fn keysyms(&self, keycode: Keycode) -> &[Keysym] {
+        let per_keycode = usize::from(self.keysyms_per_keycode);
+        let start = match keycode.checked_sub(self.first_keycode) {
+            Some(index) => usize::from(index) * per_keycode,
+            None => return &[],
+        };
+        self.keysyms.get(start..start + per_keycode).unwrap_or(&[])
+    }
+
+    /// Iterate over the keycodes in this mapping, along with their keysyms.
+    #[inline]
+    pub fn rows(&self) -> impl Iterator<Item = (Keycode, &[Keysym])> + '_ {
+        // chunks_exact() panics on zero, but there are no keysyms to chunk then anyway
+        let per_keycode = usize::from(self.keysyms_per_keycode).max(1);
+        (self.first_keycode..=Keycode::MAX).zip(self.keysyms.chunks_exact(per_keycode))
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -198,6 +307,213 @@ impl From<GetModifierMappingReply> for ModifierMapping {
     }
 }
 
+/// Which keys on the keyboard are physically held down, as reported by `query_keymap`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PressedKeys {
+    /// One bit per keycode: the bit for keycode `k` is bit `k % 8` of byte `k / 8`.
+    pub keys: [u8; 32],
+}
+
+impl PressedKeys {
+    /// Whether or not the key with the given keycode is held down.
+    #[inline]
+    #[must_use]
+    pub fn is_pressed(&self, keycode: Keycode) -> bool {
+        self.keys[usize::from(keycode / 8)] & (1 << (keycode % 8)) != 0
+    }
+
+    /// Iterate over the keycodes of every key that is held down, in ascending order.
+    #[inline]
+    pub fn keycodes(&self) -> impl Iterator<Item = Keycode> + '_ {
+        (0..=Keycode::MAX).filter(move |&keycode| self.is_pressed(keycode))
+    }
+}
+
+impl From<QueryKeymapReply> for PressedKeys {
+    #[inline]
+    fn from(qkr: QueryKeymapReply) -> Self {
+        Self { keys: qkr.keys }
+    }
+}
+
+/// The window that has the input focus.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FocusTarget {
+    /// Keyboard events are discarded.
+    None,
+    /// The focus follows the root window the pointer is on.
+    PointerRoot,
+    /// A specific window.
+    Window(Window),
+}
+
+impl FocusTarget {
+    #[inline]
+    fn to_window(self) -> Window {
+        match self {
+            Self::None => Window::const_from_xid(0),
+            Self::PointerRoot => Window::const_from_xid(1),
+            Self::Window(window) => window,
+        }
+    }
+
+    #[inline]
+    fn from_window(window: Window) -> Self {
+        match window.xid {
+            0 => Self::None,
+            1 => Self::PointerRoot,
+            _ => Self::Window(window),
+        }
+    }
+}
+
+/// Where the input focus goes if the focused window becomes unviewable.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RevertTo {
+    None,
+    PointerRoot,
+    Parent,
+}
+
+impl From<RevertTo> for InputFocus {
+    #[inline]
+    fn from(revert_to: RevertTo) -> Self {
+        match revert_to {
+            RevertTo::None => InputFocus::None,
+            RevertTo::PointerRoot => InputFocus::PointerRoot,
+            RevertTo::Parent => InputFocus::Parent,
+        }
+    }
+}
+
+impl From<InputFocus> for RevertTo {
+    #[inline]
+    fn from(input_focus: InputFocus) -> Self {
+        match input_focus {
+            InputFocus::PointerRoot => RevertTo::PointerRoot,
+            InputFocus::Parent => RevertTo::Parent,
+            // FollowKeyboard is only used by the XInput extension
+            InputFocus::None | InputFocus::FollowKeyboard => RevertTo::None,
+        }
+    }
+}
+
+/// The resources to kill with `kill_client`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum KillTarget {
+    /// Close the connection of the client that created this resource.
+    Resource(XID),
+    /// Destroy every resource left behind by clients that disconnected while in the `RetainTemporary`
+    /// close-down mode.
+    AllTemporary,
+}
+
+impl KillTarget {
+    #[inline]
+    fn to_resource(self) -> u32 {
+        match self {
+            Self::Resource(xid) => xid,
+            Self::AllTemporary => Kill::AllTemporary as u32,
+        }
+    }
+}
+
+#[inline]
+fn kill_client_request(target: KillTarget) -> KillClientRequest {
+    KillClientRequest {
+        resource: target.to_resource(),
+        ..Default::default()
+    }
+}
+
+#[inline]
+fn keyboard_mapping_request(first_keycode: Keycode, count: u8) -> GetKeyboardMappingRequest {
+    GetKeyboardMappingRequest {
+        first_keycode,
+        count,
+        ..Default::default()
+    }
+}
+
+#[inline]
+fn reparent_window_request(
+    window: Window,
+    parent: Window,
+    x: i16,
+    y: i16,
+) -> ReparentWindowRequest {
+    ReparentWindowRequest {
+        window,
+        parent,
+        x,
+        y,
+        ..Default::default()
+    }
+}
+
+/// The size of a frame that fits around a window of the given size, with its border.
+#[inline]
+fn frame_size(width: u16, height: u16, border_width: u16) -> (u16, u16) {
+    let border = border_width.saturating_mul(2);
+    (width.saturating_add(border), height.saturating_add(border))
+}
+
+/// The `NoOperation` request, followed by a number of padding bytes. The server ignores everything after the
+/// request's header; the generated version of this request can't carry anything past it.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PaddedNoOperationRequest {
+    pub req_type: u8,
+    pub length: u16,
+    /// The number of padding bytes. This is rounded up to a multiple of four.
+    pub padding: usize,
+}
+
+impl AsByteSequence for PaddedNoOperationRequest {
+    #[inline]
+    fn as_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut index: usize = 0;
+        index += self.req_type.as_bytes(&mut bytes[index..]);
+        index += 1;
+        index += self.length.as_bytes(&mut bytes[index..]);
+        bytes[index..index + self.padding]
+            .iter_mut()
+            .for_each(|b| *b = 0);
+        index + self.padding
+    }
+
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let (req_type, _) = u8::from_bytes(bytes)?;
+        let (length, _) = u16::from_bytes(bytes.get(2..)?)?;
+        let len = length as usize * 4;
+        if bytes.len() < len {
+            return None;
+        }
+
+        Some((
+            Self {
+                req_type,
+                length,
+                padding: len.checked_sub(4)?,
+            },
+            len,
+        ))
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        4 + self.padding
+    }
+}
+
+impl Request for PaddedNoOperationRequest {
+    const OPCODE: u8 = NoOperationRequest::OPCODE;
+    const EXTENSION: Option<&'static str> = None;
+    const REPLY_EXPECTS_FDS: bool = false;
+    const NAME: &'static str = NoOperationRequest::NAME;
+    type Reply = ();
+}
+
 #[inline]
 fn create_window_request(
     wid: Window,
@@ -295,6 +611,72 @@ fn intern_atom_request(name: String, exists: bool) -> InternAtomRequest {
     }
 }
 
+/// Change Hosts Request
+#[inline]
+fn change_hosts_request(mode: HostMode, family: Family, address: &[u8]) -> ChangeHostsRequest {
+    ChangeHostsRequest {
+        mode,
+        family,
+        address: address.to_vec(),
+        ..Default::default()
+    }
+}
+
+/// Change Keyboard Mapping Request. The server expects exactly `keysyms_per_keycode` keysyms for each of the
+/// `keycode_count` keycodes being changed, so anything else is rejected before it is sent.
+#[inline]
+fn change_keyboard_mapping_request(
+    first_keycode: Keycode,
+    keycode_count: u8,
+    keysyms_per_keycode: u8,
+    keysyms: &[Keysym],
+) -> crate::Result<ChangeKeyboardMappingRequest> {
+    if keysyms.len() != usize::from(keycode_count) * usize::from(keysyms_per_keycode) {
+        return Err(BreadError::StaticMsg(
+            "The number of keysyms must be keycode_count * keysyms_per_keycode",
+        ));
+    }
+
+    Ok(ChangeKeyboardMappingRequest {
+        keycode_count,
+        first_keycode,
+        keysyms_per_keycode,
+        keysyms: keysyms.to_vec(),
+        ..Default::default()
+    })
+}
+
+/// Rotate Properties Request. The server refuses to rotate a list that names the same property twice, so such
+/// lists are rejected before they are sent, as are lists too long for the request's count field.
+#[inline]
+fn rotate_properties_request(
+    window: Window,
+    delta: i16,
+    atoms: &[Atom],
+) -> crate::Result<RotatePropertiesRequest> {
+    if atoms.len() > usize::from(u16::MAX) {
+        return Err(BreadError::StaticMsg(
+            "At most 65535 properties can be rotated at once",
+        ));
+    }
+    if atoms
+        .iter()
+        .enumerate()
+        .any(|(i, atom)| atoms[..i].contains(atom))
+    {
+        return Err(BreadError::StaticMsg(
+            "A property can only appear once in the list of properties to rotate",
+        ));
+    }
+
+    Ok(RotatePropertiesRequest {
+        window,
+        delta,
+        atoms: atoms.to_vec(),
+        ..Default::default()
+    })
+}
+
 /// Change Keyboard Control Request
 #[inline]
 fn change_keyboard_control_request(props: KbParameters) -> ChangeKeyboardControlRequest {
@@ -308,16 +690,48 @@ fn change_keyboard_control_request(props: KbParameters) -> ChangeKeyboardControl
 fn change_active_pointer_grab_request(
     event_mask: EventMask,
     cursor: Cursor,
-    time: Option<Timestamp>,
+    time: Timestamp,
 ) -> ChangeActivePointerGrabRequest {
     ChangeActivePointerGrabRequest {
         cursor,
         event_mask,
-        time: time.unwrap_or(0),
+        time,
+        ..Default::default()
+    }
+}
+
+#[inline]
+fn set_input_focus_request(
+    focus: FocusTarget,
+    revert_to: RevertTo,
+    time: Timestamp,
+) -> SetInputFocusRequest {
+    SetInputFocusRequest {
+        revert_to: revert_to.into(),
+        focus: focus.to_window(),
+        time,
         ..Default::default()
     }
 }
 
+/// The time to use for a request that takes a server timestamp: `time` if one was given, or else the time
+/// of the last event received, since `CurrentTime` lets requests from different clients race each other.
+#[inline]
+pub(crate) fn time_or_last_event<Dpy: DisplayBase + ?Sized>(
+    dpy: &Dpy,
+    time: Option<Timestamp>,
+) -> Timestamp {
+    time.unwrap_or_else(|| dpy.last_event_time().get())
+}
+
+#[inline]
+fn input_focus_from_reply(reply: GetInputFocusReply) -> (FocusTarget, RevertTo) {
+    (
+        FocusTarget::from_window(reply.focus),
+        reply.revert_to.into(),
+    )
+}
+
 #[inline]
 fn change_pointer_control_request(
     accel_numer: i16,
@@ -367,17 +781,33 @@ fn create_cursor_request(
     }
 }
 
+/// Serialize an event into the 32-byte body used by the `SendEvent` request.
 #[inline]
-fn send_event_request(target: Window, em: EventMask, event: Event) -> SendEventRequest {
-    let mut bytes: [u8; 32] = [0; 32];
+fn send_event_request(
+    propagate: bool,
+    destination: Window,
+    em: EventMask,
+    event: Event,
+) -> SendEventRequest {
+    const EVENT_SIZE: usize = 32;
+
+    // serialize the event at its natural size, then pad or truncate it to fit
+    let mut bytes: TinyVec<[u8; EVENT_SIZE]> = iter::repeat(0).take(event.size()).collect();
     event.as_bytes(&mut bytes);
+    expand_or_truncate_to_length(&mut bytes, EVENT_SIZE);
+
+    // the server sets the synthetic bit itself once it delivers the event, and older servers will
+    // reject the request if we set it here, so make sure a re-sent event doesn't carry it over
+    bytes[0] = event.opcode() & OPCODE_MASK;
 
-    bytes[0] = event.opcode();
+    let mut body: [u8; EVENT_SIZE] = [0; EVENT_SIZE];
+    body.copy_from_slice(&bytes);
 
     SendEventRequest {
-        destination: target,
+        propagate,
+        destination,
         event_mask: em,
-        event: bytemuck::cast::<_, [c_char; 32]>(bytes),
+        event: bytemuck::cast::<_, [c_char; EVENT_SIZE]>(body),
         ..Default::default()
     }
 }
@@ -399,6 +829,28 @@ fn create_colormap_request(
     }
 }
 
+/// Query colors request.
+#[inline]
+fn query_colors_request(cmap: Colormap, pixels: Vec<u32>) -> QueryColorsRequest {
+    QueryColorsRequest {
+        cmap,
+        pixels,
+        ..Default::default()
+    }
+}
+
+/// Pair each pixel with the color the server returned for it.
+#[inline]
+fn zip_query_colors_reply(pixels: &[u32], reply: QueryColorsReply) -> Vec<(u32, Rgb)> {
+    pixels.iter().copied().zip(reply.colors).collect()
+}
+
+/// Get the strings out of a `STRING8` list, as used by the `ListExtensions` and `GetFontPath` replies.
+#[inline]
+fn str_list(strs: Vec<Str>) -> Vec<String> {
+    strs.into_iter().map(|s| s.name).collect()
+}
+
 pub trait DisplayXprotoExt: Display {
     /// Query for extension information.
     #[inline]
@@ -423,6 +875,13 @@ pub trait DisplayXprotoExt: Display {
         Extension::from_reply(qer)
     }
 
+    /// List the names of every extension the server supports.
+    #[inline]
+    fn list_extensions(&mut self) -> crate::Result<Vec<String>> {
+        let repl = self.exchange_request(ListExtensionsRequest::default())?;
+        Ok(str_list(repl.names))
+    }
+
     /// Create a new window.
     #[inline]
     fn create_window(
@@ -454,9 +913,19 @@ pub trait DisplayXprotoExt: Display {
         );
 
         self.exchange_request(cw)?;
+        self.track_resource(wid.xid, ResourceKind::Window);
         Ok(wid)
     }
 
+    /// Start building a child window of `parent`, which is created once `build` is called on the builder.
+    /// This is easier to get right than `create_window`, since every parameter is set by name and has a
+    /// default, so `create_window_builder(root).geometry(0, 0, 640, 480).build()` is enough for a plain
+    /// top-level window.
+    #[inline]
+    fn create_window_builder(&mut self, parent: Window) -> WindowBuilder<'_, Self> {
+        WindowBuilder::new(self, parent)
+    }
+
     /// Create a window, but assume some parameters from its parents.
     #[inline]
     fn create_simple_window(
@@ -483,6 +952,7 @@ pub trait DisplayXprotoExt: Display {
             background,
         );
         self.exchange_request(cw)?;
+        self.track_resource(wid.xid, ResourceKind::Window);
         Ok(wid)
     }
 
@@ -496,6 +966,7 @@ pub trait DisplayXprotoExt: Display {
         let gid = Gcontext::const_from_xid(generate_xid(self)?);
         let gcr = create_gc_request(gid, target.into(), props);
         self.exchange_request(gcr)?;
+        self.track_resource(gid.xid, ResourceKind::Gcontext);
         Ok(gid)
     }
 
@@ -523,6 +994,25 @@ pub trait DisplayXprotoExt: Display {
         self.exchange_request(ckcr)
     }
 
+    /// Change the keysyms of `keycode_count` keycodes, starting at `first_keycode`. `keysyms` holds
+    /// `keysyms_per_keycode` keysyms for each keycode, one keycode after another; if it has any other length,
+    /// an error is returned without sending anything.
+    #[inline]
+    fn change_keyboard_mapping(
+        &mut self,
+        first_keycode: Keycode,
+        keycode_count: u8,
+        keysyms_per_keycode: u8,
+        keysyms: &[Keysym],
+    ) -> crate::Result {
+        self.exchange_request(change_keyboard_mapping_request(
+            first_keycode,
+            keycode_count,
+            keysyms_per_keycode,
+            keysyms,
+        )?)
+    }
+
     #[inline]
     fn bell(&mut self, percent: i8) -> crate::Result {
         self.exchange_request(BellRequest {
@@ -531,6 +1021,7 @@ pub trait DisplayXprotoExt: Display {
         })
     }
 
+    /// Enable or disable checking connecting clients against the host access list.
     #[inline]
     fn set_access_control(&mut self, mode: AccessControl) -> crate::Result {
         self.exchange_request(SetAccessControlRequest {
@@ -539,6 +1030,21 @@ pub trait DisplayXprotoExt: Display {
         })
     }
 
+    /// Get the host access list, and whether or not it is being used to check connecting clients.
+    #[inline]
+    fn list_hosts(&mut self) -> crate::Result<HostList> {
+        self.exchange_request(HostListRequest::default())
+    }
+
+    /// Add a host to the access list, or remove one from it. Only the `Internet`, `DecNet`, `Chaos`,
+    /// `ServerInterpreted` and `Internet6` families can be changed.
+    #[inline]
+    fn change_hosts(&mut self, mode: HostMode, family: Family, address: &[u8]) -> crate::Result {
+        self.exchange_request(change_hosts_request(mode, family, address))
+    }
+
+    /// Change the parameters of the active pointer grab. If `time` is `None`, the time of the last event
+    /// received is used.
     #[inline]
     fn change_active_pointer_grab(
         &mut self,
@@ -546,9 +1052,189 @@ pub trait DisplayXprotoExt: Display {
         cursor: Cursor,
         time: Option<Timestamp>,
     ) -> crate::Result {
+        let time = time_or_last_event(self, time);
         self.exchange_request(change_active_pointer_grab_request(event_mask, cursor, time))
     }
 
+    /// Release the pointer grab held by this client. If `time` is `None`, the time of the last event
+    /// received is used; the grab is not released if it was made after `time`.
+    #[inline]
+    fn ungrab_pointer(&mut self, time: Option<Timestamp>) -> crate::Result {
+        let time = time_or_last_event(self, time);
+        self.exchange_request(UngrabPointerRequest {
+            time,
+            ..Default::default()
+        })
+    }
+
+    /// Grab the pointer, optionally confining it to `confine_to` and showing `cursor` while the grab is
+    /// held, and return a guard that releases the grab once it's dropped. This is meant for bracketing
+    /// gestures like interactive moves and resizes. Events are reported to `grab_window` only, and neither
+    /// the pointer nor the keyboard are frozen. If `time` is `None`, the time of the last event received is
+    /// used. If the server refuses the grab, `BreadError::GrabFailed` is returned.
+    #[inline]
+    fn grab_pointer(
+        &mut self,
+        grab_window: Window,
+        event_mask: EventMask,
+        confine_to: Option<Window>,
+        cursor: Option<Cursor>,
+        time: Option<Timestamp>,
+    ) -> crate::Result<PointerGrab<'_, Self>> {
+        let time = time_or_last_event(self, time);
+        let req = grab_pointer_request(grab_window, event_mask, confine_to, cursor, time);
+        PointerGrab::new(self, req)
+    }
+
+    /// Grab the server, and return a guard that releases the grab once it's dropped.
+    #[inline]
+    fn grab_server(&mut self) -> crate::Result<ServerGrab<'_, Self>> {
+        ServerGrab::new(self)
+    }
+
+    /// Grab the keyboard for a modal dialog, so that every key event is reported to `window`, and return a
+    /// guard that releases the grab once it's dropped. The grab is made with the time of the last event
+    /// received, and neither the pointer nor the keyboard are frozen. If the server refuses the grab,
+    /// `BreadError::GrabFailed` is returned with the reason, such as `AlreadyGrabbed` if another client holds
+    /// the keyboard or `NotViewable` if `window` isn't mapped yet.
+    #[inline]
+    fn grab_keyboard_modal(&mut self, window: Window) -> crate::Result<KeyboardGrab<'_, Self>> {
+        let req = grab_keyboard_modal_request(window, time_or_last_event(self, None));
+        KeyboardGrab::new(self, req, |_| false)
+    }
+
+    /// Grab the keyboard for a modal dialog like `grab_keyboard_modal`, but if another client holds the
+    /// keyboard, try again up to `retries` times. Other clients often hold the keyboard only briefly, such as
+    /// a window manager in the middle of a key binding. The thread sleeps for `backoff` before the first
+    /// retry, and for one more `backoff` before each retry after that.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn grab_keyboard_modal_retrying(
+        &mut self,
+        window: Window,
+        retries: u32,
+        backoff: Duration,
+    ) -> crate::Result<KeyboardGrab<'_, Self>> {
+        let req = grab_keyboard_modal_request(window, time_or_last_event(self, None));
+        KeyboardGrab::new(self, req, |attempts| {
+            if attempts >= retries {
+                return false;
+            }
+
+            thread::sleep(backoff.saturating_mul(attempts + 1));
+            true
+        })
+    }
+
+    /// Release the keyboard grab held by this client. If `time` is `None`, the time of the last event
+    /// received is used; the grab is not released if it was made after `time`.
+    #[inline]
+    fn ungrab_keyboard(&mut self, time: Option<Timestamp>) -> crate::Result {
+        let time = time_or_last_event(self, time);
+        self.exchange_request(UngrabKeyboardRequest {
+            time,
+            ..Default::default()
+        })
+    }
+
+    /// Register passive grabs so that pressing `key` while exactly `modifiers` are held grabs the keyboard
+    /// for `grab_window`, whether or not Caps Lock or Num Lock is on. Global hotkeys are bound this way,
+    /// usually on the root window. Num Lock is assumed to be `Mod2`, as on nearly every server; use
+    /// `xkb_grab_key` to look it up instead.
+    #[inline]
+    fn grab_key_all_locks(
+        &mut self,
+        grab_window: Window,
+        key: Keycode,
+        modifiers: ModMask,
+    ) -> crate::Result {
+        let reqs = grab_key_all_locks_requests(grab_window, key, modifiers, ModMask::TWO);
+        grab_keys(self, reqs)
+    }
+
+    /// Release events that are frozen by a grab. If `time` is `None`, the time of the last event received
+    /// is used.
+    ///
+    /// When a grab is made with `GrabMode::Sync` for the pointer or the keyboard, the server stops
+    /// processing events for that device after the event that activated the grab, and queues them until
+    /// the client calls this. `SyncPointer`/`SyncKeyboard` let the next event through and freeze the device
+    /// again, the `Async` modes thaw the device for good, and the `Replay` modes release the grab and resend
+    /// the event that activated it as though the grab never happened. A client that makes a synchronous grab
+    /// and never calls this leaves the device frozen, and input stops for every client until the grab is
+    /// released.
+    #[inline]
+    fn allow_events(&mut self, mode: Allow, time: Option<Timestamp>) -> crate::Result {
+        let time = time_or_last_event(self, time);
+        self.exchange_request(AllowEventsRequest {
+            mode,
+            time,
+            ..Default::default()
+        })
+    }
+
+    /// Get the window that currently has the input focus, as well as where the focus reverts to if that
+    /// window becomes unviewable.
+    #[inline]
+    fn get_input_focus(&mut self) -> crate::Result<(FocusTarget, RevertTo)> {
+        let repl = self.exchange_request(GetInputFocusRequest::default())?;
+        Ok(input_focus_from_reply(repl))
+    }
+
+    /// Get which keys on the keyboard are currently held down. This reflects the physical state of the
+    /// keyboard, so it is useful for polling keys without tracking `KeyPress` and `KeyRelease` events.
+    #[inline]
+    fn query_keymap(&mut self) -> crate::Result<PressedKeys> {
+        let repl = self.exchange_request(QueryKeymapRequest::default())?;
+        Ok(repl.into())
+    }
+
+    /// Set the input focus. If `time` is `None`, the time of the last event received is used.
+    #[inline]
+    fn set_input_focus(
+        &mut self,
+        focus: FocusTarget,
+        revert_to: RevertTo,
+        time: Option<Timestamp>,
+    ) -> crate::Result {
+        let time = time_or_last_event(self, time);
+        self.exchange_request(set_input_focus_request(focus, revert_to, time))
+    }
+
+    /// Get the pointer positions the server recorded between `start` and `stop`, relative to `window`. This
+    /// fills in the points between `MotionNotify` events, which may be compressed or skipped when the pointer
+    /// moves quickly. Either time can be zero, meaning the current server time. If the server does not keep
+    /// a motion history, which it advertises with a `motion_buffer_size` of zero in the setup, or the times
+    /// are in the wrong order, the list is empty.
+    #[inline]
+    fn get_motion_events(
+        &mut self,
+        window: Window,
+        start: Timestamp,
+        stop: Timestamp,
+    ) -> crate::Result<Vec<Timecoord>> {
+        let repl = self.exchange_request(GetMotionEventsRequest {
+            window,
+            start,
+            stop,
+            ..Default::default()
+        })?;
+        Ok(repl.events)
+    }
+
+    /// Set what happens to the resources this client created once its connection closes. By default,
+    /// they are all destroyed.
+    ///
+    /// * `CloseDown::DestroyAll` destroys them as soon as the connection closes.
+    /// * `CloseDown::RetainPermanent` keeps them until the server resets, or until another client kills
+    ///   them with `kill_client(KillTarget::Resource(..))`.
+    /// * `CloseDown::RetainTemporary` keeps them as well, but they are also destroyed when any client
+    ///   calls `kill_client(KillTarget::AllTemporary)`.
+    ///
+    /// For instance, a launcher can create a window, switch to `RetainTemporary` and exit, leaving the
+    /// window for another client to adopt, and whichever client cleans up later destroys it with
+    /// `KillTarget::AllTemporary`. The mode only matters when the connection closes, so it can be set at
+    /// any time before that. Switching to either retain mode stops the display from tracking resources, so
+    /// that they aren't freed when it's dropped.
     #[inline]
     fn set_close_down_mode(&mut self, mode: CloseDown) -> crate::Result {
         self.exchange_request(SetCloseDownModeRequest {
@@ -557,6 +1243,121 @@ pub trait DisplayXprotoExt: Display {
         })
     }
 
+    /// Send a request that does nothing. This is useful for padding out the request stream, or as a request
+    /// with no side effects.
+    #[inline]
+    fn no_operation(&mut self) -> crate::Result {
+        self.exchange_request(NoOperationRequest::default())
+    }
+
+    /// Send a request that does nothing, followed by `padding` bytes that the server ignores. This can be
+    /// used to test how large requests are handled.
+    #[inline]
+    fn no_operation_padded(&mut self, padding: usize) -> crate::Result {
+        self.exchange_request(PaddedNoOperationRequest {
+            padding,
+            ..Default::default()
+        })
+    }
+
+    /// Forcibly close the connection of the client that created a resource, or destroy the resources
+    /// left behind by clients that used a `RetainTemporary` close-down mode. What happens to the killed
+    /// client's resources depends on the close-down mode it set.
+    #[inline]
+    fn kill_client(&mut self, target: KillTarget) -> crate::Result {
+        self.exchange_request(kill_client_request(target))
+    }
+
+    /// Add a window created by another client to this client's save-set, or remove it from the save-set.
+    ///
+    /// When this client's connection closes, every window in its save-set that is an inferior of one of
+    /// its own windows is reparented to the nearest ancestor the client didn't create, and every one
+    /// that's unmapped is mapped. Window managers that reparent client windows into decoration frames
+    /// should add each client window to the save-set before reparenting it, and remove it once it's
+    /// been reparented back out of the frame. A reparented window that isn't in the save-set is
+    /// destroyed along with its frame if the window manager crashes, leaving the client without its
+    /// window, while a saved window that was never reparented is only mapped again.
+    #[inline]
+    fn change_save_set(&mut self, window: Window, mode: SetMode) -> crate::Result {
+        self.exchange_request(window.change_save_set_request(mode))
+    }
+
+    /// Move `window` so that it becomes a child of `parent`, with its top left corner, outside of its
+    /// border, at `x` and `y` relative to the parent's origin. If the window is mapped, it's unmapped before
+    /// it's moved and mapped again afterwards.
+    #[inline]
+    fn reparent_window(&mut self, window: Window, parent: Window, x: i16, y: i16) -> crate::Result {
+        self.exchange_request(reparent_window_request(window, parent, x, y))
+    }
+
+    /// Put a client's top-level window into a new frame window, the way a window manager does when it
+    /// starts managing the window, and return the frame. The frame is created where the client's window is,
+    /// big enough to hold it and its border, with the attributes in `frame_attrs`. The client's window is
+    /// added to the save-set, so that it survives if this client goes away, and is then reparented into the
+    /// top left corner of the frame. Finally, both windows are mapped.
+    ///
+    /// The server is grabbed while this happens, so that the client can't move or destroy its window
+    /// partway through. If any step fails, the frame is destroyed again before the error is returned. On an
+    /// unchecked display, none of the steps after fetching the window's geometry wait on the server, and
+    /// errors from them turn up later.
+    #[inline]
+    fn frame_window(
+        &mut self,
+        client: Window,
+        frame_attrs: WindowParameters,
+    ) -> crate::Result<Window> {
+        let mut grab = self.grab_server()?;
+        let geometry = client.geometry_immediate(&mut *grab)?;
+        let (width, height) = frame_size(geometry.width, geometry.height, geometry.border_width);
+        let frame = grab.create_window(
+            geometry.root,
+            WindowClass::InputOutput,
+            None,
+            None,
+            geometry.x,
+            geometry.y,
+            width,
+            height,
+            0,
+            frame_attrs,
+        )?;
+
+        let res = grab
+            .change_save_set(client, SetMode::Insert)
+            .and_then(|()| grab.reparent_window(client, frame, 0, 0))
+            .and_then(|()| client.map(&mut *grab))
+            .and_then(|()| frame.map(&mut *grab));
+        if let Err(e) = res {
+            if let Err(e) = frame.free(&mut *grab) {
+                log::error!("Failed to destroy frame window: {}", e);
+            }
+            return Err(e);
+        }
+
+        grab.ungrab()?;
+        Ok(frame)
+    }
+
+    /// Rotate the values of the properties named by `atoms` on `window`. The value of each property moves
+    /// `delta` places along the list, wrapping around at the end, so with a positive `delta` the value of
+    /// `atoms[i]` becomes the value of `atoms[i + delta]`, and with a negative one it moves back towards the
+    /// start of the list instead. Every property in the list must exist on the window and have a value.
+    ///
+    /// This is how the eight `CUT_BUFFER` properties on the root window are cycled as a ring of cut buffers.
+    /// If `atoms` names a property more than once, an error is returned without sending anything.
+    #[inline]
+    fn rotate_properties(&mut self, window: Window, delta: i16, atoms: &[Atom]) -> crate::Result {
+        self.exchange_request(rotate_properties_request(window, delta, atoms)?)
+    }
+
+    /// Read a property of a window in chunks of `PROPERTY_CHUNK_LENGTH` 32-bit units, sending a new
+    /// `GetProperty` request for each chunk. This is meant for properties too large to comfortably read in a
+    /// single request, like big icons or clipboard images.
+    #[inline]
+    fn get_property_stream(&mut self, window: Window, property: Atom) -> PropertyStream<'_, Self> {
+        PropertyStream::new(self, window, property)
+    }
+
     #[inline]
     fn change_pointer_control(
         &mut self,
@@ -605,10 +1406,22 @@ pub trait DisplayXprotoExt: Display {
         })
     }
 
-    /// Send an event to the X server.
+    /// Send an event to the `destination` window, on behalf of the X server. The event is delivered to
+    /// clients selecting any of the events in `mask`; if `mask` is empty, it is delivered to the client
+    /// that created the window. If `propagate` is true and no client selected the event on `destination`,
+    /// the event propagates up the window hierarchy as a device event would.
+    ///
+    /// The event is serialized into the 32-byte body that `SendEvent` expects. The server marks the
+    /// event as synthetic when it delivers it.
     #[inline]
-    fn send_event(&mut self, target: Window, mask: EventMask, event: Event) -> crate::Result {
-        self.exchange_request(send_event_request(target, mask, event))
+    fn send_event(
+        &mut self,
+        propagate: bool,
+        destination: Window,
+        mask: EventMask,
+        event: Event,
+    ) -> crate::Result {
+        self.exchange_request(send_event_request(propagate, destination, mask, event))
     }
 
     /// Create a new colormap.
@@ -624,25 +1437,35 @@ pub trait DisplayXprotoExt: Display {
         Ok(cid)
     }
 
-    /// Get the keyboard mapping for this display.
+    /// Look up the RGB values for a batch of pixels in a colormap, using one request. Each pixel is returned
+    /// alongside its color.
     #[inline]
-    fn get_keyboard_mapping(&mut self) -> crate::Result<RequestCookie<GetKeyboardMappingRequest>> {
-        let min_keycode = self.setup().min_keycode;
-        let max_keycode = self.setup().max_keycode;
+    fn query_colors(&mut self, cmap: Colormap, pixels: &[u32]) -> crate::Result<Vec<(u32, Rgb)>> {
+        let repl = self.exchange_request(query_colors_request(cmap, pixels.to_vec()))?;
+        Ok(zip_query_colors_reply(pixels, repl))
+    }
 
-        self.send_request(GetKeyboardMappingRequest {
-            first_keycode: min_keycode,
-            count: max_keycode - min_keycode,
-            ..Default::default()
-        })
+    /// Get the keysyms bound to `count` keycodes, starting at `first_keycode`. The range must lie within the
+    /// `min_keycode` and `max_keycode` of the display's setup; `Setup::keycode_range` covers all of it.
+    #[inline]
+    fn get_keyboard_mapping(
+        &mut self,
+        first_keycode: Keycode,
+        count: u8,
+    ) -> crate::Result<RequestCookie<GetKeyboardMappingRequest>> {
+        self.send_request(keyboard_mapping_request(first_keycode, count))
     }
 
-    /// Immediately get the keyboard mapping for this display.
+    /// Immediately get the keysyms bound to `count` keycodes, starting at `first_keycode`.
     #[inline]
-    fn get_keyboard_mapping_immediate(&mut self) -> crate::Result<KeyboardMapping> {
-        let tok = self.get_keyboard_mapping()?;
+    fn get_keyboard_mapping_immediate(
+        &mut self,
+        first_keycode: Keycode,
+        count: u8,
+    ) -> crate::Result<KeyboardMapping> {
+        let tok = self.get_keyboard_mapping(first_keycode, count)?;
         let repl = self.resolve_request(tok)?;
-        Ok(repl.into())
+        Ok(KeyboardMapping::from_reply(first_keycode, repl))
     }
 
     /// Get the modifier mapping for this display.
@@ -658,6 +1481,34 @@ pub trait DisplayXprotoExt: Display {
         let repl = self.resolve_request(tok)?;
         Ok(repl.into())
     }
+
+    /// Get the pointer mapping for this display. Element `i` of the map is the logical button that
+    /// physical button `i + 1` reports as.
+    #[inline]
+    fn get_pointer_mapping(&mut self) -> crate::Result<RequestCookie<GetPointerMappingRequest>> {
+        self.send_request(GetPointerMappingRequest::default())
+    }
+
+    /// Immediately get the pointer mapping for this display.
+    #[inline]
+    fn get_pointer_mapping_immediate(&mut self) -> crate::Result<Vec<u8>> {
+        let tok = self.get_pointer_mapping()?;
+        let repl = self.resolve_request(tok)?;
+        Ok(repl.map)
+    }
+
+    /// Set the pointer mapping for this display. The map must have as many elements as the one returned by
+    /// `get_pointer_mapping`, and a logical button cannot be mapped to more than once, except for zero,
+    /// which disables the physical button. If any of the buttons being changed are currently held down,
+    /// the mapping is left alone and `MappingStatus::Busy` is returned.
+    #[inline]
+    fn set_pointer_mapping(&mut self, map: &[u8]) -> crate::Result<MappingStatus> {
+        let repl = self.exchange_request(SetPointerMappingRequest {
+            map: map.to_vec(),
+            ..Default::default()
+        })?;
+        Ok(repl.status)
+    }
 }
 
 impl<D: Display + ?Sized> DisplayXprotoExt for D {}
@@ -695,6 +1546,20 @@ pub trait AsyncDisplayXprotoExt: AsyncDisplay {
         )
     }
 
+    /// List the names of every extension the server supports redox.
+    #[inline]
+    fn list_extensions_async(
+        &mut self,
+    ) -> MapFuture<
+        ExchangeRequestFuture<'_, Self, ListExtensionsRequest>,
+        fn(crate::Result<ListExtensionsReply>) -> crate::Result<Vec<String>>,
+    > {
+        MapFuture::run(
+            self.exchange_request_async(ListExtensionsRequest::default()),
+            |repl| repl.map(|repl| str_list(repl.names)),
+        )
+    }
+
     /// Create a new window redox.
     #[inline]
     fn create_window_async(
@@ -736,6 +1601,13 @@ pub trait AsyncDisplayXprotoExt: AsyncDisplay {
         }))
     }
 
+    /// Start building a child window of `parent`, which is created once `build_async` is called on the
+    /// builder.
+    #[inline]
+    fn create_window_builder_async(&mut self, parent: Window) -> WindowBuilder<'_, Self> {
+        WindowBuilder::new(self, parent)
+    }
+
     /// Create a window, but assume some parameters from its parents redox.
     #[inline]
     fn create_simple_window_async(
@@ -793,7 +1665,8 @@ pub trait AsyncDisplayXprotoExt: AsyncDisplay {
         }))
     }
 
-    /// Intern a string and get a corresponding atom for that string redox.
+    /// Intern a string and get a corresponding atom for that string redox. This only sends the request;
+    /// `intern_atom_immediate_async` both sends it and waits for the atom.
     #[inline]
     fn intern_atom_async(
         &mut self,
@@ -803,7 +1676,8 @@ pub trait AsyncDisplayXprotoExt: AsyncDisplay {
         self.send_request_async(intern_atom_request(name, only_if_exists))
     }
 
-    /// Intern an atom, but try to resolve the request immediately redox.
+    /// Intern an atom, but try to resolve the request immediately redox. The returned future sends the
+    /// request and waits for its reply, resolving directly to the atom.
     #[inline]
     fn intern_atom_immediate_async(
         &mut self,
@@ -819,6 +1693,25 @@ pub trait AsyncDisplayXprotoExt: AsyncDisplay {
         )
     }
 
+    /// Get the attributes of a window, async redox. The returned future sends the request and waits for its
+    /// reply, resolving directly to the attributes.
+    #[inline]
+    fn get_window_attributes_immediate_async(
+        &mut self,
+        window: Window,
+    ) -> MapFuture<
+        ExchangeRequestFuture<'_, Self, GetWindowAttributesRequest>,
+        fn(crate::Result<GetWindowAttributesReply>) -> crate::Result<WindowAttributes>,
+    > {
+        MapFuture::run(
+            self.exchange_request_async(GetWindowAttributesRequest {
+                window,
+                ..Default::default()
+            }),
+            |repl| repl.map(WindowAttributes::from),
+        )
+    }
+
     /// Change the keyboard's control properties redox.
     #[inline]
     fn change_keyboard_control_async(
@@ -829,6 +1722,33 @@ pub trait AsyncDisplayXprotoExt: AsyncDisplay {
         self.exchange_request_async(ckcr)
     }
 
+    /// Change the keysyms of `keycode_count` keycodes, starting at `first_keycode`, async redox.
+    #[inline]
+    fn change_keyboard_mapping_async(
+        &mut self,
+        first_keycode: Keycode,
+        keycode_count: u8,
+        keysyms_per_keycode: u8,
+        keysyms: &[Keysym],
+    ) -> EitherFuture<
+        Ready<crate::Result>,
+        ExchangeRequestFuture<'_, Self, ChangeKeyboardMappingRequest>,
+    > {
+        match change_keyboard_mapping_request(
+            first_keycode,
+            keycode_count,
+            keysyms_per_keycode,
+            keysyms,
+        ) {
+            Ok(ckmr) => EitherFuture::Right {
+                future: self.exchange_request_async(ckmr),
+            },
+            Err(e) => EitherFuture::Left {
+                future: future::ready(Err(e)),
+            },
+        }
+    }
+
     #[inline]
     fn bell_async(&mut self, percent: i8) -> ExchangeRequestFuture<'_, Self, BellRequest> {
         self.exchange_request_async(BellRequest {
@@ -837,6 +1757,7 @@ pub trait AsyncDisplayXprotoExt: AsyncDisplay {
         })
     }
 
+    /// Enable or disable checking connecting clients against the host access list, async redox.
     #[inline]
     fn set_access_control_async(
         &mut self,
@@ -848,6 +1769,24 @@ pub trait AsyncDisplayXprotoExt: AsyncDisplay {
         })
     }
 
+    /// Get the host access list, async redox.
+    #[inline]
+    fn list_hosts_async(&mut self) -> ExchangeRequestFuture<'_, Self, HostListRequest> {
+        self.exchange_request_async(HostListRequest::default())
+    }
+
+    /// Add a host to the access list, or remove one from it, async redox.
+    #[inline]
+    fn change_hosts_async(
+        &mut self,
+        mode: HostMode,
+        family: Family,
+        address: &[u8],
+    ) -> ExchangeRequestFuture<'_, Self, ChangeHostsRequest> {
+        self.exchange_request_async(change_hosts_request(mode, family, address))
+    }
+
+    /// Change the parameters of the active pointer grab, async redox.
     #[inline]
     fn change_active_pointer_grab_async(
         &mut self,
@@ -855,9 +1794,127 @@ pub trait AsyncDisplayXprotoExt: AsyncDisplay {
         cursor: Cursor,
         time: Option<Timestamp>,
     ) -> ExchangeRequestFuture<'_, Self, ChangeActivePointerGrabRequest> {
+        let time = time_or_last_event(self, time);
         self.exchange_request_async(change_active_pointer_grab_request(event_mask, cursor, time))
     }
 
+    /// Release the pointer grab held by this client, async redox.
+    #[inline]
+    fn ungrab_pointer_async(
+        &mut self,
+        time: Option<Timestamp>,
+    ) -> ExchangeRequestFuture<'_, Self, UngrabPointerRequest> {
+        let time = time_or_last_event(self, time);
+        self.exchange_request_async(UngrabPointerRequest {
+            time,
+            ..Default::default()
+        })
+    }
+
+    /// Release the keyboard grab held by this client, async redox.
+    #[inline]
+    fn ungrab_keyboard_async(
+        &mut self,
+        time: Option<Timestamp>,
+    ) -> ExchangeRequestFuture<'_, Self, UngrabKeyboardRequest> {
+        let time = time_or_last_event(self, time);
+        self.exchange_request_async(UngrabKeyboardRequest {
+            time,
+            ..Default::default()
+        })
+    }
+
+    /// Register passive grabs for `key` with `modifiers` held, whatever the state of the locks, async
+    /// redox.
+    #[inline]
+    fn grab_key_all_locks_async(
+        &mut self,
+        grab_window: Window,
+        key: Keycode,
+        modifiers: ModMask,
+    ) -> GrabKeysFuture<'_> {
+        let reqs = grab_key_all_locks_requests(grab_window, key, modifiers, ModMask::TWO);
+        Box::pin(grab_keys_async(self, reqs))
+    }
+
+    /// Release events that are frozen by a grab, async redox.
+    #[inline]
+    fn allow_events_async(
+        &mut self,
+        mode: Allow,
+        time: Option<Timestamp>,
+    ) -> ExchangeRequestFuture<'_, Self, AllowEventsRequest> {
+        let time = time_or_last_event(self, time);
+        self.exchange_request_async(AllowEventsRequest {
+            mode,
+            time,
+            ..Default::default()
+        })
+    }
+
+    /// Get the window that currently has the input focus, async redox.
+    #[inline]
+    fn get_input_focus_async(
+        &mut self,
+    ) -> MapFuture<
+        ExchangeRequestFuture<'_, Self, GetInputFocusRequest>,
+        fn(crate::Result<GetInputFocusReply>) -> crate::Result<(FocusTarget, RevertTo)>,
+    > {
+        MapFuture::run(
+            self.exchange_request_async(GetInputFocusRequest::default()),
+            |repl| repl.map(input_focus_from_reply),
+        )
+    }
+
+    /// Get which keys on the keyboard are currently held down, async redox.
+    #[inline]
+    fn query_keymap_async(
+        &mut self,
+    ) -> MapFuture<
+        ExchangeRequestFuture<'_, Self, QueryKeymapRequest>,
+        fn(crate::Result<QueryKeymapReply>) -> crate::Result<PressedKeys>,
+    > {
+        MapFuture::run(
+            self.exchange_request_async(QueryKeymapRequest::default()),
+            |repl| repl.map(PressedKeys::from),
+        )
+    }
+
+    /// Set the input focus, async redox.
+    #[inline]
+    fn set_input_focus_async(
+        &mut self,
+        focus: FocusTarget,
+        revert_to: RevertTo,
+        time: Option<Timestamp>,
+    ) -> ExchangeRequestFuture<'_, Self, SetInputFocusRequest> {
+        let time = time_or_last_event(self, time);
+        self.exchange_request_async(set_input_focus_request(focus, revert_to, time))
+    }
+
+    /// Get the pointer positions the server recorded between two times, async redox.
+    #[inline]
+    fn get_motion_events_async(
+        &mut self,
+        window: Window,
+        start: Timestamp,
+        stop: Timestamp,
+    ) -> MapFuture<
+        ExchangeRequestFuture<'_, Self, GetMotionEventsRequest>,
+        fn(crate::Result<GetMotionEventsReply>) -> crate::Result<Vec<Timecoord>>,
+    > {
+        MapFuture::run(
+            self.exchange_request_async(GetMotionEventsRequest {
+                window,
+                start,
+                stop,
+                ..Default::default()
+            }),
+            |repl| repl.map(|repl| repl.events),
+        )
+    }
+
+    /// Set what happens to the resources this client created once its connection closes, async redox.
     #[inline]
     fn set_close_down_mode_async(
         &mut self,
@@ -869,6 +1926,85 @@ pub trait AsyncDisplayXprotoExt: AsyncDisplay {
         })
     }
 
+    /// Send a request that does nothing, async redox.
+    #[inline]
+    fn no_operation_async(&mut self) -> ExchangeRequestFuture<'_, Self, NoOperationRequest> {
+        self.exchange_request_async(NoOperationRequest::default())
+    }
+
+    /// Send a request that does nothing, followed by `padding` bytes, async redox.
+    #[inline]
+    fn no_operation_padded_async(
+        &mut self,
+        padding: usize,
+    ) -> ExchangeRequestFuture<'_, Self, PaddedNoOperationRequest> {
+        self.exchange_request_async(PaddedNoOperationRequest {
+            padding,
+            ..Default::default()
+        })
+    }
+
+    /// Forcibly close the connection of the client that created a resource, async redox.
+    #[inline]
+    fn kill_client_async(
+        &mut self,
+        target: KillTarget,
+    ) -> ExchangeRequestFuture<'_, Self, KillClientRequest> {
+        self.exchange_request_async(kill_client_request(target))
+    }
+
+    /// Add a window to or remove it from this client's save-set, async redox.
+    #[inline]
+    fn change_save_set_async(
+        &mut self,
+        window: Window,
+        mode: SetMode,
+    ) -> ExchangeRequestFuture<'_, Self, ChangeSaveSetRequest> {
+        self.exchange_request_async(window.change_save_set_request(mode))
+    }
+
+    /// Move `window` so that it becomes a child of `parent`, async redox.
+    #[inline]
+    fn reparent_window_async(
+        &mut self,
+        window: Window,
+        parent: Window,
+        x: i16,
+        y: i16,
+    ) -> ExchangeRequestFuture<'_, Self, ReparentWindowRequest> {
+        self.exchange_request_async(reparent_window_request(window, parent, x, y))
+    }
+
+    /// Rotate the values of the properties named by `atoms` on `window`, async redox.
+    #[inline]
+    fn rotate_properties_async(
+        &mut self,
+        window: Window,
+        delta: i16,
+        atoms: &[Atom],
+    ) -> EitherFuture<Ready<crate::Result>, ExchangeRequestFuture<'_, Self, RotatePropertiesRequest>>
+    {
+        match rotate_properties_request(window, delta, atoms) {
+            Ok(rpr) => EitherFuture::Right {
+                future: self.exchange_request_async(rpr),
+            },
+            Err(e) => EitherFuture::Left {
+                future: future::ready(Err(e)),
+            },
+        }
+    }
+
+    /// Read a property of a window in chunks, async redox. Use `PropertyStream::next_async` to get each
+    /// chunk.
+    #[inline]
+    fn get_property_stream_async(
+        &mut self,
+        window: Window,
+        property: Atom,
+    ) -> PropertyStream<'_, Self> {
+        PropertyStream::new(self, window, property)
+    }
+
     #[inline]
     fn change_pointer_control_async(
         &mut self,
@@ -938,15 +2074,16 @@ pub trait AsyncDisplayXprotoExt: AsyncDisplay {
         })
     }
 
-    /// Send an event to the X server redox.
+    /// Send an event to the `destination` window, on behalf of the X server, redox.
     #[inline]
     fn send_event_async(
         &mut self,
-        target: Window,
+        propagate: bool,
+        destination: Window,
         mask: EventMask,
         event: Event,
     ) -> ExchangeRequestFuture<'_, Self, SendEventRequest> {
-        self.exchange_request_async(send_event_request(target, mask, event))
+        self.exchange_request_async(send_event_request(propagate, destination, mask, event))
     }
 
     /// Create a new colormap redox.
@@ -970,34 +2107,47 @@ pub trait AsyncDisplayXprotoExt: AsyncDisplay {
         }))
     }
 
-    /// Get the keyboard mapping for this display redox.
+    /// Look up the RGB values for a batch of pixels in a colormap redox.
+    #[inline]
+    fn query_colors_async(
+        &mut self,
+        cmap: Colormap,
+        pixels: &[u32],
+    ) -> MapFuture<
+        ExchangeRequestFuture<'_, Self, QueryColorsRequest>,
+        BoxedFnOnce<crate::Result<QueryColorsReply>, crate::Result<Vec<(u32, Rgb)>>>,
+    > {
+        let pixels = pixels.to_vec();
+        MapFuture::run(
+            self.exchange_request_async(query_colors_request(cmap, pixels.clone())),
+            Box::new(move |repl| repl.map(|repl| zip_query_colors_reply(&pixels, repl))),
+        )
+    }
+
+    /// Get the keysyms bound to `count` keycodes, starting at `first_keycode`, async redox.
     #[inline]
     fn get_keyboard_mapping_async(
         &mut self,
+        first_keycode: Keycode,
+        count: u8,
     ) -> SendRequestFuture<'_, Self, GetKeyboardMappingRequest> {
-        let min_keycode = self.setup().min_keycode;
-        let max_keycode = self.setup().max_keycode;
-
-        self.send_request_async(GetKeyboardMappingRequest {
-            first_keycode: min_keycode,
-            count: max_keycode - min_keycode,
-            ..Default::default()
-        })
+        self.send_request_async(keyboard_mapping_request(first_keycode, count))
     }
 
-    /// Immediately get the keyboard mapping for this display redox.
+    /// Immediately get the keysyms bound to `count` keycodes, starting at `first_keycode`, async redox.
     #[inline]
     fn get_keyboard_mapping_immediate_async(
         &mut self,
-    ) -> ExchangeRequestFuture<'_, Self, GetKeyboardMappingRequest> {
-        let min_keycode = self.setup().min_keycode;
-        let max_keycode = self.setup().max_keycode;
-
-        self.exchange_request_async(GetKeyboardMappingRequest {
-            first_keycode: min_keycode,
-            count: max_keycode - min_keycode,
-            ..Default::default()
-        })
+        first_keycode: Keycode,
+        count: u8,
+    ) -> MapFuture<
+        ExchangeRequestFuture<'_, Self, GetKeyboardMappingRequest>,
+        BoxedFnOnce<crate::Result<GetKeyboardMappingReply>, crate::Result<KeyboardMapping>>,
+    > {
+        MapFuture::run(
+            self.exchange_request_async(keyboard_mapping_request(first_keycode, count)),
+            Box::new(move |repl| repl.map(|repl| KeyboardMapping::from_reply(first_keycode, repl))),
+        )
     }
 
     /// Get the modifier mapping for this display redox.
@@ -1021,7 +2171,381 @@ pub trait AsyncDisplayXprotoExt: AsyncDisplay {
             |repl| repl.map(ModifierMapping::from),
         )
     }
+
+    /// Get the pointer mapping for this display redox.
+    #[inline]
+    fn get_pointer_mapping_async(
+        &mut self,
+    ) -> SendRequestFuture<'_, Self, GetPointerMappingRequest> {
+        self.send_request_async(GetPointerMappingRequest::default())
+    }
+
+    /// Immediately get the pointer mapping for this display redox.
+    #[inline]
+    fn get_pointer_mapping_immediate_async(
+        &mut self,
+    ) -> MapFuture<
+        ExchangeRequestFuture<'_, Self, GetPointerMappingRequest>,
+        fn(crate::Result<GetPointerMappingReply>) -> crate::Result<Vec<u8>>,
+    > {
+        MapFuture::run(
+            self.exchange_request_async(GetPointerMappingRequest::default()),
+            |repl| repl.map(|repl| repl.map),
+        )
+    }
+
+    /// Set the pointer mapping for this display redox.
+    #[inline]
+    fn set_pointer_mapping_async(
+        &mut self,
+        map: &[u8],
+    ) -> MapFuture<
+        ExchangeRequestFuture<'_, Self, SetPointerMappingRequest>,
+        fn(crate::Result<SetPointerMappingReply>) -> crate::Result<MappingStatus>,
+    > {
+        MapFuture::run(
+            self.exchange_request_async(SetPointerMappingRequest {
+                map: map.to_vec(),
+                ..Default::default()
+            }),
+            |repl| repl.map(|repl| repl.status),
+        )
+    }
 }
 
 #[cfg(feature = "async")]
 impl<D: AsyncDisplay + ?Sized> AsyncDisplayXprotoExt for D {}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        change_keyboard_mapping_request, rotate_properties_request, KeyboardMapping, PressedKeys,
+    };
+    use crate::auto::{
+        xproto::{
+            Atom, GetKeyboardMappingReply, Setup, Window, ATOM_CUT_BUFFER0, ATOM_CUT_BUFFER1,
+            ATOM_CUT_BUFFER2,
+        },
+        AsByteSequence,
+    };
+    use crate::display::{mock_display, mock_setup, DisplayBase, MockConnection};
+    use crate::XidType;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn keyboard_mapping_rows() {
+        let reply = GetKeyboardMappingReply {
+            keysyms_per_keycode: 2,
+            keysyms: alloc::vec![0x61, 0x41, 0x62, 0x42, 0x63, 0],
+            ..Default::default()
+        };
+        let mapping = KeyboardMapping::from_reply(10, reply);
+        assert_eq!(mapping.keysyms(10), &[0x61, 0x41]);
+        assert_eq!(mapping.keysyms(12), &[0x63, 0]);
+        // keycodes on either side of the mapping have no keysyms
+        assert!(mapping.keysyms(9).is_empty());
+        assert!(mapping.keysyms(13).is_empty());
+
+        let rows: Vec<_> = mapping.rows().map(|(keycode, _)| keycode).collect();
+        assert_eq!(rows, [10, 11, 12]);
+    }
+
+    #[test]
+    fn keyboard_mapping_count_mismatch() {
+        // two keycodes with three keysyms each need six keysyms
+        assert!(change_keyboard_mapping_request(10, 2, 3, &[1, 2, 3, 4, 5, 6]).is_ok());
+        assert!(change_keyboard_mapping_request(10, 2, 3, &[1, 2, 3, 4, 5]).is_err());
+        assert!(change_keyboard_mapping_request(10, 2, 3, &[1, 2, 3, 4, 5, 6, 7]).is_err());
+    }
+
+    #[test]
+    fn keymap_bits_are_indexed_by_keycode() {
+        let mut keys = [0; 32];
+        keys[1] = 0b0000_0100;
+        keys[31] = 0b1000_0000;
+        let keymap = PressedKeys { keys };
+
+        assert!(keymap.is_pressed(10));
+        assert!(!keymap.is_pressed(9));
+        assert!(keymap.is_pressed(255));
+        assert_eq!(keymap.keycodes().collect::<Vec<_>>(), [10, 255]);
+    }
+
+    #[test]
+    fn rotated_properties_are_counted_and_unique() {
+        let atoms = [ATOM_CUT_BUFFER0, ATOM_CUT_BUFFER1, ATOM_CUT_BUFFER2];
+        let req = rotate_properties_request(Window::const_from_xid(1), -1, &atoms).unwrap();
+        let mut bytes = [0; 24];
+        assert_eq!(req.as_bytes(&mut bytes), 24);
+
+        // the number of atoms comes before the delta
+        assert_eq!(&bytes[8..10], &3u16.to_ne_bytes());
+        assert_eq!(&bytes[10..12], &(-1i16).to_ne_bytes());
+        assert_eq!(&bytes[20..24], &ATOM_CUT_BUFFER2.xid().to_ne_bytes());
+
+        let repeated = [ATOM_CUT_BUFFER0, Atom::const_from_xid(9)];
+        assert!(rotate_properties_request(Window::const_from_xid(1), 1, &repeated).is_err());
+    }
+
+    #[test]
+    fn padded_no_operation() {
+        use crate::display::prelude::DisplayXprotoExt;
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        let mut display = mock_display(&mut conn);
+        display.set_checked(false);
+        display.no_operation_padded(6).unwrap();
+        drop(display);
+
+        // six bytes of padding are rounded up to eight
+        let written = conn.take_outgoing();
+        let len = 3u16.to_ne_bytes();
+        assert_eq!(
+            &written[written.len() - 12..],
+            &[127, 0, len[0], len[1], 0, 0, 0, 0, 0, 0, 0, 0][..]
+        );
+    }
+
+    #[test]
+    fn property_stream_advances_offset() {
+        use crate::{
+            auto::xproto::{Atom, GetPropertyReply, Window},
+            display::{prelude::DisplayXprotoExt, PROPERTY_CHUNK_LENGTH},
+        };
+        use alloc::{vec, vec::Vec};
+
+        let window = Window::const_from_xid(0x0200_0001);
+        let icon = Atom::const_from_xid(301);
+        let cardinal = Atom::const_from_xid(6);
+        let first_len = PROPERTY_CHUNK_LENGTH as usize * 4;
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_reply(&GetPropertyReply {
+            reply_type: 1,
+            format: 32,
+            sequence: 2,
+            length: PROPERTY_CHUNK_LENGTH,
+            ty: cardinal,
+            bytes_after: 8,
+            value_len: PROPERTY_CHUNK_LENGTH,
+            value: vec![0xAB; first_len],
+        });
+        conn.feed_reply(&GetPropertyReply {
+            reply_type: 1,
+            format: 32,
+            sequence: 3,
+            length: 2,
+            ty: cardinal,
+            bytes_after: 0,
+            value_len: 2,
+            value: vec![0xCD; 8],
+        });
+
+        let mut display = mock_display(&mut conn);
+        display.set_checked(false);
+        let chunks = display
+            .get_property_stream(window, icon)
+            .map(|chunk| chunk.unwrap().value.len())
+            .collect::<Vec<_>>();
+        drop(display);
+        assert_eq!(chunks, vec![first_len, 8]);
+
+        // both requests are 24 bytes long; the second starts where the first chunk ended
+        let written = conn.take_outgoing();
+        let requests = &written[written.len() - 48..];
+        assert_eq!(requests[0], 20);
+        assert_eq!(requests[24], 20);
+        let offset = |i: usize| {
+            u32::from_ne_bytes([
+                requests[i],
+                requests[i + 1],
+                requests[i + 2],
+                requests[i + 3],
+            ])
+        };
+        assert_eq!(offset(16), 0);
+        assert_eq!(offset(40), PROPERTY_CHUNK_LENGTH);
+        assert_eq!(offset(44), PROPERTY_CHUNK_LENGTH);
+    }
+
+    #[test]
+    fn motion_events_are_read() {
+        use crate::{
+            auto::xproto::{GetMotionEventsReply, Timecoord, Window},
+            prelude::DisplayXprotoExt,
+        };
+        use alloc::vec;
+
+        let events = vec![
+            Timecoord {
+                time: 100,
+                x: 1,
+                y: -2,
+            },
+            Timecoord {
+                time: 110,
+                x: 3,
+                y: 4,
+            },
+        ];
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        // each record is two units long
+        conn.feed_reply(&GetMotionEventsReply {
+            reply_type: 1,
+            sequence: 2,
+            length: 4,
+            events: events.clone(),
+        });
+
+        let mut display = mock_display(&mut conn);
+        let history = display
+            .get_motion_events(Window::const_from_xid(1), 0, 0)
+            .unwrap();
+        assert_eq!(history, events);
+    }
+
+    #[test]
+    fn retained_resources_are_killed_as_temporary() {
+        use crate::{
+            auto::xproto::CloseDown,
+            display::traits::{DisplayXprotoExt, KillTarget},
+        };
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        let mut display = mock_display(&mut conn);
+        display.set_checked(false);
+        display
+            .set_close_down_mode(CloseDown::RetainTemporary)
+            .unwrap();
+        display.kill_client(KillTarget::AllTemporary).unwrap();
+        drop(display);
+
+        let written = conn.take_outgoing();
+        let requests = &written[written.len() - 12..];
+        // the mode goes in the byte after the opcode
+        assert_eq!(&requests[..4], &[112, 2, 1, 0]);
+        // and AllTemporary is resource zero
+        assert_eq!(requests[4], 113);
+        assert_eq!(&requests[8..], &[0, 0, 0, 0]);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn composed_futures_resolve_directly() {
+        use crate::{
+            auto::xproto::{
+                Atom, GetGeometryReply, GetWindowAttributesReply, InternAtomReply, MapState, Window,
+            },
+            display::prelude::*,
+        };
+        use alloc::string::String;
+        use futures_lite::future;
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_reply(&InternAtomReply {
+            reply_type: 1,
+            sequence: 2,
+            atom: Atom::const_from_xid(300),
+            ..Default::default()
+        });
+        conn.feed_reply(&GetGeometryReply {
+            reply_type: 1,
+            sequence: 3,
+            width: 640,
+            height: 480,
+            ..Default::default()
+        });
+        conn.feed_reply(&GetWindowAttributesReply {
+            reply_type: 1,
+            sequence: 4,
+            length: 3,
+            map_state: MapState::Viewable,
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        let window = Window::const_from_xid(0x0200_0001);
+        future::block_on(async {
+            let atom = display
+                .intern_atom_immediate_async(String::from("WM_STATE"), false)
+                .await
+                .unwrap();
+            assert_eq!(atom, Atom::const_from_xid(300));
+
+            let geometry = display
+                .get_drawable_geometry_immediate_async(window)
+                .await
+                .unwrap();
+            assert_eq!((geometry.width, geometry.height), (640, 480));
+
+            let attributes = display
+                .get_window_attributes_immediate_async(window)
+                .await
+                .unwrap();
+            assert_eq!(attributes.map_state, MapState::Viewable);
+        });
+    }
+
+    #[test]
+    fn frame_window_order() {
+        use crate::{
+            auto::xproto::{GetGeometryReply, Window},
+            display::traits::{DisplayXprotoExt, WindowParameters},
+        };
+
+        let setup = Setup {
+            resource_id_mask: 0xFF,
+            ..mock_setup()
+        };
+        let mut conn = MockConnection::with_setup(&setup);
+        conn.feed_reply(&GetGeometryReply {
+            reply_type: 1,
+            sequence: 3,
+            root: Window::const_from_xid(0x100),
+            x: 10,
+            y: 20,
+            width: 100,
+            height: 50,
+            border_width: 2,
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        display.set_checked(false);
+        let client = Window::const_from_xid(0x400);
+        let frame = display
+            .frame_window(client, WindowParameters::default())
+            .unwrap();
+        drop(display);
+
+        // GrabServer, GetGeometry, CreateWindow, ChangeSaveSet, ReparentWindow, MapWindow twice, UngrabServer
+        let out = conn.outgoing();
+        let mut grab = alloc::vec![36, 0];
+        grab.extend_from_slice(&1u16.to_ne_bytes());
+        let start = out.windows(4).rposition(|w| w == &grab[..]).unwrap();
+        let requests = &out[start..];
+        let mut opcodes = Vec::new();
+        let mut index = 0;
+        while index < requests.len() {
+            opcodes.push(requests[index]);
+            let len = u16::from_ne_bytes([requests[index + 2], requests[index + 3]]);
+            index += usize::from(len) * 4;
+        }
+        assert_eq!(opcodes, [36, 14, 1, 6, 7, 8, 8, 37]);
+
+        // the frame goes where the window was, with room for its border
+        let create = &requests[12..44];
+        assert_eq!(&create[4..8], &frame.xid.to_ne_bytes());
+        assert_eq!(&create[8..12], &0x100u32.to_ne_bytes());
+        assert_eq!(&create[12..14], &10i16.to_ne_bytes());
+        assert_eq!(&create[14..16], &20i16.to_ne_bytes());
+        assert_eq!(&create[16..18], &104u16.to_ne_bytes());
+        assert_eq!(&create[18..20], &54u16.to_ne_bytes());
+
+        // the window is reparented into the frame's corner
+        let reparent = &requests[52..68];
+        assert_eq!(&reparent[4..8], &0x400u32.to_ne_bytes());
+        assert_eq!(&reparent[8..12], &frame.xid.to_ne_bytes());
+        assert_eq!(&reparent[12..16], &[0; 4]);
+    }
+}
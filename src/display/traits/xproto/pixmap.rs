@@ -12,6 +12,7 @@ impl Pixmap {
     /// Free the memory used by a pixmap.
     #[inline]
     pub fn free<Dpy: Display + ?Sized>(self, dpy: &mut Dpy) -> crate::Result {
+        dpy.untrack_resource(self.xid);
         dpy.exchange_request(FreePixmapRequest {
             pixmap: self,
             ..Default::default()
@@ -25,6 +26,7 @@ impl Pixmap {
         self,
         dpy: &mut Dpy,
     ) -> ExchangeRequestFuture<'_, Dpy, FreePixmapRequest> {
+        dpy.untrack_resource(self.xid);
         dpy.exchange_request_async(FreePixmapRequest {
             pixmap: self,
             ..Default::default()
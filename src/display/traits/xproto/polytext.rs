@@ -0,0 +1,335 @@
+// MIT/Apache2 License
+
+//! This module contains functions for drawing text with the `PolyText8` and `PolyText16` requests, which
+//! draw a series of strings and font changes in one go, and with the `ImageText16` request.
+
+use crate::{
+    auto::{
+        xproto::{
+            Char2b, Drawable, Font, Gcontext, ImageText16Request, PolyText16Request,
+            PolyText8Request,
+        },
+        AsByteSequence,
+    },
+    display::prelude::*,
+    Display, XidType,
+};
+use alloc::vec::Vec;
+use core::{convert::TryFrom, num::TryFromIntError};
+
+#[cfg(feature = "async")]
+use crate::display::{AsyncDisplay, ExchangeRequestFuture};
+
+/// The length byte that marks a font change instead of a string.
+const FONT_SHIFT: u8 = 255;
+/// The most characters a single text item can hold.
+const MAX_ITEM_CHARS: usize = 254;
+
+/// The most characters a single `ImageText` request can hold.
+const MAX_IMAGE_TEXT_CHARS: usize = 255;
+
+// A CHAR2B is always sent as its first byte followed by its second, no matter the byte order of the
+// connection, so a 16-bit character goes over the wire most significant byte first.
+impl From<u16> for Char2b {
+    #[inline]
+    fn from(ch: u16) -> Self {
+        let [byte1, byte2] = ch.to_be_bytes();
+        Self { byte1, byte2 }
+    }
+}
+
+impl From<Char2b> for u16 {
+    #[inline]
+    fn from(ch: Char2b) -> Self {
+        u16::from_be_bytes([ch.byte1, ch.byte2])
+    }
+}
+
+/// Converts a character to its code point, which is how ISO 10646 fonts index their glyphs. This fails for
+/// characters outside of the Basic Multilingual Plane.
+impl TryFrom<char> for Char2b {
+    type Error = TryFromIntError;
+
+    #[inline]
+    fn try_from(ch: char) -> Result<Self, TryFromIntError> {
+        u16::try_from(u32::from(ch)).map(Self::from)
+    }
+}
+
+/// An item to be drawn by `poly_text8` or `poly_text16`. The characters are `u8`s for the former and
+/// `Char2b`s for the latter.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TextItem<'a, Ch = u8> {
+    /// Move `delta` pixels along the baseline, then draw a string. Strings too long to fit in one item are
+    /// split up automatically.
+    Text { delta: i8, string: &'a [Ch] },
+    /// Switch to another font for the items that follow. This also changes the font of the graphics
+    /// context.
+    Font(Font),
+}
+
+/// Serialize text items into the body of a `PolyText` request.
+#[inline]
+fn encode_text_items<Ch: AsByteSequence>(items: &[TextItem<'_, Ch>]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for item in items {
+        match *item {
+            TextItem::Text { delta, string } => {
+                // an empty item still moves along the baseline
+                if string.is_empty() {
+                    bytes.extend_from_slice(&[0, delta.to_ne_bytes()[0]]);
+                }
+
+                // only the first piece of a split-up string is moved by the delta
+                let mut delta = delta;
+                for chunk in string.chunks(MAX_ITEM_CHARS) {
+                    bytes.extend_from_slice(&[chunk.len() as u8, delta.to_ne_bytes()[0]]);
+                    for ch in chunk {
+                        let start = bytes.len();
+                        bytes.resize(start + ch.size(), 0);
+                        ch.as_bytes(&mut bytes[start..]);
+                    }
+                    delta = 0;
+                }
+            }
+            // the font is always sent most significant byte first, no matter the byte order
+            TextItem::Font(font) => {
+                bytes.push(FONT_SHIFT);
+                bytes.extend_from_slice(&font.xid().to_be_bytes());
+            }
+        }
+    }
+    bytes
+}
+
+impl Gcontext {
+    #[inline]
+    fn poly_text8_request(
+        self,
+        drawable: Drawable,
+        x: i16,
+        y: i16,
+        items: &[TextItem<'_, u8>],
+    ) -> PolyText8Request {
+        PolyText8Request {
+            drawable,
+            gc: self,
+            x,
+            y,
+            items: encode_text_items(items),
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    fn poly_text16_request(
+        self,
+        drawable: Drawable,
+        x: i16,
+        y: i16,
+        items: &[TextItem<'_, Char2b>],
+    ) -> PolyText16Request {
+        PolyText16Request {
+            drawable,
+            gc: self,
+            x,
+            y,
+            items: encode_text_items(items),
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    fn image_text16_request(
+        self,
+        drawable: Drawable,
+        x: i16,
+        y: i16,
+        string: &[Char2b],
+    ) -> ImageText16Request {
+        ImageText16Request {
+            drawable,
+            gc: self,
+            x,
+            y,
+            string: string.iter().take(MAX_IMAGE_TEXT_CHARS).cloned().collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Draw a series of strings of 8-bit characters, starting with the baseline at `(x, y)`. Each string
+    /// starts where the last one left off, plus its delta. Only the foreground of the text is drawn.
+    #[inline]
+    pub fn poly_text8<Dpy: Display + ?Sized, Target: Into<Drawable>>(
+        self,
+        dpy: &mut Dpy,
+        target: Target,
+        x: i16,
+        y: i16,
+        items: &[TextItem<'_, u8>],
+    ) -> crate::Result {
+        dpy.exchange_request(self.poly_text8_request(target.into(), x, y, items))
+    }
+
+    /// Draw a series of strings of 8-bit characters, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub fn poly_text8_async<'a, Dpy: AsyncDisplay + ?Sized, Target: Into<Drawable>>(
+        self,
+        dpy: &'a mut Dpy,
+        target: Target,
+        x: i16,
+        y: i16,
+        items: &[TextItem<'_, u8>],
+    ) -> ExchangeRequestFuture<'a, Dpy, PolyText8Request> {
+        dpy.exchange_request_async(self.poly_text8_request(target.into(), x, y, items))
+    }
+
+    /// Draw a series of strings of 16-bit characters, starting with the baseline at `(x, y)`. Each string
+    /// starts where the last one left off, plus its delta. Only the foreground of the text is drawn.
+    #[inline]
+    pub fn poly_text16<Dpy: Display + ?Sized, Target: Into<Drawable>>(
+        self,
+        dpy: &mut Dpy,
+        target: Target,
+        x: i16,
+        y: i16,
+        items: &[TextItem<'_, Char2b>],
+    ) -> crate::Result {
+        dpy.exchange_request(self.poly_text16_request(target.into(), x, y, items))
+    }
+
+    /// Draw a series of strings of 16-bit characters, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub fn poly_text16_async<'a, Dpy: AsyncDisplay + ?Sized, Target: Into<Drawable>>(
+        self,
+        dpy: &'a mut Dpy,
+        target: Target,
+        x: i16,
+        y: i16,
+        items: &[TextItem<'_, Char2b>],
+    ) -> ExchangeRequestFuture<'a, Dpy, PolyText16Request> {
+        dpy.exchange_request_async(self.poly_text16_request(target.into(), x, y, items))
+    }
+
+    /// Draw a string of 16-bit characters with its baseline starting at `(x, y)`, filling in the
+    /// background of each character's box as well as the foreground. A request can only carry 255
+    /// characters, so only the first 255 are drawn; longer strings have to be split up by the caller,
+    /// who knows how wide the text is.
+    #[inline]
+    pub fn image_text16<Dpy: Display + ?Sized, Target: Into<Drawable>>(
+        self,
+        dpy: &mut Dpy,
+        target: Target,
+        x: i16,
+        y: i16,
+        string: &[Char2b],
+    ) -> crate::Result {
+        dpy.exchange_request(self.image_text16_request(target.into(), x, y, string))
+    }
+
+    /// Draw a string of 16-bit characters along with its background, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub fn image_text16_async<'a, Dpy: AsyncDisplay + ?Sized, Target: Into<Drawable>>(
+        self,
+        dpy: &'a mut Dpy,
+        target: Target,
+        x: i16,
+        y: i16,
+        string: &[Char2b],
+    ) -> ExchangeRequestFuture<'a, Dpy, ImageText16Request> {
+        dpy.exchange_request_async(self.image_text16_request(target.into(), x, y, string))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_text_items, TextItem};
+    use crate::{
+        auto::xproto::{Char2b, Drawable, Font, Gcontext, ImageOrder, Setup},
+        display::{mock_display, mock_setup, DisplayBase, MockConnection},
+    };
+    use alloc::vec;
+    use core::convert::TryFrom;
+
+    #[test]
+    fn text_items_are_encoded() {
+        let items = [
+            TextItem::Text {
+                delta: -2,
+                string: b"hi",
+            },
+            TextItem::Font(Font::const_from_xid(0x0102_0304)),
+            TextItem::Text {
+                delta: 5,
+                string: b"",
+            },
+        ];
+        assert_eq!(
+            encode_text_items(&items),
+            vec![2, 0xFE, b'h', b'i', 255, 1, 2, 3, 4, 0, 5]
+        );
+
+        // long strings are split up, and only the first piece gets the delta
+        let long = [b'x'; 300];
+        let bytes = encode_text_items(&[TextItem::Text {
+            delta: 3,
+            string: &long,
+        }]);
+        assert_eq!(bytes.len(), 304);
+        assert_eq!(&bytes[..2], &[254, 3]);
+        assert_eq!(&bytes[256..258], &[46, 0]);
+
+        let wide = [Char2b {
+            byte1: 0x30,
+            byte2: 0x42,
+        }];
+        assert_eq!(
+            encode_text_items(&[TextItem::Text {
+                delta: 0,
+                string: &wide,
+            }]),
+            vec![1, 0, 0x30, 0x42]
+        );
+    }
+
+    #[test]
+    fn char2b_is_big_endian() {
+        assert_eq!(
+            Char2b::from(0x3042),
+            Char2b {
+                byte1: 0x30,
+                byte2: 0x42
+            }
+        );
+        assert_eq!(Char2b::try_from('\u{3042}'), Ok(Char2b::from(0x3042)));
+        assert!(Char2b::try_from('\u{1F600}').is_err());
+        assert_eq!(u16::from(Char2b::from(0xABCD)), 0xABCD);
+
+        // the characters keep their order on a little-endian connection
+        let setup = Setup {
+            image_byte_order: ImageOrder::LsbFirst,
+            ..mock_setup()
+        };
+        let mut conn = MockConnection::with_setup(&setup);
+        let mut dpy = mock_display(&mut conn);
+        dpy.set_checked(false);
+        Gcontext::const_from_xid(2)
+            .image_text16(
+                &mut dpy,
+                Drawable::const_from_xid(1),
+                0,
+                0,
+                &[Char2b::from(0x3042), Char2b::from(0x0041)],
+            )
+            .unwrap();
+        drop(dpy);
+
+        let sent = conn.take_outgoing();
+        let request = &sent[sent.len() - 20..];
+        assert_eq!(&request[..2], &[77, 2]);
+        assert_eq!(&request[16..], &[0x30, 0x42, 0x00, 0x41]);
+    }
+}
@@ -0,0 +1,114 @@
+// MIT/Apache2 License
+
+use crate::{
+    auto::xproto::{Atom, GetPropertyReply, GetPropertyRequest, Window},
+    display::{prelude::*, Display},
+};
+use core::iter::FusedIterator;
+
+#[cfg(feature = "async")]
+use crate::display::AsyncDisplay;
+
+/// The number of 32-bit units requested by each `GetProperty` request in a property stream.
+pub const PROPERTY_CHUNK_LENGTH: u32 = 16384;
+
+/// An iterator over the contents of a property, one chunk at a time. Each chunk is the reply to a
+/// `GetProperty` request for at most `PROPERTY_CHUNK_LENGTH` 32-bit units of the property, so very large
+/// properties never have to be held in memory all at once.
+///
+/// If the property does not exist, no chunks are produced. Once an error occurs, the stream ends.
+#[derive(Debug)]
+pub struct PropertyStream<'a, Dpy: ?Sized> {
+    display: &'a mut Dpy,
+    window: Window,
+    property: Atom,
+    long_offset: u32,
+    done: bool,
+}
+
+impl<'a, Dpy: ?Sized> PropertyStream<'a, Dpy> {
+    #[inline]
+    pub(crate) fn new(display: &'a mut Dpy, window: Window, property: Atom) -> Self {
+        Self {
+            display,
+            window,
+            property,
+            long_offset: 0,
+            done: false,
+        }
+    }
+
+    #[inline]
+    fn chunk_request(&self) -> GetPropertyRequest {
+        GetPropertyRequest {
+            window: self.window,
+            property: self.property,
+            long_offset: self.long_offset,
+            long_length: PROPERTY_CHUNK_LENGTH,
+            ..Default::default()
+        }
+    }
+
+    /// Advance past the chunk we just received, and figure out whether it should be yielded.
+    #[inline]
+    fn process_reply(
+        &mut self,
+        repl: crate::Result<GetPropertyReply>,
+    ) -> Option<crate::Result<GetPropertyReply>> {
+        let repl = match repl {
+            Ok(repl) => repl,
+            Err(e) => {
+                self.done = true;
+                return Some(Err(e));
+            }
+        };
+
+        // a type of None means that the property doesn't exist
+        if repl.ty.xid == 0 {
+            self.done = true;
+            return None;
+        }
+
+        // long_offset is measured in 32-bit units, and every chunk but the last is a whole number of them;
+        // an empty chunk with more to come would just loop forever, so treat it as the end
+        #[allow(clippy::cast_possible_truncation)]
+        let advance = (repl.value.len() / 4) as u32;
+        self.long_offset = self.long_offset.wrapping_add(advance);
+        if repl.bytes_after == 0 || advance == 0 {
+            self.done = true;
+        }
+
+        Some(Ok(repl))
+    }
+}
+
+impl<Dpy: Display + ?Sized> Iterator for PropertyStream<'_, Dpy> {
+    type Item = crate::Result<GetPropertyReply>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let repl = self.display.exchange_request(self.chunk_request());
+        self.process_reply(repl)
+    }
+}
+
+impl<Dpy: Display + ?Sized> FusedIterator for PropertyStream<'_, Dpy> {}
+
+#[cfg(feature = "async")]
+impl<Dpy: AsyncDisplay + ?Sized> PropertyStream<'_, Dpy> {
+    /// Get the next chunk of the property, async redox.
+    #[inline]
+    pub async fn next_async(&mut self) -> Option<crate::Result<GetPropertyReply>> {
+        if self.done {
+            return None;
+        }
+
+        let req = self.chunk_request();
+        let repl = self.display.exchange_request_async(req).await;
+        self.process_reply(repl)
+    }
+}
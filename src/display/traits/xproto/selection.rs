@@ -0,0 +1,757 @@
+// MIT/Apache2 License
+
+//! This module contains the owner's side of the ICCCM selection protocol. Asking for the contents of a
+//! selection is a single `ConvertSelection` request, but owning one means answering every client that
+//! asks, which is what `SelectionSource` is for.
+
+use super::{time_or_last_event, PropertyFormat};
+use crate::{
+    auto::{
+        xproto::{
+            Atom, ChangePropertyRequest, EventMask, GetPropertyRequest, GetSelectionOwnerRequest,
+            InternAtomRequest, PropMode, SelectionNotifyEvent, SelectionRequestEvent,
+            SetSelectionOwnerRequest, Timestamp, Window, ATOM_ATOM, ATOM_INTEGER, ATOM_NONE,
+            WINDOW_NONE,
+        },
+        Event as _,
+    },
+    display::{prelude::*, Display, RequestCookie},
+    Event, XidType,
+};
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "async")]
+use crate::display::AsyncDisplay;
+
+/// The length of a `ChangeProperty` request, not counting its data.
+const CHANGE_PROPERTY_HEADER_LEN: usize = 24;
+
+/// The names of the atoms that `SelectionSource` answers for itself.
+const SPECIAL_ATOM_NAMES: [&str; 4] = ["TARGETS", "MULTIPLE", "TIMESTAMP", "ATOM_PAIR"];
+
+/// The contents of a selection, converted to a target. This is written to a property on the requestor's
+/// window, so `data` must already be in the format that `format` describes; 16 and 32-bit values are in the
+/// client's byte order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectionData {
+    pub ty: Atom,
+    pub format: PropertyFormat,
+    pub data: Vec<u8>,
+}
+
+impl SelectionData {
+    #[inline]
+    #[must_use]
+    pub fn new(ty: Atom, format: PropertyFormat, data: Vec<u8>) -> Self {
+        Self { ty, format, data }
+    }
+
+    /// Selection data made up of 32-bit values.
+    #[inline]
+    #[must_use]
+    pub fn longs(ty: Atom, longs: &[u32]) -> Self {
+        Self::new(
+            ty,
+            PropertyFormat::ThirtyTwo,
+            longs.iter().flat_map(|l| l.to_ne_bytes()).collect(),
+        )
+    }
+
+    /// The number of values in the data, rather than the number of bytes.
+    #[inline]
+    fn len(&self) -> u32 {
+        (self.data.len() / (self.format as usize / 8)) as u32
+    }
+}
+
+/// What `SelectionSource::handle_event` did with an event.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SelectionEvent {
+    /// A client asked for the selection as the given target, and got it.
+    Served(Atom),
+    /// A client asked for the selection as the given target, and was refused.
+    Refused(Atom),
+    /// Another client took the selection; the source no longer owns it.
+    Lost,
+}
+
+/// The atoms for the targets that every selection owner has to support.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct SpecialAtoms {
+    targets: Atom,
+    multiple: Atom,
+    timestamp: Atom,
+    atom_pair: Atom,
+}
+
+impl SpecialAtoms {
+    #[inline]
+    fn from_atoms(atoms: [Atom; 4]) -> Self {
+        let [targets, multiple, timestamp, atom_pair] = atoms;
+        Self {
+            targets,
+            multiple,
+            timestamp,
+            atom_pair,
+        }
+    }
+}
+
+/// The owner of a selection, such as the clipboard. Once the selection is acquired, every event passed to
+/// `handle_event` is checked for a `SelectionRequest` to answer or a `SelectionClear` that takes the
+/// selection away.
+///
+/// The `TARGETS`, `TIMESTAMP` and `MULTIPLE` targets are answered without consulting the callback given to
+/// `handle_event`, as the ICCCM requires. Data too large to fit in a single request would have to be sent
+/// with the `INCR` protocol, which is not supported, so such requests are refused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectionSource {
+    window: Window,
+    selection: Atom,
+    time: Timestamp,
+    targets: Vec<Atom>,
+    atoms: SpecialAtoms,
+    owned: bool,
+}
+
+impl SelectionSource {
+    /// Take ownership of `selection` for `window`, which will receive the events for it. `targets` are the
+    /// targets the selection can be converted to, which are listed to clients that ask for `TARGETS`.
+    ///
+    /// `time` should be the timestamp of the event that caused the selection to be taken; if it is zero,
+    /// the timestamp of the last event received is used instead, since the ICCCM forbids `CurrentTime`
+    /// here. Another client may take the selection at the same time, so the owner is checked afterwards,
+    /// and `None` is returned if it isn't `window`.
+    #[inline]
+    pub fn acquire<Dpy: Display + ?Sized>(
+        dpy: &mut Dpy,
+        window: Window,
+        selection: Atom,
+        targets: Vec<Atom>,
+        time: Timestamp,
+    ) -> crate::Result<Option<Self>> {
+        let toks = SPECIAL_ATOM_NAMES
+            .iter()
+            .map(|&name| dpy.send_request(special_atom_request(name)))
+            .collect::<crate::Result<Vec<RequestCookie<InternAtomRequest>>>>()?;
+        let mut atoms = [ATOM_NONE; 4];
+        for (atom, tok) in atoms.iter_mut().zip(toks) {
+            *atom = dpy.resolve_request(tok)?.atom;
+        }
+
+        let time = acquire_time(dpy, time);
+        dpy.exchange_request(set_owner_request(window, selection, time))?;
+        let owner = dpy
+            .exchange_request(GetSelectionOwnerRequest {
+                selection,
+                ..Default::default()
+            })?
+            .owner;
+
+        Ok(Self::acquired(
+            window,
+            selection,
+            targets,
+            time,
+            SpecialAtoms::from_atoms(atoms),
+            owner,
+        ))
+    }
+
+    /// Take ownership of a selection, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn acquire_async<Dpy: AsyncDisplay + ?Sized>(
+        dpy: &mut Dpy,
+        window: Window,
+        selection: Atom,
+        targets: Vec<Atom>,
+        time: Timestamp,
+    ) -> crate::Result<Option<Self>> {
+        let mut toks = Vec::with_capacity(SPECIAL_ATOM_NAMES.len());
+        for name in &SPECIAL_ATOM_NAMES {
+            toks.push(dpy.send_request_async(special_atom_request(name)).await?);
+        }
+        let mut atoms = [ATOM_NONE; 4];
+        for (atom, tok) in atoms.iter_mut().zip(toks) {
+            *atom = dpy.resolve_request_async(tok).await?.atom;
+        }
+
+        let time = acquire_time(dpy, time);
+        dpy.exchange_request_async(set_owner_request(window, selection, time))
+            .await?;
+        let owner = dpy
+            .exchange_request_async(GetSelectionOwnerRequest {
+                selection,
+                ..Default::default()
+            })
+            .await?
+            .owner;
+
+        Ok(Self::acquired(
+            window,
+            selection,
+            targets,
+            time,
+            SpecialAtoms::from_atoms(atoms),
+            owner,
+        ))
+    }
+
+    #[inline]
+    fn acquired(
+        window: Window,
+        selection: Atom,
+        targets: Vec<Atom>,
+        time: Timestamp,
+        atoms: SpecialAtoms,
+        owner: Window,
+    ) -> Option<Self> {
+        if owner == window {
+            Some(Self {
+                window,
+                selection,
+                time,
+                targets,
+                atoms,
+                owned: true,
+            })
+        } else {
+            log::debug!(
+                "Selection {} is owned by {:#X} instead of {:#X}",
+                selection.xid(),
+                owner.xid(),
+                window.xid()
+            );
+            None
+        }
+    }
+
+    /// The window that owns the selection.
+    #[inline]
+    #[must_use]
+    pub fn window(&self) -> Window {
+        self.window
+    }
+
+    /// The selection that is owned.
+    #[inline]
+    #[must_use]
+    pub fn selection(&self) -> Atom {
+        self.selection
+    }
+
+    /// The time at which the selection was acquired.
+    #[inline]
+    #[must_use]
+    pub fn time(&self) -> Timestamp {
+        self.time
+    }
+
+    /// Whether or not the selection is still owned. This becomes `false` once a `SelectionClear` event has
+    /// been handled.
+    #[inline]
+    #[must_use]
+    pub fn is_owned(&self) -> bool {
+        self.owned
+    }
+
+    /// Handle an event. `SelectionRequest` events for this selection are answered, calling `convert` to
+    /// turn the selection into the target being asked for; if it returns `None`, the request is refused.
+    /// Returns `None` if the event has nothing to do with this selection.
+    #[inline]
+    pub fn handle_event<Dpy: Display + ?Sized, F: FnMut(Atom) -> Option<SelectionData>>(
+        &mut self,
+        dpy: &mut Dpy,
+        event: &Event,
+        mut convert: F,
+    ) -> crate::Result<Option<SelectionEvent>> {
+        let request = match self.request_for(event) {
+            Request::Ignore => return Ok(None),
+            Request::Lost => return Ok(Some(SelectionEvent::Lost)),
+            Request::Convert(request) => request,
+        };
+
+        let property = if self.accepts(request) {
+            self.convert_request(dpy, request, &mut convert)?
+        } else {
+            ATOM_NONE
+        };
+
+        dpy.send_event(
+            false,
+            request.requestor,
+            EventMask::default(),
+            notify_event(request, property),
+        )?;
+        Ok(Some(outcome(request, property)))
+    }
+
+    /// Handle an event, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn handle_event_async<
+        Dpy: AsyncDisplay + ?Sized,
+        F: FnMut(Atom) -> Option<SelectionData>,
+    >(
+        &mut self,
+        dpy: &mut Dpy,
+        event: &Event,
+        mut convert: F,
+    ) -> crate::Result<Option<SelectionEvent>> {
+        let request = match self.request_for(event) {
+            Request::Ignore => return Ok(None),
+            Request::Lost => return Ok(Some(SelectionEvent::Lost)),
+            Request::Convert(request) => request,
+        };
+
+        let property = if self.accepts(request) {
+            self.convert_request_async(dpy, request, &mut convert)
+                .await?
+        } else {
+            ATOM_NONE
+        };
+
+        dpy.send_event_async(
+            false,
+            request.requestor,
+            EventMask::default(),
+            notify_event(request, property),
+        )
+        .await?;
+        Ok(Some(outcome(request, property)))
+    }
+
+    /// Give up the selection, if it is still owned.
+    #[inline]
+    pub fn release<Dpy: Display + ?Sized>(self, dpy: &mut Dpy) -> crate::Result {
+        if self.owned {
+            dpy.exchange_request(set_owner_request(WINDOW_NONE, self.selection, self.time))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Give up the selection, if it is still owned, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn release_async<Dpy: AsyncDisplay + ?Sized>(self, dpy: &mut Dpy) -> crate::Result {
+        if self.owned {
+            dpy.exchange_request_async(set_owner_request(WINDOW_NONE, self.selection, self.time))
+                .await
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Figure out what an event means for this selection. Losing the selection is recorded here.
+    #[inline]
+    fn request_for<'e>(&mut self, event: &'e Event) -> Request<'e> {
+        match event {
+            Event::SelectionRequest(request) if request.selection == self.selection => {
+                Request::Convert(request)
+            }
+            // a clear from before we took the selection is about a previous owner
+            Event::SelectionClear(clear)
+                if self.owned
+                    && clear.selection == self.selection
+                    && clear.owner == self.window
+                    && not_before(clear.time, self.time) =>
+            {
+                self.owned = false;
+                Request::Lost
+            }
+            _ => Request::Ignore,
+        }
+    }
+
+    /// Requests for a selection we don't own, or that were made before we owned it, must be refused.
+    #[inline]
+    fn accepts(&self, request: &SelectionRequestEvent) -> bool {
+        self.owned && request.owner == self.window && not_before(request.time, self.time)
+    }
+
+    /// Answer a request, returning the property the answer was written to, or `None` if it was refused.
+    #[inline]
+    fn convert_request<Dpy: Display + ?Sized, F: FnMut(Atom) -> Option<SelectionData>>(
+        &self,
+        dpy: &mut Dpy,
+        request: &SelectionRequestEvent,
+        convert: &mut F,
+    ) -> crate::Result<Atom> {
+        let property = target_property(request);
+        if request.target != self.atoms.multiple {
+            let data = self.convert_target(request.target, convert);
+            return Self::write_property(dpy, request.requestor, property, data);
+        }
+
+        // MULTIPLE always comes with a property, holding the pairs of targets and properties to convert
+        if request.property == ATOM_NONE {
+            return Ok(ATOM_NONE);
+        }
+        let pairs = dpy.exchange_request(self.pairs_request(request))?.value;
+        let mut pairs = decode_pairs(&pairs);
+        for pair in &mut pairs {
+            let data = match pair[0] {
+                target if target == self.atoms.multiple.xid => None,
+                target => self.convert_target(Atom::const_from_xid(target), convert),
+            };
+            let written =
+                Self::write_property(dpy, request.requestor, Atom::const_from_xid(pair[1]), data)?;
+            pair[1] = written.xid;
+        }
+
+        let data = Some(self.pairs_data(&pairs));
+        Self::write_property(dpy, request.requestor, property, data)
+    }
+
+    /// Answer a request, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    async fn convert_request_async<
+        Dpy: AsyncDisplay + ?Sized,
+        F: FnMut(Atom) -> Option<SelectionData>,
+    >(
+        &self,
+        dpy: &mut Dpy,
+        request: &SelectionRequestEvent,
+        convert: &mut F,
+    ) -> crate::Result<Atom> {
+        let property = target_property(request);
+        if request.target != self.atoms.multiple {
+            let data = self.convert_target(request.target, convert);
+            return Self::write_property_async(dpy, request.requestor, property, data).await;
+        }
+
+        if request.property == ATOM_NONE {
+            return Ok(ATOM_NONE);
+        }
+        let pairs = dpy
+            .exchange_request_async(self.pairs_request(request))
+            .await?
+            .value;
+        let mut pairs = decode_pairs(&pairs);
+        for pair in &mut pairs {
+            let data = match pair[0] {
+                target if target == self.atoms.multiple.xid => None,
+                target => self.convert_target(Atom::const_from_xid(target), convert),
+            };
+            let written = Self::write_property_async(
+                dpy,
+                request.requestor,
+                Atom::const_from_xid(pair[1]),
+                data,
+            )
+            .await?;
+            pair[1] = written.xid;
+        }
+
+        let data = Some(self.pairs_data(&pairs));
+        Self::write_property_async(dpy, request.requestor, property, data).await
+    }
+
+    /// Convert the selection to a single target, answering the targets every owner supports ourselves.
+    #[inline]
+    fn convert_target<F: FnMut(Atom) -> Option<SelectionData>>(
+        &self,
+        target: Atom,
+        convert: &mut F,
+    ) -> Option<SelectionData> {
+        if target == self.atoms.targets {
+            let mut targets: Vec<u32> = self.targets.iter().map(|t| t.xid).collect();
+            targets.extend_from_slice(&[
+                self.atoms.targets.xid,
+                self.atoms.multiple.xid,
+                self.atoms.timestamp.xid,
+            ]);
+            Some(SelectionData::longs(ATOM_ATOM, &targets))
+        } else if target == self.atoms.timestamp {
+            Some(SelectionData::longs(ATOM_INTEGER, &[self.time]))
+        } else {
+            convert(target)
+        }
+    }
+
+    /// Write converted data to a property on the requestor's window, returning the property, or `None` if
+    /// there is nothing to write or it doesn't fit in a request.
+    #[inline]
+    fn write_property<Dpy: Display + ?Sized>(
+        dpy: &mut Dpy,
+        requestor: Window,
+        property: Atom,
+        data: Option<SelectionData>,
+    ) -> crate::Result<Atom> {
+        match property_request(dpy.max_request_len(), requestor, property, data) {
+            Some(req) => {
+                dpy.exchange_request(req)?;
+                Ok(property)
+            }
+            None => Ok(ATOM_NONE),
+        }
+    }
+
+    /// Write converted data to a property on the requestor's window, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    async fn write_property_async<Dpy: AsyncDisplay + ?Sized>(
+        dpy: &mut Dpy,
+        requestor: Window,
+        property: Atom,
+        data: Option<SelectionData>,
+    ) -> crate::Result<Atom> {
+        match property_request(dpy.max_request_len(), requestor, property, data) {
+            Some(req) => {
+                dpy.exchange_request_async(req).await?;
+                Ok(property)
+            }
+            None => Ok(ATOM_NONE),
+        }
+    }
+
+    /// Request for the target and property pairs of a `MULTIPLE` request.
+    #[inline]
+    fn pairs_request(&self, request: &SelectionRequestEvent) -> GetPropertyRequest {
+        GetPropertyRequest {
+            window: request.requestor,
+            property: request.property,
+            ty: self.atoms.atom_pair,
+            long_length: u32::MAX,
+            ..Default::default()
+        }
+    }
+
+    /// The target and property pairs of a `MULTIPLE` request, with the ones that were refused set to `None`.
+    #[inline]
+    fn pairs_data(&self, pairs: &[[u32; 2]]) -> SelectionData {
+        let longs: Vec<u32> = pairs.iter().flatten().copied().collect();
+        SelectionData::longs(self.atoms.atom_pair, &longs)
+    }
+}
+
+/// What an event means for a `SelectionSource`.
+enum Request<'e> {
+    Ignore,
+    Lost,
+    Convert(&'e SelectionRequestEvent),
+}
+
+#[inline]
+fn special_atom_request(name: &str) -> InternAtomRequest {
+    InternAtomRequest {
+        only_if_exists: false,
+        name: String::from(name),
+        ..Default::default()
+    }
+}
+
+#[inline]
+fn set_owner_request(owner: Window, selection: Atom, time: Timestamp) -> SetSelectionOwnerRequest {
+    SetSelectionOwnerRequest {
+        owner,
+        selection,
+        time,
+        ..Default::default()
+    }
+}
+
+#[inline]
+fn acquire_time<Dpy: DisplayBase + ?Sized>(dpy: &Dpy, time: Timestamp) -> Timestamp {
+    time_or_last_event(dpy, if time == 0 { None } else { Some(time) })
+}
+
+/// Whether or not `time` is no earlier than `since`, counting across the wraparound of the server's clock. If
+/// the selection was taken at `CurrentTime`, we don't know when that was, so nothing is treated as earlier.
+#[inline]
+fn not_before(time: Timestamp, since: Timestamp) -> bool {
+    since == 0 || !crate::ServerTime::new(time).is_earlier_than(crate::ServerTime::new(since))
+}
+
+/// Request to write converted data to a property, or `None` if there is nothing to write or it doesn't fit
+/// in a request.
+#[inline]
+fn property_request(
+    max_request_len: usize,
+    requestor: Window,
+    property: Atom,
+    data: Option<SelectionData>,
+) -> Option<ChangePropertyRequest> {
+    let data = data?;
+    if property == ATOM_NONE {
+        return None;
+    }
+    if CHANGE_PROPERTY_HEADER_LEN + data.data.len() > max_request_len {
+        log::warn!(
+            "Refusing to send {} bytes of selection data, since INCR transfers are not supported",
+            data.data.len()
+        );
+        return None;
+    }
+
+    Some(ChangePropertyRequest {
+        mode: PropMode::Replace,
+        window: requestor,
+        property,
+        ty: data.ty,
+        format: data.format as u8,
+        data_len: data.len(),
+        data: data.data,
+        ..Default::default()
+    })
+}
+
+/// Obsolete clients don't name a property, in which case the target doubles as one.
+#[inline]
+fn target_property(request: &SelectionRequestEvent) -> Atom {
+    if request.property == ATOM_NONE {
+        request.target
+    } else {
+        request.property
+    }
+}
+
+#[inline]
+fn decode_pairs(bytes: &[u8]) -> Vec<[u32; 2]> {
+    bytes
+        .chunks_exact(8)
+        .map(|pair| {
+            [
+                u32::from_ne_bytes([pair[0], pair[1], pair[2], pair[3]]),
+                u32::from_ne_bytes([pair[4], pair[5], pair[6], pair[7]]),
+            ]
+        })
+        .collect()
+}
+
+#[inline]
+fn notify_event(request: &SelectionRequestEvent, property: Atom) -> Event {
+    Event::SelectionNotify(SelectionNotifyEvent {
+        event_type: SelectionNotifyEvent::OPCODE,
+        time: request.time,
+        requestor: request.requestor,
+        selection: request.selection,
+        target: request.target,
+        property,
+        ..Default::default()
+    })
+}
+
+#[inline]
+fn outcome(request: &SelectionRequestEvent, property: Atom) -> SelectionEvent {
+    if property == ATOM_NONE {
+        SelectionEvent::Refused(request.target)
+    } else {
+        SelectionEvent::Served(request.target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::display::{mock_display, mock_setup, DisplayBase, MockConnection};
+
+    #[test]
+    fn selection_source_serves_targets() {
+        use crate::{
+            auto::xproto::{
+                Atom, GetSelectionOwnerReply, InternAtomReply, SelectionClearEvent,
+                SelectionRequestEvent, Window,
+            },
+            display::{SelectionData, SelectionEvent, SelectionSource},
+            Event,
+        };
+        use alloc::{vec, vec::Vec};
+
+        let window = Window::const_from_xid(0x0200_0001);
+        let requestor = Window::const_from_xid(0x0300_0001);
+        let clipboard = Atom::const_from_xid(400);
+        let utf8_string = Atom::const_from_xid(401);
+        let property = Atom::const_from_xid(402);
+
+        // TARGETS, MULTIPLE, TIMESTAMP and ATOM_PAIR, in that order
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        for (sequence, atom) in (2..=5).zip(500..) {
+            conn.feed_reply(&InternAtomReply {
+                reply_type: 1,
+                sequence,
+                atom: Atom::const_from_xid(atom),
+                ..Default::default()
+            });
+        }
+        conn.feed_reply(&GetSelectionOwnerReply {
+            reply_type: 1,
+            sequence: 7,
+            owner: window,
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        display.set_checked(false);
+        let mut source =
+            SelectionSource::acquire(&mut display, window, clipboard, vec![utf8_string], 50)
+                .unwrap()
+                .unwrap();
+
+        let request = |target: u32, time: u32| {
+            Event::SelectionRequest(SelectionRequestEvent {
+                time,
+                owner: window,
+                requestor,
+                selection: clipboard,
+                target: Atom::const_from_xid(target),
+                property,
+                ..Default::default()
+            })
+        };
+        let unused = |_| -> Option<SelectionData> { panic!("TARGETS shouldn't need converting") };
+        assert_eq!(
+            source
+                .handle_event(&mut display, &request(500, 60), unused)
+                .unwrap(),
+            Some(SelectionEvent::Served(Atom::const_from_xid(500)))
+        );
+        // a request from before the selection was taken is refused
+        assert_eq!(
+            source
+                .handle_event(&mut display, &request(401, 40), unused)
+                .unwrap(),
+            Some(SelectionEvent::Refused(utf8_string))
+        );
+        assert_eq!(
+            source
+                .handle_event(
+                    &mut display,
+                    &Event::SelectionClear(SelectionClearEvent {
+                        time: 70,
+                        owner: window,
+                        selection: clipboard,
+                        ..Default::default()
+                    }),
+                    unused
+                )
+                .unwrap(),
+            Some(SelectionEvent::Lost)
+        );
+        assert!(!source.is_owned());
+        source.release(&mut display).unwrap();
+        drop(display);
+
+        // the TARGETS reply lists our target, then the ones every owner supports; then comes the first
+        // SelectionNotify, which names the property, and the second, which doesn't
+        let written = conn.take_outgoing();
+        let long = |i: usize| {
+            u32::from_ne_bytes([written[i], written[i + 1], written[i + 2], written[i + 3]])
+        };
+        let change_property = written.len() - 40 - 44 - 44;
+        assert_eq!(written[change_property], 18);
+        assert_eq!(long(change_property + 4), requestor.xid);
+        assert_eq!(long(change_property + 8), property.xid);
+        let targets: Vec<u32> = (0..4).map(|i| long(change_property + 24 + i * 4)).collect();
+        assert_eq!(targets, vec![401, 500, 501, 502]);
+
+        let served = written.len() - 88;
+        assert_eq!(written[served], 25);
+        assert_eq!(written[served + 12], 31);
+        assert_eq!(long(served + 32), property.xid);
+        let refused = written.len() - 44;
+        assert_eq!(long(refused + 32), 0);
+    }
+}
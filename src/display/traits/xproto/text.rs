@@ -0,0 +1,296 @@
+// MIT/Apache2 License
+
+use crate::{
+    auto::xproto::{Atom, GetAtomNameRequest, GetPropertyReply, Window, ATOM_STRING},
+    display::{prelude::*, Display},
+    BreadError,
+};
+use alloc::{string::String, vec::Vec};
+use core::char;
+
+#[cfg(feature = "async")]
+use crate::display::AsyncDisplay;
+
+/// The encoding of a text property, as determined by the property's type.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TextEncoding {
+    /// The `STRING` type, which is always ISO Latin-1.
+    Latin1,
+    /// The `UTF8_STRING` type.
+    Utf8,
+    /// The `COMPOUND_TEXT` type, which switches between character sets using ISO 2022 escape sequences.
+    /// Only ASCII, Latin-1 and embedded UTF-8 are decoded; characters from any other set are replaced with
+    /// U+FFFD.
+    CompoundText,
+}
+
+/// The decoded value of a text property, along with the encoding it was stored in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TextProperty {
+    pub text: String,
+    pub encoding: TextEncoding,
+}
+
+impl Window {
+    /// Get a property on this window and decode it as text, based on its type. Properties of type `STRING`
+    /// are decoded as Latin-1, `UTF8_STRING` as UTF-8 and `COMPOUND_TEXT` as well as possible. Returns `None`
+    /// if the property is not set, and an error if it has any other type.
+    #[inline]
+    pub fn get_text_property<Dpy: Display + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+        property: Atom,
+    ) -> crate::Result<Option<TextProperty>> {
+        let mut value = TextValue::default();
+        for chunk in dpy.get_property_stream(self, property) {
+            value.push(chunk?)?;
+        }
+
+        let ty = match value.ty {
+            Some(ty) => ty,
+            None => return Ok(None),
+        };
+        let encoding = if ty == ATOM_STRING {
+            TextEncoding::Latin1
+        } else {
+            encoding_for_name(
+                &dpy.exchange_request(GetAtomNameRequest {
+                    atom: ty,
+                    ..Default::default()
+                })?
+                .name,
+            )?
+        };
+
+        Ok(Some(TextProperty {
+            text: decode_text(&value.bytes, encoding),
+            encoding,
+        }))
+    }
+
+    /// Get a property on this window and decode it as text, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn get_text_property_async<Dpy: AsyncDisplay + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+        property: Atom,
+    ) -> crate::Result<Option<TextProperty>> {
+        let mut value = TextValue::default();
+        let mut stream = dpy.get_property_stream_async(self, property);
+        while let Some(chunk) = stream.next_async().await {
+            value.push(chunk?)?;
+        }
+
+        let ty = match value.ty {
+            Some(ty) => ty,
+            None => return Ok(None),
+        };
+        let encoding = if ty == ATOM_STRING {
+            TextEncoding::Latin1
+        } else {
+            encoding_for_name(
+                &dpy.exchange_request_async(GetAtomNameRequest {
+                    atom: ty,
+                    ..Default::default()
+                })
+                .await?
+                .name,
+            )?
+        };
+
+        Ok(Some(TextProperty {
+            text: decode_text(&value.bytes, encoding),
+            encoding,
+        }))
+    }
+}
+
+/// The chunks of a text property, gathered together.
+#[derive(Default)]
+struct TextValue {
+    ty: Option<Atom>,
+    bytes: Vec<u8>,
+}
+
+impl TextValue {
+    #[inline]
+    fn push(&mut self, chunk: GetPropertyReply) -> crate::Result {
+        if chunk.format != 8 {
+            return Err(BreadError::StaticMsg(
+                "Text properties must have a format of 8",
+            ));
+        }
+
+        self.ty = Some(chunk.ty);
+        self.bytes.extend_from_slice(&chunk.value);
+        Ok(())
+    }
+}
+
+/// Figure out the encoding of a text property from the name of its type.
+#[inline]
+fn encoding_for_name(name: &str) -> crate::Result<TextEncoding> {
+    match name {
+        "STRING" => Ok(TextEncoding::Latin1),
+        "UTF8_STRING" => Ok(TextEncoding::Utf8),
+        "COMPOUND_TEXT" => Ok(TextEncoding::CompoundText),
+        _ => Err(BreadError::StaticMsg(
+            "Property is not of a known text type",
+        )),
+    }
+}
+
+/// Decode the value of a text property. Invalid UTF-8 is replaced with U+FFFD rather than being an error,
+/// since clients are not always careful about what they store.
+#[inline]
+#[must_use]
+pub fn decode_text(bytes: &[u8], encoding: TextEncoding) -> String {
+    match encoding {
+        // the first 256 code points of Unicode are exactly Latin-1
+        TextEncoding::Latin1 => bytes.iter().copied().map(char::from).collect(),
+        TextEncoding::Utf8 => String::from_utf8_lossy(bytes).into_owned(),
+        TextEncoding::CompoundText => decode_compound_text(bytes),
+    }
+}
+
+const ESC: u8 = 0x1B;
+const CSI: u8 = 0x9B;
+
+/// A character set designated into either half of the `COMPOUND_TEXT` code space.
+#[derive(Copy, Clone)]
+enum Charset {
+    /// ASCII, which is the initial set for the left half.
+    Ascii,
+    /// The right half of Latin-1, which is the initial set for the right half.
+    Latin1,
+    /// Any other set, whose characters are this many bytes long.
+    Unsupported(usize),
+}
+
+/// Decode `COMPOUND_TEXT` on a best-effort basis.
+#[inline]
+fn decode_compound_text(bytes: &[u8]) -> String {
+    let mut text = String::with_capacity(bytes.len());
+    let mut left = Charset::Ascii;
+    let mut right = Charset::Latin1;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let b = bytes[i];
+        match b {
+            ESC => {
+                // an escape sequence is made up of intermediate bytes followed by a final byte
+                let start = i + 1;
+                let mut end = start;
+                while end < bytes.len() && (0x20..=0x2F).contains(&bytes[end]) {
+                    end += 1;
+                }
+                let intermediates = &bytes[start..end];
+                let fin = bytes.get(end).copied().unwrap_or(0);
+                i = end + 1;
+
+                match intermediates {
+                    // UTF-8 runs until ESC % @
+                    b"%" if fin == b'G' => {
+                        let len = bytes[i..]
+                            .windows(3)
+                            .position(|w| w == b"\x1B%@")
+                            .unwrap_or(bytes.len() - i);
+                        text.push_str(&String::from_utf8_lossy(&bytes[i..i + len]));
+                        i = (i + len + 3).min(bytes.len());
+                    }
+                    b"(" if fin == b'B' => left = Charset::Ascii,
+                    b"-" if fin == b'A' => right = Charset::Latin1,
+                    [b'$', rest @ ..] => match rest.first() {
+                        Some(b')' | b'-') => right = Charset::Unsupported(2),
+                        _ => left = Charset::Unsupported(2),
+                    },
+                    [b'(', ..] => left = Charset::Unsupported(1),
+                    [b')' | b'-', ..] => right = Charset::Unsupported(1),
+                    // anything else, like the extended segments, isn't understood and is skipped
+                    _ => {}
+                }
+            }
+            // direction changes carry no text
+            CSI => {
+                i += 1;
+                while i < bytes.len() && !(0x40..=0x7E).contains(&bytes[i]) {
+                    i += 1;
+                }
+                i += 1;
+            }
+            b'\t' | b'\n' => {
+                text.push(char::from(b));
+                i += 1;
+            }
+            0x20..=0x7F => i += decode_char(&mut text, left, b),
+            0xA0..=0xFF => i += decode_char(&mut text, right, b),
+            // other control characters are not allowed
+            _ => i += 1,
+        }
+    }
+
+    text
+}
+
+/// Push the character starting with the given byte, returning how many bytes it takes up.
+#[inline]
+fn decode_char(text: &mut String, charset: Charset, b: u8) -> usize {
+    match charset {
+        Charset::Ascii | Charset::Latin1 => {
+            text.push(char::from(b));
+            1
+        }
+        Charset::Unsupported(width) => {
+            text.push(char::REPLACEMENT_CHARACTER);
+            width
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_text, encoding_for_name, TextEncoding};
+
+    #[test]
+    fn latin1_is_not_utf8() {
+        // "café" in Latin-1 isn't valid UTF-8
+        let bytes = b"caf\xE9";
+        assert_eq!(decode_text(bytes, TextEncoding::Latin1), "café");
+        assert_eq!(decode_text(bytes, TextEncoding::Utf8), "caf\u{FFFD}");
+        assert_eq!(decode_text("café".as_bytes(), TextEncoding::Utf8), "café");
+
+        assert_eq!(encoding_for_name("STRING").unwrap(), TextEncoding::Latin1);
+        assert_eq!(
+            encoding_for_name("UTF8_STRING").unwrap(),
+            TextEncoding::Utf8
+        );
+        assert!(encoding_for_name("CARDINAL").is_err());
+    }
+
+    #[test]
+    fn compound_text_is_decoded() {
+        // ASCII and Latin-1 are designated from the start
+        assert_eq!(
+            decode_text(b"caf\xE9\n", TextEncoding::CompoundText),
+            "café\n"
+        );
+        // embedded UTF-8
+        assert_eq!(
+            decode_text(
+                b"a\x1B%G\xE2\x82\xAC\x1B%@b\xE9",
+                TextEncoding::CompoundText
+            ),
+            "a€bé"
+        );
+        // a two-byte set we don't know, followed by a switch back to ASCII
+        assert_eq!(
+            decode_text(
+                b"\x1B$(B\x30\x21\x30\x22\x1B(Bok",
+                TextEncoding::CompoundText
+            ),
+            "\u{FFFD}\u{FFFD}ok"
+        );
+    }
+}
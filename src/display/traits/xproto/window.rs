@@ -2,7 +2,7 @@
 
 #![allow(clippy::similar_names)]
 
-use super::Geometry as DrawableGeometry;
+use super::{time_or_last_event, Geometry as DrawableGeometry};
 pub use crate::{
     auto::{
         xproto::{
@@ -10,8 +10,9 @@ pub use crate::{
             ChangeWindowAttributesRequest, Circulate, CirculateWindowRequest, ClearAreaRequest,
             Colormap, ConfigWindow, ConfigureWindowRequest, ConvertSelectionRequest, Cursor,
             DeletePropertyRequest, DestroySubwindowsRequest, DestroyWindowRequest, EventMask,
-            Gcontext, GetGeometryRequest, GetWindowAttributesReply, GetWindowAttributesRequest,
-            Gravity, MapState, MapWindowRequest, PropMode, SetMode, StackMode, Timestamp,
+            Gcontext, GetAtomNameRequest, GetGeometryRequest, GetPropertyRequest,
+            GetWindowAttributesReply, GetWindowAttributesRequest, Gravity, ListPropertiesRequest,
+            MapState, MapWindowRequest, PropMode, QueryTreeRequest, SetMode, StackMode, Timestamp,
             UnmapWindowRequest, Visualid, Window, WindowClass, ATOM_WM_NAME,
         },
         AsByteSequence,
@@ -19,54 +20,27 @@ pub use crate::{
     display::{prelude::*, Connection, Display, DisplayExt, RequestCookie, WindowParameters},
     xid::XidType,
 };
-use alloc::{string::ToString, vec::Vec};
-use core::{iter, mem};
+use crate::{
+    auto::{
+        xproto::{ClientMessageEvent, ConfigureRequestEvent, GetPropertyReply, ATOM_ATOM},
+        Event as _,
+    },
+    client_message_data::ClientMessageData,
+    Event,
+};
+use alloc::{
+    collections::VecDeque,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{iter, mem, num::NonZeroU32};
 
 #[cfg(feature = "async")]
 use crate::display::{traits::AsyncDisplayDrawableExt, AsyncDisplay};
 #[cfg(feature = "async")]
 use futures_lite::future::{self, Ready};
 
-// macro for retrieving an atom that might be cached in the display
-macro_rules! retrieve_atom {
-    ($dpy: expr, $dgetter: ident, $dsetter: ident, $name: expr) => {{
-        match $dpy.$dgetter() {
-            Some(wpa) => Atom::const_from_xid(wpa.get()),
-            None => {
-                let wpa = $dpy.intern_atom_immediate(($name).to_string(), false)?;
-                if wpa.xid() == 0 {
-                    log::error!("Unable to intern {} atom", $name);
-                    return Ok(());
-                }
-
-                $dpy.$dsetter(core::num::NonZeroU32::new(wpa.xid()).unwrap());
-                wpa
-            }
-        }
-    }};
-}
-
-#[cfg(feature = "async")]
-macro_rules! retrieve_atom_async {
-    ($dpy: expr, $dgetter: ident, $dsetter: ident, $name: expr) => {{
-        match $dpy.$dgetter() {
-            Some(wpa) => Atom::const_from_xid(wpa.get()),
-            None => {
-                let wpa = $dpy
-                    .intern_atom_immediate_async(($name).to_string(), false)
-                    .await?;
-                if wpa.xid() == 0 {
-                    log::error!("Unable to intern {} atom", $name);
-                    return Ok(());
-                } else {
-                    $dpy.$dsetter(core::num::NonZeroU32::new(wpa.xid()).unwrap());
-                    wpa
-                }
-            }
-        }
-    }};
-}
-
 crate::create_paramaterizer! {
     pub struct ConfigureWindowParameters : (ConfigWindow, ConfigureWindowRequest) {
         x            (set_x,            x)            : i32,
@@ -118,6 +92,46 @@ pub struct WindowGeometry {
     pub border_width: u16,
 }
 
+impl ConfigureRequestEvent {
+    /// The changes the client asked for, holding only the fields that it set in `value_mask`; the other
+    /// fields of the event are meaningless. A window manager can adjust these as it sees fit and pass them
+    /// to `Window::configure` to carry out the request.
+    #[inline]
+    #[must_use]
+    pub fn requested_parameters(&self) -> ConfigureWindowParameters {
+        let mask = self.value_mask;
+        ConfigureWindowParameters {
+            x: if mask.x() { Some(self.x.into()) } else { None },
+            y: if mask.y() { Some(self.y.into()) } else { None },
+            width: if mask.width() {
+                Some(self.width.into())
+            } else {
+                None
+            },
+            height: if mask.height() {
+                Some(self.height.into())
+            } else {
+                None
+            },
+            border_width: if mask.border_width() {
+                Some(self.border_width.into())
+            } else {
+                None
+            },
+            sibling: if mask.sibling() {
+                Some(self.sibling)
+            } else {
+                None
+            },
+            stack_mode: if mask.stack_mode() {
+                Some(self.stack_mode)
+            } else {
+                None
+            },
+        }
+    }
+}
+
 impl Window {
     /// Map this window to the screen.
     #[inline]
@@ -161,7 +175,7 @@ impl Window {
 
     /// Request struct to change the property of a window.
     #[inline]
-    fn change_property_request<T: AsByteSequence>(
+    pub(super) fn change_property_request<T: AsByteSequence>(
         self,
         property: Atom,
         property_type: PropertyType,
@@ -264,6 +278,102 @@ impl Window {
         .await
     }
 
+    /// List the atoms of every property currently set on this window.
+    #[inline]
+    pub fn list_properties<Dpy: Display + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+    ) -> crate::Result<RequestCookie<ListPropertiesRequest>> {
+        dpy.send_request(ListPropertiesRequest {
+            window: self,
+            ..Default::default()
+        })
+    }
+
+    /// List the atoms of every property currently set on this window, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn list_properties_async<Dpy: AsyncDisplay + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+    ) -> crate::Result<RequestCookie<ListPropertiesRequest>> {
+        dpy.send_request_async(ListPropertiesRequest {
+            window: self,
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Immediately list the atoms of every property currently set on this window.
+    #[inline]
+    pub fn list_properties_immediate<Dpy: Display + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+    ) -> crate::Result<Vec<Atom>> {
+        let tok = self.list_properties(dpy)?;
+        Ok(dpy.resolve_request(tok)?.atoms)
+    }
+
+    /// Immediately list the atoms of every property currently set on this window, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn list_properties_immediate_async<Dpy: AsyncDisplay + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+    ) -> crate::Result<Vec<Atom>> {
+        let tok = self.list_properties_async(dpy).await?;
+        Ok(dpy.resolve_request_async(tok).await?.atoms)
+    }
+
+    /// List the names of every property currently set on this window. The names are all requested before
+    /// any of them are waited on, so this only takes two round trips no matter how many properties there
+    /// are.
+    #[inline]
+    pub fn list_property_names<Dpy: Display + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+    ) -> crate::Result<Vec<String>> {
+        let atoms = self.list_properties_immediate(dpy)?;
+        let toks = atoms
+            .into_iter()
+            .map(|atom| {
+                dpy.send_request(GetAtomNameRequest {
+                    atom,
+                    ..Default::default()
+                })
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+        toks.into_iter()
+            .map(|tok| dpy.resolve_request(tok).map(|reply| reply.name))
+            .collect()
+    }
+
+    /// List the names of every property currently set on this window, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn list_property_names_async<Dpy: AsyncDisplay + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+    ) -> crate::Result<Vec<String>> {
+        let atoms = self.list_properties_immediate_async(dpy).await?;
+        let mut toks = Vec::with_capacity(atoms.len());
+        for atom in atoms {
+            toks.push(
+                dpy.send_request_async(GetAtomNameRequest {
+                    atom,
+                    ..Default::default()
+                })
+                .await?,
+            );
+        }
+
+        let mut names = Vec::with_capacity(toks.len());
+        for tok in toks {
+            names.push(dpy.resolve_request_async(tok).await?.name);
+        }
+        Ok(names)
+    }
+
     /// Set the protocols for the WM in regards to this window.
     #[inline]
     pub fn set_wm_protocols<Dpy: Display + ?Sized>(
@@ -394,10 +504,7 @@ impl Window {
         self,
         dpy: &mut Dpy,
     ) -> crate::Result<WindowAttributes> {
-        let tok = self.window_attributes_async(dpy).await?;
-        Ok(convert_get_window_attributes_reply(
-            dpy.resolve_request_async(tok).await?,
-        ))
+        dpy.get_window_attributes_immediate_async(self).await
     }
 
     /// Get the geometry of this window.
@@ -591,7 +698,7 @@ impl Window {
     }
 
     #[inline]
-    fn change_save_set_request(self, mode: SetMode) -> ChangeSaveSetRequest {
+    pub(crate) fn change_save_set_request(self, mode: SetMode) -> ChangeSaveSetRequest {
         ChangeSaveSetRequest {
             mode,
             window: self,
@@ -599,6 +706,8 @@ impl Window {
         }
     }
 
+    /// Add this window to or remove it from the client's save-set. See
+    /// `DisplayXprotoExt::change_save_set` for why window managers need this.
     #[inline]
     pub fn change_save_set<Dpy: Display + ?Sized>(
         self,
@@ -608,6 +717,7 @@ impl Window {
         dpy.exchange_request(self.change_save_set_request(mode))
     }
 
+    /// Add this window to or remove it from the client's save-set, async redox.
     #[cfg(feature = "async")]
     #[inline]
     pub async fn change_save_set_async<Dpy: AsyncDisplay + ?Sized>(
@@ -881,6 +991,7 @@ impl Window {
     /// Free this window.
     #[inline]
     pub fn free<Dpy: Display + ?Sized>(self, dpy: &mut Dpy) -> crate::Result {
+        dpy.untrack_resource(self.xid);
         dpy.exchange_request(DestroyWindowRequest {
             window: self,
             ..Default::default()
@@ -891,6 +1002,7 @@ impl Window {
     #[cfg(feature = "async")]
     #[inline]
     pub async fn free_async<Dpy: AsyncDisplay + ?Sized>(self, dpy: &mut Dpy) -> crate::Result {
+        dpy.untrack_resource(self.xid);
         dpy.exchange_request_async(DestroyWindowRequest {
             window: self,
             ..Default::default()
@@ -898,6 +1010,172 @@ impl Window {
         .await
     }
 
+    /// Forcibly close the connection of the client this window belongs to. If this window was made by a
+    /// window manager to frame a client, such as the window the user clicked on, then the client is found
+    /// by looking for the window under it that has the `WM_STATE` property set; if there is none, the
+    /// client that created this window is killed.
+    #[inline]
+    pub fn close_client_by_window<Dpy: Display + ?Sized>(self, dpy: &mut Dpy) -> crate::Result {
+        let wm_state = dpy.intern_atom_immediate("WM_STATE".to_string(), true)?;
+        let mut client = self;
+
+        // search breadth-first, as the client window is usually a direct child of the frame
+        if wm_state.xid != 0 {
+            let mut queue = VecDeque::from(vec![self]);
+            while let Some(window) = queue.pop_front() {
+                let prop = dpy.exchange_request(has_property_request(window, wm_state))?;
+                if prop.ty.xid != 0 {
+                    client = window;
+                    break;
+                }
+
+                queue.extend(dpy.exchange_request(query_tree_request(window))?.children);
+            }
+        }
+
+        dpy.kill_client(KillTarget::Resource(client.xid))
+    }
+
+    /// Forcibly close the connection of the client this window belongs to, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn close_client_by_window_async<Dpy: AsyncDisplay + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+    ) -> crate::Result {
+        let wm_state = dpy
+            .intern_atom_immediate_async("WM_STATE".to_string(), true)
+            .await?;
+        let mut client = self;
+
+        if wm_state.xid != 0 {
+            let mut queue = VecDeque::from(vec![self]);
+            while let Some(window) = queue.pop_front() {
+                let prop = dpy
+                    .exchange_request_async(has_property_request(window, wm_state))
+                    .await?;
+                if prop.ty.xid != 0 {
+                    client = window;
+                    break;
+                }
+
+                queue.extend(
+                    dpy.exchange_request_async(query_tree_request(window))
+                        .await?
+                        .children,
+                );
+            }
+        }
+
+        dpy.kill_client_async(KillTarget::Resource(client.xid))
+            .await
+    }
+
+    /// Tell whether this window lists the given protocol, such as `WM_DELETE_WINDOW` or `WM_TAKE_FOCUS`,
+    /// in its `WM_PROTOCOLS` property.
+    #[inline]
+    pub fn supports_wm_protocol<Dpy: Display + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+        protocol: Atom,
+    ) -> crate::Result<bool> {
+        let wm_protocols = if let Some(wpa) = dpy.wm_protocols_atom() {
+            Atom::const_from_xid(wpa.get())
+        } else {
+            let wpa = dpy.intern_atom_immediate("WM_PROTOCOLS".to_string(), false)?;
+            if let Some(xid) = NonZeroU32::new(wpa.xid) {
+                dpy.set_wm_protocols_atom(xid);
+            }
+            wpa
+        };
+
+        let prop = dpy.exchange_request(wm_protocols_request(self, wm_protocols))?;
+        Ok(lists_atom(&prop, protocol))
+    }
+
+    /// Tell whether this window supports the given WM protocol, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn supports_wm_protocol_async<Dpy: AsyncDisplay + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+        protocol: Atom,
+    ) -> crate::Result<bool> {
+        let wm_protocols = if let Some(wpa) = dpy.wm_protocols_atom() {
+            Atom::const_from_xid(wpa.get())
+        } else {
+            let wpa = dpy
+                .intern_atom_immediate_async("WM_PROTOCOLS".to_string(), false)
+                .await?;
+            if let Some(xid) = NonZeroU32::new(wpa.xid) {
+                dpy.set_wm_protocols_atom(xid);
+            }
+            wpa
+        };
+
+        let prop = dpy
+            .exchange_request_async(wm_protocols_request(self, wm_protocols))
+            .await?;
+        Ok(lists_atom(&prop, protocol))
+    }
+
+    /// Politely ask the client that owns this window to close it. If the window supports `WM_DELETE_WINDOW`,
+    /// it is sent that message, which the client may handle however it likes (e.g. by asking to save any
+    /// unsaved work). Otherwise, the client is killed. If `time` is `None`, the time of the last event
+    /// received is used.
+    #[inline]
+    pub fn close_window<Dpy: Display + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+        time: Option<Timestamp>,
+    ) -> crate::Result {
+        let wm_delete_window = dpy.intern_atom_immediate("WM_DELETE_WINDOW".to_string(), false)?;
+        if self.supports_wm_protocol(dpy, wm_delete_window)? {
+            // supports_wm_protocol() populated the cache
+            let wm_protocols = dpy.wm_protocols_atom().map_or(0, NonZeroU32::get);
+            let time = time_or_last_event(dpy, time);
+            let event = delete_window_event(
+                self,
+                Atom::const_from_xid(wm_protocols),
+                wm_delete_window,
+                time,
+            );
+            dpy.send_event(false, self, EventMask::default(), event)
+        } else {
+            dpy.kill_client(KillTarget::Resource(self.xid))
+        }
+    }
+
+    /// Politely ask the client that owns this window to close it, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn close_window_async<Dpy: AsyncDisplay + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+        time: Option<Timestamp>,
+    ) -> crate::Result {
+        let wm_delete_window = dpy
+            .intern_atom_immediate_async("WM_DELETE_WINDOW".to_string(), false)
+            .await?;
+        if self
+            .supports_wm_protocol_async(dpy, wm_delete_window)
+            .await?
+        {
+            let wm_protocols = dpy.wm_protocols_atom().map_or(0, NonZeroU32::get);
+            let time = time_or_last_event(dpy, time);
+            let event = delete_window_event(
+                self,
+                Atom::const_from_xid(wm_protocols),
+                wm_delete_window,
+                time,
+            );
+            dpy.send_event_async(false, self, EventMask::default(), event)
+                .await
+        } else {
+            dpy.kill_client_async(KillTarget::Resource(self.xid)).await
+        }
+    }
+
     /// Set the event mask.
     #[inline]
     pub fn set_event_mask<Dpy: Display + ?Sized>(
@@ -955,6 +1233,69 @@ fn convert_get_window_attributes_reply(reply: GetWindowAttributesReply) -> Windo
     }
 }
 
+/// Request for a property without any of its value; the reply's type is zero if the property isn't set.
+#[inline]
+fn has_property_request(window: Window, property: Atom) -> GetPropertyRequest {
+    GetPropertyRequest {
+        window,
+        property,
+        ..Default::default()
+    }
+}
+
+/// Request for the whole `WM_PROTOCOLS` property of a window.
+#[inline]
+fn wm_protocols_request(window: Window, wm_protocols: Atom) -> GetPropertyRequest {
+    GetPropertyRequest {
+        window,
+        property: wm_protocols,
+        ty: ATOM_ATOM,
+        long_length: PROPERTY_CHUNK_LENGTH,
+        ..Default::default()
+    }
+}
+
+/// Tell whether a property of 32-bit atoms contains the given atom.
+#[inline]
+fn lists_atom(prop: &GetPropertyReply, atom: Atom) -> bool {
+    prop.format == 32
+        && prop
+            .value
+            .chunks_exact(4)
+            .any(|a| u32::from_ne_bytes([a[0], a[1], a[2], a[3]]) == atom.xid)
+}
+
+/// The `ClientMessage` that asks a window's client to close it.
+#[inline]
+fn delete_window_event(
+    window: Window,
+    wm_protocols: Atom,
+    wm_delete_window: Atom,
+    time: Timestamp,
+) -> Event {
+    let mut data = ClientMessageData::default();
+    let longs = data.longs_mut();
+    longs[0] = wm_delete_window.xid;
+    longs[1] = time;
+
+    Event::ClientMessage(ClientMessageEvent {
+        event_type: ClientMessageEvent::OPCODE,
+        format: 32,
+        window,
+        ty: wm_protocols,
+        data,
+        ..Default::default()
+    })
+}
+
+#[inline]
+fn query_tree_request(window: Window) -> QueryTreeRequest {
+    QueryTreeRequest {
+        window,
+        ..Default::default()
+    }
+}
+
 /// The type of the property being changed.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -1037,3 +1378,190 @@ pub enum PropertyFormat {
     Sixteen = 16,
     ThirtyTwo = 32,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ConfigWindow, ConfigureRequestEvent, StackMode, Window};
+    use crate::display::{mock_display, mock_setup, DisplayBase, MockConnection};
+
+    #[test]
+    fn configure_requests_only_hold_masked_fields() {
+        let mut value_mask = ConfigWindow::default();
+        value_mask
+            .set_width(true)
+            .set_height(true)
+            .set_stack_mode(true);
+        let event = ConfigureRequestEvent {
+            window: Window::const_from_xid(0x200),
+            sibling: Window::const_from_xid(0x300),
+            x: 50,
+            y: 60,
+            width: 800,
+            height: 600,
+            stack_mode: StackMode::Below,
+            value_mask,
+            ..Default::default()
+        };
+
+        let params = event.requested_parameters();
+        assert_eq!(params.x, None);
+        assert_eq!(params.y, None);
+        assert_eq!(params.width, Some(800));
+        assert_eq!(params.height, Some(600));
+        assert_eq!(params.border_width, None);
+        assert_eq!(params.sibling, None);
+        assert_eq!(params.stack_mode, Some(StackMode::Below));
+    }
+
+    #[test]
+    fn close_client_finds_wm_state() {
+        use crate::auto::xproto::{
+            Atom, GetPropertyReply, InternAtomReply, QueryTreeReply, Window,
+        };
+        use alloc::vec;
+
+        let frame = Window::const_from_xid(0x0040_0001);
+        let client = Window::const_from_xid(0x0200_0001);
+        let wm_state = Atom::const_from_xid(300);
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_reply(&InternAtomReply {
+            reply_type: 1,
+            sequence: 2,
+            atom: wm_state,
+            ..Default::default()
+        });
+        // the frame doesn't have WM_STATE set, but its only child does
+        conn.feed_reply(&GetPropertyReply {
+            reply_type: 1,
+            sequence: 3,
+            ..Default::default()
+        });
+        conn.feed_reply(&QueryTreeReply {
+            reply_type: 1,
+            sequence: 4,
+            length: 1,
+            children: vec![client],
+            ..Default::default()
+        });
+        conn.feed_reply(&GetPropertyReply {
+            reply_type: 1,
+            format: 32,
+            sequence: 5,
+            ty: wm_state,
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        display.set_checked(false);
+        frame.close_client_by_window(&mut display).unwrap();
+        drop(display);
+
+        let written = conn.take_outgoing();
+        let len = 2u16.to_ne_bytes();
+        let resource = client.xid.to_ne_bytes();
+        assert_eq!(
+            &written[written.len() - 8..],
+            &[
+                113,
+                0,
+                len[0],
+                len[1],
+                resource[0],
+                resource[1],
+                resource[2],
+                resource[3]
+            ][..]
+        );
+    }
+
+    #[test]
+    fn close_window_prefers_wm_delete_window() {
+        use crate::auto::xproto::{Atom, GetPropertyReply, InternAtomReply, Window};
+        use alloc::vec::Vec;
+
+        let window = Window::const_from_xid(0x0200_0001);
+        let wm_delete_window = Atom::const_from_xid(310);
+        let wm_protocols = Atom::const_from_xid(311);
+        let wm_take_focus = Atom::const_from_xid(312);
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_reply(&InternAtomReply {
+            reply_type: 1,
+            sequence: 2,
+            atom: wm_delete_window,
+            ..Default::default()
+        });
+        conn.feed_reply(&InternAtomReply {
+            reply_type: 1,
+            sequence: 3,
+            atom: wm_protocols,
+            ..Default::default()
+        });
+        let value = [wm_take_focus.xid, wm_delete_window.xid]
+            .iter()
+            .flat_map(|a| a.to_ne_bytes().to_vec())
+            .collect::<Vec<u8>>();
+        conn.feed_reply(&GetPropertyReply {
+            reply_type: 1,
+            format: 32,
+            sequence: 4,
+            length: 2,
+            ty: Atom::const_from_xid(4),
+            value_len: 2,
+            value,
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        display.set_checked(false);
+        window.close_window(&mut display, Some(1234)).unwrap();
+        assert_eq!(display.wm_protocols_atom().unwrap().get(), wm_protocols.xid);
+        drop(display);
+
+        // a SendEvent carrying the WM_PROTOCOLS client message
+        let written = conn.take_outgoing();
+        let request = &written[written.len() - 44..];
+        assert_eq!(request[0], 25);
+        let event = &request[12..];
+        assert_eq!(event[0], 33);
+        assert_eq!(event[1], 32);
+        assert_eq!(&event[4..8], &window.xid.to_ne_bytes());
+        assert_eq!(&event[8..12], &wm_protocols.xid.to_ne_bytes());
+        assert_eq!(&event[12..16], &wm_delete_window.xid.to_ne_bytes());
+        assert_eq!(&event[16..20], &1234u32.to_ne_bytes());
+    }
+
+    #[test]
+    fn close_window_kills_without_wm_delete_window() {
+        use crate::auto::xproto::{Atom, GetPropertyReply, InternAtomReply, Window};
+        use core::num::NonZeroU32;
+
+        let window = Window::const_from_xid(0x0200_0001);
+
+        let mut conn = MockConnection::with_setup(&mock_setup());
+        conn.feed_reply(&InternAtomReply {
+            reply_type: 1,
+            sequence: 2,
+            atom: Atom::const_from_xid(310),
+            ..Default::default()
+        });
+        // WM_PROTOCOLS isn't set on the window
+        conn.feed_reply(&GetPropertyReply {
+            reply_type: 1,
+            sequence: 3,
+            ..Default::default()
+        });
+
+        let mut display = mock_display(&mut conn);
+        display.set_checked(false);
+        display.set_wm_protocols_atom(NonZeroU32::new(311).unwrap());
+        window.close_window(&mut display, None).unwrap();
+        drop(display);
+
+        let written = conn.take_outgoing();
+        let request = &written[written.len() - 8..];
+        assert_eq!(request[0], 113);
+        assert_eq!(&request[4..8], &window.xid.to_ne_bytes());
+    }
+}
@@ -0,0 +1,217 @@
+// MIT/Apache2 License
+
+use super::WindowParameters;
+use crate::{
+    auto::xproto::{Visualid, Window, WindowClass},
+    display::{prelude::*, Display},
+};
+
+#[cfg(feature = "async")]
+use crate::{
+    auto::xproto::CreateWindowRequest,
+    display::{futures::ExchangeXidFuture, AsyncDisplay},
+    util::BoxedFnOnce,
+};
+
+/// A window that hasn't been created yet, returned by `DisplayXprotoExt::create_window_builder`. It starts
+/// out as a 1x1 `InputOutput` window at the parent's origin, with no border, no attributes, and the depth and
+/// visual of its parent; each of those can be changed before the window is created with `build`.
+#[derive(Debug)]
+#[must_use = "the window is not created until `build` is called"]
+pub struct WindowBuilder<'a, Dpy: ?Sized> {
+    display: &'a mut Dpy,
+    parent: Window,
+    class: WindowClass,
+    depth: Option<u8>,
+    visual: Option<Visualid>,
+    x: i16,
+    y: i16,
+    width: u16,
+    height: u16,
+    border_width: u16,
+    attributes: WindowParameters,
+}
+
+impl<'a, Dpy: ?Sized> WindowBuilder<'a, Dpy> {
+    #[inline]
+    pub fn new(display: &'a mut Dpy, parent: Window) -> Self {
+        Self {
+            display,
+            parent,
+            class: WindowClass::InputOutput,
+            depth: None,
+            visual: None,
+            x: 0,
+            y: 0,
+            width: 1,
+            height: 1,
+            border_width: 0,
+            attributes: WindowParameters::default(),
+        }
+    }
+
+    /// Set the position of the window, relative to its parent, and its size, not counting the border. The
+    /// width and height must not be zero.
+    #[inline]
+    pub fn geometry(self, x: i16, y: i16, width: u16, height: u16) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn border_width(self, border_width: u16) -> Self {
+        Self {
+            border_width,
+            ..self
+        }
+    }
+
+    /// Set the class of the window. An `InputOnly` window must have a border width and depth of zero, and
+    /// takes no attributes that affect how it is drawn.
+    #[inline]
+    pub fn class(self, class: WindowClass) -> Self {
+        Self { class, ..self }
+    }
+
+    /// Set the depth of the window, rather than taking its parent's. A window with a different depth than its
+    /// parent usually needs a `visual` and `colormap` of that depth, as well as a `border_pixel`, since it
+    /// can't share its parent's.
+    #[inline]
+    pub fn depth(self, depth: u8) -> Self {
+        Self {
+            depth: Some(depth),
+            ..self
+        }
+    }
+
+    /// Set the visual of the window, rather than taking its parent's.
+    #[inline]
+    pub fn visual(self, visual: Visualid) -> Self {
+        Self {
+            visual: Some(visual),
+            ..self
+        }
+    }
+
+    /// Set the attributes of the window, such as its background and the events it selects.
+    #[inline]
+    pub fn attributes(self, attributes: WindowParameters) -> Self {
+        Self { attributes, ..self }
+    }
+}
+
+impl<Dpy: Display + ?Sized> WindowBuilder<'_, Dpy> {
+    /// Create the window.
+    #[inline]
+    pub fn build(self) -> crate::Result<Window> {
+        self.display.create_window(
+            self.parent,
+            self.class,
+            self.depth,
+            self.visual,
+            self.x,
+            self.y,
+            self.width,
+            self.height,
+            self.border_width,
+            self.attributes,
+        )
+    }
+}
+
+#[cfg(feature = "async")]
+impl<'a, Dpy: AsyncDisplay + ?Sized> WindowBuilder<'a, Dpy> {
+    /// Create the window, async redox.
+    #[inline]
+    pub fn build_async(
+        self,
+    ) -> ExchangeXidFuture<
+        'a,
+        Dpy,
+        CreateWindowRequest,
+        Window,
+        BoxedFnOnce<Window, CreateWindowRequest>,
+    > {
+        self.display.create_window_async(
+            self.parent,
+            self.class,
+            self.depth,
+            self.visual,
+            self.x,
+            self.y,
+            self.width,
+            self.height,
+            self.border_width,
+            self.attributes,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        auto::xproto::Setup,
+        display::{mock_display, mock_setup, DisplayBase, MockConnection},
+    };
+
+    #[test]
+    fn window_builder_defaults() {
+        use crate::{
+            auto::xproto::{EventMask, Window, WindowClass},
+            display::traits::{DisplayXprotoExt, WindowParameters},
+        };
+
+        let setup = Setup {
+            resource_id_mask: 0xFF,
+            ..mock_setup()
+        };
+        let mut conn = MockConnection::with_setup(&setup);
+        let mut display = mock_display(&mut conn);
+        display.set_checked(false);
+        let root = Window::const_from_xid(0x100);
+        display
+            .create_window_builder(root)
+            .geometry(10, 20, 640, 480)
+            .build()
+            .unwrap();
+        display
+            .create_window_builder(root)
+            .class(WindowClass::InputOnly)
+            .attributes(WindowParameters {
+                event_mask: Some(EventMask::default()),
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+        drop(display);
+
+        let requests = conn.outgoing();
+        // both requests come last, the first with no values and the second with one
+        let first = &requests[requests.len() - 68..requests.len() - 36];
+        // copy the depth and visual from the parent
+        assert_eq!(first[1], 0);
+        assert_eq!(&first[8..12], &0x100u32.to_ne_bytes());
+        assert_eq!(&first[12..14], &10i16.to_ne_bytes());
+        assert_eq!(&first[16..18], &640u16.to_ne_bytes());
+        assert_eq!(&first[20..22], &[0, 0]);
+        assert_eq!(
+            &first[22..24],
+            &(WindowClass::InputOutput as u16).to_ne_bytes()
+        );
+        assert_eq!(&first[24..32], &[0; 8]);
+
+        // an unsized window still gets a size that the server accepts
+        let second = &requests[requests.len() - 36..];
+        assert_eq!(&second[16..18], &1u16.to_ne_bytes());
+        assert_eq!(&second[18..20], &1u16.to_ne_bytes());
+        assert_eq!(
+            &second[22..24],
+            &(WindowClass::InputOnly as u16).to_ne_bytes()
+        );
+    }
+}
@@ -0,0 +1,176 @@
+// MIT/Apache2 License
+
+use super::{time_or_last_event, PropertyFormat, PropertyType};
+use crate::{
+    auto::xproto::{
+        Atom, ChangePropertyRequest, ClientMessageEvent, EventMask, PropMode, Timestamp, Window,
+    },
+    auto::Event as _,
+    client_message_data::ClientMessageData,
+    display::{prelude::*, Display},
+    xid::XidType,
+    Event,
+};
+use alloc::string::ToString;
+
+#[cfg(feature = "async")]
+use crate::display::AsyncDisplay;
+
+/// The version of the XEMBED protocol that this library implements.
+pub const XEMBED_VERSION: u32 = 0;
+
+/// Flag in `_XEMBED_INFO` indicating that the client wants to be mapped.
+pub const XEMBED_MAPPED: u32 = 1 << 0;
+
+/// Detail for `XembedMessage::FocusIn`: do not move the focus inside of the client.
+pub const XEMBED_FOCUS_CURRENT: u32 = 0;
+/// Detail for `XembedMessage::FocusIn`: move the focus to the first element of the client.
+pub const XEMBED_FOCUS_FIRST: u32 = 1;
+/// Detail for `XembedMessage::FocusIn`: move the focus to the last element of the client.
+pub const XEMBED_FOCUS_LAST: u32 = 2;
+
+/// A message sent between an XEMBED embedder and its client. This is the second long in the data of an
+/// `_XEMBED` client message.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum XembedMessage {
+    EmbeddedNotify = 0,
+    WindowActivate = 1,
+    WindowDeactivate = 2,
+    RequestFocus = 3,
+    FocusIn = 4,
+    FocusOut = 5,
+    FocusNext = 6,
+    FocusPrev = 7,
+    ModalityOn = 10,
+    ModalityOff = 11,
+    RegisterAccelerator = 12,
+    UnregisterAccelerator = 13,
+    ActivateAccelerator = 14,
+}
+
+impl Window {
+    /// Send an XEMBED message to this window. `detail`, `data1` and `data2` depend on the message; for
+    /// instance, `FocusIn` takes one of the `XEMBED_FOCUS_*` constants as its detail, while
+    /// `EmbeddedNotify` takes the embedder's window as `data1` and its protocol version as `data2`. If no
+    /// timestamp is provided, the timestamp of the last event received is used, as the protocol requires a
+    /// real server time.
+    #[inline]
+    pub fn send_xembed_message<Dpy: Display + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+        message: XembedMessage,
+        detail: u32,
+        data1: u32,
+        data2: u32,
+        time: Option<Timestamp>,
+    ) -> crate::Result<()> {
+        let xembed_atom = retrieve_atom!(dpy, xembed_atom, set_xembed_atom, "_XEMBED");
+        let time = time_or_last_event(dpy, time);
+
+        dpy.send_event(
+            false,
+            self,
+            EventMask::default(),
+            xembed_event(self, xembed_atom, time, message, [detail, data1, data2]),
+        )
+    }
+
+    /// Send an XEMBED message to this window, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn send_xembed_message_async<Dpy: AsyncDisplay + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+        message: XembedMessage,
+        detail: u32,
+        data1: u32,
+        data2: u32,
+        time: Option<Timestamp>,
+    ) -> crate::Result<()> {
+        let xembed_atom = retrieve_atom_async!(dpy, xembed_atom, set_xembed_atom, "_XEMBED");
+        let time = time_or_last_event(dpy, time);
+
+        dpy.send_event_async(
+            false,
+            self,
+            EventMask::default(),
+            xembed_event(self, xembed_atom, time, message, [detail, data1, data2]),
+        )
+        .await
+    }
+
+    /// Set the `_XEMBED_INFO` property on this window, advertising the XEMBED version it supports and
+    /// whether or not it would like the embedder to map it. This should be set before the window is
+    /// embedded.
+    #[inline]
+    pub fn set_xembed_info<Dpy: Display + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+        version: u32,
+        mapped: bool,
+    ) -> crate::Result<()> {
+        let xembed_info_atom =
+            retrieve_atom!(dpy, xembed_info_atom, set_xembed_info_atom, "_XEMBED_INFO");
+        dpy.exchange_request(self.xembed_info_request(xembed_info_atom, version, mapped))
+    }
+
+    /// Set the `_XEMBED_INFO` property on this window, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn set_xembed_info_async<Dpy: AsyncDisplay + ?Sized>(
+        self,
+        dpy: &mut Dpy,
+        version: u32,
+        mapped: bool,
+    ) -> crate::Result<()> {
+        let xembed_info_atom =
+            retrieve_atom_async!(dpy, xembed_info_atom, set_xembed_info_atom, "_XEMBED_INFO");
+        dpy.exchange_request_async(self.xembed_info_request(xembed_info_atom, version, mapped))
+            .await
+    }
+
+    #[inline]
+    fn xembed_info_request(
+        self,
+        xembed_info_atom: Atom,
+        version: u32,
+        mapped: bool,
+    ) -> ChangePropertyRequest {
+        let flags = if mapped { XEMBED_MAPPED } else { 0 };
+        let mut cpr = self.change_property_request(
+            xembed_info_atom,
+            PropertyType::Cardinal,
+            PropertyFormat::ThirtyTwo,
+            PropMode::Replace,
+            &[version, flags],
+        );
+        // the property's type is the _XEMBED_INFO atom itself
+        cpr.ty = xembed_info_atom;
+        cpr
+    }
+}
+
+#[inline]
+fn xembed_event(
+    window: Window,
+    xembed_atom: Atom,
+    time: Timestamp,
+    message: XembedMessage,
+    rest: [u32; 3],
+) -> Event {
+    let mut data = ClientMessageData::default();
+    let longs = data.longs_mut();
+    longs[0] = time;
+    longs[1] = message as u32;
+    longs[2..].copy_from_slice(&rest);
+
+    Event::ClientMessage(ClientMessageEvent {
+        event_type: ClientMessageEvent::OPCODE,
+        format: 32,
+        window,
+        ty: xembed_atom,
+        data,
+        ..Default::default()
+    })
+}
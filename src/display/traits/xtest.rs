@@ -0,0 +1,131 @@
+// MIT/Apache2 License
+
+//! Synthetic pointer motion through the XTEST extension. Unlike `WarpPointer`, motion faked this way goes
+//! through the server's input processing as if it came from the device, so other clients see the same
+//! `MotionNotify` events they would for real motion.
+
+use crate::{
+    auto::{
+        xproto::{MotionNotifyEvent, Window, WINDOW_NONE},
+        xtest::FakeInputRequest,
+        Event as _,
+    },
+    display::{prelude::*, Display},
+    BreadError,
+};
+
+#[cfg(feature = "async")]
+use crate::display::{futures::ExchangeRequestFuture, AsyncDisplay, EitherFuture};
+#[cfg(feature = "async")]
+use futures_lite::future::{self, Ready};
+
+/// Fake motion request. For motion, `detail` says whether the coordinates are relative to the current
+/// position, and `root` picks the screen that absolute coordinates are on; `WINDOW_NONE` means the screen
+/// the pointer is already on.
+#[inline]
+fn fake_motion_request(relative: bool, root: Window, x: i16, y: i16) -> FakeInputRequest {
+    FakeInputRequest {
+        ty: MotionNotifyEvent::OPCODE,
+        detail: u8::from(relative),
+        root,
+        root_x: x,
+        root_y: y,
+        ..Default::default()
+    }
+}
+
+/// Fake motion request to a position on the given screen.
+#[inline]
+fn absolute_motion_request<Dpy: DisplayBase + ?Sized>(
+    dpy: &Dpy,
+    x: i16,
+    y: i16,
+    screen: usize,
+) -> crate::Result<FakeInputRequest> {
+    let root = dpy
+        .screens()
+        .get(screen)
+        .ok_or(BreadError::StaticMsg("Screen index out of range"))?
+        .root;
+    Ok(fake_motion_request(false, root, x, y))
+}
+
+pub trait DisplayXtestExt: Display {
+    /// Move the pointer by `dx` and `dy` from wherever it is now, as if the pointing device had been moved.
+    /// The pointer stays on its current screen.
+    #[inline]
+    fn move_pointer_relative(&mut self, dx: i16, dy: i16) -> crate::Result {
+        self.exchange_request(fake_motion_request(true, WINDOW_NONE, dx, dy))
+    }
+
+    /// Move the pointer to `x` and `y` on the root window of the screen with index `screen`, as if the
+    /// pointing device had been moved there. The pointer moves to that screen if it isn't already on it.
+    #[inline]
+    fn move_pointer_absolute(&mut self, x: i16, y: i16, screen: usize) -> crate::Result {
+        let fir = absolute_motion_request(self, x, y, screen)?;
+        self.exchange_request(fir)
+    }
+}
+
+impl<D: Display + ?Sized> DisplayXtestExt for D {}
+
+#[cfg(feature = "async")]
+pub trait AsyncDisplayXtestExt: AsyncDisplay {
+    /// Move the pointer by `dx` and `dy` from wherever it is now, async redox.
+    #[inline]
+    fn move_pointer_relative_async(
+        &mut self,
+        dx: i16,
+        dy: i16,
+    ) -> ExchangeRequestFuture<'_, Self, FakeInputRequest> {
+        self.exchange_request_async(fake_motion_request(true, WINDOW_NONE, dx, dy))
+    }
+
+    /// Move the pointer to `x` and `y` on the screen with index `screen`, async redox.
+    #[inline]
+    fn move_pointer_absolute_async(
+        &mut self,
+        x: i16,
+        y: i16,
+        screen: usize,
+    ) -> EitherFuture<Ready<crate::Result>, ExchangeRequestFuture<'_, Self, FakeInputRequest>> {
+        match absolute_motion_request(self, x, y, screen) {
+            Ok(fir) => EitherFuture::Right {
+                future: self.exchange_request_async(fir),
+            },
+            Err(e) => EitherFuture::Left {
+                future: future::ready(Err(e)),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D: AsyncDisplay + ?Sized> AsyncDisplayXtestExt for D {}
+
+#[cfg(test)]
+mod tests {
+    use super::fake_motion_request;
+    use crate::auto::{
+        xproto::{Window, WINDOW_NONE},
+        AsByteSequence,
+    };
+
+    #[test]
+    fn motion_flags_and_root() {
+        let mut bytes = [0; 36];
+
+        let relative = fake_motion_request(true, WINDOW_NONE, -3, 4);
+        relative.as_bytes(&mut bytes);
+        // MotionNotify, with the relative flag set and no root
+        assert_eq!(&bytes[4..6], &[6, 1]);
+        assert_eq!(&bytes[12..16], &[0; 4]);
+        assert_eq!(&bytes[24..26], &(-3i16).to_ne_bytes());
+        assert_eq!(&bytes[26..28], &4i16.to_ne_bytes());
+
+        let absolute = fake_motion_request(false, Window::const_from_xid(0x123), 10, 20);
+        absolute.as_bytes(&mut bytes);
+        assert_eq!(&bytes[4..6], &[6, 0]);
+        assert_eq!(&bytes[12..16], &0x123u32.to_ne_bytes());
+    }
+}
@@ -0,0 +1,145 @@
+// MIT/Apache2 License
+
+use crate::auto::xproto::{VisualClass, Visualid, Visualtype};
+use core::ops::Deref;
+
+/// A visual from the setup, with its class and color masks interpreted. This dereferences to the generated
+/// `Visualtype`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct VisualType<'a> {
+    visual: &'a Visualtype,
+}
+
+impl<'a> VisualType<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn new(visual: &'a Visualtype) -> Self {
+        Self { visual }
+    }
+
+    /// Get the generated `Visualtype` this wraps.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &'a Visualtype {
+        self.visual
+    }
+
+    /// The ID of this visual.
+    #[inline]
+    #[must_use]
+    pub const fn id(&self) -> Visualid {
+        self.visual.visual_id
+    }
+
+    /// The class of this visual, which determines how pixel values are turned into colors.
+    #[inline]
+    #[must_use]
+    pub const fn class(&self) -> VisualClass {
+        self.visual.class
+    }
+
+    /// Whether or not pixel values are made up of red, green and blue fields that map directly to colors,
+    /// without going through a writable colormap.
+    #[inline]
+    #[must_use]
+    pub fn is_true_color(&self) -> bool {
+        self.visual.class == VisualClass::TrueColor
+    }
+
+    /// Whether or not pixel values are split up into red, green and blue fields, which is the case for
+    /// `TrueColor` and `DirectColor` visuals. The masks are only meaningful for these visuals.
+    #[inline]
+    #[must_use]
+    pub fn has_rgb_fields(&self) -> bool {
+        matches!(
+            self.visual.class,
+            VisualClass::TrueColor | VisualClass::DirectColor
+        )
+    }
+
+    /// The bits of a pixel value that hold the red, green and blue fields, in that order.
+    #[inline]
+    #[must_use]
+    pub const fn rgb_masks(&self) -> (u32, u32, u32) {
+        (
+            self.visual.red_mask,
+            self.visual.green_mask,
+            self.visual.blue_mask,
+        )
+    }
+
+    /// How far the red, green and blue fields are shifted into a pixel value, in that order. A color
+    /// component can be put into a pixel with `(component << shift) & mask`, once it has been scaled down
+    /// to the width of its field.
+    #[inline]
+    #[must_use]
+    pub fn rgb_shifts(&self) -> (u32, u32, u32) {
+        let shift = |mask: u32| if mask == 0 { 0 } else { mask.trailing_zeros() };
+        let (red, green, blue) = self.rgb_masks();
+        (shift(red), shift(green), shift(blue))
+    }
+
+    /// The number of significant bits in each of the red, green and blue components of a color.
+    #[inline]
+    #[must_use]
+    pub const fn bits_per_rgb(&self) -> u8 {
+        self.visual.bits_per_rgb_value
+    }
+
+    /// The number of entries in a colormap made for this visual.
+    #[inline]
+    #[must_use]
+    pub const fn colormap_entries(&self) -> u16 {
+        self.visual.colormap_entries
+    }
+}
+
+impl<'a> From<&'a Visualtype> for VisualType<'a> {
+    #[inline]
+    fn from(visual: &'a Visualtype) -> Self {
+        Self::new(visual)
+    }
+}
+
+impl Deref for VisualType<'_> {
+    type Target = Visualtype;
+
+    #[inline]
+    fn deref(&self) -> &Visualtype {
+        self.visual
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VisualType;
+    use crate::auto::xproto::{VisualClass, Visualtype};
+
+    #[test]
+    fn true_color_masks() {
+        let visual = Visualtype {
+            visual_id: 0x21,
+            class: VisualClass::TrueColor,
+            bits_per_rgb_value: 8,
+            colormap_entries: 256,
+            red_mask: 0x00FF_0000,
+            green_mask: 0x0000_FF00,
+            blue_mask: 0x0000_00FF,
+        };
+        let visual = VisualType::new(&visual);
+        assert!(visual.is_true_color());
+        assert!(visual.has_rgb_fields());
+        assert_eq!(visual.rgb_masks(), (0x00FF_0000, 0x0000_FF00, 0xFF));
+        assert_eq!(visual.rgb_shifts(), (16, 8, 0));
+        assert_eq!(visual.bits_per_rgb(), 8);
+
+        let pseudo = Visualtype {
+            class: VisualClass::PseudoColor,
+            ..Default::default()
+        };
+        let pseudo = VisualType::from(&pseudo);
+        assert!(!pseudo.is_true_color());
+        assert!(!pseudo.has_rgb_fields());
+        assert_eq!(pseudo.rgb_shifts(), (0, 0, 0));
+    }
+}
@@ -2,10 +2,14 @@
 
 //! This module provides structures used in error handling of `breadx` functions.
 
+use crate::auto::xproto::GrabStatus;
 use alloc::{borrow::Cow, string::String, sync::Arc};
 use core::{fmt, ops::Deref};
 #[cfg(feature = "std")]
-use std::{error::Error as StdError, io::Error as IoError};
+use std::{
+    error::Error as StdError,
+    io::{Error as IoError, ErrorKind},
+};
 
 /// The common error type returned by `breadx` functions.
 #[derive(Debug, Clone)]
@@ -24,10 +28,18 @@ pub enum BreadError {
     FailedToConnect,
     /// X11 server rejected our authorization.
     FailedToAuthorize,
+    /// The X11 server speaks a major version of the protocol other than 11.
+    UnsupportedProtocolVersion {
+        major: u16,
+        minor: u16,
+    },
     /// BadReadError
     BadObjectRead(Option<&'static str>),
-    /// Required extension was not present.
-    ExtensionNotPresent(Cow<'static, str>),
+    /// Required extension was not present. `request` is the name of the request that needed it.
+    ExtensionNotPresent {
+        extension: Cow<'static, str>,
+        request: &'static str,
+    },
     /// Required request was not present.
     NoMatchingRequest(u16),
     /// An error propogated by the X11 server.
@@ -45,6 +57,33 @@ pub enum BreadError {
     LoadLibraryFailed(&'static str),
     /// Operation would block an async operation.
     WouldBlock,
+    /// The connection was lost and has been established again. Server-side resources, grabs and pending
+    /// requests from before the reconnection no longer exist.
+    Reconnected,
+    /// An image's data is not exactly as long as the number of bytes per scanline that the image was created
+    /// with calls for. Sending it anyway would shear the image. `expected` and `actual` are lengths in bytes.
+    ImageStrideMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    /// The server refused to grab the pointer or keyboard.
+    GrabFailed(GrabStatus),
+    /// A single scanline of an image is longer than the server can send in one reply, so the image can't
+    /// be read even one scanline at a time.
+    ScanlineTooLong {
+        scanline_len: usize,
+        max_len: usize,
+    },
+    /// The bytes read from the connection don't form a valid reply or event, so the display has lost
+    /// track of where packets begin. `resync` on the display can be used to recover.
+    ProtocolDesync,
+    /// A request carries a different number of file descriptors than it declares. It was not sent, since
+    /// the server would have paired the file descriptors with the wrong request.
+    FdCountMismatch {
+        request: &'static str,
+        expected: usize,
+        got: usize,
+    },
 }
 
 impl BreadError {
@@ -70,7 +109,12 @@ impl BreadError {
 impl From<IoError> for BreadError {
     #[inline]
     fn from(io: IoError) -> Self {
-        Self::Io(Arc::new(io))
+        // the server hanging up on us mid-read is a closed connection, not a generic IO failure
+        if io.kind() == ErrorKind::UnexpectedEof {
+            Self::ClosedConnection
+        } else {
+            Self::Io(Arc::new(io))
+        }
     }
 }
 
@@ -85,6 +129,7 @@ impl fmt::Display for BreadError {
             Self::UnableToOpenConnection => f.write_str("Unable to open connection to X11 server"),
             Self::FailedToConnect => f.write_str("Unable to connect to the X11 server"),
             Self::FailedToAuthorize => f.write_str("Authorization was rejected by the X11 server"),
+            Self::UnsupportedProtocolVersion { major, minor } => write!(f, "The X11 server uses protocol version {}.{}, but only version 11 is supported", major, minor),
             Self::BadObjectRead(name) => write!(
                 f,
                 "Unable to read object of type from bytes: {}",
@@ -92,7 +137,7 @@ impl fmt::Display for BreadError {
             ),
             Self::Tainted => f.write_str("Connection is tainted by an incomplete send"),
             Self::NoMatchingRequest(seq) => write!(f, "Received reply with non-matching sequence {}", seq),
-            Self::ExtensionNotPresent(ext) => write!(f, "Extension was not found on X server: {}", ext),
+            Self::ExtensionNotPresent { extension, request } => write!(f, "Extension was not found on X server: {} (needed by the {} request)", extension, request),
             Self::XProtocol {
                 error_code,
                 minor_code,
@@ -106,6 +151,12 @@ impl fmt::Display for BreadError {
             Self::ClosedConnection => f.write_str("The X connection closed without our end of the connection closing. Did you forget to listen for WM_DELTE_WINDOW?"),
             Self::LoadLibraryFailed(l) => write!(f, "Failed to load library: {}", l),
             Self::WouldBlock => f.write_str("Operation would block an async function"),
+            Self::Reconnected => f.write_str("The X connection was lost and has been re-established"),
+            Self::ImageStrideMismatch { expected, actual } => write!(f, "Image data holds {} bytes, but its scanlines call for {}", actual, expected),
+            Self::GrabFailed(status) => write!(f, "The grab failed with status {:?}", status),
+            Self::ScanlineTooLong { scanline_len, max_len } => write!(f, "A scanline of {} bytes does not fit in a reply, which can hold at most {} bytes", scanline_len, max_len),
+            Self::ProtocolDesync => f.write_str("Lost track of packet boundaries in the X connection"),
+            Self::FdCountMismatch { request, expected, got } => write!(f, "{} request declares {} file descriptors, but carries {}", request, expected, got),
             #[cfg(feature = "std")]
             Self::Io(i) => fmt::Display::fmt(&*i, f),
         }
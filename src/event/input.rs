@@ -38,6 +38,8 @@ impl AsByteSequence for InputEvent {
 
     #[inline]
     fn from_bytes(_bytes: &[u8]) -> Option<(Self, usize)> {
-        panic!("Unable to deserialize InputEvent from bytes")
+        // input events can't be told apart yet, so there's nothing to parse them into
+        log::warn!("Unable to deserialize InputEvent from bytes");
+        None
     }
 }
@@ -4,6 +4,8 @@
 
 #[cfg(feature = "input")]
 pub mod input;
+#[cfg(feature = "xkb")]
+pub mod xkb;
 
 use crate::auto::{
     xproto::{
@@ -13,14 +15,15 @@ use crate::auto::{
         GraphicsExposureEvent, GravityNotifyEvent, KeyPressEvent, KeyReleaseEvent,
         KeymapNotifyEvent, LeaveNotifyEvent, MapNotifyEvent, MapRequestEvent, MappingNotifyEvent,
         NoExposureEvent, PropertyNotifyEvent, ReparentNotifyEvent, ResizeRequestEvent,
-        SelectionClearEvent, SelectionNotifyEvent, SelectionRequestEvent, UnmapNotifyEvent,
-        VisibilityNotifyEvent,
+        SelectionClearEvent, SelectionNotifyEvent, SelectionRequestEvent, Timestamp,
+        UnmapNotifyEvent, VisibilityNotifyEvent,
     },
     AsByteSequence, Event as AutoEvent,
 };
 use tinyvec::TinyVec;
 
-const OPCODE_MASK: u8 = !0x80;
+/// Mask that removes the bit the server sets on events that were generated by `SendEvent`.
+pub(crate) const OPCODE_MASK: u8 = !0x80;
 
 #[derive(Debug)]
 pub enum Event {
@@ -55,6 +58,8 @@ pub enum Event {
     SelectionRequest(SelectionRequestEvent),
     UnmapNotify(UnmapNotifyEvent),
     VisibilityNotify(VisibilityNotifyEvent),
+    #[cfg(feature = "xkb")]
+    Xkb(xkb::XkbEvent),
     NoneOfTheAbove {
         opcode: u8,
         bytes: TinyVec<[u8; 32]>,
@@ -114,6 +119,11 @@ impl Event {
             Self::SelectionRequest(sre) => sre.as_bytes(bytes),
             Self::UnmapNotify(une) => une.as_bytes(bytes),
             Self::VisibilityNotify(vne) => vne.as_bytes(bytes),
+            #[cfg(feature = "xkb")]
+            Self::Xkb(xe) => {
+                xe.as_bytes(bytes);
+                0
+            }
             Self::NoneOfTheAbove { bytes: b, .. } => {
                 (&mut bytes[0..b.len()]).copy_from_slice(b);
                 0
@@ -121,6 +131,75 @@ impl Event {
         };
     }
 
+    /// The server timestamp carried by this event, if it carries one. Events that carry `CurrentTime` (zero),
+    /// which can happen for selection events forwarded from other clients, return `None`.
+    #[inline]
+    #[must_use]
+    pub fn timestamp(&self) -> Option<Timestamp> {
+        let time = match self {
+            Self::ButtonPress(bpe) => bpe.time,
+            Self::ButtonRelease(bre) => bre.time,
+            Self::EnterNotify(ene) => ene.time,
+            Self::KeyPress(kpe) => kpe.time,
+            Self::KeyRelease(kre) => kre.time,
+            Self::LeaveNotify(lne) => lne.time,
+            Self::PropertyNotify(pne) => pne.time,
+            Self::SelectionClear(sce) => sce.time,
+            Self::SelectionNotify(sne) => sne.time,
+            Self::SelectionRequest(sre) => sre.time,
+            #[cfg(feature = "xkb")]
+            Self::Xkb(xe) => xe.time(),
+            _ => return None,
+        };
+
+        if time == 0 {
+            None
+        } else {
+            Some(time)
+        }
+    }
+
+    /// The number of bytes this event occupies when serialized.
+    #[inline]
+    pub(crate) fn size(&self) -> usize {
+        match self {
+            Self::ConfigureNotify(cne) => cne.size(),
+            Self::ClientMessage(cne) => cne.size(),
+            Self::Expose(ee) => ee.size(),
+            Self::ButtonPress(bpe) => bpe.size(),
+            Self::ButtonRelease(bre) => bre.size(),
+            Self::CirculateNotify(cne) => cne.size(),
+            Self::CirculateRequest(cre) => cre.size(),
+            Self::ConfigureRequest(cre) => cre.size(),
+            Self::CreateNotify(cne) => cne.size(),
+            Self::DestroyNotify(dne) => dne.size(),
+            Self::EnterNotify(ene) => ene.size(),
+            Self::FocusIn(fie) => fie.size(),
+            Self::FocusOut(foe) => foe.size(),
+            Self::GraphicsExposure(gee) => gee.size(),
+            Self::GravityNotify(gne) => gne.size(),
+            Self::KeyPress(kpe) => kpe.size(),
+            Self::KeyRelease(kre) => kre.size(),
+            Self::KeymapNotify(kne) => kne.size(),
+            Self::LeaveNotify(lne) => lne.size(),
+            Self::MapNotify(mne) => mne.size(),
+            Self::MapRequest(mre) => mre.size(),
+            Self::MappingNotify(mne) => mne.size(),
+            Self::NoExposure(nee) => nee.size(),
+            Self::PropertyNotify(pne) => pne.size(),
+            Self::ReparentNotify(rne) => rne.size(),
+            Self::ResizeRequest(rre) => rre.size(),
+            Self::SelectionClear(sce) => sce.size(),
+            Self::SelectionNotify(sne) => sne.size(),
+            Self::SelectionRequest(sre) => sre.size(),
+            Self::UnmapNotify(une) => une.size(),
+            Self::VisibilityNotify(vne) => vne.size(),
+            #[cfg(feature = "xkb")]
+            Self::Xkb(xe) => xe.size(),
+            Self::NoneOfTheAbove { bytes, .. } => bytes.len(),
+        }
+    }
+
     #[allow(clippy::too_many_lines)]
     #[inline]
     pub(crate) fn differentiate(&mut self) -> crate::Result {
@@ -269,6 +348,19 @@ impl Event {
         Ok(())
     }
 
+    /// If this is an event from the XKB extension, whose first event is `first_event`, decode it as one.
+    #[cfg(feature = "xkb")]
+    #[inline]
+    pub(crate) fn differentiate_xkb(&mut self, first_event: u8) -> crate::Result {
+        if let Event::NoneOfTheAbove { opcode, bytes } = self {
+            if *opcode == first_event {
+                *self = Self::Xkb(xkb::XkbEvent::from_bytes(core::mem::take(bytes))?);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Get the opcode of this event.
     #[inline]
     #[must_use]
@@ -305,7 +397,107 @@ impl Event {
             Self::SelectionRequest(_) => SelectionRequestEvent::OPCODE,
             Self::UnmapNotify(_) => UnmapNotifyEvent::OPCODE,
             Self::VisibilityNotify(_) => VisibilityNotifyEvent::OPCODE,
+            #[cfg(feature = "xkb")]
+            Self::Xkb(xe) => xe.event_type() & OPCODE_MASK,
             Self::NoneOfTheAbove { opcode, .. } => *opcode,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Event;
+    use crate::auto::xproto::{Point, Rectangle};
+    use tinyvec::TinyVec;
+
+    /// Lay out an event the way the server sends it: the code, a byte that's unused by these events, the
+    /// sequence number, then the body.
+    fn wire_event(code: u8, body: &[&[u8]]) -> TinyVec<[u8; 32]> {
+        let mut bytes = TinyVec::from([0; 32]);
+        bytes[0] = code;
+        bytes[2..4].copy_from_slice(&7u16.to_ne_bytes());
+        let mut index = 4;
+        for field in body {
+            bytes[index..index + field.len()].copy_from_slice(field);
+            index += field.len();
+        }
+        bytes
+    }
+
+    #[test]
+    fn substructure_events_are_decoded() {
+        let root = 0x100u32.to_ne_bytes();
+        let window = 0x200u32.to_ne_bytes();
+        let frame = 0x300u32.to_ne_bytes();
+
+        let create = wire_event(
+            16,
+            &[
+                &root,
+                &window,
+                &(-5i16).to_ne_bytes(),
+                &10i16.to_ne_bytes(),
+                &640u16.to_ne_bytes(),
+                &480u16.to_ne_bytes(),
+                &2u16.to_ne_bytes(),
+                &[1],
+            ],
+        );
+        match Event::from_bytes(create).unwrap() {
+            Event::CreateNotify(cne) => {
+                assert_eq!(cne.parent.xid, 0x100);
+                assert_eq!(cne.window.xid, 0x200);
+                assert_eq!(cne.geometry(), Rectangle::new(-5, 10, 640, 480));
+                assert_eq!(cne.border_width, 2);
+                assert!(cne.override_redirect);
+            }
+            e => panic!("expected CreateNotify, got {:?}", e),
+        }
+
+        match Event::from_bytes(wire_event(17, &[&root, &window])).unwrap() {
+            Event::DestroyNotify(dne) => {
+                assert_eq!(dne.event.xid, 0x100);
+                assert_eq!(dne.window.xid, 0x200);
+            }
+            e => panic!("expected DestroyNotify, got {:?}", e),
+        }
+
+        match Event::from_bytes(wire_event(18, &[&root, &window, &[1]])).unwrap() {
+            Event::UnmapNotify(une) => {
+                assert_eq!(une.window.xid, 0x200);
+                assert!(une.from_configure);
+            }
+            e => panic!("expected UnmapNotify, got {:?}", e),
+        }
+
+        match Event::from_bytes(wire_event(19, &[&root, &window, &[0]])).unwrap() {
+            Event::MapNotify(mne) => {
+                assert_eq!(mne.event.xid, 0x100);
+                assert_eq!(mne.window.xid, 0x200);
+                assert!(!mne.override_redirect);
+            }
+            e => panic!("expected MapNotify, got {:?}", e),
+        }
+
+        let reparent = wire_event(
+            21,
+            &[
+                &root,
+                &window,
+                &frame,
+                &3i16.to_ne_bytes(),
+                &(-4i16).to_ne_bytes(),
+                &[1],
+            ],
+        );
+        match Event::from_bytes(reparent).unwrap() {
+            Event::ReparentNotify(rne) => {
+                assert_eq!(rne.window.xid, 0x200);
+                assert_eq!(rne.parent.xid, 0x300);
+                assert_eq!(rne.position(), Point::new(3, -4));
+                assert!(rne.override_redirect);
+            }
+            e => panic!("expected ReparentNotify, got {:?}", e),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+// MIT/Apache2 License
+
+#![cfg(feature = "xkb")]
+
+use crate::auto::{
+    xkb::{MapNotifyEvent, StateNotifyEvent},
+    xproto::Timestamp,
+    AsByteSequence, Event as AutoEvent,
+};
+use tinyvec::TinyVec;
+
+/// An event sent by the XKB extension. Every XKB event shares the same event code, which is the
+/// extension's first event; the kind of XKB event is told apart by the `xkb_type` byte that follows it.
+#[derive(Debug)]
+pub enum XkbEvent {
+    MapNotify(MapNotifyEvent),
+    StateNotify(StateNotifyEvent),
+    NoneOfTheAbove {
+        xkb_type: u8,
+        bytes: TinyVec<[u8; 32]>,
+    },
+}
+
+impl XkbEvent {
+    #[inline]
+    pub(crate) fn from_bytes(bytes: TinyVec<[u8; 32]>) -> crate::Result<Self> {
+        let xkb_type = bytes[1];
+        Ok(if xkb_type == MapNotifyEvent::OPCODE {
+            let e = MapNotifyEvent::from_bytes(&bytes)
+                .ok_or(crate::BreadError::BadObjectRead(Some("MapNotifyEvent")))?;
+            Self::MapNotify(e.0)
+        } else if xkb_type == StateNotifyEvent::OPCODE {
+            let e = StateNotifyEvent::from_bytes(&bytes)
+                .ok_or(crate::BreadError::BadObjectRead(Some("StateNotifyEvent")))?;
+            Self::StateNotify(e.0)
+        } else {
+            Self::NoneOfTheAbove { xkb_type, bytes }
+        })
+    }
+
+    #[inline]
+    pub(crate) fn as_bytes(&self, bytes: &mut [u8]) {
+        match self {
+            Self::MapNotify(mne) => {
+                mne.as_bytes(bytes);
+            }
+            Self::StateNotify(sne) => {
+                sne.as_bytes(bytes);
+            }
+            Self::NoneOfTheAbove { bytes: b, .. } => {
+                bytes[0..b.len()].copy_from_slice(b);
+            }
+        }
+    }
+
+    #[inline]
+    pub(crate) fn size(&self) -> usize {
+        match self {
+            Self::MapNotify(mne) => mne.size(),
+            Self::StateNotify(sne) => sne.size(),
+            Self::NoneOfTheAbove { bytes, .. } => bytes.len(),
+        }
+    }
+
+    /// The event code this event was sent with; this is the XKB extension's first event.
+    #[inline]
+    #[must_use]
+    pub fn event_type(&self) -> u8 {
+        match self {
+            Self::MapNotify(mne) => mne.event_type,
+            Self::StateNotify(sne) => sne.event_type,
+            Self::NoneOfTheAbove { bytes, .. } => bytes[0],
+        }
+    }
+
+    /// The kind of XKB event this is.
+    #[inline]
+    #[must_use]
+    pub fn xkb_type(&self) -> u8 {
+        match self {
+            Self::MapNotify(_) => MapNotifyEvent::OPCODE,
+            Self::StateNotify(_) => StateNotifyEvent::OPCODE,
+            Self::NoneOfTheAbove { xkb_type, .. } => *xkb_type,
+        }
+    }
+
+    /// The server timestamp carried by this event. Every XKB event carries one.
+    #[inline]
+    #[must_use]
+    pub fn time(&self) -> Timestamp {
+        match self {
+            Self::MapNotify(mne) => mne.time,
+            Self::StateNotify(sne) => sne.time,
+            Self::NoneOfTheAbove { bytes, .. } => {
+                Timestamp::from_ne_bytes([bytes[4], bytes[5], bytes[6], bytes[7]])
+            }
+        }
+    }
+}
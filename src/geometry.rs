@@ -0,0 +1,404 @@
+// MIT/Apache2 License
+
+//! Constructors and math for the geometry types used in drawing requests, as well as ways of getting them
+//! out of the events that carry them. The types themselves are generated, so these are implemented here
+//! instead.
+
+use crate::auto::xproto::{
+    Arc, ConfigureNotifyEvent, CreateNotifyEvent, ExposeEvent, GraphicsExposureEvent, Point,
+    Rectangle, ReparentNotifyEvent, Segment,
+};
+use core::{
+    convert::TryFrom,
+    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+};
+
+impl Point {
+    #[inline]
+    #[must_use]
+    pub const fn new(x: i16, y: i16) -> Self {
+        Self { x, y }
+    }
+}
+
+/// Arithmetic on points saturates: a coordinate that would go past the range of an `i16` is clamped to
+/// `i16::MIN` or `i16::MAX` rather than overflowing, the same way rectangles that grow too big are cut short.
+impl Add for Point {
+    type Output = Point;
+
+    #[inline]
+    fn add(self, other: Point) -> Point {
+        Point::new(
+            self.x.saturating_add(other.x),
+            self.y.saturating_add(other.y),
+        )
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    #[inline]
+    fn sub(self, other: Point) -> Point {
+        Point::new(
+            self.x.saturating_sub(other.x),
+            self.y.saturating_sub(other.y),
+        )
+    }
+}
+
+/// Negating `i16::MIN` gives `i16::MAX`, since its opposite doesn't fit.
+impl Neg for Point {
+    type Output = Point;
+
+    #[inline]
+    fn neg(self) -> Point {
+        Point::new(self.x.saturating_neg(), self.y.saturating_neg())
+    }
+}
+
+impl AddAssign for Point {
+    #[inline]
+    fn add_assign(&mut self, other: Point) {
+        self.x = self.x.saturating_add(other.x);
+        self.y = self.y.saturating_add(other.y);
+    }
+}
+
+impl SubAssign for Point {
+    #[inline]
+    fn sub_assign(&mut self, other: Point) {
+        self.x = self.x.saturating_sub(other.x);
+        self.y = self.y.saturating_sub(other.y);
+    }
+}
+
+impl Rectangle {
+    #[inline]
+    #[must_use]
+    pub const fn new(x: i16, y: i16, width: u16, height: u16) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// The top left corner of this rectangle.
+    #[inline]
+    #[must_use]
+    pub const fn origin(&self) -> Point {
+        Point::new(self.x, self.y)
+    }
+
+    /// Whether or not this rectangle covers no area.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Tell whether or not the point lies inside of this rectangle. The left and top edges are inside of
+    /// the rectangle, while the right and bottom edges are not, so that rectangles tiled next to each other
+    /// never both contain the same point.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, point: Point) -> bool {
+        let (left, top, right, bottom) = self.edges();
+        (left..right).contains(&i32::from(point.x)) && (top..bottom).contains(&i32::from(point.y))
+    }
+
+    /// The area that this rectangle and `other` have in common, or `None` if they don't overlap.
+    #[inline]
+    #[must_use]
+    pub fn intersection(&self, other: &Rectangle) -> Option<Rectangle> {
+        let (left1, top1, right1, bottom1) = self.edges();
+        let (left2, top2, right2, bottom2) = other.edges();
+
+        let left = left1.max(left2);
+        let top = top1.max(top2);
+        let right = right1.min(right2);
+        let bottom = bottom1.min(bottom2);
+        if left >= right || top >= bottom {
+            return None;
+        }
+
+        Some(Rectangle::from_edges(left, top, right, bottom))
+    }
+
+    /// The smallest rectangle that contains both this rectangle and `other`. Empty rectangles are ignored.
+    /// If the result would be wider or taller than the protocol allows, it is cut short.
+    #[inline]
+    #[must_use]
+    pub fn union(&self, other: &Rectangle) -> Rectangle {
+        if other.is_empty() {
+            return self.clone();
+        }
+        if self.is_empty() {
+            return other.clone();
+        }
+
+        let (left1, top1, right1, bottom1) = self.edges();
+        let (left2, top2, right2, bottom2) = other.edges();
+        Rectangle::from_edges(
+            left1.min(left2),
+            top1.min(top2),
+            right1.max(right2),
+            bottom1.max(bottom2),
+        )
+    }
+
+    /// The left, top, right and bottom edges of this rectangle. These are wider than the fields, since the
+    /// right and bottom edges can lie outside of the range of an `i16`.
+    #[inline]
+    fn edges(&self) -> (i32, i32, i32, i32) {
+        let left = i32::from(self.x);
+        let top = i32::from(self.y);
+        (
+            left,
+            top,
+            left + i32::from(self.width),
+            top + i32::from(self.height),
+        )
+    }
+
+    /// Build a rectangle from edges computed by `edges`. The left and top edges always come from one of the
+    /// rectangles' fields, so they fit.
+    #[inline]
+    fn from_edges(left: i32, top: i32, right: i32, bottom: i32) -> Self {
+        let length = |len: i32| u16::try_from(len).unwrap_or(u16::MAX);
+        Rectangle::new(
+            left as i16,
+            top as i16,
+            length(right - left),
+            length(bottom - top),
+        )
+    }
+}
+
+impl Arc {
+    /// Create a new arc, bounded by the given rectangle. The angles are in 64ths of a degree: `angle1` is
+    /// where the arc starts, relative to three o'clock, and `angle2` is how far it extends from there, with
+    /// positive angles running counterclockwise.
+    #[inline]
+    #[must_use]
+    pub const fn new(x: i16, y: i16, width: u16, height: u16, angle1: i16, angle2: i16) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            angle1,
+            angle2,
+        }
+    }
+
+    /// The rectangle that bounds this arc.
+    #[inline]
+    #[must_use]
+    pub const fn bounds(&self) -> Rectangle {
+        Rectangle::new(self.x, self.y, self.width, self.height)
+    }
+}
+
+impl Segment {
+    #[inline]
+    #[must_use]
+    pub const fn new(start: Point, end: Point) -> Self {
+        Self {
+            x1: start.x,
+            y1: start.y,
+            x2: end.x,
+            y2: end.y,
+        }
+    }
+
+    /// The point this segment starts at.
+    #[inline]
+    #[must_use]
+    pub const fn start(&self) -> Point {
+        Point::new(self.x1, self.y1)
+    }
+
+    /// The point this segment ends at.
+    #[inline]
+    #[must_use]
+    pub const fn end(&self) -> Point {
+        Point::new(self.x2, self.y2)
+    }
+}
+
+impl ExposeEvent {
+    /// The area of the window that needs to be redrawn. If `count` is zero, this is the last area in this
+    /// batch of exposures.
+    #[inline]
+    #[must_use]
+    pub fn rectangle(&self) -> Rectangle {
+        Rectangle::new(
+            exposure_offset(self.x),
+            exposure_offset(self.y),
+            self.width,
+            self.height,
+        )
+    }
+}
+
+impl GraphicsExposureEvent {
+    /// The area of the destination that couldn't be copied and needs to be redrawn. If `count` is zero,
+    /// this is the last area in this batch of exposures.
+    #[inline]
+    #[must_use]
+    pub fn rectangle(&self) -> Rectangle {
+        Rectangle::new(
+            exposure_offset(self.x),
+            exposure_offset(self.y),
+            self.width,
+            self.height,
+        )
+    }
+}
+
+impl ConfigureNotifyEvent {
+    /// The window's new position, relative to its parent, and size. The size does not include the border,
+    /// which is `border_width` wide on every side.
+    #[inline]
+    #[must_use]
+    pub const fn geometry(&self) -> Rectangle {
+        Rectangle::new(self.x, self.y, self.width, self.height)
+    }
+}
+
+impl CreateNotifyEvent {
+    /// The new window's position, relative to its parent, and size, not including the border.
+    #[inline]
+    #[must_use]
+    pub const fn geometry(&self) -> Rectangle {
+        Rectangle::new(self.x, self.y, self.width, self.height)
+    }
+}
+
+impl ReparentNotifyEvent {
+    /// The window's position relative to its new parent.
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> Point {
+        Point::new(self.x, self.y)
+    }
+}
+
+/// Exposures are measured from the window's origin, so they're unsigned, unlike the coordinates of a
+/// rectangle. Windows are almost never big enough for this to matter, but if they are, the offset is cut
+/// short.
+#[inline]
+fn exposure_offset(offset: u16) -> i16 {
+    i16::try_from(offset).unwrap_or(i16::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::auto::{
+        xproto::{ConfigureNotifyEvent, ExposeEvent, Point, Rectangle, Segment},
+        AsByteSequence,
+    };
+
+    #[test]
+    fn point_arithmetic() {
+        let mut p = Point::new(3, 4) + Point::new(1, -6);
+        assert_eq!(p, Point::new(4, -2));
+        p -= Point::new(4, 4);
+        assert_eq!(p, Point::new(0, -6));
+        assert_eq!(-p, Point::new(0, 6));
+        assert_eq!(Point::new(1, 1) - Point::new(2, 3), Point::new(-1, -2));
+
+        // coordinates clamp instead of overflowing
+        assert_eq!(-Point::new(i16::MIN, 0), Point::new(i16::MAX, 0));
+        assert_eq!(
+            Point::new(i16::MAX, i16::MIN) + Point::new(1, -1),
+            Point::new(i16::MAX, i16::MIN)
+        );
+        let mut q = Point::new(i16::MIN, 0);
+        q -= Point::new(1, 0);
+        assert_eq!(q, Point::new(i16::MIN, 0));
+    }
+
+    #[test]
+    fn rectangle_hit_testing() {
+        let rect = Rectangle::new(10, 10, 20, 10);
+        assert!(rect.contains(Point::new(10, 10)));
+        assert!(rect.contains(Point::new(29, 19)));
+        // the right and bottom edges aren't inside
+        assert!(!rect.contains(Point::new(30, 15)));
+        assert!(!rect.contains(Point::new(15, 20)));
+        assert!(!Rectangle::new(0, 0, 0, 5).contains(Point::new(0, 0)));
+
+        // the far edges can go past i16::MAX
+        let edge = Rectangle::new(i16::MAX - 1, 0, 10, 10);
+        assert!(edge.contains(Point::new(i16::MAX, 5)));
+    }
+
+    #[test]
+    fn rectangle_intersection_and_union() {
+        let a = Rectangle::new(0, 0, 10, 10);
+        let b = Rectangle::new(5, -5, 10, 10);
+        assert_eq!(a.intersection(&b), Some(Rectangle::new(5, 0, 5, 5)));
+        assert_eq!(a.union(&b), Rectangle::new(0, -5, 15, 15));
+
+        // touching isn't overlapping
+        assert_eq!(a.intersection(&Rectangle::new(10, 0, 5, 5)), None);
+        // empty rectangles don't stretch the union
+        assert_eq!(a.union(&Rectangle::new(100, 100, 0, 0)), a);
+
+        // the union is cut short if it's too big
+        let far = Rectangle::new(i16::MAX, i16::MAX, u16::MAX, u16::MAX);
+        let huge = Rectangle::new(i16::MIN, i16::MIN, 1, 1).union(&far);
+        assert_eq!(huge.width, u16::MAX);
+        assert_eq!(huge.height, u16::MAX);
+    }
+
+    #[test]
+    fn constructors_match_wire_format() {
+        let mut bytes = [0u8; 8];
+        Rectangle::new(1, -2, 3, 4).as_bytes(&mut bytes);
+        let mut expected = [0u8; 8];
+        expected[0..2].copy_from_slice(&1i16.to_ne_bytes());
+        expected[2..4].copy_from_slice(&(-2i16).to_ne_bytes());
+        expected[4..6].copy_from_slice(&3u16.to_ne_bytes());
+        expected[6..8].copy_from_slice(&4u16.to_ne_bytes());
+        assert_eq!(bytes, expected);
+
+        let segment = Segment::new(Point::new(1, 2), Point::new(3, 4));
+        assert_eq!(
+            (segment.x1, segment.y1, segment.x2, segment.y2),
+            (1, 2, 3, 4)
+        );
+        assert_eq!(segment.end() - segment.start(), Point::new(2, 2));
+    }
+
+    #[test]
+    fn event_geometry() {
+        let expose = ExposeEvent {
+            x: 5,
+            y: 6,
+            width: 7,
+            height: 8,
+            ..Default::default()
+        };
+        assert_eq!(expose.rectangle(), Rectangle::new(5, 6, 7, 8));
+        let far = ExposeEvent {
+            x: u16::MAX,
+            ..expose
+        };
+        assert_eq!(far.rectangle().x, i16::MAX);
+
+        let configure = ConfigureNotifyEvent {
+            x: -10,
+            y: 20,
+            width: 300,
+            height: 200,
+            border_width: 1,
+            ..Default::default()
+        };
+        assert_eq!(configure.geometry(), Rectangle::new(-10, 20, 300, 200));
+    }
+}
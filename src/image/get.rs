@@ -0,0 +1,254 @@
+// MIT/Apache2 License
+
+//! Reading images back from the server. A `GetImage` reply can't be any longer than the server allows,
+//! so large images are read in bands of scanlines, one request per band.
+
+use super::{bits_per_pixel, Image};
+use crate::{
+    auto::xproto::{Drawable, GetImageReply, GetImageRequest, ImageFormat},
+    display::DisplayBase,
+    util::roundup,
+    BreadError,
+};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+/// The length of a `GetImage` reply, not counting its data.
+const REPLY_HEADER_LEN: usize = 32;
+
+/// How the scanlines of an image being read from the server are laid out, and how many of them can be
+/// read at once.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct ImageBands {
+    format: ImageFormat,
+    depth: u8,
+    bits_per_pixel: u8,
+    scanline_pad: u8,
+    /// The length of one scanline of one plane.
+    bytes_per_line: usize,
+    /// The number of planes in the reply; this is always one for `ZPixmap` images.
+    planes: usize,
+    /// The most scanlines a single reply can hold.
+    band_height: usize,
+}
+
+impl ImageBands {
+    /// Figure out the layout of an image of the given depth and width. The server sends a `ZPixmap` image
+    /// in the display's format for its depth, and an `XYPixmap` image as one bitmap for each plane in the
+    /// plane mask.
+    #[inline]
+    pub(crate) fn new<Dpy: DisplayBase + ?Sized>(
+        dpy: &Dpy,
+        depth: u8,
+        format: ImageFormat,
+        plane_mask: u32,
+        width: u16,
+    ) -> crate::Result<Self> {
+        let (bits_per_pixel, scanline_pad, planes) = if format == ImageFormat::ZPixmap {
+            match dpy.pixmap_format(depth) {
+                Some(f) => (f.bits_per_pixel, f.scanline_pad, 1),
+                None => (bits_per_pixel(dpy, depth), dpy.bitmap_scanline_pad(), 1),
+            }
+        } else {
+            let depth_mask = u32::MAX
+                .checked_shr(32u32.saturating_sub(u32::from(depth)))
+                .unwrap_or(0);
+            (
+                1,
+                dpy.bitmap_scanline_pad(),
+                (plane_mask & depth_mask).count_ones() as usize,
+            )
+        };
+        let bytes_per_line = roundup(
+            usize::from(width) * usize::from(bits_per_pixel),
+            usize::from(scanline_pad),
+        ) >> 3;
+
+        // the reply's length field is 32 bits wide, but the server won't send more than it would accept
+        let max_data_len = dpy
+            .max_request_len()
+            .min(u32::MAX as usize * 4)
+            .saturating_sub(REPLY_HEADER_LEN);
+        let row_len = bytes_per_line * planes;
+        if row_len > max_data_len {
+            return Err(BreadError::ScanlineTooLong {
+                scanline_len: row_len,
+                max_len: max_data_len,
+            });
+        }
+        let band_height = match row_len {
+            0 => usize::from(u16::MAX),
+            row_len => (max_data_len / row_len).min(usize::from(u16::MAX)),
+        };
+
+        Ok(Self {
+            format,
+            depth,
+            bits_per_pixel,
+            scanline_pad,
+            bytes_per_line,
+            planes,
+            band_height,
+        })
+    }
+
+    /// Split a `GetImage` request for the given rectangle into bands.
+    #[inline]
+    pub(crate) fn requests(
+        &self,
+        drawable: Drawable,
+        x: i16,
+        y: i16,
+        width: u16,
+        height: u16,
+        plane_mask: u32,
+    ) -> Vec<GetImageRequest> {
+        (0..usize::from(height))
+            .step_by(self.band_height)
+            .map(|top| GetImageRequest {
+                format: self.format,
+                drawable,
+                x,
+                y: (i32::from(y) + top as i32) as i16,
+                width,
+                height: (usize::from(height) - top).min(self.band_height) as u16,
+                plane_mask,
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    /// Stitch the replies to the requests from `requests` back together into one image.
+    #[inline]
+    pub(crate) fn into_image<Dpy: DisplayBase + ?Sized>(
+        self,
+        dpy: &Dpy,
+        width: u16,
+        height: u16,
+        replies: Vec<GetImageReply>,
+    ) -> crate::Result<Image<Vec<u8>>> {
+        let height = usize::from(height);
+        let plane_len = self.bytes_per_line * height;
+        let visual = replies
+            .first()
+            .and_then(|reply| dpy.visual_id_to_visual(reply.visual));
+
+        // each band holds its piece of every plane, one after another, but the image holds every plane whole
+        let mut data = Vec::with_capacity(plane_len * self.planes);
+        for plane in 0..self.planes {
+            for (i, reply) in replies.iter().enumerate() {
+                let rows = (height - i * self.band_height).min(self.band_height);
+                let band_plane_len = rows * self.bytes_per_line;
+                let start = plane * band_plane_len;
+                let band =
+                    reply
+                        .data
+                        .get(start..start + band_plane_len)
+                        .ok_or(BreadError::StaticMsg(
+                            "GetImage reply holds less data than expected",
+                        ))?;
+                data.extend_from_slice(band);
+            }
+        }
+
+        let (red_mask, green_mask, blue_mask) =
+            visual.map_or((0, 0, 0), |v| (v.red_mask, v.green_mask, v.blue_mask));
+        Ok(Image {
+            width: usize::from(width),
+            height,
+            x_offset: 0,
+            format: self.format,
+            // like Xlib, an XY image has only the planes that were asked for
+            depth: if self.format == ImageFormat::ZPixmap {
+                self.depth
+            } else {
+                u8::try_from(self.planes).unwrap_or(u8::MAX)
+            },
+            bit_order: dpy.bitmap_bit_order(),
+            byte_order: dpy.image_byte_order(),
+            bitmap_unit: dpy.bitmap_scanline_unit(),
+            bitmap_pad: u32::from(self.scanline_pad),
+            bytes_per_line: self.bytes_per_line,
+            bits_per_pixel: self.bits_per_pixel,
+            red_mask,
+            green_mask,
+            blue_mask,
+            data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ImageBands;
+    use crate::{
+        auto::xproto::{Drawable, Format, GetImageReply, ImageFormat, Setup},
+        display::{mock_display, mock_setup, MockConnection},
+        BreadError,
+    };
+    use alloc::{vec, vec::Vec};
+
+    fn setup(maximum_request_length: u16) -> Setup {
+        Setup {
+            maximum_request_length,
+            bitmap_format_scanline_unit: 32,
+            bitmap_format_scanline_pad: 32,
+            pixmap_formats: vec![Format {
+                depth: 8,
+                bits_per_pixel: 8,
+                scanline_pad: 32,
+            }],
+            ..mock_setup()
+        }
+    }
+
+    #[test]
+    fn bands_fit_in_a_reply() {
+        // 80 bytes per reply leaves room for 48 bytes of data, or six 8-byte scanlines
+        let mut conn = MockConnection::with_setup(&setup(20));
+        let dpy = mock_display(&mut conn);
+        let bands = ImageBands::new(&dpy, 8, ImageFormat::ZPixmap, !0, 5).unwrap();
+        assert_eq!(bands.bytes_per_line, 8);
+        assert_eq!(bands.band_height, 6);
+
+        let reqs = bands.requests(Drawable::const_from_xid(1), 0, 10, 5, 14, !0);
+        let rows: Vec<_> = reqs.iter().map(|r| (r.y, r.height)).collect();
+        assert_eq!(rows, vec![(10, 6), (16, 6), (22, 2)]);
+
+        // a scanline that doesn't fit in a reply can't be read at all
+        assert!(matches!(
+            ImageBands::new(&dpy, 8, ImageFormat::ZPixmap, !0, 64),
+            Err(BreadError::ScanlineTooLong {
+                scanline_len: 64,
+                max_len: 48
+            })
+        ));
+    }
+
+    #[test]
+    fn xy_planes_are_stitched_together() {
+        let mut conn = MockConnection::with_setup(&setup(20));
+        let dpy = mock_display(&mut conn);
+        // two planes of a 32-pixel-wide bitmap take up 8 bytes per scanline
+        let bands = ImageBands::new(&dpy, 8, ImageFormat::XyPixmap, 0b101, 32).unwrap();
+        assert_eq!(bands.band_height, 6);
+
+        let band = |rows: u8, first: u8| GetImageReply {
+            data: (0..2 * rows)
+                .flat_map(|i| vec![first + i; 4])
+                .collect::<Vec<u8>>(),
+            ..Default::default()
+        };
+        // each band holds the first plane's rows, then the second plane's
+        let image = bands
+            .into_image(&dpy, 32, 8, vec![band(6, 0), band(2, 20)])
+            .unwrap();
+        assert_eq!(image.depth, 2);
+        let rows: Vec<u8> = image.data.chunks(4).map(|row| row[0]).collect();
+        assert_eq!(
+            rows,
+            vec![0, 1, 2, 3, 4, 5, 20, 21, 6, 7, 8, 9, 10, 11, 22, 23]
+        );
+        image.check_stride().unwrap();
+    }
+}
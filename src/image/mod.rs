@@ -5,6 +5,7 @@
 #![allow(clippy::similar_names, clippy::unreadable_literal)]
 
 pub(crate) mod fit;
+pub(crate) mod get;
 pub(crate) mod put;
 
 use crate::{
@@ -132,22 +133,15 @@ const OS_BYTE_ORDER: ImageOrder = ImageOrder::MsbFirst;
 /// Helper function to get the bits per pixel and scanline pad for a given depth.
 #[inline]
 fn bits_per_pixel<Dpy: DisplayBase + ?Sized>(dpy: &Dpy, depth: u8) -> u8 {
-    dpy.setup()
-        .pixmap_formats
-        .iter()
-        .find_map(|f| {
-            if f.depth == depth {
-                Some(f.bits_per_pixel)
-            } else {
-                None
-            }
-        })
-        .unwrap_or_else(|| match depth {
+    dpy.pixmap_format(depth).map_or_else(
+        || match depth {
             i if i <= 4 => 4,
             i if i <= 8 => 8,
             i if i <= 16 => 16,
             _ => 32,
-        })
+        },
+        |f| f.bits_per_pixel,
+    )
 }
 
 impl<Data> Image<Data>
@@ -371,9 +365,9 @@ where
             width,
             height,
             format,
-            byte_order: dpy.setup().image_byte_order,
-            bitmap_unit: dpy.setup().bitmap_format_scanline_unit,
-            bit_order: dpy.setup().bitmap_format_bit_order,
+            byte_order: dpy.image_byte_order(),
+            bitmap_unit: dpy.bitmap_scanline_unit(),
+            bit_order: dpy.bitmap_bit_order(),
             red_mask,
             green_mask,
             blue_mask,
@@ -386,6 +380,61 @@ where
         })
     }
 
+    /// Create a new image, padding each scanline the way the server expects for images of this depth and
+    /// format. See `new` for more information.
+    #[inline]
+    pub fn with_server_padding<Dpy: DisplayBase + ?Sized>(
+        dpy: &Dpy,
+        visual: Option<&Visualtype>,
+        depth: u8,
+        format: ImageFormat,
+        data: Data,
+        width: usize,
+        height: usize,
+    ) -> Option<Self> {
+        let quantum = match format {
+            ImageFormat::ZPixmap => dpy.pixmap_format(depth).map(|f| f.scanline_pad),
+            _ => None,
+        }
+        .unwrap_or_else(|| dpy.bitmap_scanline_pad());
+
+        Self::new(
+            dpy,
+            visual,
+            depth,
+            format,
+            0,
+            data,
+            width,
+            height,
+            quantum.into(),
+            None,
+        )
+    }
+
+    /// Make sure that the image's data holds exactly `bytes_per_line` bytes for every scanline of the image,
+    /// no more and no less. In XY formats, the image has a set of scanlines for every plane.
+    #[inline]
+    pub fn check_stride(&self) -> crate::Result {
+        let scanlines = match self.format {
+            ImageFormat::ZPixmap => Some(self.height),
+            _ => self.height.checked_mul(usize::from(self.depth)),
+        };
+        // if the length doesn't even fit in memory, no buffer can match it
+        let expected = scanlines
+            .and_then(|scanlines| scanlines.checked_mul(self.bytes_per_line))
+            .unwrap_or(usize::MAX);
+
+        if self.data.len() == expected {
+            Ok(())
+        } else {
+            Err(crate::BreadError::ImageStrideMismatch {
+                expected,
+                actual: self.data.len(),
+            })
+        }
+    }
+
     /// Get a reference to the interior data.
     #[inline]
     pub fn data(&self) -> &[u8] {
@@ -34,7 +34,7 @@ fn prepare_xy_image<Dpy: DisplayBase + ?Sized, Data: Deref<Target = [u8]>>(
     src_y: usize,
 ) {
     let total_xoffset = image.x_offset() as usize + src_x;
-    req.left_pad = (total_xoffset & (dpy.setup().bitmap_format_scanline_unit as usize - 1)) as u8;
+    req.left_pad = (total_xoffset & (dpy.bitmap_scanline_unit() as usize - 1)) as u8;
     let total_xoffset = (total_xoffset - req.left_pad as usize) >> 3;
 
     if req.left_pad != 0 && req.format == ImageFormat::ZPixmap {
@@ -43,7 +43,7 @@ fn prepare_xy_image<Dpy: DisplayBase + ?Sized, Data: Deref<Target = [u8]>>(
 
     let bytes_per_dest = roundup(
         req.width as usize + req.left_pad as usize,
-        dpy.setup().bitmap_format_scanline_pad as usize,
+        dpy.bitmap_scanline_pad() as usize,
     ) >> 3;
     let bytes_per_dest_plane = bytes_per_dest * req.height as usize;
 
@@ -53,9 +53,9 @@ fn prepare_xy_image<Dpy: DisplayBase + ?Sized, Data: Deref<Target = [u8]>>(
         image.byte_order(),
     );
     let swap_function = SWAP_FUNCTION_TABLE[sft_index][swap_function_table_index(
-        u32::from(dpy.setup().bitmap_format_scanline_unit),
-        dpy.setup().bitmap_format_bit_order,
-        dpy.setup().image_byte_order,
+        u32::from(dpy.bitmap_scanline_unit()),
+        dpy.bitmap_bit_order(),
+        dpy.image_byte_order(),
     )];
     let mut half_order = HALF_ORDER_TABLE[sft_index];
     if let ImageOrder::MsbFirst = half_order {
@@ -145,7 +145,7 @@ fn prepare_z_image<Dpy: DisplayBase + ?Sized, Data: Deref<Target = [u8]>>(
     }
 
     // we may be alright with our current set
-    if (image.byte_order() == dpy.setup().image_byte_order || image.bits_per_pixel() == 8)
+    if (image.byte_order() == dpy.image_byte_order() || image.bits_per_pixel() == 8)
         && image.bytes_per_line() == bytes_per_dest
         && (src_x == 0 || (src_y + req.height as usize) < image.height())
     {
@@ -163,7 +163,7 @@ fn prepare_z_image<Dpy: DisplayBase + ?Sized, Data: Deref<Target = [u8]>>(
     // determine what kind of shifts we need to do
     length = roundup(bytes_per_dest * req.height as usize, 4);
     let mut buffer: Vec<u8> = iter::repeat(0).take(length).collect();
-    if image.byte_order() == dpy.setup().image_byte_order || image.bits_per_pixel() == 8 {
+    if image.byte_order() == dpy.image_byte_order() || image.bits_per_pixel() == 8 {
         no_swap(
             &src_data,
             &mut buffer,
@@ -242,23 +242,17 @@ fn put_sub_image_req<Dpy: DisplayBase + ?Sized, Data: Deref<Target = [u8]>>(
     // How many bits are available to use.
     let mut req: PutImageRequest = Default::default();
 
-    #[allow(unused_comparisons, clippy::absurd_extreme_comparisons)]
-    let available: usize = if dpy.setup().maximum_request_length > 65535 {
-        65536 << 2
-    } else {
-        (dpy.setup().maximum_request_length as usize) << 2
-    } - req.size();
+    // The limit is read fresh on every call, so that it follows BIG-REQUESTS being enabled. A big request
+    // also carries a four-byte extended length.
+    let available: usize =
+        dpy.max_request_len() - req.size() - if dpy.bigreq_enabled() { 4 } else { 0 };
 
     let (left_pad, bytes_per_row) =
         if image.bits_per_pixel() == 1 || image.format() != ImageFormat::ZPixmap {
-            let left_pad =
-                (image.x_offset() + src_x) & (dpy.setup().bitmap_format_scanline_unit as usize - 1);
+            let left_pad = (image.x_offset() + src_x) & (dpy.bitmap_scanline_unit() as usize - 1);
             (
                 left_pad,
-                (roundup(
-                    width + left_pad,
-                    dpy.setup().bitmap_format_scanline_pad as usize,
-                ) >> 3)
+                (roundup(width + left_pad, dpy.bitmap_scanline_pad() as usize) >> 3)
                     * image.depth() as usize,
             )
         } else {
@@ -381,7 +375,10 @@ pub(crate) fn put_image_req<Dpy: DisplayBase + ?Sized, Data: Deref<Target = [u8]
     dest_y: isize,
     mut width: usize,
     mut height: usize,
-) -> Vec<PutImageRequest> {
+) -> crate::Result<Vec<PutImageRequest>> {
+    // if the image's data isn't laid out the way it says it is, every row after the first is shifted
+    image.check_stride()?;
+
     let src_x: usize = match src_x {
         x_offset if x_offset < 0 => {
             width = width.saturating_sub(usize::try_from(-x_offset).unwrap());
@@ -401,48 +398,44 @@ pub(crate) fn put_image_req<Dpy: DisplayBase + ?Sized, Data: Deref<Target = [u8]
     if src_x + width > image.width() {
         width = image.width().saturating_sub(src_x);
     }
-    if src_y + width > image.height() {
+    if src_y + height > image.height() {
         height = image.height().saturating_sub(src_y);
     }
 
     if width == 0 || height == 0 {
         log::error!("Width and height of zero, no drawing could be done.");
-        return vec![]; // no drawing could be done
+        return Ok(vec![]); // no drawing could be done
     }
 
     let (dest_bits_per_pixel, dest_scanline_pad) =
         if image.bits_per_pixel() == 1 || image.format() != ImageFormat::ZPixmap {
-            (1_usize, dpy.setup().bitmap_format_scanline_pad as usize)
+            (1_usize, dpy.bitmap_scanline_pad() as usize)
         } else {
-            let mut dest_bits_per_pixel = image.bits_per_pixel() as usize;
-            let mut dest_scanline_pad = image.bitmap_pad() as usize;
-
-            // scan the display's formats
-            dpy.setup().pixmap_formats.iter().for_each(|f| {
-                if f.depth == image.depth() {
-                    dest_bits_per_pixel = f.bits_per_pixel as usize;
-                    dest_scanline_pad = f.scanline_pad as usize;
-                }
-            });
+            // use the display's format for this depth, if it has one
+            let (dest_bits_per_pixel, dest_scanline_pad) = match dpy.pixmap_format(image.depth()) {
+                Some(f) => (f.bits_per_pixel as usize, f.scanline_pad as usize),
+                None => (image.bits_per_pixel() as usize, image.bitmap_pad() as usize),
+            };
 
             if dest_bits_per_pixel != image.bits_per_pixel() as _ {
+                let bytes_per_line = roundup(dest_bits_per_pixel * width, dest_scanline_pad) >> 3;
                 let mut new_image = Image {
                     width,
                     height,
                     x_offset: 0,
                     format: ImageFormat::ZPixmap,
-                    byte_order: dpy.setup().image_byte_order,
-                    bitmap_unit: dpy.setup().bitmap_format_scanline_unit,
-                    bit_order: dpy.setup().bitmap_format_bit_order,
+                    byte_order: dpy.image_byte_order(),
+                    bitmap_unit: dpy.bitmap_scanline_unit(),
+                    bit_order: dpy.bitmap_bit_order(),
                     bitmap_pad: dest_scanline_pad as _,
                     bits_per_pixel: dest_bits_per_pixel as _,
                     depth: image.depth(),
                     red_mask: 0,
                     green_mask: 0,
                     blue_mask: 0,
-                    bytes_per_line: roundup(dest_bits_per_pixel * width, dest_scanline_pad) >> 3,
+                    bytes_per_line,
                     data: iter::repeat(0)
-                        .take(height * image.bytes_per_line())
+                        .take(height * bytes_per_line)
                         .collect::<Box<[u8]>>(),
                 };
 
@@ -453,7 +446,7 @@ pub(crate) fn put_image_req<Dpy: DisplayBase + ?Sized, Data: Deref<Target = [u8]
                 }
 
                 // put the new image and return
-                return put_sub_image_req(
+                return Ok(put_sub_image_req(
                     dpy,
                     drawable,
                     gc,
@@ -466,12 +459,12 @@ pub(crate) fn put_image_req<Dpy: DisplayBase + ?Sized, Data: Deref<Target = [u8]
                     height,
                     dest_bits_per_pixel,
                     dest_scanline_pad,
-                );
+                ));
             }
             (dest_bits_per_pixel, dest_scanline_pad)
         };
 
-    put_sub_image_req(
+    Ok(put_sub_image_req(
         dpy,
         drawable,
         gc,
@@ -484,5 +477,167 @@ pub(crate) fn put_image_req<Dpy: DisplayBase + ?Sized, Data: Deref<Target = [u8]
         height,
         dest_bits_per_pixel,
         dest_scanline_pad,
-    )
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::put_image_req;
+    use crate::{
+        auto::{
+            bigreq::EnableReply,
+            xproto::{Drawable, Format, Gcontext, ImageFormat, QueryExtensionReply, Setup},
+            AsByteSequence,
+        },
+        display::{mock_display, mock_setup, BasicDisplay, DisplayBase, MockConnection},
+        BreadError, Image,
+    };
+    use alloc::{vec, vec::Vec};
+
+    fn setup() -> Setup {
+        Setup {
+            bitmap_format_scanline_unit: 32,
+            bitmap_format_scanline_pad: 32,
+            pixmap_formats: vec![Format {
+                depth: 8,
+                bits_per_pixel: 8,
+                scanline_pad: 32,
+            }],
+            ..mock_setup()
+        }
+    }
+
+    #[test]
+    fn big_requests_raise_the_chunk_size() {
+        const WIDTH: usize = 1024;
+        const HEIGHT: usize = 512;
+
+        let chunks = |dpy: &mut BasicDisplay<&mut MockConnection>| {
+            let data: Vec<u8> = vec![0; WIDTH * HEIGHT];
+            let image = Image::new(
+                &*dpy,
+                None,
+                8,
+                ImageFormat::ZPixmap,
+                0,
+                data,
+                WIDTH,
+                HEIGHT,
+                8,
+                None,
+            )
+            .unwrap();
+            let reqs = put_image_req(
+                dpy,
+                Drawable::const_from_xid(1),
+                Gcontext::const_from_xid(2),
+                &image,
+                0,
+                0,
+                0,
+                0,
+                WIDTH,
+                HEIGHT,
+            )
+            .unwrap();
+            assert!(reqs.iter().all(|r| r.size() <= dpy.max_request_len()));
+            reqs.len()
+        };
+
+        let mut conn = MockConnection::with_setup(&setup());
+        let mut dpy = mock_display(&mut conn);
+        assert!(!dpy.bigreq_enabled());
+        assert_eq!(chunks(&mut dpy), 3);
+
+        let mut conn = MockConnection::new();
+        conn.feed_setup(&setup());
+        conn.feed_reply(&QueryExtensionReply {
+            reply_type: 1,
+            sequence: 1,
+            present: true,
+            major_opcode: 133,
+            ..Default::default()
+        });
+        conn.feed_reply(&EnableReply {
+            reply_type: 1,
+            sequence: 2,
+            maximum_request_length: 1 << 20,
+            ..Default::default()
+        });
+        let mut dpy = mock_display(&mut conn);
+        assert!(dpy.bigreq_enabled());
+        assert_eq!(dpy.max_request_len(), 4 << 20);
+        assert_eq!(chunks(&mut dpy), 1);
+    }
+
+    #[test]
+    fn scanlines_are_padded() {
+        let mut conn = MockConnection::with_setup(&setup());
+        let mut dpy = mock_display(&mut conn);
+        let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6];
+        let image =
+            Image::new(&dpy, None, 8, ImageFormat::ZPixmap, 0, data, 3, 2, 8, None).unwrap();
+
+        let reqs = put_image_req(
+            &mut dpy,
+            Drawable::const_from_xid(1),
+            Gcontext::const_from_xid(2),
+            &image,
+            0,
+            0,
+            0,
+            0,
+            3,
+            2,
+        )
+        .unwrap();
+        assert_eq!(reqs.len(), 1);
+        assert_eq!(reqs[0].data, vec![1, 2, 3, 0, 4, 5, 6, 0]);
+    }
+
+    #[test]
+    fn short_data_is_rejected() {
+        let mut conn = MockConnection::with_setup(&setup());
+        let mut dpy = mock_display(&mut conn);
+        let data: Vec<u8> = vec![1, 2, 3, 4, 5];
+        let image =
+            Image::new(&dpy, None, 8, ImageFormat::ZPixmap, 0, data, 3, 2, 8, None).unwrap();
+
+        let res = put_image_req(
+            &mut dpy,
+            Drawable::const_from_xid(1),
+            Gcontext::const_from_xid(2),
+            &image,
+            0,
+            0,
+            0,
+            0,
+            3,
+            2,
+        );
+        assert!(matches!(
+            res,
+            Err(BreadError::ImageStrideMismatch {
+                expected: 6,
+                actual: 5
+            })
+        ));
+    }
+
+    #[test]
+    fn long_data_is_rejected() {
+        let mut conn = MockConnection::with_setup(&setup());
+        let dpy = mock_display(&mut conn);
+        // one byte more than two scanlines of three bytes
+        let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6, 7];
+        let image =
+            Image::new(&dpy, None, 8, ImageFormat::ZPixmap, 0, data, 3, 2, 8, None).unwrap();
+        assert!(matches!(
+            image.check_stride(),
+            Err(BreadError::ImageStrideMismatch {
+                expected: 6,
+                actual: 7
+            })
+        ));
+    }
 }
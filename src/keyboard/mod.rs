@@ -14,7 +14,7 @@ both of the above.
 
 use crate::{
     auto::xproto::{KeyButMask, Keycode, Keysym},
-    display::Display,
+    display::{Display, DisplayBase},
 };
 use gluten_keyboard::Key;
 
@@ -24,6 +24,8 @@ use crate::display::AsyncDisplay;
 mod convert;
 pub use convert::*;
 
+mod modifiers;
+
 mod xproto;
 pub use xproto::*;
 
@@ -55,6 +57,41 @@ impl KeyboardState<XprotoKeymap> {
             XprotoKeymap::init_from_async(display).await?,
         ))
     }
+
+    /// Whether or not the display has received a `MappingNotify` event for the keyboard or modifier
+    /// mapping since the keymap was fetched, meaning that keycodes may now translate to different keys.
+    #[inline]
+    pub fn is_stale<Dpy: DisplayBase + ?Sized>(&self, display: &Dpy) -> bool {
+        self.keymap.is_stale(display)
+    }
+
+    /// Fetch the keymap again if it is stale, returning whether or not it was. Call this after handling
+    /// events, or at least before translating keycodes once a `MappingNotify` event has come in, so that
+    /// key presses keep matching the layout after the user switches it.
+    #[inline]
+    pub fn refresh<Dpy: Display + ?Sized>(&mut self, display: &mut Dpy) -> crate::Result<bool> {
+        if self.is_stale(display) {
+            self.keymap = XprotoKeymap::init_from(display)?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Fetch the keymap again if it is stale, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn refresh_async<Dpy: AsyncDisplay + ?Sized>(
+        &mut self,
+        display: &mut Dpy,
+    ) -> crate::Result<bool> {
+        if self.is_stale(display) {
+            self.keymap = XprotoKeymap::init_from_async(display).await?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
 }
 
 impl<Km: Keymap + ?Sized> KeyboardState<Km> {
@@ -82,3 +119,61 @@ impl<Km: Keymap + ?Sized> KeyboardState<Km> {
 pub trait Keymap {
     fn lookup_keysyms(&self, keycode: Keycode) -> &[Keysym];
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        auto::xproto::Setup,
+        display::{mock_display, mock_setup, MockConnection},
+    };
+
+    #[test]
+    fn mapping_notify_makes_keymaps_stale() {
+        use crate::{
+            auto::xproto::{GetKeyboardMappingReply, Mapping, MappingNotifyEvent},
+            display::Display,
+            KeyboardState,
+        };
+        use alloc::vec;
+
+        let setup = Setup {
+            min_keycode: 8,
+            max_keycode: 9,
+            ..mock_setup()
+        };
+        let keymap = |sequence, keysyms| GetKeyboardMappingReply {
+            reply_type: 1,
+            keysyms_per_keycode: 1,
+            sequence,
+            length: 2,
+            keysyms,
+        };
+        let mapping_notify = |request| MappingNotifyEvent {
+            event_type: 34,
+            request,
+            first_keycode: 8,
+            count: 2,
+            ..Default::default()
+        };
+        let mut conn = MockConnection::with_setup(&setup);
+        conn.feed_reply(&keymap(2, vec![0x61, 0x62]));
+        conn.feed_reply(&mapping_notify(Mapping::Pointer));
+        conn.feed_reply(&mapping_notify(Mapping::Keyboard));
+        conn.feed_reply(&keymap(3, vec![0x71, 0x77]));
+
+        let mut display = mock_display(&mut conn);
+        let mut state = KeyboardState::new(&mut display).unwrap();
+        assert_eq!(state.lookup_keysyms(8), &[0x61]);
+
+        // a new pointer mapping doesn't affect the keymap
+        display.wait_for_event().unwrap();
+        assert!(!state.is_stale(&display));
+        assert!(!state.refresh(&mut display).unwrap());
+
+        display.wait_for_event().unwrap();
+        assert!(state.is_stale(&display));
+        assert!(state.refresh(&mut display).unwrap());
+        assert_eq!(state.lookup_keysyms(8), &[0x71]);
+        assert!(!state.is_stale(&display));
+    }
+}
@@ -0,0 +1,91 @@
+// MIT/Apache2 License
+
+//! Helpers for the modifier and button state carried by input events and `QueryPointer` replies. The
+//! `KeyButMask` type itself is generated, along with a predicate for each of its bits.
+
+use crate::auto::xproto::KeyButMask;
+
+impl KeyButMask {
+    /// The modifier that Num Lock is bound to on nearly every server. The binding can be changed with
+    /// `SetModifierMapping`, so this is a convention rather than a guarantee.
+    pub const NUM_LOCK: Self = Self::MOD2;
+    /// The lock modifiers, which are toggled rather than held.
+    pub const LOCKS: Self = Self {
+        inner: Self::LOCK.inner | Self::NUM_LOCK.inner,
+    };
+    /// Every pointer button.
+    pub const BUTTONS: Self = Self {
+        inner: Self::BUTTON1.inner
+            | Self::BUTTON2.inner
+            | Self::BUTTON3.inner
+            | Self::BUTTON4.inner
+            | Self::BUTTON5.inner,
+    };
+
+    /// Whether or not every bit set in `other` is also set in this mask.
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.inner & other.inner == other.inner
+    }
+
+    /// Whether or not pointer button `n` is held down. Only buttons 1 through 5 are tracked, so this is
+    /// always `false` for any other button.
+    #[inline]
+    #[must_use]
+    pub const fn button(self, n: u8) -> bool {
+        match n {
+            1..=5 => self.inner & (Self::BUTTON1.inner << (n - 1)) != 0,
+            _ => false,
+        }
+    }
+
+    /// Whether or not any pointer button is held down.
+    #[inline]
+    #[must_use]
+    pub const fn any_button(self) -> bool {
+        self.inner & Self::BUTTONS.inner != 0
+    }
+
+    /// This mask with Caps Lock and Num Lock cleared. Whether or not a lock is on shouldn't change which
+    /// shortcut a key press triggers, so compare masks with this applied.
+    #[inline]
+    #[must_use]
+    pub const fn without_locks(self) -> Self {
+        Self {
+            inner: self.inner & !Self::LOCKS.inner,
+        }
+    }
+
+    /// This mask with the pointer buttons cleared, leaving only the keyboard modifiers.
+    #[inline]
+    #[must_use]
+    pub const fn without_buttons(self) -> Self {
+        Self {
+            inner: self.inner & !Self::BUTTONS.inner,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::auto::xproto::KeyButMask;
+
+    #[test]
+    fn shortcuts_ignore_locks_and_buttons() {
+        let state = KeyButMask::CONTROL | KeyButMask::LOCK | KeyButMask::MOD2 | KeyButMask::BUTTON3;
+        assert!(state.control());
+        assert!(state.button(3));
+        assert!(!state.button(1));
+        assert!(!state.button(0));
+        assert!(!state.button(6));
+        assert!(state.any_button());
+        assert!(state.contains(KeyButMask::CONTROL | KeyButMask::LOCK));
+        assert!(!state.contains(KeyButMask::CONTROL | KeyButMask::SHIFT));
+
+        let shortcut = state.without_locks().without_buttons();
+        assert_eq!(shortcut, KeyButMask::CONTROL);
+        assert!(!shortcut.lock());
+        assert!(!shortcut.mod2());
+    }
+}
@@ -95,11 +95,21 @@
 //! * `async` - Enables the `_async` suffix family of functions. These functions and methods are similar
 //!             to their blocking variants, but they use non-blocking variants of network calls. This uses
 //!             the [`async_net`](https://crates.io/crates/async-net) crate to provide non-blocking calls.
-//!             However, it nearly triples the size of this package's dependency tree.
+//!             However, it nearly triples the size of this package's dependency tree. Connecting with
+//!             `AsyncDisplayConnection::create_async` never blocks the executor, but host names are
+//!             resolved on a background thread pool, since `async-io` has no resolver of its own.
+//! * `tokio-support` - Enables `TokioConnection` and `TokioDisplayConnection`, which drive the connection
+//!                     with [`tokio`](https://crates.io/crates/tokio)'s reactor instead of `async-io`'s.
+//!                     `TokioDisplayConnection::create_tokio` resolves and connects through `tokio`
+//!                     as well. Only available on Unix. Implies `async`.
 //! * `image-support` - Coming soon.
 //! * `nightly-min-specialization` - Coming soon.
 //! * `parallel` - Uses the [`rayon`](https://crates.io/crates/rayon) crate to parallelize computationally
 //!                expensive operations.
+//! * `all-extensions` - Enables every X11 extension that `breadx` supports. Each extension can also be
+//!                      enabled on its own by its name (e.g. `render`, `shm` or `xkb`); only the core
+//!                      protocol is always compiled in. Extensions that refer to the types of other
+//!                      extensions enable those extensions as well.
 
 #![deny(deprecated)]
 #![forbid(unsafe_code)]
@@ -136,9 +146,11 @@ pub mod display;
 pub mod error;
 pub mod event;
 pub mod extension;
+mod geometry;
 pub mod image;
 pub mod keyboard;
 pub(crate) mod paramatizer;
+mod time;
 pub(crate) mod util;
 mod xid;
 
@@ -160,13 +172,17 @@ pub use error::*;
 pub use event::Event;
 pub use extension::*;
 pub use keyboard::*;
+pub use time::ServerTime;
 pub use xid::*;
 
 pub type Fd = cty::c_int;
 
 /// A request that can be sent as an instruction to the X server.
 pub trait Request: auto::AsByteSequence {
-    type Reply: auto::AsByteSequence;
+    /// The reply the server sends in response to this request. Replies are parsed out of the
+    /// display's buffers into owned data, so they never borrow from the display and can be moved
+    /// elsewhere once received.
+    type Reply: auto::AsByteSequence + 'static;
     // Excerpt from the X Window System Protocol:
     //
     // Every request contains an 8-bit major opcode
@@ -177,6 +193,18 @@ pub trait Request: auto::AsByteSequence {
 
     /// Whether or not this request's reply includes file descriptors.
     const REPLY_EXPECTS_FDS: bool;
+
+    /// The name of this request, as it appears in the protocol description. This is only used for
+    /// diagnostics.
+    const NAME: &'static str = "<unknown>";
+
+    /// The number of file descriptors this request declares that it carries. The display checks this against
+    /// the file descriptors actually attached to the request before sending it, since the server would
+    /// otherwise pair the extra or missing descriptors with the wrong request.
+    #[inline]
+    fn fd_count(&self) -> usize {
+        0
+    }
 }
 
 pub use auto::xproto::{
@@ -32,7 +32,7 @@ impl AsByteSequence for NotifyData {
     #[inline]
     fn from_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
         let mut data = Self([0; NOTIFY_DATA_LEN]);
-        data.0.copy_from_slice(&bytes[..NOTIFY_DATA_LEN]);
+        data.0.copy_from_slice(bytes.get(..NOTIFY_DATA_LEN)?);
         Some((data, NOTIFY_DATA_LEN))
     }
 }
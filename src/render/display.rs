@@ -6,20 +6,22 @@ use crate::{
         render::{
             Color, CreateConicalGradientRequest, CreateLinearGradientRequest, CreatePictureRequest,
             CreateRadialGradientRequest, Fixed, PictType, Pictformat, Pictforminfo, Pictscreen,
-            Picture, Pictvisual, Pointfix, QueryPictFormatsReply, QueryPictFormatsRequest,
-            QueryVersionReply, QueryVersionRequest,
+            Picture, Pointfix, QueryPictFormatsReply, QueryPictFormatsRequest, QueryVersionReply,
+            QueryVersionRequest,
         },
-        xproto::{Drawable, Setup, Visualtype},
+        xproto::{Drawable, Setup, Visualid, Visualtype},
     },
     display::{
-        generate_xid, prelude::*, Display, DisplayBase, DisplayExt, PendingReply, PendingRequest,
-        RequestInfo, EXT_KEY_SIZE,
+        generate_xid, prelude::*, Display, DisplayBase, DisplayExt, EventDecoder,
+        ExtensionPresence, OrphanReplyHook, PendingReply, PendingRequest, RequestInfo,
+        ResourceKind, EXT_KEY_SIZE,
     },
     event::Event,
-    BreadError, XID,
+    BreadError, ServerTime, XID,
 };
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
 use core::num::NonZeroU32;
+use hashbrown::HashMap;
 
 #[cfg(feature = "async")]
 use crate::display::AsyncDisplay;
@@ -31,6 +33,9 @@ use core::task::{Context, Poll};
 pub struct RenderDisplay<Dpy: ?Sized> {
     formats: Box<[Pictforminfo]>,
     screens: Box<[Pictscreen]>,
+    // lookup tables built from the formats and screens, so finding a format doesn't need a scan
+    visual_formats: HashMap<Visualid, Pictformat>,
+    standard_formats: [Option<Pictformat>; STANDARD_FORMAT_COUNT],
     subpixels: Box<[u32]>,
     major_version: u32,
     minor_version: u32,
@@ -57,84 +62,64 @@ impl<Dpy> RenderDisplay<Dpy> {
     }
 
     #[inline]
-    fn fold_for_visformat<F: FnMut(&Pictvisual) -> bool>(&self, mut f: F) -> Option<Pictformat> {
-        self.screens
+    fn from_info(inner: Dpy, info: XrenderInfo) -> Self {
+        let XrenderInfo {
+            formats,
+            screens,
+            subpixels,
+            major_version,
+            minor_version,
+        } = info;
+
+        let visual_formats = screens
             .iter()
             .flat_map(|s| s.depths.iter())
             .flat_map(|d| d.visuals.iter())
-            .find_map(|v| if f(v) { Some(v.format) } else { None })
+            .map(|v| (v.visual, v.format))
+            .collect();
+
+        let mut standard_formats = [None; STANDARD_FORMAT_COUNT];
+        standard_formats
+            .iter_mut()
+            .zip(STANDARD_FORMATS)
+            .for_each(|(slot, is_standard)| {
+                *slot = formats.iter().find(|p| is_standard(p)).map(|p| p.id);
+            });
+
+        Self {
+            formats,
+            screens,
+            visual_formats,
+            standard_formats,
+            subpixels,
+            major_version,
+            minor_version,
+            inner,
+        }
+    }
+
+    /// Get the picture formats supported by the server.
+    #[inline]
+    pub fn formats(&self) -> &[Pictforminfo] {
+        &self.formats
+    }
+
+    /// Get the picture formats used by the visuals of each screen.
+    #[inline]
+    pub fn screens(&self) -> &[Pictscreen] {
+        &self.screens
     }
 
     /// Get a `Pictformat` object that matches the given `Visualtype`.
     #[inline]
     pub fn find_visual_format(&self, visual: &Visualtype) -> Option<Pictformat> {
-        self.fold_for_visformat(|v| v.visual == visual.visual_id)
+        self.visual_formats.get(&visual.visual_id).copied()
     }
 
     /// Get a `Pictformat` based on a standard format.
     #[inline]
     pub fn find_standard_format(&self, standard: StandardFormat) -> Option<Pictformat> {
-        const STANDARDS: &[fn(&Pictforminfo) -> bool] = &[
-            |p| {
-                p.ty == PictType::Direct
-                    && p.depth == 32
-                    && p.direct.red_shift == 16
-                    && p.direct.red_mask == 0xFF
-                    && p.direct.green_shift == 8
-                    && p.direct.green_mask == 0xFF
-                    && p.direct.blue_shift == 0
-                    && p.direct.blue_mask == 0xFF
-                    && p.direct.alpha_shift == 24
-                    && p.direct.alpha_mask == 0xFF
-            },
-            |p| {
-                p.ty == PictType::Direct
-                    && p.depth == 24
-                    && p.direct.red_shift == 16
-                    && p.direct.red_mask == 0xFF
-                    && p.direct.green_shift == 8
-                    && p.direct.green_mask == 0xFF
-                    && p.direct.blue_shift == 0
-                    && p.direct.blue_mask == 0xFF
-                    && p.direct.alpha_mask == 0
-            },
-            |p| {
-                p.ty == PictType::Direct
-                    && p.depth == 8
-                    && p.direct.red_mask == 0x00
-                    && p.direct.green_mask == 0x00
-                    && p.direct.blue_mask == 0x00
-                    && p.direct.alpha_shift == 0
-                    && p.direct.alpha_mask == 0xFF
-            },
-            |p| {
-                p.ty == PictType::Direct
-                    && p.depth == 4
-                    && p.direct.red_mask == 0x00
-                    && p.direct.green_mask == 0x00
-                    && p.direct.blue_mask == 0x00
-                    && p.direct.alpha_shift == 0
-                    && p.direct.alpha_mask == 0x0F
-            },
-            |p| {
-                p.ty == PictType::Direct
-                    && p.depth == 1
-                    && p.direct.red_mask == 0x00
-                    && p.direct.green_mask == 0x00
-                    && p.direct.blue_mask == 0x00
-                    && p.direct.alpha_shift == 0
-                    && p.direct.alpha_mask == 0x01
-            },
-        ];
-        let standard_identifier = STANDARDS[standard as usize];
-
-        self.formats.iter().find_map(|p| {
-            if standard_identifier(p) {
-                Some(p.id)
-            } else {
-                None
-            }
-        })
+        self.standard_formats[standard as usize]
     }
 
     #[inline]
@@ -157,69 +142,73 @@ impl<Dpy> RenderDisplay<Dpy> {
 
     #[inline]
     fn create_linear_gradient_request(
-        pid: Picture,
         p1: Pointfix,
         p2: Pointfix,
-        stops: &[Fixed],
-        colors: &[Color],
-    ) -> CreateLinearGradientRequest {
-        assert_eq!(stops.len(), colors.len());
-        CreateLinearGradientRequest {
-            picture: pid,
+        stops: &[(Fixed, Color)],
+    ) -> crate::Result<CreateLinearGradientRequest> {
+        let (stops, colors) = split_gradient_stops(stops)?;
+        Ok(CreateLinearGradientRequest {
             p1,
             p2,
             num_stops: stops.len() as u32,
-            stops: stops.to_vec(),
-            colors: colors.to_vec(),
+            stops,
+            colors,
             ..Default::default()
-        }
+        })
     }
 
     #[inline]
     fn create_radial_gradient_request(
-        pid: Picture,
         inner: Pointfix,
         outer: Pointfix,
         inner_radius: Fixed,
         outer_radius: Fixed,
-        stops: &[Fixed],
-        colors: &[Color],
-    ) -> CreateRadialGradientRequest {
-        assert_eq!(stops.len(), colors.len());
-        CreateRadialGradientRequest {
-            picture: pid,
+        stops: &[(Fixed, Color)],
+    ) -> crate::Result<CreateRadialGradientRequest> {
+        let (stops, colors) = split_gradient_stops(stops)?;
+        Ok(CreateRadialGradientRequest {
             inner,
             outer,
             inner_radius,
             outer_radius,
             num_stops: stops.len() as u32,
-            stops: stops.to_vec(),
-            colors: colors.to_vec(),
+            stops,
+            colors,
             ..Default::default()
-        }
+        })
     }
 
     #[inline]
     fn create_conical_gradient_request(
-        pid: Picture,
         center: Pointfix,
         angle: Fixed,
-        stops: &[Fixed],
-        colors: &[Color],
-    ) -> CreateConicalGradientRequest {
-        assert_eq!(stops.len(), colors.len());
-        CreateConicalGradientRequest {
-            picture: pid,
+        stops: &[(Fixed, Color)],
+    ) -> crate::Result<CreateConicalGradientRequest> {
+        let (stops, colors) = split_gradient_stops(stops)?;
+        Ok(CreateConicalGradientRequest {
             center,
             angle,
             num_stops: stops.len() as u32,
-            stops: stops.to_vec(),
-            colors: colors.to_vec(),
+            stops,
+            colors,
             ..Default::default()
-        }
+        })
     }
 }
 
+/// Split a list of gradient stops into the two parallel lists the server expects, making sure that the stops
+/// are in order.
+#[inline]
+fn split_gradient_stops(stops: &[(Fixed, Color)]) -> crate::Result<(Vec<Fixed>, Vec<Color>)> {
+    if stops.windows(2).any(|w| w[0].0 > w[1].0) {
+        return Err(BreadError::StaticMsg(
+            "Gradient stops must be in non-decreasing order",
+        ));
+    }
+
+    Ok(stops.iter().copied().unzip())
+}
+
 /// Standard formats.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(usize)]
@@ -231,6 +220,62 @@ pub enum StandardFormat {
     A1 = 4,
 }
 
+const STANDARD_FORMAT_COUNT: usize = 5;
+
+/// Tell whether a format matches each of the standard formats, in the order of `StandardFormat`.
+const STANDARD_FORMATS: &[fn(&Pictforminfo) -> bool; STANDARD_FORMAT_COUNT] = &[
+    |p| {
+        p.ty == PictType::Direct
+            && p.depth == 32
+            && p.direct.red_shift == 16
+            && p.direct.red_mask == 0xFF
+            && p.direct.green_shift == 8
+            && p.direct.green_mask == 0xFF
+            && p.direct.blue_shift == 0
+            && p.direct.blue_mask == 0xFF
+            && p.direct.alpha_shift == 24
+            && p.direct.alpha_mask == 0xFF
+    },
+    |p| {
+        p.ty == PictType::Direct
+            && p.depth == 24
+            && p.direct.red_shift == 16
+            && p.direct.red_mask == 0xFF
+            && p.direct.green_shift == 8
+            && p.direct.green_mask == 0xFF
+            && p.direct.blue_shift == 0
+            && p.direct.blue_mask == 0xFF
+            && p.direct.alpha_mask == 0
+    },
+    |p| {
+        p.ty == PictType::Direct
+            && p.depth == 8
+            && p.direct.red_mask == 0x00
+            && p.direct.green_mask == 0x00
+            && p.direct.blue_mask == 0x00
+            && p.direct.alpha_shift == 0
+            && p.direct.alpha_mask == 0xFF
+    },
+    |p| {
+        p.ty == PictType::Direct
+            && p.depth == 4
+            && p.direct.red_mask == 0x00
+            && p.direct.green_mask == 0x00
+            && p.direct.blue_mask == 0x00
+            && p.direct.alpha_shift == 0
+            && p.direct.alpha_mask == 0x0F
+    },
+    |p| {
+        p.ty == PictType::Direct
+            && p.depth == 1
+            && p.direct.red_mask == 0x00
+            && p.direct.green_mask == 0x00
+            && p.direct.blue_mask == 0x00
+            && p.direct.alpha_shift == 0
+            && p.direct.alpha_mask == 0x01
+    },
+];
+
 struct XrenderInfo {
     formats: Box<[Pictforminfo]>,
     screens: Box<[Pictscreen]>,
@@ -354,6 +399,61 @@ impl<Dpy: DisplayBase> DisplayBase for RenderDisplay<Dpy> {
         self.inner.set_extension_opcode(key, opcode);
     }
 
+    #[inline]
+    fn get_extension_presence(&mut self, key: &[u8; EXT_KEY_SIZE]) -> ExtensionPresence {
+        self.inner.get_extension_presence(key)
+    }
+
+    #[inline]
+    fn set_extension_absent(&mut self, key: [u8; EXT_KEY_SIZE]) {
+        self.inner.set_extension_absent(key);
+    }
+
+    #[inline]
+    fn get_extension_first_event(&self, key: &[u8; EXT_KEY_SIZE]) -> Option<u8> {
+        self.inner.get_extension_first_event(key)
+    }
+
+    #[inline]
+    fn set_extension_first_event(&mut self, key: [u8; EXT_KEY_SIZE], first_event: u8) {
+        self.inner.set_extension_first_event(key, first_event);
+    }
+
+    #[inline]
+    fn add_pending_extension_query(&mut self, key: [u8; EXT_KEY_SIZE], req_id: u16) {
+        self.inner.add_pending_extension_query(key, req_id);
+    }
+
+    #[inline]
+    fn take_pending_extension_query(&mut self, key: &[u8; EXT_KEY_SIZE]) -> Option<u16> {
+        self.inner.take_pending_extension_query(key)
+    }
+
+    #[inline]
+    fn pending_extension_query(&self, key: &[u8; EXT_KEY_SIZE]) -> Option<u16> {
+        self.inner.pending_extension_query(key)
+    }
+
+    #[inline]
+    fn defer_request(&mut self, request: RequestInfo) {
+        self.inner.defer_request(request);
+    }
+
+    #[inline]
+    fn take_deferred_request(&mut self) -> Option<RequestInfo> {
+        self.inner.take_deferred_request()
+    }
+
+    #[inline]
+    fn restore_deferred_request(&mut self, request: RequestInfo) {
+        self.inner.restore_deferred_request(request);
+    }
+
+    #[inline]
+    fn has_deferred_requests(&self) -> bool {
+        self.inner.has_deferred_requests()
+    }
+
     #[inline]
     fn wm_protocols_atom(&self) -> Option<NonZeroU32> {
         self.inner.wm_protocols_atom()
@@ -363,6 +463,147 @@ impl<Dpy: DisplayBase> DisplayBase for RenderDisplay<Dpy> {
     fn set_wm_protocols_atom(&mut self, a: NonZeroU32) {
         self.inner.set_wm_protocols_atom(a)
     }
+
+    #[inline]
+    fn xembed_atom(&self) -> Option<NonZeroU32> {
+        self.inner.xembed_atom()
+    }
+
+    #[inline]
+    fn set_xembed_atom(&mut self, a: NonZeroU32) {
+        self.inner.set_xembed_atom(a);
+    }
+
+    #[inline]
+    fn xembed_info_atom(&self) -> Option<NonZeroU32> {
+        self.inner.xembed_info_atom()
+    }
+
+    #[inline]
+    fn set_xembed_info_atom(&mut self, a: NonZeroU32) {
+        self.inner.set_xembed_info_atom(a);
+    }
+
+    #[inline]
+    fn last_event_time(&self) -> ServerTime {
+        self.inner.last_event_time()
+    }
+
+    #[inline]
+    fn set_last_event_time(&mut self, time: ServerTime) {
+        self.inner.set_last_event_time(time);
+    }
+
+    #[inline]
+    fn keymap_generation(&self) -> u32 {
+        self.inner.keymap_generation()
+    }
+
+    #[inline]
+    fn set_keymap_generation(&mut self, generation: u32) {
+        self.inner.set_keymap_generation(generation);
+    }
+
+    #[inline]
+    fn ge_negotiated(&self) -> bool {
+        self.inner.ge_negotiated()
+    }
+
+    #[inline]
+    fn set_ge_negotiated(&mut self) {
+        self.inner.set_ge_negotiated();
+    }
+
+    #[inline]
+    fn max_pending(&self) -> usize {
+        self.inner.max_pending()
+    }
+
+    #[inline]
+    fn set_max_pending(&mut self, max: usize) {
+        self.inner.set_max_pending(max);
+    }
+
+    #[inline]
+    fn awaited_reply_count(&self) -> usize {
+        self.inner.awaited_reply_count()
+    }
+
+    #[inline]
+    fn reserve_pending(&mut self, additional: usize) {
+        self.inner.reserve_pending(additional);
+    }
+
+    #[inline]
+    fn unread_reply_count(&self) -> usize {
+        self.inner.unread_reply_count()
+    }
+
+    #[inline]
+    fn root_size(&self, screen: usize) -> Option<(u16, u16)> {
+        self.inner.root_size(screen)
+    }
+
+    #[inline]
+    fn set_root_size(&mut self, screen: usize, width: u16, height: u16) {
+        self.inner.set_root_size(screen, width, height);
+    }
+
+    #[inline]
+    fn tracking_resources(&self) -> bool {
+        self.inner.tracking_resources()
+    }
+
+    #[inline]
+    fn track_resources(&mut self, track: bool) {
+        self.inner.track_resources(track);
+    }
+
+    #[inline]
+    fn track_resource(&mut self, xid: XID, kind: ResourceKind) {
+        self.inner.track_resource(xid, kind);
+    }
+
+    #[inline]
+    fn untrack_resource(&mut self, xid: XID) {
+        self.inner.untrack_resource(xid);
+    }
+
+    #[inline]
+    fn add_orphan_reply(&mut self, sequence: u16, reply: &[u8]) {
+        self.inner.add_orphan_reply(sequence, reply);
+    }
+
+    #[inline]
+    fn orphan_reply_count(&self) -> usize {
+        self.inner.orphan_reply_count()
+    }
+
+    #[inline]
+    fn on_orphan_reply(&mut self, hook: Option<OrphanReplyHook>) {
+        self.inner.on_orphan_reply(hook);
+    }
+
+    #[inline]
+    fn register_event_decoder(
+        &mut self,
+        extension: &'static str,
+        event_count: u8,
+        decoder: EventDecoder,
+    ) {
+        self.inner
+            .register_event_decoder(extension, event_count, decoder);
+    }
+
+    #[inline]
+    fn event_decoder(&self, code: u8) -> Option<(u8, EventDecoder)> {
+        self.inner.event_decoder(code)
+    }
+
+    #[inline]
+    fn has_event_decoder(&self, extension: &str) -> bool {
+        self.inner.has_event_decoder(extension)
+    }
 }
 
 impl<'a, Dpy: DisplayBase> DisplayBase for &'a RenderDisplay<Dpy>
@@ -483,6 +724,61 @@ where
         self.inner().set_extension_opcode(key, opcode);
     }
 
+    #[inline]
+    fn get_extension_presence(&mut self, key: &[u8; EXT_KEY_SIZE]) -> ExtensionPresence {
+        self.inner().get_extension_presence(key)
+    }
+
+    #[inline]
+    fn set_extension_absent(&mut self, key: [u8; EXT_KEY_SIZE]) {
+        self.inner().set_extension_absent(key);
+    }
+
+    #[inline]
+    fn get_extension_first_event(&self, key: &[u8; EXT_KEY_SIZE]) -> Option<u8> {
+        self.inner().get_extension_first_event(key)
+    }
+
+    #[inline]
+    fn set_extension_first_event(&mut self, key: [u8; EXT_KEY_SIZE], first_event: u8) {
+        self.inner().set_extension_first_event(key, first_event);
+    }
+
+    #[inline]
+    fn add_pending_extension_query(&mut self, key: [u8; EXT_KEY_SIZE], req_id: u16) {
+        self.inner().add_pending_extension_query(key, req_id);
+    }
+
+    #[inline]
+    fn take_pending_extension_query(&mut self, key: &[u8; EXT_KEY_SIZE]) -> Option<u16> {
+        self.inner().take_pending_extension_query(key)
+    }
+
+    #[inline]
+    fn pending_extension_query(&self, key: &[u8; EXT_KEY_SIZE]) -> Option<u16> {
+        self.inner().pending_extension_query(key)
+    }
+
+    #[inline]
+    fn defer_request(&mut self, request: RequestInfo) {
+        self.inner().defer_request(request);
+    }
+
+    #[inline]
+    fn take_deferred_request(&mut self) -> Option<RequestInfo> {
+        self.inner().take_deferred_request()
+    }
+
+    #[inline]
+    fn restore_deferred_request(&mut self, request: RequestInfo) {
+        self.inner().restore_deferred_request(request);
+    }
+
+    #[inline]
+    fn has_deferred_requests(&self) -> bool {
+        self.inner().has_deferred_requests()
+    }
+
     #[inline]
     fn wm_protocols_atom(&self) -> Option<NonZeroU32> {
         self.inner().wm_protocols_atom()
@@ -492,6 +788,147 @@ where
     fn set_wm_protocols_atom(&mut self, a: NonZeroU32) {
         self.inner().set_wm_protocols_atom(a)
     }
+
+    #[inline]
+    fn xembed_atom(&self) -> Option<NonZeroU32> {
+        self.inner().xembed_atom()
+    }
+
+    #[inline]
+    fn set_xembed_atom(&mut self, a: NonZeroU32) {
+        self.inner().set_xembed_atom(a);
+    }
+
+    #[inline]
+    fn xembed_info_atom(&self) -> Option<NonZeroU32> {
+        self.inner().xembed_info_atom()
+    }
+
+    #[inline]
+    fn set_xembed_info_atom(&mut self, a: NonZeroU32) {
+        self.inner().set_xembed_info_atom(a);
+    }
+
+    #[inline]
+    fn last_event_time(&self) -> ServerTime {
+        self.inner().last_event_time()
+    }
+
+    #[inline]
+    fn set_last_event_time(&mut self, time: ServerTime) {
+        self.inner().set_last_event_time(time);
+    }
+
+    #[inline]
+    fn keymap_generation(&self) -> u32 {
+        self.inner().keymap_generation()
+    }
+
+    #[inline]
+    fn set_keymap_generation(&mut self, generation: u32) {
+        self.inner().set_keymap_generation(generation);
+    }
+
+    #[inline]
+    fn ge_negotiated(&self) -> bool {
+        self.inner().ge_negotiated()
+    }
+
+    #[inline]
+    fn set_ge_negotiated(&mut self) {
+        self.inner().set_ge_negotiated();
+    }
+
+    #[inline]
+    fn max_pending(&self) -> usize {
+        self.inner().max_pending()
+    }
+
+    #[inline]
+    fn set_max_pending(&mut self, max: usize) {
+        self.inner().set_max_pending(max);
+    }
+
+    #[inline]
+    fn awaited_reply_count(&self) -> usize {
+        self.inner().awaited_reply_count()
+    }
+
+    #[inline]
+    fn reserve_pending(&mut self, additional: usize) {
+        self.inner().reserve_pending(additional);
+    }
+
+    #[inline]
+    fn unread_reply_count(&self) -> usize {
+        self.inner().unread_reply_count()
+    }
+
+    #[inline]
+    fn root_size(&self, screen: usize) -> Option<(u16, u16)> {
+        self.inner().root_size(screen)
+    }
+
+    #[inline]
+    fn set_root_size(&mut self, screen: usize, width: u16, height: u16) {
+        self.inner().set_root_size(screen, width, height);
+    }
+
+    #[inline]
+    fn tracking_resources(&self) -> bool {
+        self.inner().tracking_resources()
+    }
+
+    #[inline]
+    fn track_resources(&mut self, track: bool) {
+        self.inner().track_resources(track);
+    }
+
+    #[inline]
+    fn track_resource(&mut self, xid: XID, kind: ResourceKind) {
+        self.inner().track_resource(xid, kind);
+    }
+
+    #[inline]
+    fn untrack_resource(&mut self, xid: XID) {
+        self.inner().untrack_resource(xid);
+    }
+
+    #[inline]
+    fn add_orphan_reply(&mut self, sequence: u16, reply: &[u8]) {
+        self.inner().add_orphan_reply(sequence, reply);
+    }
+
+    #[inline]
+    fn orphan_reply_count(&self) -> usize {
+        self.inner().orphan_reply_count()
+    }
+
+    #[inline]
+    fn on_orphan_reply(&mut self, hook: Option<OrphanReplyHook>) {
+        self.inner().on_orphan_reply(hook);
+    }
+
+    #[inline]
+    fn register_event_decoder(
+        &mut self,
+        extension: &'static str,
+        event_count: u8,
+        decoder: EventDecoder,
+    ) {
+        self.inner()
+            .register_event_decoder(extension, event_count, decoder);
+    }
+
+    #[inline]
+    fn event_decoder(&self, code: u8) -> Option<(u8, EventDecoder)> {
+        self.inner().event_decoder(code)
+    }
+
+    #[inline]
+    fn has_event_decoder(&self, extension: &str) -> bool {
+        self.inner().has_event_decoder(extension)
+    }
 }
 
 impl<Dpy: Display> Display for RenderDisplay<Dpy> {
@@ -603,25 +1040,10 @@ impl<Dpy: Display> RenderDisplay<Dpy> {
             })
         }
 
-        let XrenderInfo {
-            formats,
-            screens,
-            subpixels,
-            major_version,
-            minor_version,
-        } = match xrender_info(&mut dpy, client_major_version, client_minor_version) {
-            Ok(x) => x,
-            Err(e) => return Err((dpy, e)),
-        };
-
-        Ok(Self {
-            inner: dpy,
-            major_version,
-            minor_version,
-            formats,
-            screens,
-            subpixels,
-        })
+        match xrender_info(&mut dpy, client_major_version, client_minor_version) {
+            Ok(info) => Ok(Self::from_info(dpy, info)),
+            Err(e) => Err((dpy, e)),
+        }
     }
 
     /// Create a new Picture.
@@ -638,17 +1060,19 @@ impl<Dpy: Display> RenderDisplay<Dpy> {
         Ok(pic)
     }
 
-    /// Create a new linear gradient.
+    /// Create a new linear gradient. Each stop is a position along the gradient, from 0 to 1, paired with
+    /// the color at that position; the stops must be in non-decreasing order.
     #[inline]
     pub fn create_linear_gradient(
         &mut self,
         p1: Pointfix,
         p2: Pointfix,
-        stops: &[Fixed],
-        colors: &[Color],
+        stops: &[(Fixed, Color)],
     ) -> crate::Result<Picture> {
+        // check the stops before an XID is used up on them
+        let mut clgr = Self::create_linear_gradient_request(p1, p2, stops)?;
         let pic = Picture::const_from_xid(generate_xid(self)?);
-        let clgr = Self::create_linear_gradient_request(pic, p1, p2, stops, colors);
+        clgr.picture = pic;
         self.exchange_request(clgr)?;
         Ok(pic)
     }
@@ -661,19 +1085,13 @@ impl<Dpy: Display> RenderDisplay<Dpy> {
         outer: Pointfix,
         inner_radius: Fixed,
         outer_radius: Fixed,
-        stops: &[Fixed],
-        colors: &[Color],
+        stops: &[(Fixed, Color)],
     ) -> crate::Result<Picture> {
+        // check the stops before an XID is used up on them
+        let mut crgr =
+            Self::create_radial_gradient_request(inner, outer, inner_radius, outer_radius, stops)?;
         let pic = Picture::const_from_xid(generate_xid(self)?);
-        let crgr = Self::create_radial_gradient_request(
-            pic,
-            inner,
-            outer,
-            inner_radius,
-            outer_radius,
-            stops,
-            colors,
-        );
+        crgr.picture = pic;
         self.exchange_request(crgr)?;
         Ok(pic)
     }
@@ -683,11 +1101,12 @@ impl<Dpy: Display> RenderDisplay<Dpy> {
         &mut self,
         center: Pointfix,
         angle: Fixed,
-        stops: &[Fixed],
-        colors: &[Color],
+        stops: &[(Fixed, Color)],
     ) -> crate::Result<Picture> {
+        // check the stops before an XID is used up on them
+        let mut ccgr = Self::create_conical_gradient_request(center, angle, stops)?;
         let pic = Picture::const_from_xid(generate_xid(self)?);
-        let ccgr = Self::create_conical_gradient_request(pic, center, angle, stops, colors);
+        ccgr.picture = pic;
         self.exchange_request(ccgr)?;
         Ok(pic)
     }
@@ -740,25 +1159,10 @@ impl<Dpy: AsyncDisplay> RenderDisplay<Dpy> {
             })
         }
 
-        let XrenderInfo {
-            major_version,
-            minor_version,
-            formats,
-            screens,
-            subpixels,
-        } = match xrender_info(&mut dpy, client_major_version, client_minor_version).await {
-            Ok(x) => x,
-            Err(e) => return Err((dpy, e)),
-        };
-
-        Ok(Self {
-            inner: dpy,
-            major_version,
-            minor_version,
-            formats,
-            screens,
-            subpixels,
-        })
+        match xrender_info(&mut dpy, client_major_version, client_minor_version).await {
+            Ok(info) => Ok(Self::from_info(dpy, info)),
+            Err(e) => Err((dpy, e)),
+        }
     }
 
     /// Create a new Picture, async redox.
@@ -781,11 +1185,12 @@ impl<Dpy: AsyncDisplay> RenderDisplay<Dpy> {
         &mut self,
         p1: Pointfix,
         p2: Pointfix,
-        stops: &[Fixed],
-        colors: &[Color],
+        stops: &[(Fixed, Color)],
     ) -> crate::Result<Picture> {
+        // check the stops before an XID is used up on them
+        let mut clgr = Self::create_linear_gradient_request(p1, p2, stops)?;
         let pic = Picture::const_from_xid(generate_xid(self)?);
-        let clgr = Self::create_linear_gradient_request(pic, p1, p2, stops, colors);
+        clgr.picture = pic;
         self.exchange_request_async(clgr).await?;
         Ok(pic)
     }
@@ -798,19 +1203,13 @@ impl<Dpy: AsyncDisplay> RenderDisplay<Dpy> {
         outer: Pointfix,
         inner_radius: Fixed,
         outer_radius: Fixed,
-        stops: &[Fixed],
-        colors: &[Color],
+        stops: &[(Fixed, Color)],
     ) -> crate::Result<Picture> {
+        // check the stops before an XID is used up on them
+        let mut crgr =
+            Self::create_radial_gradient_request(inner, outer, inner_radius, outer_radius, stops)?;
         let pic = Picture::const_from_xid(generate_xid(self)?);
-        let crgr = Self::create_radial_gradient_request(
-            pic,
-            inner,
-            outer,
-            inner_radius,
-            outer_radius,
-            stops,
-            colors,
-        );
+        crgr.picture = pic;
         self.exchange_request_async(crgr).await?;
         Ok(pic)
     }
@@ -820,12 +1219,128 @@ impl<Dpy: AsyncDisplay> RenderDisplay<Dpy> {
         &mut self,
         center: Pointfix,
         angle: Fixed,
-        stops: &[Fixed],
-        colors: &[Color],
+        stops: &[(Fixed, Color)],
     ) -> crate::Result<Picture> {
+        // check the stops before an XID is used up on them
+        let mut ccgr = Self::create_conical_gradient_request(center, angle, stops)?;
         let pic = Picture::const_from_xid(generate_xid(self)?);
-        let ccgr = Self::create_conical_gradient_request(pic, center, angle, stops, colors);
+        ccgr.picture = pic;
         self.exchange_request_async(ccgr).await?;
         Ok(pic)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{RenderDisplay, StandardFormat, XrenderInfo};
+    use crate::{
+        auto::{
+            render::{
+                Color, Directformat, PictType, Pictdepth, Pictformat, Pictforminfo, Pictscreen,
+                Pictvisual, Pointfix,
+            },
+            xproto::{Setup, Visualtype},
+        },
+        display::{mock_display, mock_setup, DisplayBase, MockConnection},
+        xid::XidType,
+    };
+    use alloc::vec;
+
+    #[test]
+    fn format_lookups_are_cached() {
+        let a8 = Pictforminfo {
+            id: Pictformat::const_from_xid(1),
+            ty: PictType::Direct,
+            depth: 8,
+            direct: Directformat {
+                alpha_mask: 0xFF,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let rgb24 = Pictforminfo {
+            id: Pictformat::const_from_xid(2),
+            ty: PictType::Direct,
+            depth: 24,
+            direct: Directformat {
+                red_shift: 16,
+                red_mask: 0xFF,
+                green_shift: 8,
+                green_mask: 0xFF,
+                blue_mask: 0xFF,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let screen = Pictscreen {
+            depths: vec![Pictdepth {
+                depth: 24,
+                visuals: vec![Pictvisual {
+                    visual: 0x21,
+                    format: rgb24.id,
+                }],
+            }],
+            ..Default::default()
+        };
+
+        let dpy = RenderDisplay::from_info(
+            (),
+            XrenderInfo {
+                formats: vec![a8, rgb24].into_boxed_slice(),
+                screens: vec![screen].into_boxed_slice(),
+                subpixels: vec![].into_boxed_slice(),
+                major_version: 0,
+                minor_version: 11,
+            },
+        );
+
+        let standard = |s| dpy.find_standard_format(s).map(|f| f.xid());
+        assert_eq!(standard(StandardFormat::A8), Some(1));
+        assert_eq!(standard(StandardFormat::Rgb24), Some(2));
+        assert_eq!(standard(StandardFormat::Argb32), None);
+
+        let visual = |visual_id| Visualtype {
+            visual_id,
+            ..Default::default()
+        };
+        assert_eq!(
+            dpy.find_visual_format(&visual(0x21)).map(|f| f.xid()),
+            Some(2)
+        );
+        assert_eq!(dpy.find_visual_format(&visual(0x22)), None);
+    }
+
+    #[test]
+    fn bad_gradient_stops_keep_the_xid() {
+        let setup = Setup {
+            resource_id_mask: 0xFF,
+            ..mock_setup()
+        };
+        let mut conn = MockConnection::with_setup(&setup);
+        let inner = mock_display(&mut conn);
+        let mut dpy = RenderDisplay::from_info(
+            inner,
+            XrenderInfo {
+                formats: vec![].into_boxed_slice(),
+                screens: vec![].into_boxed_slice(),
+                subpixels: vec![].into_boxed_slice(),
+                major_version: 0,
+                minor_version: 11,
+            },
+        );
+
+        let stops = [(0x10000, Color::default()), (0, Color::default())];
+        assert!(dpy
+            .create_linear_gradient(Pointfix::default(), Pointfix::default(), &stops)
+            .is_err());
+        assert!(dpy
+            .create_conical_gradient(Pointfix::default(), 0, &stops)
+            .is_err());
+        assert!(dpy
+            .create_radial_gradient(Pointfix::default(), Pointfix::default(), 0, 0, &stops)
+            .is_err());
+
+        // the failed calls above should not have taken any XIDs
+        assert_eq!(dpy.generate_xid().unwrap(), 1);
+    }
+}
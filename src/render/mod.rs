@@ -5,6 +5,7 @@
 mod display;
 mod picture;
 mod tesselate;
+mod transform;
 
 pub use display::*;
 pub use picture::*;
@@ -19,7 +20,8 @@ impl Copy for Linefix {}
 impl Copy for Color {}
 impl Copy for Trapezoid {}
 
-const MULTIPLIER: f64 = 0xFFFF as f64;
+// Fixed values are 16.16 fixed point numbers.
+const MULTIPLIER: f64 = 65536.0;
 
 /// Convert a fixed point value to a 64-bit float.
 #[inline]
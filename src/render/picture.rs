@@ -4,13 +4,15 @@ use crate::{
     auto::{
         render::{
             ChangePictureRequest, Color, CompositeRequest, Cp, CreatePictureRequest,
-            FillRectanglesRequest, FreePictureRequest, PictOp, Pictformat, Picture, PolyEdge,
-            PolyMode, Repeat, Trapezoid, TrapezoidsRequest,
+            FillRectanglesRequest, Fixed, FreePictureRequest, PictOp, Pictformat, Picture,
+            PolyEdge, PolyMode, Repeat, SetPictureFilterRequest, SetPictureTransformRequest,
+            Transform, Trapezoid, TrapezoidsRequest,
         },
         xproto::{Atom, Pixmap, Rectangle, SubwindowMode},
     },
     display::{Display, DisplayExt},
 };
+use alloc::string::ToString;
 
 #[cfg(feature = "async")]
 use crate::display::{AsyncDisplay, AsyncDisplayExt};
@@ -92,6 +94,74 @@ impl Picture {
             .await
     }
 
+    /// Set the transform used to map this picture's coordinates when it is used as a source or a mask.
+    #[inline]
+    pub fn set_transform<Dpy: Display + ?Sized>(
+        self,
+        display: &mut Dpy,
+        transform: Transform,
+    ) -> crate::Result {
+        display.exchange_request(SetPictureTransformRequest {
+            picture: self,
+            transform,
+            ..Default::default()
+        })
+    }
+
+    /// Set the transform used to map this picture's coordinates, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn set_transform_async<Dpy: AsyncDisplay + ?Sized>(
+        self,
+        display: &mut Dpy,
+        transform: Transform,
+    ) -> crate::Result {
+        display
+            .exchange_request_async(SetPictureTransformRequest {
+                picture: self,
+                transform,
+                ..Default::default()
+            })
+            .await
+    }
+
+    /// Set the filter used to sample this picture when it is transformed. Every server supports the
+    /// `"nearest"` and `"bilinear"` filters, as well as the `"fast"`, `"good"` and `"best"` aliases;
+    /// `params` is only needed by filters such as `"convolution"`, which take a kernel.
+    #[inline]
+    pub fn set_filter<Dpy: Display + ?Sized>(
+        self,
+        display: &mut Dpy,
+        filter: &str,
+        params: &[Fixed],
+    ) -> crate::Result {
+        display.exchange_request(self.set_filter_request(filter, params))
+    }
+
+    /// Set the filter used to sample this picture when it is transformed, async redox.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn set_filter_async<Dpy: AsyncDisplay + ?Sized>(
+        self,
+        display: &mut Dpy,
+        filter: &str,
+        params: &[Fixed],
+    ) -> crate::Result {
+        display
+            .exchange_request_async(self.set_filter_request(filter, params))
+            .await
+    }
+
+    #[inline]
+    fn set_filter_request(self, filter: &str, params: &[Fixed]) -> SetPictureFilterRequest {
+        SetPictureFilterRequest {
+            picture: self,
+            filter: filter.to_string(),
+            values: params.to_vec(),
+            ..Default::default()
+        }
+    }
+
     /// Composite this picture with another.
     #[inline]
     pub fn composite<Dpy: Display + ?Sized>(
@@ -0,0 +1,124 @@
+// MIT/Apache2 License
+
+//! Constructors for the transforms applied to pictures with `Picture::set_transform`. Transforms map points
+//! on the destination onto points on the source picture, so they are the inverse of the transformation the
+//! image appears to go through; for instance, scaling by 2 makes the picture appear at half of its size.
+
+use super::{double_to_fixed, fixed_to_double, Fixed, Transform};
+
+const ONE: Fixed = 1 << 16;
+
+impl Transform {
+    /// The transform that leaves the picture as it is.
+    #[inline]
+    #[must_use]
+    pub fn identity() -> Self {
+        Self {
+            matrix11: ONE,
+            matrix22: ONE,
+            matrix33: ONE,
+            ..Default::default()
+        }
+    }
+
+    /// Scale the picture's coordinates by `sx` horizontally and `sy` vertically.
+    #[inline]
+    #[must_use]
+    pub fn scale(sx: f64, sy: f64) -> Self {
+        Self {
+            matrix11: double_to_fixed(sx),
+            matrix22: double_to_fixed(sy),
+            ..Self::identity()
+        }
+    }
+
+    /// Offset the picture's coordinates by `tx` horizontally and `ty` vertically.
+    #[inline]
+    #[must_use]
+    pub fn translate(tx: f64, ty: f64) -> Self {
+        Self {
+            matrix13: double_to_fixed(tx),
+            matrix23: double_to_fixed(ty),
+            ..Self::identity()
+        }
+    }
+
+    /// Rotate the picture's coordinates by `angle` radians around the origin.
+    #[cfg(feature = "std")]
+    #[inline]
+    #[must_use]
+    pub fn rotate(angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            matrix11: double_to_fixed(cos),
+            matrix12: double_to_fixed(-sin),
+            matrix21: double_to_fixed(sin),
+            matrix22: double_to_fixed(cos),
+            ..Self::identity()
+        }
+    }
+
+    /// Combine this transform with another. The resulting transform applies `other` to a point first, and
+    /// then this transform.
+    #[inline]
+    #[must_use]
+    pub fn multiply(&self, other: &Self) -> Self {
+        let a = self.to_rows();
+        let b = other.to_rows();
+        let cell = |row: usize, col: usize| {
+            double_to_fixed(
+                (0..3)
+                    .map(|i| fixed_to_double(a[row][i]) * fixed_to_double(b[i][col]))
+                    .sum(),
+            )
+        };
+
+        Self {
+            matrix11: cell(0, 0),
+            matrix12: cell(0, 1),
+            matrix13: cell(0, 2),
+            matrix21: cell(1, 0),
+            matrix22: cell(1, 1),
+            matrix23: cell(1, 2),
+            matrix31: cell(2, 0),
+            matrix32: cell(2, 1),
+            matrix33: cell(2, 2),
+        }
+    }
+
+    #[inline]
+    fn to_rows(&self) -> [[Fixed; 3]; 3] {
+        [
+            [self.matrix11, self.matrix12, self.matrix13],
+            [self.matrix21, self.matrix22, self.matrix23],
+            [self.matrix31, self.matrix32, self.matrix33],
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Transform, ONE};
+    use crate::{auto::render::SetPictureTransformRequest, display::RequestInfo};
+
+    #[test]
+    fn transform_is_row_major() {
+        let transform = Transform::scale(2.0, 2.0).multiply(&Transform::translate(1.5, -1.0));
+        assert_eq!(transform.matrix11, 2 * ONE);
+        assert_eq!(transform.matrix13, 3 * ONE);
+        assert_eq!(transform.matrix23, -2 * ONE);
+
+        let req = SetPictureTransformRequest {
+            transform,
+            ..Default::default()
+        };
+        let data = RequestInfo::from_request(req, false, usize::from(u16::MAX) * 4).data;
+        // the picture ID, then each row of the matrix in turn
+        let fixed = |i: usize| i32::from_ne_bytes([data[i], data[i + 1], data[i + 2], data[i + 3]]);
+        assert_eq!(fixed(8), 2 * ONE);
+        assert_eq!(fixed(12), 0);
+        assert_eq!(fixed(16), 3 * ONE);
+        assert_eq!(fixed(28), -2 * ONE);
+        assert_eq!(fixed(40), ONE);
+    }
+}
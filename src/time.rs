@@ -0,0 +1,114 @@
+// MIT/Apache2 License
+
+//! Server timestamps. The server counts time in milliseconds in a 32-bit value, which wraps around roughly
+//! every 49.7 days, so two timestamps can't be compared or subtracted as plain integers once a session has
+//! been running across the wrap.
+
+use core::time::Duration;
+
+/// Half of the timestamp space. The protocol treats a timestamp as later than another if it is less than
+/// this far ahead of it, once wraparound is taken into account.
+const HALF_RANGE: u32 = 1 << 31;
+
+/// A timestamp from the server's clock, in milliseconds. Zero is reserved for `CurrentTime`, which stands
+/// for whatever the server's time is when it processes the request.
+///
+/// Requests and events carry timestamps as `auto::xproto::Timestamp`, which is a plain `u32`; `get` and the
+/// `From<u32>` implementation convert between the two.
+///
+/// This type deliberately doesn't implement `Ord`: since the clock wraps around, "later than" isn't
+/// transitive across the whole range. Use `is_earlier_than` and `duration_since` instead.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct ServerTime(u32);
+
+impl ServerTime {
+    /// `CurrentTime`, which the server replaces with its own time.
+    pub const CURRENT_TIME: ServerTime = ServerTime(0);
+
+    #[inline]
+    #[must_use]
+    pub const fn new(millis: u32) -> Self {
+        Self(millis)
+    }
+
+    /// The raw value, as it is sent on the wire.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_current_time(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Whether or not this timestamp comes before `other`. `CurrentTime` is never earlier than anything,
+    /// and every real timestamp is earlier than it, which is how the server treats it when deciding whether
+    /// a grab or selection request is out of date.
+    #[inline]
+    #[must_use]
+    pub fn is_earlier_than(self, other: ServerTime) -> bool {
+        if self.is_current_time() {
+            false
+        } else if other.is_current_time() {
+            true
+        } else {
+            let ahead = other.0.wrapping_sub(self.0);
+            ahead != 0 && ahead < HALF_RANGE
+        }
+    }
+
+    /// The time that passed between `earlier` and this timestamp, counting across the wraparound. Returns
+    /// `None` if either timestamp is `CurrentTime`, or if `earlier` is actually later than this one.
+    #[inline]
+    #[must_use]
+    pub fn duration_since(self, earlier: ServerTime) -> Option<Duration> {
+        if self.is_current_time() || earlier.is_current_time() || self.is_earlier_than(earlier) {
+            None
+        } else {
+            Some(Duration::from_millis(u64::from(
+                self.0.wrapping_sub(earlier.0),
+            )))
+        }
+    }
+}
+
+impl From<u32> for ServerTime {
+    #[inline]
+    fn from(millis: u32) -> Self {
+        Self(millis)
+    }
+}
+
+impl From<ServerTime> for u32 {
+    #[inline]
+    fn from(time: ServerTime) -> u32 {
+        time.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ServerTime;
+    use core::time::Duration;
+
+    #[test]
+    fn timestamps_compare_across_the_wrap() {
+        let before = ServerTime::new(u32::MAX - 99);
+        let after = ServerTime::new(400);
+        assert!(before.is_earlier_than(after));
+        assert!(!after.is_earlier_than(before));
+        assert_eq!(
+            after.duration_since(before),
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(before.duration_since(after), None);
+
+        assert!(after.is_earlier_than(ServerTime::CURRENT_TIME));
+        assert!(!ServerTime::CURRENT_TIME.is_earlier_than(after));
+        assert_eq!(ServerTime::CURRENT_TIME.duration_since(after), None);
+    }
+}
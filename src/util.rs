@@ -2,6 +2,7 @@
 
 #![allow(clippy::unreadable_literal)]
 
+use crate::Fd;
 use core::iter;
 use tinyvec::{Array, TinyVec};
 
@@ -23,6 +24,24 @@ pub(crate) fn convert_nix_error(e: NixError) -> IoError {
     }
 }
 
+/// Close a series of file descriptors that we own. Errors are logged and then ignored, since there is
+/// little that can be done about them.
+#[inline]
+pub(crate) fn close_fds<I: IntoIterator<Item = Fd>>(fds: I) {
+    cfg_if::cfg_if! {
+        if #[cfg(all(unix, feature = "std"))] {
+            fds.into_iter().for_each(|fd| {
+                if let Err(e) = nix::unistd::close(fd) {
+                    log::warn!("Failed to close file descriptor {}: {}", fd, e);
+                }
+            });
+        } else {
+            // we can't receive file descriptors on non-unix systems anyways
+            let _ = fds;
+        }
+    }
+}
+
 /// Expand or truncate a `TinyVec<[u8; N]>` so that its length becomes the given value.
 #[inline]
 pub(crate) fn expand_or_truncate_to_length<A: Array<Item = u8>>(tv: &mut TinyVec<A>, len: usize) {
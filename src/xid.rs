@@ -8,6 +8,11 @@ use core::cell::Cell;
 pub type XID = u32;
 
 /// A type that acts as a wrapper around an XID.
+///
+/// Every kind of resource gets its own wrapper, like `Window`, `Pixmap` or `Gcontext`, and requests take the
+/// specific kind their protocol description asks for, so a graphics context can't be passed where a window
+/// is expected. Types that stand for more than one kind of resource, like `Drawable` and `Fontable`, can be
+/// created from each of the kinds they cover using `From`.
 pub trait XidType {
     fn xid(&self) -> XID;
     fn from_xid(xid: XID) -> Self;